@@ -31,6 +31,29 @@ pub fn criterion_benchmark(criterion: &mut Criterion) {
             predictions
         })
     });
+    criterion.bench_function("propagate all unchecked", |b| {
+        b.iter(|| {
+            let mut predictions = Vec::new();
+            for test_case in test_cases.list.iter() {
+                let constants = sgp4::Constants::from_elements(
+                    &sgp4::Elements::from_tle(
+                        None,
+                        test_case.line1.as_bytes(),
+                        test_case.line2.as_bytes(),
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+                for state in &test_case.states {
+                    if let State::Ok { time, .. } = state {
+                        predictions
+                            .push(constants.propagate_unchecked(sgp4::MinutesSinceEpoch(*time)));
+                    }
+                }
+            }
+            predictions
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
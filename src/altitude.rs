@@ -0,0 +1,150 @@
+use crate::events;
+use crate::geodetic;
+use crate::gp;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+
+/// The altitude of the satellite at a given time
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltitudeExtremum {
+    /// Time of the extremum in minutes since epoch
+    pub t: MinutesSinceEpoch,
+
+    /// Height above the WGS84 ellipsoid in km
+    pub altitude: f64,
+}
+
+/// The lowest and highest altitudes of the satellite in a time window
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AltitudeExtrema {
+    /// The lowest altitude
+    pub minimum: AltitudeExtremum,
+
+    /// The highest altitude
+    pub maximum: AltitudeExtremum,
+}
+
+impl propagator::Constants {
+    // height above the WGS84 ellipsoid in km
+    fn altitude(&self, t: MinutesSinceEpoch) -> core::result::Result<f64, gp::Error> {
+        self.propagate(t)
+            .map(|prediction| geodetic::Geodetic::from_teme(0.0, &prediction.position).altitude)
+    }
+
+    /// Finds the lowest and highest altitudes above the WGS84 ellipsoid in a time window
+    ///
+    /// The altitude is sampled 64 times per orbit, and the best samples are refined with a golden-section search.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch (must not be smaller than `start`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let extrema = constants.altitude_extrema(
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    /// )?;
+    /// println!(
+    ///     "altitude range: [{}, {}] km",
+    ///     extrema.minimum.altitude, extrema.maximum.altitude
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn altitude_extrema(
+        &self,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+    ) -> core::result::Result<AltitudeExtrema, gp::Error> {
+        assert!(end.0 >= start.0, "end must not be smaller than start");
+        let step = self.period() / 64.0;
+        let mut t = start.0;
+        let mut minimum = (t, self.altitude(start)?);
+        let mut maximum = minimum;
+        while t < end.0 {
+            t = (t + step).min(end.0);
+            let altitude = self.altitude(MinutesSinceEpoch(t))?;
+            if altitude < minimum.1 {
+                minimum = (t, altitude);
+            }
+            if altitude > maximum.1 {
+                maximum = (t, altitude);
+            }
+        }
+        let (minimum_t, minimum_altitude) = events::maximize(
+            (minimum.0 - step).max(start.0),
+            (minimum.0 + step).min(end.0),
+            1e-6,
+            |t| self.altitude(t).map(|altitude| -altitude),
+        )?;
+        let (maximum_t, maximum_altitude) = events::maximize(
+            (maximum.0 - step).max(start.0),
+            (maximum.0 + step).min(end.0),
+            1e-6,
+            |t| self.altitude(t),
+        )?;
+        Ok(AltitudeExtrema {
+            minimum: AltitudeExtremum {
+                t: minimum_t,
+                altitude: -minimum_altitude,
+            },
+            maximum: AltitudeExtremum {
+                t: maximum_t,
+                altitude: maximum_altitude,
+            },
+        })
+    }
+
+    /// Finds the times at which the altitude above the WGS84 ellipsoid crosses a threshold
+    ///
+    /// Crossings with the direction [Direction::Increasing](enum.Direction.html#variant.Increasing)
+    /// are climbs above the threshold, and crossings with the direction
+    /// [Direction::Decreasing](enum.Direction.html#variant.Decreasing) are descents below the threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `threshold` - The altitude threshold in km
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// for crossing in constants.altitude_crossings(
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    ///     420.0,
+    /// )? {
+    ///     println!("{:?} at t = {} min", crossing.direction, crossing.t.0);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn altitude_crossings(
+        &self,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        threshold: f64,
+    ) -> core::result::Result<alloc::vec::Vec<events::Crossing>, gp::Error> {
+        events::find_crossings(start, end, self.period() / 64.0, 1e-6, |t| {
+            self.altitude(t).map(|altitude| altitude - threshold)
+        })
+    }
+}
@@ -0,0 +1,50 @@
+/// An angle in radians
+///
+/// Functions that take angles as `impl Into<Radians>` (for instance
+/// [Orbit::from_kozai_elements](struct.Orbit.html#method.from_kozai_elements)) accept both
+/// `Radians` and [Degrees](struct.Degrees.html), which prevents passing TLE or OMM angles
+/// (stored in degrees in `Elements`) where radians are expected.
+///
+/// # Example
+///
+/// ```
+/// let right_angle = sgp4::Radians::from(sgp4::Degrees(90.0));
+/// assert!((right_angle.0 - core::f64::consts::FRAC_PI_2).abs() < 1e-15);
+/// assert!((right_angle.to_degrees().0 - 90.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Radians(pub f64);
+
+/// An angle in degrees
+///
+/// `Elements` store angles in degrees, see [Radians](struct.Radians.html).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Degrees(pub f64);
+
+impl Radians {
+    /// Converts the angle to degrees
+    pub fn to_degrees(self) -> Degrees {
+        Degrees(self.0 * (180.0 / core::f64::consts::PI))
+    }
+}
+
+impl Degrees {
+    /// Converts the angle to radians
+    pub fn to_radians(self) -> Radians {
+        Radians(self.0 * (core::f64::consts::PI / 180.0))
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(degrees: Degrees) -> Self {
+        degrees.to_radians()
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(radians: Radians) -> Self {
+        radians.to_degrees()
+    }
+}
@@ -0,0 +1,107 @@
+use crate::events;
+use crate::gp;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+use crate::vector;
+
+/// Orbital apsis type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Apsis {
+    /// Local minimum of the geocentric distance
+    Perigee,
+
+    /// Local maximum of the geocentric distance
+    Apogee,
+}
+
+/// A perigee or apogee passage
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApsisPassage {
+    /// Time of the passage in minutes since epoch
+    pub t: MinutesSinceEpoch,
+
+    /// Passage type
+    pub apsis: Apsis,
+
+    /// Geocentric distance at the passage in km
+    pub radius: f64,
+}
+
+impl propagator::Constants {
+    /// Finds the perigee and apogee passages in a time window
+    ///
+    /// Passages are the zero crossings of the radial velocity ṙ · r / |r|.
+    /// Since SGP4 predictions include short-period perturbations, near-circular orbits
+    /// may have more than one perigee and one apogee per revolution.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("MOLNIYA 1-36".to_owned()),
+    ///     "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+    ///     "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::from_elements(&elements)?;
+    /// let passages = constants.apsis_passages(
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    /// )?;
+    /// for passage in passages.iter() {
+    ///     println!("{:?} at t = {} min (r = {} km)", passage.apsis, passage.t.0, passage.radius);
+    /// }
+    ///
+    /// // two revolutions per day, the first passage is an apogee
+    /// assert_eq!(passages.len(), 4);
+    /// assert_eq!(passages[0].apsis, sgp4::Apsis::Apogee);
+    ///
+    /// // perigees and apogees alternate, half a period apart
+    /// for pair in passages.windows(2) {
+    ///     assert_ne!(pair[0].apsis, pair[1].apsis);
+    ///     assert!((pair[1].t.0 - pair[0].t.0 - constants.period() / 2.0).abs() < 0.1);
+    /// }
+    ///
+    /// // the radius is a (1 - e) at perigee and a (1 + e) at apogee,
+    /// // up to short-period perturbations of a few tens of km
+    /// let semi_major_axis = elements.semi_major_axis(&sgp4::WGS84);
+    /// for passage in passages.iter() {
+    ///     let radius = match passage.apsis {
+    ///         sgp4::Apsis::Perigee => semi_major_axis * (1.0 - elements.eccentricity),
+    ///         sgp4::Apsis::Apogee => semi_major_axis * (1.0 + elements.eccentricity),
+    ///     };
+    ///     assert!((passage.radius - radius).abs() < 50.0);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn apsis_passages(
+        &self,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+    ) -> core::result::Result<alloc::vec::Vec<ApsisPassage>, gp::Error> {
+        events::find_crossings(start, end, self.period() / 16.0, 1e-6, |t| {
+            self.propagate(t)
+                .map(|prediction| vector::dot(&prediction.position, &prediction.velocity))
+        })?
+        .into_iter()
+        .map(|crossing| {
+            Ok(ApsisPassage {
+                t: crossing.t,
+                apsis: match crossing.direction {
+                    events::Direction::Increasing => Apsis::Perigee,
+                    events::Direction::Decreasing => Apsis::Apogee,
+                },
+                radius: vector::norm(&self.propagate(crossing.t)?.position),
+            })
+        })
+        .collect()
+    }
+}
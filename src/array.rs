@@ -0,0 +1,69 @@
+use crate::gp;
+use crate::propagator;
+use crate::tle;
+
+// copies the position and velocity into an array row
+fn write_state(mut row: ndarray::ArrayViewMut1<f64>, prediction: &propagator::Prediction) {
+    for (value, component) in row
+        .iter_mut()
+        .zip(prediction.position.iter().chain(prediction.velocity.iter()))
+    {
+        *value = *component;
+    }
+}
+
+/// Returns the positions and velocities of predictions as a 2-D array
+///
+/// The array has one row per prediction and six columns (x, y, z, ẋ, ẏ, ż),
+/// with positions in km and velocities in km.s⁻¹ in the True Equator, Mean Equinox (TEME) reference frame.
+///
+/// # Arguments
+///
+/// * `predictions` - The predictions to stack
+pub fn predictions_to_array(predictions: &[propagator::Prediction]) -> ndarray::Array2<f64> {
+    let mut states = ndarray::Array2::zeros((predictions.len(), 6));
+    for (row, prediction) in states.rows_mut().into_iter().zip(predictions) {
+        write_state(row, prediction);
+    }
+    states
+}
+
+impl propagator::Constants {
+    /// Calculates the SGP4 positions and velocities at several times and returns them as a 2-D array
+    ///
+    /// The array has one row per time and six columns (x, y, z, ẋ, ẏ, ż),
+    /// with positions in km and velocities in km.s⁻¹ in the True Equator, Mean Equinox (TEME) reference frame.
+    /// The propagation stops at the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `times` - The times since epoch in minutes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let times: Vec<sgp4::MinutesSinceEpoch> = (0..=1440)
+    ///     .map(|minutes| sgp4::MinutesSinceEpoch(minutes as f64))
+    ///     .collect();
+    /// let states = constants.propagate_to_array(&times)?;
+    /// assert_eq!(states.shape(), &[1441, 6]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn propagate_to_array(
+        &self,
+        times: &[tle::MinutesSinceEpoch],
+    ) -> core::result::Result<ndarray::Array2<f64>, gp::Error> {
+        let mut states = ndarray::Array2::zeros((times.len(), 6));
+        for (row, t) in states.rows_mut().into_iter().zip(times) {
+            write_state(row, &self.propagate(*t)?);
+        }
+        Ok(states)
+    }
+}
@@ -0,0 +1,284 @@
+use crate::model;
+use crate::observer;
+use crate::propagator;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// 1″ = π / (180 × 3600) rad
+const ARCSECOND: f64 = core::f64::consts::PI / (180.0 * 3600.0);
+
+/// Spherical equatorial coordinates of a point
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Equatorial {
+    /// Right ascension in rad, in the range [0, 2π[
+    pub right_ascension: f64,
+
+    /// Declination in rad (positive north), in the range [-π/2, π/2]
+    pub declination: f64,
+
+    /// Distance in km
+    pub distance: f64,
+}
+
+impl Equatorial {
+    /// Converts a cartesian position to right ascension, declination and distance
+    ///
+    /// The coordinates are expressed in the reference frame of the position.
+    /// Applied to a geocentric TEME position (for instance a prediction), this yields
+    /// coordinates referred to the true equator and mean equinox of date.
+    /// Astrometric catalogs use the J2000 (or ICRS) equator and equinox,
+    /// see [sgp4::teme_to_mean_j2000](fn.teme_to_mean_j2000.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The three position components (x, y, z) in km
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let t = sgp4::MinutesSinceEpoch(30.0);
+    /// let prediction = constants.propagate(t)?;
+    /// let of_date = sgp4::Equatorial::from_cartesian(&prediction.position);
+    /// let j2000 = sgp4::Equatorial::from_cartesian(&sgp4::teme_to_mean_j2000(
+    ///     constants.julian_years_since_j2000(t),
+    ///     &prediction.position,
+    /// ));
+    /// println!(
+    ///     "RA = {}° (J2000 {}°), Dec = {}° (J2000 {}°), distance = {} km",
+    ///     of_date.right_ascension.to_degrees(),
+    ///     j2000.right_ascension.to_degrees(),
+    ///     of_date.declination.to_degrees(),
+    ///     j2000.declination.to_degrees(),
+    ///     of_date.distance,
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_cartesian(position: &[f64; 3]) -> Self {
+        // d = (x² + y² + z²)¹ᐟ²
+        let distance = (position[0].powi(2) + position[1].powi(2) + position[2].powi(2)).sqrt();
+
+        Equatorial {
+            // α = tan⁻¹(y / x) mod 2π
+            right_ascension: model::positive_angle(position[1].atan2(position[0])),
+
+            // δ = tan⁻¹(z / (x² + y²)¹ᐟ²)
+            declination: position[2].atan2((position[0].powi(2) + position[1].powi(2)).sqrt()),
+            distance,
+        }
+    }
+}
+
+/// Atmospheric conditions at a ground observer, used to correct elevations for refraction
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AtmosphericConditions {
+    /// Air pressure in hPa
+    pub pressure: f64,
+
+    /// Air temperature in °C
+    pub temperature: f64,
+}
+
+impl AtmosphericConditions {
+    /// Standard conditions (1010 hPa and 10 °C)
+    pub const STANDARD: Self = Self {
+        pressure: 1010.0,
+        temperature: 10.0,
+    };
+
+    /// Returns the refraction correction in rad, to be added to a geometric elevation
+    ///
+    /// The correction is calculated with Sæmundsson's formula scaled by the pressure and temperature,
+    /// which is accurate to about 0.1' above the horizon. Refraction is not applied to elevations below -1°,
+    /// where the formula is not valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `elevation` - The geometric (airless) elevation in rad
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let horizon = sgp4::AtmosphericConditions::STANDARD.refraction(0.0);
+    /// assert!((horizon.to_degrees() * 60.0 - 29.0).abs() < 0.1);
+    /// ```
+    pub fn refraction(&self, elevation: f64) -> f64 {
+        let elevation = elevation.to_degrees();
+        if elevation < -1.0 {
+            return 0.0;
+        }
+
+        // R = 1.02' / tan(h + 10.3° / (h + 5.11°)) P / 1010 hPa 283 K / (273 K + T)
+        (1.02 / (elevation + 10.3 / (elevation + 5.11)).to_radians().tan()
+            * (self.pressure / 1010.0)
+            * (283.0 / (273.0 + self.temperature))
+            / 60.0)
+            .to_radians()
+    }
+}
+
+impl Default for AtmosphericConditions {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl observer::Observer {
+    /// Returns the topocentric right ascension, declination and range of a predicted satellite position
+    ///
+    /// The coordinates are referred to the true equator and mean equinox of date (TEME),
+    /// which differ from apparent coordinates of date by the equation of the equinoxes (about 1″).
+    /// If `conditions` is not `None`, the elevation is corrected for atmospheric refraction
+    /// before conversion, hence the coordinates match the apparent direction of the satellite.
+    /// Light-time and aberration are neglected.
+    ///
+    /// # Arguments
+    ///
+    /// * `sidereal_time` - Greenwich sidereal time in rad at the prediction time
+    /// * `prediction` - The satellite position and velocity in the TEME reference frame
+    /// * `conditions` - The atmospheric conditions used to correct refraction, or `None` for geometric coordinates
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: 48.8566_f64.to_radians(),
+    ///     longitude: 2.3522_f64.to_radians(),
+    ///     altitude: 0.035,
+    /// };
+    /// let t = sgp4::MinutesSinceEpoch(60.0);
+    /// let prediction = constants.propagate(t)?;
+    /// let sidereal_time = sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t));
+    /// let pointing = observer.topocentric_equatorial(
+    ///     sidereal_time,
+    ///     &prediction,
+    ///     Some(&sgp4::AtmosphericConditions::STANDARD),
+    /// );
+    /// println!(
+    ///     "RA = {} h, Dec = {}°, range = {} km",
+    ///     pointing.right_ascension.to_degrees() / 15.0,
+    ///     pointing.declination.to_degrees(),
+    ///     pointing.distance,
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn topocentric_equatorial(
+        &self,
+        sidereal_time: f64,
+        prediction: &propagator::Prediction,
+        conditions: Option<&AtmosphericConditions>,
+    ) -> Equatorial {
+        // ρ = r - rₒ
+        let rho = vector::sub(&prediction.position, &self.position(sidereal_time));
+        let Some(conditions) = conditions else {
+            return Equatorial::from_cartesian(&rho);
+        };
+
+        // θₗ = θ + λ
+        let local_sidereal_time = sidereal_time + self.longitude;
+        let (latitude_sine, latitude_cosine) = self.latitude.sin_cos();
+        let (local_sidereal_time_sine, local_sidereal_time_cosine) = local_sidereal_time.sin_cos();
+
+        // local east, north and up unit vectors in TEME
+        let east = [-local_sidereal_time_sine, local_sidereal_time_cosine, 0.0];
+        let north = [
+            -latitude_sine * local_sidereal_time_cosine,
+            -latitude_sine * local_sidereal_time_sine,
+            latitude_cosine,
+        ];
+        let up = [
+            latitude_cosine * local_sidereal_time_cosine,
+            latitude_cosine * local_sidereal_time_sine,
+            latitude_sine,
+        ];
+        let rho_east = vector::dot(&rho, &east);
+        let rho_north = vector::dot(&rho, &north);
+        let horizontal = (rho_east.powi(2) + rho_north.powi(2)).sqrt();
+
+        // E = tan⁻¹(ρᵤ / (ρₑ² + ρₙ²)¹ᐟ²)
+        let elevation = vector::dot(&rho, &up).atan2(horizontal);
+
+        // E' = E + R(E)
+        let (apparent_elevation_sine, apparent_elevation_cosine) =
+            (elevation + conditions.refraction(elevation)).sin_cos();
+
+        // the azimuth and range are not changed by refraction
+        let range = vector::norm(&rho);
+        let (azimuth_sine, azimuth_cosine) = if horizontal > 0.0 {
+            (rho_east / horizontal, rho_north / horizontal)
+        } else {
+            (0.0, 1.0)
+        };
+        let apparent_rho = [0, 1, 2].map(|index| {
+            range
+                * (apparent_elevation_cosine
+                    * (azimuth_sine * east[index] + azimuth_cosine * north[index])
+                    + apparent_elevation_sine * up[index])
+        });
+        Equatorial::from_cartesian(&apparent_rho)
+    }
+}
+
+/// Rotates a TEME vector to the mean equator and equinox of J2000
+///
+/// The rotation applies the IAU 1976 precession from the epoch to J2000.
+/// Nutation (the difference between the true and mean equators) is neglected,
+/// which limits the accuracy of the resulting directions to about 20″.
+///
+/// # Arguments
+///
+/// * `epoch` - Years since UTC 1 January 2000 12h00 (J2000)
+/// * `vector` - The three components (x, y, z) of a vector in the TEME reference frame, for example a position in km
+pub fn teme_to_mean_j2000(epoch: f64, vector: &[f64; 3]) -> [f64; 3] {
+    // T = y₂₀₀₀ / 100
+    let t = epoch / 100.0;
+
+    // ζ = 2306.2181″ T + 0.30188″ T² + 0.017998″ T³
+    let zeta = (2306.2181 * t + 0.30188 * t.powi(2) + 0.017998 * t.powi(3)) * ARCSECOND;
+
+    // z = 2306.2181″ T + 1.09468″ T² + 0.018203″ T³
+    let z = (2306.2181 * t + 1.09468 * t.powi(2) + 0.018203 * t.powi(3)) * ARCSECOND;
+
+    // θ = 2004.3109″ T - 0.42665″ T² - 0.041833″ T³
+    let theta = (2004.3109 * t - 0.42665 * t.powi(2) - 0.041833 * t.powi(3)) * ARCSECOND;
+
+    // r₂₀₀₀ = R₃(ζ) R₂(-θ) R₃(z) r
+    rotate_z(&rotate_y(&rotate_z(vector, z), -theta), zeta)
+}
+
+// frame rotation about the z axis by an angle in rad
+fn rotate_z(vector: &[f64; 3], angle: f64) -> [f64; 3] {
+    let (sine, cosine) = angle.sin_cos();
+    [
+        cosine * vector[0] + sine * vector[1],
+        -sine * vector[0] + cosine * vector[1],
+        vector[2],
+    ]
+}
+
+// frame rotation about the y axis by an angle in rad
+fn rotate_y(vector: &[f64; 3], angle: f64) -> [f64; 3] {
+    let (sine, cosine) = angle.sin_cos();
+    [
+        cosine * vector[0] - sine * vector[2],
+        vector[1],
+        sine * vector[0] + cosine * vector[2],
+    ]
+}
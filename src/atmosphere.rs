@@ -0,0 +1,182 @@
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// exponential atmosphere layers (base altitude in km, base density in kg.m⁻³, scale height in km)
+// from Vallado, Fundamentals of Astrodynamics and Applications, table 8-4
+const LAYERS: [(f64, f64, f64); 28] = [
+    (0.0, 1.225, 7.249),
+    (25.0, 3.899e-2, 6.349),
+    (30.0, 1.774e-2, 6.682),
+    (40.0, 3.972e-3, 7.554),
+    (50.0, 1.057e-3, 8.382),
+    (60.0, 3.206e-4, 7.714),
+    (70.0, 8.770e-5, 6.549),
+    (80.0, 1.905e-5, 5.799),
+    (90.0, 3.396e-6, 5.382),
+    (100.0, 5.297e-7, 5.877),
+    (110.0, 9.661e-8, 7.263),
+    (120.0, 2.438e-8, 9.473),
+    (130.0, 8.484e-9, 12.636),
+    (140.0, 3.845e-9, 16.149),
+    (150.0, 2.070e-9, 22.523),
+    (180.0, 5.464e-10, 29.740),
+    (200.0, 2.789e-10, 37.105),
+    (250.0, 7.248e-11, 45.546),
+    (300.0, 2.418e-11, 53.628),
+    (350.0, 9.518e-12, 53.298),
+    (400.0, 3.725e-12, 58.515),
+    (450.0, 1.585e-12, 60.828),
+    (500.0, 6.967e-13, 63.822),
+    (600.0, 1.454e-13, 71.835),
+    (700.0, 3.614e-14, 88.667),
+    (800.0, 1.170e-14, 124.64),
+    (900.0, 5.245e-15, 181.05),
+    (1000.0, 3.019e-15, 268.00),
+];
+
+/// Solar and geomagnetic activity indices that drive the thermosphere's density
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolarActivity {
+    /// Daily 10.7 cm solar radio flux in solar flux units (10⁻²² W.m⁻².Hz⁻¹)
+    pub f107: f64,
+
+    /// 81-day centred average of the 10.7 cm solar radio flux in solar flux units
+    pub f107_average: f64,
+
+    /// Daily planetary geomagnetic index
+    pub ap: f64,
+}
+
+impl Default for SolarActivity {
+    /// Returns a moderate solar activity (F10.7 = 150 and Ap = 15)
+    fn default() -> Self {
+        SolarActivity {
+            f107: 150.0,
+            f107_average: 150.0,
+            ap: 15.0,
+        }
+    }
+}
+
+// T = 900 + 2.5 (F̄₁₀.₇ - 70) + 1.5 Aₚ (exospheric temperature in K)
+// ρ = 6 × 10⁻¹⁰ exp(-(h - 175) / H) with H = T / (27 - 0.012 (h - 200))
+fn thermosphere_density(altitude: f64, solar_activity: &SolarActivity) -> f64 {
+    let temperature = 900.0 + 2.5 * (solar_activity.f107_average - 70.0) + 1.5 * solar_activity.ap;
+    let scale_height = temperature / (27.0 - 0.012 * (altitude - 200.0));
+    6e-10 * (-(altitude - 175.0) / scale_height).exp()
+}
+
+/// Returns the atmospheric density in kg.m⁻³
+///
+/// The density is interpolated from the exponential model in Vallado's Fundamentals of Astrodynamics and Applications (table 8-4),
+/// which corresponds to a moderate solar activity. Above 180 km, the density is scaled with the solar activity
+/// by the ratio of the Australian Space Weather Agency thermosphere model at the given and default activities.
+/// The model is clamped to 500 km, its upper validity bound, for this ratio.
+///
+/// # Arguments
+///
+/// * `altitude` - The altitude above the ellipsoid in km
+/// * `solar_activity` - The solar and geomagnetic indices
+///
+/// # Example
+///
+/// ```
+/// let quiet = sgp4::exponential_density(
+///     400.0,
+///     &sgp4::SolarActivity {
+///         f107: 70.0,
+///         f107_average: 70.0,
+///         ap: 4.0,
+///     },
+/// );
+/// let active = sgp4::exponential_density(
+///     400.0,
+///     &sgp4::SolarActivity {
+///         f107: 250.0,
+///         f107_average: 250.0,
+///         ap: 30.0,
+///     },
+/// );
+/// assert!(active > quiet * 5.0);
+/// ```
+pub fn exponential_density(altitude: f64, solar_activity: &SolarActivity) -> f64 {
+    let (base_altitude, base_density, scale_height) = LAYERS
+        .iter()
+        .rev()
+        .find(|(base_altitude, _, _)| altitude >= *base_altitude)
+        .unwrap_or(&LAYERS[0]);
+
+    // ρ = ρ₀ exp(-(h - h₀) / H)
+    let density = base_density * (-(altitude - base_altitude) / scale_height).exp();
+    if altitude > 180.0 {
+        let altitude = altitude.min(500.0);
+        density * thermosphere_density(altitude, solar_activity)
+            / thermosphere_density(altitude, &SolarActivity::default())
+    } else {
+        density
+    }
+}
+
+/// Model of the atmospheric density used by the decay and lifetime estimators
+///
+/// The estimators average the drag over one revolution, hence models that depend on the position
+/// or the local time (for example NRLMSISE-00 or JB2008, implemented by external crates)
+/// should return a density averaged over latitude and local time. Additional inputs,
+/// such as the JB2008 solar indices, can be stored in the implementing type.
+///
+/// The trait is implemented for functions and closures with the signature of
+/// [exponential_density](fn.exponential_density.html).
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// // a model with twice the default density
+/// struct Doubled;
+///
+/// impl sgp4::DensityModel for Doubled {
+///     fn density(&self, altitude: f64, solar_activity: &sgp4::SolarActivity) -> f64 {
+///         2.0 * sgp4::exponential_density(altitude, solar_activity)
+///     }
+/// }
+///
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let default = elements.lifetime(0.005, |_| sgp4::SolarActivity::default(), sgp4::ExponentialDensity, 25.0);
+/// let doubled = elements.lifetime(0.005, |_| sgp4::SolarActivity::default(), Doubled, 25.0);
+/// assert!(doubled.unwrap() < default.unwrap());
+/// #     Ok(())
+/// # }
+/// ```
+pub trait DensityModel {
+    /// Returns the atmospheric density in kg.m⁻³
+    ///
+    /// # Arguments
+    ///
+    /// * `altitude` - The altitude above the ellipsoid in km
+    /// * `solar_activity` - The solar and geomagnetic indices
+    fn density(&self, altitude: f64, solar_activity: &SolarActivity) -> f64;
+}
+
+impl<F> DensityModel for F
+where
+    F: Fn(f64, &SolarActivity) -> f64,
+{
+    fn density(&self, altitude: f64, solar_activity: &SolarActivity) -> f64 {
+        self(altitude, solar_activity)
+    }
+}
+
+/// The default density model, see [exponential_density](fn.exponential_density.html)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExponentialDensity;
+
+impl DensityModel for ExponentialDensity {
+    fn density(&self, altitude: f64, solar_activity: &SolarActivity) -> f64 {
+        exponential_density(altitude, solar_activity)
+    }
+}
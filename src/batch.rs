@@ -0,0 +1,107 @@
+use crate::propagator;
+use crate::tle;
+use crate::ElementsError;
+
+// smallest number of elements per thread, below this the thread start-up cost dominates
+#[cfg(feature = "std")]
+const MINIMUM_CHUNK_SIZE: usize = 256;
+
+// initializes one propagator per element, in order, with one thread per available core if std is enabled
+fn batch(
+    elements: &[tle::Elements],
+    initialize: fn(&tle::Elements) -> core::result::Result<propagator::Constants, ElementsError>,
+) -> alloc::vec::Vec<core::result::Result<propagator::Constants, ElementsError>> {
+    let mut results = alloc::vec::Vec::with_capacity(elements.len());
+    #[cfg(feature = "std")]
+    {
+        let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+        let chunk_size = elements.len().div_ceil(threads).max(MINIMUM_CHUNK_SIZE);
+        if chunk_size < elements.len() {
+            let (first, rest) = elements.split_at(chunk_size);
+            std::thread::scope(|scope| {
+                let handles = rest
+                    .chunks(chunk_size)
+                    .map(|chunk| {
+                        scope.spawn(move || {
+                            chunk.iter().map(initialize).collect::<alloc::vec::Vec<_>>()
+                        })
+                    })
+                    .collect::<alloc::vec::Vec<_>>();
+
+                // the current thread initializes the first chunk while the others run
+                results.extend(first.iter().map(initialize));
+                for handle in handles {
+                    match handle.join() {
+                        Ok(chunk) => results.extend(chunk),
+                        Err(payload) => std::panic::resume_unwind(payload),
+                    }
+                }
+            });
+            return results;
+        }
+    }
+    results.extend(elements.iter().map(initialize));
+    results
+}
+
+impl propagator::Constants {
+    /// Initializes one propagator per `Elements` object
+    ///
+    /// The results are in the same order as the elements, and each one is identical to
+    /// the result of `Constants::from_elements`. Each propagator is initialized independently
+    /// (there is no setup work shared between elements), hence this function only parallelizes:
+    /// if the `std` feature is enabled, large batches (several hundred elements or more)
+    /// are split across the available cores, which shortens catalog-wide start-up times.
+    /// Otherwise it is equivalent to calling `Constants::from_elements` on each element.
+    /// A panic in a worker thread is propagated to the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Orbital elements and drag terms parsed from TLEs or OMMs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = [
+    ///     sgp4::Elements::from_tle(
+    ///         Some("ISS (ZARYA)".to_owned()),
+    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    ///     )?,
+    ///     sgp4::Elements::from_tle(
+    ///         Some("MOLNIYA 1-36".to_owned()),
+    ///         "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+    ///         "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    ///     )?,
+    /// ];
+    /// let catalog = sgp4::Constants::from_elements_batch(&elements)
+    ///     .into_iter()
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(catalog.len(), 2);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_elements_batch(
+        elements: &[tle::Elements],
+    ) -> alloc::vec::Vec<core::result::Result<Self, ElementsError>> {
+        batch(elements, propagator::Constants::from_elements)
+    }
+
+    /// Initializes one propagator per `Elements` object in AFSPC compatibility mode
+    ///
+    /// This is the batch version of `Constants::from_elements_afspc_compatibility_mode`,
+    /// see `Constants::from_elements_batch` for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Orbital elements and drag terms parsed from TLEs or OMMs
+    pub fn from_elements_afspc_compatibility_mode_batch(
+        elements: &[tle::Elements],
+    ) -> alloc::vec::Vec<core::result::Result<Self, ElementsError>> {
+        batch(
+            elements,
+            propagator::Constants::from_elements_afspc_compatibility_mode,
+        )
+    }
+}
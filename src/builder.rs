@@ -0,0 +1,373 @@
+use crate::deep_space;
+use crate::gp;
+use crate::model;
+use crate::propagator;
+use crate::third_body;
+use crate::tle;
+use crate::Degrees;
+use crate::ElementsError;
+
+/// Convention used to convert the elements' UTC epoch to years since J2000
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EpochConvention {
+    /// The recommended expression, see [Elements::epoch](struct.Elements.html#method.epoch)
+    #[default]
+    Utc,
+
+    /// The AFSPC expression, see [Elements::epoch_afspc_compatibility_mode](struct.Elements.html#method.epoch_afspc_compatibility_mode)
+    AfspcCompatibilityMode,
+}
+
+// epoch quantities, read from an Elements object or given directly
+#[derive(Clone)]
+enum Source<'a> {
+    Elements(&'a tle::Elements),
+    Orbit {
+        epoch: f64,
+        drag_term: f64,
+        orbit_0: propagator::Orbit,
+    },
+}
+
+/// Configures the initialization of a propagator
+///
+/// The builder is created with [Constants::builder](struct.Constants.html#method.builder) from an `Elements` object,
+/// or with [Constants::builder_from_orbit](struct.Constants.html#method.builder_from_orbit) from epoch quantities.
+/// Each setting can be changed individually, the others keep their default values:
+/// the WGS84 model, the IAU sidereal time expression, the UTC epoch convention,
+/// the analytic solar and lunar orbits, the resonance thresholds and the eccentricity limits
+/// of the reference implementation, and no propagation horizon.
+#[derive(Clone)]
+pub struct ConstantsBuilder<'a> {
+    source: Source<'a>,
+    geopotential: model::Geopotential,
+    epoch_to_sidereal_time: fn(f64) -> f64,
+    epoch_convention: EpochConvention,
+    third_bodies: Option<&'a dyn Fn(f64) -> third_body::ThirdBodies>,
+    resonance_boundaries: deep_space::ResonanceBoundaries,
+    eccentricity_limits: gp::EccentricityLimits,
+    horizon: Option<gp::Horizon>,
+}
+
+impl<'a> ConstantsBuilder<'a> {
+    fn new(source: Source<'a>) -> Self {
+        ConstantsBuilder {
+            source,
+            geopotential: model::WGS84,
+            epoch_to_sidereal_time: model::iau_epoch_to_sidereal_time,
+            epoch_convention: EpochConvention::Utc,
+            third_bodies: None,
+            resonance_boundaries: deep_space::ResonanceBoundaries::SGP4,
+            eccentricity_limits: gp::EccentricityLimits::SGP4,
+            horizon: None,
+        }
+    }
+
+    /// Selects the WGS72 model, the AFSPC sidereal time expression and the AFSPC epoch convention
+    ///
+    /// This is the configuration of [Constants::from_elements_afspc_compatibility_mode](struct.Constants.html#method.from_elements_afspc_compatibility_mode).
+    pub fn afspc_compatibility_mode(self) -> Self {
+        self.with_geopotential(model::WGS72)
+            .with_epoch_to_sidereal_time(model::afspc_epoch_to_sidereal_time)
+            .with_epoch_convention(EpochConvention::AfspcCompatibilityMode)
+    }
+
+    /// Changes the model of Earth gravity
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity used in the Kozai to Brouwer conversion and in propagation
+    pub fn with_geopotential(mut self, geopotential: model::Geopotential) -> Self {
+        self.geopotential = geopotential;
+        self
+    }
+
+    /// Changes the sidereal time expression
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch_to_sidereal_time` - The function to use to convert the J2000 epoch to sidereal time
+    pub fn with_epoch_to_sidereal_time(mut self, epoch_to_sidereal_time: fn(f64) -> f64) -> Self {
+        self.epoch_to_sidereal_time = epoch_to_sidereal_time;
+        self
+    }
+
+    /// Changes the expression used to convert the elements' epoch to years since J2000
+    ///
+    /// The epoch convention is ignored by builders created with
+    /// [Constants::builder_from_orbit](struct.Constants.html#method.builder_from_orbit),
+    /// whose epoch is already expressed in years since J2000.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch_convention` - The epoch convention
+    pub fn with_epoch_convention(mut self, epoch_convention: EpochConvention) -> Self {
+        self.epoch_convention = epoch_convention;
+        self
+    }
+
+    /// Replaces the analytic solar and lunar orbits of deep space propagators
+    ///
+    /// Deep space propagators (period greater than or equal to 225 min) use the solar and lunar orbits
+    /// returned by `third_bodies` instead of the analytic series of SGP4.
+    /// This is meant for research on the fidelity of the perturbation model,
+    /// for example with orbits derived from the DE440 ephemerides with
+    /// [ThirdBodyElements::from_state](struct.ThirdBodyElements.html#method.from_state).
+    /// TLEs are fitted with the analytic series, hence other orbits do not improve prediction accuracy in general.
+    ///
+    /// `third_bodies` is not called for near earth orbits.
+    ///
+    /// # Arguments
+    ///
+    /// * `third_bodies` - The function to use to calculate the solar and lunar orbits at the J2000 epoch
+    pub fn with_third_bodies(
+        mut self,
+        third_bodies: &'a dyn Fn(f64) -> third_body::ThirdBodies,
+    ) -> Self {
+        self.third_bodies = Some(third_bodies);
+        self
+    }
+
+    /// Changes the thresholds used to select the resonance branch of deep space propagators
+    ///
+    /// This is meant for research on the behavior near the regime boundaries,
+    /// or to match modified legacy implementations.
+    ///
+    /// # Arguments
+    ///
+    /// * `resonance_boundaries` - The mean motion and eccentricity thresholds of the resonance branches
+    pub fn with_resonance_boundaries(
+        mut self,
+        resonance_boundaries: deep_space::ResonanceBoundaries,
+    ) -> Self {
+        self.resonance_boundaries = resonance_boundaries;
+        self
+    }
+
+    /// Changes the eccentricity thresholds applied by `Constants::propagate`
+    ///
+    /// See [Constants::propagate_with_eccentricity_limits](struct.Constants.html#method.propagate_with_eccentricity_limits).
+    ///
+    /// # Arguments
+    ///
+    /// * `eccentricity_limits` - The propagated eccentricity thresholds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let strict = sgp4::EccentricityLimits {
+    ///     strict: true,
+    ///     ..sgp4::EccentricityLimits::SGP4
+    /// };
+    /// let constants = sgp4::Constants::builder(&elements)
+    ///     .with_eccentricity_limits(strict)
+    ///     .build()?;
+    /// assert_eq!(
+    ///     constants.propagate(sgp4::MinutesSinceEpoch(60.0))?.position,
+    ///     constants
+    ///         .propagate_with_eccentricity_limits(sgp4::MinutesSinceEpoch(60.0), &strict)?
+    ///         .position,
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_eccentricity_limits(mut self, eccentricity_limits: gp::EccentricityLimits) -> Self {
+        self.eccentricity_limits = eccentricity_limits;
+        self
+    }
+
+    /// Makes `Constants::propagate` return an `Error::BeyondHorizon` if |t| is larger than the horizon
+    ///
+    /// See [Constants::propagate_within_horizon](struct.Constants.html#method.propagate_within_horizon).
+    ///
+    /// # Arguments
+    ///
+    /// * `horizon` - The largest accepted absolute number of minutes since epoch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::builder(&elements)
+    ///     .with_horizon(sgp4::Horizon::from_days(7.0))
+    ///     .build()?;
+    /// constants.propagate(sgp4::MinutesSinceEpoch(-60.0 * 24.0))?;
+    /// assert!(matches!(
+    ///     constants.propagate(sgp4::MinutesSinceEpoch(60.0 * 24.0 * 10.0)),
+    ///     Err(sgp4::Error::BeyondHorizon { .. }),
+    /// ));
+    ///
+    /// // an explicit horizon replaces the stored one
+    /// constants.propagate_within_horizon(
+    ///     sgp4::MinutesSinceEpoch(60.0 * 24.0 * 10.0),
+    ///     &sgp4::Horizon::THIRTY_DAYS,
+    /// )?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_horizon(mut self, horizon: gp::Horizon) -> Self {
+        self.horizon = Some(horizon);
+        self
+    }
+
+    /// Initializes the propagator
+    pub fn build(&self) -> core::result::Result<propagator::Constants, ElementsError> {
+        let (epoch, drag_term, orbit_0) = match &self.source {
+            Source::Elements(elements) => (
+                match self.epoch_convention {
+                    EpochConvention::Utc => elements.epoch(),
+                    EpochConvention::AfspcCompatibilityMode => {
+                        elements.epoch_afspc_compatibility_mode()
+                    }
+                },
+                elements.drag_term,
+                propagator::Orbit::from_kozai_elements(
+                    &self.geopotential,
+                    Degrees(elements.inclination),
+                    Degrees(elements.right_ascension),
+                    elements.eccentricity,
+                    Degrees(elements.argument_of_perigee),
+                    Degrees(elements.mean_anomaly),
+                    elements.mean_motion * (core::f64::consts::PI / 720.0),
+                )?,
+            ),
+            Source::Orbit {
+                epoch,
+                drag_term,
+                orbit_0,
+            } => (*epoch, *drag_term, orbit_0.clone()),
+        };
+        let mut constants = propagator::Constants::initialize(
+            self.geopotential,
+            self.epoch_to_sidereal_time,
+            self.third_bodies,
+            &self.resonance_boundaries,
+            epoch,
+            drag_term,
+            orbit_0,
+        )?;
+        constants.eccentricity_limits = self.eccentricity_limits;
+        constants.horizon = self.horizon;
+        Ok(constants)
+    }
+}
+
+impl propagator::Constants {
+    /// Returns a builder that initializes a propagator from an `Elements` object
+    ///
+    /// The default builder is equivalent to [Constants::from_elements](struct.Constants.html#method.from_elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Orbital elements and drag term parsed from a TLE or OMM
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    ///
+    /// // AFSPC geopotential and epoch, IAU sidereal time
+    /// let constants = sgp4::Constants::builder(&elements)
+    ///     .with_geopotential(sgp4::WGS72)
+    ///     .with_epoch_convention(sgp4::EpochConvention::AfspcCompatibilityMode)
+    ///     .build()?;
+    /// let prediction = constants.propagate(sgp4::MinutesSinceEpoch(60.0))?;
+    ///
+    /// let reference = sgp4::Constants::builder(&elements)
+    ///     .afspc_compatibility_mode()
+    ///     .build()?
+    ///     .propagate(sgp4::MinutesSinceEpoch(60.0))?;
+    /// for index in 0..3 {
+    ///     assert!((prediction.position[index] - reference.position[index]).abs() < 1e-9);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn builder(elements: &tle::Elements) -> ConstantsBuilder<'_> {
+        ConstantsBuilder::new(Source::Elements(elements))
+    }
+
+    /// Returns a builder that initializes a propagator from epoch quantities
+    ///
+    /// The default builder is equivalent to [Constants::new](struct.Constants.html#method.new)
+    /// with the WGS84 model and the IAU sidereal time expression.
+    /// `orbit_0` must have been calculated with the geopotential passed to
+    /// [ConstantsBuilder::with_geopotential](struct.ConstantsBuilder.html#method.with_geopotential).
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The number of years since UTC 1 January 2000 12h00 (J2000)
+    /// * `drag_term` - The radiation pressure coefficient in earth radii⁻¹ (B*)
+    /// * `orbit_0` - The Brouwer orbital elements at epoch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("MOLNIYA 1-36".to_owned()),
+    ///     "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+    ///     "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    /// )?;
+    /// let orbit_0 = sgp4::Orbit::from_kozai_elements(
+    ///     &sgp4::WGS84,
+    ///     sgp4::Degrees(elements.inclination),
+    ///     sgp4::Degrees(elements.right_ascension),
+    ///     elements.eccentricity,
+    ///     sgp4::Degrees(elements.argument_of_perigee),
+    ///     sgp4::Degrees(elements.mean_anomaly),
+    ///     elements.mean_motion * (core::f64::consts::PI / 720.0),
+    /// )?;
+    /// let third_bodies = |epoch| {
+    ///     // replace this with a high-fidelity ephemeris
+    ///     sgp4::ThirdBodies::analytic(epoch)
+    /// };
+    /// let constants = sgp4::Constants::builder_from_orbit(elements.epoch(), elements.drag_term, orbit_0.clone())
+    ///     .with_third_bodies(&third_bodies)
+    ///     .build()?;
+    /// let prediction = constants.propagate(sgp4::MinutesSinceEpoch(60.0 * 24.0))?;
+    /// let reference = sgp4::Constants::from_elements(&elements)?
+    ///     .propagate(sgp4::MinutesSinceEpoch(60.0 * 24.0))?;
+    /// for index in 0..3 {
+    ///     assert!((prediction.position[index] - reference.position[index]).abs() < 1e-3);
+    /// }
+    ///
+    /// let constants = sgp4::Constants::builder_from_orbit(elements.epoch(), elements.drag_term, orbit_0)
+    ///     .with_resonance_boundaries(sgp4::ResonanceBoundaries {
+    ///         half_day_minimum_eccentricity: 0.7,
+    ///         ..sgp4::ResonanceBoundaries::SGP4
+    ///     })
+    ///     .build()?;
+    /// assert!(constants.deep_space_coefficients().unwrap().resonance.is_none());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn builder_from_orbit<'a>(
+        epoch: f64,
+        drag_term: f64,
+        orbit_0: propagator::Orbit,
+    ) -> ConstantsBuilder<'a> {
+        ConstantsBuilder::new(Source::Orbit {
+            epoch,
+            drag_term,
+            orbit_0,
+        })
+    }
+}
@@ -0,0 +1,380 @@
+use crate::catalog;
+use crate::norad_id;
+use crate::tle;
+
+// first bytes of a cache file
+const MAGIC: &[u8; 8] = b"SGP4CAT\0";
+
+// version of the binary layout, incremented on incompatible changes
+const VERSION: u16 = 1;
+
+// length marker of a missing optional string
+const NONE_LENGTH: u16 = u16::MAX;
+
+/// Represents an error raised while reading a catalog cache
+#[derive(Debug)]
+pub enum CacheFileError {
+    /// The file could not be read or written
+    Io(std::io::Error),
+
+    /// The file does not start with the cache signature
+    NotACache,
+
+    /// The file was written by an incompatible version of the crate
+    UnsupportedVersion(u16),
+
+    /// The file is truncated or contains invalid values
+    Corrupted,
+}
+
+impl From<std::io::Error> for CacheFileError {
+    fn from(value: std::io::Error) -> Self {
+        if value.kind() == std::io::ErrorKind::UnexpectedEof {
+            CacheFileError::Corrupted
+        } else {
+            CacheFileError::Io(value)
+        }
+    }
+}
+
+impl core::fmt::Display for CacheFileError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CacheFileError::Io(error) => error.fmt(formatter),
+            CacheFileError::NotACache => formatter.write_str("the file is not a catalog cache"),
+            CacheFileError::UnsupportedVersion(version) => formatter.write_fmt(format_args!(
+                "unsupported catalog cache version {}",
+                version
+            )),
+            CacheFileError::Corrupted => formatter.write_str("the catalog cache is corrupted"),
+        }
+    }
+}
+
+impl std::error::Error for CacheFileError {}
+
+/// Origin of cached elements
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheSource {
+    /// URL or path of the downloaded or loaded data
+    pub name: String,
+
+    /// UTC time at which the data was retrieved
+    pub retrieved_at: chrono::NaiveDateTime,
+}
+
+/// Elements stored in a catalog cache
+pub struct CacheEntry {
+    /// The orbital elements
+    pub elements: tle::Elements,
+
+    /// UTC time at which the elements were parsed
+    pub parsed_at: chrono::NaiveDateTime,
+
+    /// Index of the elements' source in [CatalogCache::sources](struct.CatalogCache.html#structfield.sources)
+    pub source: usize,
+}
+
+/// Parsed elements and their provenance, stored in a compact binary file
+///
+/// The cache lets applications start without downloading or parsing text catalogs.
+/// Elements are stored with fixed-size little-endian fields and length-prefixed UTF-8 strings,
+/// hence a cache is read without text parsing and preserves full-precision values and long object names.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let mut cache = sgp4::CatalogCache::new();
+/// cache.push(
+///     sgp4::CacheSource {
+///         name: "https://celestrak.org/NORAD/elements/gp.php?GROUP=stations&FORMAT=tle".to_owned(),
+///         retrieved_at: chrono::NaiveDate::from_ymd_opt(2020, 7, 13)
+///             .unwrap()
+///             .and_hms_opt(0, 0, 0)
+///             .unwrap(),
+///     },
+///     sgp4::parse_3les(
+///         "ISS (ZARYA)
+/// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+/// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+/// ",
+///     )?,
+///     chrono::NaiveDate::from_ymd_opt(2020, 7, 13)
+///         .unwrap()
+///         .and_hms_opt(0, 0, 1)
+///         .unwrap(),
+/// );
+/// let path = std::env::temp_dir().join("sgp4-catalog-cache-example.bin");
+/// cache.save(&path)?;
+/// let cache = sgp4::CatalogCache::load(&path)?;
+/// assert_eq!(
+///     cache.sources[cache.entries[0].source].retrieved_at.to_string(),
+///     "2020-07-13 00:00:00",
+/// );
+/// let catalog = cache.into_catalog();
+/// assert!(catalog.contains(sgp4::NoradId::new(25544)?));
+/// #     std::fs::remove_file(&path)?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct CatalogCache {
+    /// The sources of the cached elements
+    pub sources: Vec<CacheSource>,
+
+    /// The cached elements, in insertion order
+    pub entries: Vec<CacheEntry>,
+}
+
+// little-endian encoding of cache fields
+trait WriteField: std::io::Write {
+    fn write_u8(&mut self, value: u8) -> std::io::Result<()> {
+        self.write_all(&[value])
+    }
+
+    fn write_u16(&mut self, value: u16) -> std::io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, value: u64) -> std::io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_f64(&mut self, value: f64) -> std::io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_datetime(&mut self, value: &chrono::NaiveDateTime) -> std::io::Result<()> {
+        let datetime = value.and_utc();
+        self.write_all(&datetime.timestamp().to_le_bytes())?;
+        self.write_all(&datetime.timestamp_subsec_nanos().to_le_bytes())
+    }
+
+    fn write_string(&mut self, value: Option<&str>) -> std::io::Result<()> {
+        match value {
+            Some(value) => {
+                let mut length = value.len().min((NONE_LENGTH - 1) as usize);
+                while !value.is_char_boundary(length) {
+                    length -= 1;
+                }
+                self.write_u16(length as u16)?;
+                self.write_all(&value.as_bytes()[..length])
+            }
+            None => self.write_u16(NONE_LENGTH),
+        }
+    }
+}
+
+impl<W: std::io::Write> WriteField for W {}
+
+// little-endian decoding of cache fields
+trait ReadField: std::io::Read {
+    fn read_bytes<const N: usize>(&mut self) -> std::io::Result<[u8; N]> {
+        let mut buffer = [0; N];
+        self.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn read_u8(&mut self) -> std::io::Result<u8> {
+        Ok(self.read_bytes::<1>()?[0])
+    }
+
+    fn read_u16(&mut self) -> std::io::Result<u16> {
+        Ok(u16::from_le_bytes(self.read_bytes()?))
+    }
+
+    fn read_u64(&mut self) -> std::io::Result<u64> {
+        Ok(u64::from_le_bytes(self.read_bytes()?))
+    }
+
+    fn read_f64(&mut self) -> std::io::Result<f64> {
+        Ok(f64::from_le_bytes(self.read_bytes()?))
+    }
+
+    fn read_datetime(&mut self) -> Result<chrono::NaiveDateTime, CacheFileError> {
+        let seconds = i64::from_le_bytes(self.read_bytes()?);
+        let nanoseconds = u32::from_le_bytes(self.read_bytes()?);
+        chrono::DateTime::from_timestamp(seconds, nanoseconds)
+            .map(|datetime| datetime.naive_utc())
+            .ok_or(CacheFileError::Corrupted)
+    }
+
+    fn read_string(&mut self) -> Result<Option<String>, CacheFileError> {
+        let length = self.read_u16()?;
+        if length == NONE_LENGTH {
+            return Ok(None);
+        }
+        let mut buffer = vec![0; length as usize];
+        self.read_exact(&mut buffer)?;
+        String::from_utf8(buffer)
+            .map(Some)
+            .map_err(|_| CacheFileError::Corrupted)
+    }
+}
+
+impl<R: std::io::Read> ReadField for R {}
+
+impl CatalogCache {
+    /// Returns an empty cache
+    pub fn new() -> Self {
+        CatalogCache::default()
+    }
+
+    /// Adds a source and its elements
+    ///
+    /// Returns the index of the source.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The origin of the elements
+    /// * `elements` - The parsed elements
+    /// * `parsed_at` - UTC time at which the elements were parsed
+    pub fn push<I: IntoIterator<Item = tle::Elements>>(
+        &mut self,
+        source: CacheSource,
+        elements: I,
+        parsed_at: chrono::NaiveDateTime,
+    ) -> usize {
+        let index = self.sources.len();
+        self.sources.push(source);
+        self.entries
+            .extend(elements.into_iter().map(|elements| CacheEntry {
+                elements,
+                parsed_at,
+                source: index,
+            }));
+        index
+    }
+
+    /// Consumes the cache and returns a catalog with the most recent elements of each satellite
+    pub fn into_catalog(self) -> catalog::Catalog {
+        let mut catalog = catalog::Catalog::new();
+        catalog.merge(self.entries.into_iter().map(|entry| entry.elements));
+        catalog
+    }
+
+    /// Encodes the cache
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The output, wrapping it in a `std::io::BufWriter` is recommended for files
+    pub fn write<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_u16(VERSION)?;
+        writer.write_u64(self.sources.len() as u64)?;
+        for source in &self.sources {
+            writer.write_string(Some(&source.name))?;
+            writer.write_datetime(&source.retrieved_at)?;
+        }
+        writer.write_u64(self.entries.len() as u64)?;
+        for entry in &self.entries {
+            let elements = &entry.elements;
+            writer.write_u64(entry.source as u64)?;
+            writer.write_datetime(&entry.parsed_at)?;
+            writer.write_string(elements.object_name.as_deref())?;
+            writer.write_string(elements.international_designator.as_deref())?;
+            writer.write_u64(elements.norad_id.get())?;
+            writer.write_u8(match elements.classification {
+                tle::Classification::Unclassified => b'U',
+                tle::Classification::Classified => b'C',
+                tle::Classification::Secret => b'S',
+            })?;
+            writer.write_datetime(&elements.datetime)?;
+            writer.write_f64(elements.mean_motion_dot)?;
+            writer.write_f64(elements.mean_motion_ddot)?;
+            writer.write_f64(elements.drag_term)?;
+            writer.write_u64(elements.element_set_number)?;
+            writer.write_f64(elements.inclination)?;
+            writer.write_f64(elements.right_ascension)?;
+            writer.write_f64(elements.eccentricity)?;
+            writer.write_f64(elements.argument_of_perigee)?;
+            writer.write_f64(elements.mean_anomaly)?;
+            writer.write_f64(elements.mean_motion)?;
+            writer.write_u64(elements.revolution_number)?;
+            writer.write_u8(elements.ephemeris_type)?;
+        }
+        writer.flush()
+    }
+
+    /// Decodes a cache
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The input, wrapping it in a `std::io::BufReader` is recommended for files
+    pub fn read<R: std::io::Read>(mut reader: R) -> Result<Self, CacheFileError> {
+        if &reader.read_bytes::<8>()? != MAGIC {
+            return Err(CacheFileError::NotACache);
+        }
+        let version = reader.read_u16()?;
+        if version != VERSION {
+            return Err(CacheFileError::UnsupportedVersion(version));
+        }
+        let sources_count = reader.read_u64()?;
+        let mut sources = Vec::new();
+        for _ in 0..sources_count {
+            sources.push(CacheSource {
+                name: reader.read_string()?.ok_or(CacheFileError::Corrupted)?,
+                retrieved_at: reader.read_datetime()?,
+            });
+        }
+        let entries_count = reader.read_u64()?;
+        let mut entries = Vec::new();
+        for _ in 0..entries_count {
+            let source = reader.read_u64()? as usize;
+            if source >= sources.len() {
+                return Err(CacheFileError::Corrupted);
+            }
+            entries.push(CacheEntry {
+                parsed_at: reader.read_datetime()?,
+                elements: tle::Elements {
+                    object_name: reader.read_string()?,
+                    international_designator: reader.read_string()?,
+                    norad_id: norad_id::NoradId::new(reader.read_u64()?)
+                        .map_err(|_| CacheFileError::Corrupted)?,
+                    classification: match reader.read_u8()? {
+                        b'U' => tle::Classification::Unclassified,
+                        b'C' => tle::Classification::Classified,
+                        b'S' => tle::Classification::Secret,
+                        _ => return Err(CacheFileError::Corrupted),
+                    },
+                    datetime: reader.read_datetime()?,
+                    mean_motion_dot: reader.read_f64()?,
+                    mean_motion_ddot: reader.read_f64()?,
+                    drag_term: reader.read_f64()?,
+                    element_set_number: reader.read_u64()?,
+                    inclination: reader.read_f64()?,
+                    right_ascension: reader.read_f64()?,
+                    eccentricity: reader.read_f64()?,
+                    argument_of_perigee: reader.read_f64()?,
+                    mean_anomaly: reader.read_f64()?,
+                    mean_motion: reader.read_f64()?,
+                    revolution_number: reader.read_u64()?,
+                    ephemeris_type: reader.read_u8()?,
+                },
+                source,
+            });
+        }
+        Ok(CatalogCache { sources, entries })
+    }
+
+    /// Writes the cache to a file, replacing it if it exists
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The cache file path
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        self.write(std::io::BufWriter::new(std::fs::File::create(path)?))
+    }
+
+    /// Reads a cache file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The cache file path
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> Result<Self, CacheFileError> {
+        CatalogCache::read(std::io::BufReader::new(
+            std::fs::File::open(path).map_err(CacheFileError::Io)?,
+        ))
+    }
+}
@@ -0,0 +1,446 @@
+use crate::norad_id;
+use crate::propagator;
+use crate::staleness;
+use crate::tle;
+
+/// An orbital element compared by [Catalog::diff](struct.Catalog.html#method.diff)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ElementsField {
+    Inclination,
+    RightAscension,
+    Eccentricity,
+    ArgumentOfPerigee,
+    MeanAnomaly,
+    MeanMotion,
+    DragTerm,
+}
+
+/// Largest element changes that are not reported by [Catalog::diff](struct.Catalog.html#method.diff)
+///
+/// The default tolerances are zero, hence any change is reported.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementsTolerances {
+    /// Inclination tolerance in deg
+    pub inclination: f64,
+
+    /// Right ascension of the ascending node tolerance in deg
+    pub right_ascension: f64,
+
+    /// Eccentricity tolerance
+    pub eccentricity: f64,
+
+    /// Argument of perigee tolerance in deg
+    pub argument_of_perigee: f64,
+
+    /// Mean anomaly tolerance in deg
+    pub mean_anomaly: f64,
+
+    /// Mean motion tolerance in day⁻¹
+    pub mean_motion: f64,
+
+    /// Drag term (B*) tolerance in earth radii⁻¹
+    pub drag_term: f64,
+}
+
+/// A satellite whose elements differ between two catalogs
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatalogUpdate {
+    /// The satellite's catalog number
+    pub norad_id: norad_id::NoradId,
+
+    /// Epoch difference (new minus old) in days
+    pub epoch_change: f64,
+
+    /// The elements whose change exceeds the tolerances
+    pub changed: alloc::vec::Vec<ElementsField>,
+}
+
+/// Differences between an old and a new catalog
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatalogDiff {
+    /// NORAD IDs of the satellites that are only in the new catalog
+    pub added: alloc::vec::Vec<norad_id::NoradId>,
+
+    /// NORAD IDs of the satellites that are only in the old catalog
+    pub removed: alloc::vec::Vec<norad_id::NoradId>,
+
+    /// Satellites whose epoch changed or whose elements changed beyond the tolerances
+    pub updated: alloc::vec::Vec<CatalogUpdate>,
+}
+
+// absolute difference between two angles in deg, in the range [0, 180]
+fn angle_difference(a: f64, b: f64) -> f64 {
+    let difference = (a - b).abs() % 360.0;
+    difference.min(360.0 - difference)
+}
+
+// elements that changed beyond the tolerances
+fn changed_fields(
+    old: &tle::Elements,
+    new: &tle::Elements,
+    tolerances: &ElementsTolerances,
+) -> alloc::vec::Vec<ElementsField> {
+    [
+        (
+            ElementsField::Inclination,
+            angle_difference(old.inclination, new.inclination),
+            tolerances.inclination,
+        ),
+        (
+            ElementsField::RightAscension,
+            angle_difference(old.right_ascension, new.right_ascension),
+            tolerances.right_ascension,
+        ),
+        (
+            ElementsField::Eccentricity,
+            (old.eccentricity - new.eccentricity).abs(),
+            tolerances.eccentricity,
+        ),
+        (
+            ElementsField::ArgumentOfPerigee,
+            angle_difference(old.argument_of_perigee, new.argument_of_perigee),
+            tolerances.argument_of_perigee,
+        ),
+        (
+            ElementsField::MeanAnomaly,
+            angle_difference(old.mean_anomaly, new.mean_anomaly),
+            tolerances.mean_anomaly,
+        ),
+        (
+            ElementsField::MeanMotion,
+            (old.mean_motion - new.mean_motion).abs(),
+            tolerances.mean_motion,
+        ),
+        (
+            ElementsField::DragTerm,
+            (old.drag_term - new.drag_term).abs(),
+            tolerances.drag_term,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, change, tolerance)| change > tolerance)
+    .map(|(field, _, _)| field)
+    .collect()
+}
+
+// elements and their propagator constants, built on first use
+struct Entry {
+    elements: tle::Elements,
+    constants:
+        core::cell::OnceCell<core::result::Result<propagator::Constants, crate::ElementsError>>,
+}
+
+impl Entry {
+    fn new(elements: tle::Elements) -> Self {
+        Entry {
+            elements,
+            constants: core::cell::OnceCell::new(),
+        }
+    }
+}
+
+/// A collection of orbital elements keyed by NORAD ID
+///
+/// The catalog stores at most one set of elements per satellite. Propagator constants are built
+/// the first time they are requested and are discarded when the elements are replaced.
+/// Satellites are iterated in increasing NORAD ID order.
+#[derive(Default)]
+pub struct Catalog {
+    entries: alloc::collections::BTreeMap<norad_id::NoradId, Entry>,
+}
+
+impl Catalog {
+    /// Returns an empty catalog
+    pub fn new() -> Self {
+        Catalog::default()
+    }
+
+    /// Returns the number of satellites in the catalog
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the catalog contains no satellites
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns true if the catalog contains elements for the given satellite
+    ///
+    /// # Arguments
+    ///
+    /// * `norad_id` - The satellite's catalog number
+    pub fn contains(&self, norad_id: norad_id::NoradId) -> bool {
+        self.entries.contains_key(&norad_id)
+    }
+
+    /// Adds elements to the catalog and returns the elements they replace, if any
+    ///
+    /// The elements are inserted regardless of their epoch, see [Catalog::update](struct.Catalog.html#method.update)
+    /// to keep the most recent elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The satellite's orbital elements
+    pub fn insert(&mut self, elements: tle::Elements) -> Option<tle::Elements> {
+        self.entries
+            .insert(elements.norad_id, Entry::new(elements))
+            .map(|entry| entry.elements)
+    }
+
+    /// Adds elements to the catalog if they are more recent than the stored elements
+    ///
+    /// Returns true if the elements were inserted.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The satellite's orbital elements
+    pub fn update(&mut self, elements: tle::Elements) -> bool {
+        match self.entries.get(&elements.norad_id) {
+            Some(entry) if entry.elements.datetime >= elements.datetime => false,
+            _ => {
+                self.insert(elements);
+                true
+            }
+        }
+    }
+
+    /// Updates the catalog with a new download and returns the number of inserted elements
+    ///
+    /// Each set of elements is passed to [Catalog::update](struct.Catalog.html#method.update),
+    /// hence older elements in the download do not replace more recent elements in the catalog.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The downloaded orbital elements
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut catalog: sgp4::Catalog = sgp4::parse_3les(
+    ///     "ISS (ZARYA)
+    /// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+    /// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+    /// ",
+    /// )?
+    /// .into_iter()
+    /// .collect();
+    /// let inserted = catalog.merge(sgp4::parse_3les(
+    ///     "MOLNIYA 1-36
+    /// 1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+    /// 2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656
+    /// ",
+    /// )?);
+    /// assert_eq!(inserted, 1);
+    /// for elements in catalog.iter() {
+    ///     println!("{}: {}", elements.norad_id, elements.object_name.as_ref().unwrap());
+    /// }
+    /// let prediction = catalog
+    ///     .constants(sgp4::NoradId::new(25544)?)
+    ///     .expect("the ISS is in the catalog")?
+    ///     .propagate(sgp4::MinutesSinceEpoch(60.0))?;
+    /// println!("r = {:?} km", prediction.position);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn merge<I: IntoIterator<Item = tle::Elements>>(&mut self, elements: I) -> usize {
+        elements
+            .into_iter()
+            .map(|elements| self.update(elements))
+            .filter(|inserted| *inserted)
+            .count()
+    }
+
+    /// Removes a satellite from the catalog and returns its elements
+    ///
+    /// # Arguments
+    ///
+    /// * `norad_id` - The satellite's catalog number
+    pub fn remove(&mut self, norad_id: norad_id::NoradId) -> Option<tle::Elements> {
+        self.entries.remove(&norad_id).map(|entry| entry.elements)
+    }
+
+    /// Returns the elements of a satellite
+    ///
+    /// # Arguments
+    ///
+    /// * `norad_id` - The satellite's catalog number
+    pub fn get(&self, norad_id: norad_id::NoradId) -> Option<&tle::Elements> {
+        self.entries.get(&norad_id).map(|entry| &entry.elements)
+    }
+
+    /// Returns the propagator constants of a satellite
+    ///
+    /// The constants are built with [Constants::from_elements](struct.Constants.html#method.from_elements)
+    /// on the first call and cached. Returns `None` if the satellite is not in the catalog.
+    ///
+    /// # Arguments
+    ///
+    /// * `norad_id` - The satellite's catalog number
+    pub fn constants(
+        &self,
+        norad_id: norad_id::NoradId,
+    ) -> Option<core::result::Result<&propagator::Constants, crate::ElementsError>> {
+        self.entries.get(&norad_id).map(|entry| {
+            entry
+                .constants
+                .get_or_init(|| propagator::Constants::from_elements(&entry.elements))
+                .as_ref()
+                .map_err(Clone::clone)
+        })
+    }
+
+    /// Compares the catalog with a newer catalog
+    ///
+    /// Elements are compared field by field, without propagation. Angles are compared modulo 360°.
+    /// Since the mean anomaly changes quickly, its tolerance is only meaningful for elements
+    /// with the same epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `new` - The newer catalog
+    /// * `tolerances` - The largest element changes that are not reported
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let tles = "ISS (ZARYA)
+    /// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+    /// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+    /// ";
+    /// let old: sgp4::Catalog = sgp4::parse_3les(tles)?.into_iter().collect();
+    /// let mut new: sgp4::Catalog = sgp4::parse_3les(tles)?.into_iter().collect();
+    /// new.merge(sgp4::parse_3les(
+    ///     "MOLNIYA 1-36
+    /// 1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+    /// 2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656
+    /// ",
+    /// )?);
+    /// let diff = old.diff(&new, &sgp4::ElementsTolerances::default());
+    /// assert_eq!(diff.added, vec![8195]);
+    /// assert!(diff.removed.is_empty() && diff.updated.is_empty());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, new: &Catalog, tolerances: &ElementsTolerances) -> CatalogDiff {
+        let mut diff = CatalogDiff::default();
+        for (norad_id, entry) in &self.entries {
+            match new.entries.get(norad_id) {
+                Some(new_entry) => {
+                    let changed = changed_fields(&entry.elements, &new_entry.elements, tolerances);
+                    if !changed.is_empty() || entry.elements.datetime != new_entry.elements.datetime
+                    {
+                        diff.updated.push(CatalogUpdate {
+                            norad_id: *norad_id,
+                            epoch_change: (new_entry.elements.epoch() - entry.elements.epoch())
+                                * 365.25,
+                            changed,
+                        });
+                    }
+                }
+                None => diff.removed.push(*norad_id),
+            }
+        }
+        diff.added = new
+            .norad_ids()
+            .filter(|norad_id| !self.contains(*norad_id))
+            .collect();
+        diff
+    }
+
+    /// Returns the satellites whose elements are too old at a propagation time
+    ///
+    /// Each satellite is returned with the age of its elements in days and the age classification.
+    /// Fresh satellites are omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The propagation time in years since UTC 1 January 2000 12h00 (J2000)
+    /// * `thresholds` - The warning and rejection thresholds
+    pub fn stale(
+        &self,
+        epoch: f64,
+        thresholds: &staleness::AgeThresholds,
+    ) -> alloc::vec::Vec<(norad_id::NoradId, f64, staleness::AgeStatus)> {
+        self.entries
+            .iter()
+            .map(|(norad_id, entry)| {
+                let age = entry.elements.age(epoch);
+                (*norad_id, age, thresholds.classify(age))
+            })
+            .filter(|(_, _, status)| *status != staleness::AgeStatus::Fresh)
+            .collect()
+    }
+
+    /// Removes the satellites whose elements are rejected at a propagation time and returns their elements
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The propagation time in years since UTC 1 January 2000 12h00 (J2000)
+    /// * `thresholds` - The warning and rejection thresholds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut catalog: sgp4::Catalog = sgp4::parse_3les(
+    ///     "ISS (ZARYA)
+    /// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+    /// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+    /// MOLNIYA 1-36
+    /// 1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+    /// 2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656
+    /// ",
+    /// )?
+    /// .into_iter()
+    /// .collect();
+    /// let iss = sgp4::NoradId::new(25544)?;
+    /// let now = catalog.get(iss).unwrap().epoch() + 1.0 / 365.25;
+    /// let removed = catalog.remove_rejected(now, &sgp4::AgeThresholds::default());
+    /// assert_eq!(removed.len(), 1);
+    /// assert!(catalog.contains(iss));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn remove_rejected(
+        &mut self,
+        epoch: f64,
+        thresholds: &staleness::AgeThresholds,
+    ) -> alloc::vec::Vec<tle::Elements> {
+        self.stale(epoch, thresholds)
+            .into_iter()
+            .filter(|(_, _, status)| *status == staleness::AgeStatus::Rejected)
+            .filter_map(|(norad_id, _, _)| self.remove(norad_id))
+            .collect()
+    }
+
+    /// Returns an iterator over the NORAD IDs in increasing order
+    pub fn norad_ids(&self) -> impl Iterator<Item = norad_id::NoradId> + '_ {
+        self.entries.keys().copied()
+    }
+
+    /// Returns an iterator over the elements in increasing NORAD ID order
+    pub fn iter(&self) -> impl Iterator<Item = &tle::Elements> {
+        self.entries.values().map(|entry| &entry.elements)
+    }
+}
+
+impl FromIterator<tle::Elements> for Catalog {
+    fn from_iter<I: IntoIterator<Item = tle::Elements>>(elements: I) -> Self {
+        let mut catalog = Catalog::new();
+        catalog.merge(elements);
+        catalog
+    }
+}
+
+impl Extend<tle::Elements> for Catalog {
+    fn extend<I: IntoIterator<Item = tle::Elements>>(&mut self, elements: I) {
+        self.merge(elements);
+    }
+}
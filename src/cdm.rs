@@ -0,0 +1,639 @@
+use crate::astrometry;
+use crate::conjunction;
+use crate::gp;
+use crate::norad_id;
+use crate::propagator;
+use crate::relative;
+use crate::tle;
+use crate::vector;
+use chrono::{Datelike, Timelike};
+use core::fmt::Write;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// keywords and units of the RTN covariance terms, in the order of the lower triangle
+const COVARIANCE_KEYWORDS: [(&str, &str); 21] = [
+    ("CR_R", "m**2"),
+    ("CT_R", "m**2"),
+    ("CT_T", "m**2"),
+    ("CN_R", "m**2"),
+    ("CN_T", "m**2"),
+    ("CN_N", "m**2"),
+    ("CRDOT_R", "m**2/s"),
+    ("CRDOT_T", "m**2/s"),
+    ("CRDOT_N", "m**2/s"),
+    ("CRDOT_RDOT", "m**2/s**2"),
+    ("CTDOT_R", "m**2/s"),
+    ("CTDOT_T", "m**2/s"),
+    ("CTDOT_N", "m**2/s"),
+    ("CTDOT_RDOT", "m**2/s**2"),
+    ("CTDOT_TDOT", "m**2/s**2"),
+    ("CNDOT_R", "m**2/s"),
+    ("CNDOT_T", "m**2/s"),
+    ("CNDOT_N", "m**2/s"),
+    ("CNDOT_RDOT", "m**2/s**2"),
+    ("CNDOT_TDOT", "m**2/s**2"),
+    ("CNDOT_NDOT", "m**2/s**2"),
+];
+
+/// One of the two objects described by a Conjunction Data Message
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CdmObject {
+    /// The object's catalog number
+    pub norad_id: norad_id::NoradId,
+
+    /// The name associated with the object
+    pub object_name: Option<alloc::string::String>,
+
+    /// The object's international designator
+    pub international_designator: Option<alloc::string::String>,
+
+    /// The reference frame of the position and velocity (REF_FRAME)
+    ///
+    /// CCSDS 508.0-B-1 allows EME2000, GCRF and ITRF. Objects built from SGP4 predictions use EME2000.
+    /// Objects whose frame is set to TEME (True Equator, Mean Equinox) are rotated to EME2000 when the message is written.
+    pub reference_frame: alloc::string::String,
+
+    /// The three position components (x, y, z) at TCA in km
+    pub position: [f64; 3],
+
+    /// The three velocity components (x, y, z) at TCA in km.s⁻¹
+    pub velocity: [f64; 3],
+
+    /// The lower triangle of the position and velocity covariance in the object's RTN frame
+    ///
+    /// The terms are listed row by row (CR_R, CT_R, CT_T, CN_R, …, CNDOT_NDOT)
+    /// in km², km².s⁻¹ and km².s⁻². SGP4 does not provide covariance information,
+    /// hence the covariance is `None` unless set by the caller. Messages written without covariance
+    /// use the DEFAULT covariance method and zero terms, which are read back as `None`.
+    pub covariance: Option<[f64; 21]>,
+}
+
+/// A CCSDS Conjunction Data Message (CDM)
+///
+/// The fields use the crate's units (km and km.s⁻¹). They are converted to the units required by the standard
+/// when the message is written.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cdm {
+    /// UTC timestamp of the message creation
+    pub creation_date: chrono::NaiveDateTime,
+
+    /// The organization that created the message
+    pub originator: alloc::string::String,
+
+    /// The message identifier, unique for the originator
+    pub message_id: alloc::string::String,
+
+    /// UTC time of closest approach (TCA)
+    pub tca: chrono::NaiveDateTime,
+
+    /// Distance between the objects at TCA in km
+    pub miss_distance: f64,
+
+    /// Norm of the relative velocity at TCA in km.s⁻¹
+    pub relative_speed: f64,
+
+    /// The three components (radial, transverse, normal) of the second object's position
+    /// relative to the first, in the first object's RTN frame at TCA in km
+    pub relative_position: [f64; 3],
+
+    /// The three components (radial, transverse, normal) of the second object's velocity
+    /// relative to the first, projected on the first object's RTN frame at TCA in km.s⁻¹
+    pub relative_velocity: [f64; 3],
+
+    /// The first (OBJECT1) and second (OBJECT2) objects
+    pub objects: [CdmObject; 2],
+}
+
+/// Represents a Conjunction Data Message parse error
+#[derive(Debug, Clone)]
+pub enum CdmError {
+    /// A line is neither a comment nor a KEYWORD = VALUE pair
+    MalformedLine {
+        /// Line number (starting at 1)
+        line: usize,
+    },
+
+    /// A value could not be parsed
+    BadValue {
+        /// Line number (starting at 1)
+        line: usize,
+
+        /// The keyword associated with the value
+        keyword: alloc::string::String,
+    },
+
+    /// A mandatory keyword is missing
+    MissingKeyword {
+        /// The message section ("HEADER" for the header and relative metadata, "OBJECT1" or "OBJECT2")
+        section: &'static str,
+
+        /// The missing keyword
+        keyword: &'static str,
+    },
+}
+
+impl core::fmt::Display for CdmError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CdmError::MalformedLine { line } => formatter.write_fmt(format_args!(
+                "CDM parse error: line {} is not a KEYWORD = VALUE pair",
+                line
+            )),
+            CdmError::BadValue { line, keyword } => formatter.write_fmt(format_args!(
+                "CDM parse error: parsing the value of {} failed on line {}",
+                keyword, line
+            )),
+            CdmError::MissingKeyword { section, keyword } => formatter.write_fmt(format_args!(
+                "CDM parse error: the keyword {} is missing in {}",
+                keyword, section
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CdmError {}
+
+// a KEYWORD = VALUE pair, without the unit
+struct Entry<'a> {
+    line: usize,
+    keyword: &'a str,
+    value: &'a str,
+}
+
+impl Entry<'_> {
+    fn bad_value(&self) -> CdmError {
+        CdmError::BadValue {
+            line: self.line,
+            keyword: self.keyword.into(),
+        }
+    }
+
+    fn parse<T: core::str::FromStr>(&self) -> core::result::Result<T, CdmError> {
+        self.value.parse().map_err(|_| self.bad_value())
+    }
+
+    // CCSDS ASCII time code A (calendar) or B (day of year)
+    fn datetime(&self) -> core::result::Result<chrono::NaiveDateTime, CdmError> {
+        chrono::NaiveDateTime::parse_from_str(self.value, "%Y-%m-%dT%H:%M:%S%.f")
+            .or_else(|_| chrono::NaiveDateTime::parse_from_str(self.value, "%Y-%jT%H:%M:%S%.f"))
+            .map_err(|_| self.bad_value())
+    }
+
+    // "UNKNOWN" is written in place of missing names and designators
+    fn name(&self) -> Option<alloc::string::String> {
+        if self.value.is_empty() || self.value == "UNKNOWN" {
+            None
+        } else {
+            Some(self.value.into())
+        }
+    }
+}
+
+fn find<'a, 'b>(entries: &'b [Entry<'a>], keyword: &str) -> Option<&'b Entry<'a>> {
+    entries.iter().find(|entry| entry.keyword == keyword)
+}
+
+fn require<'a, 'b>(
+    entries: &'b [Entry<'a>],
+    section: &'static str,
+    keyword: &'static str,
+) -> core::result::Result<&'b Entry<'a>, CdmError> {
+    find(entries, keyword).ok_or(CdmError::MissingKeyword { section, keyword })
+}
+
+// converts years since J2000 to a UTC datetime (leap seconds are ignored, as in julian_years_since_j2000)
+fn j2000_years_to_datetime(epoch: f64) -> chrono::NaiveDateTime {
+    chrono::NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+        + chrono::Duration::nanoseconds((epoch * (365.25 * 24.0 * 60.0 * 60.0 * 1e9)).round() as i64)
+}
+
+// writes a datetime in the CCSDS ASCII time code A format
+fn write_datetime(
+    kvn: &mut alloc::string::String,
+    keyword: &str,
+    datetime: &chrono::NaiveDateTime,
+) -> core::fmt::Result {
+    writeln!(
+        kvn,
+        "{:<36} = {:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}",
+        keyword,
+        datetime.year(),
+        datetime.month(),
+        datetime.day(),
+        datetime.hour(),
+        datetime.minute(),
+        datetime.second(),
+        datetime.nanosecond() / 1000,
+    )
+}
+
+fn write_value(
+    kvn: &mut alloc::string::String,
+    keyword: &str,
+    value: impl core::fmt::Display,
+) -> core::fmt::Result {
+    writeln!(kvn, "{:<36} = {}", keyword, value)
+}
+
+fn write_quantity(
+    kvn: &mut alloc::string::String,
+    keyword: &str,
+    value: f64,
+    unit: &str,
+) -> core::fmt::Result {
+    writeln!(kvn, "{:<36} = {:<24} [{}]", keyword, value, unit)
+}
+
+impl CdmObject {
+    /// Returns an object description with the satellite's state at TCA
+    ///
+    /// The SGP4 prediction is rotated from TEME to EME2000 with
+    /// [teme_to_mean_j2000](fn.teme_to_mean_j2000.html), which neglects nutation (about 20″).
+    /// The covariance is not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The satellite's orbital elements
+    /// * `constants` - The satellite's propagator constants, built from the elements
+    /// * `tca` - Time of closest approach in years since UTC 1 January 2000 12h00 (J2000)
+    pub fn new(
+        elements: &tle::Elements,
+        constants: &propagator::Constants,
+        tca: f64,
+    ) -> core::result::Result<Self, gp::Error> {
+        let prediction = constants.propagate(constants.minutes_since_epoch(tca))?;
+        Ok(CdmObject {
+            norad_id: elements.norad_id,
+            object_name: elements.object_name.clone(),
+            international_designator: elements.international_designator.clone(),
+            reference_frame: "EME2000".into(),
+            position: astrometry::teme_to_mean_j2000(tca, &prediction.position),
+            velocity: astrometry::teme_to_mean_j2000(tca, &prediction.velocity),
+            covariance: None,
+        })
+    }
+
+    fn from_entries(
+        entries: &[Entry],
+        section: &'static str,
+    ) -> core::result::Result<Self, CdmError> {
+        let mut covariance = None;
+        for (index, (keyword, _)) in COVARIANCE_KEYWORDS.iter().enumerate() {
+            if let Some(entry) = find(entries, keyword) {
+                // m² → km²
+                covariance.get_or_insert([0.0; 21])[index] = entry.parse::<f64>()? * 1e-6;
+            }
+        }
+
+        // zero terms with the DEFAULT method are written in place of a missing covariance
+        if find(entries, "COVARIANCE_METHOD").is_some_and(|entry| entry.value == "DEFAULT")
+            && covariance.is_some_and(|covariance| covariance.iter().all(|term| *term == 0.0))
+        {
+            covariance = None;
+        }
+        Ok(CdmObject {
+            norad_id: require(entries, section, "OBJECT_DESIGNATOR")?.parse()?,
+            object_name: find(entries, "OBJECT_NAME").and_then(Entry::name),
+            international_designator: find(entries, "INTERNATIONAL_DESIGNATOR")
+                .and_then(Entry::name),
+            reference_frame: require(entries, section, "REF_FRAME")?.value.into(),
+            position: [
+                require(entries, section, "X")?.parse()?,
+                require(entries, section, "Y")?.parse()?,
+                require(entries, section, "Z")?.parse()?,
+            ],
+            velocity: [
+                require(entries, section, "X_DOT")?.parse()?,
+                require(entries, section, "Y_DOT")?.parse()?,
+                require(entries, section, "Z_DOT")?.parse()?,
+            ],
+            covariance,
+        })
+    }
+}
+
+impl Cdm {
+    /// Returns a Conjunction Data Message describing a close approach
+    ///
+    /// The relative position and velocity are calculated from the objects' states.
+    ///
+    /// # Arguments
+    ///
+    /// * `creation_date` - UTC timestamp of the message creation
+    /// * `originator` - The organization that creates the message
+    /// * `message_id` - The message identifier
+    /// * `tca` - Time of closest approach in years since UTC 1 January 2000 12h00 (J2000)
+    /// * `objects` - The first and second objects, with their states at TCA
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let tle = (
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// );
+    /// let elements = sgp4::Elements::from_tle(Some("ISS (ZARYA)".to_owned()), tle.0, tle.1)?;
+    /// let iss = sgp4::Constants::from_elements(&elements)?;
+    /// // a virtual satellite on a crossing orbit
+    /// let mut other_elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+    /// other_elements.right_ascension += 10.0;
+    /// other_elements.mean_anomaly += 0.5;
+    /// let other = sgp4::Constants::from_elements(&other_elements)?;
+    /// for (index, conjunction) in sgp4::conjunctions(
+    ///     &iss,
+    ///     &other,
+    ///     elements.epoch(),
+    ///     elements.epoch() + 1.0 / 365.25,
+    ///     1000.0,
+    /// )?
+    /// .iter()
+    /// .enumerate()
+    /// {
+    ///     let cdm = sgp4::Cdm::new(
+    ///         elements.datetime,
+    ///         "EXAMPLE",
+    ///         &format!("EXAMPLE-{}", index),
+    ///         conjunction.tca,
+    ///         [
+    ///             sgp4::CdmObject::new(&elements, &iss, conjunction.tca)?,
+    ///             sgp4::CdmObject::new(&other_elements, &other, conjunction.tca)?,
+    ///         ],
+    ///     );
+    ///     println!("{}", cdm.to_kvn());
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        creation_date: chrono::NaiveDateTime,
+        originator: &str,
+        message_id: &str,
+        tca: f64,
+        objects: [CdmObject; 2],
+    ) -> Self {
+        let [radial, transverse, normal] = relative::ric_axes(&propagator::Prediction {
+            position: objects[0].position,
+            velocity: objects[0].velocity,
+        });
+        let position = vector::sub(&objects[1].position, &objects[0].position);
+        let velocity = vector::sub(&objects[1].velocity, &objects[0].velocity);
+        Cdm {
+            creation_date,
+            originator: originator.into(),
+            message_id: message_id.into(),
+            tca: j2000_years_to_datetime(tca),
+            miss_distance: vector::norm(&position),
+            relative_speed: vector::norm(&velocity),
+            relative_position: [
+                vector::dot(&position, &radial),
+                vector::dot(&position, &transverse),
+                vector::dot(&position, &normal),
+            ],
+            relative_velocity: [
+                vector::dot(&velocity, &radial),
+                vector::dot(&velocity, &transverse),
+                vector::dot(&velocity, &normal),
+            ],
+            objects,
+        }
+    }
+
+    /// Parses a message in the Keyword = Value Notation (KVN) format
+    ///
+    /// Values are converted to the crate's units (km and km.s⁻¹). Comments, unknown keywords and units are ignored.
+    /// Optional relative quantities that are missing from the message are calculated from the objects' states.
+    ///
+    /// # Arguments
+    ///
+    /// * `kvn` - The message
+    pub fn from_kvn(kvn: &str) -> core::result::Result<Self, CdmError> {
+        // entries of the header and relative metadata, OBJECT1 and OBJECT2
+        let mut sections: [alloc::vec::Vec<Entry>; 3] = Default::default();
+        let mut section = 0;
+        for (index, line) in kvn.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("COMMENT") {
+                continue;
+            }
+            let (keyword, value) = line
+                .split_once('=')
+                .ok_or(CdmError::MalformedLine { line: index + 1 })?;
+
+            // units are enclosed in square brackets after the value
+            let entry = Entry {
+                line: index + 1,
+                keyword: keyword.trim(),
+                value: value.split('[').next().unwrap_or_default().trim(),
+            };
+            if entry.keyword == "OBJECT" {
+                section = match entry.value {
+                    "OBJECT1" => 1,
+                    "OBJECT2" => 2,
+                    _ => return Err(entry.bad_value()),
+                };
+            }
+            sections[section].push(entry);
+        }
+        let header = &sections[0];
+        let mut cdm = Cdm::new(
+            require(header, "HEADER", "CREATION_DATE")?.datetime()?,
+            require(header, "HEADER", "ORIGINATOR")?.value,
+            require(header, "HEADER", "MESSAGE_ID")?.value,
+            0.0,
+            [
+                CdmObject::from_entries(&sections[1], "OBJECT1")?,
+                CdmObject::from_entries(&sections[2], "OBJECT2")?,
+            ],
+        );
+        cdm.tca = require(header, "HEADER", "TCA")?.datetime()?;
+
+        // m → km
+        cdm.miss_distance = require(header, "HEADER", "MISS_DISTANCE")?.parse::<f64>()? * 1e-3;
+        if let Some(entry) = find(header, "RELATIVE_SPEED") {
+            cdm.relative_speed = entry.parse::<f64>()? * 1e-3;
+        }
+        for (suffix, (position, velocity)) in ["R", "T", "N"].iter().zip(
+            cdm.relative_position
+                .iter_mut()
+                .zip(cdm.relative_velocity.iter_mut()),
+        ) {
+            if let Some(entry) = find(header, &alloc::format!("RELATIVE_POSITION_{}", suffix)) {
+                *position = entry.parse::<f64>()? * 1e-3;
+            }
+            if let Some(entry) = find(header, &alloc::format!("RELATIVE_VELOCITY_{}", suffix)) {
+                *velocity = entry.parse::<f64>()? * 1e-3;
+            }
+        }
+        Ok(cdm)
+    }
+
+    /// Finds the closest approach near the message's TCA with SGP4
+    ///
+    /// The result can be compared with the message's TCA and miss distance to verify a received conjunction.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary` - The first object's (OBJECT1) propagator constants
+    /// * `secondary` - The second object's (OBJECT2) propagator constants
+    /// * `window` - The half-width of the search window around the message's TCA in minutes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let tle = (
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// );
+    /// let elements = sgp4::Elements::from_tle(Some("ISS (ZARYA)".to_owned()), tle.0, tle.1)?;
+    /// let iss = sgp4::Constants::from_elements(&elements)?;
+    /// // a virtual satellite on a crossing orbit
+    /// let mut other_elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+    /// other_elements.right_ascension += 10.0;
+    /// let other = sgp4::Constants::from_elements(&other_elements)?;
+    /// let conjunction = sgp4::closest_approach(
+    ///     &iss,
+    ///     &other,
+    ///     elements.epoch(),
+    ///     elements.epoch() + 60.0 / (365.25 * 24.0 * 60.0),
+    ///     1e-6,
+    /// )?;
+    /// let kvn = sgp4::Cdm::new(
+    ///     elements.datetime,
+    ///     "EXAMPLE",
+    ///     "EXAMPLE-1",
+    ///     conjunction.tca,
+    ///     [
+    ///         sgp4::CdmObject::new(&elements, &iss, conjunction.tca)?,
+    ///         sgp4::CdmObject::new(&other_elements, &other, conjunction.tca)?,
+    ///     ],
+    /// )
+    /// .to_kvn();
+    ///
+    /// // received message
+    /// let cdm = sgp4::Cdm::from_kvn(&kvn)?;
+    /// let verification = cdm.closest_approach(&iss, &other, 10.0)?;
+    /// println!(
+    ///     "miss distance: {} km (message), {} km (SGP4)",
+    ///     cdm.miss_distance, verification.miss_distance,
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn closest_approach(
+        &self,
+        primary: &propagator::Constants,
+        secondary: &propagator::Constants,
+        window: f64,
+    ) -> core::result::Result<conjunction::Conjunction, gp::Error> {
+        let tca = tle::julian_years_since_j2000(&self.tca);
+
+        // Δy = w / (365.25 × 24 × 60)
+        let window = window / (365.25 * 24.0 * 60.0);
+        conjunction::closest_approach(primary, secondary, tca - window, tca + window, 1e-6)
+    }
+
+    /// Returns the message in the Keyword = Value Notation (KVN) format
+    ///
+    /// Distances and speeds are written in the units required by the CCSDS 508.0-B-1 standard.
+    /// The standard does not allow TEME, hence TEME states are rotated to EME2000 at TCA
+    /// with [teme_to_mean_j2000](fn.teme_to_mean_j2000.html).
+    pub fn to_kvn(&self) -> alloc::string::String {
+        let mut kvn = alloc::string::String::new();
+        self.write_kvn(&mut kvn)
+            .expect("writing to a String does not fail");
+        kvn
+    }
+
+    fn write_kvn(&self, kvn: &mut alloc::string::String) -> core::fmt::Result {
+        write_value(kvn, "CCSDS_CDM_VERS", "1.0")?;
+        write_datetime(kvn, "CREATION_DATE", &self.creation_date)?;
+        write_value(kvn, "ORIGINATOR", &self.originator)?;
+        write_value(kvn, "MESSAGE_ID", &self.message_id)?;
+        write_datetime(kvn, "TCA", &self.tca)?;
+        write_quantity(kvn, "MISS_DISTANCE", self.miss_distance * 1e3, "m")?;
+        write_quantity(kvn, "RELATIVE_SPEED", self.relative_speed * 1e3, "m/s")?;
+        for (suffix, value) in ["R", "T", "N"].iter().zip(self.relative_position.iter()) {
+            write_quantity(
+                kvn,
+                &alloc::format!("RELATIVE_POSITION_{}", suffix),
+                value * 1e3,
+                "m",
+            )?;
+        }
+        for (suffix, value) in ["R", "T", "N"].iter().zip(self.relative_velocity.iter()) {
+            write_quantity(
+                kvn,
+                &alloc::format!("RELATIVE_VELOCITY_{}", suffix),
+                value * 1e3,
+                "m/s",
+            )?;
+        }
+        let tca = tle::julian_years_since_j2000(&self.tca);
+        for (index, object) in self.objects.iter().enumerate() {
+            let (reference_frame, position, velocity) = if object.reference_frame == "TEME" {
+                (
+                    "EME2000",
+                    astrometry::teme_to_mean_j2000(tca, &object.position),
+                    astrometry::teme_to_mean_j2000(tca, &object.velocity),
+                )
+            } else {
+                (
+                    object.reference_frame.as_str(),
+                    object.position,
+                    object.velocity,
+                )
+            };
+            write_value(kvn, "OBJECT", alloc::format!("OBJECT{}", index + 1))?;
+            write_value(kvn, "OBJECT_DESIGNATOR", object.norad_id)?;
+            write_value(kvn, "CATALOG_NAME", "SATCAT")?;
+            write_value(
+                kvn,
+                "OBJECT_NAME",
+                object.object_name.as_deref().unwrap_or("UNKNOWN"),
+            )?;
+            write_value(
+                kvn,
+                "INTERNATIONAL_DESIGNATOR",
+                object
+                    .international_designator
+                    .as_deref()
+                    .unwrap_or("UNKNOWN"),
+            )?;
+            write_value(kvn, "EPHEMERIS_NAME", "NONE")?;
+            write_value(
+                kvn,
+                "COVARIANCE_METHOD",
+                if object.covariance.is_some() {
+                    "CALCULATED"
+                } else {
+                    "DEFAULT"
+                },
+            )?;
+            write_value(kvn, "MANEUVERABLE", "N/A")?;
+            write_value(kvn, "REF_FRAME", reference_frame)?;
+            for (keyword, value) in ["X", "Y", "Z"].iter().zip(position.iter()) {
+                write_quantity(kvn, keyword, *value, "km")?;
+            }
+            for (keyword, value) in ["X_DOT", "Y_DOT", "Z_DOT"].iter().zip(velocity.iter()) {
+                write_quantity(kvn, keyword, *value, "km/s")?;
+            }
+            for ((keyword, unit), value) in COVARIANCE_KEYWORDS
+                .iter()
+                .zip(object.covariance.unwrap_or([0.0; 21]).iter())
+            {
+                write_quantity(kvn, keyword, value * 1e6, unit)?;
+            }
+        }
+        Ok(())
+    }
+}
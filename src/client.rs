@@ -0,0 +1,336 @@
+use crate::norad_id;
+use crate::space_track;
+use crate::tle;
+
+// Celestrak GP query endpoint
+const CELESTRAK_URL: &str = "https://celestrak.org/NORAD/elements/gp.php";
+
+// Space-Track login endpoint, which also runs the query passed with the credentials
+const SPACE_TRACK_LOGIN_URL: &str = "https://www.space-track.org/ajaxauth/login";
+
+/// Status code and body of an HTTP response
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// The HTTP status code
+    pub status: u16,
+
+    /// The response headers as (name, value) pairs, names are compared case-insensitively
+    pub headers: alloc::vec::Vec<(alloc::string::String, alloc::string::String)>,
+
+    /// The response body
+    pub body: alloc::vec::Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Returns the value of the first header with the given name (case-insensitive), if any
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The header name, for example `"ETag"`
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Asynchronous HTTP transport used by [GpClient](struct.GpClient.html)
+///
+/// The trait decouples the client from HTTP libraries. It is usually implemented
+/// with a few lines of glue code around reqwest, hyper or a platform-specific API.
+/// Implementations must not treat non-2xx status codes as errors, they are checked by the client.
+pub trait HttpClient {
+    /// The transport error type
+    type Error;
+
+    /// Sends a GET request and returns the response
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The full URL, including the percent-encoded query string
+    /// * `headers` - Additional request headers as (name, value) pairs, for example `If-None-Match`
+    fn get(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> impl core::future::Future<Output = core::result::Result<HttpResponse, Self::Error>> + Send;
+
+    /// Sends a POST request with an `application/x-www-form-urlencoded` body and returns the response
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The full URL
+    /// * `form` - The form fields as (name, value) pairs, not encoded
+    fn post_form(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+    ) -> impl core::future::Future<Output = core::result::Result<HttpResponse, Self::Error>> + Send;
+}
+
+/// Selects the element sets returned by Celestrak
+#[derive(Debug, Clone, Copy)]
+pub enum CelestrakQuery<'a> {
+    /// A predefined group, for example `"stations"` or `"galileo"`
+    Group(&'a str),
+
+    /// A single satellite catalog number
+    CatalogNumber(norad_id::NoradId),
+
+    /// All the objects of a launch (for example `"1998-067"`) or a single piece (for example `"1998-067A"`)
+    InternationalDesignator(&'a str),
+
+    /// Objects whose name contains the given string
+    Name(&'a str),
+}
+
+/// Space-Track account credentials
+#[derive(Clone)]
+pub struct SpaceTrackCredentials {
+    /// The account user name (usually an email address)
+    pub identity: alloc::string::String,
+
+    /// The account password
+    pub password: alloc::string::String,
+}
+
+impl core::fmt::Debug for SpaceTrackCredentials {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter
+            .debug_struct("SpaceTrackCredentials")
+            .field("identity", &self.identity)
+            .field("password", &"***")
+            .finish()
+    }
+}
+
+/// Represents an error raised while fetching GP data
+#[derive(Debug)]
+pub enum ClientError<E> {
+    /// The HTTP transport failed
+    Http(E),
+
+    /// The server returned a non-2xx status code
+    Status(u16),
+
+    /// The response body is not a JSON array of OMM objects
+    Json(serde_json::Error),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for ClientError<E> {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ClientError::Http(error) => formatter.write_fmt(format_args!("HTTP error: {}", error)),
+            ClientError::Status(status) => {
+                formatter.write_fmt(format_args!("the server returned the status {}", status))
+            }
+            ClientError::Json(error) => {
+                formatter.write_fmt(format_args!("the response could not be parsed: {}", error))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for ClientError<E> {}
+
+// builds the URL of a Celestrak query (JSON format)
+pub(crate) fn celestrak_url(query: CelestrakQuery<'_>) -> alloc::string::String {
+    let (key, value) = match query {
+        CelestrakQuery::Group(group) => ("GROUP", space_track::percent_encode(group)),
+        CelestrakQuery::CatalogNumber(norad_id) => ("CATNR", alloc::format!("{}", norad_id)),
+        CelestrakQuery::InternationalDesignator(international_designator) => (
+            "INTDES",
+            space_track::percent_encode(international_designator),
+        ),
+        CelestrakQuery::Name(name) => ("NAME", space_track::percent_encode(name)),
+    };
+    alloc::format!("{}?{}={}&FORMAT=json", CELESTRAK_URL, key, value)
+}
+
+// parses a JSON array of OMMs, Celestrak returns a plain-text message if no objects match the query
+pub(crate) fn parse_body<E>(
+    body: &[u8],
+) -> core::result::Result<alloc::vec::Vec<tle::Elements>, ClientError<E>> {
+    if core::str::from_utf8(body).map(str::trim) == Ok("No GP data found") {
+        return Ok(alloc::vec::Vec::new());
+    }
+    serde_json::from_slice(body).map_err(ClientError::Json)
+}
+
+// checks the status code and parses the body
+pub(crate) fn parse_response<E>(
+    response: &HttpResponse,
+) -> core::result::Result<alloc::vec::Vec<tle::Elements>, ClientError<E>> {
+    if !(200..300).contains(&response.status) {
+        return Err(ClientError::Status(response.status));
+    }
+    parse_body(&response.body)
+}
+
+/// Fetches General Perturbations (GP) data from Celestrak and Space-Track
+///
+/// The client requests OMMs in JSON format and parses them into `Elements`.
+/// It is generic over the HTTP transport, hence it works with any async runtime.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// struct Reqwest(reqwest::Client);
+/// # mod reqwest {
+/// #     pub struct Client;
+/// # }
+///
+/// impl sgp4::HttpClient for Reqwest {
+///     type Error = std::convert::Infallible;
+///
+///     async fn get(
+///         &self,
+///         url: &str,
+///         headers: &[(&str, &str)],
+///     ) -> Result<sgp4::HttpResponse, Self::Error> {
+///         // let mut request = self.0.get(url);
+///         // for (name, value) in headers {
+///         //     request = request.header(*name, *value);
+///         // }
+///         // let response = request.send().await?;
+///         // Ok(sgp4::HttpResponse {
+///         //     status: response.status().as_u16(),
+///         //     headers: response
+///         //         .headers()
+///         //         .iter()
+///         //         .filter_map(|(name, value)| {
+///         //             Some((name.to_string(), value.to_str().ok()?.to_owned()))
+///         //         })
+///         //         .collect(),
+///         //     body: response.bytes().await?.to_vec(),
+///         // })
+/// #       assert!(url.ends_with("?CATNR=25544&FORMAT=json"));
+/// #       Ok(sgp4::HttpResponse {
+/// #           status: 200,
+/// #           headers: Vec::new(),
+/// #           body: br#"[{
+/// #               "OBJECT_NAME": "ISS (ZARYA)",
+/// #               "OBJECT_ID": "1998-067A",
+/// #               "EPOCH": "2020-07-12T01:19:07.402656",
+/// #               "MEAN_MOTION": 15.49560532,
+/// #               "ECCENTRICITY": 0.0001771,
+/// #               "INCLINATION": 51.6435,
+/// #               "RA_OF_ASC_NODE": 225.4004,
+/// #               "ARG_OF_PERICENTER": 44.9625,
+/// #               "MEAN_ANOMALY": 5.1087,
+/// #               "EPHEMERIS_TYPE": 0,
+/// #               "CLASSIFICATION_TYPE": "U",
+/// #               "NORAD_CAT_ID": 25544,
+/// #               "ELEMENT_SET_NO": 999,
+/// #               "REV_AT_EPOCH": 23587,
+/// #               "BSTAR": 0.0049645,
+/// #               "MEAN_MOTION_DOT": 0.00289036,
+/// #               "MEAN_MOTION_DDOT": 0
+/// #           }]"#
+/// #           .to_vec(),
+/// #       })
+///     }
+///
+///     async fn post_form(
+///         &self,
+///         url: &str,
+///         form: &[(&str, &str)],
+///     ) -> Result<sgp4::HttpResponse, Self::Error> {
+///         // let response = self.0.post(url).form(form).send().await?;
+/// #       unreachable!()
+///     }
+/// }
+///
+/// # let future = async {
+/// let client = sgp4::GpClient::new(Reqwest(reqwest::Client));
+/// let elements_vec = client
+///     .celestrak(sgp4::CelestrakQuery::CatalogNumber(sgp4::NoradId::new(25544)?))
+///     .await?;
+/// assert_eq!(elements_vec[0].norad_id, 25544);
+/// #     Ok::<(), anyhow::Error>(())
+/// # };
+/// # use std::future::Future;
+/// # assert!(matches!(
+/// #     std::pin::pin!(future)
+/// #         .as_mut()
+/// #         .poll(&mut std::task::Context::from_waker(std::task::Waker::noop())),
+/// #     std::task::Poll::Ready(Ok(()))
+/// # ));
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct GpClient<C: HttpClient> {
+    http_client: C,
+}
+
+impl<C: HttpClient> GpClient<C> {
+    /// Creates a client from an HTTP transport
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The transport used to send requests
+    pub fn new(http_client: C) -> Self {
+        GpClient { http_client }
+    }
+
+    /// Returns the HTTP transport
+    pub fn http_client(&self) -> &C {
+        &self.http_client
+    }
+
+    /// Fetches the element sets that match a Celestrak query
+    ///
+    /// An empty list is returned if no objects match the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The group, catalog number, international designator or name to retrieve
+    pub async fn celestrak(
+        &self,
+        query: CelestrakQuery<'_>,
+    ) -> core::result::Result<alloc::vec::Vec<tle::Elements>, ClientError<C::Error>> {
+        parse_response(
+            &self
+                .http_client
+                .get(&celestrak_url(query), &[])
+                .await
+                .map_err(ClientError::Http)?,
+        )
+    }
+
+    /// Runs a Space-Track query and returns the resulting element sets
+    ///
+    /// The credentials and the query are sent in a single login request, hence no session cookie is kept.
+    /// The query must select the `gp` (or `gp_history`) class and request the JSON format.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - The Space-Track account
+    /// * `query` - The query path, for example `/basicspacedata/query/class/gp/NORAD_CAT_ID/25544/format/json`
+    ///   (see [SpaceTrackQuery::path](struct.SpaceTrackQuery.html#method.path))
+    pub async fn space_track(
+        &self,
+        credentials: &SpaceTrackCredentials,
+        query: &str,
+    ) -> core::result::Result<alloc::vec::Vec<tle::Elements>, ClientError<C::Error>> {
+        let query = alloc::format!("{}{}", space_track::SPACE_TRACK_URL, query);
+        parse_response(
+            &self
+                .http_client
+                .post_form(
+                    SPACE_TRACK_LOGIN_URL,
+                    &[
+                        ("identity", credentials.identity.as_str()),
+                        ("password", credentials.password.as_str()),
+                        ("query", query.as_str()),
+                    ],
+                )
+                .await
+                .map_err(ClientError::Http)?,
+        )
+    }
+}
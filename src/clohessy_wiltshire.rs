@@ -0,0 +1,96 @@
+use crate::propagator;
+use crate::relative;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Linearized relative motion propagator (Clohessy-Wiltshire / Hill equations)
+///
+/// The propagator predicts the motion of a target satellite in the RIC frame of a reference satellite
+/// whose orbit is assumed to be circular. It neglects perturbations and non-linear terms,
+/// hence it is accurate for short horizons and small separations compared with the orbit radius.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClohessyWiltshire {
+    /// Angular velocity of the reference frame in rad.s⁻¹
+    pub mean_motion: f64,
+
+    /// Relative state at the beginning of the propagation
+    pub initial_state: relative::RicState,
+}
+
+impl ClohessyWiltshire {
+    /// Seeds a relative motion propagator from SGP4 predictions of a reference and a target satellite
+    ///
+    /// The mean motion is the instantaneous angular velocity |r × v| / |r|² of the reference satellite.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The reference satellite's position and velocity in the TEME reference frame
+    /// * `target` - The target satellite's position and velocity in the TEME reference frame at the same time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let reference = constants.propagate(sgp4::MinutesSinceEpoch(0.0))?;
+    /// // a virtual target 100 m above the ISS, with the same velocity
+    /// let mut target = reference.clone();
+    /// for (position, direction) in target.position.iter_mut().zip(reference.position.iter()) {
+    ///     *position += 0.1 * direction / 6795.0;
+    /// }
+    /// let propagator = sgp4::ClohessyWiltshire::new(&reference, &target);
+    /// for minutes in 0..10 {
+    ///     println!("{:?} km", propagator.propagate(minutes as f64).position);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new(reference: &propagator::Prediction, target: &propagator::Prediction) -> Self {
+        ClohessyWiltshire {
+            // n = |r × v| / |r|²
+            mean_motion: vector::norm(&vector::cross(&reference.position, &reference.velocity))
+                / vector::dot(&reference.position, &reference.position),
+            initial_state: relative::ric_state(reference, target),
+        }
+    }
+
+    /// Returns the target's relative state in the RIC frame after the given duration
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since the initial state (can be positive, negative or zero)
+    pub fn propagate(&self, t: f64) -> relative::RicState {
+        let n = self.mean_motion;
+        let [x, y, z] = self.initial_state.position;
+        let [vx, vy, vz] = self.initial_state.velocity;
+
+        // nt (t in s)
+        let nt = n * t * 60.0;
+        let (s, c) = nt.sin_cos();
+        relative::RicState {
+            position: [
+                // x = (4 - 3 cos nt) x₀ + sin nt / n ẋ₀ + 2 (1 - cos nt) / n ẏ₀
+                (4.0 - 3.0 * c) * x + s / n * vx + 2.0 * (1.0 - c) / n * vy,
+                // y = 6 (sin nt - nt) x₀ + y₀ - 2 (1 - cos nt) / n ẋ₀ + (4 sin nt - 3 nt) / n ẏ₀
+                6.0 * (s - nt) * x + y - 2.0 * (1.0 - c) / n * vx + (4.0 * s - 3.0 * nt) / n * vy,
+                // z = cos nt z₀ + sin nt / n ż₀
+                c * z + s / n * vz,
+            ],
+            velocity: [
+                // ẋ = 3 n sin nt x₀ + cos nt ẋ₀ + 2 sin nt ẏ₀
+                3.0 * n * s * x + c * vx + 2.0 * s * vy,
+                // ẏ = -6 n (1 - cos nt) x₀ - 2 sin nt ẋ₀ + (4 cos nt - 3) ẏ₀
+                -6.0 * n * (1.0 - c) * x - 2.0 * s * vx + (4.0 * c - 3.0) * vy,
+                // ż = -n sin nt z₀ + cos nt ż₀
+                -n * s * z + c * vz,
+            ],
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use crate::events;
+use crate::gp;
+use crate::propagator;
+use crate::relative;
+use crate::tle::MinutesSinceEpoch;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// tolerance of the coarse screening in minutes
+const COARSE_TOLERANCE: f64 = 1e-3;
+
+/// A close approach between two satellites
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Conjunction {
+    /// Time of closest approach (TCA) in years since UTC 1 January 2000 12h00 (J2000)
+    pub tca: f64,
+
+    /// Distance between the satellites at TCA in km
+    pub miss_distance: f64,
+
+    /// The three components (x, y, z) of the second satellite's velocity relative to the first at TCA,
+    /// in the TEME reference frame in km.s⁻¹
+    pub relative_velocity: [f64; 3],
+
+    /// Norm of the relative velocity at TCA in km.s⁻¹
+    pub relative_speed: f64,
+
+    /// The three components (radial, in-track, cross-track) of the second satellite's position
+    /// in the RIC frame of the first satellite at TCA in km
+    pub ric_position: [f64; 3],
+}
+
+/// A screening volume centred on the primary satellite and aligned with its RIC frame
+///
+/// The extents are half-sizes (or semi-axes) in km. Operational volumes are often much thinner
+/// in the radial direction than in the in-track and cross-track directions.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScreeningVolume {
+    /// A sphere
+    Sphere {
+        /// Radius in km
+        radius: f64,
+    },
+
+    /// An ellipsoid
+    Ellipsoid {
+        /// Semi-axis along the radial direction in km
+        radial: f64,
+
+        /// Semi-axis along the in-track direction in km
+        in_track: f64,
+
+        /// Semi-axis along the cross-track direction in km
+        cross_track: f64,
+    },
+
+    /// A rectangular box
+    Box {
+        /// Half-size along the radial direction in km
+        radial: f64,
+
+        /// Half-size along the in-track direction in km
+        in_track: f64,
+
+        /// Half-size along the cross-track direction in km
+        cross_track: f64,
+    },
+}
+
+impl ScreeningVolume {
+    /// Returns the radius of the smallest sphere that contains the volume in km
+    pub fn bounding_radius(&self) -> f64 {
+        match *self {
+            ScreeningVolume::Sphere { radius } => radius,
+            ScreeningVolume::Ellipsoid {
+                radial,
+                in_track,
+                cross_track,
+            } => radial.max(in_track).max(cross_track),
+            ScreeningVolume::Box {
+                radial,
+                in_track,
+                cross_track,
+            } => vector::norm(&[radial, in_track, cross_track]),
+        }
+    }
+
+    /// Returns true if the volume contains a position
+    ///
+    /// # Arguments
+    ///
+    /// * `ric_position` - The position in the primary's RIC frame in km
+    pub fn contains(&self, ric_position: &[f64; 3]) -> bool {
+        match *self {
+            ScreeningVolume::Sphere { radius } => vector::norm(ric_position) <= radius,
+            ScreeningVolume::Ellipsoid {
+                radial,
+                in_track,
+                cross_track,
+            } => {
+                // (x / a)² + (y / b)² + (z / c)² ≤ 1
+                (ric_position[0] / radial).powi(2)
+                    + (ric_position[1] / in_track).powi(2)
+                    + (ric_position[2] / cross_track).powi(2)
+                    <= 1.0
+            }
+            ScreeningVolume::Box {
+                radial,
+                in_track,
+                cross_track,
+            } => {
+                ric_position[0].abs() <= radial
+                    && ric_position[1].abs() <= in_track
+                    && ric_position[2].abs() <= cross_track
+            }
+        }
+    }
+}
+
+/// Finds the close approaches between two satellites in a time window
+///
+/// The range rate between the satellites is sampled 32 times per orbit of the satellite with the shortest period,
+/// and each sign change from negative to positive (local minimum of the distance) is located by bisection,
+/// then refined with [sgp4::closest_approach](fn.closest_approach.html).
+/// Approaches whose miss distance is larger than the threshold are discarded.
+///
+/// # Arguments
+///
+/// * `a` - The first satellite's propagator constants
+/// * `b` - The second satellite's propagator constants
+/// * `start` - The beginning of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `end` - The end of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `threshold` - The maximum miss distance in km
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let tle = (
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// );
+/// let elements = sgp4::Elements::from_tle(Some("ISS (ZARYA)".to_owned()), tle.0, tle.1)?;
+/// let iss = sgp4::Constants::from_elements(&elements)?;
+/// // a virtual satellite on an orbit tilted by 10°, which crosses the ISS's orbit near the nodes
+/// let mut other_elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+/// other_elements.inclination += 10.0;
+/// other_elements.mean_anomaly -= 0.01;
+/// let other = sgp4::Constants::from_elements(&other_elements)?;
+/// let conjunctions = sgp4::conjunctions(
+///     &iss,
+///     &other,
+///     elements.epoch(),
+///     elements.epoch() + 1.0 / 365.25,
+///     50.0,
+/// )?;
+/// for conjunction in conjunctions.iter() {
+///     println!(
+///         "TCA = {} y, miss distance = {} km",
+///         conjunction.tca, conjunction.miss_distance
+///     );
+/// }
+///
+/// // the first approach occurs 44 minutes after epoch
+/// assert_eq!(conjunctions.len(), 5);
+/// assert!((iss.minutes_since_epoch(conjunctions[0].tca).0 - 43.977).abs() < 1e-3);
+/// assert!((conjunctions[0].miss_distance - 8.978).abs() < 1e-3);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn conjunctions(
+    a: &propagator::Constants,
+    b: &propagator::Constants,
+    start: f64,
+    end: f64,
+    threshold: f64,
+) -> core::result::Result<alloc::vec::Vec<Conjunction>, gp::Error> {
+    let mut conjunctions = alloc::vec::Vec::new();
+    for crossing in events::find_crossings(
+        a.minutes_since_epoch(start),
+        a.minutes_since_epoch(end),
+        a.period().min(b.period()) / 32.0,
+        COARSE_TOLERANCE,
+        |t| {
+            Ok(relative::relative_state(
+                &a.propagate(t)?,
+                &b.propagate(b.minutes_since_epoch(a.julian_years_since_j2000(t)))?,
+            )
+            .range_rate)
+        },
+    )? {
+        if crossing.direction == events::Direction::Increasing {
+            let conjunction = closest_approach(
+                a,
+                b,
+                a.julian_years_since_j2000(MinutesSinceEpoch(crossing.t.0 - COARSE_TOLERANCE))
+                    .max(start),
+                a.julian_years_since_j2000(MinutesSinceEpoch(crossing.t.0 + COARSE_TOLERANCE))
+                    .min(end),
+                1e-6,
+            )?;
+            if conjunction.miss_distance <= threshold {
+                conjunctions.push(conjunction);
+            }
+        }
+    }
+    Ok(conjunctions)
+}
+
+/// Finds the time of closest approach (TCA) between two satellites in a short time window
+///
+/// The distance between the satellites is minimized with a golden-section search.
+/// The window must contain a single local minimum of the distance, for example
+/// a bracket around a candidate returned by a coarse screening.
+///
+/// # Arguments
+///
+/// * `a` - The first satellite's propagator constants
+/// * `b` - The second satellite's propagator constants
+/// * `start` - The beginning of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `end` - The end of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `tolerance` - The maximum error on the TCA in minutes (strictly positive)
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let tle = (
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// );
+/// let elements = sgp4::Elements::from_tle(Some("ISS (ZARYA)".to_owned()), tle.0, tle.1)?;
+/// let iss = sgp4::Constants::from_elements(&elements)?;
+/// // a virtual satellite on a crossing orbit
+/// let mut other_elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+/// other_elements.right_ascension += 10.0;
+/// let other = sgp4::Constants::from_elements(&other_elements)?;
+/// let conjunction = sgp4::closest_approach(
+///     &iss,
+///     &other,
+///     elements.epoch(),
+///     elements.epoch() + 60.0 / (365.25 * 24.0 * 60.0),
+///     1e-6,
+/// )?;
+/// println!("miss distance = {} km", conjunction.miss_distance);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn closest_approach(
+    a: &propagator::Constants,
+    b: &propagator::Constants,
+    start: f64,
+    end: f64,
+    tolerance: f64,
+) -> core::result::Result<Conjunction, gp::Error> {
+    assert!(tolerance > 0.0, "tolerance must be strictly positive");
+
+    // the search runs in the first satellite's propagation time
+    let relative_state = |t: MinutesSinceEpoch| {
+        Ok(relative::relative_state(
+            &a.propagate(t)?,
+            &b.propagate(b.minutes_since_epoch(a.julian_years_since_j2000(t)))?,
+        ))
+    };
+    let (t, _) = events::maximize(
+        a.minutes_since_epoch(start).0,
+        a.minutes_since_epoch(end).0,
+        tolerance,
+        |t| relative_state(t).map(|relative_state| -relative_state.range),
+    )?;
+    let a_prediction = a.propagate(t)?;
+    let b_prediction = b.propagate(b.minutes_since_epoch(a.julian_years_since_j2000(t)))?;
+    let relative_state = relative::relative_state(&a_prediction, &b_prediction);
+    Ok(Conjunction {
+        tca: a.julian_years_since_j2000(t),
+        miss_distance: relative_state.range,
+        relative_speed: vector::norm(&relative_state.velocity),
+        relative_velocity: relative_state.velocity,
+        ric_position: relative::ric_state(&a_prediction, &b_prediction).position,
+    })
+}
+
+/// Finds the close approaches between two satellites whose miss vector lies in a screening volume
+///
+/// Candidates are found with [sgp4::conjunctions](fn.conjunctions.html) using the volume's bounding radius,
+/// and kept if the second satellite's position at TCA, expressed in the first satellite's RIC frame,
+/// lies inside the volume.
+///
+/// # Arguments
+///
+/// * `a` - The first (primary) satellite's propagator constants
+/// * `b` - The second (secondary) satellite's propagator constants
+/// * `start` - The beginning of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `end` - The end of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `volume` - The screening volume centred on the primary
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let tle = (
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// );
+/// let elements = sgp4::Elements::from_tle(Some("ISS (ZARYA)".to_owned()), tle.0, tle.1)?;
+/// let iss = sgp4::Constants::from_elements(&elements)?;
+/// // a virtual satellite on a crossing orbit
+/// let mut other_elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+/// other_elements.right_ascension += 10.0;
+/// other_elements.mean_anomaly += 0.5;
+/// let other = sgp4::Constants::from_elements(&other_elements)?;
+/// let conjunctions = sgp4::conjunctions_in_volume(
+///     &iss,
+///     &other,
+///     elements.epoch(),
+///     elements.epoch() + 1.0 / 365.25,
+///     &sgp4::ScreeningVolume::Box {
+///         radial: 0.4,
+///         in_track: 25.0,
+///         cross_track: 25.0,
+///     },
+/// )?;
+/// #     Ok(())
+/// # }
+/// ```
+pub fn conjunctions_in_volume(
+    a: &propagator::Constants,
+    b: &propagator::Constants,
+    start: f64,
+    end: f64,
+    volume: &ScreeningVolume,
+) -> core::result::Result<alloc::vec::Vec<Conjunction>, gp::Error> {
+    let mut conjunctions = conjunctions(a, b, start, end, volume.bounding_radius())?;
+    conjunctions.retain(|conjunction| volume.contains(&conjunction.ric_position));
+    Ok(conjunctions)
+}
@@ -0,0 +1,117 @@
+use crate::model;
+use crate::tle;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Weights of the element differences combined by [Elements::distance](struct.Elements.html#method.distance)
+///
+/// Each weight is the inverse of the difference considered significant,
+/// hence distances below 1 indicate element sets that likely describe the same object.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementsWeights {
+    /// Semi-major axis weight in km⁻¹
+    pub semi_major_axis: f64,
+
+    /// Inclination weight in deg⁻¹
+    pub inclination: f64,
+
+    /// Right ascension of the ascending node weight in deg⁻¹
+    pub right_ascension: f64,
+
+    /// Eccentricity weight
+    pub eccentricity: f64,
+
+    /// Argument of latitude (ω + M) weight in deg⁻¹
+    pub phase: f64,
+}
+
+impl Default for ElementsWeights {
+    /// Returns weights that consider 10 km, 0.1°, 0.1°, 0.001 and 1° differences significant
+    fn default() -> Self {
+        ElementsWeights {
+            semi_major_axis: 0.1,
+            inclination: 10.0,
+            right_ascension: 10.0,
+            eccentricity: 1000.0,
+            phase: 1.0,
+        }
+    }
+}
+
+impl tle::Elements {
+    /// Returns a weighted distance between two element sets
+    ///
+    /// The distance is the root sum of squares of the weighted differences of semi-major axis (WGS84),
+    /// inclination, right ascension of the ascending node, eccentricity and argument of latitude (ω + M).
+    /// The right ascension difference is multiplied by the sine of the mean inclination
+    /// since the node is ill-defined for equatorial orbits.
+    ///
+    /// The other element set's argument of latitude is moved to this element set's epoch
+    /// with its mean motion, while secular drifts of the node and the perigee are ignored.
+    /// Hence the phase difference is only meaningful for epochs a few revolutions apart,
+    /// and the phase weight should be set to zero to correlate older element sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The element set to compare
+    /// * `weights` - The weights of the element differences
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    ///
+    /// // a virtual satellite on a nearby orbit, a few degrees ahead
+    /// let mut other = sgp4::Elements::from_tle(
+    ///     None,
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// other.mean_anomaly += 5.0;
+    /// let weights = sgp4::ElementsWeights::default();
+    /// assert!(elements.distance(&other, &weights) > 1.0);
+    /// assert!(
+    ///     elements.distance(
+    ///         &other,
+    ///         &sgp4::ElementsWeights {
+    ///             phase: 0.0,
+    ///             ..weights
+    ///         }
+    ///     ) < 1.0
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn distance(&self, other: &tle::Elements, weights: &ElementsWeights) -> f64 {
+        let inclination = ((self.inclination + other.inclination) / 2.0).to_radians();
+
+        // Δu = (ω₂ + M₂ - 360 n₂ (t₂ - t₁)) - (ω₁ + M₁)
+        let phase = (other.argument_of_perigee + other.mean_anomaly
+            - 360.0 * other.mean_motion * self.age(other.epoch())
+            - self.argument_of_perigee
+            - self.mean_anomaly)
+            .to_radians();
+        [
+            weights.semi_major_axis
+                * (other.semi_major_axis(&model::WGS84) - self.semi_major_axis(&model::WGS84)),
+            weights.inclination * (other.inclination - self.inclination),
+            weights.right_ascension
+                * model::signed_angle((other.right_ascension - self.right_ascension).to_radians())
+                    .to_degrees()
+                * inclination.sin().abs(),
+            weights.eccentricity * (other.eccentricity - self.eccentricity),
+            weights.phase * model::signed_angle(phase).to_degrees(),
+        ]
+        .iter()
+        .map(|difference| difference.powi(2))
+        .sum::<f64>()
+        .sqrt()
+    }
+}
@@ -0,0 +1,192 @@
+use crate::gp;
+use crate::model;
+use crate::observer;
+use crate::propagator;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Coverage statistics of a constellation sampled on a latitude / longitude grid
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoverageMap {
+    /// Grid latitudes in rad, from -π/2 to π/2
+    pub latitudes: alloc::vec::Vec<f64>,
+
+    /// Grid longitudes in rad, from -π (included) to π (excluded)
+    pub longitudes: alloc::vec::Vec<f64>,
+
+    /// Number of time samples
+    pub samples: usize,
+
+    /// Number of time samples during which at least one satellite is in view, for each grid point
+    ///
+    /// The grid points are stored in row-major order (the index is `latitude_index * longitudes.len() + longitude_index`).
+    pub covered: alloc::vec::Vec<usize>,
+
+    /// Number of satellites in view summed over all the time samples, for each grid point
+    ///
+    /// The grid points are stored in row-major order.
+    pub in_view: alloc::vec::Vec<usize>,
+}
+
+impl CoverageMap {
+    /// Returns the fraction of the time samples during which at least one satellite is in view, in the range [0, 1]
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude_index` - Index of the grid point's latitude in `latitudes`
+    /// * `longitude_index` - Index of the grid point's longitude in `longitudes`
+    pub fn coverage(&self, latitude_index: usize, longitude_index: usize) -> f64 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+        self.covered[latitude_index * self.longitudes.len() + longitude_index] as f64
+            / self.samples as f64
+    }
+
+    /// Returns the mean number of satellites in view
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude_index` - Index of the grid point's latitude in `latitudes`
+    /// * `longitude_index` - Index of the grid point's longitude in `longitudes`
+    pub fn mean_in_view(&self, latitude_index: usize, longitude_index: usize) -> f64 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+        self.in_view[latitude_index * self.longitudes.len() + longitude_index] as f64
+            / self.samples as f64
+    }
+}
+
+/// Samples the coverage of a constellation on a regular latitude / longitude grid
+///
+/// Grid points are located on the WGS84 ellipsoid. A satellite is in view of a grid point
+/// if its elevation is above the minimum elevation. The constellation is propagated from
+/// `start` to `end` (both included) with a fixed step.
+///
+/// # Arguments
+///
+/// * `constellation` - The satellites' propagator constants
+/// * `minimum_elevation` - The elevation above which a satellite is in view in rad
+/// * `resolution` - The latitude and longitude spacing between grid points in rad (strictly positive)
+/// * `start` - The beginning of the time span in years since UTC 1 January 2000 12h00 (J2000)
+/// * `end` - The end of the time span in years since UTC 1 January 2000 12h00 (J2000)
+/// * `step` - The time step in minutes (strictly positive)
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let coverage_map = sgp4::coverage_map(
+///     &[sgp4::Constants::from_elements(&elements)?],
+///     10.0_f64.to_radians(),
+///     5.0_f64.to_radians(),
+///     elements.epoch(),
+///     elements.epoch() + 1.0 / 365.25,
+///     1.0,
+/// )?;
+/// for (latitude_index, latitude) in coverage_map.latitudes.iter().enumerate() {
+///     println!(
+///         "{}°: {}",
+///         latitude.to_degrees(),
+///         coverage_map.coverage(latitude_index, 0)
+///     );
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+pub fn coverage_map(
+    constellation: &[propagator::Constants],
+    minimum_elevation: f64,
+    resolution: f64,
+    start: f64,
+    end: f64,
+    step: f64,
+) -> core::result::Result<CoverageMap, gp::Error> {
+    assert!(resolution > 0.0, "resolution must be strictly positive");
+    assert!(step > 0.0, "step must be strictly positive");
+    let latitudes: alloc::vec::Vec<f64> = (0
+        ..=(core::f64::consts::PI / resolution + 1.0e-9).floor() as usize)
+        .map(|index| -core::f64::consts::FRAC_PI_2 + index as f64 * resolution)
+        .collect();
+    let longitudes: alloc::vec::Vec<f64> = (0..(2.0 * core::f64::consts::PI / resolution - 1.0e-9)
+        .ceil() as usize)
+        .map(|index| -core::f64::consts::PI + index as f64 * resolution)
+        .collect();
+
+    // grid points in the Earth-fixed frame, and local vertical directions
+    let mut grid = alloc::vec::Vec::with_capacity(latitudes.len() * longitudes.len());
+    for latitude in &latitudes {
+        for longitude in &longitudes {
+            grid.push((
+                observer::Observer {
+                    latitude: *latitude,
+                    longitude: *longitude,
+                    altitude: 0.0,
+                }
+                .position(0.0),
+                [
+                    latitude.cos() * longitude.cos(),
+                    latitude.cos() * longitude.sin(),
+                    latitude.sin(),
+                ],
+            ));
+        }
+    }
+    let mut covered = alloc::vec![0; grid.len()];
+    let mut in_view = alloc::vec![0; grid.len()];
+    let sin_minimum_elevation = minimum_elevation.sin();
+    let mut samples = 0;
+    let mut satellites = alloc::vec::Vec::with_capacity(constellation.len());
+    loop {
+        // y₂₀₀₀ = y₀ + k Δt / (365.25 × 24 × 60)
+        let epoch = start + (samples as f64 * step) / (365.25 * 24.0 * 60.0);
+        if epoch > end {
+            break;
+        }
+        let sidereal_time = model::iau_epoch_to_sidereal_time(epoch);
+        satellites.clear();
+        for constants in constellation {
+            let position = constants
+                .propagate(constants.minutes_since_epoch(epoch))?
+                .position;
+
+            // rotation from TEME to the Earth-fixed frame
+            satellites.push([
+                sidereal_time.cos() * position[0] + sidereal_time.sin() * position[1],
+                -sidereal_time.sin() * position[0] + sidereal_time.cos() * position[1],
+                position[2],
+            ]);
+        }
+        for (index, (point, up)) in grid.iter().enumerate() {
+            // the elevation is above εₘ if ρ · û ≥ |ρ| sin εₘ
+            let count = satellites
+                .iter()
+                .filter(|satellite| {
+                    let rho = vector::sub(satellite, point);
+                    vector::dot(&rho, up) >= vector::norm(&rho) * sin_minimum_elevation
+                })
+                .count();
+            if count > 0 {
+                covered[index] += 1;
+                in_view[index] += count;
+            }
+        }
+        samples += 1;
+    }
+    Ok(CoverageMap {
+        latitudes,
+        longitudes,
+        samples,
+        covered,
+        in_view,
+    })
+}
@@ -0,0 +1,172 @@
+use crate::atmosphere;
+use crate::model;
+use crate::tle;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// altitude at which the object is considered decayed in km
+const DECAY_ALTITUDE: f64 = 120.0;
+
+// relative half-width of the decay window
+const DECAY_UNCERTAINTY: f64 = 0.25;
+
+// ρ₀ = 0.15696615 kg.m⁻².earth radii⁻¹ (B* reference density)
+const DRAG_TERM_REFERENCE_DENSITY: f64 = 0.15696615;
+
+/// Estimated re-entry time of a decaying object
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecayEstimate {
+    /// Most likely decay time in years since UTC 1 January 2000 12h00 (J2000)
+    pub epoch: f64,
+
+    /// Beginning of the uncertainty window in years since UTC 1 January 2000 12h00 (J2000)
+    pub earliest: f64,
+
+    /// End of the uncertainty window in years since UTC 1 January 2000 12h00 (J2000)
+    pub latest: f64,
+}
+
+impl tle::Elements {
+    /// Returns the ballistic coefficient B = C_D A / m in m².kg⁻¹ derived from the drag term
+    ///
+    /// The drag term (B*) absorbs modelling errors during element fitting,
+    /// hence the ballistic coefficient is only a rough estimate of the object's physical properties
+    /// and is negative if the drag term is negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     None,
+    ///     "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+    ///     "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    /// )?;
+    /// println!("B = {} m².kg⁻¹", elements.ballistic_coefficient());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn ballistic_coefficient(&self) -> f64 {
+        // B = 2 B* / ρ₀
+        2.0 * self.drag_term / DRAG_TERM_REFERENCE_DENSITY
+    }
+
+    /// Returns a first-order estimate of the elements' decay time
+    ///
+    /// The orbit is assumed to be circular, with the radius derived from the mean motion.
+    /// The semi-major axis decays in an exponential atmosphere whose density varies with altitude.
+    /// If the first derivative of the mean motion is positive, it sets the current decay rate,
+    /// which implicitly accounts for the current solar activity and the object's ballistic coefficient.
+    /// Otherwise, the decay rate is derived from the drag term (B*) with an average solar activity.
+    /// The second derivative of the mean motion is ignored since it is almost always zero in published elements.
+    ///
+    /// The uncertainty window spans ±25 % of the remaining lifetime, a typical accuracy for such estimates.
+    /// Returns `None` if neither the mean motion derivative nor the drag term indicates a decay.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut elements = sgp4::Elements::from_tle(
+    ///     None,
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    ///
+    /// // virtual object on the ISS orbit without reboosts
+    /// elements.mean_motion_dot = 0.0001;
+    /// let estimate = elements.decay_estimate().unwrap();
+    /// println!(
+    ///     "decay in {} days (between {} and {})",
+    ///     elements.age(estimate.epoch),
+    ///     elements.age(estimate.earliest),
+    ///     elements.age(estimate.latest),
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn decay_estimate(&self) -> Option<DecayEstimate> {
+        self.decay_estimate_with_density(&atmosphere::ExponentialDensity)
+    }
+
+    /// Returns a first-order estimate of the elements' decay time with the given density model
+    ///
+    /// See [Elements::decay_estimate](struct.Elements.html#method.decay_estimate) for details.
+    /// The model is evaluated with an average solar activity.
+    ///
+    /// # Arguments
+    ///
+    /// * `density` - The atmospheric density model
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     None,
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let estimate = elements.decay_estimate_with_density(&sgp4::ExponentialDensity);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn decay_estimate_with_density(
+        &self,
+        density: &impl atmosphere::DensityModel,
+    ) -> Option<DecayEstimate> {
+        let geopotential = model::WGS84;
+        let solar_activity = atmosphere::SolarActivity::default();
+
+        let semi_major_axis_0 = self.semi_major_axis(&geopotential);
+        let altitude_0 = semi_major_axis_0 - geopotential.ae;
+
+        // rate of change of the semi-major axis in km.day⁻¹
+        let decay_rate: &dyn Fn(f64) -> f64 = if self.mean_motion_dot > 0.0 {
+            // ȧ₀ = -2 a₀ ṅ / (3 n₀) with ṅ = 2 × mean_motion_dot
+            let decay_rate_0 =
+                -2.0 * semi_major_axis_0 * 2.0 * self.mean_motion_dot / (3.0 * self.mean_motion);
+            let density_0 = density.density(altitude_0, &solar_activity);
+
+            // ȧ = ȧ₀ (ρ / ρ₀) (a / a₀)¹ᐟ²
+            &move |semi_major_axis: f64| {
+                decay_rate_0 * density.density(semi_major_axis - geopotential.ae, &solar_activity)
+                    / density_0
+                    * (semi_major_axis / semi_major_axis_0).sqrt()
+            }
+        } else if self.drag_term > 0.0 {
+            let ballistic_coefficient = self.ballistic_coefficient();
+
+            // μ = kₑ² aₑ³ in m³.s⁻²
+            let gravitational_parameter =
+                geopotential.ke.powi(2) * (geopotential.ae * 1e3).powi(3) / 3600.0;
+
+            // ȧ = -B ρ (μ a)¹ᐟ² (m.s⁻¹ converted to km.day⁻¹)
+            &move |semi_major_axis: f64| {
+                -ballistic_coefficient
+                    * density.density(semi_major_axis - geopotential.ae, &solar_activity)
+                    * (gravitational_parameter * semi_major_axis * 1e3).sqrt()
+                    * 86.4
+            }
+        } else {
+            return None;
+        };
+
+        // Δt = Σ Δa / |ȧ| (1 km altitude steps)
+        let mut lifetime = 0.0;
+        let mut semi_major_axis = semi_major_axis_0;
+        while semi_major_axis - geopotential.ae > DECAY_ALTITUDE {
+            let step = (semi_major_axis - geopotential.ae - DECAY_ALTITUDE).min(1.0);
+            lifetime += step / -decay_rate(semi_major_axis - step / 2.0);
+            semi_major_axis -= step;
+        }
+        let epoch = self.epoch();
+        Some(DecayEstimate {
+            epoch: epoch + lifetime / 365.25,
+            earliest: epoch + (1.0 - DECAY_UNCERTAINTY) * lifetime / 365.25,
+            latest: epoch + (1.0 + DECAY_UNCERTAINTY) * lifetime / 365.25,
+        })
+    }
+}
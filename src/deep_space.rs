@@ -1,15 +1,14 @@
 use crate::gp;
 use crate::model;
 use crate::propagator;
+use crate::real::Real;
 use crate::third_body;
+use crate::trig;
 use core::cmp::Ordering;
 
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
-#[cfg(not(feature = "std"))]
-use num_traits::Euclid;
-
 // θ̇ = 4.37526908801129966 × 10⁻³ rad.min⁻¹
 #[allow(clippy::excessive_precision)]
 const SIDEREAL_SPEED: f64 = 4.37526908801129966e-3;
@@ -63,14 +62,14 @@ const G54: f64 = 4.4108898;
 ///
 /// Use [Constants::initial_state](struct.Constants.html#method.initial_state) to initialize a resonance state.
 #[derive(Copy, Clone)]
-pub struct ResonanceState {
+pub struct ResonanceState<T: Real = f64> {
     t: f64,
-    mean_motion: f64,
-    lambda: f64,
+    mean_motion: T,
+    lambda: T,
 }
 
-impl ResonanceState {
-    pub(crate) fn new(mean_motion_0: f64, lambda_0: f64) -> ResonanceState {
+impl<T: Real> ResonanceState<T> {
+    pub(crate) fn new(mean_motion_0: T, lambda_0: T) -> ResonanceState<T> {
         ResonanceState {
             t: 0.0,
             mean_motion: mean_motion_0,
@@ -90,24 +89,25 @@ impl ResonanceState {
     fn integrate(
         &mut self,
         geopotential: &model::Geopotential,
-        argument_of_perigee_0: f64,
-        lambda_dot_0: f64,
-        resonance: &propagator::Resonance,
+        argument_of_perigee_0: T,
+        lambda_dot_0: T,
+        resonance: &propagator::Resonance<T>,
         sidereal_time_0: f64,
-        t: f64,
-        p22: f64,
-        p23: f64,
-    ) -> (f64, f64) {
-        if (self.t != 0.0 && self.t.is_sign_positive() != t.is_sign_positive())
-            || t.abs() < self.t.abs()
+        t: T,
+        p22: T,
+        p23: T,
+    ) -> (T, T) {
+        let c = T::from_f64;
+        if (self.t != 0.0 && self.t.is_sign_positive() != t.value().is_sign_positive())
+            || t.value().abs() < self.t.abs()
         {
             panic!("the resonance integration state must be manually reset if the target times are non-monotonic");
         }
         // θ = θ₀ + 4.37526908801129966 × 10⁻³ t rem 2π
         #[allow(clippy::excessive_precision)]
         let sidereal_time =
-            (sidereal_time_0 + t * 4.37526908801129966e-3) % (2.0 * core::f64::consts::PI);
-        let (delta_t, ordering) = if t > 0.0 {
+            (c(sidereal_time_0) + t * 4.37526908801129966e-3) % (2.0 * core::f64::consts::PI);
+        let (delta_t, ordering) = if t.value() > 0.0 {
             (DELTA_T, Ordering::Less)
         } else {
             (-DELTA_T, Ordering::Greater)
@@ -115,18 +115,19 @@ impl ResonanceState {
         loop {
             // λ̇ᵢ = nᵢ + λ̇₀
             let lambda_dot = self.mean_motion + lambda_dot_0;
-            let (ni_dot, ni_ddot) = match resonance {
-                propagator::Resonance::OneDay { dr1, dr2, dr3 } => (
-                    // ṅᵢ = 𝛿ᵣ₁ sin(λᵢ - λ₃₁) + 𝛿ᵣ₂ sin(2 (λᵢ - λ₂₂)) + 𝛿ᵣ₃ sin(3 (λᵢ - λ₃₃))
-                    dr1 * (self.lambda - LAMBDA31).sin()
-                        + dr2 * (2.0 * (self.lambda - LAMBDA22)).sin()
-                        + dr3 * (3.0 * (self.lambda - LAMBDA33)).sin(),
-                    // n̈ᵢ = (𝛿ᵣ₁ cos(λᵢ - λ₃₁) + 𝛿ᵣ₂ cos(2 (λᵢ - λ₂₂)) + 𝛿ᵣ₃ cos(3 (λᵢ - λ₃₃))) λ̇ᵢ
-                    (dr1 * (self.lambda - LAMBDA31).cos()
-                        + 2.0 * dr2 * (2.0 * (self.lambda - LAMBDA22)).cos()
-                        + 3.0 * dr3 * (3.0 * (self.lambda - LAMBDA33)).cos())
-                        * lambda_dot,
-                ),
+            let (ni_dot, ni_ddot) = match *resonance {
+                propagator::Resonance::OneDay { dr1, dr2, dr3 } => {
+                    let (sine_31, cosine_31) = trig::sin_cos(self.lambda - LAMBDA31);
+                    let (sine_22, cosine_22) = trig::sin_cos(c(2.0) * (self.lambda - LAMBDA22));
+                    let (sine_33, cosine_33) = trig::sin_cos(c(3.0) * (self.lambda - LAMBDA33));
+                    (
+                        // ṅᵢ = 𝛿ᵣ₁ sin(λᵢ - λ₃₁) + 𝛿ᵣ₂ sin(2 (λᵢ - λ₂₂)) + 𝛿ᵣ₃ sin(3 (λᵢ - λ₃₃))
+                        dr1 * sine_31 + dr2 * sine_22 + dr3 * sine_33,
+                        // n̈ᵢ = (𝛿ᵣ₁ cos(λᵢ - λ₃₁) + 𝛿ᵣ₂ cos(2 (λᵢ - λ₂₂)) + 𝛿ᵣ₃ cos(3 (λᵢ - λ₃₃))) λ̇ᵢ
+                        (dr1 * cosine_31 + c(2.0) * dr2 * cosine_22 + c(3.0) * dr3 * cosine_33)
+                            * lambda_dot,
+                    )
+                }
                 propagator::Resonance::HalfDay {
                     d2201,
                     d2211,
@@ -142,63 +143,76 @@ impl ResonanceState {
                 } => {
                     // ωᵢ = ω₀ + ω̇ tᵢ
                     let argument_of_perigee_i = argument_of_perigee_0 + k14 * self.t;
+                    let (sine_2201, cosine_2201) =
+                        trig::sin_cos(c(2.0) * argument_of_perigee_i + self.lambda - G22);
+                    let (sine_2211, cosine_2211) = trig::sin_cos(self.lambda - G22);
+                    let (sine_3210, cosine_3210) =
+                        trig::sin_cos(argument_of_perigee_i + self.lambda - G32);
+                    let (sine_3222, cosine_3222) =
+                        trig::sin_cos(-argument_of_perigee_i + self.lambda - G32);
+                    let (sine_4410, cosine_4410) =
+                        trig::sin_cos(c(2.0) * argument_of_perigee_i + c(2.0) * self.lambda - G44);
+                    let (sine_4422, cosine_4422) = trig::sin_cos(c(2.0) * self.lambda - G44);
+                    let (sine_5220, cosine_5220) =
+                        trig::sin_cos(argument_of_perigee_i + self.lambda - G52);
+                    let (sine_5232, cosine_5232) =
+                        trig::sin_cos(-argument_of_perigee_i + self.lambda - G52);
+                    let (sine_5421, cosine_5421) =
+                        trig::sin_cos(argument_of_perigee_i + c(2.0) * self.lambda - G54);
+                    let (sine_5433, cosine_5433) =
+                        trig::sin_cos(-argument_of_perigee_i + c(2.0) * self.lambda - G54);
                     (
-                        // ṅᵢ = Σ₍ₗₘₚₖ₎ Dₗₘₚₖ sin((l - 2 p) ωᵢ + m / 2 λᵢ - Gₗₘ)
+                        // ṅᵢ = Σ₍ₗₘₚₖ₎ Dₗₘₚₖ sin((l - 2 p) ωᵢ + m / 2 λᵢ - Gₗₘ)
                         // (l, m, p, k) ∈ {(2, 2, 0, -1), (2, 2, 1, 1), (3, 2, 1, 0),
                         //     (3, 2, 2, 2), (4, 4, 1, 0), (4, 4, 2, 2), (5, 2, 2, 0),
                         //     (5, 2, 3, 2), (5, 4, 2, 1), (5, 4, 3, 3)}
-                        d2201 * (2.0 * argument_of_perigee_i + self.lambda - G22).sin()
-                            + d2211 * (self.lambda - G22).sin()
-                            + d3210 * (argument_of_perigee_i + self.lambda - G32).sin()
-                            + d3222 * (-argument_of_perigee_i + self.lambda - G32).sin()
-                            + d4410 * (2.0 * argument_of_perigee_i + 2.0 * self.lambda - G44).sin()
-                            + d4422 * (2.0 * self.lambda - G44).sin()
-                            + d5220 * (argument_of_perigee_i + self.lambda - G52).sin()
-                            + d5232 * (-argument_of_perigee_i + self.lambda - G52).sin()
-                            + d5421 * (argument_of_perigee_i + 2.0 * self.lambda - G54).sin()
-                            + d5433 * (-argument_of_perigee_i + 2.0 * self.lambda - G54).sin(),
+                        d2201 * sine_2201
+                            + d2211 * sine_2211
+                            + d3210 * sine_3210
+                            + d3222 * sine_3222
+                            + d4410 * sine_4410
+                            + d4422 * sine_4422
+                            + d5220 * sine_5220
+                            + d5232 * sine_5232
+                            + d5421 * sine_5421
+                            + d5433 * sine_5433,
                         // n̈ᵢ = (Σ₍ₗₘₚₖ₎ m / 2 Dₗₘₚₖ cos((l - 2 p) ωᵢ + m / 2 λᵢ - Gₗₘ)) λ̇ᵢ
                         // (l, m, p, k) ∈ {(2, 2, 0, -1), (2, 2, 1, 1), (3, 2, 1, 0),
                         //     (3, 2, 2, 2), (4, 4, 1, 0), (4, 4, 2, 2), (5, 2, 2, 0),
                         //     (5, 2, 3, 2), (5, 4, 2, 1), (5, 4, 3, 3)}
-                        (d2201 * (2.0 * argument_of_perigee_i + self.lambda - G22).cos()
-                            + d2211 * (self.lambda - G22).cos()
-                            + d3210 * (argument_of_perigee_i + self.lambda - G32).cos()
-                            + d3222 * (-argument_of_perigee_i + self.lambda - G32).cos()
-                            + d5220 * (argument_of_perigee_i + self.lambda - G52).cos()
-                            + d5232 * (-argument_of_perigee_i + self.lambda - G52).cos()
-                            + 2.0
-                                * (d4410
-                                    * (2.0 * argument_of_perigee_i + 2.0 * self.lambda - G44)
-                                        .cos()
-                                    + d4422 * (2.0 * self.lambda - G44).cos()
-                                    + d5421
-                                        * (argument_of_perigee_i + 2.0 * self.lambda - G54).cos()
-                                    + d5433
-                                        * (-argument_of_perigee_i + 2.0 * self.lambda - G54)
-                                            .cos()))
+                        (d2201 * cosine_2201
+                            + d2211 * cosine_2211
+                            + d3210 * cosine_3210
+                            + d3222 * cosine_3222
+                            + d5220 * cosine_5220
+                            + d5232 * cosine_5232
+                            + c(2.0)
+                                * (d4410 * cosine_4410
+                                    + d4422 * cosine_4422
+                                    + d5421 * cosine_5421
+                                    + d5433 * cosine_5433))
                             * lambda_dot,
                     )
                 }
             };
-            if (t - delta_t)
+            if (t.value() - delta_t)
                 .partial_cmp(&self.t)
                 .unwrap_or(Ordering::Equal)
                 == ordering
             {
                 return (
                     // p₂₈ = (kₑ / (nᵢ + ṅᵢ (t - tᵢ) + ¹/₂ n̈ᵢ (t - tᵢ)²))²ᐟ³
-                    (geopotential.ke
+                    (c(geopotential.ke)
                         / (self.mean_motion
                             + ni_dot * (t - self.t)
-                            + ni_ddot * (t - self.t).powi(2) * 0.5))
-                        .powf(2.0 / 3.0),
+                            + ni_ddot * (t - self.t).integer_power(2) * 0.5))
+                        .real_power(2.0 / 3.0),
                     match resonance {
                         propagator::Resonance::OneDay { .. } => {
                             // p₂₉ = λᵢ + λ̇ᵢ (t - tᵢ) + ¹/₂ ṅᵢ (t - tᵢ)² - p₂₂ - p₂₃ + θ
                             self.lambda
                                 + lambda_dot * (t - self.t)
-                                + ni_dot * (t - self.t).powi(2) * 0.5
+                                + ni_dot * (t - self.t).integer_power(2) * 0.5
                                 - p22
                                 - p23
                                 + sidereal_time
@@ -207,9 +221,9 @@ impl ResonanceState {
                             // p₂₉ = λᵢ + λ̇ᵢ (t - tᵢ) + ¹/₂ ṅᵢ (t - tᵢ)² - 2 p₂₂ + 2 θ
                             self.lambda
                                 + lambda_dot * (t - self.t)
-                                + ni_dot * (t - self.t).powi(2) * 0.5
-                                - 2.0 * p22
-                                + 2.0 * sidereal_time
+                                + ni_dot * (t - self.t).integer_power(2) * 0.5
+                                - c(2.0) * p22
+                                + c(2.0) * sidereal_time
                         }
                     },
                 );
@@ -227,51 +241,310 @@ impl ResonanceState {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-pub(crate) fn constants(
-    geopotential: model::Geopotential,
-    epoch_to_sidereal_time: impl Fn(f64) -> f64,
+/// Deep space resonance terms calculated during initialization
+///
+/// The resonance integrator advances the resonant mean longitude λ with the rate λ̇ = n + λ̇₀,
+/// where the mean motion rate ṅ is a sum of sines of λ weighted by the coefficients below.
+#[derive(Debug, Clone)]
+pub enum ResonanceCoefficients {
+    /// Geosynchronous resonance (period between 1200 and 1800 min)
+    OneDay {
+        /// Resonant mean longitude λ₀ = M₀ + Ω₀ + ω₀ − θ₀ at epoch in rad
+        lambda_0: f64,
+
+        /// Resonant mean longitude rate λ̇₀ in rad.min⁻¹
+        lambda_dot_0: f64,
+
+        /// Sidereal time θ₀ at epoch in rad
+        sidereal_time_0: f64,
+
+        /// Coefficient 𝛿ᵣ₁ of sin(λ - λ₃₁) in rad.min⁻²
+        dr1: f64,
+
+        /// Coefficient 𝛿ᵣ₂ of sin(2 (λ - λ₂₂)) in rad.min⁻²
+        dr2: f64,
+
+        /// Coefficient 𝛿ᵣ₃ of sin(3 (λ - λ₃₃)) in rad.min⁻²
+        dr3: f64,
+    },
+
+    /// Molniya resonance (period between 680 and 760 min and eccentricity greater than 0.5)
+    HalfDay {
+        /// Resonant mean longitude λ₀ = M₀ + 2 Ω₀ − 2 θ₀ at epoch in rad
+        lambda_0: f64,
+
+        /// Resonant mean longitude rate λ̇₀ in rad.min⁻¹
+        lambda_dot_0: f64,
+
+        /// Sidereal time θ₀ at epoch in rad
+        sidereal_time_0: f64,
+
+        /// Coefficient D₂₂₀₁ in rad.min⁻²
+        d2201: f64,
+
+        /// Coefficient D₂₂₁₁ in rad.min⁻²
+        d2211: f64,
+
+        /// Coefficient D₃₂₁₀ in rad.min⁻²
+        d3210: f64,
+
+        /// Coefficient D₃₂₂₂ in rad.min⁻²
+        d3222: f64,
+
+        /// Coefficient D₄₄₁₀ in rad.min⁻²
+        d4410: f64,
+
+        /// Coefficient D₄₄₂₂ in rad.min⁻²
+        d4422: f64,
+
+        /// Coefficient D₅₂₂₀ in rad.min⁻²
+        d5220: f64,
+
+        /// Coefficient D₅₂₃₂ in rad.min⁻²
+        d5232: f64,
+
+        /// Coefficient D₅₄₂₁ in rad.min⁻²
+        d5421: f64,
+
+        /// Coefficient D₅₄₃₃ in rad.min⁻²
+        d5433: f64,
+    },
+}
+
+/// Deep space coefficients calculated during initialization
+///
+/// Use [Constants::deep_space_coefficients](struct.Constants.html#method.deep_space_coefficients)
+/// to read the coefficients of a propagator.
+#[derive(Debug, Clone)]
+pub struct DeepSpaceCoefficients {
+    /// Secular rates caused by the sun
+    pub solar_dots: third_body::Dots,
+
+    /// Secular rates caused by the moon
+    pub lunar_dots: third_body::Dots,
+
+    /// Long-period periodic coefficients of the sun
+    pub solar_coefficients: third_body::Coefficients,
+
+    /// Long-period periodic coefficients of the moon
+    pub lunar_coefficients: third_body::Coefficients,
+
+    /// Resonance terms, None if the orbit is not resonant
+    pub resonance: Option<ResonanceCoefficients>,
+}
+
+/// Mean motion and eccentricity thresholds that select the deep space resonance branch
+///
+/// Deep space orbits with a Brouwer mean motion in ]one_day_minimum, one_day_maximum[ use the one-day
+/// (geosynchronous) resonance. Otherwise, orbits with a mean motion in [half_day_minimum, half_day_maximum]
+/// and an eccentricity greater than or equal to half_day_minimum_eccentricity use the half-day (Molniya) resonance.
+/// Other deep space orbits are not resonant.
+///
+/// `ResonanceBoundaries::SGP4` reproduces the reference implementation. Other values are meant for studies
+/// of the behavior near the boundaries, or to match modified legacy implementations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResonanceBoundaries {
+    /// Smallest mean motion (excluded) of the one-day resonance in rad.min⁻¹
+    pub one_day_minimum: f64,
+
+    /// Largest mean motion (excluded) of the one-day resonance in rad.min⁻¹
+    pub one_day_maximum: f64,
+
+    /// Smallest mean motion (included) of the half-day resonance in rad.min⁻¹
+    pub half_day_minimum: f64,
+
+    /// Largest mean motion (included) of the half-day resonance in rad.min⁻¹
+    pub half_day_maximum: f64,
+
+    /// Smallest eccentricity (included) of the half-day resonance (unitless)
+    pub half_day_minimum_eccentricity: f64,
+}
+
+impl ResonanceBoundaries {
+    /// The thresholds of the reference implementation
+    ///
+    /// The one-day branch covers periods between 1200 and 1800 min,
+    /// and the half-day branch covers periods between 680 and 760 min with an eccentricity of at least 0.5.
+    pub const SGP4: Self = Self {
+        one_day_minimum: 0.0034906585,
+        one_day_maximum: 0.0052359877,
+        half_day_minimum: 8.26e-3,
+        half_day_maximum: 9.24e-3,
+        half_day_minimum_eccentricity: 0.5,
+    };
+
+    // whether the orbit uses the one-day resonance
+    fn is_one_day<T: Real>(&self, orbit_0: &propagator::Orbit<T>) -> bool {
+        orbit_0.mean_motion.value() > self.one_day_minimum
+            && orbit_0.mean_motion.value() < self.one_day_maximum
+    }
+
+    // whether the orbit uses the half-day resonance (the one-day branch takes precedence)
+    fn is_half_day<T: Real>(&self, orbit_0: &propagator::Orbit<T>) -> bool {
+        orbit_0.mean_motion.value() >= self.half_day_minimum
+            && orbit_0.mean_motion.value() <= self.half_day_maximum
+            && orbit_0.eccentricity.value() >= self.half_day_minimum_eccentricity
+    }
+}
+
+impl Default for ResonanceBoundaries {
+    fn default() -> Self {
+        Self::SGP4
+    }
+}
+
+impl propagator::Constants {
+    /// Returns the deep space coefficients of the propagator
+    ///
+    /// The result is None for near earth orbits (period smaller than 225 min).
+    /// The coefficients are the ones used by `Constants::propagate` and can be compared
+    /// with other implementations or reused in semi-analytical studies
+    /// (for example GEO libration or Molniya resonance).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("MOLNIYA 1-36".to_owned()),
+    ///     "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+    ///     "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    /// )?)?;
+    /// let coefficients = constants.deep_space_coefficients().unwrap();
+    /// assert!(matches!(
+    ///     coefficients.resonance,
+    ///     Some(sgp4::ResonanceCoefficients::HalfDay { .. })
+    /// ));
+    /// println!(
+    ///     "lunar right ascension rate: {} rad.min⁻¹",
+    ///     coefficients.lunar_dots.right_ascension
+    /// );
+    ///
+    /// // attribute the inclination drift after one day to the sun and the moon
+    /// let t = 60.0 * 24.0;
+    /// for (body, dots, body_coefficients) in [
+    ///     ("sun", &coefficients.solar_dots, &coefficients.solar_coefficients),
+    ///     ("moon", &coefficients.lunar_dots, &coefficients.lunar_coefficients),
+    /// ] {
+    ///     println!(
+    ///         "{} inclination drift: {} rad (secular) + {} rad (periodic)",
+    ///         body,
+    ///         dots.inclination * t,
+    ///         body_coefficients.effects(t).inclination
+    ///     );
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn deep_space_coefficients(&self) -> Option<DeepSpaceCoefficients> {
+        self.deep_space_coefficients_from(|| analytic_third_bodies(self.epoch))
+    }
+
+    /// Returns the deep space coefficients of a propagator initialized with external solar and lunar orbits
+    ///
+    /// This function behaves like [Constants::deep_space_coefficients](struct.Constants.html#method.deep_space_coefficients)
+    /// for propagators created with [ConstantsBuilder::with_third_bodies](struct.ConstantsBuilder.html#method.with_third_bodies).
+    /// The per-body rates are not stored in the propagator, hence they are recalculated from the solar and lunar orbits,
+    /// which must be the ones used during initialization.
+    ///
+    /// # Arguments
+    ///
+    /// * `third_bodies` - The solar and lunar orbits at the propagator's epoch
+    pub fn deep_space_coefficients_with_third_bodies(
+        &self,
+        third_bodies: &third_body::ThirdBodies,
+    ) -> Option<DeepSpaceCoefficients> {
+        self.deep_space_coefficients_from(|| {
+            ((&third_bodies.sun).into(), (&third_bodies.moon).into())
+        })
+    }
+
+    // the per-body rates are only needed by analyses, they are recalculated instead of stored
+    fn deep_space_coefficients_from(
+        &self,
+        third_bodies: impl FnOnce() -> (third_body::Orientation, third_body::Orientation),
+    ) -> Option<DeepSpaceCoefficients> {
+        match &self.method {
+            propagator::Method::NearEarth { .. } => None,
+            propagator::Method::DeepSpace(deep_space) => Some({
+                let (solar, lunar) = third_bodies();
+
+                // p₂ = 1 − e₀², β₀ = p₂¹ᐟ²
+                let p2 = 1.0 - self.orbit_0.eccentricity.powi(2);
+                let ((_, solar_dots), (_, lunar_dots)) =
+                    third_body_terms(&self.orbit_0, &solar, &lunar, p2, p2.sqrt());
+                DeepSpaceCoefficients {
+                    solar_dots,
+                    lunar_dots,
+                    solar_coefficients: third_body::Coefficients::new(
+                        &deep_space.solar_perturbations,
+                        SOLAR_ECCENTRICITY,
+                        SOLAR_MEAN_MOTION,
+                    ),
+                    lunar_coefficients: third_body::Coefficients::new(
+                        &deep_space.lunar_perturbations,
+                        LUNAR_ECCENTRICITY,
+                        LUNAR_MEAN_MOTION,
+                    ),
+                    resonance: match &deep_space.resonant {
+                        propagator::Resonant::No { .. } => None,
+                        propagator::Resonant::Yes {
+                            lambda_0,
+                            lambda_dot_0,
+                            sidereal_time_0,
+                            resonance,
+                        } => Some(match resonance {
+                            propagator::Resonance::OneDay { dr1, dr2, dr3 } => {
+                                ResonanceCoefficients::OneDay {
+                                    lambda_0: *lambda_0,
+                                    lambda_dot_0: *lambda_dot_0,
+                                    sidereal_time_0: *sidereal_time_0,
+                                    dr1: *dr1,
+                                    dr2: *dr2,
+                                    dr3: *dr3,
+                                }
+                            }
+                            propagator::Resonance::HalfDay {
+                                d2201,
+                                d2211,
+                                d3210,
+                                d3222,
+                                d4410,
+                                d4422,
+                                d5220,
+                                d5232,
+                                d5421,
+                                d5433,
+                                ..
+                            } => ResonanceCoefficients::HalfDay {
+                                lambda_0: *lambda_0,
+                                lambda_dot_0: *lambda_dot_0,
+                                sidereal_time_0: *sidereal_time_0,
+                                d2201: *d2201,
+                                d2211: *d2211,
+                                d3210: *d3210,
+                                d3222: *d3222,
+                                d4410: *d4410,
+                                d4422: *d4422,
+                                d5220: *d5220,
+                                d5232: *d5232,
+                                d5421: *d5421,
+                                d5433: *d5433,
+                            },
+                        }),
+                    },
+                }
+            }),
+        }
+    }
+}
+
+// sines and cosines of the solar and lunar orbit angles given by the analytic series of SGP4
+pub(crate) fn analytic_third_bodies(
     epoch: f64,
-    orbit_0: propagator::Orbit,
-    p1: f64,
-    a0: f64,
-    c1: f64,
-    b0: f64,
-    c4: f64,
-    k0: f64,
-    k1: f64,
-    k14: f64,
-    p2: f64,
-    p14: f64,
-    p15: f64,
-) -> propagator::Constants {
+) -> (third_body::Orientation, third_body::Orientation) {
     // d₁₉₀₀ = 365.25 (y₂₀₀₀ + 100)
     let d1900 = (epoch + 100.0) * 365.25;
-    let (solar_perturbations, solar_dots) = third_body::perturbations_and_dots(
-        orbit_0.inclination,
-        orbit_0.eccentricity,
-        orbit_0.argument_of_perigee,
-        orbit_0.mean_motion,
-        // sin Iₛ = 0.39785416
-        0.39785416,
-        // cos Iₛ = 0.91744867
-        0.91744867,
-        // sin(Ω₀ - Ωₛ) = sin Ω₀
-        orbit_0.right_ascension.sin(),
-        // cos(Ω₀ - Ωₛ) = cos Ω₀
-        orbit_0.right_ascension.cos(),
-        SOLAR_ECCENTRICITY,
-        // sin ωₛ = -0.98088458
-        -0.98088458,
-        // cos ωₛ = 0.1945905
-        0.1945905,
-        SOLAR_PERTURBATION_COEFFICIENT,
-        SOLAR_MEAN_MOTION,
-        // Mₛ₀ = (6.2565837 + 0.017201977 d₁₉₀₀) rem 2π
-        (6.2565837 + 0.017201977 * d1900) % (2.0 * core::f64::consts::PI),
-        p2,
-        b0,
-    );
 
     // Ωₗₑ = 4.523602 - 9.2422029 × 10⁻⁴ d₁₉₀₀ rem 2π
     let lunar_right_ascension_epsilon =
@@ -301,31 +574,132 @@ pub(crate) fn constants(
                 + 0.91744867 * lunar_right_ascension_sine * lunar_right_ascension_epsilon.sin(),
         )
         - lunar_right_ascension_epsilon;
-    let (lunar_perturbations, lunar_dots) = third_body::perturbations_and_dots(
+    (
+        third_body::Orientation {
+            // sin Iₛ = 0.39785416
+            inclination_sine: 0.39785416,
+            // cos Iₛ = 0.91744867
+            inclination_cosine: 0.91744867,
+            // sin Ωₛ = 0
+            right_ascension_sine: 0.0,
+            // cos Ωₛ = 1
+            right_ascension_cosine: 1.0,
+            // sin ωₛ = -0.98088458
+            argument_of_perigee_sine: -0.98088458,
+            // cos ωₛ = 0.1945905
+            argument_of_perigee_cosine: 0.1945905,
+            // Mₛ₀ = (6.2565837 + 0.017201977 d₁₉₀₀) rem 2π
+            mean_anomaly_0: (6.2565837 + 0.017201977 * d1900) % (2.0 * core::f64::consts::PI),
+        },
+        third_body::Orientation {
+            inclination_sine: lunar_inclination_sine,
+            inclination_cosine: lunar_inclination_cosine,
+            right_ascension_sine: lunar_right_ascension_sine,
+            right_ascension_cosine: lunar_right_ascension_cosine,
+            argument_of_perigee_sine: lunar_argument_of_perigee.sin(),
+            argument_of_perigee_cosine: lunar_argument_of_perigee.cos(),
+            // Mₗ₀ = (-1.1151842 + 0.228027132 d₁₉₀₀) rem 2π
+            mean_anomaly_0: (-1.1151842 + 0.228027132 * d1900) % (2.0 * core::f64::consts::PI),
+        },
+    )
+}
+
+// perturbations and secular rates of the sun and the moon
+#[allow(clippy::type_complexity)]
+fn third_body_terms<T: Real>(
+    orbit_0: &propagator::Orbit<T>,
+    solar: &third_body::Orientation,
+    lunar: &third_body::Orientation,
+    p2: T,
+    b0: T,
+) -> (
+    (third_body::Perturbations<T>, third_body::Dots<T>),
+    (third_body::Perturbations<T>, third_body::Dots<T>),
+) {
+    let c = T::from_f64;
+    let (right_ascension_sine, right_ascension_cosine) = orbit_0.right_ascension.sine_cosine();
+    let solar_terms = third_body::perturbations_and_dots(
+        orbit_0.inclination,
+        orbit_0.eccentricity,
+        orbit_0.argument_of_perigee,
+        orbit_0.mean_motion,
+        solar.inclination_sine,
+        solar.inclination_cosine,
+        // sin(Ω₀ - Ωₛ) = sin Ω₀ cos Ωₛ - cos Ω₀ sin Ωₛ
+        right_ascension_sine * solar.right_ascension_cosine
+            - right_ascension_cosine * solar.right_ascension_sine,
+        // cos(Ω₀ - Ωₛ) = cos Ωₛ cos Ω₀ + sin Ωₛ sin Ω₀
+        c(solar.right_ascension_cosine) * right_ascension_cosine
+            + c(solar.right_ascension_sine) * right_ascension_sine,
+        SOLAR_ECCENTRICITY,
+        solar.argument_of_perigee_sine,
+        solar.argument_of_perigee_cosine,
+        SOLAR_PERTURBATION_COEFFICIENT,
+        SOLAR_MEAN_MOTION,
+        solar.mean_anomaly_0,
+        p2,
+        b0,
+    );
+    let lunar_terms = third_body::perturbations_and_dots(
         orbit_0.inclination,
         orbit_0.eccentricity,
         orbit_0.argument_of_perigee,
         orbit_0.mean_motion,
-        lunar_inclination_sine,
-        lunar_inclination_cosine,
+        lunar.inclination_sine,
+        lunar.inclination_cosine,
         // sin(Ω₀ - Ωₗ) = sin Ω₀ cos Ωₗ - cos Ω₀ sin Ωₗ
-        orbit_0.right_ascension.sin() * lunar_right_ascension_cosine
-            - orbit_0.right_ascension.cos() * lunar_right_ascension_sine,
+        right_ascension_sine * lunar.right_ascension_cosine
+            - right_ascension_cosine * lunar.right_ascension_sine,
         // cos(Ω₀ - Ωₗ) = cos Ωₗ cos Ω₀ + sin Ωₗ sin Ω₀
-        lunar_right_ascension_cosine * orbit_0.right_ascension.cos()
-            + lunar_right_ascension_sine * orbit_0.right_ascension.sin(),
+        c(lunar.right_ascension_cosine) * right_ascension_cosine
+            + c(lunar.right_ascension_sine) * right_ascension_sine,
         LUNAR_ECCENTRICITY,
-        lunar_argument_of_perigee.sin(),
-        lunar_argument_of_perigee.cos(),
+        lunar.argument_of_perigee_sine,
+        lunar.argument_of_perigee_cosine,
         LUNAR_PERTURBATION_COEFFICIENT,
         LUNAR_MEAN_MOTION,
-        // Mₗ₀ = (-1.1151842 + 0.228027132 d₁₉₀₀) rem 2π
-        (-1.1151842 + 0.228027132 * d1900) % (2.0 * core::f64::consts::PI),
+        lunar.mean_anomaly_0,
         p2,
         b0,
     );
+    (solar_terms, lunar_terms)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn constants<T: Real>(
+    geopotential: model::Geopotential,
+    epoch_to_sidereal_time: impl Fn(f64) -> f64,
+    third_bodies: Option<&dyn Fn(f64) -> third_body::ThirdBodies>,
+    resonance_boundaries: &ResonanceBoundaries,
+    epoch: f64,
+    orbit_0: propagator::Orbit<T>,
+    p1: T,
+    a0: T,
+    c1: T,
+    b0: T,
+    c4: T,
+    k0: T,
+    k1: T,
+    k14: T,
+    p2: T,
+    p14: T,
+    p15: T,
+) -> propagator::Constants<T> {
+    let c = T::from_f64;
+    let (solar, lunar) = match third_bodies {
+        Some(third_bodies) => {
+            let third_bodies = third_bodies(epoch);
+            ((&third_bodies.sun).into(), (&third_bodies.moon).into())
+        }
+        None => analytic_third_bodies(epoch),
+    };
+    let ((solar_perturbations, solar_dots), (lunar_perturbations, lunar_dots)) =
+        third_body_terms(&orbit_0, &solar, &lunar, p2, b0);
     propagator::Constants {
         geopotential,
+        epoch,
+        eccentricity_limits: gp::EccentricityLimits::SGP4,
+        horizon: None,
 
         // Ω̇ = p₁₄ + (Ω̇ₛ + Ω̇ₗ)
         right_ascension_dot: p14 + (solar_dots.right_ascension + lunar_dots.right_ascension),
@@ -340,18 +714,16 @@ pub(crate) fn constants(
         c4,
         k0,
         k1,
-        method: propagator::Method::DeepSpace {
+        method: propagator::Method::DeepSpace(propagator::DeepSpace {
             eccentricity_dot: solar_dots.eccentricity + lunar_dots.eccentricity,
             inclination_dot: solar_dots.inclination + lunar_dots.inclination,
             solar_perturbations,
             lunar_perturbations,
-            resonant: if (orbit_0.mean_motion < 0.0052359877 && orbit_0.mean_motion > 0.0034906585)
-                || (orbit_0.mean_motion >= 8.26e-3
-                    && orbit_0.mean_motion <= 9.24e-3
-                    && orbit_0.eccentricity >= 0.5)
+            resonant: if resonance_boundaries.is_one_day(&orbit_0)
+                || resonance_boundaries.is_half_day(&orbit_0)
             {
                 let sidereal_time_0 = epoch_to_sidereal_time(epoch);
-                if orbit_0.mean_motion < 0.0052359877 && orbit_0.mean_motion > 0.0034906585 {
+                if resonance_boundaries.is_one_day(&orbit_0) {
                     propagator::Resonant::Yes {
                         // λ₀ = M₀ + Ω₀ + ω₀ − θ₀ rem 2π
                         lambda_0: (orbit_0.mean_anomaly
@@ -367,43 +739,13 @@ pub(crate) fn constants(
                             + (solar_dots.right_ascension + lunar_dots.right_ascension)
                             - orbit_0.mean_motion,
                         sidereal_time_0,
-                        resonance: {
-                            // p₁₇ = 3 (n / a₀")²
-                            let p17 = 3.0 * (orbit_0.mean_motion / a0).powi(2);
-                            propagator::Resonance::OneDay {
-                                // 𝛿ᵣ₁ = p₁₇ (¹⁵/₁₆ sin²I₀ (1 + 3 p₁) - ³/₄ (1 + p₁))
-                                //           (1 + 2 e₀²) 2.1460748 × 10⁻⁶ / a₀"²
-                                dr1: p17
-                                    * (0.9375
-                                        * orbit_0.inclination.sin().powi(2)
-                                        * (1.0 + 3.0 * p1)
-                                        - 0.75 * (1.0 + p1))
-                                    * (1.0 + 2.0 * orbit_0.eccentricity.powi(2))
-                                    * 2.1460748e-6
-                                    / a0,
-
-                                // 𝛿ᵣ₂ = 2 p₁₇ (³/₄ (1 + p₁)²)
-                                //      (1 + e₀² (- ⁵/₂ + ¹³/₁₆ e₀²)) 1.7891679 × 10⁻⁶
-                                dr2: 2.0
-                                    * p17
-                                    * (0.75 * (1.0 + p1).powi(2))
-                                    * (1.0
-                                        + orbit_0.eccentricity.powi(2)
-                                            * (-2.5 + 0.8125 * orbit_0.eccentricity.powi(2)))
-                                    * 1.7891679e-6,
-
-                                // 𝛿ᵣ₃ = 3 p₁₇ (¹⁵/₈ (1 + p₁)³) (1 + e₀² (- 6 + 6.60937 e₀²))
-                                //       2.2123015 × 10⁻⁷ / a₀"²
-                                dr3: 3.0
-                                    * p17
-                                    * (1.875 * (1.0 + p1).powi(3))
-                                    * (1.0
-                                        + orbit_0.eccentricity.powi(2)
-                                            * (-6.0 + 6.60937 * orbit_0.eccentricity.powi(2)))
-                                    * 2.2123015e-7
-                                    / a0,
-                            }
-                        },
+                        resonance: one_day_resonance(
+                            orbit_0.inclination,
+                            orbit_0.eccentricity,
+                            orbit_0.mean_motion,
+                            p1,
+                            a0,
+                        ),
                     }
                 } else {
                     propagator::Resonant::Yes {
@@ -418,238 +760,293 @@ pub(crate) fn constants(
                         // λ̇₀ = p₁₅ + (Ṁₛ + Ṁₗ) + 2 (p₁₄ + (Ω̇ₛ + Ω̇ₗ) - θ̇) - n₀"
                         lambda_dot_0: p15
                             + (solar_dots.mean_anomaly + lunar_dots.mean_anomaly)
-                            + 2.0
+                            + c(2.0)
                                 * (p14 + (solar_dots.right_ascension + lunar_dots.right_ascension)
                                     - SIDEREAL_SPEED)
                             - orbit_0.mean_motion,
                         sidereal_time_0,
-                        resonance: {
-                            // p₁₈ = 3 n₀"² / a₀"²
-                            let p18 = 3.0 * orbit_0.mean_motion.powi(2) * (1.0 / a0).powi(2);
-
-                            // p₁₉ = p₁₈ / a₀"
-                            let p19 = p18 * (1.0 / a0);
-
-                            // p₂₀ = p₁₉ / a₀"
-                            let p20 = p19 * (1.0 / a0);
-
-                            // p₂₁ = p₂₀ / a₀"
-                            let p21 = p20 * (1.0 / a0);
-
-                            // F₂₂₀ = ³/₄ (1 + 2 p₁ + p₁²)
-                            let f220 = 0.75 * (1.0 + 2.0 * p1 + p1.powi(2));
-
-                            // G₂₁₁ = │ 3.616 - 13.247 e₀ + 16.29 e₀²                          if e₀ ≤ 0.65
-                            //        │ - 72.099 + 331.819 e₀ - 508.738 e₀² + 266.724 e₀³      otherwise
-                            // G₃₁₀ = │ - 19.302 + 117.39 e₀ - 228.419 e₀² + 156.591 e₀³       if e₀ ≤ 0.65
-                            //        │ - 346.844 + 1582.851 e₀ - 2415.925 e₀² + 1246.113 e₀³  otherwise
-                            // G₃₂₂ = │ - 18.9068 + 109.7927 e₀ - 214.6334 e₀² + 146.5816 e₀³  if e₀ ≤ 0.65
-                            //        │ - 342.585 + 1554.908 e₀ - 2366.899 e₀² + 1215.972 e₀³  otherwise
-                            // G₄₁₀ = │ - 41.122 + 242.694 e₀ - 471.094 e₀² + 313.953 e₀³      if e₀ ≤ 0.65
-                            //        │ - 1052.797 + 4758.686 e₀ - 7193.992 e₀² + 3651.957 e₀³ otherwise
-                            // G₄₂₂ = │ - 146.407 + 841.88 e₀ - 1629.014 e₀² + 1083.435 e₀³    if e₀ ≤ 0.65
-                            //        │ - 3581.69 + 16178.11 e₀ - 24462.77 e₀² + 12422.52 e₀³  otherwise
-                            let (g211, g310, g322, g410, g422) = if orbit_0.eccentricity <= 0.65 {
-                                (
-                                    3.616 - 13.247 * orbit_0.eccentricity
-                                        + 16.29 * orbit_0.eccentricity.powi(2),
-                                    -19.302 + 117.39 * orbit_0.eccentricity
-                                        - 228.419 * orbit_0.eccentricity.powi(2)
-                                        + 156.591 * orbit_0.eccentricity.powi(3),
-                                    -18.9068 + 109.7927 * orbit_0.eccentricity
-                                        - 214.6334 * orbit_0.eccentricity.powi(2)
-                                        + 146.5816 * orbit_0.eccentricity.powi(3),
-                                    -41.122 + 242.694 * orbit_0.eccentricity
-                                        - 471.094 * orbit_0.eccentricity.powi(2)
-                                        + 313.953 * orbit_0.eccentricity.powi(3),
-                                    -146.407 + 841.88 * orbit_0.eccentricity
-                                        - 1629.014 * orbit_0.eccentricity.powi(2)
-                                        + 1083.435 * orbit_0.eccentricity.powi(3),
-                                )
-                            } else {
-                                (
-                                    -72.099 + 331.819 * orbit_0.eccentricity
-                                        - 508.738 * orbit_0.eccentricity.powi(2)
-                                        + 266.724 * orbit_0.eccentricity.powi(3),
-                                    -346.844 + 1582.851 * orbit_0.eccentricity
-                                        - 2415.925 * orbit_0.eccentricity.powi(2)
-                                        + 1246.113 * orbit_0.eccentricity.powi(3),
-                                    -342.585 + 1554.908 * orbit_0.eccentricity
-                                        - 2366.899 * orbit_0.eccentricity.powi(2)
-                                        + 1215.972 * orbit_0.eccentricity.powi(3),
-                                    -1052.797 + 4758.686 * orbit_0.eccentricity
-                                        - 7193.992 * orbit_0.eccentricity.powi(2)
-                                        + 3651.957 * orbit_0.eccentricity.powi(3),
-                                    -3581.69 + 16178.11 * orbit_0.eccentricity
-                                        - 24462.77 * orbit_0.eccentricity.powi(2)
-                                        + 12422.52 * orbit_0.eccentricity.powi(3),
-                                )
-                            };
-
-                            // G₅₂₀ = │ - 532.114 + 3017.977 e₀ - 5740.032 e₀² + 3708.276 e₀³ if e₀ ≤ 0.65
-                            //        │ 1464.74 - 4664.75 e₀ + 3763.64 e₀²                    if 0.65 < e₀ < 0.715
-                            //        │ - 5149.66 + 29936.92 e₀ - 54087.36 e₀² + 31324.56 e₀³ otherwise
-                            let g520 = if orbit_0.eccentricity <= 0.65 {
-                                -532.114 + 3017.977 * orbit_0.eccentricity
-                                    - 5740.032 * orbit_0.eccentricity.powi(2)
-                                    + 3708.276 * orbit_0.eccentricity.powi(3)
-                            } else if orbit_0.eccentricity < 0.715 {
-                                1464.74 - 4664.75 * orbit_0.eccentricity
-                                    + 3763.64 * orbit_0.eccentricity.powi(2)
-                            } else {
-                                -5149.66 + 29936.92 * orbit_0.eccentricity
-                                    - 54087.36 * orbit_0.eccentricity.powi(2)
-                                    + 31324.56 * orbit_0.eccentricity.powi(3)
-                            };
-
-                            // G₅₃₂ = │ - 853.666 + 4690.25 e₀ - 8624.77 e₀² + 5341.4 e₀³          if e₀ < 0.7
-                            //        │ - 40023.88 + 170470.89 e₀ - 242699.48 e₀² + 115605.82 e₀³  otherwise
-                            // G₅₂₁ = │ - 822.71072 + 4568.6173 e₀ - 8491.4146 e₀² + 5337.524 e₀³  if e₀ < 0.7
-                            //        │ - 51752.104 + 218913.95 e₀ - 309468.16 e₀² + 146349.42 e₀³ otherwise
-                            // G₅₃₃ = │ - 919.2277 + 4988.61 e₀ - 9064.77 e₀² + 5542.21 e₀³        if e₀ < 0.7
-                            //        │ - 37995.78 + 161616.52 e₀ - 229838.2 e₀² + 109377.94 e₀³   otherwise
-                            let (g532, g521, g533) = if orbit_0.eccentricity < 0.7 {
-                                (
-                                    -853.666 + 4690.25 * orbit_0.eccentricity
-                                        - 8624.77 * orbit_0.eccentricity.powi(2)
-                                        + 5341.4 * orbit_0.eccentricity.powi(3),
-                                    -822.71072 + 4568.6173 * orbit_0.eccentricity
-                                        - 8491.4146 * orbit_0.eccentricity.powi(2)
-                                        + 5337.524 * orbit_0.eccentricity.powi(3),
-                                    -919.2277 + 4988.61 * orbit_0.eccentricity
-                                        - 9064.77 * orbit_0.eccentricity.powi(2)
-                                        + 5542.21 * orbit_0.eccentricity.powi(3),
-                                )
-                            } else {
-                                (
-                                    -40023.88 + 170470.89 * orbit_0.eccentricity
-                                        - 242699.48 * orbit_0.eccentricity.powi(2)
-                                        + 115605.82 * orbit_0.eccentricity.powi(3),
-                                    -51752.104 + 218913.95 * orbit_0.eccentricity
-                                        - 309468.16 * orbit_0.eccentricity.powi(2)
-                                        + 146349.42 * orbit_0.eccentricity.powi(3),
-                                    -37995.78 + 161616.52 * orbit_0.eccentricity
-                                        - 229838.2 * orbit_0.eccentricity.powi(2)
-                                        + 109377.94 * orbit_0.eccentricity.powi(3),
-                                )
-                            };
-
-                            propagator::Resonance::HalfDay {
-                                // D₂₂₀₋₁ = p₁₈ 1.7891679 × 10⁻⁶ F₂₂₀ (- 0.306 - 0.44 (e₀ - 0.64))
-                                d2201: p18
-                                    * 1.7891679e-6
-                                    * f220
-                                    * (-0.306 - (orbit_0.eccentricity - 0.64) * 0.44),
-
-                                // D₂₂₁₁ = p₁₈ 1.7891679 × 10⁻⁶ (³/₂ sin²I₀) G₂₁₁
-                                d2211: p18
-                                    * 1.7891679e-6
-                                    * (1.5 * orbit_0.inclination.sin().powi(2))
-                                    * g211,
-
-                                // D₃₂₁₀ = p₁₉ 3.7393792 × 10⁻⁷ (¹⁵/₈ sin I₀ (1 - 2 p₁ - 3 p₁²)) G₃₁₀
-                                d3210: p19
-                                    * 3.7393792e-7
-                                    * (1.875
-                                        * orbit_0.inclination.sin()
-                                        * (1.0 - 2.0 * p1 - 3.0 * p1.powi(2)))
-                                    * g310,
-
-                                // D₃₂₂₂ = p₁₉ 3.7393792 × 10⁻⁷ (- ¹⁵/₈ sin I₀ (1 + 2 p₁ - 3 p₁²)) G₃₂₂
-                                d3222: p19
-                                    * 3.7393792e-7
-                                    * (-1.875
-                                        * orbit_0.inclination.sin()
-                                        * (1.0 + 2.0 * p1 - 3.0 * p1.powi(2)))
-                                    * g322,
-
-                                // D₄₄₁₀ = 2 p₂₀ 7.3636953 × 10⁻⁹ (35 sin²I₀ F₂₂₀) G₄₁₀
-                                d4410: 2.0
-                                    * p20
-                                    * 7.3636953e-9
-                                    * (35.0 * orbit_0.inclination.sin().powi(2) * f220)
-                                    * g410,
-
-                                // D₄₄₂₂ = 2 p₂₀ 7.3636953 × 10⁻⁹ (³¹⁵/₈ sin⁴I₀) G₄₂₂
-                                d4422: 2.0
-                                    * p20
-                                    * 7.3636953e-9
-                                    * (39.375 * orbit_0.inclination.sin().powi(4))
-                                    * g422,
-
-                                // D₅₂₂₀ = p₂₁ 1.1428639 × 10⁻⁷ (³¹⁵/₃₂ sin I₀
-                                //         (sin²I₀ (1 - 2 p₁ - 5 p₁²)
-                                //         + 0.33333333 (- 2 + 4 p₁ + 6 p₁²))) G₅₂₀
-                                d5220: p21
-                                    * 1.1428639e-7
-                                    * (9.84375
-                                        * orbit_0.inclination.sin()
-                                        * (orbit_0.inclination.sin().powi(2)
-                                            * (1.0 - 2.0 * p1 - 5.0 * p1.powi(2))
-                                            + 0.33333333 * (-2.0 + 4.0 * p1 + 6.0 * p1.powi(2))))
-                                    * g520,
-
-                                // D₅₂₃₂ = p₂₁ 1.1428639 × 10⁻⁷ (sin I₀
-                                //         (4.92187512 sin²I₀ (- 2 - 4 p₁ + 10 p₁²)
-                                //         + 6.56250012 (1 + p₁ - 3 p₁²))) G₅₃₂
-                                d5232: p21
-                                    * 1.1428639e-7
-                                    * (orbit_0.inclination.sin()
-                                        * (4.92187512
-                                            * orbit_0.inclination.sin().powi(2)
-                                            * (-2.0 - 4.0 * p1 + 10.0 * p1.powi(2))
-                                            + 6.56250012 * (1.0 + 2.0 * p1 - 3.0 * p1.powi(2))))
-                                    * g532,
-
-                                // D₅₄₂₁ = 2 p₂₁ 2.1765803 × 10⁻⁹ (⁹⁴⁵/₃₂ sin I₀
-                                //         (2 - 8 p₁ + p₁² (- 12 + 8 p₁ + 10 p₁²))) G₅₂₁
-                                d5421: 2.0
-                                    * p21
-                                    * 2.1765803e-9
-                                    * (29.53125
-                                        * orbit_0.inclination.sin()
-                                        * (2.0 - 8.0 * p1
-                                            + p1.powi(2) * (-12.0 + 8.0 * p1 + 10.0 * p1.powi(2))))
-                                    * g521,
-
-                                // D₅₄₃₃ = 2 p₂₁ 2.1765803 × 10⁻⁹ (⁹⁴⁵/₃₂ sin I₀
-                                //         (- 2 - 8 p₁ + p₁² (12 + 8 p₁ - 10 p₁²))) G₅₃₃
-                                d5433: 2.0
-                                    * p21
-                                    * 2.1765803e-9
-                                    * (29.53125
-                                        * orbit_0.inclination.sin()
-                                        * (-2.0 - 8.0 * p1
-                                            + p1.powi(2) * (12.0 + 8.0 * p1 - 10.0 * p1.powi(2))))
-                                    * g533,
-                                k14,
-                            }
-                        },
+                        resonance: half_day_resonance(
+                            orbit_0.inclination,
+                            orbit_0.eccentricity,
+                            orbit_0.mean_motion,
+                            p1,
+                            a0,
+                            k14,
+                        ),
                     }
                 }
             } else {
                 propagator::Resonant::No { a0 }
             },
-        },
+        }),
         orbit_0,
     }
 }
 
-impl propagator::Constants {
+// coefficients of the one-day (geosynchronous) resonance
+fn one_day_resonance<T: Real>(
+    inclination_0: T,
+    eccentricity_0: T,
+    mean_motion_0: T,
+    p1: T,
+    a0: T,
+) -> propagator::Resonance<T> {
+    let c = T::from_f64;
+    let inclination_0_sine = inclination_0.sine();
+    // p₁₇ = 3 (n / a₀")²
+    let p17 = c(3.0) * (mean_motion_0 / a0).integer_power(2);
+    propagator::Resonance::OneDay {
+        // 𝛿ᵣ₁ = p₁₇ (¹⁵/₁₆ sin²I₀ (1 + 3 p₁) - ³/₄ (1 + p₁))
+        //           (1 + 2 e₀²) 2.1460748 × 10⁻⁶ / a₀"²
+        dr1: p17
+            * (c(0.9375) * inclination_0_sine.integer_power(2) * (c(1.0) + c(3.0) * p1)
+                - c(0.75) * (c(1.0) + p1))
+            * (c(1.0) + c(2.0) * eccentricity_0.integer_power(2))
+            * 2.1460748e-6
+            / a0,
+
+        // 𝛿ᵣ₂ = 2 p₁₇ (³/₄ (1 + p₁)²)
+        //      (1 + e₀² (- ⁵/₂ + ¹³/₁₆ e₀²)) 1.7891679 × 10⁻⁶
+        dr2: c(2.0)
+            * p17
+            * (c(0.75) * (c(1.0) + p1).integer_power(2))
+            * (c(1.0)
+                + eccentricity_0.integer_power(2)
+                    * (c(-2.5) + c(0.8125) * eccentricity_0.integer_power(2)))
+            * 1.7891679e-6,
+
+        // 𝛿ᵣ₃ = 3 p₁₇ (¹⁵/₈ (1 + p₁)³) (1 + e₀² (- 6 + 6.60937 e₀²))
+        //       2.2123015 × 10⁻⁷ / a₀"²
+        dr3: c(3.0)
+            * p17
+            * (c(1.875) * (c(1.0) + p1).integer_power(3))
+            * (c(1.0)
+                + eccentricity_0.integer_power(2)
+                    * (c(-6.0) + c(6.60937) * eccentricity_0.integer_power(2)))
+            * 2.2123015e-7
+            / a0,
+    }
+}
+
+// coefficients of the half-day (Molniya) resonance
+fn half_day_resonance<T: Real>(
+    inclination_0: T,
+    eccentricity_0: T,
+    mean_motion_0: T,
+    p1: T,
+    a0: T,
+    k14: T,
+) -> propagator::Resonance<T> {
+    let c = T::from_f64;
+    let inclination_0_sine = inclination_0.sine();
+    // p₁₈ = 3 n₀"² / a₀"²
+    let p18 = c(3.0) * mean_motion_0.integer_power(2) * (c(1.0) / a0).integer_power(2);
+
+    // p₁₉ = p₁₈ / a₀"
+    let p19 = p18 * (c(1.0) / a0);
+
+    // p₂₀ = p₁₉ / a₀"
+    let p20 = p19 * (c(1.0) / a0);
+
+    // p₂₁ = p₂₀ / a₀"
+    let p21 = p20 * (c(1.0) / a0);
+
+    // F₂₂₀ = ³/₄ (1 + 2 p₁ + p₁²)
+    let f220 = c(0.75) * (c(1.0) + c(2.0) * p1 + p1.integer_power(2));
+
+    // G₂₁₁ = │ 3.616 - 13.247 e₀ + 16.29 e₀²                          if e₀ ≤ 0.65
+    //        │ - 72.099 + 331.819 e₀ - 508.738 e₀² + 266.724 e₀³      otherwise
+    // G₃₁₀ = │ - 19.302 + 117.39 e₀ - 228.419 e₀² + 156.591 e₀³       if e₀ ≤ 0.65
+    //        │ - 346.844 + 1582.851 e₀ - 2415.925 e₀² + 1246.113 e₀³  otherwise
+    // G₃₂₂ = │ - 18.9068 + 109.7927 e₀ - 214.6334 e₀² + 146.5816 e₀³  if e₀ ≤ 0.65
+    //        │ - 342.585 + 1554.908 e₀ - 2366.899 e₀² + 1215.972 e₀³  otherwise
+    // G₄₁₀ = │ - 41.122 + 242.694 e₀ - 471.094 e₀² + 313.953 e₀³      if e₀ ≤ 0.65
+    //        │ - 1052.797 + 4758.686 e₀ - 7193.992 e₀² + 3651.957 e₀³ otherwise
+    // G₄₂₂ = │ - 146.407 + 841.88 e₀ - 1629.014 e₀² + 1083.435 e₀³    if e₀ ≤ 0.65
+    //        │ - 3581.69 + 16178.11 e₀ - 24462.77 e₀² + 12422.52 e₀³  otherwise
+    let (g211, g310, g322, g410, g422) = if eccentricity_0.value() <= 0.65 {
+        (
+            c(3.616) - c(13.247) * eccentricity_0 + c(16.29) * eccentricity_0.integer_power(2),
+            c(-19.302) + c(117.39) * eccentricity_0 - c(228.419) * eccentricity_0.integer_power(2)
+                + c(156.591) * eccentricity_0.integer_power(3),
+            c(-18.9068) + c(109.7927) * eccentricity_0
+                - c(214.6334) * eccentricity_0.integer_power(2)
+                + c(146.5816) * eccentricity_0.integer_power(3),
+            c(-41.122) + c(242.694) * eccentricity_0 - c(471.094) * eccentricity_0.integer_power(2)
+                + c(313.953) * eccentricity_0.integer_power(3),
+            c(-146.407) + c(841.88) * eccentricity_0
+                - c(1629.014) * eccentricity_0.integer_power(2)
+                + c(1083.435) * eccentricity_0.integer_power(3),
+        )
+    } else {
+        (
+            c(-72.099) + c(331.819) * eccentricity_0 - c(508.738) * eccentricity_0.integer_power(2)
+                + c(266.724) * eccentricity_0.integer_power(3),
+            c(-346.844) + c(1582.851) * eccentricity_0
+                - c(2415.925) * eccentricity_0.integer_power(2)
+                + c(1246.113) * eccentricity_0.integer_power(3),
+            c(-342.585) + c(1554.908) * eccentricity_0
+                - c(2366.899) * eccentricity_0.integer_power(2)
+                + c(1215.972) * eccentricity_0.integer_power(3),
+            c(-1052.797) + c(4758.686) * eccentricity_0
+                - c(7193.992) * eccentricity_0.integer_power(2)
+                + c(3651.957) * eccentricity_0.integer_power(3),
+            c(-3581.69) + c(16178.11) * eccentricity_0
+                - c(24462.77) * eccentricity_0.integer_power(2)
+                + c(12422.52) * eccentricity_0.integer_power(3),
+        )
+    };
+
+    // G₅₂₀ = │ - 532.114 + 3017.977 e₀ - 5740.032 e₀² + 3708.276 e₀³ if e₀ ≤ 0.65
+    //        │ 1464.74 - 4664.75 e₀ + 3763.64 e₀²                    if 0.65 < e₀ < 0.715
+    //        │ - 5149.66 + 29936.92 e₀ - 54087.36 e₀² + 31324.56 e₀³ otherwise
+    let g520 = if eccentricity_0.value() <= 0.65 {
+        c(-532.114) + c(3017.977) * eccentricity_0 - c(5740.032) * eccentricity_0.integer_power(2)
+            + c(3708.276) * eccentricity_0.integer_power(3)
+    } else if eccentricity_0.value() < 0.715 {
+        c(1464.74) - c(4664.75) * eccentricity_0 + c(3763.64) * eccentricity_0.integer_power(2)
+    } else {
+        c(-5149.66) + c(29936.92) * eccentricity_0 - c(54087.36) * eccentricity_0.integer_power(2)
+            + c(31324.56) * eccentricity_0.integer_power(3)
+    };
+
+    // G₅₃₂ = │ - 853.666 + 4690.25 e₀ - 8624.77 e₀² + 5341.4 e₀³          if e₀ < 0.7
+    //        │ - 40023.88 + 170470.89 e₀ - 242699.48 e₀² + 115605.82 e₀³  otherwise
+    // G₅₂₁ = │ - 822.71072 + 4568.6173 e₀ - 8491.4146 e₀² + 5337.524 e₀³  if e₀ < 0.7
+    //        │ - 51752.104 + 218913.95 e₀ - 309468.16 e₀² + 146349.42 e₀³ otherwise
+    // G₅₃₃ = │ - 919.2277 + 4988.61 e₀ - 9064.77 e₀² + 5542.21 e₀³        if e₀ < 0.7
+    //        │ - 37995.78 + 161616.52 e₀ - 229838.2 e₀² + 109377.94 e₀³   otherwise
+    let (g532, g521, g533) = if eccentricity_0.value() < 0.7 {
+        (
+            c(-853.666) + c(4690.25) * eccentricity_0
+                - c(8624.77) * eccentricity_0.integer_power(2)
+                + c(5341.4) * eccentricity_0.integer_power(3),
+            c(-822.71072) + c(4568.6173) * eccentricity_0
+                - c(8491.4146) * eccentricity_0.integer_power(2)
+                + c(5337.524) * eccentricity_0.integer_power(3),
+            c(-919.2277) + c(4988.61) * eccentricity_0
+                - c(9064.77) * eccentricity_0.integer_power(2)
+                + c(5542.21) * eccentricity_0.integer_power(3),
+        )
+    } else {
+        (
+            c(-40023.88) + c(170470.89) * eccentricity_0
+                - c(242699.48) * eccentricity_0.integer_power(2)
+                + c(115605.82) * eccentricity_0.integer_power(3),
+            c(-51752.104) + c(218913.95) * eccentricity_0
+                - c(309468.16) * eccentricity_0.integer_power(2)
+                + c(146349.42) * eccentricity_0.integer_power(3),
+            c(-37995.78) + c(161616.52) * eccentricity_0
+                - c(229838.2) * eccentricity_0.integer_power(2)
+                + c(109377.94) * eccentricity_0.integer_power(3),
+        )
+    };
+
+    propagator::Resonance::HalfDay {
+        // D₂₂₀₋₁ = p₁₈ 1.7891679 × 10⁻⁶ F₂₂₀ (- 0.306 - 0.44 (e₀ - 0.64))
+        d2201: p18 * 1.7891679e-6 * f220 * (c(-0.306) - (eccentricity_0 - 0.64) * 0.44),
+
+        // D₂₂₁₁ = p₁₈ 1.7891679 × 10⁻⁶ (³/₂ sin²I₀) G₂₁₁
+        d2211: p18 * 1.7891679e-6 * (c(1.5) * inclination_0_sine.integer_power(2)) * g211,
+
+        // D₃₂₁₀ = p₁₉ 3.7393792 × 10⁻⁷ (¹⁵/₈ sin I₀ (1 - 2 p₁ - 3 p₁²)) G₃₁₀
+        d3210: p19
+            * 3.7393792e-7
+            * (c(1.875)
+                * inclination_0_sine
+                * (c(1.0) - c(2.0) * p1 - c(3.0) * p1.integer_power(2)))
+            * g310,
+
+        // D₃₂₂₂ = p₁₉ 3.7393792 × 10⁻⁷ (- ¹⁵/₈ sin I₀ (1 + 2 p₁ - 3 p₁²)) G₃₂₂
+        d3222: p19
+            * 3.7393792e-7
+            * (c(-1.875)
+                * inclination_0_sine
+                * (c(1.0) + c(2.0) * p1 - c(3.0) * p1.integer_power(2)))
+            * g322,
+
+        // D₄₄₁₀ = 2 p₂₀ 7.3636953 × 10⁻⁹ (35 sin²I₀ F₂₂₀) G₄₁₀
+        d4410: c(2.0)
+            * p20
+            * 7.3636953e-9
+            * (c(35.0) * inclination_0_sine.integer_power(2) * f220)
+            * g410,
+
+        // D₄₄₂₂ = 2 p₂₀ 7.3636953 × 10⁻⁹ (³¹⁵/₈ sin⁴I₀) G₄₂₂
+        d4422: c(2.0)
+            * p20
+            * 7.3636953e-9
+            * (c(39.375) * inclination_0_sine.integer_power(4))
+            * g422,
+
+        // D₅₂₂₀ = p₂₁ 1.1428639 × 10⁻⁷ (³¹⁵/₃₂ sin I₀
+        //         (sin²I₀ (1 - 2 p₁ - 5 p₁²)
+        //         + 0.33333333 (- 2 + 4 p₁ + 6 p₁²))) G₅₂₀
+        d5220: p21
+            * 1.1428639e-7
+            * (c(9.84375)
+                * inclination_0_sine
+                * (inclination_0_sine.integer_power(2)
+                    * (c(1.0) - c(2.0) * p1 - c(5.0) * p1.integer_power(2))
+                    + c(0.33333333) * (c(-2.0) + c(4.0) * p1 + c(6.0) * p1.integer_power(2))))
+            * g520,
+
+        // D₅₂₃₂ = p₂₁ 1.1428639 × 10⁻⁷ (sin I₀
+        //         (4.92187512 sin²I₀ (- 2 - 4 p₁ + 10 p₁²)
+        //         + 6.56250012 (1 + p₁ - 3 p₁²))) G₅₃₂
+        d5232: p21
+            * 1.1428639e-7
+            * (inclination_0_sine
+                * (c(4.92187512)
+                    * inclination_0_sine.integer_power(2)
+                    * (c(-2.0) - c(4.0) * p1 + c(10.0) * p1.integer_power(2))
+                    + c(6.56250012) * (c(1.0) + c(2.0) * p1 - c(3.0) * p1.integer_power(2))))
+            * g532,
+
+        // D₅₄₂₁ = 2 p₂₁ 2.1765803 × 10⁻⁹ (⁹⁴⁵/₃₂ sin I₀
+        //         (2 - 8 p₁ + p₁² (- 12 + 8 p₁ + 10 p₁²))) G₅₂₁
+        d5421: c(2.0)
+            * p21
+            * 2.1765803e-9
+            * (c(29.53125)
+                * inclination_0_sine
+                * (c(2.0) - c(8.0) * p1
+                    + p1.integer_power(2)
+                        * (c(-12.0) + c(8.0) * p1 + c(10.0) * p1.integer_power(2))))
+            * g521,
+
+        // D₅₄₃₃ = 2 p₂₁ 2.1765803 × 10⁻⁹ (⁹⁴⁵/₃₂ sin I₀
+        //         (- 2 - 8 p₁ + p₁² (12 + 8 p₁ - 10 p₁²))) G₅₃₃
+        d5433: c(2.0)
+            * p21
+            * 2.1765803e-9
+            * (c(29.53125)
+                * inclination_0_sine
+                * (c(-2.0) - c(8.0) * p1
+                    + p1.integer_power(2)
+                        * (c(12.0) + c(8.0) * p1 - c(10.0) * p1.integer_power(2))))
+            * g533,
+        k14,
+    }
+}
+
+impl<T: Real> propagator::Constants<T> {
     #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     pub(crate) fn deep_space_orbital_elements(
         &self,
-        eccentricity_dot: f64,
-        inclination_dot: f64,
-        solar_perturbations: &third_body::Perturbations,
-        lunar_perturbations: &third_body::Perturbations,
-        resonant: &propagator::Resonant,
-        state: Option<&mut ResonanceState>,
-        t: f64,
-        p22: f64,
-        p23: f64,
+        eccentricity_dot: T,
+        inclination_dot: T,
+        solar_perturbations: &third_body::Perturbations<T>,
+        lunar_perturbations: &third_body::Perturbations<T>,
+        resonant: &propagator::Resonant<T>,
+        state: Option<&mut ResonanceState<T>>,
+        t: T,
+        p22: T,
+        p23: T,
         afspc_compatibility_mode: bool,
-    ) -> core::result::Result<(propagator::Orbit, f64, f64, f64, f64, f64, f64), gp::Error> {
+        limits: Option<&gp::EccentricityLimits>,
+    ) -> core::result::Result<(propagator::Orbit<T>, T, T, T, T, T, T), gp::Error> {
+        let c = T::from_f64;
         let (p28, p29) = match resonant {
             propagator::Resonant::No { a0 } => {
                 assert!(
@@ -699,41 +1096,46 @@ impl propagator::Constants {
         let inclination = self.orbit_0.inclination
             + inclination_dot * t
             + (solar_delta_inclination + lunar_delta_inclination);
-        let (right_ascension, argument_of_perigee) = if inclination >= 0.2 {
+        let (inclination_sine, inclination_cosine) = trig::sin_cos(inclination);
+        let (right_ascension, argument_of_perigee) = if inclination.value() >= 0.2 {
             (
                 // Ω = p₂₂ + (pₛ₅ + pₗ₅) / sin I
-                p22 + (ps5 + pl5) / inclination.sin(),
+                p22 + (ps5 + pl5) / inclination_sine,
                 // ω = p₂₃ + (pₛ₄ + pₗ₄) - cos I (pₛ₅ + pₗ₅) / sin I
-                p23 + (ps4 + pl4) - inclination.cos() * ((ps5 + pl5) / inclination.sin()),
+                p23 + (ps4 + pl4) - inclination_cosine * ((ps5 + pl5) / inclination_sine),
             )
         } else {
             //             sin I sin p₂₂ + (pₛ₅ + pₗ₅) cos p₂₂ + (δIₛ + δIₗ) cos I sin p₂₂
             // p₃₀ = tan⁻¹ -------------------------------------------------------------
             //             sin I cos p₂₂ - (pₛ₅ + pₗ₅) sin p₂₂ + (δIₛ + δIₗ) cos I cos p₂₂
-            let p30 = (inclination.sin() * p22.sin()
-                + ((ps5 + pl5) * p22.cos()
-                    + (solar_delta_inclination + lunar_delta_inclination)
-                        * inclination.cos()
-                        * p22.sin()))
-            .atan2(
-                inclination.sin() * p22.cos()
-                    + (-(ps5 + pl5) * p22.sin()
+            let (p22_sine, p22_cosine) = trig::sin_cos(p22);
+            let p30 = trig::atan2(
+                inclination_sine * p22_sine
+                    + ((ps5 + pl5) * p22_cosine
+                        + (solar_delta_inclination + lunar_delta_inclination)
+                            * inclination_cosine
+                            * p22_sine),
+                inclination_sine * p22_cosine
+                    + (-(ps5 + pl5) * p22_sine
                         + (solar_delta_inclination + lunar_delta_inclination)
-                            * inclination.cos()
-                            * p22.cos()),
+                            * inclination_cosine
+                            * p22_cosine),
             );
 
             // Ω = │ p₃₀ + 2π if p₃₀ + π < p₂₂ rem 2π
             //     │ p₃₀ - 2π if p₃₀ - π > p₂₂ rem 2π
             //     │ p₃₀      otherwise
-            let right_ascension =
-                if p30 < p22 % (2.0 * core::f64::consts::PI) - core::f64::consts::PI {
-                    p30 + (2.0 * core::f64::consts::PI)
-                } else if p30 > p22 % (2.0 * core::f64::consts::PI) + core::f64::consts::PI {
-                    p30 - (2.0 * core::f64::consts::PI)
-                } else {
-                    p30
-                };
+            let right_ascension = if p30.value()
+                < (p22 % (2.0 * core::f64::consts::PI)).value() - core::f64::consts::PI
+            {
+                p30 + (2.0 * core::f64::consts::PI)
+            } else if p30.value()
+                > (p22 % (2.0 * core::f64::consts::PI)).value() + core::f64::consts::PI
+            {
+                p30 - (2.0 * core::f64::consts::PI)
+            } else {
+                p30
+            };
             (
                 right_ascension,
                 // ω = │ p₂₃ + (pₛ₄ + pₗ₄) + cos I ((p₂₂ rem 2π) - Ω)
@@ -741,88 +1143,75 @@ impl propagator::Constants {
                 // ω = │ p₂₃ + (pₛ₄ + pₗ₄) + cos I ((p₂₂ rem 2π) - Ω)
                 //     │ - (δIₛ + δIₗ) (p₂₂ rem 2π) sin I             otherwise
                 p23 + (ps4 + pl4)
-                    + inclination.cos() * (p22 % (2.0 * core::f64::consts::PI) - right_ascension)
+                    + inclination_cosine * (p22 % (2.0 * core::f64::consts::PI) - right_ascension)
                     - (solar_delta_inclination + lunar_delta_inclination)
                         * if afspc_compatibility_mode {
-                            #[cfg(feature = "std")]
-                            {
-                                p22.rem_euclid(2.0 * core::f64::consts::PI)
-                            }
-                            #[cfg(not(feature = "std"))]
-                            {
-                                Euclid::rem_euclid(&p22, &(2.0 * core::f64::consts::PI))
-                            }
+                            p22.euclidean_remainder(2.0 * core::f64::consts::PI)
                         } else {
                             p22 % (2.0 * core::f64::consts::PI)
                         }
-                        * inclination.sin(),
+                        * inclination_sine,
             )
         };
 
         // p₃₁ = e₀ + ė t - C₄ t
         let p31 = self.orbit_0.eccentricity + eccentricity_dot * t - self.c4 * t;
-        if !(-0.001..1.0).contains(&p31) {
-            Err(gp::Error::OutOfRangeEccentricity {
-                eccentricity: p31,
-                t,
+        // e = │ clamp + (δeₛ + δeₗ) if minimum ≤ p₃₁ < clamp (10⁻⁶ by default)
+        //     │ p₃₁ + (δeₛ + δeₗ)   otherwise
+        let eccentricity = gp::EccentricityLimits::apply(limits, p31, t.value())?
+            + (solar_delta_eccentricity + lunar_delta_eccentricity);
+        if limits.is_some() && !(0.0..=1.0).contains(&eccentricity.value()) {
+            Err(gp::Error::OutOfRangePerturbedEccentricity {
+                eccentricity: eccentricity.value(),
+                t: t.value(),
             })
         } else {
-            // e = │ 10⁻⁶ + (δeₛ + δeₗ) if p₃₁ < 10⁻⁶
-            //     │ p₃₁ + (δeₛ + δeₗ)  otherwise
-            let eccentricity =
-                (p31).max(1.0e-6) + (solar_delta_eccentricity + lunar_delta_eccentricity);
-            if !(0.0..=1.0).contains(&eccentricity) {
-                Err(gp::Error::OutOfRangePerturbedEccentricity { eccentricity, t })
-            } else {
-                // a = p₂₈ (1 - C₁ t)²
-                let a = p28 * (1.0 - self.c1 * t).powi(2);
-                Ok((
-                    propagator::Orbit {
-                        inclination,
-                        right_ascension,
-                        eccentricity,
-                        argument_of_perigee,
-
-                        // M = p₂₉ + (δMₛ + δMₗ) + n₀" k₁ t²
-                        mean_anomaly: p29
-                            + (solar_delta_mean_motion + lunar_delta_mean_motion)
-                            + self.orbit_0.mean_motion * self.k1 * t.powi(2),
-
-                        // n = kₑ / a³ᐟ²
-                        mean_motion: self.geopotential.ke / a.powf(1.5),
-                    },
-                    a,
-                    //         1 J₃
-                    // p₃₂ = - - -- sin I
-                    //         2 J₂
-                    -0.5 * (self.geopotential.j3 / self.geopotential.j2) * inclination.sin(),
-                    // p₃₃ = 1 - cos²I
-                    1.0 - inclination.cos().powi(2),
-                    // p₃₄ = 7 cos²I - 1
-                    7.0 * inclination.cos().powi(2) - 1.0,
-                    //       │   1 J₃       3 + 5 cos I
-                    // p₃₅ = │ - - -- sin I ----------- if |1 + cos I| > 1.5 × 10⁻¹²
-                    //       │   4 J₂        1 + cos I
-                    //       │   1 J₃       3 + 5 cos I
-                    //       │ - - -- sin I ----------- otherwise
-                    //       │   4 J₂       1.5 × 10⁻¹²
-                    if (1.0 + inclination.cos()).abs() > 1.5e-12 {
-                        -0.25
-                            * (self.geopotential.j3 / self.geopotential.j2)
-                            * inclination.sin()
-                            * (3.0 + 5.0 * inclination.cos())
-                            / (1.0 + inclination.cos())
-                    } else {
-                        -0.25
-                            * (self.geopotential.j3 / self.geopotential.j2)
-                            * inclination.sin()
-                            * (3.0 + 5.0 * inclination.cos())
-                            / 1.5e-12
-                    },
-                    // p₃₆ = 3 cos²I - 1
-                    3.0 * inclination.cos().powi(2) - 1.0,
-                ))
-            }
+            // a = p₂₈ (1 - C₁ t)²
+            let a = p28 * (c(1.0) - self.c1 * t).integer_power(2);
+            Ok((
+                propagator::Orbit {
+                    inclination,
+                    right_ascension,
+                    eccentricity,
+                    argument_of_perigee,
+
+                    // M = p₂₉ + (δMₛ + δMₗ) + n₀" k₁ t²
+                    mean_anomaly: p29
+                        + (solar_delta_mean_motion + lunar_delta_mean_motion)
+                        + self.orbit_0.mean_motion * self.k1 * t.integer_power(2),
+
+                    // n = kₑ / a³ᐟ²
+                    mean_motion: c(self.geopotential.ke) / a.real_power(1.5),
+                },
+                a,
+                //         1 J₃
+                // p₃₂ = - - -- sin I
+                //         2 J₂
+                c(-0.5 * (self.geopotential.j3 / self.geopotential.j2)) * inclination_sine,
+                // p₃₃ = 1 - cos²I
+                c(1.0) - inclination_cosine.integer_power(2),
+                // p₃₄ = 7 cos²I - 1
+                c(7.0) * inclination_cosine.integer_power(2) - 1.0,
+                //       │   1 J₃       3 + 5 cos I
+                // p₃₅ = │ - - -- sin I ----------- if |1 + cos I| > 1.5 × 10⁻¹²
+                //       │   4 J₂        1 + cos I
+                //       │   1 J₃       3 + 5 cos I
+                //       │ - - -- sin I ----------- otherwise
+                //       │   4 J₂       1.5 × 10⁻¹²
+                if (1.0 + inclination_cosine.value()).abs() > 1.5e-12 {
+                    c(-0.25 * (self.geopotential.j3 / self.geopotential.j2))
+                        * inclination_sine
+                        * (c(3.0) + c(5.0) * inclination_cosine)
+                        / (c(1.0) + inclination_cosine)
+                } else {
+                    c(-0.25 * (self.geopotential.j3 / self.geopotential.j2))
+                        * inclination_sine
+                        * (c(3.0) + c(5.0) * inclination_cosine)
+                        / 1.5e-12
+                },
+                // p₃₆ = 3 cos²I - 1
+                c(3.0) * inclination_cosine.integer_power(2) - 1.0,
+            ))
         }
     }
 }
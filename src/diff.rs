@@ -0,0 +1,126 @@
+use crate::model;
+use crate::propagator;
+use crate::relative;
+use crate::tle;
+use crate::vector;
+
+/// Differences between two element sets of the same object
+///
+/// All the differences are calculated as new minus old, angle differences are in the range [-180, 180[.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementsDiff {
+    /// Epoch difference in days
+    pub epoch: f64,
+
+    /// Inclination difference in deg
+    pub inclination: f64,
+
+    /// Right ascension of the ascending node difference in deg
+    pub right_ascension: f64,
+
+    /// Eccentricity difference
+    pub eccentricity: f64,
+
+    /// Argument of perigee difference in deg
+    pub argument_of_perigee: f64,
+
+    /// Mean anomaly difference in deg
+    pub mean_anomaly: f64,
+
+    /// Mean motion difference in rev.day⁻¹
+    pub mean_motion: f64,
+
+    /// Drag term (B*) difference in earth radii⁻¹
+    pub drag_term: f64,
+
+    /// Mean semi-major axis difference in km (WGS84)
+    pub semi_major_axis: f64,
+
+    /// Position of the new prediction relative to the old one, at the new epoch,
+    /// in the old prediction's radial, in-track, cross-track frame in km
+    ///
+    /// `None` if either element set cannot be propagated to the new epoch.
+    pub ric_position: Option<[f64; 3]>,
+
+    /// In-track position difference divided by the speed in s
+    ///
+    /// The timing shift is positive if the new elements place the satellite ahead of the old ones,
+    /// in which case ground stations see it earlier than predicted.
+    /// `None` if either element set cannot be propagated to the new epoch.
+    pub timing_shift: Option<f64>,
+}
+
+// signed angle difference in deg in the range [-180, 180[
+fn angle_difference(old: f64, new: f64) -> f64 {
+    model::signed_angle((new - old).to_radians()).to_degrees()
+}
+
+impl tle::Elements {
+    /// Compares the elements with a newer element set of the same object
+    ///
+    /// The mean elements are compared directly, regardless of the epoch difference.
+    /// The position difference is calculated by propagating both element sets to the new epoch with the WGS84 model.
+    ///
+    /// # Arguments
+    ///
+    /// * `new` - The new element set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let old = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    ///
+    /// // a virtual update whose satellite is 0.1° ahead
+    /// let mut new = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// new.mean_anomaly += 0.1;
+    /// let diff = old.diff(&new);
+    /// println!("{:?}", diff);
+    /// assert!((diff.timing_shift.unwrap() - 1.55).abs() < 0.05);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, new: &tle::Elements) -> ElementsDiff {
+        let ric_position = propagator::Constants::from_elements(self)
+            .ok()
+            .zip(propagator::Constants::from_elements(new).ok())
+            .and_then(|(old_constants, new_constants)| {
+                let old_prediction = old_constants
+                    .propagate(self.datetime_to_minutes_since_epoch(&new.datetime).ok()?)
+                    .ok()?;
+                let new_prediction = new_constants.propagate(tle::MinutesSinceEpoch(0.0)).ok()?;
+                Some((
+                    relative::ric_state(&old_prediction, &new_prediction).position,
+                    vector::norm(&old_prediction.velocity),
+                ))
+            });
+        ElementsDiff {
+            epoch: self.age(new.epoch()),
+            inclination: new.inclination - self.inclination,
+            right_ascension: angle_difference(self.right_ascension, new.right_ascension),
+            eccentricity: new.eccentricity - self.eccentricity,
+            argument_of_perigee: angle_difference(
+                self.argument_of_perigee,
+                new.argument_of_perigee,
+            ),
+            mean_anomaly: angle_difference(self.mean_anomaly, new.mean_anomaly),
+            mean_motion: new.mean_motion - self.mean_motion,
+            drag_term: new.drag_term - self.drag_term,
+            semi_major_axis: new.semi_major_axis(&model::WGS84)
+                - self.semi_major_axis(&model::WGS84),
+            ric_position: ric_position.map(|(position, _)| position),
+
+            // Δt = Δi / |v|
+            timing_shift: ric_position.map(|(position, speed)| position[1] / speed),
+        }
+    }
+}
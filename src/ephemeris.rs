@@ -0,0 +1,164 @@
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// 1 AU = 149597870.7 km
+const ASTRONOMICAL_UNIT: f64 = 149597870.7;
+
+/// Returns the approximate position of the Sun in the True Equator, Mean Equinox (TEME) reference frame in km
+///
+/// The low-precision solar coordinates expression (accurate to about 0.01°) is used,
+/// which is sufficient for illumination and phase angle calculations.
+/// The difference between the mean-of-date and TEME frames is neglected.
+///
+/// # Arguments
+///
+/// * `epoch` - Years since UTC 1 January 2000 12h00
+///
+/// # Example
+///
+/// ```
+/// let sun_position = sgp4::sun_position(sgp4::julian_years_since_j2000(
+///     &chrono::NaiveDate::from_ymd_opt(2020, 7, 12)
+///         .unwrap()
+///         .and_hms_opt(21, 16, 1)
+///         .unwrap(),
+/// ));
+/// println!("r☉ = {:?} km", sun_position);
+/// ```
+pub fn sun_position(epoch: f64) -> [f64; 3] {
+    // c₂₀₀₀ = y₂₀₀₀ / 100
+    let c2000 = epoch / 100.0;
+
+    // λ☉ = 280.460° + 36000.771° c₂₀₀₀
+    let mean_longitude = (280.460 + 36000.771 * c2000).to_radians();
+
+    // M☉ = 357.5291092° + 35999.05034° c₂₀₀₀
+    let mean_anomaly = (357.5291092 + 35999.05034 * c2000).to_radians();
+
+    // λₑ = λ☉ + 1.914666471° sin M☉ + 0.019994643° sin 2 M☉
+    let ecliptic_longitude = mean_longitude
+        + 1.914666471_f64.to_radians() * mean_anomaly.sin()
+        + 0.019994643_f64.to_radians() * (2.0 * mean_anomaly).sin();
+
+    // ε = 23.439291° - 0.0130042° c₂₀₀₀
+    let obliquity = (23.439291 - 0.0130042 * c2000).to_radians();
+
+    // r☉ = 1.000140612 - 0.016708617 cos M☉ - 0.000139589 cos 2 M☉ (AU)
+    let distance =
+        (1.000140612 - 0.016708617 * mean_anomaly.cos() - 0.000139589 * (2.0 * mean_anomaly).cos())
+            * ASTRONOMICAL_UNIT;
+    [
+        distance * ecliptic_longitude.cos(),
+        distance * obliquity.cos() * ecliptic_longitude.sin(),
+        distance * obliquity.sin() * ecliptic_longitude.sin(),
+    ]
+}
+
+/// Returns the approximate position of the Moon in the True Equator, Mean Equinox (TEME) reference frame in km
+///
+/// The truncated lunar series of Montenbruck and Gill (accurate to a few arcminutes) is used,
+/// which is sufficient for illumination and transit predictions.
+/// The difference between the mean-of-date and TEME frames is neglected.
+///
+/// # Arguments
+///
+/// * `epoch` - Years since UTC 1 January 2000 12h00
+///
+/// # Example
+///
+/// ```
+/// let moon_position = sgp4::moon_position(sgp4::julian_years_since_j2000(
+///     &chrono::NaiveDate::from_ymd_opt(2020, 7, 12)
+///         .unwrap()
+///         .and_hms_opt(21, 16, 1)
+///         .unwrap(),
+/// ));
+/// let distance = moon_position.iter().map(|x| x.powi(2)).sum::<f64>().sqrt();
+/// assert!(distance > 356000.0 && distance < 407000.0);
+/// ```
+pub fn moon_position(epoch: f64) -> [f64; 3] {
+    // c₂₀₀₀ = y₂₀₀₀ / 100
+    let c2000 = epoch / 100.0;
+
+    // L₀ = 218.31617° + 481267.88088° c₂₀₀₀
+    let mean_longitude = (218.31617 + 481267.88088 * c2000).to_radians();
+
+    // l = 134.96292° + 477198.86753° c₂₀₀₀
+    let mean_anomaly = (134.96292 + 477198.86753 * c2000).to_radians();
+
+    // l' = 357.52543° + 35999.04944° c₂₀₀₀
+    let solar_mean_anomaly = (357.52543 + 35999.04944 * c2000).to_radians();
+
+    // F = 93.27283° + 483202.01873° c₂₀₀₀
+    let argument_of_latitude = (93.27283 + 483202.01873 * c2000).to_radians();
+
+    // D = 297.85027° + 445267.11135° c₂₀₀₀
+    let elongation = (297.85027 + 445267.11135 * c2000).to_radians();
+
+    // 1″ in rad
+    let arcsecond = (1.0_f64 / 3600.0).to_radians();
+
+    // λ = L₀ + 22640″ sin l + 769″ sin 2l - 4586″ sin(l - 2D) + 2370″ sin 2D - 668″ sin l'
+    //     - 412″ sin 2F - 212″ sin(2l - 2D) - 206″ sin(l + l' - 2D) + 192″ sin(l + 2D)
+    //     - 165″ sin(l' - 2D) + 148″ sin(l - l') - 125″ sin D - 110″ sin(l + l') - 55″ sin(2F - 2D)
+    let ecliptic_longitude = mean_longitude
+        + arcsecond
+            * (22640.0 * mean_anomaly.sin() + 769.0 * (2.0 * mean_anomaly).sin()
+                - 4586.0 * (mean_anomaly - 2.0 * elongation).sin()
+                + 2370.0 * (2.0 * elongation).sin()
+                - 668.0 * solar_mean_anomaly.sin()
+                - 412.0 * (2.0 * argument_of_latitude).sin()
+                - 212.0 * (2.0 * mean_anomaly - 2.0 * elongation).sin()
+                - 206.0 * (mean_anomaly + solar_mean_anomaly - 2.0 * elongation).sin()
+                + 192.0 * (mean_anomaly + 2.0 * elongation).sin()
+                - 165.0 * (solar_mean_anomaly - 2.0 * elongation).sin()
+                + 148.0 * (mean_anomaly - solar_mean_anomaly).sin()
+                - 125.0 * elongation.sin()
+                - 110.0 * (mean_anomaly + solar_mean_anomaly).sin()
+                - 55.0 * (2.0 * argument_of_latitude - 2.0 * elongation).sin());
+
+    // β = 18520″ sin(F + λ - L₀ + 412″ sin 2F + 541″ sin l') - 526″ sin(F - 2D)
+    //     + 44″ sin(l + F - 2D) - 31″ sin(-l + F - 2D) - 25″ sin(-2l + F)
+    //     - 23″ sin(l' + F - 2D) + 21″ sin(-l + F) + 11″ sin(-l' + F - 2D)
+    let ecliptic_latitude = arcsecond
+        * (18520.0
+            * (argument_of_latitude + ecliptic_longitude - mean_longitude
+                + arcsecond
+                    * (412.0 * (2.0 * argument_of_latitude).sin()
+                        + 541.0 * solar_mean_anomaly.sin()))
+            .sin()
+            - 526.0 * (argument_of_latitude - 2.0 * elongation).sin()
+            + 44.0 * (mean_anomaly + argument_of_latitude - 2.0 * elongation).sin()
+            - 31.0 * (-mean_anomaly + argument_of_latitude - 2.0 * elongation).sin()
+            - 25.0 * (-2.0 * mean_anomaly + argument_of_latitude).sin()
+            - 23.0 * (solar_mean_anomaly + argument_of_latitude - 2.0 * elongation).sin()
+            + 21.0 * (-mean_anomaly + argument_of_latitude).sin()
+            + 11.0 * (-solar_mean_anomaly + argument_of_latitude - 2.0 * elongation).sin());
+
+    // r = 385000 - 20905 cos l - 3699 cos(2D - l) - 2956 cos 2D - 570 cos 2l
+    //     + 246 cos(2l - 2D) - 205 cos(l' - 2D) - 171 cos(l + 2D) - 152 cos(l + l' - 2D) (km)
+    let distance = 385000.0
+        - 20905.0 * mean_anomaly.cos()
+        - 3699.0 * (2.0 * elongation - mean_anomaly).cos()
+        - 2956.0 * (2.0 * elongation).cos()
+        - 570.0 * (2.0 * mean_anomaly).cos()
+        + 246.0 * (2.0 * mean_anomaly - 2.0 * elongation).cos()
+        - 205.0 * (solar_mean_anomaly - 2.0 * elongation).cos()
+        - 171.0 * (mean_anomaly + 2.0 * elongation).cos()
+        - 152.0 * (mean_anomaly + solar_mean_anomaly - 2.0 * elongation).cos();
+
+    // ε = 23.439291° - 0.0130042° c₂₀₀₀
+    let obliquity = (23.439291 - 0.0130042 * c2000).to_radians();
+    let (longitude_sine, longitude_cosine) = ecliptic_longitude.sin_cos();
+    let (latitude_sine, latitude_cosine) = ecliptic_latitude.sin_cos();
+    let (obliquity_sine, obliquity_cosine) = obliquity.sin_cos();
+    [
+        distance * latitude_cosine * longitude_cosine,
+        distance
+            * (obliquity_cosine * latitude_cosine * longitude_sine
+                - obliquity_sine * latitude_sine),
+        distance
+            * (obliquity_sine * latitude_cosine * longitude_sine
+                + obliquity_cosine * latitude_sine),
+    ]
+}
@@ -0,0 +1,224 @@
+use crate::tle::MinutesSinceEpoch;
+
+/// Direction of a zero crossing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// The function goes from negative to positive values
+    Increasing,
+
+    /// The function goes from positive to negative values
+    Decreasing,
+}
+
+/// A zero crossing of a scalar function of time
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crossing {
+    /// Time of the crossing in minutes since epoch
+    pub t: MinutesSinceEpoch,
+
+    /// Sign change direction
+    pub direction: Direction,
+}
+
+// refines a bracketed sign change by bisection
+// the function must have opposite signs at start and end
+#[cfg(feature = "alloc")]
+pub(crate) fn bisect<E>(
+    mut start: f64,
+    mut end: f64,
+    start_is_negative: bool,
+    tolerance: f64,
+    function: &mut impl FnMut(MinutesSinceEpoch) -> core::result::Result<f64, E>,
+) -> core::result::Result<f64, E> {
+    while end - start > tolerance {
+        let middle = 0.5 * (start + end);
+        // the tolerance is below the resolution of t
+        if middle <= start || middle >= end {
+            break;
+        }
+        if (function(MinutesSinceEpoch(middle))? < 0.0) == start_is_negative {
+            start = middle;
+        } else {
+            end = middle;
+        }
+    }
+    Ok(0.5 * (start + end))
+}
+
+/// Finds the times at which a function of time changes sign
+///
+/// The function is sampled with a fixed step, and each sign change is refined by bisection.
+/// The step must be small enough to ensure that the function changes sign at most once per step,
+/// otherwise some crossings are missed.
+///
+/// This is the building block of the crate's event detectors and can be used to implement custom events.
+///
+/// # Arguments
+///
+/// * `start` - The beginning of the search window in minutes since epoch
+/// * `end` - The end of the search window in minutes since epoch
+/// * `step` - The sampling step in minutes (strictly positive)
+/// * `tolerance` - The maximum error on the crossing times in minutes (strictly positive)
+/// * `function` - The function whose zero crossings are searched
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let constants = sgp4::Constants::from_elements(
+///     &sgp4::Elements::from_tle(
+///         Some("ISS (ZARYA)".to_owned()),
+///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+///     )?,
+/// )?;
+/// // times at which the satellite crosses the 6795 km geocentric distance sphere
+/// let crossings = sgp4::find_crossings(
+///     sgp4::MinutesSinceEpoch(0.0),
+///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+///     1.0,
+///     1e-6,
+///     |t| {
+///         constants.propagate(t).map(|prediction| {
+///             prediction.position.iter().map(|x| x.powi(2)).sum::<f64>().sqrt() - 6795.0
+///         })
+///     },
+/// )?;
+///
+/// // the ISS completes 15.5 orbits per day and crosses the sphere twice per orbit
+/// assert_eq!(crossings.len(), 32);
+/// for pair in crossings.windows(2) {
+///     assert_ne!(pair[0].direction, pair[1].direction);
+/// }
+/// let prediction = constants.propagate(crossings[0].t)?;
+/// let r = prediction.position.iter().map(|x| x.powi(2)).sum::<f64>().sqrt();
+/// assert!((r - 6795.0).abs() < 1e-3);
+/// #     Ok(())
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn find_crossings<E>(
+    start: MinutesSinceEpoch,
+    end: MinutesSinceEpoch,
+    step: f64,
+    tolerance: f64,
+    mut function: impl FnMut(MinutesSinceEpoch) -> core::result::Result<f64, E>,
+) -> core::result::Result<alloc::vec::Vec<Crossing>, E> {
+    assert!(step > 0.0, "step must be strictly positive");
+    assert!(tolerance > 0.0, "tolerance must be strictly positive");
+    let mut crossings = alloc::vec::Vec::new();
+    if end.0 < start.0 {
+        return Ok(crossings);
+    }
+    let mut previous_t = start.0;
+    let mut previous_is_negative = function(start)? < 0.0;
+    let mut index = 0.0;
+    while previous_t < end.0 {
+        // samples are computed from the start rather than accumulated,
+        // so that steps smaller than the resolution of t still advance
+        index += 1.0;
+        let t = (start.0 + index * step).min(end.0);
+        if t <= previous_t {
+            continue;
+        }
+        let is_negative = function(MinutesSinceEpoch(t))? < 0.0;
+        if is_negative != previous_is_negative {
+            crossings.push(Crossing {
+                t: MinutesSinceEpoch(bisect(
+                    previous_t,
+                    t,
+                    previous_is_negative,
+                    tolerance,
+                    &mut function,
+                )?),
+                direction: if previous_is_negative {
+                    Direction::Increasing
+                } else {
+                    Direction::Decreasing
+                },
+            });
+        }
+        previous_t = t;
+        previous_is_negative = is_negative;
+    }
+    Ok(crossings)
+}
+
+// interval bounds, None if the interval extends beyond the window
+#[cfg(feature = "alloc")]
+pub(crate) type Interval = (Option<MinutesSinceEpoch>, Option<MinutesSinceEpoch>);
+
+// finds the intervals during which a function of time is positive
+#[cfg(feature = "alloc")]
+pub(crate) fn positive_intervals<E>(
+    start: MinutesSinceEpoch,
+    end: MinutesSinceEpoch,
+    step: f64,
+    tolerance: f64,
+    mut function: impl FnMut(MinutesSinceEpoch) -> core::result::Result<f64, E>,
+) -> core::result::Result<alloc::vec::Vec<Interval>, E> {
+    let mut intervals = alloc::vec::Vec::new();
+    if end.0 < start.0 {
+        return Ok(intervals);
+    }
+    let mut positive = function(start)? >= 0.0;
+    let mut interval_start = None;
+    for crossing in find_crossings(start, end, step, tolerance, &mut function)? {
+        match crossing.direction {
+            Direction::Increasing => {
+                interval_start = Some(crossing.t);
+                positive = true;
+            }
+            Direction::Decreasing => {
+                intervals.push((interval_start.take(), Some(crossing.t)));
+                positive = false;
+            }
+        }
+    }
+    if positive {
+        intervals.push((interval_start, None));
+    }
+    Ok(intervals)
+}
+
+// finds the maximum of a unimodal function of time with a golden-section search
+#[cfg(feature = "alloc")]
+pub(crate) fn maximize<E>(
+    mut start: f64,
+    mut end: f64,
+    tolerance: f64,
+    mut function: impl FnMut(MinutesSinceEpoch) -> core::result::Result<f64, E>,
+) -> core::result::Result<(MinutesSinceEpoch, f64), E> {
+    // φ⁻¹ = (5¹ᐟ² - 1) / 2
+    const INVERSE_GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+    let mut left = end - INVERSE_GOLDEN_RATIO * (end - start);
+    let mut right = start + INVERSE_GOLDEN_RATIO * (end - start);
+    let mut left_value = function(MinutesSinceEpoch(left))?;
+    let mut right_value = function(MinutesSinceEpoch(right))?;
+    while end - start > tolerance {
+        let width = end - start;
+        if left_value < right_value {
+            start = left;
+            left = right;
+            left_value = right_value;
+            right = start + INVERSE_GOLDEN_RATIO * (end - start);
+            right_value = function(MinutesSinceEpoch(right))?;
+        } else {
+            end = right;
+            right = left;
+            right_value = left_value;
+            left = end - INVERSE_GOLDEN_RATIO * (end - start);
+            left_value = function(MinutesSinceEpoch(left))?;
+        }
+        // the tolerance is below the resolution of t
+        if end - start >= width {
+            break;
+        }
+    }
+    let t = MinutesSinceEpoch(0.5 * (start + end));
+    let value = function(t)?;
+    Ok((t, value))
+}
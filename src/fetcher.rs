@@ -0,0 +1,220 @@
+use crate::client;
+use crate::tle;
+
+// Celestrak updates GP data every two hours and asks clients not to download a query more often
+const CELESTRAK_MINIMUM_INTERVAL: core::time::Duration =
+    core::time::Duration::from_secs(2 * 60 * 60);
+
+// validators and download time of a cached response, stored next to the body
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct CacheMetadata {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    checked_at: u64,
+}
+
+/// Origin of the elements returned by [CachingFetcher](struct.CachingFetcher.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// The cached response is recent enough, no request was sent
+    Fresh,
+
+    /// The server confirmed that the cached response is up to date (HTTP 304)
+    NotModified,
+
+    /// A new response was downloaded and cached
+    Downloaded,
+}
+
+/// Represents an error raised by a caching fetcher
+#[derive(Debug)]
+pub enum FetcherError<E> {
+    /// The request failed or the response could not be parsed
+    Client(client::ClientError<E>),
+
+    /// The cache directory could not be read or written
+    Io(std::io::Error),
+}
+
+impl<E> From<client::ClientError<E>> for FetcherError<E> {
+    fn from(value: client::ClientError<E>) -> Self {
+        FetcherError::Client(value)
+    }
+}
+
+impl<E> From<std::io::Error> for FetcherError<E> {
+    fn from(value: std::io::Error) -> Self {
+        FetcherError::Io(value)
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for FetcherError<E> {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FetcherError::Client(error) => error.fmt(formatter),
+            FetcherError::Io(error) => formatter.write_fmt(format_args!("cache error: {}", error)),
+        }
+    }
+}
+
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for FetcherError<E> {}
+
+// seconds since the Unix epoch
+fn unix_seconds() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+// reads a file, None if it does not exist
+fn read_optional(path: &std::path::Path) -> std::io::Result<Option<Vec<u8>>> {
+    match std::fs::read(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Downloads Celestrak GP data politely, with an on-disk cache
+///
+/// Each query's response is stored in the cache directory with its `ETag` and `Last-Modified` headers.
+/// The fetcher returns the cached elements without sending a request if the query was checked
+/// less than the minimum interval ago (two hours by default, Celestrak's update period).
+/// Otherwise, it sends a conditional request and reuses the cached response if the server replies
+/// with 304 Not Modified. Periodic refreshes therefore download each query at most once per update.
+///
+/// Cache files are read and written with blocking calls, which is acceptable for typical catalog sizes.
+///
+/// # Example
+///
+/// ```no_run
+/// # struct Transport;
+/// # impl sgp4::HttpClient for Transport {
+/// #     type Error = std::convert::Infallible;
+/// #     async fn get(&self, _: &str, _: &[(&str, &str)]) -> Result<sgp4::HttpResponse, Self::Error> {
+/// #         unimplemented!()
+/// #     }
+/// #     async fn post_form(&self, _: &str, _: &[(&str, &str)]) -> Result<sgp4::HttpResponse, Self::Error> {
+/// #         unimplemented!()
+/// #     }
+/// # }
+/// # async fn refresh() -> anyhow::Result<()> {
+/// let fetcher = sgp4::CachingFetcher::new(Transport, "gp-cache");
+/// let (elements_vec, status) = fetcher
+///     .celestrak(sgp4::CelestrakQuery::Group("stations"))
+///     .await?;
+/// println!("{} satellites ({:?})", elements_vec.len(), status);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachingFetcher<C: client::HttpClient> {
+    http_client: C,
+    directory: std::path::PathBuf,
+    minimum_interval: core::time::Duration,
+}
+
+impl<C: client::HttpClient> CachingFetcher<C> {
+    /// Creates a fetcher that stores responses in the given directory
+    ///
+    /// The directory is created on the first download if it does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The transport used to send requests
+    /// * `directory` - The cache directory
+    pub fn new<P: Into<std::path::PathBuf>>(http_client: C, directory: P) -> Self {
+        CachingFetcher {
+            http_client,
+            directory: directory.into(),
+            minimum_interval: CELESTRAK_MINIMUM_INTERVAL,
+        }
+    }
+
+    /// Changes the minimum time between two requests for the same query
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum_interval` - The minimum interval, shorter values than two hours are discouraged by Celestrak
+    pub fn with_minimum_interval(mut self, minimum_interval: core::time::Duration) -> Self {
+        self.minimum_interval = minimum_interval;
+        self
+    }
+
+    /// Returns the HTTP transport
+    pub fn http_client(&self) -> &C {
+        &self.http_client
+    }
+
+    /// Returns the elements that match a Celestrak query and whether they were downloaded
+    ///
+    /// An empty list is returned if no objects match the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The group, catalog number, international designator or name to retrieve
+    pub async fn celestrak(
+        &self,
+        query: client::CelestrakQuery<'_>,
+    ) -> core::result::Result<(Vec<tle::Elements>, CacheStatus), FetcherError<C::Error>> {
+        let url = client::celestrak_url(query);
+        // the percent-encoded query (for example "GROUP-stations") is a valid file name
+        let name = url[url.find('?').map_or(0, |index| index + 1)..]
+            .trim_end_matches("&FORMAT=json")
+            .replace('=', "-");
+        let body_path = self.directory.join(format!("{}.json", name));
+        let metadata_path = self.directory.join(format!("{}.metadata.json", name));
+        let cached_body = read_optional(&body_path)?;
+        let mut metadata: CacheMetadata = match (&cached_body, read_optional(&metadata_path)?) {
+            (Some(_), Some(metadata)) => serde_json::from_slice(&metadata).unwrap_or_default(),
+            _ => CacheMetadata::default(),
+        };
+        let now = unix_seconds();
+        if let Some(body) = &cached_body {
+            if now.saturating_sub(metadata.checked_at) < self.minimum_interval.as_secs() {
+                return Ok((client::parse_body(body)?, CacheStatus::Fresh));
+            }
+        }
+        let mut headers = Vec::new();
+        if cached_body.is_some() {
+            if let Some(etag) = &metadata.etag {
+                headers.push(("If-None-Match", etag.as_str()));
+            }
+            if let Some(last_modified) = &metadata.last_modified {
+                headers.push(("If-Modified-Since", last_modified.as_str()));
+            }
+        }
+        let response = self
+            .http_client
+            .get(&url, &headers)
+            .await
+            .map_err(client::ClientError::Http)?;
+        metadata.checked_at = now;
+        if response.status == 304 {
+            if let Some(body) = cached_body {
+                write_metadata(&metadata_path, &metadata)?;
+                return Ok((client::parse_body(&body)?, CacheStatus::NotModified));
+            }
+        }
+        let elements_vec = client::parse_response(&response)?;
+        metadata.etag = response.header("ETag").map(str::to_owned);
+        metadata.last_modified = response.header("Last-Modified").map(str::to_owned);
+        std::fs::create_dir_all(&self.directory)?;
+        std::fs::write(&body_path, &response.body)?;
+        write_metadata(&metadata_path, &metadata)?;
+        Ok((elements_vec, CacheStatus::Downloaded))
+    }
+}
+
+// writes cache metadata as JSON
+fn write_metadata<E>(
+    path: &std::path::Path,
+    metadata: &CacheMetadata,
+) -> core::result::Result<(), FetcherError<E>> {
+    std::fs::write(
+        path,
+        serde_json::to_vec(metadata).map_err(client::ClientError::Json)?,
+    )?;
+    Ok(())
+}
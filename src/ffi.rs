@@ -0,0 +1,328 @@
+use crate::norad_id;
+use crate::observer;
+use crate::propagator;
+use crate::tle;
+
+// capacity of the zero-padded object name in bytes
+const OBJECT_NAME_CAPACITY: usize = 32;
+
+// capacity of the zero-padded international designator in bytes
+const INTERNATIONAL_DESIGNATOR_CAPACITY: usize = 16;
+
+/// C-compatible mirror of [Prediction](struct.Prediction.html)
+///
+/// The layout of `#[repr(C)]` structs does not depend on the crate's internal types,
+/// hence they can be shared with other languages or through shared memory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct CPrediction {
+    /// The three position components (x, y, z) in km, in the TEME reference frame
+    pub position: [f64; 3],
+
+    /// The three velocity components (x, y, z) in km.s⁻¹, in the TEME reference frame
+    pub velocity: [f64; 3],
+}
+
+impl From<&propagator::Prediction> for CPrediction {
+    fn from(value: &propagator::Prediction) -> Self {
+        CPrediction {
+            position: value.position,
+            velocity: value.velocity,
+        }
+    }
+}
+
+impl From<&CPrediction> for propagator::Prediction {
+    fn from(value: &CPrediction) -> Self {
+        propagator::Prediction {
+            position: value.position,
+            velocity: value.velocity,
+        }
+    }
+}
+
+/// C-compatible mirror of [LookAngles](struct.LookAngles.html)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct CLookAngles {
+    /// Azimuth in rad, measured clockwise from the north
+    pub azimuth: f64,
+
+    /// Elevation in rad
+    pub elevation: f64,
+
+    /// Range in km
+    pub range: f64,
+
+    /// Range rate in km.s⁻¹
+    pub range_rate: f64,
+}
+
+impl From<&observer::LookAngles> for CLookAngles {
+    fn from(value: &observer::LookAngles) -> Self {
+        CLookAngles {
+            azimuth: value.azimuth,
+            elevation: value.elevation,
+            range: value.range,
+            range_rate: value.range_rate,
+        }
+    }
+}
+
+impl From<&CLookAngles> for observer::LookAngles {
+    fn from(value: &CLookAngles) -> Self {
+        observer::LookAngles {
+            azimuth: value.azimuth,
+            elevation: value.elevation,
+            range: value.range,
+            range_rate: value.range_rate,
+        }
+    }
+}
+
+/// C-compatible flattened mirror of [Elements](struct.Elements.html)
+///
+/// The epoch is stored as seconds and nanoseconds since the Unix epoch (UTC, without leap seconds).
+/// The object name and the international designator are UTF-8 strings padded with zeros,
+/// longer strings are truncated (at a character boundary) when converting from `Elements`.
+/// An empty string is converted to `None`, and both strings are ignored if neither the `alloc` nor the `heapless` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let c_elements = sgp4::CElements::from(&elements);
+/// let round_trip = sgp4::Elements::try_from(&c_elements)?;
+/// assert_eq!(round_trip.datetime, elements.datetime);
+/// assert_eq!(round_trip.object_name.as_deref(), Some("ISS (ZARYA)"));
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct CElements {
+    /// The satellite catalog number
+    pub norad_id: u64,
+
+    /// Whole seconds between the Unix epoch and the elements' epoch
+    pub epoch_seconds: i64,
+
+    /// Sub-second part of the elements' epoch in ns, in the range [0, 10⁹[
+    pub epoch_nanoseconds: u32,
+
+    /// The elements classification as an ASCII character (`U`, `C` or `S`)
+    pub classification: u8,
+
+    /// NORAD internal use, always 0 in distributed data
+    pub ephemeris_type: u8,
+
+    /// Time derivative of the mean motion divided by two in rev.day⁻²
+    pub mean_motion_dot: f64,
+
+    /// Second time derivative of the mean motion divided by six in rev.day⁻³
+    pub mean_motion_ddot: f64,
+
+    /// Radiation pressure coefficient in earth radii⁻¹ (B*)
+    pub drag_term: f64,
+
+    /// The element set number
+    pub element_set_number: u64,
+
+    /// Angle between the equator and the orbit plane in deg
+    pub inclination: f64,
+
+    /// Right ascension of the ascending node in deg
+    pub right_ascension: f64,
+
+    /// The shape of the orbit
+    pub eccentricity: f64,
+
+    /// Argument of perigee in deg
+    pub argument_of_perigee: f64,
+
+    /// Mean anomaly in deg
+    pub mean_anomaly: f64,
+
+    /// Mean number of orbits per day (Kozai convention)
+    pub mean_motion: f64,
+
+    /// The orbit number at epoch
+    pub revolution_number: u64,
+
+    /// The object name, UTF-8 padded with zeros
+    pub object_name: [u8; OBJECT_NAME_CAPACITY],
+
+    /// The international designator, UTF-8 padded with zeros
+    pub international_designator: [u8; INTERNATIONAL_DESIGNATOR_CAPACITY],
+}
+
+/// Represents an error raised while converting [CElements](struct.CElements.html) to elements
+#[derive(Debug, Clone)]
+pub enum CElementsError {
+    /// The epoch cannot be represented as a datetime
+    InvalidEpoch,
+
+    /// The catalog number has more than 9 digits
+    InvalidNoradId(u64),
+
+    /// The classification character is not `U`, `C` or `S`
+    UnknownClassification(u8),
+
+    /// The object name or the international designator is not valid UTF-8
+    InvalidUtf8,
+}
+
+impl core::fmt::Display for CElementsError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CElementsError::InvalidEpoch => formatter.write_str("the epoch is out of range"),
+            CElementsError::InvalidNoradId(norad_id) => formatter.write_fmt(format_args!(
+                "the catalog number {} has more than 9 digits",
+                norad_id
+            )),
+            CElementsError::UnknownClassification(classification) => formatter.write_fmt(
+                format_args!("unknown classification character {}", classification),
+            ),
+            CElementsError::InvalidUtf8 => formatter.write_str("a string field is not valid UTF-8"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CElementsError {}
+
+// copies a string into a zero-padded buffer, truncated at a character boundary
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+fn to_buffer<const N: usize>(string: Option<&str>) -> [u8; N] {
+    let mut buffer = [0; N];
+    if let Some(string) = string {
+        let mut length = string.len().min(N);
+        while !string.is_char_boundary(length) {
+            length -= 1;
+        }
+        buffer[..length].copy_from_slice(&string.as_bytes()[..length]);
+    }
+    buffer
+}
+
+// reads a zero-padded buffer (None if empty)
+#[cfg(feature = "alloc")]
+fn from_buffer(
+    buffer: &[u8],
+) -> core::result::Result<Option<alloc::string::String>, CElementsError> {
+    let length = buffer
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(buffer.len());
+    if length == 0 {
+        Ok(None)
+    } else {
+        core::str::from_utf8(&buffer[..length])
+            .map(|string| Some(alloc::string::String::from(string)))
+            .map_err(|_| CElementsError::InvalidUtf8)
+    }
+}
+
+// reads a zero-padded buffer into a fixed-capacity string (None if empty)
+#[cfg(all(feature = "heapless", not(feature = "alloc")))]
+fn from_buffer<const N: usize>(
+    buffer: &[u8; N],
+) -> core::result::Result<Option<heapless::String<N>>, CElementsError> {
+    let length = buffer
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(buffer.len());
+    if length == 0 {
+        Ok(None)
+    } else {
+        let mut string = heapless::String::new();
+        string
+            .push_str(
+                core::str::from_utf8(&buffer[..length]).map_err(|_| CElementsError::InvalidUtf8)?,
+            )
+            .map_err(|_| CElementsError::InvalidUtf8)?;
+        Ok(Some(string))
+    }
+}
+
+impl From<&tle::Elements> for CElements {
+    fn from(value: &tle::Elements) -> Self {
+        let datetime = value.datetime.and_utc();
+        CElements {
+            norad_id: value.norad_id.get(),
+            epoch_seconds: datetime.timestamp(),
+            epoch_nanoseconds: datetime.timestamp_subsec_nanos(),
+            classification: match value.classification {
+                tle::Classification::Unclassified => b'U',
+                tle::Classification::Classified => b'C',
+                tle::Classification::Secret => b'S',
+            },
+            ephemeris_type: value.ephemeris_type,
+            mean_motion_dot: value.mean_motion_dot,
+            mean_motion_ddot: value.mean_motion_ddot,
+            drag_term: value.drag_term,
+            element_set_number: value.element_set_number,
+            inclination: value.inclination,
+            right_ascension: value.right_ascension,
+            eccentricity: value.eccentricity,
+            argument_of_perigee: value.argument_of_perigee,
+            mean_anomaly: value.mean_anomaly,
+            mean_motion: value.mean_motion,
+            revolution_number: value.revolution_number,
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            object_name: to_buffer(value.object_name.as_deref()),
+            #[cfg(not(any(feature = "alloc", feature = "heapless")))]
+            object_name: [0; OBJECT_NAME_CAPACITY],
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            international_designator: to_buffer(value.international_designator.as_deref()),
+            #[cfg(not(any(feature = "alloc", feature = "heapless")))]
+            international_designator: [0; INTERNATIONAL_DESIGNATOR_CAPACITY],
+        }
+    }
+}
+
+impl TryFrom<&CElements> for tle::Elements {
+    type Error = CElementsError;
+
+    fn try_from(value: &CElements) -> core::result::Result<Self, Self::Error> {
+        Ok(tle::Elements {
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            object_name: from_buffer(&value.object_name)?,
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            international_designator: from_buffer(&value.international_designator)?,
+            norad_id: norad_id::NoradId::new(value.norad_id)
+                .map_err(|_| CElementsError::InvalidNoradId(value.norad_id))?,
+            classification: match value.classification {
+                b'U' => tle::Classification::Unclassified,
+                b'C' => tle::Classification::Classified,
+                b'S' => tle::Classification::Secret,
+                classification => {
+                    return Err(CElementsError::UnknownClassification(classification))
+                }
+            },
+            datetime: chrono::DateTime::from_timestamp(
+                value.epoch_seconds,
+                value.epoch_nanoseconds,
+            )
+            .ok_or(CElementsError::InvalidEpoch)?
+            .naive_utc(),
+            mean_motion_dot: value.mean_motion_dot,
+            mean_motion_ddot: value.mean_motion_ddot,
+            drag_term: value.drag_term,
+            element_set_number: value.element_set_number,
+            inclination: value.inclination,
+            right_ascension: value.right_ascension,
+            eccentricity: value.eccentricity,
+            argument_of_perigee: value.argument_of_perigee,
+            mean_anomaly: value.mean_anomaly,
+            mean_motion: value.mean_motion,
+            revolution_number: value.revolution_number,
+            ephemeris_type: value.ephemeris_type,
+        })
+    }
+}
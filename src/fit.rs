@@ -0,0 +1,428 @@
+use crate::gp;
+use crate::model;
+use crate::norad_id;
+use crate::propagator;
+use crate::tle;
+use crate::ElementsError;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// number of fit parameters (Kozai mean motion, e sin ϖ, e cos ϖ, tan(I / 2) sin Ω, tan(I / 2) cos Ω, λ, B*)
+pub(crate) const PARAMETERS: usize = 7;
+
+// finite difference steps (relative for the mean motion, absolute otherwise)
+const STEPS: [f64; PARAMETERS] = [1e-7, 1e-7, 1e-7, 1e-7, 1e-7, 1e-7, 1e-6];
+
+// largest number of damping increases per iteration
+const MAXIMUM_DAMPING_INCREASES: usize = 16;
+
+/// A position and velocity at a given time, for example from a precise ephemeris
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateVector {
+    /// The state's time (UTC)
+    pub datetime: chrono::NaiveDateTime,
+
+    /// The three position components (x, y, z) in km, in the TEME reference frame
+    pub position: [f64; 3],
+
+    /// The three velocity components (x, y, z) in km.s⁻¹, in the TEME reference frame
+    pub velocity: [f64; 3],
+}
+
+/// Settings of the iterative elements fit
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FitOptions {
+    /// Whether the drag term (B*) is fitted along with the orbital elements
+    pub fit_drag_term: bool,
+
+    /// The drag term (B*) in earth radii⁻¹, used as the initial value if it is fitted and as a constant otherwise
+    pub drag_term: f64,
+
+    /// Largest number of iterations
+    pub maximum_iterations: usize,
+
+    /// Relative decrease of the sum of squared residuals below which the iterations stop
+    pub tolerance: f64,
+}
+
+impl Default for FitOptions {
+    /// Returns options that fit the orbital elements only, with a zero drag term
+    fn default() -> Self {
+        FitOptions {
+            fit_drag_term: false,
+            drag_term: 0.0,
+            maximum_iterations: 50,
+            tolerance: 1e-10,
+        }
+    }
+}
+
+/// Elements obtained by fitting SGP4 to reference data
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementsFit {
+    /// The fitted elements
+    ///
+    /// The identification fields (NORAD ID, names, element set and revolution numbers) are zero or `None`
+    /// and can be set by the caller.
+    pub elements: tle::Elements,
+
+    /// Root mean square of the weighted residuals
+    pub rms: f64,
+
+    /// Number of iterations performed
+    pub iterations: usize,
+}
+
+/// Represents an error raised while fitting elements
+#[derive(Debug, Clone)]
+pub enum FitError {
+    /// There are fewer scalar measurements than fitted parameters
+    NotEnoughMeasurements,
+
+    /// The normal equations are singular, the measurements do not constrain all the parameters
+    SingularNormalMatrix,
+
+    /// The initial elements cannot be used to initialize a propagator
+    Elements(ElementsError),
+
+    /// A measurement time is too far from the epoch
+    Datetime(tle::DatetimeToMinutesSinceEpochError),
+
+    /// The elements cannot be propagated to a measurement time
+    Propagation(gp::Error),
+}
+
+impl core::fmt::Display for FitError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FitError::NotEnoughMeasurements => {
+                formatter.write_str("There are fewer measurements than fitted parameters")
+            }
+            FitError::SingularNormalMatrix => {
+                formatter.write_str("The measurements do not constrain all the fitted parameters")
+            }
+            FitError::Elements(error) => error.fmt(formatter),
+            FitError::Datetime(error) => error.fmt(formatter),
+            FitError::Propagation(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl From<ElementsError> for FitError {
+    fn from(value: ElementsError) -> Self {
+        Self::Elements(value)
+    }
+}
+
+impl From<tle::DatetimeToMinutesSinceEpochError> for FitError {
+    fn from(value: tle::DatetimeToMinutesSinceEpochError) -> Self {
+        Self::Datetime(value)
+    }
+}
+
+impl From<gp::Error> for FitError {
+    fn from(value: gp::Error) -> Self {
+        Self::Propagation(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FitError {}
+
+// solution of the linear system A x = b (Gaussian elimination with partial pivoting)
+fn solve<const N: usize>(mut matrix: [[f64; N]; N], mut vector: [f64; N]) -> Option<[f64; N]> {
+    for column in 0..N {
+        let pivot = (column..N).max_by(|a, b| {
+            matrix[*a][column]
+                .abs()
+                .partial_cmp(&matrix[*b][column].abs())
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })?;
+        if matrix[pivot][column].abs() < f64::MIN_POSITIVE || !matrix[pivot][column].is_finite() {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        vector.swap(column, pivot);
+        let pivot_row = matrix[column];
+        for row in column + 1..N {
+            let factor = matrix[row][column] / pivot_row[column];
+            for (value, pivot_value) in matrix[row].iter_mut().zip(pivot_row.iter()).skip(column) {
+                *value -= factor * pivot_value;
+            }
+            vector[row] -= factor * vector[column];
+        }
+    }
+    let mut solution = [0.0; N];
+    for row in (0..N).rev() {
+        let mut value = vector[row];
+        for index in row + 1..N {
+            value -= matrix[row][index] * solution[index];
+        }
+        solution[row] = value / matrix[row][row];
+    }
+    Some(solution)
+}
+
+// fit parameters from Keplerian elements in rad (the mean motion is the Kozai mean motion in rad.min⁻¹)
+pub(crate) fn parameters(orbit: &propagator::Orbit, drag_term: f64) -> [f64; PARAMETERS] {
+    // ϖ = Ω + ω, λ = ϖ + M
+    let longitude_of_perigee = orbit.right_ascension + orbit.argument_of_perigee;
+    let tan_half_inclination = (orbit.inclination / 2.0).tan();
+    [
+        orbit.mean_motion,
+        orbit.eccentricity * longitude_of_perigee.sin(),
+        orbit.eccentricity * longitude_of_perigee.cos(),
+        tan_half_inclination * orbit.right_ascension.sin(),
+        tan_half_inclination * orbit.right_ascension.cos(),
+        longitude_of_perigee + orbit.mean_anomaly,
+        drag_term,
+    ]
+}
+
+// elements with default identification fields from fit parameters
+pub(crate) fn elements(
+    parameters: &[f64; PARAMETERS],
+    datetime: chrono::NaiveDateTime,
+) -> tle::Elements {
+    // ϖ = tan⁻¹(e sin ϖ / e cos ϖ), Ω = tan⁻¹(tan(I / 2) sin Ω / tan(I / 2) cos Ω)
+    let longitude_of_perigee = parameters[1].atan2(parameters[2]);
+    let right_ascension = parameters[3].atan2(parameters[4]);
+    tle::Elements {
+        object_name: None,
+        international_designator: None,
+        norad_id: norad_id::NoradId::default(),
+        classification: tle::Classification::Unclassified,
+        datetime,
+        mean_motion_dot: 0.0,
+        mean_motion_ddot: 0.0,
+        drag_term: parameters[6],
+        element_set_number: 0,
+
+        // I = 2 tan⁻¹(|tan(I / 2)|)
+        inclination: (2.0 * parameters[3].hypot(parameters[4]).atan()).to_degrees(),
+        right_ascension: model::positive_angle(right_ascension).to_degrees(),
+
+        // e = |(e sin ϖ, e cos ϖ)|
+        eccentricity: parameters[1].hypot(parameters[2]),
+        argument_of_perigee: model::positive_angle(longitude_of_perigee - right_ascension)
+            .to_degrees(),
+        mean_anomaly: model::positive_angle(parameters[5] - longitude_of_perigee).to_degrees(),
+        mean_motion: parameters[0] * (720.0 / core::f64::consts::PI),
+        revolution_number: 0,
+        ephemeris_type: 0,
+    }
+}
+
+// Levenberg-Marquardt minimization of the squared residuals
+// returns the parameters, the sum of squared residuals, the number of residuals and the number of iterations
+pub(crate) fn least_squares<F>(
+    initial_parameters: [f64; PARAMETERS],
+    fit_drag_term: bool,
+    options: &FitOptions,
+    residuals: F,
+) -> core::result::Result<([f64; PARAMETERS], f64, usize, usize), FitError>
+where
+    F: Fn(&[f64; PARAMETERS]) -> core::result::Result<alloc::vec::Vec<f64>, FitError>,
+{
+    let free_parameters = if fit_drag_term {
+        PARAMETERS
+    } else {
+        PARAMETERS - 1
+    };
+    let mut parameters = initial_parameters;
+    let mut current_residuals = residuals(&parameters)?;
+    if current_residuals.len() < free_parameters {
+        return Err(FitError::NotEnoughMeasurements);
+    }
+    let mut cost: f64 = current_residuals
+        .iter()
+        .map(|residual| residual.powi(2))
+        .sum();
+    let mut damping = 1e-3;
+    let mut iterations = 0;
+    while iterations < options.maximum_iterations {
+        iterations += 1;
+
+        // Jᵢⱼ = ∂rᵢ / ∂pⱼ (forward differences)
+        let mut jacobian = alloc::vec::Vec::with_capacity(free_parameters);
+        for index in 0..free_parameters {
+            let step = if index == 0 {
+                STEPS[index] * parameters[index].abs()
+            } else {
+                STEPS[index]
+            };
+            let mut perturbed_parameters = parameters;
+            perturbed_parameters[index] += step;
+            jacobian.push(
+                residuals(&perturbed_parameters)?
+                    .iter()
+                    .zip(current_residuals.iter())
+                    .map(|(perturbed_residual, residual)| (perturbed_residual - residual) / step)
+                    .collect::<alloc::vec::Vec<f64>>(),
+            );
+        }
+
+        // N = Jᵀ J, g = -Jᵀ r
+        let mut normal_matrix = [[0.0; PARAMETERS]; PARAMETERS];
+        let mut gradient = [0.0; PARAMETERS];
+        for row in 0..free_parameters {
+            for column in 0..free_parameters {
+                normal_matrix[row][column] = jacobian[row]
+                    .iter()
+                    .zip(jacobian[column].iter())
+                    .map(|(a, b)| a * b)
+                    .sum();
+            }
+            gradient[row] = -jacobian[row]
+                .iter()
+                .zip(current_residuals.iter())
+                .map(|(a, b)| a * b)
+                .sum::<f64>();
+        }
+        for (index, row) in normal_matrix.iter_mut().enumerate().skip(free_parameters) {
+            row[index] = 1.0;
+        }
+
+        // (N + μ diag(N)) Δp = g
+        let mut improvement = None;
+        for _ in 0..MAXIMUM_DAMPING_INCREASES {
+            let mut damped_matrix = normal_matrix;
+            for (index, row) in damped_matrix.iter_mut().enumerate() {
+                row[index] *= 1.0 + damping;
+            }
+            let delta = solve(damped_matrix, gradient).ok_or(FitError::SingularNormalMatrix)?;
+            let mut candidate_parameters = parameters;
+            for (parameter, delta) in candidate_parameters.iter_mut().zip(delta.iter()) {
+                *parameter += delta;
+            }
+            if let Ok(candidate_residuals) = residuals(&candidate_parameters) {
+                let candidate_cost: f64 = candidate_residuals
+                    .iter()
+                    .map(|residual| residual.powi(2))
+                    .sum();
+                if candidate_cost < cost {
+                    improvement = Some((cost - candidate_cost) / cost);
+                    parameters = candidate_parameters;
+                    current_residuals = candidate_residuals;
+                    cost = candidate_cost;
+                    damping /= 10.0;
+                    break;
+                }
+            }
+            damping *= 10.0;
+        }
+        match improvement {
+            Some(improvement) if improvement >= options.tolerance => (),
+            _ => break,
+        }
+    }
+    Ok((parameters, cost, current_residuals.len(), iterations))
+}
+
+impl ElementsFit {
+    /// Fits SGP4 mean elements to a list of position and velocity vectors
+    ///
+    /// The mean elements are initialized with the osculating elements of the state closest to the epoch
+    /// and refined with a Levenberg-Marquardt differential correction that minimizes the position
+    /// and velocity residuals. Velocity residuals are multiplied by the inverse mean motion
+    /// so that both contributions have comparable magnitudes in km. The fitted elements are
+    /// the SGP4 elements that best reproduce the states over their time span, which is the
+    /// expected input to generate TLEs for a spacecraft from its precise ephemeris.
+    ///
+    /// A single state is enough to fit the orbital elements. Fitting the drag term requires
+    /// states spread over at least several revolutions.
+    ///
+    /// # Arguments
+    ///
+    /// * `states` - The reference positions and velocities in the TEME reference frame
+    /// * `epoch` - The epoch of the fitted elements (UTC)
+    /// * `options` - The fit settings
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::from_elements(&elements)?;
+    /// let mut states = Vec::new();
+    /// for minutes in (0..24 * 60).step_by(10) {
+    ///     let prediction = constants.propagate(sgp4::MinutesSinceEpoch(minutes as f64))?;
+    ///     states.push(sgp4::StateVector {
+    ///         datetime: elements.minutes_since_epoch_to_datetime(&sgp4::MinutesSinceEpoch(minutes as f64))?,
+    ///         position: prediction.position,
+    ///         velocity: prediction.velocity,
+    ///     });
+    /// }
+    /// let fit = sgp4::ElementsFit::from_states(
+    ///     &states,
+    ///     elements.datetime,
+    ///     &sgp4::FitOptions {
+    ///         fit_drag_term: true,
+    ///         ..Default::default()
+    ///     },
+    /// )?;
+    /// assert!(fit.rms < 1e-3);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_states(
+        states: &[StateVector],
+        epoch: chrono::NaiveDateTime,
+        options: &FitOptions,
+    ) -> core::result::Result<ElementsFit, FitError> {
+        let closest_state = states
+            .iter()
+            .min_by_key(|state| (state.datetime - epoch).num_milliseconds().abs())
+            .ok_or(FitError::NotEnoughMeasurements)?;
+        let mut orbit = propagator::Orbit::from_prediction(
+            &model::WGS84,
+            &propagator::Prediction {
+                position: closest_state.position,
+                velocity: closest_state.velocity,
+            },
+        );
+
+        // M(epoch) = M(t) + n (epoch - t)
+        orbit.mean_anomaly +=
+            orbit.mean_motion * ((epoch - closest_state.datetime).num_milliseconds() as f64 / 60e3);
+
+        // velocity residuals are scaled by 1 / n (s)
+        let velocity_scale = 60.0 / orbit.mean_motion;
+        let (parameters, cost, count, iterations) = least_squares(
+            parameters(&orbit, options.drag_term),
+            options.fit_drag_term,
+            options,
+            |parameters| {
+                let candidate = elements(parameters, epoch);
+                let constants = propagator::Constants::from_elements(&candidate)?;
+                let mut residuals = alloc::vec::Vec::with_capacity(states.len() * 6);
+                for state in states {
+                    let prediction = constants
+                        .propagate(candidate.datetime_to_minutes_since_epoch(&state.datetime)?)?;
+                    for index in 0..3 {
+                        residuals.push(state.position[index] - prediction.position[index]);
+                    }
+                    for index in 0..3 {
+                        residuals.push(
+                            (state.velocity[index] - prediction.velocity[index]) * velocity_scale,
+                        );
+                    }
+                }
+                Ok(residuals)
+            },
+        )?;
+        Ok(ElementsFit {
+            elements: elements(&parameters, epoch),
+            rms: (cost / count as f64).sqrt(),
+            iterations,
+        })
+    }
+}
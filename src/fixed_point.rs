@@ -0,0 +1,698 @@
+use crate::gp;
+use crate::propagator;
+use crate::trig;
+
+// number of fractional bits of the Q31.32 format
+const FRACTIONAL_BITS: u32 = 32;
+
+// minutes per day, time polynomials are evaluated in days to keep their coefficients representable
+const MINUTES_PER_DAY: f64 = 1440.0;
+
+// π × 2⁶⁴, used to reduce angles without accumulating the rounding error of π in Q31.32
+const PI_Q64: i128 = 0x3_243F_6A88_85A3_08D3;
+
+// Q31.32 fixed-point number, operations saturate instead of overflowing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Fixed(i64);
+
+impl Fixed {
+    const ZERO: Fixed = Fixed(0);
+    const ONE: Fixed = Fixed(1 << FRACTIONAL_BITS);
+
+    fn from_f64(value: f64) -> Fixed {
+        let scaled = value * (1u64 << FRACTIONAL_BITS) as f64;
+        Fixed(if scaled >= 0.0 {
+            (scaled + 0.5) as i64
+        } else {
+            (scaled - 0.5) as i64
+        })
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << FRACTIONAL_BITS) as f64
+    }
+
+    fn from_i128(value: i128) -> Fixed {
+        Fixed(value.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    fn abs(self) -> Fixed {
+        Fixed(self.0.saturating_abs())
+    }
+
+    fn sqrt(self) -> Fixed {
+        if self.0 <= 0 {
+            Fixed::ZERO
+        } else {
+            Fixed(isqrt((self.0 as u128) << FRACTIONAL_BITS) as i64)
+        }
+    }
+
+    // rounds to the nearest integer
+    fn round(self) -> i64 {
+        self.0.saturating_add(1 << (FRACTIONAL_BITS - 1)) >> FRACTIONAL_BITS
+    }
+}
+
+impl core::ops::Add for Fixed {
+    type Output = Fixed;
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0.saturating_add(other.0))
+    }
+}
+
+impl core::ops::Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0.saturating_sub(other.0))
+    }
+}
+
+impl core::ops::Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Fixed {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+impl core::ops::Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, other: Fixed) -> Fixed {
+        Fixed::from_i128((self.0 as i128 * other.0 as i128) >> FRACTIONAL_BITS)
+    }
+}
+
+impl core::ops::Div for Fixed {
+    type Output = Fixed;
+    fn div(self, other: Fixed) -> Fixed {
+        if other.0 == 0 {
+            if self.0 < 0 {
+                Fixed(i64::MIN)
+            } else {
+                Fixed(i64::MAX)
+            }
+        } else {
+            Fixed::from_i128(((self.0 as i128) << FRACTIONAL_BITS) / other.0 as i128)
+        }
+    }
+}
+
+// Q63.64 fixed-point number, used for the coefficients of time polynomials
+// the rounding error of a coefficient is multiplied by tᵏ, hence the extra fractional bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Wide(i128);
+
+impl Wide {
+    const ZERO: Wide = Wide(0);
+
+    fn from_f64(value: f64) -> Wide {
+        let scaled = value * (1u128 << (2 * FRACTIONAL_BITS)) as f64;
+        Wide(if scaled >= 0.0 {
+            (scaled + 0.5) as i128
+        } else {
+            (scaled - 0.5) as i128
+        })
+    }
+}
+
+// c₀ tⁿ⁻¹ + c₁ tⁿ⁻² + ... + cₙ₋₁, evaluated with Horner's method and 64 fractional bits
+fn polynomial(t: Fixed, coefficients: &[Wide]) -> Fixed {
+    let mut accumulator = 0i128;
+    for coefficient in coefficients {
+        accumulator = (accumulator.saturating_mul(t.0 as i128) >> FRACTIONAL_BITS)
+            .saturating_add(coefficient.0);
+    }
+    Fixed::from_i128(accumulator >> FRACTIONAL_BITS)
+}
+
+// ⌊n¹ᐟ²⌋, calculated digit by digit
+fn isqrt(value: u128) -> u128 {
+    let mut remainder = value;
+    let mut root = 0;
+    let mut bit = 1 << 126;
+    while bit > value {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+// fixed-point copies of the lookup tables used by the lut-trig feature
+// sin(k π / 32) × 2³² for k ∈ [0, 16], rounded to the nearest integer
+const SINE_TABLE: [Fixed; 17] = [
+    Fixed(0),
+    Fixed(420980412),
+    Fixed(837906553),
+    Fixed(1246763195),
+    Fixed(1643612827),
+    Fixed(2024633568),
+    Fixed(2386155981),
+    Fixed(2724698408),
+    Fixed(3037000500),
+    Fixed(3320054617),
+    Fixed(3571134792),
+    Fixed(3787822988),
+    Fixed(3968032378),
+    Fixed(4110027446),
+    Fixed(4212440704),
+    Fixed(4274285855),
+    Fixed(4294967296),
+];
+
+// tan⁻¹(k / 16) × 2³² for k ∈ [0, 16], rounded to the nearest integer
+const ARCTANGENT_TABLE: [Fixed; 17] = [
+    Fixed(0),
+    Fixed(268086748),
+    Fixed(534100635),
+    Fixed(796063384),
+    Fixed(1052175346),
+    Fixed(1300880604),
+    Fixed(1540908296),
+    Fixed(1771289359),
+    Fixed(1991351318),
+    Fixed(2200695975),
+    Fixed(2399165791),
+    Fixed(2586804454),
+    Fixed(2763816217),
+    Fixed(2930527289),
+    Fixed(3087351340),
+    Fixed(3234760121),
+    Fixed(3373259426),
+];
+
+// sin(k π / 32) for any integer k, from the first quadrant table
+fn table_sine(k: i64) -> Fixed {
+    let k = (k & 63) as usize;
+    match k / 16 {
+        0 => SINE_TABLE[k],
+        1 => SINE_TABLE[32 - k],
+        2 => -SINE_TABLE[k - 32],
+        _ => -SINE_TABLE[64 - k],
+    }
+}
+
+// sin x = sin(k π / 32) cos r + cos(k π / 32) sin r
+// cos x = cos(k π / 32) cos r - sin(k π / 32) sin r
+// with k = ⌊32 x / π⌉ and r = x - k π / 32 ∈ [-π / 64, π / 64]
+fn sin_cos(angle: Fixed) -> (Fixed, Fixed) {
+    let k = (angle * Fixed::from_f64(32.0 / core::f64::consts::PI)).round();
+    let r = Fixed::from_i128(
+        (((angle.0 as i128) << FRACTIONAL_BITS) - k as i128 * (PI_Q64 >> 5)) >> FRACTIONAL_BITS,
+    );
+    let r2 = r * r;
+
+    // sin r = r - r³ / 3! + r⁵ / 5! - r⁷ / 7!
+    let r_sine = r
+        * (Fixed::ONE
+            - r2 * Fixed::from_f64(1.0 / 6.0)
+                * (Fixed::ONE
+                    - r2 * Fixed::from_f64(1.0 / 20.0)
+                        * (Fixed::ONE - r2 * Fixed::from_f64(1.0 / 42.0))));
+
+    // cos r = 1 - r² / 2! + r⁴ / 4! - r⁶ / 6!
+    let r_cosine = Fixed::ONE
+        - r2 * Fixed::from_f64(0.5)
+            * (Fixed::ONE
+                - r2 * Fixed::from_f64(1.0 / 12.0)
+                    * (Fixed::ONE - r2 * Fixed::from_f64(1.0 / 30.0)));
+    let k_sine = table_sine(k);
+    let k_cosine = table_sine(k + 16);
+    (
+        k_sine * r_cosine + k_cosine * r_sine,
+        k_cosine * r_cosine - k_sine * r_sine,
+    )
+}
+
+// tan⁻¹ z = tan⁻¹(k / 16) + tan⁻¹ d
+// with z = min(|x|, |y|) / max(|x|, |y|), k = ⌊16 z⌉ and d = (z - k / 16) / (1 + z k / 16)
+fn atan2(y: Fixed, x: Fixed) -> Fixed {
+    let (x_abs, y_abs) = (x.abs(), y.abs());
+    let (numerator, denominator) = if y_abs > x_abs {
+        (x_abs, y_abs)
+    } else {
+        (y_abs, x_abs)
+    };
+    let z = if denominator == Fixed::ZERO {
+        Fixed::ZERO
+    } else {
+        numerator / denominator
+    };
+    let k = (z * Fixed::from_f64(16.0)).round().clamp(0, 16);
+    let c = Fixed(k << (FRACTIONAL_BITS - 4));
+    let d = (z - c) / (Fixed::ONE + z * c);
+    let d2 = d * d;
+
+    // tan⁻¹ d = d - d³ / 3 + d⁵ / 5 - d⁷ / 7
+    let mut angle = ARCTANGENT_TABLE[k as usize]
+        + d * (Fixed::ONE
+            - d2 * (Fixed::from_f64(1.0 / 3.0)
+                - d2 * (Fixed::from_f64(1.0 / 5.0) - d2 * Fixed::from_f64(1.0 / 7.0))));
+    if y_abs > x_abs {
+        angle = Fixed::from_f64(core::f64::consts::FRAC_PI_2) - angle;
+    }
+    if x.0 < 0 {
+        angle = Fixed::from_f64(core::f64::consts::PI) - angle;
+    }
+    if y.0 < 0 {
+        -angle
+    } else {
+        angle
+    }
+}
+
+// angle rem 2π, with the sign of the angle
+fn remainder_two_pi(angle: Fixed) -> Fixed {
+    Fixed::from_i128((((angle.0 as i128) << FRACTIONAL_BITS) % (PI_Q64 << 1)) >> FRACTIONAL_BITS)
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Elliptic {
+    k11: Fixed,
+    k12: Fixed,
+    k13: Fixed,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct HighAltitude {
+    c5: Fixed,
+    eta: Fixed,
+    k7: Fixed,
+    elliptic: Option<Elliptic>,
+}
+
+/// Position and velocity predicted by the fixed-point propagator
+///
+/// The position and velocity are given in the True Equator, Mean Equinox (TEME) of epoch reference frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedPointPrediction {
+    /// The three position components (x, y, z) in m
+    pub position: [i32; 3],
+
+    /// The three velocity components (x, y, z) in mm.s⁻¹
+    pub velocity: [i32; 3],
+}
+
+/// Experimental fixed-point near earth propagator for microcontrollers without a floating point unit
+///
+/// The constants are calculated once from floating-point [Constants](struct.Constants.html),
+/// either on the target with software floating point or on a host (with the `serde` feature,
+/// `FixedPointConstants` can be serialized and uploaded). Propagation uses only integer arithmetic,
+/// with Q31.32 numbers (64-bit integers with 32 fractional bits) and 128-bit intermediate products.
+///
+/// Only near earth orbits (period smaller than 225 min) are supported.
+/// The AFSPC compatibility mode is not supported.
+///
+/// Compared with the floating-point propagator, on the near earth satellites of the Vallado test cases,
+/// the position error is below 2 m and the velocity error below 1 mm.s⁻¹ within 30 days of epoch,
+/// and below 25 m and 6 mm.s⁻¹ within one year of epoch. These bounds do not hold
+/// close to the drag singularity of a decaying orbit, where neither propagator is meaningful.
+/// Arithmetic saturates instead of overflowing, hence propagation never panics.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let constants = sgp4::Constants::from_elements(&elements)?;
+/// let fixed_point_constants = sgp4::FixedPointConstants::from_constants(&constants).unwrap();
+/// let prediction = fixed_point_constants.propagate(3_600_000)?;
+/// let reference = constants.propagate(sgp4::MinutesSinceEpoch(60.0))?;
+/// for index in 0..3 {
+///     assert!((prediction.position[index] as f64 - reference.position[index] * 1e3).abs() < 5.0);
+///     assert!((prediction.velocity[index] as f64 - reference.velocity[index] * 1e6).abs() < 5.0);
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedPointConstants {
+    ae: Fixed,
+    ke: Fixed,
+    j2: Fixed,
+    inclination: Fixed,
+    inclination_sine: Fixed,
+    inclination_cosine: Fixed,
+    // time polynomials, highest degree first
+    // k₀, Ω̇ and Ω₀ (Ω = Ω₀ + Ω̇ t + k₀ t²)
+    right_ascension: [Wide; 3],
+    // ω̇ and ω₀ (ω = ω₀ + ω̇ t)
+    argument_of_perigee: [Wide; 2],
+    // Ṁ and M₀ (p₂₄ = M₀ + Ṁ t)
+    mean_anomaly: [Wide; 2],
+    // n₀" k₁₀, n₀" k₉, n₀" k₈, n₀" k₁, 0 and 0 (n₀" t² (k₁ + t (k₈ + t (k₉ + t k₁₀))))
+    mean_anomaly_drag: [Wide; 6],
+    // -C₄ and e₀ (p₂₇ = e₀ - C₄ t)
+    eccentricity: [Wide; 2],
+    // -D₄, -D₃, -D₂, -C₁ and 1 (a = a₀" (1 - t (C₁ + t (D₂ + t (D₃ + t D₄))))²)
+    drag: [Wide; 5],
+    a0: Fixed,
+    k2: Fixed,
+    k3: Fixed,
+    k4: Fixed,
+    k5: Fixed,
+    k6: Fixed,
+    high_altitude: Option<HighAltitude>,
+}
+
+impl FixedPointConstants {
+    /// Converts floating-point constants to fixed-point constants
+    ///
+    /// Returns `None` if the orbit is a deep space orbit (period greater than or equal to 225 min).
+    /// The polynomial coefficients are converted to day units (instead of minutes)
+    /// so that their magnitude fits the fixed-point format.
+    ///
+    /// # Arguments
+    ///
+    /// * `constants` - The floating-point propagator constants
+    pub fn from_constants(constants: &propagator::Constants) -> Option<FixedPointConstants> {
+        let propagator::Method::NearEarth {
+            a0,
+            k2,
+            k3,
+            k4,
+            k5,
+            k6,
+            high_altitude,
+        } = &constants.method
+        else {
+            return None;
+        };
+        let orbit_0 = &constants.orbit_0;
+
+        // t is expressed in days, a coefficient of tᵏ is multiplied by 1440ᵏ
+        let day = MINUTES_PER_DAY;
+        let (inclination_sine, inclination_cosine) = trig::sin_cos(orbit_0.inclination);
+        let (high_altitude_coefficients, high_altitude) = match high_altitude {
+            propagator::HighAltitude::No {} => ([0.0; 6], None),
+            propagator::HighAltitude::Yes {
+                c5,
+                d2,
+                d3,
+                d4,
+                eta,
+                k7,
+                k8,
+                k9,
+                k10,
+                elliptic,
+            } => (
+                [*d2, *d3, *d4, *k8, *k9, *k10],
+                Some(HighAltitude {
+                    c5: Fixed::from_f64(*c5),
+                    eta: Fixed::from_f64(*eta),
+                    k7: Fixed::from_f64(*k7),
+                    elliptic: match elliptic {
+                        propagator::Elliptic::No {} => None,
+                        propagator::Elliptic::Yes { k11, k12, k13 } => Some(Elliptic {
+                            k11: Fixed::from_f64(*k11),
+                            k12: Fixed::from_f64(k12 * day),
+                            k13: Fixed::from_f64(*k13),
+                        }),
+                    },
+                }),
+            ),
+        };
+        let [d2, d3, d4, k8, k9, k10] = high_altitude_coefficients;
+        Some(FixedPointConstants {
+            ae: Fixed::from_f64(constants.geopotential.ae),
+            ke: Fixed::from_f64(constants.geopotential.ke),
+            j2: Fixed::from_f64(constants.geopotential.j2),
+            inclination: Fixed::from_f64(orbit_0.inclination),
+            inclination_sine: Fixed::from_f64(inclination_sine),
+            inclination_cosine: Fixed::from_f64(inclination_cosine),
+            right_ascension: [
+                Wide::from_f64(constants.k0 * day * day),
+                Wide::from_f64(constants.right_ascension_dot * day),
+                Wide::from_f64(orbit_0.right_ascension),
+            ],
+            argument_of_perigee: [
+                Wide::from_f64(constants.argument_of_perigee_dot * day),
+                Wide::from_f64(orbit_0.argument_of_perigee),
+            ],
+            mean_anomaly: [
+                Wide::from_f64(constants.mean_anomaly_dot * day),
+                Wide::from_f64(orbit_0.mean_anomaly),
+            ],
+            mean_anomaly_drag: [
+                Wide::from_f64(orbit_0.mean_motion * k10 * day * day * day * day * day),
+                Wide::from_f64(orbit_0.mean_motion * k9 * day * day * day * day),
+                Wide::from_f64(orbit_0.mean_motion * k8 * day * day * day),
+                Wide::from_f64(orbit_0.mean_motion * constants.k1 * day * day),
+                Wide::ZERO,
+                Wide::ZERO,
+            ],
+            eccentricity: [
+                Wide::from_f64(-constants.c4 * day),
+                Wide::from_f64(orbit_0.eccentricity),
+            ],
+            drag: [
+                Wide::from_f64(-d4 * day * day * day * day),
+                Wide::from_f64(-d3 * day * day * day),
+                Wide::from_f64(-d2 * day * day),
+                Wide::from_f64(-constants.c1 * day),
+                Wide::from_f64(1.0),
+            ],
+            a0: Fixed::from_f64(*a0),
+            k2: Fixed::from_f64(*k2),
+            k3: Fixed::from_f64(*k3),
+            k4: Fixed::from_f64(*k4),
+            k5: Fixed::from_f64(*k5),
+            k6: Fixed::from_f64(*k6),
+            high_altitude,
+        })
+    }
+
+    /// Calculates the position and velocity of the satellite with integer arithmetic
+    ///
+    /// # Arguments
+    ///
+    /// * `milliseconds` - The number of milliseconds since epoch (can be positive, negative or zero)
+    pub fn propagate(
+        &self,
+        milliseconds: i64,
+    ) -> core::result::Result<FixedPointPrediction, gp::Error> {
+        // t in days
+        let t = Fixed::from_i128(((milliseconds as i128) << FRACTIONAL_BITS) / 86_400_000);
+        let minutes = || milliseconds as f64 / 60_000.0;
+
+        // Ω = Ω₀ + Ω̇ t + k₀ t²
+        let right_ascension = polynomial(t, &self.right_ascension);
+
+        // p₂₃ = ω₀ + ω̇ t
+        let p23 = polynomial(t, &self.argument_of_perigee);
+
+        // a = a₀" (1 - t (C₁ + t (D₂ + t (D₃ + t D₄))))²
+        let p = polynomial(t, &self.drag);
+        let a = self.a0 * p * p;
+
+        // p₂₇ = e₀ - C₄ t (near earth with perigee below 220 km)
+        let mut p27 = polynomial(t, &self.eccentricity);
+
+        // p₂₄ = M₀ + Ṁ t
+        let p24 = polynomial(t, &self.mean_anomaly);
+
+        // n₀" t² (k₁ + t (k₈ + t (k₉ + t k₁₀)))
+        let mean_anomaly_drag = polynomial(t, &self.mean_anomaly_drag);
+        let (argument_of_perigee, mean_anomaly) = match &self.high_altitude {
+            None => (p23, p24 + mean_anomaly_drag),
+            Some(high_altitude) => {
+                let (argument_of_perigee, p26) = match &high_altitude.elliptic {
+                    Some(elliptic) => {
+                        // p₂₅ = k₁₃ ((1 + η cos p₂₄)³ - k₁₁) + k₁₂ t
+                        let p = Fixed::ONE + high_altitude.eta * sin_cos(p24).1;
+                        let p25 = elliptic.k13 * (p * p * p - elliptic.k11) + elliptic.k12 * t;
+                        (p23 - p25, p24 + p25)
+                    }
+                    None => (p23, p24),
+                };
+
+                // p₂₇ = e₀ - (C₄ t + C₅ (sin p₂₆ - k₇))
+                p27 = p27 - high_altitude.c5 * (sin_cos(p26).0 - high_altitude.k7);
+
+                // M = p₂₆ + n₀" t² (k₁ + t (k₈ + t (k₉ + t k₁₀)))
+                (argument_of_perigee, p26 + mean_anomaly_drag)
+            }
+        };
+        if p27 < Fixed::from_f64(-0.001) || p27 >= Fixed::ONE {
+            return Err(gp::Error::OutOfRangeEccentricity {
+                eccentricity: p27.to_f64(),
+                t: minutes(),
+            });
+        }
+
+        // e = │ 10⁻⁶ if p₂₇ < 10⁻⁶
+        //     │ p₂₇  otherwise
+        let eccentricity = p27.max(Fixed::from_f64(1.0e-6));
+
+        // n = kₑ / a³ᐟ²
+        let mean_motion = self.ke / (a * a.sqrt());
+
+        // p₃₇ = 1 / (a (1 - e²))
+        let p37 = Fixed::ONE / (a * (Fixed::ONE - eccentricity * eccentricity));
+        let (argument_of_perigee_sine, argument_of_perigee_cosine) = sin_cos(argument_of_perigee);
+
+        // aₓₙ = e cos ω
+        let axn = eccentricity * argument_of_perigee_cosine;
+
+        // aᵧₙ = e sin ω + p₃₇ p₃₂
+        let ayn = eccentricity * argument_of_perigee_sine + p37 * self.k2;
+
+        // p₃₈ = M + ω + p₃₇ p₃₅ aₓₙ rem 2π
+        let p38 = remainder_two_pi(mean_anomaly + argument_of_perigee + p37 * self.k5 * axn);
+
+        // (E + ω)₀ = p₃₈
+        let mut ew = p38;
+        let (mut ew_sine, mut ew_cosine) = sin_cos(ew);
+        for _ in 0..10 {
+            //             p₃₈ - aᵧₙ cos (E + ω)ᵢ + aₓₙ sin (E + ω)ᵢ - (E + ω)ᵢ
+            // Δ(E + ω)ᵢ = ---------------------------------------------------
+            //                   1 - cos (E + ω)ᵢ aₓₙ - sin (E + ω)ᵢ aᵧₙ
+            let delta = (p38 - ayn * ew_cosine + axn * ew_sine - ew)
+                / (Fixed::ONE - ew_cosine * axn - ew_sine * ayn);
+            if delta.abs().0 <= 1 {
+                break;
+            }
+
+            // (E + ω)ᵢ₊₁ = (E + ω)ᵢ + Δ(E + ω)ᵢ|[-0.95, 0.95]
+            ew = ew + delta.clamp(Fixed::from_f64(-0.95), Fixed::from_f64(0.95));
+            (ew_sine, ew_cosine) = sin_cos(ew);
+        }
+
+        // p₃₉ = aₓₙ² + aᵧₙ²
+        let p39 = axn * axn + ayn * ayn;
+
+        // pₗ = a (1 - p₃₉)
+        let pl = a * (Fixed::ONE - p39);
+        if pl.0 < 0 {
+            return Err(gp::Error::NegativeSemiLatusRectum { t: minutes() });
+        }
+
+        // p₄₀ = aₓₙ sin(E + ω) - aᵧₙ cos(E + ω)
+        let p40 = axn * ew_sine - ayn * ew_cosine;
+
+        // r = a (1 - aₓₙ cos(E + ω) + aᵧₙ sin(E + ω))
+        let r = a * (Fixed::ONE - (axn * ew_cosine + ayn * ew_sine));
+
+        // ṙ = a¹ᐟ² p₄₀ / r
+        let r_dot = a.sqrt() * p40 / r;
+
+        // β = (1 - p₃₉)¹ᐟ²
+        let b = (Fixed::ONE - p39).sqrt();
+
+        // p₄₁ = p₄₀ / (1 + β)
+        let p41 = p40 / (Fixed::ONE + b);
+
+        // p₄₂ = a / r (sin(E + ω) - aᵧₙ - aₓₙ p₄₁)
+        let p42 = a / r * (ew_sine - ayn - axn * p41);
+
+        // p₄₃ = a / r (cos(E + ω) - aₓₙ + aᵧₙ p₄₁)
+        let p43 = a / r * (ew_cosine - axn + ayn * p41);
+
+        //           p₄₂
+        // u = tan⁻¹ ---
+        //           p₄₃
+        let u = atan2(p42, p43);
+
+        // p₄₄ = 2 p₄₃ p₄₂
+        let p44 = Fixed::from_f64(2.0) * p43 * p42;
+
+        // p₄₅ = 1 - 2 p₄₂²
+        let p45 = Fixed::ONE - Fixed::from_f64(2.0) * p42 * p42;
+
+        // ¹/₂ J₂ / pₗ
+        let half_j2_pl = Fixed::from_f64(0.5) * self.j2 / pl;
+
+        // p₄₆ = (¹/₂ J₂ / pₗ) / pₗ
+        let p46 = half_j2_pl / pl;
+
+        // rₖ = r (1 - ³/₂ p₄₆ β p₃₆) + ¹/₂ (¹/₂ J₂ / pₗ) p₃₃ p₄₅
+        let rk = r * (Fixed::ONE - Fixed::from_f64(1.5) * p46 * b * self.k6)
+            + Fixed::from_f64(0.5) * half_j2_pl * self.k3 * p45;
+
+        // uₖ = u - ¹/₄ p₄₆ p₃₄ p₄₄
+        let uk = u - Fixed::from_f64(0.25) * p46 * self.k4 * p44;
+
+        // Iₖ = I + ³/₂ p₄₆ cos I sin I p₄₅
+        let inclination_k = self.inclination
+            + Fixed::from_f64(1.5) * p46 * self.inclination_cosine * self.inclination_sine * p45;
+
+        // Ωₖ = Ω + ³/₂ p₄₆ cos I p₄₄
+        let right_ascension_k =
+            right_ascension + Fixed::from_f64(1.5) * p46 * self.inclination_cosine * p44;
+
+        // ṙₖ = ṙ - n (¹/₂ J₂ / pₗ) p₃₃ p₄₄ / kₑ
+        let rk_dot = r_dot - mean_motion * half_j2_pl * self.k3 * p44 / self.ke;
+
+        // rḟₖ = pₗ¹ᐟ² / r + n (¹/₂ J₂ / pₗ) (p₃₃ p₄₅ + ³/₂ p₃₆) / kₑ
+        let rfk_dot = pl.sqrt() / r
+            + mean_motion * half_j2_pl * (self.k3 * p45 + Fixed::from_f64(1.5) * self.k6) / self.ke;
+
+        let (inclination_k_sine, inclination_k_cosine) = sin_cos(inclination_k);
+        let (right_ascension_k_sine, right_ascension_k_cosine) = sin_cos(right_ascension_k);
+        let (uk_sine, uk_cosine) = sin_cos(uk);
+
+        // u₀ = - sin Ωₖ cos Iₖ sin uₖ + cos Ωₖ cos uₖ
+        let u0 = -right_ascension_k_sine * inclination_k_cosine * uk_sine
+            + right_ascension_k_cosine * uk_cosine;
+
+        // u₁ = cos Ωₖ cos Iₖ sin uₖ + sin Ωₖ cos uₖ
+        let u1 = right_ascension_k_cosine * inclination_k_cosine * uk_sine
+            + right_ascension_k_sine * uk_cosine;
+
+        // u₂ = sin Iₖ sin uₖ
+        let u2 = inclination_k_sine * uk_sine;
+
+        // rₖ aₑ in m
+        let position_scale = rk * self.ae * Fixed::from_f64(1.0e3);
+
+        // aₑ kₑ / 60 in mm.s⁻¹
+        let velocity_scale = self.ae * self.ke * Fixed::from_f64(1.0e6 / 60.0);
+        Ok(FixedPointPrediction {
+            position: [
+                // r₀ = rₖ u₀ aₑ
+                (position_scale * u0).round() as i32,
+                // r₁ = rₖ u₁ aₑ
+                (position_scale * u1).round() as i32,
+                // r₂ = rₖ u₂ aₑ
+                (position_scale * u2).round() as i32,
+            ],
+            velocity: [
+                // ṙ₀ = (ṙₖ u₀ + rḟₖ (- sin Ωₖ cos Iₖ cos uₖ - cos Ωₖ sin uₖ)) aₑ kₑ / 60
+                ((rk_dot * u0
+                    + rfk_dot
+                        * (-right_ascension_k_sine * inclination_k_cosine * uk_cosine
+                            - right_ascension_k_cosine * uk_sine))
+                    * velocity_scale)
+                    .round() as i32,
+                // ṙ₁ = (ṙₖ u₁ + rḟₖ (cos Ωₖ cos Iₖ cos uₖ - sin Ωₖ sin uₖ)) aₑ kₑ / 60
+                ((rk_dot * u1
+                    + rfk_dot
+                        * (right_ascension_k_cosine * inclination_k_cosine * uk_cosine
+                            - right_ascension_k_sine * uk_sine))
+                    * velocity_scale)
+                    .round() as i32,
+                // ṙ₂ = (ṙₖ u₂ + rḟₖ (sin Iₖ cos uₖ)) aₑ kₑ / 60
+                ((rk_dot * u2 + rfk_dot * (inclination_k_sine * uk_cosine)) * velocity_scale)
+                    .round() as i32,
+            ],
+        })
+    }
+}
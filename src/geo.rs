@@ -0,0 +1,138 @@
+use crate::geodetic;
+use crate::gp;
+use crate::model;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// T = 1436.0682 min (sidereal day)
+const SIDEREAL_DAY: f64 = 1436.0682;
+
+// J₂₂ = 1.8155 × 10⁻⁶ (sectoral harmonic amplitude)
+const J22: f64 = 1.8155e-6;
+
+// λ₂₂ = -14.93° (sectoral harmonic phase)
+const J22_LONGITUDE: f64 = -14.93 * (core::f64::consts::PI / 180.0);
+
+// a = 42164.17 km (geostationary radius)
+const GEOSTATIONARY_RADIUS: f64 = 42164.17;
+
+// ωₑ = 6.300388 rad.day⁻¹ (Earth rotation rate)
+const EARTH_ROTATION_RATE: f64 = 6.300388;
+
+/// Oscillation of a geosynchronous satellite's longitude around a stable point
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Libration {
+    /// Longitude of the stable point in rad, near 75° E or 105° W
+    pub center: f64,
+
+    /// Largest angle between the satellite's longitude and the stable point in rad
+    pub amplitude: f64,
+}
+
+/// Longitude motion of a geosynchronous satellite
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeoState {
+    /// Sub-satellite longitude in rad (positive east), in the range [-π, π[
+    pub longitude: f64,
+
+    /// Mean longitude drift rate in rad.day⁻¹ (positive eastwards)
+    pub drift_rate: f64,
+
+    /// Libration around the closest stable point
+    ///
+    /// `None` if the satellite drifts fast enough to circulate around the Earth.
+    pub libration: Option<Libration>,
+}
+
+impl propagator::Constants {
+    // sub-satellite longitude at the given time in rad
+    pub(crate) fn longitude(&self, t: MinutesSinceEpoch) -> core::result::Result<f64, gp::Error> {
+        Ok(geodetic::Geodetic::from_teme(
+            model::iau_epoch_to_sidereal_time(self.julian_years_since_j2000(t)),
+            &self.propagate(t)?.position,
+        )
+        .longitude)
+    }
+
+    /// Calculates the longitude, drift rate and libration of a geosynchronous satellite
+    ///
+    /// The drift rate is the longitude change over one sidereal day centred on the given time,
+    /// which removes the daily oscillations caused by the eccentricity and the inclination.
+    /// The libration is estimated with the energy integral of the longitude motion,
+    /// considering only the J₂₂ sectoral harmonic.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     None,
+    ///     "1 25954U 99060A   04039.68057285 -.00000108  00000-0  00000-0 0  6847".as_bytes(),
+    ///     "2 25954   0.0004 243.8136 0001765  15.5294  22.7134  1.00271289 15615".as_bytes(),
+    /// )?)?;
+    /// let geo_state = constants.geo_state(sgp4::MinutesSinceEpoch(0.0))?;
+    /// println!(
+    ///     "longitude = {}°, drift = {}°/day",
+    ///     geo_state.longitude.to_degrees(),
+    ///     geo_state.drift_rate.to_degrees(),
+    /// );
+    ///
+    /// // the satellite is kept at 101.04° W, 3.9° east of the 104.93° W stable point
+    /// assert!((geo_state.longitude.to_degrees() + 101.04).abs() < 0.01);
+    /// assert!(geo_state.drift_rate.to_degrees().abs() < 0.01);
+    /// let libration = geo_state.libration.unwrap();
+    /// assert!((libration.center.to_degrees() + 104.93).abs() < 1e-9);
+    /// assert!((libration.amplitude.to_degrees() - 3.9).abs() < 0.01);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn geo_state(&self, t: MinutesSinceEpoch) -> core::result::Result<GeoState, gp::Error> {
+        let longitude = self.longitude(t)?;
+
+        // λ̇ = (λ(t + T / 2) - λ(t - T / 2)) / T
+        let drift_rate = model::signed_angle(
+            self.longitude(MinutesSinceEpoch(t.0 + SIDEREAL_DAY / 2.0))?
+                - self.longitude(MinutesSinceEpoch(t.0 - SIDEREAL_DAY / 2.0))?,
+        ) * (24.0 * 60.0 / SIDEREAL_DAY);
+
+        // A = 18 ωₑ² J₂₂ (aₑ / a)²
+        let acceleration = 18.0
+            * EARTH_ROTATION_RATE.powi(2)
+            * J22
+            * (self.geopotential.ae / GEOSTATIONARY_RADIUS).powi(2);
+
+        // λₛ = λ₂₂ ± π / 2
+        let center = if model::signed_angle(longitude - J22_LONGITUDE) > 0.0 {
+            J22_LONGITUDE + core::f64::consts::FRAC_PI_2
+        } else {
+            J22_LONGITUDE - core::f64::consts::FRAC_PI_2
+        };
+
+        // E = λ̇² / 2 - A cos(2 (λ - λₛ)) / 2
+        let energy = drift_rate.powi(2) / 2.0
+            - acceleration * (2.0 * model::signed_angle(longitude - center)).cos() / 2.0;
+        Ok(GeoState {
+            longitude,
+            drift_rate,
+            libration: if energy < acceleration / 2.0 {
+                Some(Libration {
+                    center: model::signed_angle(center),
+
+                    // Δλ = cos⁻¹(-2 E / A) / 2
+                    amplitude: (-2.0 * energy / acceleration).clamp(-1.0, 1.0).acos() / 2.0,
+                })
+            } else {
+                None
+            },
+        })
+    }
+}
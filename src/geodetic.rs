@@ -0,0 +1,95 @@
+use crate::model;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// a = 6378.137 km (WGS84 equatorial radius)
+pub(crate) const ELLIPSOID_EQUATORIAL_RADIUS: f64 = 6378.137;
+
+// f = 1 / 298.257223563 (WGS84 flattening)
+pub(crate) const ELLIPSOID_FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Geodetic coordinates of a point with respect to the WGS84 ellipsoid
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geodetic {
+    /// Geodetic latitude in rad (positive north), in the range [-π/2, π/2]
+    pub latitude: f64,
+
+    /// Longitude in rad (positive east), in the range [-π, π[
+    pub longitude: f64,
+
+    /// Height above the WGS84 ellipsoid in km
+    pub altitude: f64,
+}
+
+impl Geodetic {
+    /// Converts a position in the True Equator, Mean Equinox (TEME) reference frame to geodetic coordinates
+    ///
+    /// Applied to a satellite position, this yields the sub-satellite point and the altitude.
+    /// Polar motion is neglected.
+    ///
+    /// # Arguments
+    ///
+    /// * `sidereal_time` - Greenwich sidereal time in rad, for example calculated with [sgp4::iau_epoch_to_sidereal_time](fn.iau_epoch_to_sidereal_time.html)
+    /// * `position` - The position in the TEME reference frame in km
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let t = sgp4::MinutesSinceEpoch(30.0);
+    /// let sub_satellite_point = sgp4::Geodetic::from_teme(
+    ///     sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+    ///     &constants.propagate(t)?.position,
+    /// );
+    /// println!(
+    ///     "latitude = {}°, longitude = {}°, altitude = {} km",
+    ///     sub_satellite_point.latitude.to_degrees(),
+    ///     sub_satellite_point.longitude.to_degrees(),
+    ///     sub_satellite_point.altitude,
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_teme(sidereal_time: f64, position: &[f64; 3]) -> Self {
+        // e² = f (2 - f)
+        let eccentricity_squared = ELLIPSOID_FLATTENING * (2.0 - ELLIPSOID_FLATTENING);
+
+        // rₑ = (x² + y²)¹ᐟ²
+        let equatorial_distance = (position[0].powi(2) + position[1].powi(2)).sqrt();
+
+        // φ₀ = tan⁻¹(z / rₑ)
+        let mut latitude = position[2].atan2(equatorial_distance);
+        for _ in 0..10 {
+            // N = a / (1 - e² sin²φₖ)¹ᐟ²
+            let n = ELLIPSOID_EQUATORIAL_RADIUS
+                / (1.0 - eccentricity_squared * latitude.sin().powi(2)).sqrt();
+
+            // φₖ₊₁ = tan⁻¹((z + N e² sin φₖ) / rₑ)
+            let next_latitude = (position[2] + n * eccentricity_squared * latitude.sin())
+                .atan2(equatorial_distance);
+            if (next_latitude - latitude).abs() < 1.0e-12 {
+                latitude = next_latitude;
+                break;
+            }
+            latitude = next_latitude;
+        }
+        Geodetic {
+            latitude,
+
+            // λ = tan⁻¹(y / x) - θ
+            longitude: model::signed_angle(position[1].atan2(position[0]) - sidereal_time),
+
+            // h = rₑ cos φ + z sin φ - a (1 - e² sin²φ)¹ᐟ²
+            altitude: equatorial_distance * latitude.cos() + position[2] * latitude.sin()
+                - ELLIPSOID_EQUATORIAL_RADIUS
+                    * (1.0 - eccentricity_squared * latitude.sin().powi(2)).sqrt(),
+        }
+    }
+}
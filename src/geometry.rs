@@ -0,0 +1,100 @@
+use crate::model;
+use crate::tle;
+use crate::Degrees;
+use crate::Orbit;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+impl tle::Elements {
+    // Brouwer mean motion in rad.min⁻¹ (NaN if the elements are invalid)
+    fn brouwer_mean_motion(&self, geopotential: &model::Geopotential) -> f64 {
+        Orbit::from_kozai_elements(
+            geopotential,
+            Degrees(self.inclination),
+            Degrees(self.right_ascension),
+            self.eccentricity,
+            Degrees(self.argument_of_perigee),
+            Degrees(self.mean_anomaly),
+            self.mean_motion * (core::f64::consts::PI / 720.0),
+        )
+        .map_or(f64::NAN, |orbit| orbit.mean_motion)
+    }
+
+    /// Returns the mean semi-major axis in km
+    ///
+    /// The semi-major axis is calculated from the Brouwer mean motion, as in SGP4's initialization.
+    /// Returns NaN if the mean motion is not strictly positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// assert!((elements.semi_major_axis(&sgp4::WGS84) - 6798.0).abs() < 5.0);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn semi_major_axis(&self, geopotential: &model::Geopotential) -> f64 {
+        // a₀" = aₑ (kₑ / n₀")²ᐟ³
+        geopotential.ae * (geopotential.ke / self.brouwer_mean_motion(geopotential)).powf(2.0 / 3.0)
+    }
+
+    /// Returns the mean apogee altitude above the equatorial radius in km
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     None,
+    ///     "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+    ///     "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    /// )?;
+    /// println!(
+    ///     "{} km x {} km",
+    ///     elements.perigee_altitude(&sgp4::WGS84),
+    ///     elements.apogee_altitude(&sgp4::WGS84),
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn apogee_altitude(&self, geopotential: &model::Geopotential) -> f64 {
+        // hₐ = a (1 + e) - aₑ
+        self.semi_major_axis(geopotential) * (1.0 + self.eccentricity) - geopotential.ae
+    }
+
+    /// Returns the mean perigee altitude above the equatorial radius in km
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    pub fn perigee_altitude(&self, geopotential: &model::Geopotential) -> f64 {
+        // hₚ = a (1 - e) - aₑ
+        self.semi_major_axis(geopotential) * (1.0 - self.eccentricity) - geopotential.ae
+    }
+
+    /// Returns the orbital period in minutes
+    ///
+    /// The period is calculated from the Brouwer mean motion,
+    /// and matches [Constants::period](struct.Constants.html#method.period).
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    pub fn period(&self, geopotential: &model::Geopotential) -> f64 {
+        2.0 * core::f64::consts::PI / self.brouwer_mean_motion(geopotential)
+    }
+}
@@ -0,0 +1,230 @@
+use crate::ephemeris;
+use crate::events;
+use crate::geodetic;
+use crate::gp;
+use crate::model;
+use crate::observer;
+use crate::propagator;
+use crate::relative;
+use crate::tle::MinutesSinceEpoch;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Orientation of a flat reflecting panel (solar array, antenna or mirror-like surface)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PanelOrientation {
+    /// Normal fixed with respect to the satellite's radial, in-track and cross-track axes
+    ///
+    /// For example, `[-1.0, 0.0, 0.0]` models an Earth-facing (nadir) panel.
+    /// The components do not need to be normalized.
+    Ric([f64; 3]),
+
+    /// Normal fixed in the TEME reference frame, for example for an inertially stabilized satellite
+    ///
+    /// The components do not need to be normalized.
+    Inertial([f64; 3]),
+}
+
+impl PanelOrientation {
+    /// Returns the unit normal of the panel in the TEME reference frame
+    ///
+    /// # Arguments
+    ///
+    /// * `prediction` - The satellite position and velocity in the TEME reference frame
+    pub fn normal(&self, prediction: &propagator::Prediction) -> [f64; 3] {
+        let normal = match self {
+            PanelOrientation::Ric(components) => {
+                let axes = relative::ric_axes(prediction);
+                [0, 1, 2].map(|index| {
+                    components[0] * axes[0][index]
+                        + components[1] * axes[1][index]
+                        + components[2] * axes[2][index]
+                })
+            }
+            PanelOrientation::Inertial(normal) => *normal,
+        };
+        let norm = vector::norm(&normal);
+        normal.map(|component| component / norm)
+    }
+}
+
+/// A time interval during which the Sun's reflection on a panel is close to the observer's direction
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Glint {
+    /// Time at which the glint angle falls below the maximum angle in minutes since epoch
+    ///
+    /// `None` if the glint is already in progress at the beginning of the window.
+    pub start: Option<MinutesSinceEpoch>,
+
+    /// Time of the smallest glint angle in minutes since epoch
+    pub peak: MinutesSinceEpoch,
+
+    /// Time at which the glint angle rises above the maximum angle in minutes since epoch
+    ///
+    /// `None` if the glint is still in progress at the end of the window.
+    pub end: Option<MinutesSinceEpoch>,
+
+    /// Smallest glint angle in rad, see [sgp4::glint_angle](fn.glint_angle.html)
+    pub minimum_angle: f64,
+
+    /// Distance between the observer and the satellite at the peak in km
+    pub range: f64,
+}
+
+/// Returns the angle between the specular reflection of the Sun on a panel and the observer's direction in rad
+///
+/// A zero angle means that the observer sees the Sun's mirror image at the center of the panel.
+/// Since the Sun's apparent radius is about 0.27°, flat panels produce flares for angles up to
+/// a fraction of a degree, and slightly curved or rough panels for angles of a few degrees.
+///
+/// The result is `None` if the Sun or the observer is behind the panel.
+/// Earth shadow and horizon checks are not applied.
+/// All positions must be expressed in the same reference frame (for instance TEME).
+///
+/// # Arguments
+///
+/// * `observer_position` - The observer's position in km
+/// * `satellite_position` - The satellite's position in km
+/// * `sun_position` - The Sun's position in km
+/// * `normal` - The panel's unit normal, see [PanelOrientation::normal](enum.PanelOrientation.html#method.normal)
+pub fn glint_angle(
+    observer_position: &[f64; 3],
+    satellite_position: &[f64; 3],
+    sun_position: &[f64; 3],
+    normal: &[f64; 3],
+) -> Option<f64> {
+    let sun_direction = vector::sub(sun_position, satellite_position);
+    let observer_direction = vector::sub(observer_position, satellite_position);
+    let sun_projection = vector::dot(normal, &sun_direction);
+    if sun_projection <= 0.0 || vector::dot(normal, &observer_direction) <= 0.0 {
+        return None;
+    }
+
+    // ŝᵣ = 2 (n̂ · s) n̂ - s
+    let reflection =
+        [0, 1, 2].map(|index| 2.0 * sun_projection * normal[index] - sun_direction[index]);
+    Some(vector::angle(&reflection, &observer_direction))
+}
+
+// returns true if the satellite is in the Earth's shadow (cylindrical shadow model)
+fn is_shadowed(satellite_position: &[f64; 3], sun_position: &[f64; 3]) -> bool {
+    let sun_distance = vector::norm(sun_position);
+
+    // p = r · ŝ
+    let projection = vector::dot(satellite_position, sun_position) / sun_distance;
+
+    // d² = |r|² - p²
+    projection < 0.0
+        && vector::dot(satellite_position, satellite_position) - projection.powi(2)
+            < geodetic::ELLIPSOID_EQUATORIAL_RADIUS.powi(2)
+}
+
+impl propagator::Constants {
+    /// Finds the time intervals during which a panel of the satellite reflects the Sun towards a ground observer
+    ///
+    /// The glint angle (see [sgp4::glint_angle](fn.glint_angle.html)) is sampled with a fixed step,
+    /// and the intervals during which it is smaller than `maximum_angle` are refined by bisection.
+    /// Samples where the satellite is below the horizon, in the Earth's shadow (cylindrical model)
+    /// or where the panel faces away from the Sun or the observer are treated as outside the glint.
+    ///
+    /// Glints of low earth orbit satellites last a few seconds for a maximum angle of 1°,
+    /// hence the step must be of the order of a second (0.01 min) to avoid missing glints.
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - The ground observer
+    /// * `orientation` - The panel orientation
+    /// * `maximum_angle` - The largest glint angle considered a flare in rad
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `step` - The sampling step in minutes (strictly positive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: 48.8566_f64.to_radians(),
+    ///     longitude: 2.3522_f64.to_radians(),
+    ///     altitude: 0.035,
+    /// };
+    /// for glint in constants.glints(
+    ///     &observer,
+    ///     &sgp4::PanelOrientation::Ric([-1.0, 0.0, 0.0]),
+    ///     2.0_f64.to_radians(),
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    ///     0.01,
+    /// )? {
+    ///     println!(
+    ///         "glint at t = {} min, angle {}°",
+    ///         glint.peak.0,
+    ///         glint.minimum_angle.to_degrees()
+    ///     );
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn glints(
+        &self,
+        observer: &observer::Observer,
+        orientation: &PanelOrientation,
+        maximum_angle: f64,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        step: f64,
+    ) -> core::result::Result<alloc::vec::Vec<Glint>, gp::Error> {
+        // returns the glint angle (π if there is no visible reflection) and the range
+        let angle_and_range = |t: MinutesSinceEpoch| {
+            self.propagate(t).map(|prediction| {
+                let epoch = self.julian_years_since_j2000(t);
+                let sidereal_time = model::iau_epoch_to_sidereal_time(epoch);
+                let sun_position = ephemeris::sun_position(epoch);
+                let look_angles = observer.look_angles(sidereal_time, &prediction);
+                let angle = if look_angles.elevation <= 0.0
+                    || is_shadowed(&prediction.position, &sun_position)
+                {
+                    None
+                } else {
+                    glint_angle(
+                        &observer.position(sidereal_time),
+                        &prediction.position,
+                        &sun_position,
+                        &orientation.normal(&prediction),
+                    )
+                };
+                (angle.unwrap_or(core::f64::consts::PI), look_angles.range)
+            })
+        };
+        events::positive_intervals(start, end, step, 1e-7, |t| {
+            angle_and_range(t).map(|(angle, _)| maximum_angle - angle)
+        })?
+        .into_iter()
+        .map(|(glint_start, glint_end)| {
+            let (peak, _) = events::maximize(
+                glint_start.map_or(start.0, |glint_start| glint_start.0),
+                glint_end.map_or(end.0, |glint_end| glint_end.0),
+                1e-7,
+                |t| angle_and_range(t).map(|(angle, _)| -angle),
+            )?;
+            let (minimum_angle, range) = angle_and_range(peak)?;
+            Ok(Glint {
+                start: glint_start,
+                peak,
+                end: glint_end,
+                minimum_angle,
+                range,
+            })
+        })
+        .collect()
+    }
+}
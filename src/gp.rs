@@ -1,3 +1,5 @@
+use crate::real::Real;
+
 /// Represents a propagation error caused by orbital elements divergence
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -21,6 +23,22 @@ pub enum Error {
         /// Minutes since epoch
         t: f64,
     },
+
+    ClampedEccentricity {
+        /// Eccentricity value before clamping (unitless)
+        eccentricity: f64,
+
+        /// Minutes since epoch
+        t: f64,
+    },
+
+    BeyondHorizon {
+        /// Largest accepted absolute time in minutes since epoch
+        horizon: f64,
+
+        /// Minutes since epoch
+        t: f64,
+    },
 }
 
 impl core::fmt::Display for Error {
@@ -42,10 +60,126 @@ impl core::fmt::Display for Error {
             ),
             Error::NegativeSemiLatusRectum { t } => formatter.write_fmt(
                 core::format_args!("The propagated semi-latus rectum is negative {} minutes after epoch", t)
-            )
+            ),
+            Error::ClampedEccentricity { eccentricity, t } => formatter.write_fmt(
+                core::format_args!(
+                    "The propagated eccentricity ({}) is below the clamping threshold {} minutes after epoch",
+                    eccentricity,
+                    t,
+                )
+            ),
+            Error::BeyondHorizon { horizon, t } => formatter.write_fmt(
+                core::format_args!(
+                    "The propagation time ({} minutes after epoch) is beyond the horizon (±{} minutes)",
+                    t,
+                    horizon,
+                )
+            ),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
+
+/// Eccentricity thresholds applied during propagation
+///
+/// SGP4 propagates the secular eccentricity with a linear drag term that may become slightly negative.
+/// Values in the range [minimum, clamp[ are replaced with clamp, and values below minimum
+/// (or greater than or equal to 1) result in an `Error::OutOfRangeEccentricity`.
+/// `EccentricityLimits::SGP4` reproduces the reference implementation (minimum -0.001, clamp 10⁻⁶).
+///
+/// If `strict` is true, clamping is replaced with an `Error::ClampedEccentricity`,
+/// which flags marginal element sets instead of silently modifying the orbit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EccentricityLimits {
+    /// Smallest propagated eccentricity that does not result in an error (unitless)
+    pub minimum: f64,
+
+    /// Value that smaller propagated eccentricities are raised to (unitless)
+    pub clamp: f64,
+
+    /// Whether eccentricities smaller than clamp result in an error instead of being clamped
+    pub strict: bool,
+}
+
+impl EccentricityLimits {
+    /// The thresholds of the reference implementation
+    pub const SGP4: Self = Self {
+        minimum: -0.001,
+        clamp: 1.0e-6,
+        strict: false,
+    };
+
+    // applies the limits to the propagated eccentricity, without range checks if limits is None
+    pub(crate) fn apply<T: Real>(
+        limits: Option<&Self>,
+        eccentricity: T,
+        t: f64,
+    ) -> core::result::Result<T, Error> {
+        match limits {
+            Some(limits) => {
+                if !(limits.minimum..1.0).contains(&eccentricity.value()) {
+                    Err(Error::OutOfRangeEccentricity {
+                        eccentricity: eccentricity.value(),
+                        t,
+                    })
+                } else if limits.strict && eccentricity.value() < limits.clamp {
+                    Err(Error::ClampedEccentricity {
+                        eccentricity: eccentricity.value(),
+                        t,
+                    })
+                } else {
+                    Ok(eccentricity.maximum(limits.clamp))
+                }
+            }
+            None => Ok(eccentricity.maximum(Self::SGP4.clamp)),
+        }
+    }
+}
+
+impl Default for EccentricityLimits {
+    fn default() -> Self {
+        Self::SGP4
+    }
+}
+
+/// Largest absolute propagation time, in minutes since epoch
+///
+/// SGP4 predictions degrade quickly away from the epoch (typically a few kilometers per day for low orbits),
+/// and element sets are usually refreshed every few days. `Constants::propagate_within_horizon`
+/// returns an `Error::BeyondHorizon` instead of a prediction if |t| is larger than the horizon,
+/// which prevents downstream systems from silently using stale element sets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Horizon(pub f64);
+
+impl Horizon {
+    /// A 30 days horizon, a conservative bound for most catalog objects
+    pub const THIRTY_DAYS: Self = Self(30.0 * 24.0 * 60.0);
+
+    /// Creates a horizon from a number of days
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - The largest absolute propagation time in days
+    pub fn from_days(days: f64) -> Self {
+        Self(days * 24.0 * 60.0)
+    }
+
+    // returns an error if t is outside [-horizon, horizon] (or NaN)
+    pub(crate) fn check(&self, t: f64) -> core::result::Result<(), Error> {
+        if (-self.0..=self.0).contains(&t) {
+            Ok(())
+        } else {
+            Err(Error::BeyondHorizon { horizon: self.0, t })
+        }
+    }
+}
+
+impl Default for Horizon {
+    fn default() -> Self {
+        Self::THIRTY_DAYS
+    }
+}
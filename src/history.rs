@@ -0,0 +1,465 @@
+use crate::gp;
+use crate::propagator;
+use crate::relative;
+use crate::tle;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The discontinuity between two consecutive element sets at their handover
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Handover {
+    /// Time of the handover in years since UTC 1 January 2000 12h00 (J2000)
+    pub epoch: f64,
+
+    /// State of the newer element set's prediction relative to the older one,
+    /// in the RIC frame of the older element set's prediction
+    pub ric_state: relative::RicState,
+
+    /// Norm of the position discontinuity in km
+    pub position_jump: f64,
+
+    /// Norm of the velocity discontinuity in km.s⁻¹
+    pub velocity_jump: f64,
+}
+
+/// The error of an element set propagated to the epoch of a newer element set
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropagationError {
+    /// Propagation horizon (newer epoch minus older epoch) in days
+    pub horizon: f64,
+
+    /// The three components (radial, in-track, cross-track) of the propagated position
+    /// relative to the newer element set's position, in the RIC frame of the newer element set in km
+    pub ric_position: [f64; 3],
+}
+
+/// Propagation error statistics for a range of horizons
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccuracyStatistics {
+    /// Number of element set pairs in the horizon range
+    pub samples: usize,
+
+    /// Mean error (radial, in-track, cross-track) in km
+    pub mean: [f64; 3],
+
+    /// Root mean square error (radial, in-track, cross-track) in km
+    pub rms: [f64; 3],
+
+    /// Largest position error norm in km
+    pub maximum: f64,
+}
+
+/// A time-ordered history of the element sets of a single satellite
+///
+/// The history is a continuous ephemeris: each element set is used from the midpoint
+/// between its epoch and the previous epoch to the midpoint between its epoch and the next epoch.
+/// The first and last element sets are used before and after the history, respectively.
+#[derive(Default)]
+pub struct ElementsHistory {
+    entries: alloc::vec::Vec<(tle::Elements, propagator::Constants)>,
+}
+
+impl ElementsHistory {
+    /// Returns an empty history
+    pub fn new() -> Self {
+        ElementsHistory::default()
+    }
+
+    /// Returns the number of element sets in the history
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the history contains no element sets
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds an element set to the history
+    ///
+    /// An element set with the same epoch as the new one is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Orbital elements of the history's satellite
+    pub fn insert(
+        &mut self,
+        elements: tle::Elements,
+    ) -> core::result::Result<(), crate::ElementsError> {
+        let constants = propagator::Constants::from_elements(&elements)?;
+        let index = self
+            .entries
+            .partition_point(|(other, _)| other.datetime < elements.datetime);
+        if self
+            .entries
+            .get(index)
+            .is_some_and(|(other, _)| other.datetime == elements.datetime)
+        {
+            self.entries[index] = (elements, constants);
+        } else {
+            self.entries.insert(index, (elements, constants));
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over the element sets in increasing epoch order
+    pub fn iter(&self) -> impl Iterator<Item = &tle::Elements> {
+        self.entries.iter().map(|(elements, _)| elements)
+    }
+
+    /// Returns the propagator constants used at the given time
+    ///
+    /// Returns `None` if the history is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The time in years since UTC 1 January 2000 12h00 (J2000)
+    pub fn constants(&self, epoch: f64) -> Option<&propagator::Constants> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        Some(&self.entries[self.handover_index(epoch)].1)
+    }
+
+    // index of the element set whose validity interval contains the given time
+    fn handover_index(&self, epoch: f64) -> usize {
+        // the handover between the element sets k - 1 and k happens at (y₀ₖ₋₁ + y₀ₖ) / 2
+        self.entries
+            .windows(2)
+            .take_while(|pair| (pair[0].0.epoch() + pair[1].0.epoch()) / 2.0 <= epoch)
+            .count()
+    }
+
+    /// Returns the median drag term of the element sets in a window centred on the given time
+    ///
+    /// The median is robust to the outliers that often affect the B* values of individual element sets.
+    /// Returns `None` if no element set lies in the window.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The centre of the window in years since UTC 1 January 2000 12h00 (J2000)
+    /// * `window` - The duration of the window in days
+    pub fn smoothed_drag_term(&self, epoch: f64, window: f64) -> Option<f64> {
+        let mut drag_terms: alloc::vec::Vec<f64> = self
+            .entries
+            .iter()
+            .filter(|(elements, _)| (elements.epoch() - epoch).abs() * 365.25 <= window / 2.0)
+            .map(|(elements, _)| elements.drag_term)
+            .collect();
+        if drag_terms.is_empty() {
+            return None;
+        }
+        drag_terms.sort_by(f64::total_cmp);
+
+        // the two middle indices are equal if the number of drag terms is odd
+        let length = drag_terms.len();
+        Some((drag_terms[(length - 1) / 2] + drag_terms[length / 2]) / 2.0)
+    }
+
+    /// Replaces the drag term used to propagate each element set with a smoothed drag term
+    ///
+    /// The smoothed drag term is the median of the drag terms in a window centred on the element set's epoch
+    /// (see [ElementsHistory::smoothed_drag_term](struct.ElementsHistory.html#method.smoothed_drag_term)).
+    /// The stored elements keep their published drag terms, only the propagator constants change.
+    /// Element sets inserted afterwards use their published drag terms until this method is called again.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The duration of the smoothing window in days
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let tle = (
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// );
+    /// let mut history = sgp4::ElementsHistory::new();
+    /// for (hours, drag_term) in [(0, -3.1515e-5), (12, 2.0e-4), (24, -2.9e-5)] {
+    ///     // virtual updates with noisy drag terms
+    ///     let mut elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+    ///     elements.datetime += sgp4::chrono::Duration::hours(hours);
+    ///     elements.mean_anomaly += elements.mean_motion * (hours as f64 / 24.0) * 360.0;
+    ///     elements.drag_term = drag_term;
+    ///     history.insert(elements)?;
+    /// }
+    /// history.smooth_drag_terms(3.0)?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn smooth_drag_terms(
+        &mut self,
+        window: f64,
+    ) -> core::result::Result<(), crate::ElementsError> {
+        let drag_terms: alloc::vec::Vec<f64> = self
+            .entries
+            .iter()
+            .map(|(elements, _)| {
+                self.smoothed_drag_term(elements.epoch(), window)
+                    .unwrap_or(elements.drag_term)
+            })
+            .collect();
+        for ((elements, constants), drag_term) in self.entries.iter_mut().zip(drag_terms) {
+            let published = core::mem::replace(&mut elements.drag_term, drag_term);
+            let smoothed = propagator::Constants::from_elements(elements);
+            elements.drag_term = published;
+            *constants = smoothed?;
+        }
+        Ok(())
+    }
+
+    /// Returns the position and velocity discontinuities at each handover
+    ///
+    /// Both element sets are propagated to the midpoint between their epochs.
+    /// The discontinuities are a measure of the catalog's effective accuracy for the satellite,
+    /// since each element set is propagated over half the interval between updates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let tle = (
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// );
+    /// let mut history = sgp4::ElementsHistory::new();
+    /// history.insert(sgp4::Elements::from_tle(None, tle.0, tle.1)?)?;
+    /// // a virtual update published 12 hours later (the mean anomaly is advanced by half a day)
+    /// let mut update = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+    /// update.datetime += sgp4::chrono::Duration::hours(12);
+    /// update.mean_anomaly += update.mean_motion * 0.5 * 360.0;
+    /// history.insert(update)?;
+    /// for handover in history.handovers()? {
+    ///     println!(
+    ///         "RIC jump = {:?} km ({} km)",
+    ///         handover.ric_state.position, handover.position_jump,
+    ///     );
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn handovers(&self) -> core::result::Result<alloc::vec::Vec<Handover>, gp::Error> {
+        self.entries
+            .windows(2)
+            .map(|pair| {
+                let epoch = (pair[0].0.epoch() + pair[1].0.epoch()) / 2.0;
+                let before = pair[0].1.propagate(pair[0].1.minutes_since_epoch(epoch))?;
+                let after = pair[1].1.propagate(pair[1].1.minutes_since_epoch(epoch))?;
+                Ok(Handover {
+                    epoch,
+                    ric_state: relative::ric_state(&before, &after),
+                    position_jump: vector::norm(&vector::sub(&after.position, &before.position)),
+                    velocity_jump: vector::norm(&vector::sub(&after.velocity, &before.velocity)),
+                })
+            })
+            .collect()
+    }
+
+    /// Propagates each element set to the epochs of the newer element sets and returns the position errors
+    ///
+    /// Newer element sets are used as references. Their own errors are therefore included in the results,
+    /// which makes the estimates slightly pessimistic for short horizons.
+    ///
+    /// # Arguments
+    ///
+    /// * `maximum_horizon` - The largest propagation horizon in days
+    pub fn propagation_errors(
+        &self,
+        maximum_horizon: f64,
+    ) -> core::result::Result<alloc::vec::Vec<PropagationError>, gp::Error> {
+        let mut errors = alloc::vec::Vec::new();
+        for (index, (elements, constants)) in self.entries.iter().enumerate() {
+            for (reference_elements, reference_constants) in &self.entries[index + 1..] {
+                let target = reference_elements.epoch();
+                let horizon = elements.age(target);
+                if horizon > maximum_horizon {
+                    break;
+                }
+                let reference = reference_constants.propagate(tle::MinutesSinceEpoch(0.0))?;
+                let prediction = constants.propagate(constants.minutes_since_epoch(target))?;
+                errors.push(PropagationError {
+                    horizon,
+                    ric_position: relative::ric_state(&reference, &prediction).position,
+                });
+            }
+        }
+        Ok(errors)
+    }
+
+    /// Estimates the propagation accuracy for a range of horizons by cross-validation
+    ///
+    /// Returns `None` if no pair of element sets is separated by a horizon in the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum_horizon` - The shortest propagation horizon in days
+    /// * `maximum_horizon` - The longest propagation horizon in days
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let tle = (
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// );
+    /// let mut history = sgp4::ElementsHistory::new();
+    /// for hours in [0, 12, 24, 36] {
+    ///     // virtual updates (the mean anomaly is advanced by the elapsed time)
+    ///     let mut elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+    ///     elements.datetime += sgp4::chrono::Duration::hours(hours);
+    ///     elements.mean_anomaly += elements.mean_motion * (hours as f64 / 24.0) * 360.0;
+    ///     history.insert(elements)?;
+    /// }
+    /// if let Some(statistics) = history.accuracy(0.0, 1.0)? {
+    ///     println!("RIC RMS error over 1 day: {:?} km", statistics.rms);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn accuracy(
+        &self,
+        minimum_horizon: f64,
+        maximum_horizon: f64,
+    ) -> core::result::Result<Option<AccuracyStatistics>, gp::Error> {
+        let errors: alloc::vec::Vec<PropagationError> = self
+            .propagation_errors(maximum_horizon)?
+            .into_iter()
+            .filter(|error| error.horizon >= minimum_horizon)
+            .collect();
+        if errors.is_empty() {
+            return Ok(None);
+        }
+        let samples = errors.len() as f64;
+        let mut statistics = AccuracyStatistics {
+            samples: errors.len(),
+            mean: [0.0; 3],
+            rms: [0.0; 3],
+            maximum: 0.0,
+        };
+        for error in &errors {
+            for (component, (mean, rms)) in error
+                .ric_position
+                .iter()
+                .zip(statistics.mean.iter_mut().zip(statistics.rms.iter_mut()))
+            {
+                *mean += component / samples;
+                *rms += component.powi(2) / samples;
+            }
+            statistics.maximum = statistics.maximum.max(vector::norm(&error.ric_position));
+        }
+        for rms in statistics.rms.iter_mut() {
+            *rms = rms.sqrt();
+        }
+        Ok(Some(statistics))
+    }
+
+    /// Calculates the satellite's position and velocity at the given time
+    ///
+    /// Without blending, the prediction jumps at each handover by the difference between consecutive element sets.
+    /// With blending, the predictions of the two element sets are mixed with a smooth weight
+    /// in a window centred on the handover, which yields a continuous position and velocity.
+    ///
+    /// Returns `None` if the history is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The time in years since UTC 1 January 2000 12h00 (J2000)
+    /// * `blend` - The duration of the blending window in minutes, zero disables blending
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let tle = (
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// );
+    /// let mut history = sgp4::ElementsHistory::new();
+    /// history.insert(sgp4::Elements::from_tle(None, tle.0, tle.1)?)?;
+    /// // a virtual update published 12 hours later (the mean anomaly is advanced by half a day)
+    /// let mut update = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+    /// update.datetime += sgp4::chrono::Duration::hours(12);
+    /// update.mean_anomaly += update.mean_motion * 0.5 * 360.0;
+    /// let start = update.epoch() - 1.0 / 365.25;
+    /// history.insert(update)?;
+    /// for minutes in (0..2 * 24 * 60).step_by(10) {
+    ///     let epoch = start + minutes as f64 / (365.25 * 24.0 * 60.0);
+    ///     if let Some(prediction) = history.propagate(epoch, 30.0) {
+    ///         println!("r = {:?} km", prediction?.position);
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn propagate(
+        &self,
+        epoch: f64,
+        blend: f64,
+    ) -> Option<core::result::Result<propagator::Prediction, gp::Error>> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let propagate = |index: usize| {
+            let constants = &self.entries[index].1;
+            constants.propagate(constants.minutes_since_epoch(epoch))
+        };
+        let index = self.handover_index(epoch);
+        if blend > 0.0 {
+            // the nearest handover is before or after the time
+            for handover_index in [index, index + 1] {
+                if handover_index == 0 || handover_index >= self.entries.len() {
+                    continue;
+                }
+                let handover = (self.entries[handover_index - 1].0.epoch()
+                    + self.entries[handover_index].0.epoch())
+                    / 2.0;
+
+                // s = (t - tₕ) / Δtᵦ + 1 / 2
+                let s = (epoch - handover) * (365.25 * 24.0 * 60.0) / blend + 0.5;
+                if (0.0..=1.0).contains(&s) {
+                    return Some(propagate(handover_index - 1).and_then(|before| {
+                        propagate(handover_index)
+                            .map(|after| blend_predictions(&before, &after, s, blend))
+                    }));
+                }
+            }
+        }
+        Some(propagate(index))
+    }
+}
+
+// mixes two predictions with the weight w = 3 s² - 2 s³, whose derivative is zero at both ends of the window
+fn blend_predictions(
+    before: &propagator::Prediction,
+    after: &propagator::Prediction,
+    s: f64,
+    blend: f64,
+) -> propagator::Prediction {
+    // w = 3 s² - 2 s³
+    let weight = s * s * (3.0 - 2.0 * s);
+
+    // ẇ = 6 s (1 - s) / (60 Δtᵦ) in s⁻¹ (Δtᵦ is the window duration in minutes)
+    let weight_rate = 6.0 * s * (1.0 - s) / (60.0 * blend);
+    let mut prediction = propagator::Prediction {
+        position: [0.0; 3],
+        velocity: [0.0; 3],
+    };
+    for index in 0..3 {
+        // r = (1 - w) r₁ + w r₂
+        prediction.position[index] =
+            (1.0 - weight) * before.position[index] + weight * after.position[index];
+
+        // ṙ = (1 - w) ṙ₁ + w ṙ₂ + ẇ (r₂ - r₁)
+        prediction.velocity[index] = (1.0 - weight) * before.velocity[index]
+            + weight * after.velocity[index]
+            + weight_rate * (after.position[index] - before.position[index]);
+    }
+    prediction
+}
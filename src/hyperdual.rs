@@ -0,0 +1,469 @@
+use crate::deep_space;
+use crate::gp;
+use crate::model;
+use crate::propagator;
+use crate::real::sealed::Sealed;
+use crate::real::Real;
+use crate::tle;
+use crate::ElementsError;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Hyperdual number a + b ε₁ + c ε₂ + d ε₁ε₂, with ε₁² = ε₂² = 0 and ε₁ε₂ ≠ 0
+///
+/// If the inputs x of a function f are seeded with x + ε₁ u + ε₂ v, the result is
+/// f + ε₁ ∇f·u + ε₂ ∇f·v + ε₁ε₂ uᵀ ∇²f v, exact to machine precision.
+/// Setting the ε₂ and ε₁ε₂ parts to zero yields a dual number (first derivatives only).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hyperdual {
+    /// Real part a
+    pub real: f64,
+
+    /// First derivative part b (ε₁)
+    pub e1: f64,
+
+    /// First derivative part c (ε₂)
+    pub e2: f64,
+
+    /// Second derivative part d (ε₁ε₂)
+    pub e12: f64,
+}
+
+impl Hyperdual {
+    /// Creates a hyperdual number from its four parts
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part
+    /// * `e1` - The ε₁ part
+    /// * `e2` - The ε₂ part
+    /// * `e12` - The ε₁ε₂ part
+    pub const fn new(real: f64, e1: f64, e2: f64, e12: f64) -> Self {
+        Hyperdual { real, e1, e2, e12 }
+    }
+
+    /// Creates a variable x + ε₁ + ε₂, which yields first and second derivatives with respect to x
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The variable's value
+    pub const fn variable(real: f64) -> Self {
+        Hyperdual::new(real, 1.0, 1.0, 0.0)
+    }
+
+    // applies a function with value f, first derivative f1 and second derivative f2 at the real part
+    fn chain(self, f: f64, f1: f64, f2: f64) -> Self {
+        Hyperdual {
+            real: f,
+            e1: f1 * self.e1,
+            e2: f1 * self.e2,
+            e12: f1 * self.e12 + f2 * self.e1 * self.e2,
+        }
+    }
+
+    // arctangent of a ratio, only its derivative parts are used
+    fn atan(self) -> Self {
+        let square = 1.0 + self.real * self.real;
+        self.chain(
+            self.real.atan(),
+            1.0 / square,
+            -2.0 * self.real / (square * square),
+        )
+    }
+}
+
+impl core::ops::Add for Hyperdual {
+    type Output = Hyperdual;
+    fn add(self, other: Hyperdual) -> Hyperdual {
+        Hyperdual {
+            real: self.real + other.real,
+            e1: self.e1 + other.e1,
+            e2: self.e2 + other.e2,
+            e12: self.e12 + other.e12,
+        }
+    }
+}
+
+impl core::ops::Sub for Hyperdual {
+    type Output = Hyperdual;
+    fn sub(self, other: Hyperdual) -> Hyperdual {
+        Hyperdual {
+            real: self.real - other.real,
+            e1: self.e1 - other.e1,
+            e2: self.e2 - other.e2,
+            e12: self.e12 - other.e12,
+        }
+    }
+}
+
+impl core::ops::Mul for Hyperdual {
+    type Output = Hyperdual;
+    fn mul(self, other: Hyperdual) -> Hyperdual {
+        Hyperdual {
+            real: self.real * other.real,
+            e1: self.real * other.e1 + self.e1 * other.real,
+            e2: self.real * other.e2 + self.e2 * other.real,
+            e12: self.real * other.e12
+                + self.e1 * other.e2
+                + self.e2 * other.e1
+                + self.e12 * other.real,
+        }
+    }
+}
+
+impl core::ops::Div for Hyperdual {
+    type Output = Hyperdual;
+    fn div(self, other: Hyperdual) -> Hyperdual {
+        let inverse = 1.0 / other.real;
+        Hyperdual {
+            real: self.real / other.real,
+            ..self
+                * other.chain(
+                    inverse,
+                    -inverse * inverse,
+                    2.0 * inverse * inverse * inverse,
+                )
+        }
+    }
+}
+
+impl core::ops::Neg for Hyperdual {
+    type Output = Hyperdual;
+    fn neg(self) -> Hyperdual {
+        Hyperdual {
+            real: -self.real,
+            e1: -self.e1,
+            e2: -self.e2,
+            e12: -self.e12,
+        }
+    }
+}
+
+impl core::ops::Add<f64> for Hyperdual {
+    type Output = Hyperdual;
+    fn add(self, other: f64) -> Hyperdual {
+        Hyperdual {
+            real: self.real + other,
+            ..self
+        }
+    }
+}
+
+impl core::ops::Sub<f64> for Hyperdual {
+    type Output = Hyperdual;
+    fn sub(self, other: f64) -> Hyperdual {
+        Hyperdual {
+            real: self.real - other,
+            ..self
+        }
+    }
+}
+
+impl core::ops::Mul<f64> for Hyperdual {
+    type Output = Hyperdual;
+    fn mul(self, other: f64) -> Hyperdual {
+        Hyperdual {
+            real: self.real * other,
+            e1: self.e1 * other,
+            e2: self.e2 * other,
+            e12: self.e12 * other,
+        }
+    }
+}
+
+impl core::ops::Div<f64> for Hyperdual {
+    type Output = Hyperdual;
+    fn div(self, other: f64) -> Hyperdual {
+        Hyperdual {
+            real: self.real / other,
+            e1: self.e1 / other,
+            e2: self.e2 / other,
+            e12: self.e12 / other,
+        }
+    }
+}
+
+impl core::ops::Rem<f64> for Hyperdual {
+    type Output = Hyperdual;
+    fn rem(self, other: f64) -> Hyperdual {
+        Hyperdual {
+            real: self.real % other,
+            ..self
+        }
+    }
+}
+
+impl core::ops::AddAssign for Hyperdual {
+    fn add_assign(&mut self, other: Hyperdual) {
+        *self = *self + other;
+    }
+}
+
+impl Sealed for Hyperdual {
+    const HAS_DERIVATIVES: bool = true;
+
+    fn map_sine_cosine(self, f: fn(f64) -> (f64, f64)) -> (Self, Self) {
+        let (sine, cosine) = f(self.real);
+        (
+            self.chain(sine, cosine, -sine),
+            self.chain(cosine, -sine, -cosine),
+        )
+    }
+
+    fn map_arctangent2(self, x: Self, f: fn(f64, f64) -> f64) -> Self {
+        // the derivatives of atan2(y, x) are those of atan(y / x) or -atan(x / y),
+        // the ratio with the smallest magnitude is used to avoid divisions by zero
+        let derivatives = if x.real.abs() >= self.real.abs() {
+            (self / x).atan()
+        } else {
+            -(x / self).atan()
+        };
+        Hyperdual {
+            real: f(self.real, x.real),
+            ..derivatives
+        }
+    }
+
+    fn euclidean_remainder(self, modulus: f64) -> Self {
+        Hyperdual {
+            real: self.real.euclidean_remainder(modulus),
+            ..self
+        }
+    }
+
+    fn clamped(self, minimum: f64, maximum: f64) -> Self {
+        let real = self.real.clamp(minimum, maximum);
+        if real == self.real {
+            self
+        } else {
+            Hyperdual::from_f64(real)
+        }
+    }
+
+    fn maximum(self, other: f64) -> Self {
+        let real = self.real.max(other);
+        if real == self.real {
+            self
+        } else {
+            Hyperdual::from_f64(real)
+        }
+    }
+}
+
+impl Real for Hyperdual {
+    fn from_f64(value: f64) -> Self {
+        Hyperdual::new(value, 0.0, 0.0, 0.0)
+    }
+
+    fn value(self) -> f64 {
+        self.real
+    }
+
+    fn sine(self) -> Self {
+        let sine = self.real.sin();
+        self.chain(sine, self.real.cos(), -sine)
+    }
+
+    fn cosine(self) -> Self {
+        let cosine = self.real.cos();
+        self.chain(cosine, -self.real.sin(), -cosine)
+    }
+
+    fn sine_cosine(self) -> (Self, Self) {
+        self.map_sine_cosine(|angle| angle.sin_cos())
+    }
+
+    fn square_root(self) -> Self {
+        let root = self.real.sqrt();
+        self.chain(root, 0.5 / root, -0.25 / (root * self.real))
+    }
+
+    fn absolute(self) -> Self {
+        if self.real < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn integer_power(self, exponent: i32) -> Self {
+        let exponent_f64 = exponent as f64;
+        self.chain(
+            self.real.powi(exponent),
+            exponent_f64 * self.real.powi(exponent - 1),
+            exponent_f64 * (exponent_f64 - 1.0) * self.real.powi(exponent - 2),
+        )
+    }
+
+    fn real_power(self, exponent: f64) -> Self {
+        self.chain(
+            self.real.powf(exponent),
+            exponent * self.real.powf(exponent - 1.0),
+            exponent * (exponent - 1.0) * self.real.powf(exponent - 2.0),
+        )
+    }
+
+    fn arctangent2(self, x: Self) -> Self {
+        self.map_arctangent2(x, |y, x| y.atan2(x))
+    }
+}
+
+/// Orbital elements and drag term with a generic number type
+///
+/// Seeding fields with [Hyperdual](struct.Hyperdual.html) variables yields
+/// the derivatives of the predictions with respect to these fields.
+#[derive(Debug, Clone)]
+pub struct DifferentiableElements<T: Real> {
+    /// The number of years since UTC 1 January 2000 12h00 (J2000)
+    pub epoch: f64,
+
+    /// Angle between the equator and the orbit plane in rad
+    pub inclination: T,
+
+    /// Angle between vernal equinox and the point where the orbit crosses the equatorial plane in rad
+    pub right_ascension: T,
+
+    /// Shape of the orbit
+    pub eccentricity: T,
+
+    /// Angle between the ascending node and the orbit's point of closest approach to the earth in rad
+    pub argument_of_perigee: T,
+
+    /// Angle of the satellite location measured from perigee in rad
+    pub mean_anomaly: T,
+
+    /// Mean orbital angular velocity in rad.min⁻¹ (Kozai convention)
+    pub kozai_mean_motion: T,
+
+    /// The radiation pressure coefficient in earth radii⁻¹ (B*)
+    pub drag_term: T,
+}
+
+impl<T: Real> DifferentiableElements<T> {
+    /// Converts TLE or OMM elements to SI angles without derivative parts
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Orbital elements and drag term parsed from a TLE or OMM
+    pub fn from_elements(elements: &tle::Elements) -> Self {
+        DifferentiableElements {
+            epoch: elements.epoch(),
+            inclination: T::from_f64(elements.inclination * (core::f64::consts::PI / 180.0)),
+            right_ascension: T::from_f64(
+                elements.right_ascension * (core::f64::consts::PI / 180.0),
+            ),
+            eccentricity: T::from_f64(elements.eccentricity),
+            argument_of_perigee: T::from_f64(
+                elements.argument_of_perigee * (core::f64::consts::PI / 180.0),
+            ),
+            mean_anomaly: T::from_f64(elements.mean_anomaly * (core::f64::consts::PI / 180.0)),
+            kozai_mean_motion: T::from_f64(elements.mean_motion * (core::f64::consts::PI / 720.0)),
+            drag_term: T::from_f64(elements.drag_term),
+        }
+    }
+}
+
+/// Errors returned by the differentiable propagator
+#[derive(Debug, Clone)]
+pub enum DifferentiableError {
+    /// The elements cannot be converted to Brouwer elements
+    Elements(ElementsError),
+
+    /// The propagated orbit diverged
+    Propagation(gp::Error),
+}
+
+impl core::fmt::Display for DifferentiableError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DifferentiableError::Elements(error) => error.fmt(formatter),
+            DifferentiableError::Propagation(error) => error.fmt(formatter),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DifferentiableError {}
+
+/// Calculates the SGP4 position and velocity predictions with a generic number type
+///
+/// This function runs the initialization and the propagation of `Constants::from_elements`
+/// and `Constants::propagate` with the same code, hence derivatives with respect to the elements,
+/// the drag term and the propagation time are exact (no finite differencing).
+/// With `f64`, the results are identical to those of `Constants::propagate`.
+/// Near earth and deep space orbits are supported. The resonance integrator of deep space orbits
+/// uses fixed 720 min steps, hence derivatives with respect to the time are those of the last step.
+///
+/// # Arguments
+///
+/// * `geopotential` - The model of Earth gravity
+/// * `elements` - The Kozai elements and drag term at epoch
+/// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+///
+/// // derivatives with respect to the time (ε₁) and the drag term (ε₂)
+/// let mut differentiable = sgp4::DifferentiableElements::<sgp4::Hyperdual>::from_elements(&elements);
+/// differentiable.drag_term.e2 = 1.0;
+/// let prediction = sgp4::propagate_differentiable(
+///     &sgp4::WGS84,
+///     &differentiable,
+///     sgp4::Hyperdual::new(1440.0, 1.0, 0.0, 0.0),
+/// )?;
+///
+/// // the time derivative of the position is close to the SGP4 velocity (km.s⁻¹)
+/// for index in 0..3 {
+///     assert!((prediction.position[index].e1 / 60.0 - prediction.velocity[index].real).abs() < 1e-3);
+/// }
+/// println!("∂r/∂B* = {} km", prediction.position[0].e2);
+/// println!("∂²r/∂t∂B* = {} km.min⁻¹", prediction.position[0].e12);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn propagate_differentiable<T: Real>(
+    geopotential: &model::Geopotential,
+    elements: &DifferentiableElements<T>,
+    t: T,
+) -> core::result::Result<propagator::Prediction<T>, DifferentiableError> {
+    let constants = propagator::Constants::initialize(
+        *geopotential,
+        model::iau_epoch_to_sidereal_time,
+        None,
+        &deep_space::ResonanceBoundaries::SGP4,
+        elements.epoch,
+        elements.drag_term,
+        propagator::Orbit {
+            inclination: elements.inclination,
+            right_ascension: elements.right_ascension,
+            eccentricity: elements.eccentricity,
+            argument_of_perigee: elements.argument_of_perigee,
+            mean_anomaly: elements.mean_anomaly,
+            mean_motion: crate::brouwer_mean_motion(
+                geopotential,
+                elements.inclination,
+                elements.eccentricity,
+                elements.kozai_mean_motion,
+            )
+            .map_err(|error| DifferentiableError::Elements(error.into()))?,
+        },
+    )
+    .map_err(|error| DifferentiableError::Elements(error.into()))?;
+    constants
+        .propagate_from_state_with_limits(
+            t,
+            constants.initial_state().as_mut(),
+            false,
+            Some(&gp::EccentricityLimits::SGP4),
+        )
+        .map_err(DifferentiableError::Propagation)
+}
@@ -0,0 +1,108 @@
+use crate::model;
+use crate::propagator;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+impl propagator::Orbit {
+    /// Converts a position and velocity to osculating Keplerian elements
+    ///
+    /// The returned elements are osculating (two-body) elements, not Brouwer mean elements,
+    /// and must not be used to initialize an SGP4 propagator. The mean motion is the osculating
+    /// Keplerian mean motion. The orbit must be elliptic.
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity that provides the gravitational parameter
+    /// * `prediction` - The position and velocity in the TEME reference frame
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let orbit = sgp4::Orbit::from_prediction(
+    ///     &sgp4::WGS84,
+    ///     &constants.propagate(sgp4::MinutesSinceEpoch(60.0))?,
+    /// );
+    /// println!("osculating eccentricity = {}", orbit.eccentricity);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_prediction(
+        geopotential: &model::Geopotential,
+        prediction: &propagator::Prediction,
+    ) -> Self {
+        let position = &prediction.position;
+        let velocity = &prediction.velocity;
+
+        // μ = kₑ² aₑ³ / 60² (km³.s⁻²)
+        let mu = geopotential.ke.powi(2) * geopotential.ae.powi(3) / 3600.0;
+        let radius = vector::norm(position);
+        let radial_velocity = vector::dot(position, velocity);
+        let squared_speed = vector::dot(velocity, velocity);
+
+        // h = r × v
+        let angular_momentum = vector::cross(position, velocity);
+        let angular_momentum_norm = vector::norm(&angular_momentum);
+
+        // e = ((|v|² - μ / |r|) r - (r · v) v) / μ
+        let eccentricity_vector = [
+            ((squared_speed - mu / radius) * position[0] - radial_velocity * velocity[0]) / mu,
+            ((squared_speed - mu / radius) * position[1] - radial_velocity * velocity[1]) / mu,
+            ((squared_speed - mu / radius) * position[2] - radial_velocity * velocity[2]) / mu,
+        ];
+
+        // a = 1 / (2 / |r| - |v|² / μ)
+        let semi_major_axis = 1.0 / (2.0 / radius - squared_speed / mu);
+
+        // Ω = tan⁻¹(hₓ / -hᵧ)
+        let right_ascension = angular_momentum[0].atan2(-angular_momentum[1]);
+
+        // p̂ = [cos Ω, sin Ω, 0], q̂ = ĥ × p̂
+        let node = [right_ascension.cos(), right_ascension.sin(), 0.0];
+        let normal = [
+            angular_momentum[0] / angular_momentum_norm,
+            angular_momentum[1] / angular_momentum_norm,
+            angular_momentum[2] / angular_momentum_norm,
+        ];
+        let in_plane = vector::cross(&normal, &node);
+
+        // ω = tan⁻¹((e · q̂) / (e · p̂))
+        let argument_of_perigee = vector::dot(&eccentricity_vector, &in_plane)
+            .atan2(vector::dot(&eccentricity_vector, &node));
+
+        // u = tan⁻¹((r · q̂) / (r · p̂))
+        let argument_of_latitude =
+            vector::dot(position, &in_plane).atan2(vector::dot(position, &node));
+        let eccentricity = vector::norm(&eccentricity_vector);
+
+        // ν = u - ω
+        let true_anomaly = argument_of_latitude - argument_of_perigee;
+
+        // E = 2 tan⁻¹(((1 - e) / (1 + e))¹ᐟ² tan(ν / 2))
+        let eccentric_anomaly = 2.0
+            * (((1.0 - eccentricity) / (1.0 + eccentricity)).sqrt() * (0.5 * true_anomaly).sin())
+                .atan2((0.5 * true_anomaly).cos());
+        propagator::Orbit {
+            // I = cos⁻¹(h_z / |h|)
+            inclination: normal[2].clamp(-1.0, 1.0).acos(),
+            right_ascension: model::positive_angle(right_ascension),
+            eccentricity,
+            argument_of_perigee: model::positive_angle(argument_of_perigee),
+
+            // M = E - e sin E
+            mean_anomaly: model::positive_angle(
+                eccentric_anomaly - eccentricity * eccentric_anomaly.sin(),
+            ),
+
+            // n = (μ / a³)¹ᐟ² (rad.min⁻¹)
+            mean_motion: 60.0 * (mu / semi_major_axis.powi(3)).sqrt(),
+        }
+    }
+}
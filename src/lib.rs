@@ -45,305 +45,1551 @@ compile_error!("feature \"std\" and feature \"libm\" cannot be enabled at the sa
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(not(feature = "std"))]
-use num_traits::Float;
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
 
+#[cfg(feature = "alloc")]
+mod altitude;
+mod angle;
+#[cfg(feature = "alloc")]
+mod apsides;
+#[cfg(feature = "ndarray")]
+mod array;
+mod astrometry;
+mod atmosphere;
+#[cfg(feature = "alloc")]
+mod batch;
+mod builder;
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "alloc")]
+mod catalog;
+#[cfg(feature = "alloc")]
+mod cdm;
+#[cfg(feature = "serde")]
+mod client;
+mod clohessy_wiltshire;
+#[cfg(feature = "alloc")]
+mod conjunction;
+mod correlation;
+#[cfg(feature = "alloc")]
+mod coverage;
+mod decay;
 mod deep_space;
+mod diff;
+mod ephemeris;
+mod events;
+#[cfg(all(feature = "std", feature = "serde"))]
+mod fetcher;
+mod ffi;
+#[cfg(feature = "alloc")]
+mod fit;
+#[cfg(feature = "fixed-point")]
+mod fixed_point;
+mod geo;
+mod geodetic;
+mod geometry;
+#[cfg(feature = "alloc")]
+mod glint;
 mod gp;
+#[cfg(feature = "alloc")]
+mod history;
+#[cfg(feature = "hyperdual")]
+mod hyperdual;
+mod kepler;
+mod lifetime;
+#[cfg(all(feature = "std", feature = "serde"))]
+mod loader;
+mod metadata;
+#[cfg(feature = "uniffi")]
+mod mobile;
 mod model;
 mod near_earth;
+#[cfg(feature = "alloc")]
+mod nodes;
+mod norad_id;
+#[cfg(feature = "alloc")]
+mod observations;
+mod observer;
+#[cfg(feature = "alloc")]
+mod passes;
+mod photometry;
+#[cfg(feature = "alloc")]
+mod planes;
 mod propagator;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "alloc")]
+mod quality;
+mod radio;
+mod re_epoch;
+mod real;
+#[cfg(feature = "alloc")]
+mod refresh;
+#[cfg(feature = "alloc")]
+mod region;
+mod relative;
+#[cfg(feature = "alloc")]
+mod revisit;
+mod roe;
+#[cfg(feature = "alloc")]
+mod schedule;
+#[cfg(feature = "alloc")]
+mod screening;
+mod selection;
+mod solar_time;
+#[cfg(feature = "alloc")]
+mod space_track;
+mod staleness;
+#[cfg(feature = "alloc")]
+mod station_keeping;
+#[cfg(feature = "alloc")]
+mod statistics;
+mod streak;
+#[cfg(feature = "alloc")]
+mod terminator;
 mod third_body;
+#[cfg(feature = "hifitime")]
+mod time_scales;
 mod tle;
+#[cfg(feature = "alloc")]
+mod transits;
+mod trig;
+mod uncertainty;
+#[cfg(feature = "alloc")]
+mod validation;
+mod vector;
+#[cfg(feature = "verification")]
+mod verification;
+#[cfg(feature = "wasm")]
+mod wasm;
+pub use angle::Degrees;
+pub use angle::Radians;
+pub use astrometry::teme_to_mean_j2000;
+pub use astrometry::AtmosphericConditions;
+pub use astrometry::Equatorial;
+pub use atmosphere::exponential_density;
+pub use atmosphere::DensityModel;
+pub use atmosphere::ExponentialDensity;
+pub use atmosphere::SolarActivity;
+pub use builder::ConstantsBuilder;
+pub use builder::EpochConvention;
 pub use chrono;
+pub use clohessy_wiltshire::ClohessyWiltshire;
+pub use correlation::ElementsWeights;
+pub use decay::DecayEstimate;
+pub use deep_space::DeepSpaceCoefficients;
+pub use deep_space::ResonanceBoundaries;
+pub use deep_space::ResonanceCoefficients;
 pub use deep_space::ResonanceState;
+pub use diff::ElementsDiff;
+pub use ephemeris::moon_position;
+pub use ephemeris::sun_position;
+pub use events::Crossing;
+pub use events::Direction;
+pub use ffi::CElements;
+pub use ffi::CElementsError;
+pub use ffi::CLookAngles;
+pub use ffi::CPrediction;
+pub use geo::GeoState;
+pub use geo::Libration;
+pub use geodetic::Geodetic;
+pub use gp::EccentricityLimits;
 pub use gp::Error;
+pub use gp::Horizon;
+pub use metadata::ElementsMetadata;
 pub use model::afspc_epoch_to_sidereal_time;
+pub use model::era_epoch_to_sidereal_time;
 pub use model::iau_epoch_to_sidereal_time;
 pub use model::Geopotential;
+pub use model::EGM2008;
+pub use model::EGM96;
+pub use model::JGM3;
 pub use model::WGS72;
+pub use model::WGS72_OLD;
 pub use model::WGS84;
+pub use norad_id::NoradId;
+pub use norad_id::NoradIdError;
+pub use observer::LookAngles;
+pub use observer::MaidenheadError;
+pub use observer::Observer;
+pub use photometry::phase_angle;
+pub use photometry::visual_magnitude;
 pub use propagator::Constants;
 pub use propagator::Orbit;
 pub use propagator::Prediction;
+pub use radio::doppler_shift;
+pub use radio::DopplerPair;
+pub use radio::Transponder;
+pub use re_epoch::ReEpochError;
+pub use real::Real;
+pub use relative::line_of_sight;
+pub use relative::relative_state;
+pub use relative::ric_state;
+pub use relative::RelativeState;
+pub use relative::RicState;
+pub use roe::RelativeOrbitalElements;
+pub use selection::select_elements;
+pub use selection::SelectionPolicy;
+pub use staleness::AgeStatus;
+pub use staleness::AgeThresholds;
+pub use streak::Streak;
+pub use third_body::Coefficients as ThirdBodyCoefficients;
+pub use third_body::Dots as ThirdBodyDots;
+pub use third_body::Effects as ThirdBodyEffects;
+pub use third_body::ThirdBodies;
+pub use third_body::ThirdBodyElements;
+pub use tle::iter_tles;
 pub use tle::julian_years_since_j2000;
 pub use tle::julian_years_since_j2000_afspc_compatibility_mode;
+pub use tle::minutes_since_epoch;
 pub use tle::Classification;
 pub use tle::DatetimeToMinutesSinceEpochError;
 pub use tle::Elements;
 pub use tle::Error as TleError;
 pub use tle::ErrorLine as TleErrorLine;
 pub use tle::ErrorWhat as TleErrorWhat;
+pub use tle::FormatError as TleFormatError;
 pub use tle::MinutesSinceEpoch;
 pub use tle::MinutesSinceEpochToDatetimeError;
+pub use tle::Tles;
+pub use uncertainty::ErrorGrowth;
+pub use uncertainty::ErrorGrowthModel;
+pub use uncertainty::OrbitRegime;
+pub use uncertainty::UncertainPrediction;
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub use tle::parse_2les;
+pub use altitude::AltitudeExtrema;
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub use tle::parse_3les;
+pub use altitude::AltitudeExtremum;
 
-/// Represents a propagation error caused by orbital elements divergence
-#[derive(Debug, Clone)]
-pub enum KozaiElementsError {
-    NegativeKozaiMeanMotion,
-    NegativeBrouwerMeanMotion,
-}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use apsides::Apsis;
 
-impl core::fmt::Display for KozaiElementsError {
-    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            KozaiElementsError::NegativeKozaiMeanMotion => formatter
-                .write_str("The Kozai mean motion calculated from epoch elements is negative"),
-            KozaiElementsError::NegativeBrouwerMeanMotion => formatter
-                .write_str("The Brouwer mean motion calculated from epoch elements is negative"),
-        }
-    }
-}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use apsides::ApsisPassage;
 
-#[cfg(feature = "std")]
-impl std::error::Error for KozaiElementsError {}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use catalog::Catalog;
 
-/// The orbit used to generate epoch constants has an invalid eccentricity
-#[derive(Debug, Clone)]
-pub struct OutOfRangeEpochEccentricity(pub f64);
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use catalog::CatalogDiff;
 
-impl core::fmt::Display for OutOfRangeEpochEccentricity {
-    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        formatter.write_fmt(core::format_args!(
-            "The epoch eccentricity ({}) is outside the range [0, 1[",
-            self.0
-        ))
-    }
-}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use catalog::CatalogUpdate;
 
-#[cfg(feature = "std")]
-impl std::error::Error for OutOfRangeEpochEccentricity {}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use catalog::ElementsField;
 
-/// Errors returned when creating epoch contants from elements
-#[derive(Debug, Clone)]
-pub enum ElementsError {
-    KozaiElementsError(KozaiElementsError),
-    OutOfRangeEpochEccentricity(OutOfRangeEpochEccentricity),
-}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use catalog::ElementsTolerances;
 
-impl core::fmt::Display for ElementsError {
-    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            ElementsError::KozaiElementsError(error) => error.fmt(formatter),
-            ElementsError::OutOfRangeEpochEccentricity(error) => error.fmt(formatter),
-        }
-    }
-}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use cdm::Cdm;
 
-impl From<KozaiElementsError> for ElementsError {
-    fn from(value: KozaiElementsError) -> Self {
-        Self::KozaiElementsError(value)
-    }
-}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use cdm::CdmError;
 
-impl From<OutOfRangeEpochEccentricity> for ElementsError {
-    fn from(value: OutOfRangeEpochEccentricity) -> Self {
-        Self::OutOfRangeEpochEccentricity(value)
-    }
-}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use cdm::CdmObject;
 
-#[cfg(feature = "std")]
-impl std::error::Error for ElementsError {}
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use conjunction::closest_approach;
 
-impl Orbit {
-    /// Creates a new Brouwer orbit representation from Kozai elements
-    ///
-    /// If the Kozai orbital elements are obtained from a TLE or OMM,
-    /// the convenience function [sgp4::Constants::from_elements](struct.Constants.html#method.from_elements)
-    /// can be used instead of manually mapping the `Elements` fields to the `Constants::new` parameters.
-    ///
-    /// # Arguments
-    ///
-    /// * `geopotential` - The model of Earth gravity to use in the conversion
-    /// * `inclination` - Angle between the equator and the orbit plane in rad
-    /// * `right_ascension` - Angle between vernal equinox and the point where the orbit crosses the equatorial plane in rad
-    /// * `eccentricity` - The shape of the orbit
-    /// * `argument_of_perigee` - Angle between the ascending node and the orbit's point of closest approach to the earth in rad
-    /// * `mean_anomaly` - Angle of the satellite location measured from perigee in rad
-    /// * `kozai_mean_motion` - Mean orbital angular velocity in rad.min⁻¹ (Kozai convention)
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # fn main() -> anyhow::Result<()> {
-    /// let elements = sgp4::Elements::from_tle(
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use conjunction::conjunctions;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use conjunction::conjunctions_in_volume;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use conjunction::Conjunction;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use conjunction::ScreeningVolume;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use coverage::coverage_map;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use coverage::CoverageMap;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use events::find_crossings;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use fit::ElementsFit;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use fit::FitError;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use fit::FitOptions;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use fit::StateVector;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use glint::glint_angle;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use glint::Glint;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use glint::PanelOrientation;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use history::AccuracyStatistics;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use history::ElementsHistory;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use history::Handover;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use history::PropagationError;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use nodes::Node;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use nodes::NodeCrossing;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use observations::Measurement;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use observations::Observation;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use passes::CompassPoint;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use passes::Pass;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use passes::TrackPoint;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use planes::orbital_planes;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use planes::OrbitalPlane;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use quality::tle_quality;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use quality::ElementsQuality;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use refresh::CatalogSource;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use refresh::RefreshError;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use refresh::RefreshingTracker;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use region::Region;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use region::RegionLookup;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use region::RegionSegment;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use region::RegionVisit;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use revisit::revisit;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use revisit::Access;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use revisit::Revisit;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use revisit::Target;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use schedule::contacts;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use schedule::schedule;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use schedule::Contact;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use screening::conjunction_possible;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use screening::screen_catalog;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use screening::CatalogConjunction;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use space_track::SortOrder;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use space_track::SpaceTrackClass;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use space_track::SpaceTrackField;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use space_track::SpaceTrackFormat;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use space_track::SpaceTrackPredicate;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use space_track::SpaceTrackQuery;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use space_track::SpaceTrackTime;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use space_track::SpaceTrackValue;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use station_keeping::BoxViolation;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use station_keeping::StationKeepingBox;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use statistics::CatalogKey;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use statistics::CatalogStatistics;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use terminator::Terminator;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use terminator::TerminatorCrossing;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use tle::parse_2les;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use tle::parse_3les;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use transits::Transit;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use transits::TransitBody;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use validation::ElementsIssue;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use cache::CacheEntry;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use cache::CacheFileError;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use cache::CacheSource;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use cache::CatalogCache;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use client::CelestrakQuery;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use client::ClientError;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use client::GpClient;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use client::HttpClient;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use client::HttpResponse;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use client::SpaceTrackCredentials;
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "serde"))))]
+pub use fetcher::CacheStatus;
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "serde"))))]
+pub use fetcher::CachingFetcher;
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "serde"))))]
+pub use fetcher::FetcherError;
+
+#[cfg(all(feature = "std", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "serde"))))]
+pub use loader::LoadError;
+
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+pub use array::predictions_to_array;
+
+#[cfg(feature = "fixed-point")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fixed-point")))]
+pub use fixed_point::FixedPointConstants;
+
+#[cfg(feature = "fixed-point")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fixed-point")))]
+pub use fixed_point::FixedPointPrediction;
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub use heapless;
+
+#[cfg(feature = "hyperdual")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hyperdual")))]
+pub use hyperdual::propagate_differentiable;
+
+#[cfg(feature = "hyperdual")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hyperdual")))]
+pub use hyperdual::DifferentiableElements;
+
+#[cfg(feature = "hyperdual")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hyperdual")))]
+pub use hyperdual::DifferentiableError;
+
+#[cfg(feature = "hyperdual")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hyperdual")))]
+pub use hyperdual::Hyperdual;
+
+#[cfg(feature = "hifitime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hifitime")))]
+pub use hifitime;
+
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+pub use ndarray;
+
+#[cfg(feature = "uniffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uniffi")))]
+pub use mobile::UniffiError;
+
+#[cfg(feature = "uniffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uniffi")))]
+pub use mobile::UniffiPrediction;
+
+#[cfg(feature = "uniffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uniffi")))]
+pub use mobile::UniffiPropagator;
+
+#[cfg(feature = "python")]
+#[cfg_attr(docsrs, doc(cfg(feature = "python")))]
+pub use python::PyConstants;
+
+#[cfg(feature = "python")]
+#[cfg_attr(docsrs, doc(cfg(feature = "python")))]
+pub use python::PyElements;
+
+#[cfg(feature = "hifitime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hifitime")))]
+pub use time_scales::datetime_to_hifitime;
+
+#[cfg(feature = "hifitime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hifitime")))]
+pub use time_scales::hifitime_to_datetime;
+
+#[cfg(all(feature = "heapless", not(feature = "alloc")))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "heapless", not(feature = "alloc")))))]
+pub use tle::InternationalDesignator;
+
+#[cfg(all(feature = "heapless", not(feature = "alloc")))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "heapless", not(feature = "alloc")))))]
+pub use tle::ObjectName;
+
+#[cfg(feature = "verification")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verification")))]
+pub use verification::reference_cases;
+
+#[cfg(feature = "verification")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verification")))]
+pub use verification::verify;
+
+#[cfg(feature = "verification")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verification")))]
+pub use verification::ReferenceCase;
+
+#[cfg(feature = "verification")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verification")))]
+pub use verification::ReferenceState;
+
+#[cfg(feature = "verification")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verification")))]
+pub use verification::Residuals;
+
+#[cfg(feature = "wasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+pub use wasm::WasmPropagator;
+
+/// Represents an error raised while converting Kozai elements to Brouwer elements
+#[derive(Debug, Clone)]
+pub enum KozaiElementsError {
+    /// The Kozai mean motion is negative or zero
+    NegativeKozaiMeanMotion {
+        /// The invalid Kozai mean motion in rad.min⁻¹
+        kozai_mean_motion: f64,
+    },
+
+    /// The Brouwer mean motion derived from the Kozai mean motion is negative or zero
+    NegativeBrouwerMeanMotion {
+        /// The invalid Brouwer mean motion in rad.min⁻¹
+        brouwer_mean_motion: f64,
+    },
+
+    /// The eccentricity is outside the range [0, 1[
+    OutOfRangeEccentricity {
+        /// The invalid eccentricity (unitless)
+        eccentricity: f64,
+    },
+}
+
+impl core::fmt::Display for KozaiElementsError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            KozaiElementsError::NegativeKozaiMeanMotion { kozai_mean_motion } => {
+                formatter.write_fmt(core::format_args!(
+                    "The Kozai mean motion calculated from epoch elements ({} rad.min⁻¹) is negative",
+                    kozai_mean_motion
+                ))
+            }
+            KozaiElementsError::NegativeBrouwerMeanMotion {
+                brouwer_mean_motion,
+            } => formatter.write_fmt(core::format_args!(
+                "The Brouwer mean motion calculated from epoch elements ({} rad.min⁻¹) is negative",
+                brouwer_mean_motion
+            )),
+            KozaiElementsError::OutOfRangeEccentricity { eccentricity } => {
+                formatter.write_fmt(core::format_args!(
+                    "The epoch eccentricity ({}) is outside the range [0, 1[",
+                    eccentricity
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KozaiElementsError {}
+
+/// The orbit used to generate epoch constants has an invalid eccentricity
+#[derive(Debug, Clone)]
+pub struct OutOfRangeEpochEccentricity(pub f64);
+
+impl core::fmt::Display for OutOfRangeEpochEccentricity {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_fmt(core::format_args!(
+            "The epoch eccentricity ({}) is outside the range [0, 1[",
+            self.0
+        ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRangeEpochEccentricity {}
+
+/// Errors returned when creating epoch contants from elements
+#[derive(Debug, Clone)]
+pub enum ElementsError {
+    KozaiElementsError(KozaiElementsError),
+    OutOfRangeEpochEccentricity(OutOfRangeEpochEccentricity),
+}
+
+impl core::fmt::Display for ElementsError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ElementsError::KozaiElementsError(error) => error.fmt(formatter),
+            ElementsError::OutOfRangeEpochEccentricity(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl From<KozaiElementsError> for ElementsError {
+    fn from(value: KozaiElementsError) -> Self {
+        Self::KozaiElementsError(value)
+    }
+}
+
+impl From<OutOfRangeEpochEccentricity> for ElementsError {
+    fn from(value: OutOfRangeEpochEccentricity) -> Self {
+        Self::OutOfRangeEpochEccentricity(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ElementsError {}
+
+// 𝛿₀ as a function of the Kozai mean motion
+fn kozai_correction<T: Real>(
+    geopotential: &Geopotential,
+    inclination: T,
+    eccentricity: T,
+    kozai_mean_motion: T,
+) -> T {
+    let c = T::from_f64;
+
+    // a₁ = (kₑ / n₀)²ᐟ³
+    let a1 = (c(geopotential.ke) / kozai_mean_motion).real_power(2.0 / 3.0);
+
+    //      3      3 cos²I₀ - 1
+    // p₀ = - J₂ ---------------
+    //      4       (1 − e₀²)³ᐟ²
+    let p0 = c(0.75 * geopotential.j2) * (c(3.0) * inclination.cosine().integer_power(2) - 1.0)
+        / (c(1.0) - eccentricity.integer_power(2)).real_power(3.0 / 2.0);
+
+    // 𝛿₁ = p₀ / a₁²
+    let d1 = p0 / a1.integer_power(2);
+
+    // 𝛿₀ = p₀ / (a₁ (1 - ¹/₃ 𝛿₁ - 𝛿₁² - ¹³⁴/₈₁ 𝛿₁³))²
+    p0 / (a1
+        * (c(1.0)
+            - d1.integer_power(2)
+            - d1 * (c(1.0 / 3.0) + c(134.0) * d1.integer_power(2) / 81.0)))
+        .integer_power(2)
+}
+
+/// Converts a Kozai mean motion to a Brouwer mean motion
+///
+/// TLEs and OMMs store the Kozai mean motion, whereas SGP4 propagates Brouwer mean elements.
+///
+/// # Arguments
+///
+/// * `geopotential` - The model of Earth gravity to use in the conversion
+/// * `inclination` - Angle between the equator and the orbit plane, see [Radians](struct.Radians.html)
+/// * `eccentricity` - The shape of the orbit
+/// * `kozai_mean_motion` - Mean orbital angular velocity in rad.min⁻¹ (Kozai convention)
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let inclination = sgp4::Degrees(elements.inclination);
+/// let kozai_mean_motion = elements.mean_motion * (core::f64::consts::PI / 720.0);
+/// let brouwer_mean_motion = sgp4::kozai_to_brouwer(
+///     &sgp4::WGS84,
+///     inclination,
+///     elements.eccentricity,
+///     kozai_mean_motion,
+/// )?;
+/// let round_trip = sgp4::brouwer_to_kozai(
+///     &sgp4::WGS84,
+///     inclination,
+///     elements.eccentricity,
+///     brouwer_mean_motion,
+/// )?;
+/// assert!((round_trip - kozai_mean_motion).abs() < 1e-12);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn kozai_to_brouwer(
+    geopotential: &Geopotential,
+    inclination: impl Into<Radians>,
+    eccentricity: f64,
+    kozai_mean_motion: f64,
+) -> core::result::Result<f64, KozaiElementsError> {
+    brouwer_mean_motion(
+        geopotential,
+        inclination.into().0,
+        eccentricity,
+        kozai_mean_motion,
+    )
+}
+
+// n₀" as a function of the Kozai mean motion
+fn brouwer_mean_motion<T: Real>(
+    geopotential: &Geopotential,
+    inclination: T,
+    eccentricity: T,
+    kozai_mean_motion: T,
+) -> core::result::Result<T, KozaiElementsError> {
+    if kozai_mean_motion.value() <= 0.0 {
+        Err(KozaiElementsError::NegativeKozaiMeanMotion {
+            kozai_mean_motion: kozai_mean_motion.value(),
+        })
+    } else if !(0.0..1.0).contains(&eccentricity.value()) {
+        Err(KozaiElementsError::OutOfRangeEccentricity {
+            eccentricity: eccentricity.value(),
+        })
+    } else {
+        //         n₀
+        // n₀" = ------
+        //       1 + 𝛿₀
+        let brouwer_mean_motion = kozai_mean_motion
+            / (T::from_f64(1.0)
+                + kozai_correction(geopotential, inclination, eccentricity, kozai_mean_motion));
+        if brouwer_mean_motion.value() <= 0.0 {
+            Err(KozaiElementsError::NegativeBrouwerMeanMotion {
+                brouwer_mean_motion: brouwer_mean_motion.value(),
+            })
+        } else {
+            Ok(brouwer_mean_motion)
+        }
+    }
+}
+
+/// Converts a Brouwer mean motion to a Kozai mean motion
+///
+/// This is the inverse of [kozai_to_brouwer](fn.kozai_to_brouwer.html), solved by fixed-point iteration.
+/// It can be used to write SGP4 mean elements, for example fitted or propagated, to a TLE or OMM.
+///
+/// # Arguments
+///
+/// * `geopotential` - The model of Earth gravity to use in the conversion
+/// * `inclination` - Angle between the equator and the orbit plane, see [Radians](struct.Radians.html)
+/// * `eccentricity` - The shape of the orbit
+/// * `brouwer_mean_motion` - Mean orbital angular velocity in rad.min⁻¹ (Brouwer convention)
+pub fn brouwer_to_kozai(
+    geopotential: &Geopotential,
+    inclination: impl Into<Radians>,
+    eccentricity: f64,
+    brouwer_mean_motion: f64,
+) -> core::result::Result<f64, KozaiElementsError> {
+    let inclination = inclination.into().0;
+    if brouwer_mean_motion <= 0.0 {
+        return Err(KozaiElementsError::NegativeBrouwerMeanMotion {
+            brouwer_mean_motion,
+        });
+    }
+    if !(0.0..1.0).contains(&eccentricity) {
+        return Err(KozaiElementsError::OutOfRangeEccentricity { eccentricity });
+    }
+
+    // n₀ = n₀" (1 + 𝛿₀(n₀)) solved by fixed-point iteration
+    let mut kozai_mean_motion = brouwer_mean_motion;
+    for _ in 0..10 {
+        let next = brouwer_mean_motion
+            * (1.0 + kozai_correction(geopotential, inclination, eccentricity, kozai_mean_motion));
+        if (next - kozai_mean_motion).abs() < 1.0e-15 {
+            return Ok(next);
+        }
+        kozai_mean_motion = next;
+    }
+    Ok(kozai_mean_motion)
+}
+
+impl Orbit {
+    /// Creates a new Brouwer orbit representation from Kozai elements
+    ///
+    /// If the Kozai orbital elements are obtained from a TLE or OMM,
+    /// the convenience function [sgp4::Constants::from_elements](struct.Constants.html#method.from_elements)
+    /// can be used instead of manually mapping the `Elements` fields to the `Constants::new` parameters.
+    ///
+    /// Angles are given as [Radians](struct.Radians.html) or [Degrees](struct.Degrees.html).
+    /// `Elements` store angles in degrees, hence their fields must be wrapped in `Degrees`.
+    ///
+    /// The conversion fails if the Kozai or Brouwer mean motion is not strictly positive,
+    /// or if the eccentricity is outside the range [0, 1[. The error carries the offending value.
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    /// * `inclination` - Angle between the equator and the orbit plane
+    /// * `right_ascension` - Angle between vernal equinox and the point where the orbit crosses the equatorial plane
+    /// * `eccentricity` - The shape of the orbit
+    /// * `argument_of_perigee` - Angle between the ascending node and the orbit's point of closest approach to the earth
+    /// * `mean_anomaly` - Angle of the satellite location measured from perigee
+    /// * `kozai_mean_motion` - Mean orbital angular velocity in rad.min⁻¹ (Kozai convention)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let orbit_0 = sgp4::Orbit::from_kozai_elements(
+    ///     &sgp4::WGS84,
+    ///     sgp4::Degrees(elements.inclination),
+    ///     sgp4::Degrees(elements.right_ascension),
+    ///     elements.eccentricity,
+    ///     sgp4::Degrees(elements.argument_of_perigee),
+    ///     sgp4::Degrees(elements.mean_anomaly),
+    ///     elements.mean_motion * (core::f64::consts::PI / 720.0),
+    /// )?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_kozai_elements(
+        geopotential: &Geopotential,
+        inclination: impl Into<Radians>,
+        right_ascension: impl Into<Radians>,
+        eccentricity: f64,
+        argument_of_perigee: impl Into<Radians>,
+        mean_anomaly: impl Into<Radians>,
+        kozai_mean_motion: f64,
+    ) -> core::result::Result<Self, KozaiElementsError> {
+        let inclination = inclination.into();
+        Ok(propagator::Orbit {
+            inclination: inclination.0,
+            right_ascension: right_ascension.into().0,
+            eccentricity,
+            argument_of_perigee: argument_of_perigee.into().0,
+            mean_anomaly: mean_anomaly.into().0,
+            mean_motion: kozai_to_brouwer(
+                geopotential,
+                inclination,
+                eccentricity,
+                kozai_mean_motion,
+            )?,
+        })
+    }
+}
+
+impl Constants {
+    /// Initializes a new propagator from epoch quantities
+    ///
+    /// If the orbital elements are obtained from a TLE or OMM,
+    /// the convenience function [sgp4::Constants::from_elements](struct.Constants.html#method.from_elements)
+    /// can be used instead of manually mapping the `Elements` fields to the `Constants::new` parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    /// * `epoch_to_sidereal_time` - The function to use to convert the J2000 epoch to sidereal time
+    /// * `epoch` - The number of years since UTC 1 January 2000 12h00 (J2000)
+    /// * `drag_term` - The radiation pressure coefficient in earth radii⁻¹ (B*)
+    /// * `orbit_0` - The Brouwer orbital elements at epoch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::new(
+    ///     sgp4::WGS84,
+    ///     sgp4::iau_epoch_to_sidereal_time,
+    ///     elements.epoch(),
+    ///     elements.drag_term,
+    ///     sgp4::Orbit::from_kozai_elements(
+    ///         &sgp4::WGS84,
+    ///         sgp4::Degrees(elements.inclination),
+    ///         sgp4::Degrees(elements.right_ascension),
+    ///         elements.eccentricity,
+    ///         sgp4::Degrees(elements.argument_of_perigee),
+    ///         sgp4::Degrees(elements.mean_anomaly),
+    ///         elements.mean_motion * (core::f64::consts::PI / 720.0),
+    ///     )?,
+    /// )?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        geopotential: Geopotential,
+        epoch_to_sidereal_time: impl Fn(f64) -> f64,
+        epoch: f64,
+        drag_term: f64,
+        orbit_0: propagator::Orbit,
+    ) -> core::result::Result<Self, OutOfRangeEpochEccentricity> {
+        Constants::initialize(
+            geopotential,
+            epoch_to_sidereal_time,
+            None,
+            &ResonanceBoundaries::SGP4,
+            epoch,
+            drag_term,
+            orbit_0,
+        )
+    }
+
+    /// Initializes a new propagator from epoch quantities and external solar and lunar orbits
+    ///
+    /// This function behaves like [Constants::new](struct.Constants.html#method.new),
+    /// but deep space propagators (period greater than or equal to 225 min) use the solar and lunar orbits
+    /// returned by `third_bodies` instead of the analytic series of SGP4.
+    /// It is equivalent to [Constants::builder_from_orbit](struct.Constants.html#method.builder_from_orbit)
+    /// with [ConstantsBuilder::with_third_bodies](struct.ConstantsBuilder.html#method.with_third_bodies).
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    /// * `epoch_to_sidereal_time` - The function to use to convert the J2000 epoch to sidereal time
+    /// * `third_bodies` - The function to use to calculate the solar and lunar orbits at the J2000 epoch
+    /// * `epoch` - The number of years since UTC 1 January 2000 12h00 (J2000)
+    /// * `drag_term` - The radiation pressure coefficient in earth radii⁻¹ (B*)
+    /// * `orbit_0` - The Brouwer orbital elements at epoch
+    #[deprecated(
+        note = "use Constants::builder_from_orbit with ConstantsBuilder::with_third_bodies"
+    )]
+    pub fn new_with_third_bodies(
+        geopotential: Geopotential,
+        epoch_to_sidereal_time: impl Fn(f64) -> f64,
+        third_bodies: impl Fn(f64) -> ThirdBodies,
+        epoch: f64,
+        drag_term: f64,
+        orbit_0: propagator::Orbit,
+    ) -> core::result::Result<Self, OutOfRangeEpochEccentricity> {
+        Constants::initialize(
+            geopotential,
+            epoch_to_sidereal_time,
+            Some(&third_bodies),
+            &ResonanceBoundaries::SGP4,
+            epoch,
+            drag_term,
+            orbit_0,
+        )
+    }
+
+    /// Initializes a new propagator from epoch quantities and custom resonance thresholds
+    ///
+    /// This function behaves like [Constants::new](struct.Constants.html#method.new),
+    /// but deep space propagators (period greater than or equal to 225 min) select the resonance branch
+    /// with `resonance_boundaries` instead of the thresholds of the reference implementation.
+    /// It is equivalent to [Constants::builder_from_orbit](struct.Constants.html#method.builder_from_orbit)
+    /// with [ConstantsBuilder::with_resonance_boundaries](struct.ConstantsBuilder.html#method.with_resonance_boundaries).
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    /// * `epoch_to_sidereal_time` - The function to use to convert the J2000 epoch to sidereal time
+    /// * `resonance_boundaries` - The mean motion and eccentricity thresholds of the resonance branches
+    /// * `epoch` - The number of years since UTC 1 January 2000 12h00 (J2000)
+    /// * `drag_term` - The radiation pressure coefficient in earth radii⁻¹ (B*)
+    /// * `orbit_0` - The Brouwer orbital elements at epoch
+    #[deprecated(
+        note = "use Constants::builder_from_orbit with ConstantsBuilder::with_resonance_boundaries"
+    )]
+    pub fn new_with_resonance_boundaries(
+        geopotential: Geopotential,
+        epoch_to_sidereal_time: impl Fn(f64) -> f64,
+        resonance_boundaries: &ResonanceBoundaries,
+        epoch: f64,
+        drag_term: f64,
+        orbit_0: propagator::Orbit,
+    ) -> core::result::Result<Self, OutOfRangeEpochEccentricity> {
+        Constants::initialize(
+            geopotential,
+            epoch_to_sidereal_time,
+            None,
+            resonance_boundaries,
+            epoch,
+            drag_term,
+            orbit_0,
+        )
+    }
+
+    /// Initializes a new propagator from an `Elements` object
+    ///
+    /// This is the recommended method to initialize a propagator from a TLE or OMM.
+    /// The WGS84 model, the IAU sidereal time expression and the accurate UTC to J2000 expression are used.
+    /// [Constants::builder](struct.Constants.html#method.builder) can be used to change these settings individually.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Orbital elements and drag term parsed from a TLE or OMM
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(
+    ///     &sgp4::Elements::from_tle(
+    ///         Some("ISS (ZARYA)".to_owned()),
+    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    ///     )?,
+    /// )?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_elements(elements: &Elements) -> core::result::Result<Self, ElementsError> {
+        Constants::builder(elements).build()
+    }
+
+    /// Initializes a new propagator from an `Elements` object
+    ///
+    /// This method should be used if compatibility with the AFSPC implementation is needed.
+    /// The WGS72 model, the AFSPC sidereal time expression and the AFSPC UTC to J2000 expression are used.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - Orbital elements and drag term parsed from a TLE or OMM
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements_afspc_compatibility_mode(
+    ///     &sgp4::Elements::from_tle(
+    ///         Some("ISS (ZARYA)".to_owned()),
+    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    ///     )?,
+    /// )?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_elements_afspc_compatibility_mode(
+        elements: &Elements,
+    ) -> core::result::Result<Self, ElementsError> {
+        Constants::builder(elements)
+            .afspc_compatibility_mode()
+            .build()
+    }
+
+    /// Calculates the SGP4 position and velocity predictions
+    ///
+    /// This is an advanced API which results in marginally faster propagation than `Constants::propagate` in some cases
+    /// (see `Constants::initial_state` for details), at the cost of added complexity for the user.
+    ///
+    /// The propagation times must be monotonic if the same resonance state is used repeatedly.
+    /// The `afspc_compatibility_mode` makes a difference only if the satellite is on a Lyddane deep space orbit
+    /// (period greater than 225 min and inclination smaller than 0.2 rad).
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+    /// * `state` - The deep space propagator state returned by `Constants::initial_state`
+    /// * `afspc_compatibility_mode` - Set to true if compatibility with the AFSPC implementation is needed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("MOLNIYA 1-36".to_owned()),
+    ///     "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+    ///     "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::from_elements(&elements)?;
+    /// let mut state = constants.initial_state();
+    /// for days in 0..7 {
+    ///     println!("t = {} min", days * 60 * 24);
+    ///     let prediction =
+    ///         constants.propagate_from_state(sgp4::MinutesSinceEpoch((days * 60 * 24) as f64), state.as_mut(), false)?;
+    ///     println!("    r = {:?} km", prediction.position);
+    ///     println!("    ṙ = {:?} km.s⁻¹", prediction.velocity);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn propagate_from_state(
+        &self,
+        t: MinutesSinceEpoch,
+        state: Option<&mut ResonanceState>,
+        afspc_compatibility_mode: bool,
+    ) -> core::result::Result<Prediction, gp::Error> {
+        if let Some(horizon) = &self.horizon {
+            horizon.check(t.0)?;
+        }
+        self.propagate_from_state_with_limits(
+            t.0,
+            state,
+            afspc_compatibility_mode,
+            Some(&self.eccentricity_limits),
+        )
+    }
+
+    /// Calculates the SGP4 position and velocity predictions
+    ///
+    /// This is the recommended method to propagate epoch orbital elements.
+    /// It applies the eccentricity limits and the horizon set with
+    /// [Constants::builder](struct.Constants.html#method.builder), if any.
+    ///
+    /// # Arguments
+    /// `t` - The number of minutes since epoch (can be positive, negative or zero)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements_afspc_compatibility_mode(
+    ///     &sgp4::Elements::from_tle(
+    ///         Some("ISS (ZARYA)".to_owned()),
+    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    ///     )?,
+    /// )?;
+    /// let prediction = constants.propagate(sgp4::MinutesSinceEpoch(60.0 * 24.0));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn propagate(&self, t: MinutesSinceEpoch) -> core::result::Result<Prediction, gp::Error> {
+        self.propagate_from_state(t, self.initial_state().as_mut(), false)
+    }
+
+    /// Calculates the SGP4 position and velocity predictions without range checks
+    ///
+    /// This method skips the eccentricity and semi-latus rectum checks of `Constants::propagate`
+    /// and never fails. It is meant for inner loops over inputs that have already been validated,
+    /// for example by a successful `Constants::propagate` call at the extremities of a time range
+    /// over which the orbit does not decay.
+    ///
+    /// If `Constants::propagate` would return an error, the prediction returned by this method is garbage
+    /// (its components may be NaN, infinite or arbitrarily large). It is never an error in the Rust sense
+    /// (no panic and no undefined behavior), but it must not be used.
+    ///
+    /// # Arguments
+    /// `t` - The number of minutes since epoch (can be positive, negative or zero)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// constants.propagate(sgp4::MinutesSinceEpoch(0.0))?;
+    /// constants.propagate(sgp4::MinutesSinceEpoch(60.0 * 24.0))?;
+    /// for minutes in 0..=60 * 24 {
+    ///     let prediction = constants.propagate_unchecked(sgp4::MinutesSinceEpoch(minutes as f64));
+    ///     assert!(prediction.position.iter().all(|component| component.is_finite()));
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn propagate_unchecked(&self, t: MinutesSinceEpoch) -> Prediction {
+        match self.propagate_from_state_with_limits(t.0, self.initial_state().as_mut(), false, None)
+        {
+            Ok(prediction) => prediction,
+            Err(_) => unreachable!("propagation without checks does not return errors"),
+        }
+    }
+
+    /// Calculates the SGP4 position and velocity predictions with custom eccentricity thresholds
+    ///
+    /// `Constants::propagate` uses the limits set with
+    /// [ConstantsBuilder::with_eccentricity_limits](struct.ConstantsBuilder.html#method.with_eccentricity_limits)
+    /// (`EccentricityLimits::SGP4` by default). Setting `strict` to true
+    /// returns an error instead of clamping small eccentricities, which detects marginal element sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+    /// * `limits` - The propagated eccentricity thresholds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let strict = sgp4::EccentricityLimits {
+    ///     strict: true,
+    ///     ..sgp4::EccentricityLimits::SGP4
+    /// };
+    /// let prediction = constants.propagate_with_eccentricity_limits(sgp4::MinutesSinceEpoch(60.0), &strict)?;
+    /// assert_eq!(
+    ///     prediction.position,
+    ///     constants.propagate(sgp4::MinutesSinceEpoch(60.0))?.position,
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn propagate_with_eccentricity_limits(
+        &self,
+        t: MinutesSinceEpoch,
+        limits: &gp::EccentricityLimits,
+    ) -> core::result::Result<Prediction, gp::Error> {
+        if let Some(horizon) = &self.horizon {
+            horizon.check(t.0)?;
+        }
+        self.propagate_from_state_with_limits(
+            t.0,
+            self.initial_state().as_mut(),
+            false,
+            Some(limits),
+        )
+    }
+
+    /// Calculates the SGP4 position and velocity predictions if t is within the given horizon
+    ///
+    /// This method behaves like `Constants::propagate` but returns an `Error::BeyondHorizon`
+    /// if |t| is larger than the horizon, since SGP4 predictions far from the epoch are unreliable.
+    /// The given horizon replaces the one set with
+    /// [ConstantsBuilder::with_horizon](struct.ConstantsBuilder.html#method.with_horizon), if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+    /// * `horizon` - The largest accepted absolute number of minutes since epoch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
     ///     Some("ISS (ZARYA)".to_owned()),
     ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
     ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
-    /// )?;
-    /// let orbit_0 = sgp4::Orbit::from_kozai_elements(
-    ///     &sgp4::WGS84,
-    ///     elements.inclination * (core::f64::consts::PI / 180.0),
-    ///     elements.right_ascension * (core::f64::consts::PI / 180.0),
-    ///     elements.eccentricity,
-    ///     elements.argument_of_perigee * (core::f64::consts::PI / 180.0),
-    ///     elements.mean_anomaly * (core::f64::consts::PI / 180.0),
-    ///     elements.mean_motion * (core::f64::consts::PI / 720.0),
-    /// )?;
+    /// )?)?;
+    /// let horizon = sgp4::Horizon::from_days(7.0);
+    /// constants.propagate_within_horizon(sgp4::MinutesSinceEpoch(-60.0 * 24.0), &horizon)?;
+    /// assert!(matches!(
+    ///     constants.propagate_within_horizon(sgp4::MinutesSinceEpoch(60.0 * 24.0 * 10.0), &horizon),
+    ///     Err(sgp4::Error::BeyondHorizon { .. }),
+    /// ));
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn from_kozai_elements(
-        geopotential: &Geopotential,
-        inclination: f64,
-        right_ascension: f64,
-        eccentricity: f64,
-        argument_of_perigee: f64,
-        mean_anomaly: f64,
-        kozai_mean_motion: f64,
-    ) -> core::result::Result<Self, KozaiElementsError> {
-        if kozai_mean_motion <= 0.0 {
-            Err(KozaiElementsError::NegativeKozaiMeanMotion)
-        } else {
-            let mean_motion = {
-                // a₁ = (kₑ / n₀)²ᐟ³
-                let a1 = (geopotential.ke / kozai_mean_motion).powf(2.0 / 3.0);
-
-                //      3      3 cos²I₀ - 1
-                // p₀ = - J₂ ---------------
-                //      4       (1 − e₀²)³ᐟ²
-                let p0 = 0.75 * geopotential.j2 * (3.0 * inclination.cos().powi(2) - 1.0)
-                    / (1.0 - eccentricity.powi(2)).powf(3.0 / 2.0);
-
-                // 𝛿₁ = p₀ / a₁²
-                let d1 = p0 / a1.powi(2);
-
-                // 𝛿₀ = p₀ / (a₁ (1 - ¹/₃ 𝛿₁ - 𝛿₁² - ¹³⁴/₈₁ 𝛿₁³))²
-                let d0 = p0
-                    / (a1 * (1.0 - d1.powi(2) - d1 * (1.0 / 3.0 + 134.0 * d1.powi(2) / 81.0)))
-                        .powi(2);
-
-                //         n₀
-                // n₀" = ------
-                //       1 + 𝛿₀
-                kozai_mean_motion / (1.0 + d0)
-            };
-            if mean_motion <= 0.0 {
-                Err(KozaiElementsError::NegativeBrouwerMeanMotion)
-            } else {
-                Ok(propagator::Orbit {
-                    inclination,
-                    right_ascension,
-                    eccentricity,
-                    argument_of_perigee,
-                    mean_anomaly,
-                    mean_motion,
-                })
-            }
-        }
+    pub fn propagate_within_horizon(
+        &self,
+        t: MinutesSinceEpoch,
+        horizon: &gp::Horizon,
+    ) -> core::result::Result<Prediction, gp::Error> {
+        horizon.check(t.0)?;
+        self.propagate_from_state_with_limits(
+            t.0,
+            self.initial_state().as_mut(),
+            false,
+            Some(&self.eccentricity_limits),
+        )
     }
-}
 
-impl Constants {
-    /// Initializes a new propagator from epoch quantities
+    /// Calculates the SGP4 position and velocity predictions
     ///
-    /// If the orbital elements are obtained from a TLE or OMM,
-    /// the convenience function [sgp4::Constants::from_elements](struct.Constants.html#method.from_elements)
-    /// can be used instead of manually mapping the `Elements` fields to the `Constants::new` parameters.
+    /// This method should be used if compatibility with the AFSPC implementation is needed.
+    /// Its behavior is different from `Constants::propagate`
+    /// only if the satellite is on a Lyddane deep space orbit
+    /// (period greater than 225 min and inclination smaller than 0.2 rad).
     ///
     /// # Arguments
-    ///
-    /// * `geopotential` - The model of Earth gravity to use in the conversion
-    /// * `epoch_to_sidereal_time` - The function to use to convert the J2000 epoch to sidereal time
-    /// * `epoch` - The number of years since UTC 1 January 2000 12h00 (J2000)
-    /// * `drag_term` - The radiation pressure coefficient in earth radii⁻¹ (B*)
-    /// * `orbit_0` - The Brouwer orbital elements at epoch
+    /// `t` - The number of minutes since epoch (can be positive, negative or zero)
     ///
     /// # Example
     ///
     /// ```
     /// # fn main() -> anyhow::Result<()> {
-    /// let elements = sgp4::Elements::from_tle(
-    ///     Some("ISS (ZARYA)".to_owned()),
-    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
-    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
-    /// )?;
-    /// let constants = sgp4::Constants::new(
-    ///     sgp4::WGS84,
-    ///     sgp4::iau_epoch_to_sidereal_time,
-    ///     elements.epoch(),
-    ///     elements.drag_term,
-    ///     sgp4::Orbit::from_kozai_elements(
-    ///         &sgp4::WGS84,
-    ///         elements.inclination * (core::f64::consts::PI / 180.0),
-    ///         elements.right_ascension * (core::f64::consts::PI / 180.0),
-    ///         elements.eccentricity,
-    ///         elements.argument_of_perigee * (core::f64::consts::PI / 180.0),
-    ///         elements.mean_anomaly * (core::f64::consts::PI / 180.0),
-    ///         elements.mean_motion * (core::f64::consts::PI / 720.0),
+    /// let constants = sgp4::Constants::from_elements_afspc_compatibility_mode(
+    ///     &sgp4::Elements::from_tle(
+    ///         Some("ISS (ZARYA)".to_owned()),
+    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
     ///     )?,
     /// )?;
+    /// let prediction = constants.propagate_afspc_compatibility_mode(sgp4::MinutesSinceEpoch(60.0 * 24.0));
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn new(
+    pub fn propagate_afspc_compatibility_mode(
+        &self,
+        t: MinutesSinceEpoch,
+    ) -> core::result::Result<Prediction, gp::Error> {
+        self.propagate_from_state(t, self.initial_state().as_mut(), true)
+    }
+
+    /// Returns the epoch used to initialize the propagator in years since UTC 1 January 2000 12h00 (J2000)
+    pub fn epoch(&self) -> f64 {
+        self.epoch
+    }
+
+    /// Returns the number of years since UTC 1 January 2000 12h00 (J2000) at the given propagation time
+    ///
+    /// The result can be passed to [sgp4::iau_epoch_to_sidereal_time](fn.iau_epoch_to_sidereal_time.html)
+    /// or [sgp4::sun_position](fn.sun_position.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+    pub fn julian_years_since_j2000(&self, t: MinutesSinceEpoch) -> f64 {
+        // y₂₀₀₀ = y₀ + t / (365.25 × 24 × 60)
+        self.epoch + t.0 / (365.25 * 24.0 * 60.0)
+    }
+
+    /// Returns the propagation time corresponding to a number of years since UTC 1 January 2000 12h00 (J2000)
+    ///
+    /// This is the inverse of [Constants::julian_years_since_j2000](struct.Constants.html#method.julian_years_since_j2000).
+    /// It converts a time shared by several satellites to each satellite's propagation time.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - Years since UTC 1 January 2000 12h00 (J2000)
+    pub fn minutes_since_epoch(&self, epoch: f64) -> MinutesSinceEpoch {
+        // t = (y₂₀₀₀ - y₀) × 365.25 × 24 × 60
+        MinutesSinceEpoch((epoch - self.epoch) * (365.25 * 24.0 * 60.0))
+    }
+
+    /// Returns the orbital period at epoch in minutes
+    ///
+    /// The period is calculated from the Brouwer mean motion.
+    pub fn period(&self) -> f64 {
+        2.0 * core::f64::consts::PI / self.orbit_0.mean_motion
+    }
+}
+
+impl<T: Real> Constants<T> {
+    fn initialize(
         geopotential: Geopotential,
         epoch_to_sidereal_time: impl Fn(f64) -> f64,
+        third_bodies: Option<&dyn Fn(f64) -> ThirdBodies>,
+        resonance_boundaries: &ResonanceBoundaries,
         epoch: f64,
-        drag_term: f64,
-        orbit_0: propagator::Orbit,
+        drag_term: T,
+        orbit_0: propagator::Orbit<T>,
     ) -> core::result::Result<Self, OutOfRangeEpochEccentricity> {
-        if orbit_0.eccentricity < 0.0 || orbit_0.eccentricity >= 1.0 {
-            Err(OutOfRangeEpochEccentricity(orbit_0.eccentricity))
+        let c = T::from_f64;
+        if orbit_0.eccentricity.value() < 0.0 || orbit_0.eccentricity.value() >= 1.0 {
+            Err(OutOfRangeEpochEccentricity(orbit_0.eccentricity.value()))
         } else {
             // p₁ = cos I₀
-            let p1 = orbit_0.inclination.cos();
+            let p1 = orbit_0.inclination.cosine();
 
             // p₂ = 1 − e₀²
-            let p2 = 1.0 - orbit_0.eccentricity.powi(2);
+            let p2 = c(1.0) - orbit_0.eccentricity.integer_power(2);
 
             // k₆ = 3 p₁² - 1
-            let k6 = 3.0 * p1.powi(2) - 1.0;
+            let k6 = c(3.0) * p1.integer_power(2) - 1.0;
 
             // a₀" = (kₑ / n₀")²ᐟ³
-            let a0 = (geopotential.ke / orbit_0.mean_motion).powf(2.0 / 3.0);
+            let a0 = (c(geopotential.ke) / orbit_0.mean_motion).real_power(2.0 / 3.0);
 
             // p₃ = a₀" (1 - e₀)
-            let p3 = a0 * (1.0 - orbit_0.eccentricity);
+            let p3 = a0 * (c(1.0) - orbit_0.eccentricity);
             let (s, p6) = {
                 // p₄ = aₑ (p₃ - 1)
-                let p4 = geopotential.ae * (p3 - 1.0);
+                let p4 = c(geopotential.ae) * (p3 - 1.0);
 
                 // p₅ = │ 20      if p₄ < 98
                 //      │ p₄ - 78 if 98 ≤ p₄ < 156
                 //      │ 78      otherwise
-                let p5 = if p4 < 98.0 {
-                    20.0
-                } else if p4 < 156.0 {
+                let p5 = if p4.value() < 98.0 {
+                    c(20.0)
+                } else if p4.value() < 156.0 {
                     p4 - 78.0
                 } else {
-                    78.0
+                    c(78.0)
                 };
                 (
                     // s = p₅ / aₑ + 1
                     p5 / geopotential.ae + 1.0,
                     // p₆ = ((120 - p₅) / aₑ)⁴
-                    ((120.0 - p5) / geopotential.ae).powi(4),
+                    ((c(120.0) - p5) / geopotential.ae).integer_power(4),
                 )
             };
 
             // ξ = 1 / (a₀" - s)
-            let xi = 1.0 / (a0 - s);
+            let xi = c(1.0) / (a0 - s);
 
             // p₇ = p₆ ξ⁴
-            let p7 = p6 * xi.powi(4);
+            let p7 = p6 * xi.integer_power(4);
 
             // η = a₀" e₀ ξ
             let eta = a0 * orbit_0.eccentricity * xi;
 
             // p₈ = |1 - η²|
-            let p8 = (1.0 - eta.powi(2)).abs();
+            let p8 = (c(1.0) - eta.integer_power(2)).absolute();
 
             // p₉ = p₇ / p₈⁷ᐟ²
-            let p9 = p7 / p8.powf(3.5);
+            let p9 = p7 / p8.real_power(3.5);
 
             // C₁ = B* p₉ n₀" (a₀" (1 + ³/₂ η² + e₀ η (4 + η²))
             //      + ³/₈ J₂ ξ k₆ (8 + 3 η² (8 + η²)) / p₈)
@@ -351,42 +1597,51 @@ impl Constants {
                 * (p9
                     * orbit_0.mean_motion
                     * (a0
-                        * (1.0
-                            + 1.5 * eta.powi(2)
-                            + orbit_0.eccentricity * eta * (4.0 + eta.powi(2)))
-                        + 0.375 * geopotential.j2 * xi / p8
+                        * (c(1.0)
+                            + c(1.5) * eta.integer_power(2)
+                            + orbit_0.eccentricity * eta * (c(4.0) + eta.integer_power(2)))
+                        + c(0.375) * geopotential.j2 * xi / p8
                             * k6
-                            * (8.0 + 3.0 * eta.powi(2) * (8.0 + eta.powi(2)))));
+                            * (c(8.0)
+                                + c(3.0)
+                                    * eta.integer_power(2)
+                                    * (c(8.0) + eta.integer_power(2)))));
 
             // p₁₀ = (a₀" p₂)⁻²
-            let p10 = 1.0 / (a0 * p2).powi(2);
+            let p10 = c(1.0) / (a0 * p2).integer_power(2);
 
             // β₀ = p₂¹ᐟ²
-            let b0 = p2.sqrt();
+            let b0 = p2.square_root();
 
             // p₁₁ = ³/₂ J₂ p₁₀ n₀"
-            let p11 = 1.5 * geopotential.j2 * p10 * orbit_0.mean_motion;
+            let p11 = c(1.5) * geopotential.j2 * p10 * orbit_0.mean_motion;
 
             // p₁₂ = ¹/₂ p₁₁ J₂ p₁₀
-            let p12 = 0.5 * p11 * geopotential.j2 * p10;
+            let p12 = c(0.5) * p11 * geopotential.j2 * p10;
 
             // p₁₃ = - ¹⁵/₃₂ J₄ p₁₀² n₀"
-            let p13 = -0.46875 * geopotential.j4 * p10.powi(2) * orbit_0.mean_motion;
+            let p13 = c(-0.46875) * geopotential.j4 * p10.integer_power(2) * orbit_0.mean_motion;
 
             // p₁₄ = - p₁₁ p₁ + (¹/₂ p₁₂ (4 - 19 p₁²) + 2 p₁₃ (3 - 7 p₁²)) p₁
             let p14 = -p11 * p1
-                + (0.5 * p12 * (4.0 - 19.0 * p1.powi(2)) + 2.0 * p13 * (3.0 - 7.0 * p1.powi(2)))
+                + (c(0.5) * p12 * (c(4.0) - c(19.0) * p1.integer_power(2))
+                    + c(2.0) * p13 * (c(3.0) - c(7.0) * p1.integer_power(2)))
                     * p1;
 
             // k₁₄ = - ¹/₂ p₁₁ (1 - 5 p₁²) + ¹/₁₆ p₁₂ (7 - 114 p₁² + 395 p₁⁴)
-            let k14 = -0.5 * p11 * (1.0 - 5.0 * p1.powi(2))
-                + 0.0625 * p12 * (7.0 - 114.0 * p1.powi(2) + 395.0 * p1.powi(4))
-                + p13 * (3.0 - 36.0 * p1.powi(2) + 49.0 * p1.powi(4));
+            let k14 = c(-0.5) * p11 * (c(1.0) - c(5.0) * p1.integer_power(2))
+                + c(0.0625)
+                    * p12
+                    * (c(7.0) - c(114.0) * p1.integer_power(2) + c(395.0) * p1.integer_power(4))
+                + p13 * (c(3.0) - c(36.0) * p1.integer_power(2) + c(49.0) * p1.integer_power(4));
 
             // p₁₅ = n₀" + ¹/₂ p₁₁ β₀ k₆ + ¹/₁₆ p₁₂ β₀ (13 - 78 p₁² + 137 p₁⁴)
             let p15 = orbit_0.mean_motion
-                + 0.5 * p11 * b0 * k6
-                + 0.0625 * p12 * b0 * (13.0 - 78.0 * p1.powi(2) + 137.0 * p1.powi(4));
+                + c(0.5) * p11 * b0 * k6
+                + c(0.0625)
+                    * p12
+                    * b0
+                    * (c(13.0) - c(78.0) * p1.integer_power(2) + c(137.0) * p1.integer_power(4));
 
             // C₄ = 2 B* n₀" p₉ a₀" p₂ (
             //      η (2 + ¹/₂ η²)
@@ -394,33 +1649,37 @@ impl Constants {
             //      - J₂ ξ / (a p₈) (-3 k₆ (1 - 2 e₀ η + η² (³/₂ - ¹/₂ e₀ η))
             //      + ³/₄ (1 - p₁²) (2 η² - e₀ η (1 + η²)) cos 2 ω₀)
             let c4 = drag_term
-                * (2.0
+                * (c(2.0)
                     * orbit_0.mean_motion
                     * p9
                     * a0
                     * p2
-                    * (eta * (2.0 + 0.5 * eta.powi(2))
-                        + orbit_0.eccentricity * (0.5 + 2.0 * eta.powi(2))
-                        - geopotential.j2 * xi / (a0 * p8)
-                            * (-3.0
+                    * (eta * (c(2.0) + c(0.5) * eta.integer_power(2))
+                        + orbit_0.eccentricity * (c(0.5) + c(2.0) * eta.integer_power(2))
+                        - c(geopotential.j2) * xi / (a0 * p8)
+                            * (c(-3.0)
                                 * k6
-                                * (1.0 - 2.0 * orbit_0.eccentricity * eta
-                                    + eta.powi(2) * (1.5 - 0.5 * orbit_0.eccentricity * eta))
-                                + 0.75
-                                    * (1.0 - p1.powi(2))
-                                    * (2.0 * eta.powi(2)
-                                        - orbit_0.eccentricity * eta * (1.0 + eta.powi(2)))
-                                    * (2.0 * orbit_0.argument_of_perigee).cos())));
+                                * (c(1.0) - c(2.0) * orbit_0.eccentricity * eta
+                                    + eta.integer_power(2)
+                                        * (c(1.5) - c(0.5) * orbit_0.eccentricity * eta))
+                                + c(0.75)
+                                    * (c(1.0) - p1.integer_power(2))
+                                    * (c(2.0) * eta.integer_power(2)
+                                        - orbit_0.eccentricity
+                                            * eta
+                                            * (c(1.0) + eta.integer_power(2)))
+                                    * (c(2.0) * orbit_0.argument_of_perigee).cosine())));
 
             // k₀ = - ⁷/₂ p₂ p₁₁ p₁ C₁
-            let k0 = 3.5 * p2 * (-p11 * p1) * c1;
+            let k0 = c(3.5) * p2 * (-p11 * p1) * c1;
 
             // k₁ = ³/₂ C₁
-            let k1 = 1.5 * c1;
+            let k1 = c(1.5) * c1;
 
-            if orbit_0.mean_motion > 2.0 * core::f64::consts::PI / 225.0 {
+            if orbit_0.mean_motion.value() > 2.0 * core::f64::consts::PI / 225.0 {
                 Ok(near_earth::constants(
                     geopotential,
+                    epoch,
                     drag_term,
                     orbit_0,
                     p1,
@@ -445,6 +1704,8 @@ impl Constants {
                 Ok(deep_space::constants(
                     geopotential,
                     epoch_to_sidereal_time,
+                    third_bodies,
+                    resonance_boundaries,
                     epoch,
                     orbit_0,
                     p1,
@@ -463,90 +1724,6 @@ impl Constants {
         }
     }
 
-    /// Initializes a new propagator from an `Elements` object
-    ///
-    /// This is the recommended method to initialize a propagator from a TLE or OMM.
-    /// The WGS84 model, the IAU sidereal time expression and the accurate UTC to J2000 expression are used.
-    ///
-    /// # Arguments
-    ///
-    /// * `elements` - Orbital elements and drag term parsed from a TLE or OMM
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # fn main() -> anyhow::Result<()> {
-    /// let constants = sgp4::Constants::from_elements(
-    ///     &sgp4::Elements::from_tle(
-    ///         Some("ISS (ZARYA)".to_owned()),
-    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
-    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
-    ///     )?,
-    /// )?;
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn from_elements(elements: &Elements) -> core::result::Result<Self, ElementsError> {
-        Ok(Constants::new(
-            WGS84,
-            iau_epoch_to_sidereal_time,
-            elements.epoch(),
-            elements.drag_term,
-            Orbit::from_kozai_elements(
-                &WGS84,
-                elements.inclination * (core::f64::consts::PI / 180.0),
-                elements.right_ascension * (core::f64::consts::PI / 180.0),
-                elements.eccentricity,
-                elements.argument_of_perigee * (core::f64::consts::PI / 180.0),
-                elements.mean_anomaly * (core::f64::consts::PI / 180.0),
-                elements.mean_motion * (core::f64::consts::PI / 720.0),
-            )?,
-        )?)
-    }
-
-    /// Initializes a new propagator from an `Elements` object
-    ///
-    /// This method should be used if compatibility with the AFSPC implementation is needed.
-    /// The WGS72 model, the AFSPC sidereal time expression and the AFSPC UTC to J2000 expression are used.
-    ///
-    /// # Arguments
-    ///
-    /// * `elements` - Orbital elements and drag term parsed from a TLE or OMM
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # fn main() -> anyhow::Result<()> {
-    /// let constants = sgp4::Constants::from_elements_afspc_compatibility_mode(
-    ///     &sgp4::Elements::from_tle(
-    ///         Some("ISS (ZARYA)".to_owned()),
-    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
-    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
-    ///     )?,
-    /// )?;
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn from_elements_afspc_compatibility_mode(
-        elements: &Elements,
-    ) -> core::result::Result<Self, ElementsError> {
-        Ok(Constants::new(
-            WGS72,
-            afspc_epoch_to_sidereal_time,
-            elements.epoch_afspc_compatibility_mode(),
-            elements.drag_term,
-            Orbit::from_kozai_elements(
-                &WGS72,
-                elements.inclination * (core::f64::consts::PI / 180.0),
-                elements.right_ascension * (core::f64::consts::PI / 180.0),
-                elements.eccentricity,
-                elements.argument_of_perigee * (core::f64::consts::PI / 180.0),
-                elements.mean_anomaly * (core::f64::consts::PI / 180.0),
-                elements.mean_motion * (core::f64::consts::PI / 720.0),
-            )?,
-        )?)
-    }
-
     /// Returns the initial deep space resonance integrator state
     ///
     /// For most orbits, SGP4 propagation is stateless.
@@ -563,10 +1740,10 @@ impl Constants {
     /// For non-deep space or non-resonant orbits, their behavior is identical to `Constants::propagate`.
     ///
     /// See `Constants::propagate_from_state` for an example.
-    pub fn initial_state(&self) -> Option<ResonanceState> {
+    pub fn initial_state(&self) -> Option<ResonanceState<T>> {
         match &self.method {
             propagator::Method::NearEarth { .. } => None,
-            propagator::Method::DeepSpace { resonant, .. } => match resonant {
+            propagator::Method::DeepSpace(deep_space) => match &deep_space.resonant {
                 propagator::Resonant::No { .. } => None,
                 propagator::Resonant::Yes { lambda_0, .. } => {
                     Some(ResonanceState::new(self.orbit_0.mean_motion, *lambda_0))
@@ -575,56 +1752,23 @@ impl Constants {
         }
     }
 
-    /// Calculates the SGP4 position and velocity predictions
-    ///
-    /// This is an advanced API which results in marginally faster propagation than `Constants::propagate` in some cases
-    /// (see `Constants::initial_state` for details), at the cost of added complexity for the user.
-    ///
-    /// The propagation times must be monotonic if the same resonance state is used repeatedly.
-    /// The `afspc_compatibility_mode` makes a difference only if the satellite is on a Lyddane deep space orbit
-    /// (period greater than 225 min and inclination smaller than 0.2 rad).
-    ///
-    /// # Arguments
-    ///
-    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
-    /// * `state` - The deep space propagator state returned by `Constants::initial_state`
-    /// * `afspc_compatibility_mode` - Set to true if compatibility with the AFSPC implementation is needed
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # fn main() -> anyhow::Result<()> {
-    /// let elements = sgp4::Elements::from_tle(
-    ///     Some("MOLNIYA 1-36".to_owned()),
-    ///     "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
-    ///     "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
-    /// )?;
-    /// let constants = sgp4::Constants::from_elements(&elements)?;
-    /// let mut state = constants.initial_state();
-    /// for days in 0..7 {
-    ///     println!("t = {} min", days * 60 * 24);
-    ///     let prediction =
-    ///         constants.propagate_from_state(sgp4::MinutesSinceEpoch((days * 60 * 24) as f64), state.as_mut(), false)?;
-    ///     println!("    r = {:?} km", prediction.position);
-    ///     println!("    ṙ = {:?} km.s⁻¹", prediction.velocity);
-    /// }
-    /// #     Ok(())
-    /// # }
-    /// ```
-    #[allow(clippy::many_single_char_names)]
-    pub fn propagate_from_state(
+    // secular and long-period orbital elements and short-period coefficients
+    #[allow(clippy::type_complexity)]
+    fn orbital_elements(
         &self,
-        t: MinutesSinceEpoch,
-        state: Option<&mut ResonanceState>,
+        t: T,
+        state: Option<&mut ResonanceState<T>>,
         afspc_compatibility_mode: bool,
-    ) -> core::result::Result<Prediction, gp::Error> {
+        limits: Option<&gp::EccentricityLimits>,
+    ) -> core::result::Result<(Orbit<T>, T, T, T, T, T, T), gp::Error> {
         // p₂₂ = Ω₀ + Ω̇ t + k₀ t²
-        let p22 =
-            self.orbit_0.right_ascension + self.right_ascension_dot * t.0 + self.k0 * t.0.powi(2);
+        let p22 = self.orbit_0.right_ascension
+            + self.right_ascension_dot * t
+            + self.k0 * t.integer_power(2);
 
         // p₂₃ = ω₀ + ω̇ t
-        let p23 = self.orbit_0.argument_of_perigee + self.argument_of_perigee_dot * t.0;
-        let (orbit, a, p32, p33, p34, p35, p36) = match &self.method {
+        let p23 = self.orbit_0.argument_of_perigee + self.argument_of_perigee_dot * t;
+        match &self.method {
             propagator::Method::NearEarth {
                 a0,
                 k2,
@@ -646,39 +1790,52 @@ impl Constants {
                     *k5,
                     *k6,
                     high_altitude,
-                    t.0,
+                    t,
                     p22,
                     p23,
+                    limits,
                 )
             }
-            propagator::Method::DeepSpace {
-                eccentricity_dot,
-                inclination_dot,
-                solar_perturbations,
-                lunar_perturbations,
-                resonant,
-            } => self.deep_space_orbital_elements(
-                *eccentricity_dot,
-                *inclination_dot,
-                solar_perturbations,
-                lunar_perturbations,
-                resonant,
+            propagator::Method::DeepSpace(deep_space) => self.deep_space_orbital_elements(
+                deep_space.eccentricity_dot,
+                deep_space.inclination_dot,
+                &deep_space.solar_perturbations,
+                &deep_space.lunar_perturbations,
+                &deep_space.resonant,
                 state,
-                t.0,
+                t,
                 p22,
                 p23,
                 afspc_compatibility_mode,
+                limits,
             ),
-        }?;
+        }
+    }
+
+    // propagation without the eccentricity and semi-latus rectum checks if limits is None
+    #[allow(clippy::many_single_char_names)]
+    fn propagate_from_state_with_limits(
+        &self,
+        t: T,
+        state: Option<&mut ResonanceState<T>>,
+        afspc_compatibility_mode: bool,
+        limits: Option<&gp::EccentricityLimits>,
+    ) -> core::result::Result<Prediction<T>, gp::Error> {
+        let c = T::from_f64;
+        let (orbit, a, p32, p33, p34, p35, p36) =
+            self.orbital_elements(t, state, afspc_compatibility_mode, limits)?;
 
         // p₃₇ = 1 / (a (1 - e²))
-        let p37 = 1.0 / (a * (1.0 - orbit.eccentricity.powi(2)));
+        let p37 = c(1.0) / (a * (c(1.0) - orbit.eccentricity.integer_power(2)));
+
+        let (argument_of_perigee_sine, argument_of_perigee_cosine) =
+            trig::sin_cos(orbit.argument_of_perigee);
 
         // aₓₙ = e cos ω
-        let axn = orbit.eccentricity * orbit.argument_of_perigee.cos();
+        let axn = orbit.eccentricity * argument_of_perigee_cosine;
 
         // aᵧₙ = e sin ω + p₃₇ p₃₂
-        let ayn = orbit.eccentricity * orbit.argument_of_perigee.sin() + p37 * p32;
+        let ayn = orbit.eccentricity * argument_of_perigee_sine + p37 * p32;
 
         // p₃₈ = M + ω + p₃₇ p₃₅ aₓₙ rem 2π
         let p38 = (orbit.mean_anomaly + orbit.argument_of_perigee + p37 * p35 * axn)
@@ -686,103 +1843,112 @@ impl Constants {
 
         // (E + ω)₀ = p₃₈
         let mut ew = p38;
+        let (mut ew_sine, mut ew_cosine) = trig::sin_cos(ew);
         for _ in 0..10 {
             //             p₃₈ - aᵧₙ cos (E + ω)ᵢ + aₓₙ sin (E + ω)ᵢ - (E + ω)ᵢ
             // Δ(E + ω)ᵢ = ---------------------------------------------------
             //                   1 - cos (E + ω)ᵢ aₓₙ - sin (E + ω)ᵢ aᵧₙ
-            let delta = (p38 - ayn * ew.cos() + axn * ew.sin() - ew)
-                / (1.0 - ew.cos() * axn - ew.sin() * ayn);
-
-            if delta.abs() < 1.0e-12 {
+            let delta = (p38 - ayn * ew_cosine + axn * ew_sine - ew)
+                / (c(1.0) - ew_cosine * axn - ew_sine * ayn);
+
+            if delta.value().abs() < 1.0e-12 {
+                // the derivative parts of a Newton step lag one iteration behind the real part
+                if T::HAS_DERIVATIVES {
+                    ew += delta - delta.value();
+                    (ew_sine, ew_cosine) = trig::sin_cos(ew);
+                }
                 break;
             }
 
             // (E + ω)ᵢ₊₁ = (E + ω)ᵢ + Δ(E + ω)ᵢ|[-0.95, 0.95]
-            ew += if delta < -0.95 {
-                -0.95
-            } else if delta > 0.95 {
-                0.95
-            } else {
-                delta
-            };
+            ew += delta.clamped(-0.95, 0.95);
+            (ew_sine, ew_cosine) = trig::sin_cos(ew);
         }
 
         // p₃₉ = aₓₙ² + aᵧₙ²
-        let p39 = axn.powi(2) + ayn.powi(2);
+        let p39 = axn.integer_power(2) + ayn.integer_power(2);
 
         // pₗ = a (1 - p₃₉)
-        let pl = a * (1.0 - p39);
-        if pl < 0.0 {
-            Err(gp::Error::NegativeSemiLatusRectum { t: t.0 })
+        let pl = a * (c(1.0) - p39);
+        if limits.is_some() && pl.value() < 0.0 {
+            Err(gp::Error::NegativeSemiLatusRectum { t: t.value() })
         } else {
             // p₄₀ = aₓₙ sin(E + ω) - aᵧₙ cos(E + ω)
-            let p40 = axn * ew.sin() - ayn * ew.cos();
+            let p40 = axn * ew_sine - ayn * ew_cosine;
 
             // r = a (1 - aₓₙ cos(E + ω) + aᵧₙ sin(E + ω))
-            let r = a * (1.0 - (axn * ew.cos() + ayn * ew.sin()));
+            let r = a * (c(1.0) - (axn * ew_cosine + ayn * ew_sine));
 
-            // ṙ = a¹ᐟ² p₄₀ / r
-            let r_dot = a.sqrt() * p40 / r;
+            // ṙ = a¹ᐟ² p₄₀ / r
+            let r_dot = a.square_root() * p40 / r;
 
             // β = (1 - p₃₉)¹ᐟ²
-            let b = (1.0 - p39).sqrt();
+            let b = (c(1.0) - p39).square_root();
 
             // p₄₁ = p₄₀ / (1 + β)
-            let p41 = p40 / (1.0 + b);
+            let p41 = p40 / (c(1.0) + b);
 
             // p₄₂ = a / r (sin(E + ω) - aᵧₙ - aₓₙ p₄₁)
-            let p42 = a / r * (ew.sin() - ayn - axn * p41);
+            let p42 = a / r * (ew_sine - ayn - axn * p41);
 
             // p₄₃ = a / r (cos(E + ω) - aₓₙ + aᵧₙ p₄₁)
-            let p43 = a / r * (ew.cos() - axn + ayn * p41);
+            let p43 = a / r * (ew_cosine - axn + ayn * p41);
 
             //           p₄₂
             // u = tan⁻¹ ---
             //           p₄₃
-            let u = p42.atan2(p43);
+            let u = trig::atan2(p42, p43);
 
             // p₄₄ = 2 p₄₃ p₄₂
-            let p44 = 2.0 * p43 * p42;
+            let p44 = c(2.0) * p43 * p42;
 
             // p₄₅ = 1 - 2 p₄₂²
-            let p45 = 1.0 - 2.0 * p42.powi(2);
+            let p45 = c(1.0) - c(2.0) * p42.integer_power(2);
 
             // p₄₆ = (¹/₂ J₂ / pₗ) / pₗ
-            let p46 = 0.5 * self.geopotential.j2 / pl / pl;
+            let p46 = c(0.5 * self.geopotential.j2) / pl / pl;
 
             // rₖ = r (1 - ³/₂ p₄₆ β p₃₆) + ¹/₂ (¹/₂ J₂ / pₗ) p₃₃ p₄₅
-            let rk = r * (1.0 - 1.5 * p46 * b * p36)
-                + 0.5 * (0.5 * self.geopotential.j2 / pl) * p33 * p45;
+            let rk = r * (c(1.0) - c(1.5) * p46 * b * p36)
+                + c(0.5) * (c(0.5 * self.geopotential.j2) / pl) * p33 * p45;
 
             // uₖ = u - ¹/₄ p₄₆ p₃₄ p₄₄
-            let uk = u - 0.25 * p46 * p34 * p44;
+            let uk = u - c(0.25) * p46 * p34 * p44;
+
+            let (inclination_sine, inclination_cosine) = trig::sin_cos(orbit.inclination);
 
             // Iₖ = I + ³/₂ p₄₆ cos I sin I p₄₅
-            let inclination_k = orbit.inclination
-                + 1.5 * p46 * orbit.inclination.cos() * orbit.inclination.sin() * p45;
+            let inclination_k =
+                orbit.inclination + c(1.5) * p46 * inclination_cosine * inclination_sine * p45;
 
             // Ωₖ = Ω + ³/₂ p₄₆ cos I p₄₄
-            let right_ascension_k =
-                orbit.right_ascension + 1.5 * p46 * orbit.inclination.cos() * p44;
+            let right_ascension_k = orbit.right_ascension + c(1.5) * p46 * inclination_cosine * p44;
 
-            // ṙₖ = ṙ + n (¹/₂ J₂ / pₗ) p₃₃ / kₑ
+            // ṙₖ = ṙ + n (¹/₂ J₂ / pₗ) p₃₃ / kₑ
             let rk_dot = r_dot
-                - orbit.mean_motion * (0.5 * self.geopotential.j2 / pl) * p33 * p44
+                - orbit.mean_motion * (c(0.5 * self.geopotential.j2) / pl) * p33 * p44
                     / self.geopotential.ke;
 
-            // rḟₖ = pₗ¹ᐟ² / r + n (¹/₂ J₂ / pₗ) (p₃₃ p₄₅ + ³/₂ p₃₆) / kₑ
-            let rfk_dot = pl.sqrt() / r
-                + orbit.mean_motion * (0.5 * self.geopotential.j2 / pl) * (p33 * p45 + 1.5 * p36)
+            // rḟₖ = pₗ¹ᐟ² / r + n (¹/₂ J₂ / pₗ) (p₃₃ p₄₅ + ³/₂ p₃₆) / kₑ
+            let rfk_dot = pl.square_root() / r
+                + orbit.mean_motion
+                    * (c(0.5 * self.geopotential.j2) / pl)
+                    * (p33 * p45 + c(1.5) * p36)
                     / self.geopotential.ke;
 
+            let (inclination_k_sine, inclination_k_cosine) = trig::sin_cos(inclination_k);
+            let (right_ascension_k_sine, right_ascension_k_cosine) =
+                trig::sin_cos(right_ascension_k);
+            let (uk_sine, uk_cosine) = trig::sin_cos(uk);
+
             // u₀ = - sin Ωₖ cos Iₖ sin uₖ + cos Ωₖ cos uₖ
-            let u0 = -right_ascension_k.sin() * inclination_k.cos() * uk.sin()
-                + right_ascension_k.cos() * uk.cos();
+            let u0 = -right_ascension_k_sine * inclination_k_cosine * uk_sine
+                + right_ascension_k_cosine * uk_cosine;
             // u₁ = cos Ωₖ cos Iₖ sin uₖ + sin Ωₖ cos uₖ
-            let u1 = right_ascension_k.cos() * inclination_k.cos() * uk.sin()
-                + right_ascension_k.sin() * uk.cos();
+            let u1 = right_ascension_k_cosine * inclination_k_cosine * uk_sine
+                + right_ascension_k_sine * uk_cosine;
             // u₂ = sin Iₖ sin uₖ
-            let u2 = inclination_k.sin() * uk.sin();
+            let u2 = inclination_k_sine * uk_sine;
             Ok(Prediction {
                 position: [
                     // r₀ = rₖ u₀ aₑ
@@ -793,81 +1959,23 @@ impl Constants {
                     rk * u2 * self.geopotential.ae,
                 ],
                 velocity: [
-                    // ṙ₀ = (ṙₖ u₀ + rḟₖ (- sin Ωₖ cos Iₖ cos uₖ - cos Ωₖ sin uₖ)) aₑ kₑ / 60
+                    // ṙ₀ = (ṙₖ u₀ + rḟₖ (- sin Ωₖ cos Iₖ cos uₖ - cos Ωₖ sin uₖ)) aₑ kₑ / 60
                     (rk_dot * u0
                         + rfk_dot
-                            * (-right_ascension_k.sin() * inclination_k.cos() * uk.cos()
-                                - right_ascension_k.cos() * uk.sin()))
+                            * (-right_ascension_k_sine * inclination_k_cosine * uk_cosine
+                                - right_ascension_k_cosine * uk_sine))
                         * (self.geopotential.ae * self.geopotential.ke / 60.0),
-                    // ṙ₁ = (ṙₖ u₁ + rḟₖ (cos Ωₖ cos Iₖ cos uₖ - sin Ωₖ sin uₖ)) aₑ kₑ / 60
+                    // ṙ₁ = (ṙₖ u₁ + rḟₖ (cos Ωₖ cos Iₖ cos uₖ - sin Ωₖ sin uₖ)) aₑ kₑ / 60
                     (rk_dot * u1
                         + rfk_dot
-                            * (right_ascension_k.cos() * inclination_k.cos() * uk.cos()
-                                - right_ascension_k.sin() * uk.sin()))
+                            * (right_ascension_k_cosine * inclination_k_cosine * uk_cosine
+                                - right_ascension_k_sine * uk_sine))
                         * (self.geopotential.ae * self.geopotential.ke / 60.0),
-                    // ṙ₂ = (ṙₖ u₂ + rḟₖ (sin Iₖ cos uₖ)) aₑ kₑ / 60
-                    (rk_dot * u2 + rfk_dot * (inclination_k.sin() * uk.cos()))
+                    // ṙ₂ = (ṙₖ u₂ + rḟₖ (sin Iₖ cos uₖ)) aₑ kₑ / 60
+                    (rk_dot * u2 + rfk_dot * (inclination_k_sine * uk_cosine))
                         * (self.geopotential.ae * self.geopotential.ke / 60.0),
                 ],
             })
         }
     }
-
-    /// Calculates the SGP4 position and velocity predictions
-    ///
-    /// This is the recommended method to propagate epoch orbital elements.
-    ///
-    /// # Arguments
-    /// `t` - The number of minutes since epoch (can be positive, negative or zero)
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # fn main() -> anyhow::Result<()> {
-    /// let constants = sgp4::Constants::from_elements_afspc_compatibility_mode(
-    ///     &sgp4::Elements::from_tle(
-    ///         Some("ISS (ZARYA)".to_owned()),
-    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
-    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
-    ///     )?,
-    /// )?;
-    /// let prediction = constants.propagate(sgp4::MinutesSinceEpoch(60.0 * 24.0));
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn propagate(&self, t: MinutesSinceEpoch) -> core::result::Result<Prediction, gp::Error> {
-        self.propagate_from_state(t, self.initial_state().as_mut(), false)
-    }
-
-    /// Calculates the SGP4 position and velocity predictions
-    ///
-    /// This method should be used if compatibility with the AFSPC implementation is needed.
-    /// Its behavior is different from `Constants::propagate`
-    /// only if the satellite is on a Lyddane deep space orbit
-    /// (period greater than 225 min and inclination smaller than 0.2 rad).
-    ///
-    /// # Arguments
-    /// `t` - The number of minutes since epoch (can be positive, negative or zero)
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # fn main() -> anyhow::Result<()> {
-    /// let constants = sgp4::Constants::from_elements_afspc_compatibility_mode(
-    ///     &sgp4::Elements::from_tle(
-    ///         Some("ISS (ZARYA)".to_owned()),
-    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
-    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
-    ///     )?,
-    /// )?;
-    /// let prediction = constants.propagate_afspc_compatibility_mode(sgp4::MinutesSinceEpoch(60.0 * 24.0));
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn propagate_afspc_compatibility_mode(
-        &self,
-        t: MinutesSinceEpoch,
-    ) -> core::result::Result<Prediction, gp::Error> {
-        self.propagate_from_state(t, self.initial_state().as_mut(), true)
-    }
 }
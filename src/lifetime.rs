@@ -0,0 +1,163 @@
+use crate::atmosphere;
+use crate::model;
+use crate::tle;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// perigee altitude at which the object is considered decayed in km
+const DECAY_ALTITUDE: f64 = 120.0;
+
+// number of eccentric anomaly samples used to average the drag over one revolution
+const ORBIT_SAMPLES: usize = 36;
+
+// largest semi-major axis change per integration step in km
+const MAXIMUM_SEMI_MAJOR_AXIS_STEP: f64 = 1.0;
+
+// largest integration step in days
+const MAXIMUM_STEP: f64 = 1.0;
+
+// orbit-averaged rates of change of the semi-major axis (km.day⁻¹) and eccentricity (day⁻¹)
+fn averaged_rates<D>(
+    semi_major_axis: f64,
+    eccentricity: f64,
+    ballistic_coefficient: f64,
+    solar_activity: &atmosphere::SolarActivity,
+    density: &D,
+) -> (f64, f64)
+where
+    D: atmosphere::DensityModel,
+{
+    let geopotential = model::WGS84;
+
+    // μ = kₑ² aₑ³ in km³.s⁻²
+    let gravitational_parameter = geopotential.ke.powi(2) * geopotential.ae.powi(3) / 3600.0;
+    let mut semi_major_axis_dot = 0.0;
+    let mut eccentricity_dot = 0.0;
+    for index in 0..ORBIT_SAMPLES {
+        let eccentric_anomaly =
+            (index as f64) * (2.0 * core::f64::consts::PI / ORBIT_SAMPLES as f64);
+        let e_cos_e = eccentricity * eccentric_anomaly.cos();
+
+        // r = a (1 - e cos E)
+        let radius = semi_major_axis * (1.0 - e_cos_e);
+
+        // B ρ in km⁻¹
+        let drag =
+            ballistic_coefficient * density.density(radius - geopotential.ae, solar_activity) * 1e3;
+
+        // v = (μ (1 + e cos E) / (a (1 - e cos E)))¹ᐟ²
+        let speed = (gravitational_parameter * (1.0 + e_cos_e)
+            / (semi_major_axis * (1.0 - e_cos_e)))
+            .sqrt();
+
+        // cos ν = (cos E - e) / (1 - e cos E)
+        let cos_true_anomaly = (eccentric_anomaly.cos() - eccentricity) / (1.0 - e_cos_e);
+
+        // dM = (1 - e cos E) dE
+        let weight = (1.0 - e_cos_e) / ORBIT_SAMPLES as f64;
+
+        // ȧ = -a² B ρ v³ / μ
+        semi_major_axis_dot -=
+            weight * semi_major_axis.powi(2) * drag * speed.powi(3) / gravitational_parameter;
+
+        // ė = -B ρ v (e + cos ν)
+        eccentricity_dot -= weight * drag * speed * (eccentricity + cos_true_anomaly);
+    }
+    (semi_major_axis_dot * 86400.0, eccentricity_dot * 86400.0)
+}
+
+impl tle::Elements {
+    /// Estimates the decay time by integrating the orbit-averaged drag
+    ///
+    /// Unlike [Elements::decay_estimate](struct.Elements.html#method.decay_estimate),
+    /// this method does not rely on the mean motion derivatives. The semi-major axis and eccentricity
+    /// are propagated with Gauss' equations averaged over one revolution, using the given density model
+    /// and solar activity. Other perturbations (Earth oblateness, third bodies, solar radiation pressure)
+    /// are ignored. The object is considered decayed once its perigee altitude drops below 120 km.
+    ///
+    /// Returns `None` if the object does not decay within the maximum duration
+    /// or if the ballistic coefficient is not strictly positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `ballistic_coefficient` - The object's C_D A / m in m².kg⁻¹, for example calculated with [Elements::ballistic_coefficient](struct.Elements.html#method.ballistic_coefficient)
+    /// * `solar_activity` - The solar activity indices as a function of the time in years since UTC 1 January 2000 12h00 (J2000)
+    /// * `density` - The atmospheric density model, for example [ExponentialDensity](struct.ExponentialDensity.html) or a function such as [exponential_density](fn.exponential_density.html)
+    /// * `maximum_duration` - The largest lifetime considered in years
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    ///
+    /// // lifetime without reboosts during a solar maximum
+    /// if let Some(epoch) = elements.lifetime(
+    ///     0.005,
+    ///     |_| sgp4::SolarActivity {
+    ///         f107: 200.0,
+    ///         f107_average: 200.0,
+    ///         ap: 20.0,
+    ///     },
+    ///     sgp4::ExponentialDensity,
+    ///     25.0,
+    /// ) {
+    ///     println!("decay in {} days", elements.age(epoch));
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn lifetime<S, D>(
+        &self,
+        ballistic_coefficient: f64,
+        solar_activity: S,
+        density: D,
+        maximum_duration: f64,
+    ) -> Option<f64>
+    where
+        S: Fn(f64) -> atmosphere::SolarActivity,
+        D: atmosphere::DensityModel,
+    {
+        if ballistic_coefficient <= 0.0 {
+            return None;
+        }
+        let geopotential = model::WGS84;
+        let epoch = self.epoch();
+
+        let mut semi_major_axis = self.semi_major_axis(&geopotential);
+        let mut eccentricity = self.eccentricity;
+        let mut elapsed = 0.0;
+        while semi_major_axis * (1.0 - eccentricity) - geopotential.ae > DECAY_ALTITUDE {
+            if elapsed > maximum_duration * 365.25 {
+                return None;
+            }
+            let activity = solar_activity(epoch + elapsed / 365.25);
+            let (semi_major_axis_dot, eccentricity_dot) = averaged_rates(
+                semi_major_axis,
+                eccentricity,
+                ballistic_coefficient,
+                &activity,
+                &density,
+            );
+            let step = (MAXIMUM_SEMI_MAJOR_AXIS_STEP / -semi_major_axis_dot).min(MAXIMUM_STEP);
+
+            // midpoint integration
+            let (semi_major_axis_dot, eccentricity_dot) = averaged_rates(
+                semi_major_axis + semi_major_axis_dot * step / 2.0,
+                (eccentricity + eccentricity_dot * step / 2.0).max(0.0),
+                ballistic_coefficient,
+                &activity,
+                &density,
+            );
+            semi_major_axis += semi_major_axis_dot * step;
+            eccentricity = (eccentricity + eccentricity_dot * step).max(0.0);
+            elapsed += step;
+        }
+        Some(epoch + elapsed / 365.25)
+    }
+}
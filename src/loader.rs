@@ -0,0 +1,272 @@
+use crate::catalog;
+use crate::tle;
+
+/// Represents an error raised while loading a catalog directory
+#[derive(Debug)]
+pub enum LoadError {
+    /// The directory or a file could not be read
+    Io {
+        path: std::path::PathBuf,
+        error: std::io::Error,
+    },
+
+    /// A TLE or 3LE file contains an invalid element set
+    Tle {
+        path: std::path::PathBuf,
+        error: tle::Error,
+    },
+
+    /// A JSON file is not an OMM object or an array of OMM objects
+    Json {
+        path: std::path::PathBuf,
+        error: serde_json::Error,
+    },
+
+    /// A CSV row is not a valid OMM (lines start at 1, the header is line 1)
+    Csv {
+        path: std::path::PathBuf,
+        line: usize,
+        error: serde_json::Error,
+    },
+}
+
+impl core::fmt::Display for LoadError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LoadError::Io { path, error } => {
+                formatter.write_fmt(format_args!("{}: {}", path.display(), error))
+            }
+            LoadError::Tle { path, error } => {
+                formatter.write_fmt(format_args!("{}: {}", path.display(), error))
+            }
+            LoadError::Json { path, error } => {
+                formatter.write_fmt(format_args!("{}: {}", path.display(), error))
+            }
+            LoadError::Csv { path, line, error } => {
+                formatter.write_fmt(format_args!("{}:{}: {}", path.display(), line, error))
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+// format of a catalog file, detected from its content
+enum Format {
+    Json,
+    Csv,
+    TwoLines,
+    ThreeLines,
+}
+
+// detects the format from the first non-empty line
+fn detect_format(content: &str) -> Option<Format> {
+    let first_line = content.lines().find(|line| !line.trim().is_empty())?.trim();
+    if first_line.starts_with('[') || first_line.starts_with('{') {
+        Some(Format::Json)
+    } else if first_line.contains(',') && first_line.contains("NORAD_CAT_ID") {
+        Some(Format::Csv)
+    } else if first_line.starts_with("1 ") && first_line.len() == 69 {
+        Some(Format::TwoLines)
+    } else {
+        Some(Format::ThreeLines)
+    }
+}
+
+// splits a CSV line, quoted fields may contain commas and escaped quotes ("")
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut characters = line.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '"' if quoted && characters.peek() == Some(&'"') => {
+                field.push('"');
+                characters.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(core::mem::take(&mut field)),
+            character => field.push(character),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+// parses CSV OMMs (Celestrak and Space-Track), each row is converted to a JSON object of strings
+fn parse_csv(path: &std::path::Path, content: &str) -> Result<Vec<tle::Elements>, LoadError> {
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let header = match lines.next() {
+        Some((_, header)) => split_csv_line(header.trim()),
+        None => return Ok(Vec::new()),
+    };
+    lines
+        .map(|(index, line)| {
+            let values = split_csv_line(line.trim());
+            if values.len() != header.len() {
+                return Err(LoadError::Csv {
+                    path: path.to_owned(),
+                    line: index + 1,
+                    error: <serde_json::Error as serde::de::Error>::custom(format!(
+                        "expected {} columns, found {}",
+                        header.len(),
+                        values.len()
+                    )),
+                });
+            }
+            serde_json::from_value(serde_json::Value::Object(
+                header
+                    .iter()
+                    .zip(values)
+                    .filter(|(_, value)| !value.is_empty())
+                    .map(|(name, value)| (name.clone(), serde_json::Value::String(value)))
+                    .collect(),
+            ))
+            .map_err(|error| LoadError::Csv {
+                path: path.to_owned(),
+                line: index + 1,
+                error,
+            })
+        })
+        .collect()
+}
+
+// parses TLEs, with or without title lines (Space-Track's "0 " title prefix is removed)
+fn parse_lines(
+    content: &str,
+    with_titles: bool,
+) -> core::result::Result<Vec<tle::Elements>, tle::Error> {
+    let lines: Vec<&str> = content
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if with_titles {
+        lines
+            .chunks(3)
+            .filter(|chunk| chunk.len() == 3)
+            .map(|chunk| {
+                tle::Elements::from_3le(
+                    chunk[0].strip_prefix("0 ").unwrap_or(chunk[0]),
+                    chunk[1].as_bytes(),
+                    chunk[2].as_bytes(),
+                )
+            })
+            .collect()
+    } else {
+        lines
+            .chunks(2)
+            .filter(|chunk| chunk.len() == 2)
+            .map(|chunk| tle::Elements::from_tle(None, chunk[0].as_bytes(), chunk[1].as_bytes()))
+            .collect()
+    }
+}
+
+// reads and parses a single catalog file
+fn load_file(path: &std::path::Path) -> Result<Vec<tle::Elements>, LoadError> {
+    let content = std::fs::read_to_string(path).map_err(|error| LoadError::Io {
+        path: path.to_owned(),
+        error,
+    })?;
+    match detect_format(&content) {
+        None => Ok(Vec::new()),
+        Some(Format::Json) => if content.trim_start().starts_with('[') {
+            serde_json::from_str(&content)
+        } else {
+            serde_json::from_str(&content).map(|elements| vec![elements])
+        }
+        .map_err(|error| LoadError::Json {
+            path: path.to_owned(),
+            error,
+        }),
+        Some(Format::Csv) => parse_csv(path, &content),
+        Some(Format::TwoLines) => parse_lines(&content, false).map_err(|error| LoadError::Tle {
+            path: path.to_owned(),
+            error,
+        }),
+        Some(Format::ThreeLines) => parse_lines(&content, true).map_err(|error| LoadError::Tle {
+            path: path.to_owned(),
+            error,
+        }),
+    }
+}
+
+impl catalog::Catalog {
+    /// Parses all the files in a directory and merges their elements into the catalog
+    ///
+    /// The format of each file is detected from its content: JSON OMM arrays (or single objects),
+    /// CSV OMMs with a header line (Celestrak or Space-Track columns), TLEs (2LE) and TLEs with title lines (3LE).
+    /// Files are parsed in name order, hidden files and subdirectories are ignored.
+    /// Elements are merged with [Catalog::merge](struct.Catalog.html#method.merge), hence the most recent
+    /// elements of each satellite are kept.
+    ///
+    /// Returns the number of inserted elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory to scan
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let directory = std::env::temp_dir().join("sgp4-load-directory-example");
+    /// std::fs::create_dir_all(&directory)?;
+    /// std::fs::write(
+    ///     directory.join("stations.tle"),
+    ///     "ISS (ZARYA)
+    /// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+    /// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+    /// ",
+    /// )?;
+    /// std::fs::write(
+    ///     directory.join("molniya.csv"),
+    ///     "OBJECT_NAME,OBJECT_ID,EPOCH,MEAN_MOTION,ECCENTRICITY,INCLINATION,RA_OF_ASC_NODE,\
+    /// ARG_OF_PERICENTER,MEAN_ANOMALY,EPHEMERIS_TYPE,CLASSIFICATION_TYPE,NORAD_CAT_ID,\
+    /// ELEMENT_SET_NO,REV_AT_EPOCH,BSTAR,MEAN_MOTION_DOT,MEAN_MOTION_DDOT
+    /// MOLNIYA 1-36,1975-081A,2006-06-25T07:58:18.143616,2.00491383,.6877146,64.1586,279.0717,\
+    /// 264.7651,20.2257,0,U,8195,81,22565,.00011873,.00000099,0
+    /// ",
+    /// )?;
+    /// let mut catalog = sgp4::Catalog::new();
+    /// assert_eq!(catalog.load_directory(&directory)?, 2);
+    /// assert_eq!(
+    ///     catalog
+    ///         .get(sgp4::NoradId::new(8195)?)
+    ///         .and_then(|elements| elements.object_name.as_deref()),
+    ///     Some("MOLNIYA 1-36"),
+    /// );
+    /// #     std::fs::remove_dir_all(&directory)?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn load_directory<P: AsRef<std::path::Path>>(
+        &mut self,
+        directory: P,
+    ) -> Result<usize, LoadError> {
+        let directory = directory.as_ref();
+        let io_error = |error| LoadError::Io {
+            path: directory.to_owned(),
+            error,
+        };
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(directory).map_err(io_error)? {
+            let entry = entry.map_err(io_error)?;
+            if entry.file_type().map_err(io_error)?.is_file()
+                && !entry.file_name().to_string_lossy().starts_with('.')
+            {
+                paths.push(entry.path());
+            }
+        }
+        paths.sort();
+        let mut inserted = 0;
+        for path in paths {
+            inserted += self.merge(load_file(&path)?);
+        }
+        Ok(inserted)
+    }
+}
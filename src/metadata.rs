@@ -0,0 +1,174 @@
+use crate::brouwer_to_kozai;
+use crate::model;
+use crate::norad_id;
+use crate::propagator;
+use crate::tle;
+use crate::KozaiElementsError;
+use crate::Radians;
+
+/// Identification and informational fields of an element set
+///
+/// The default metadata describes an unclassified object with NORAD ID 0
+/// and zero mean motion derivatives.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementsMetadata {
+    /// The name of the object
+    #[cfg(feature = "alloc")]
+    pub object_name: Option<alloc::string::String>,
+
+    /// The name of the object
+    #[cfg(all(feature = "heapless", not(feature = "alloc")))]
+    pub object_name: Option<tle::ObjectName>,
+
+    /// The international designator (launch year, launch number and piece)
+    #[cfg(feature = "alloc")]
+    pub international_designator: Option<alloc::string::String>,
+
+    /// The international designator (launch year, launch number and piece)
+    #[cfg(all(feature = "heapless", not(feature = "alloc")))]
+    pub international_designator: Option<tle::InternationalDesignator>,
+
+    /// The satellite catalog number
+    pub norad_id: norad_id::NoradId,
+
+    /// The elements classification
+    pub classification: tle::Classification,
+
+    /// First time derivative of the mean motion divided by two in rev.day⁻², not used by SGP4
+    pub mean_motion_dot: f64,
+
+    /// Second time derivative of the mean motion divided by six in rev.day⁻³, not used by SGP4
+    pub mean_motion_ddot: f64,
+
+    /// The element set number
+    pub element_set_number: u64,
+
+    /// The revolution number at epoch
+    pub revolution_number: u64,
+
+    /// The ephemeris type, 0 in distributed data
+    pub ephemeris_type: u8,
+}
+
+impl Default for ElementsMetadata {
+    fn default() -> Self {
+        ElementsMetadata {
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            object_name: None,
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            international_designator: None,
+            norad_id: norad_id::NoradId::default(),
+            classification: tle::Classification::Unclassified,
+            mean_motion_dot: 0.0,
+            mean_motion_ddot: 0.0,
+            element_set_number: 0,
+            revolution_number: 0,
+            ephemeris_type: 0,
+        }
+    }
+}
+
+// angle in rad converted to deg in the range [0, 360[
+fn to_degrees(angle: f64) -> f64 {
+    model::positive_angle(angle).to_degrees()
+}
+
+impl tle::Elements {
+    /// Creates elements from a Brouwer orbit, an epoch and metadata
+    ///
+    /// This is the inverse of [Orbit::from_kozai_elements](struct.Orbit.html#method.from_kozai_elements):
+    /// the mean motion is converted to the Kozai convention and angles are converted to degrees in the range [0, 360[.
+    /// A negative inclination is made positive by rotating the node and the perigee by 180°.
+    /// The resulting elements can be serialized or formatted like parsed elements,
+    /// for example with [Elements::to_tle](struct.Elements.html#method.to_tle).
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity to use in the conversion
+    /// * `orbit` - The Brouwer orbital elements at epoch
+    /// * `datetime` - The epoch (UTC)
+    /// * `drag_term` - The radiation pressure coefficient in earth radii⁻¹ (B*)
+    /// * `metadata` - The identification and informational fields
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let orbit = sgp4::Orbit {
+    ///     inclination: 97.5_f64.to_radians(),
+    ///     right_ascension: 30.0_f64.to_radians(),
+    ///     eccentricity: 0.001,
+    ///     argument_of_perigee: 90.0_f64.to_radians(),
+    ///     mean_anomaly: 0.0,
+    ///     mean_motion: 15.0 * (core::f64::consts::PI / 720.0),
+    /// };
+    /// let elements = sgp4::Elements::from_orbit(
+    ///     &sgp4::WGS84,
+    ///     &orbit,
+    ///     sgp4::chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+    ///         .unwrap()
+    ///         .and_hms_opt(0, 0, 0)
+    ///         .unwrap(),
+    ///     1e-4,
+    ///     sgp4::ElementsMetadata {
+    ///         object_name: Some("SIMULATED".to_owned()),
+    ///         norad_id: sgp4::NoradId::new(99999)?,
+    ///         ..Default::default()
+    ///     },
+    /// )?;
+    /// let (line1, line2) = elements.to_tle()?;
+    /// println!("{line1}\n{line2}");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_orbit(
+        geopotential: &model::Geopotential,
+        orbit: &propagator::Orbit,
+        datetime: chrono::NaiveDateTime,
+        drag_term: f64,
+        metadata: ElementsMetadata,
+    ) -> core::result::Result<tle::Elements, KozaiElementsError> {
+        let (inclination, right_ascension, argument_of_perigee) = if orbit.inclination < 0.0 {
+            (
+                -orbit.inclination,
+                orbit.right_ascension + core::f64::consts::PI,
+                orbit.argument_of_perigee - core::f64::consts::PI,
+            )
+        } else {
+            (
+                orbit.inclination,
+                orbit.right_ascension,
+                orbit.argument_of_perigee,
+            )
+        };
+        Ok(tle::Elements {
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            object_name: metadata.object_name,
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            international_designator: metadata.international_designator,
+            norad_id: metadata.norad_id,
+            classification: metadata.classification,
+            datetime,
+            mean_motion_dot: metadata.mean_motion_dot,
+            mean_motion_ddot: metadata.mean_motion_ddot,
+            drag_term,
+            element_set_number: metadata.element_set_number,
+            inclination: inclination.to_degrees(),
+            right_ascension: to_degrees(right_ascension),
+            eccentricity: orbit.eccentricity,
+            argument_of_perigee: to_degrees(argument_of_perigee),
+            mean_anomaly: to_degrees(orbit.mean_anomaly),
+
+            // n₀ in rev.day⁻¹
+            mean_motion: brouwer_to_kozai(
+                geopotential,
+                Radians(orbit.inclination),
+                orbit.eccentricity,
+                orbit.mean_motion,
+            )? * (720.0 / core::f64::consts::PI),
+            revolution_number: metadata.revolution_number,
+            ephemeris_type: metadata.ephemeris_type,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use crate::propagator;
+use crate::tle;
+
+/// Position and velocity exported to Kotlin and Swift
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct UniffiPrediction {
+    /// The three position components (x, y, z) in km
+    pub position: Vec<f64>,
+
+    /// The three velocity components (x, y, z) in km.s⁻¹
+    pub velocity: Vec<f64>,
+}
+
+/// Error exported to Kotlin and Swift
+#[derive(Debug, Clone, uniffi::Error)]
+pub enum UniffiError {
+    /// The TLE or OMM could not be parsed
+    Parse { message: String },
+
+    /// The elements cannot be used to initialize a propagator
+    Elements { message: String },
+
+    /// The propagation time is out of range or the orbit diverged
+    Propagation { message: String },
+}
+
+impl core::fmt::Display for UniffiError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UniffiError::Parse { message }
+            | UniffiError::Elements { message }
+            | UniffiError::Propagation { message } => formatter.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for UniffiError {}
+
+/// A satellite propagator exported to Kotlin and Swift
+///
+/// The propagator keeps the parsed elements and the SGP4 epoch constants (WGS84, IAU sidereal time).
+/// Positions and velocities are expressed in the True Equator, Mean Equinox (TEME) reference frame.
+#[derive(uniffi::Object)]
+pub struct UniffiPropagator {
+    elements: tle::Elements,
+    constants: propagator::Constants,
+}
+
+impl UniffiPropagator {
+    fn new(elements: tle::Elements) -> Result<std::sync::Arc<Self>, UniffiError> {
+        let constants = propagator::Constants::from_elements(&elements).map_err(|error| {
+            UniffiError::Elements {
+                message: error.to_string(),
+            }
+        })?;
+        Ok(std::sync::Arc::new(UniffiPropagator {
+            elements,
+            constants,
+        }))
+    }
+}
+
+#[uniffi::export]
+impl UniffiPropagator {
+    /// Parses a TLE and initializes a propagator
+    #[uniffi::constructor]
+    pub fn from_tle(
+        object_name: Option<String>,
+        line1: String,
+        line2: String,
+    ) -> Result<std::sync::Arc<Self>, UniffiError> {
+        UniffiPropagator::new(
+            tle::Elements::from_tle(
+                object_name,
+                line1.trim().as_bytes(),
+                line2.trim().as_bytes(),
+            )
+            .map_err(|error| UniffiError::Parse {
+                message: error.to_string(),
+            })?,
+        )
+    }
+
+    /// Parses a JSON-encoded OMM object and initializes a propagator
+    #[uniffi::constructor]
+    pub fn from_json(omm: String) -> Result<std::sync::Arc<Self>, UniffiError> {
+        UniffiPropagator::new(
+            serde_json::from_str(&omm).map_err(|error| UniffiError::Parse {
+                message: error.to_string(),
+            })?,
+        )
+    }
+
+    /// Returns the name of the satellite, if any
+    pub fn object_name(&self) -> Option<String> {
+        self.elements.object_name.clone()
+    }
+
+    /// Returns the satellite catalog number
+    pub fn norad_id(&self) -> u64 {
+        self.elements.norad_id.get()
+    }
+
+    /// Returns the elements' epoch in milliseconds since the Unix epoch
+    pub fn epoch_unix_milliseconds(&self) -> i64 {
+        self.elements.datetime.and_utc().timestamp_millis()
+    }
+
+    /// Returns the prediction at the given number of minutes since epoch
+    pub fn propagate(&self, minutes: f64) -> Result<UniffiPrediction, UniffiError> {
+        let prediction = self
+            .constants
+            .propagate(tle::MinutesSinceEpoch(minutes))
+            .map_err(|error| UniffiError::Propagation {
+                message: error.to_string(),
+            })?;
+        Ok(UniffiPrediction {
+            position: prediction.position.to_vec(),
+            velocity: prediction.velocity.to_vec(),
+        })
+    }
+
+    /// Returns the prediction at the given time in milliseconds since the Unix epoch
+    ///
+    /// Kotlin's `Instant.toEpochMilli()` and Swift's `Date.timeIntervalSince1970 * 1000` return this value.
+    pub fn propagate_unix_milliseconds(
+        &self,
+        unix_milliseconds: i64,
+    ) -> Result<UniffiPrediction, UniffiError> {
+        self.propagate((unix_milliseconds - self.epoch_unix_milliseconds()) as f64 / 60000.0)
+    }
+}
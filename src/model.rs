@@ -29,27 +29,199 @@ pub struct Geopotential {
     pub j4: f64,
 }
 
+impl Geopotential {
+    /// Creates a custom geopotential model
+    ///
+    /// This function can be called in a const context to define mission-specific models as constants,
+    /// in which case invalid parameters are reported at compile time.
+    ///
+    /// # Arguments
+    ///
+    /// * `ae` - Equatorial radius of the earth in km, must be positive
+    /// * `ke` - Square root of earth's gravitational parameter in earth radii³ min⁻², must be positive
+    /// * `j2` - Un-normalised second zonal harmonic, must not be zero
+    /// * `j3` - Un-normalised third zonal harmonic
+    /// * `j4` - Un-normalised fourth zonal harmonic
+    ///
+    /// # Panics
+    ///
+    /// Panics if a parameter is not finite, if `ae` or `ke` is not positive, or if `j2` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // WGS84 without the J₃ and J₄ zonal harmonics, to isolate the effect of J₂
+    /// const WGS84_J2: sgp4::Geopotential = sgp4::Geopotential::new(
+    ///     6378.137,
+    ///     0.07436685316871385,
+    ///     0.00108262998905,
+    ///     0.0,
+    ///     0.0,
+    /// );
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::new(
+    ///     WGS84_J2,
+    ///     sgp4::iau_epoch_to_sidereal_time,
+    ///     elements.epoch(),
+    ///     elements.drag_term,
+    ///     sgp4::Orbit::from_kozai_elements(
+    ///         &WGS84_J2,
+    ///         sgp4::Degrees(elements.inclination),
+    ///         sgp4::Degrees(elements.right_ascension),
+    ///         elements.eccentricity,
+    ///         sgp4::Degrees(elements.argument_of_perigee),
+    ///         sgp4::Degrees(elements.mean_anomaly),
+    ///         elements.mean_motion * (core::f64::consts::PI / 720.0),
+    ///     )?,
+    /// )?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Invalid parameters in a constant do not compile:
+    ///
+    /// ```compile_fail
+    /// const INVALID: sgp4::Geopotential =
+    ///     sgp4::Geopotential::new(-6378.137, 0.07436685316871385, 0.00108262998905, 0.0, 0.0);
+    /// ```
+    pub const fn new(ae: f64, ke: f64, j2: f64, j3: f64, j4: f64) -> Geopotential {
+        assert!(
+            ae.is_finite() && ae > 0.0,
+            "the equatorial radius ae must be positive and finite"
+        );
+        assert!(
+            ke.is_finite() && ke > 0.0,
+            "the gravitational parameter ke must be positive and finite"
+        );
+        assert!(
+            j2.is_finite() && j2 != 0.0,
+            "the zonal harmonic J2 must be finite and non-zero"
+        );
+        assert!(
+            j3.is_finite() && j4.is_finite(),
+            "the zonal harmonics J3 and J4 must be finite"
+        );
+        Geopotential { ae, ke, j2, j3, j4 }
+    }
+}
+
 /// The geopotential model recommended by the IAU
 ///
 /// This model is recommended to propagate orbits.
-pub const WGS84: Geopotential = Geopotential {
-    ae: 6378.137,
-    ke: 0.07436685316871385,
-    j2: 0.00108262998905,
-    j3: -0.00000253215306,
-    j4: -0.00000161098761,
-};
+pub const WGS84: Geopotential = Geopotential::new(
+    6378.137,
+    0.07436685316871385,
+    0.00108262998905,
+    -0.00000253215306,
+    -0.00000161098761,
+);
 
 /// The geopotential model used in the AFSPC implementation
 ///
 /// This model should be used if compatibility with the AFSPC implementation is needed.
-pub const WGS72: Geopotential = Geopotential {
-    ae: 6378.135,
-    ke: 0.07436691613317342,
-    j2: 0.001082616,
-    j3: -0.00000253881,
-    j4: -0.00000165597,
-};
+pub const WGS72: Geopotential = Geopotential::new(
+    6378.135,
+    0.07436691613317342,
+    0.001082616,
+    -0.00000253881,
+    -0.00000165597,
+);
+
+/// The historical WGS72 geopotential model with a truncated gravitational parameter
+///
+/// This is the `wgs72old` constant set of Vallado et al. (2006), "Revisiting Spacetrack Report #3".
+/// kₑ is set to the legacy value 0.0743669161 instead of being calculated from GM = 398600.8 km³.s⁻²,
+/// the other terms are identical to [WGS72](constant.WGS72.html).
+/// This model should only be used for exact comparisons against legacy implementations.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let constants = sgp4::Constants::new(
+///     sgp4::WGS72_OLD,
+///     sgp4::afspc_epoch_to_sidereal_time,
+///     elements.epoch_afspc_compatibility_mode(),
+///     elements.drag_term,
+///     sgp4::Orbit::from_kozai_elements(
+///         &sgp4::WGS72_OLD,
+///         sgp4::Degrees(elements.inclination),
+///         sgp4::Degrees(elements.right_ascension),
+///         elements.eccentricity,
+///         sgp4::Degrees(elements.argument_of_perigee),
+///         sgp4::Degrees(elements.mean_anomaly),
+///         elements.mean_motion * (core::f64::consts::PI / 720.0),
+///     )?,
+/// )?;
+/// let prediction = constants.propagate(sgp4::MinutesSinceEpoch(60.0))?;
+/// #     Ok(())
+/// # }
+/// ```
+pub const WGS72_OLD: Geopotential = Geopotential::new(
+    6378.135,
+    0.0743669161,
+    0.001082616,
+    -0.00000253881,
+    -0.00000165597,
+);
+
+/// The geopotential model derived from EGM96
+///
+/// The zonal harmonics are calculated from the normalized coefficients C̄₂₀, C̄₃₀ and C̄₄₀
+/// of Lemoine et al. (1998), NASA/TP-1998-206861, with Jₙ = -(2n + 1)¹ᐟ² C̄ₙ₀.
+/// kₑ is calculated from GM = 398600.4415 km³.s⁻² and aₑ = 6378.1363 km.
+///
+/// TLEs are fitted with WGS72, hence this model does not improve the accuracy of SGP4 predictions.
+/// It should be used to match the output of other toolchains configured with the same model.
+pub const EGM96: Geopotential = Geopotential::new(
+    6378.1363,
+    0.07436685995417885,
+    0.00108262668355315,
+    -0.00000253265648533224,
+    -0.00000161962159136700,
+);
+
+/// The geopotential model derived from EGM2008
+///
+/// The zonal harmonics are calculated from the normalized coefficients C̄₂₀, C̄₃₀ and C̄₄₀
+/// of Pavlis et al. (2012), Journal of Geophysical Research 117 (B4), with Jₙ = -(2n + 1)¹ᐟ² C̄ₙ₀.
+/// kₑ is calculated from GM = 398600.4415 km³.s⁻² and aₑ = 6378.1363 km.
+///
+/// TLEs are fitted with WGS72, hence this model does not improve the accuracy of SGP4 predictions.
+/// It should be used to match the output of other toolchains configured with the same model.
+pub const EGM2008: Geopotential = Geopotential::new(
+    6378.1363,
+    0.07436685995417885,
+    0.00108262617385222,
+    -0.00000253241051856772,
+    -0.00000161989759991697,
+);
+
+/// The geopotential model derived from JGM-3
+///
+/// The zonal harmonics are calculated from the normalized coefficients C̄₂₀, C̄₃₀ and C̄₄₀
+/// of Tapley et al. (1996), Journal of Geophysical Research 101 (B12), with Jₙ = -(2n + 1)¹ᐟ² C̄ₙ₀.
+/// kₑ is calculated from GM = 398600.4415 km³.s⁻² and aₑ = 6378.1363 km.
+///
+/// TLEs are fitted with WGS72, hence this model does not improve the accuracy of SGP4 predictions.
+/// It should be used to match the output of other toolchains configured with the same model.
+pub const JGM3: Geopotential = Geopotential::new(
+    6378.1363,
+    0.07436685995417885,
+    0.00108263602298405,
+    -0.00000253243534575439,
+    -0.00000161933120507190,
+);
 
 /// Converts an epoch to sidereal time using the IAU expression
 ///
@@ -111,3 +283,73 @@ pub fn afspc_epoch_to_sidereal_time(epoch: f64) -> f64 {
         Euclid::rem_euclid(&theta, &(2.0 * core::f64::consts::PI))
     }
 }
+
+/// Converts an epoch to the Earth Rotation Angle using the IAU 2006 expression
+///
+/// The Earth Rotation Angle (ERA) is the angle between the Celestial and Terrestrial Intermediate Origins.
+/// It is used instead of the Greenwich mean sidereal time by frame pipelines based on the IAU 2006/2000A
+/// conventions. It differs from the IAU sidereal time by the accumulated precession in right ascension
+/// (about 4.5 × 10⁻³ rad in 2020). The epoch is interpreted as UT1, with UT1 = UTC.
+///
+/// # Arguments
+///
+/// * `epoch` - Years since UTC 1 January 2000 12h00
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let constants = sgp4::Constants::new(
+///     sgp4::WGS84,
+///     sgp4::era_epoch_to_sidereal_time,
+///     elements.epoch(),
+///     elements.drag_term,
+///     sgp4::Orbit::from_kozai_elements(
+///         &sgp4::WGS84,
+///         sgp4::Degrees(elements.inclination),
+///         sgp4::Degrees(elements.right_ascension),
+///         elements.eccentricity,
+///         sgp4::Degrees(elements.argument_of_perigee),
+///         sgp4::Degrees(elements.mean_anomaly),
+///         elements.mean_motion * (core::f64::consts::PI / 720.0),
+///     )?,
+/// )?;
+/// let difference = sgp4::iau_epoch_to_sidereal_time(elements.epoch())
+///     - sgp4::era_epoch_to_sidereal_time(elements.epoch());
+/// assert!((difference - 4.5e-3).abs() < 1e-4);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn era_epoch_to_sidereal_time(epoch: f64) -> f64 {
+    // d₂₀₀₀ = 365.25 y₂₀₀₀
+    let d2000 = epoch * 365.25;
+
+    // θ = 2π (d₂₀₀₀ mod 1 + 0.7790572732640 + 0.00273781191135448 d₂₀₀₀) mod 2π
+    // the integer part of d₂₀₀₀ is removed first to preserve the precision of the fractional turns
+    let theta = 2.0
+        * core::f64::consts::PI
+        * ((d2000 - d2000.floor()) + 0.7790572732640 + 0.00273781191135448 * d2000);
+    positive_angle(theta)
+}
+
+// angle rem 2π, in the range [0, 2π[
+pub(crate) fn positive_angle(angle: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        angle.rem_euclid(2.0 * core::f64::consts::PI)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        Euclid::rem_euclid(&angle, &(2.0 * core::f64::consts::PI))
+    }
+}
+
+// angle rem 2π, in the range [-π, π[
+pub(crate) fn signed_angle(angle: f64) -> f64 {
+    positive_angle(angle + core::f64::consts::PI) - core::f64::consts::PI
+}
@@ -0,0 +1,124 @@
+use crate::events;
+use crate::gp;
+use crate::model;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Orbital node type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Node {
+    /// The satellite crosses the equator northbound
+    Ascending,
+
+    /// The satellite crosses the equator southbound
+    Descending,
+}
+
+/// An equator crossing
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeCrossing {
+    /// Time of the crossing in minutes since epoch
+    pub t: MinutesSinceEpoch,
+
+    /// Crossing direction
+    pub node: Node,
+
+    /// Angle between the vernal equinox and the crossing point in rad, in the range [0, 2π[
+    ///
+    /// The angle is measured in the True Equator, Mean Equinox (TEME) reference frame.
+    pub right_ascension: f64,
+
+    /// Geographic longitude of the crossing point in rad, in the range [-π, π[
+    ///
+    /// The longitude is calculated with the sidereal time expression passed to
+    /// [Constants::node_crossings](struct.Constants.html#method.node_crossings).
+    pub longitude: f64,
+}
+
+impl propagator::Constants {
+    /// Finds the equator crossings (orbital nodes) in a time window
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `epoch_to_sidereal_time` - The function to use to convert the J2000 epoch to sidereal time,
+    ///   usually the one used to initialize the propagator
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(
+    ///     &sgp4::Elements::from_tle(
+    ///         Some("ISS (ZARYA)".to_owned()),
+    ///         "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///         "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    ///     )?,
+    /// )?;
+    /// let crossings = constants.node_crossings(
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    ///     sgp4::iau_epoch_to_sidereal_time,
+    /// )?;
+    /// for crossing in crossings.iter() {
+    ///     if crossing.node == sgp4::Node::Ascending {
+    ///         println!(
+    ///             "t = {} min, longitude = {}°",
+    ///             crossing.t.0,
+    ///             crossing.longitude.to_degrees()
+    ///         );
+    ///     }
+    /// }
+    ///
+    /// // the ISS is 9.6° past the ascending node at epoch, hence the first crossing is descending
+    /// assert_eq!(crossings.len(), 31);
+    /// assert_eq!(crossings[0].node, sgp4::Node::Descending);
+    /// assert!((crossings[0].t.0 - 43.92).abs() < 0.01);
+    ///
+    /// // the ascending node is close to the right ascension of the ascending node (221.28°),
+    /// // and the Earth rotates by 23.6° under the orbit between two ascending crossings
+    /// assert_eq!(crossings[1].node, sgp4::Node::Ascending);
+    /// assert!((crossings[1].right_ascension.to_degrees() - 220.97).abs() < 0.01);
+    /// assert!(
+    ///     (crossings[3].longitude.to_degrees() - crossings[1].longitude.to_degrees() + 23.6).abs()
+    ///         < 0.1
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn node_crossings(
+        &self,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        epoch_to_sidereal_time: impl Fn(f64) -> f64,
+    ) -> core::result::Result<alloc::vec::Vec<NodeCrossing>, gp::Error> {
+        events::find_crossings(start, end, self.period() / 8.0, 1e-6, |t| {
+            self.propagate(t).map(|prediction| prediction.position[2])
+        })?
+        .into_iter()
+        .map(|crossing| {
+            let prediction = self.propagate(crossing.t)?;
+            let right_ascension =
+                model::positive_angle(prediction.position[1].atan2(prediction.position[0]));
+            Ok(NodeCrossing {
+                t: crossing.t,
+                node: match crossing.direction {
+                    events::Direction::Increasing => Node::Ascending,
+                    events::Direction::Decreasing => Node::Descending,
+                },
+                right_ascension,
+                longitude: model::signed_angle(
+                    right_ascension
+                        - epoch_to_sidereal_time(self.julian_years_since_j2000(crossing.t)),
+                ),
+            })
+        })
+        .collect()
+    }
+}
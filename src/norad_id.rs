@@ -0,0 +1,246 @@
+use crate::tle::TrimAscii;
+
+/// Represents an error raised while creating or formatting a NORAD ID
+#[derive(Debug, Clone)]
+pub enum NoradIdError {
+    /// The catalog number has more than 9 digits
+    OutOfRange(u64),
+
+    /// The catalog number is larger than 339999 (Z9999), the largest Alpha-5 number
+    OutOfAlpha5Range(NoradId),
+
+    /// The representation is empty or has too many characters
+    Length(usize),
+
+    /// A character is not a digit, or not an Alpha-5 letter (A to Z without I and O) at the first position
+    Character {
+        /// Zero-based index of the character in the trimmed representation
+        index: usize,
+
+        /// The invalid character
+        character: char,
+    },
+}
+
+impl core::fmt::Display for NoradIdError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NoradIdError::OutOfRange(norad_id) => formatter.write_fmt(core::format_args!(
+                "The NORAD ID {} has more than 9 digits",
+                norad_id
+            )),
+            NoradIdError::OutOfAlpha5Range(norad_id) => formatter.write_fmt(core::format_args!(
+                "The NORAD ID {} is larger than the largest Alpha-5 number (339999)",
+                norad_id
+            )),
+            NoradIdError::Length(length) => formatter.write_fmt(core::format_args!(
+                "The NORAD ID has {} characters (expected 1 to 9 digits or 5 Alpha-5 characters)",
+                length
+            )),
+            NoradIdError::Character { index, character } => {
+                formatter.write_fmt(core::format_args!(
+                    "The NORAD ID character {:?} at index {} is invalid",
+                    character,
+                    index
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoradIdError {}
+
+/// The catalog number USSPACECOM has designated for an object
+///
+/// NORAD IDs range from 0 to 999999999 (the 9 digits of the OMM `NORAD_CAT_ID` field).
+/// TLEs have room for 5 characters: numbers up to 99999 are written with digits,
+/// and numbers from 100000 to 339999 use the Alpha-5 scheme, where the first character is a letter
+/// standing for the two leading digits (A = 10, …, H = 17, J = 18, …, N = 22, P = 23, …, Z = 33,
+/// I and O are skipped to avoid confusion with 1 and 0).
+///
+/// `NoradId` displays as a number and parses both representations.
+/// It can be compared with `u64` values.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let norad_id: sgp4::NoradId = "E8493".parse()?;
+/// assert_eq!(norad_id, 148493);
+/// assert_eq!(norad_id.to_string(), "148493");
+/// assert_eq!(&norad_id.to_alpha5()?, b"E8493");
+/// assert_eq!(&sgp4::NoradId::new(8195)?.to_alpha5()?, b"08195");
+/// assert!(sgp4::NoradId::new(340000)?.to_alpha5().is_err());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NoradId(u32);
+
+impl NoradId {
+    /// The largest NORAD ID (999999999)
+    pub const MAX: Self = Self(999999999);
+
+    /// Creates a NORAD ID from its numerical value
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The catalog number, in the range [0, 999999999]
+    pub const fn new(value: u64) -> core::result::Result<Self, NoradIdError> {
+        if value > Self::MAX.0 as u64 {
+            Err(NoradIdError::OutOfRange(value))
+        } else {
+            Ok(Self(value as u32))
+        }
+    }
+
+    /// Returns the numerical value of the catalog number
+    pub const fn get(self) -> u64 {
+        self.0 as u64
+    }
+
+    /// Parses a TLE catalog number field
+    ///
+    /// The field has up to 5 characters, either digits (optionally padded with leading spaces)
+    /// or an Alpha-5 letter followed by 4 digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The characters of the TLE field
+    pub fn from_alpha5(field: &[u8]) -> core::result::Result<Self, NoradIdError> {
+        parse(field, 5)
+    }
+
+    /// Formats the catalog number as a 5-character TLE field
+    ///
+    /// Numbers up to 99999 are padded with leading zeros,
+    /// and numbers from 100000 to 339999 use the Alpha-5 scheme.
+    pub fn to_alpha5(self) -> core::result::Result<[u8; 5], NoradIdError> {
+        let prefix = self.0 / 10000;
+        let mut field = [b'0'; 5];
+        field[0] = match prefix {
+            0..=9 => b'0' + prefix as u8,
+            10..=33 => {
+                // skip I and O
+                let mut letter = b'A' + (prefix - 10) as u8;
+                if letter >= b'I' {
+                    letter += 1;
+                }
+                if letter >= b'O' {
+                    letter += 1;
+                }
+                letter
+            }
+            _ => return Err(NoradIdError::OutOfAlpha5Range(self)),
+        };
+        let mut remainder = self.0 % 10000;
+        for byte in field[1..].iter_mut().rev() {
+            *byte = b'0' + (remainder % 10) as u8;
+            remainder /= 10;
+        }
+        Ok(field)
+    }
+}
+
+// parses digits or an Alpha-5 number after trimming leading and trailing spaces
+fn parse(bytes: &[u8], maximum_length: usize) -> core::result::Result<NoradId, NoradIdError> {
+    let bytes = bytes.trim_ascii_polyfill();
+    if bytes.is_empty() || bytes.len() > maximum_length {
+        return Err(NoradIdError::Length(bytes.len()));
+    }
+    let mut value = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        value = value * 10
+            + match byte {
+                b'0'..=b'9' => (byte - b'0') as u64,
+                b'A'..=b'Z' if index == 0 && bytes.len() == 5 && !matches!(byte, b'I' | b'O') => {
+                    // A = 10, …, H = 17, J = 18, …, N = 22, P = 23, …, Z = 33
+                    let mut prefix = (byte - b'A') as u64 + 10;
+                    if *byte > b'I' {
+                        prefix -= 1;
+                    }
+                    if *byte > b'O' {
+                        prefix -= 1;
+                    }
+                    prefix
+                }
+                _ => {
+                    return Err(NoradIdError::Character {
+                        index,
+                        character: *byte as char,
+                    })
+                }
+            };
+    }
+    NoradId::new(value)
+}
+
+impl core::fmt::Display for NoradId {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+impl core::str::FromStr for NoradId {
+    type Err = NoradIdError;
+
+    fn from_str(string: &str) -> core::result::Result<Self, Self::Err> {
+        parse(string.as_bytes(), 9)
+    }
+}
+
+impl TryFrom<u64> for NoradId {
+    type Error = NoradIdError;
+
+    fn try_from(value: u64) -> core::result::Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<NoradId> for u64 {
+    fn from(norad_id: NoradId) -> Self {
+        norad_id.get()
+    }
+}
+
+impl PartialEq<u64> for NoradId {
+    fn eq(&self, other: &u64) -> bool {
+        self.get() == *other
+    }
+}
+
+impl PartialEq<NoradId> for u64 {
+    fn eq(&self, other: &NoradId) -> bool {
+        *self == other.get()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NoradId {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u64(self.get())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NoradId {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        match serde_json::value::Value::deserialize(deserializer)? {
+            serde_json::value::Value::Number(number) => number
+                .as_u64()
+                .ok_or_else(|| serde::de::Error::custom("parsing the number as u64 failed"))
+                .and_then(|value| NoradId::new(value).map_err(serde::de::Error::custom)),
+            serde_json::value::Value::String(string) => {
+                string.parse().map_err(serde::de::Error::custom)
+            }
+            _ => Err(serde::de::Error::custom("expected a u64 or string")),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use crate::fit;
+use crate::model;
+use crate::observer;
+use crate::propagator;
+use crate::tle;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A quantity measured by a ground station
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Measurement {
+    /// Local horizontal coordinates in rad (azimuth clockwise from the north)
+    AzimuthElevation { azimuth: f64, elevation: f64 },
+
+    /// Topocentric equatorial coordinates in the TEME reference frame in rad
+    RightAscensionDeclination {
+        right_ascension: f64,
+        declination: f64,
+    },
+
+    /// Distance between the station and the satellite in km
+    Range(f64),
+
+    /// Rate of change of the distance between the station and the satellite in km.s⁻¹
+    RangeRate(f64),
+}
+
+/// A measurement of a satellite made by a ground station
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observation {
+    /// The measurement's time (UTC)
+    pub datetime: chrono::NaiveDateTime,
+
+    /// The station's location
+    pub observer: observer::Observer,
+
+    /// The measured quantity
+    pub measurement: Measurement,
+
+    /// The measurement's standard deviation, in rad for angles, km for ranges and km.s⁻¹ for range rates
+    pub standard_deviation: f64,
+}
+
+impl fit::ElementsFit {
+    /// Fits SGP4 mean elements to angle, range and range rate observations from ground stations
+    ///
+    /// The elements are refined from an initial guess, for example a previous element set of the same object,
+    /// with the Levenberg-Marquardt differential correction used by
+    /// [ElementsFit::from_states](struct.ElementsFit.html#method.from_states).
+    /// Residuals are divided by the measurements' standard deviations, hence the returned `rms` is dimensionless
+    /// and close to 1 if the standard deviations are realistic. Azimuth and right ascension residuals
+    /// are multiplied by the cosine of the elevation and declination respectively.
+    /// The station positions are calculated with the IAU sidereal time expression, without polar motion
+    /// or atmospheric refraction.
+    ///
+    /// The fitted elements have the initial elements' epoch and identification fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `observations` - The measurements, which may come from several stations
+    /// * `initial_elements` - The initial guess
+    /// * `options` - The fit settings (the initial elements' drag term is not used, see `options.drag_term`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::from_elements(&elements)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: 48.8566_f64.to_radians(),
+    ///     longitude: 2.3522_f64.to_radians(),
+    ///     altitude: 0.035,
+    /// };
+    ///
+    /// // simulated azimuth, elevation and range measurements
+    /// let mut observations = Vec::new();
+    /// for minutes in 0..24 * 60 {
+    ///     let t = sgp4::MinutesSinceEpoch(minutes as f64);
+    ///     let look_angles = observer.look_angles(
+    ///         sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+    ///         &constants.propagate(t)?,
+    ///     );
+    ///     if look_angles.elevation > 0.0 {
+    ///         let datetime = elements.minutes_since_epoch_to_datetime(&t)?;
+    ///         observations.push(sgp4::Observation {
+    ///             datetime,
+    ///             observer,
+    ///             measurement: sgp4::Measurement::AzimuthElevation {
+    ///                 azimuth: look_angles.azimuth,
+    ///                 elevation: look_angles.elevation,
+    ///             },
+    ///             standard_deviation: 1e-4,
+    ///         });
+    ///         observations.push(sgp4::Observation {
+    ///             datetime,
+    ///             observer,
+    ///             measurement: sgp4::Measurement::Range(look_angles.range),
+    ///             standard_deviation: 0.01,
+    ///         });
+    ///     }
+    /// }
+    ///
+    /// // a perturbed initial guess
+    /// let mut initial_elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// initial_elements.mean_anomaly += 0.1;
+    /// let fit = sgp4::ElementsFit::from_observations(
+    ///     &observations,
+    ///     &initial_elements,
+    ///     &sgp4::FitOptions {
+    ///         drag_term: initial_elements.drag_term,
+    ///         ..Default::default()
+    ///     },
+    /// )?;
+    /// assert!((fit.elements.mean_anomaly - elements.mean_anomaly).abs() < 0.01);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_observations(
+        observations: &[Observation],
+        initial_elements: &tle::Elements,
+        options: &fit::FitOptions,
+    ) -> core::result::Result<fit::ElementsFit, fit::FitError> {
+        let epoch = initial_elements.datetime;
+        let (parameters, cost, count, iterations) = fit::least_squares(
+            fit::parameters(
+                &propagator::Orbit {
+                    inclination: initial_elements.inclination.to_radians(),
+                    right_ascension: initial_elements.right_ascension.to_radians(),
+                    eccentricity: initial_elements.eccentricity,
+                    argument_of_perigee: initial_elements.argument_of_perigee.to_radians(),
+                    mean_anomaly: initial_elements.mean_anomaly.to_radians(),
+                    mean_motion: initial_elements.mean_motion * (core::f64::consts::PI / 720.0),
+                },
+                options.drag_term,
+            ),
+            options.fit_drag_term,
+            options,
+            |parameters| {
+                let candidate = fit::elements(parameters, epoch);
+                let constants = propagator::Constants::from_elements(&candidate)?;
+                let mut residuals = alloc::vec::Vec::with_capacity(observations.len() * 2);
+                for observation in observations {
+                    let t = candidate.datetime_to_minutes_since_epoch(&observation.datetime)?;
+                    let prediction = constants.propagate(t)?;
+                    let sidereal_time =
+                        model::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t));
+                    match observation.measurement {
+                        Measurement::AzimuthElevation { azimuth, elevation } => {
+                            let look_angles =
+                                observation.observer.look_angles(sidereal_time, &prediction);
+                            residuals.push(
+                                model::signed_angle(azimuth - look_angles.azimuth)
+                                    * elevation.cos()
+                                    / observation.standard_deviation,
+                            );
+                            residuals.push(
+                                (elevation - look_angles.elevation)
+                                    / observation.standard_deviation,
+                            );
+                        }
+                        Measurement::RightAscensionDeclination {
+                            right_ascension,
+                            declination,
+                        } => {
+                            // ρ = r - rₒ
+                            let rho = vector::sub(
+                                &prediction.position,
+                                &observation.observer.position(sidereal_time),
+                            );
+                            residuals.push(
+                                model::signed_angle(right_ascension - rho[1].atan2(rho[0]))
+                                    * declination.cos()
+                                    / observation.standard_deviation,
+                            );
+                            residuals.push(
+                                (declination
+                                    - (rho[2] / vector::norm(&rho)).clamp(-1.0, 1.0).asin())
+                                    / observation.standard_deviation,
+                            );
+                        }
+                        Measurement::Range(range) => {
+                            residuals.push(
+                                (range
+                                    - observation
+                                        .observer
+                                        .look_angles(sidereal_time, &prediction)
+                                        .range)
+                                    / observation.standard_deviation,
+                            );
+                        }
+                        Measurement::RangeRate(range_rate) => {
+                            residuals.push(
+                                (range_rate
+                                    - observation.observer.range_rate(sidereal_time, &prediction))
+                                    / observation.standard_deviation,
+                            );
+                        }
+                    }
+                }
+                Ok(residuals)
+            },
+        )?;
+        let mut elements = fit::elements(&parameters, epoch);
+        elements.object_name = initial_elements.object_name.clone();
+        elements.international_designator = initial_elements.international_designator.clone();
+        elements.norad_id = initial_elements.norad_id;
+        elements.classification = initial_elements.classification;
+        elements.element_set_number = initial_elements.element_set_number;
+        elements.revolution_number = initial_elements.revolution_number;
+        Ok(fit::ElementsFit {
+            elements,
+            rms: (cost / count as f64).sqrt(),
+            iterations,
+        })
+    }
+}
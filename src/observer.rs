@@ -0,0 +1,291 @@
+use crate::geodetic::{ELLIPSOID_EQUATORIAL_RADIUS, ELLIPSOID_FLATTENING};
+use crate::model;
+use crate::propagator;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// ωₑ = 7.292115 × 10⁻⁵ rad.s⁻¹ (Earth rotation rate)
+const EARTH_ROTATION_RATE: f64 = 7.292115e-5;
+
+/// Geodetic location of a ground observer
+///
+/// The position is defined with respect to the WGS84 ellipsoid.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observer {
+    /// Geodetic latitude in rad (positive north)
+    pub latitude: f64,
+
+    /// Longitude in rad (positive east)
+    pub longitude: f64,
+
+    /// Height above the WGS84 ellipsoid in km
+    pub altitude: f64,
+}
+
+/// Direction of a satellite as seen by a ground observer
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LookAngles {
+    /// Angle between the north and the projection of the satellite direction on the local horizontal plane in rad
+    ///
+    /// The azimuth is measured clockwise (towards the east) and lies in the range [0, 2π[.
+    pub azimuth: f64,
+
+    /// Angle between the local horizontal plane and the satellite direction in rad
+    pub elevation: f64,
+
+    /// Distance between the observer and the satellite in km
+    pub range: f64,
+
+    /// Rate of change of the distance between the observer and the satellite in km.s⁻¹
+    ///
+    /// The range rate is positive when the satellite moves away from the observer.
+    pub range_rate: f64,
+}
+
+/// Represents an error raised while parsing a Maidenhead grid locator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaidenheadError {
+    /// The locator does not have 2, 4, 6, 8 or 10 characters
+    Length(usize),
+
+    /// A character is outside the range allowed at its position
+    Character {
+        /// Zero-based index of the character in the locator
+        index: usize,
+
+        /// The invalid character
+        character: char,
+    },
+}
+
+impl core::fmt::Display for MaidenheadError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MaidenheadError::Length(length) => formatter.write_fmt(core::format_args!(
+                "The Maidenhead locator has {} characters (expected 2, 4, 6, 8 or 10)",
+                length
+            )),
+            MaidenheadError::Character { index, character } => {
+                formatter.write_fmt(core::format_args!(
+                    "The Maidenhead locator character {:?} at index {} is out of range",
+                    character,
+                    index
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MaidenheadError {}
+
+impl Observer {
+    /// Creates an observer at the centre of a Maidenhead grid locator
+    ///
+    /// Locators alternate letter and digit pairs (longitude first): fields (`AA` to `RR`, 20° × 10°),
+    /// squares (`00` to `99`, 2° × 1°), subsquares (`aa` to `xx`, 5' × 2.5'),
+    /// extended squares (`00` to `99`, 30" × 15") and extended subsquares (`aa` to `xx`, 1.25" × 0.625").
+    /// Letters are case-insensitive.
+    /// The centre of a six-character locator is within about 5 km of any point of the subsquare.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - The grid locator, with 2, 4, 6, 8 or 10 characters
+    /// * `altitude` - The height above the WGS84 ellipsoid in km
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let observer = sgp4::Observer::from_maidenhead("JO02ae", 0.02)?;
+    /// assert!((observer.latitude.to_degrees() - 52.1875).abs() < 1e-9);
+    /// assert!((observer.longitude.to_degrees() - 0.0417).abs() < 1e-4);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_maidenhead(
+        locator: &str,
+        altitude: f64,
+    ) -> core::result::Result<Self, MaidenheadError> {
+        let length = locator.chars().count();
+        if length == 0 || length > 10 || length % 2 == 1 {
+            return Err(MaidenheadError::Length(length));
+        }
+        let mut longitude = -180.0;
+        let mut latitude = -90.0;
+        let mut longitude_size = 360.0;
+        let mut latitude_size = 180.0;
+        for (index, character) in locator.chars().enumerate() {
+            // pairs alternate between letters (18 fields, then 24 subdivisions) and digits (10 subdivisions)
+            let (first, divisions) = match index / 2 {
+                0 => ('A', 18),
+                2 | 4 => ('A', 24),
+                _ => ('0', 10),
+            };
+            let value = (character.to_ascii_uppercase() as u32)
+                .checked_sub(first as u32)
+                .filter(|value| *value < divisions)
+                .ok_or(MaidenheadError::Character { index, character })?;
+            if index % 2 == 0 {
+                longitude_size /= divisions as f64;
+                longitude += value as f64 * longitude_size;
+            } else {
+                latitude_size /= divisions as f64;
+                latitude += value as f64 * latitude_size;
+            }
+        }
+        Ok(Observer {
+            latitude: (latitude + latitude_size / 2.0).to_radians(),
+            longitude: (longitude + longitude_size / 2.0).to_radians(),
+            altitude,
+        })
+    }
+
+    /// Returns the observer's position in the True Equator, Mean Equinox (TEME) reference frame in km
+    ///
+    /// Polar motion is neglected.
+    ///
+    /// # Arguments
+    ///
+    /// * `sidereal_time` - Greenwich sidereal time in rad, for example calculated with [sgp4::iau_epoch_to_sidereal_time](fn.iau_epoch_to_sidereal_time.html)
+    pub fn position(&self, sidereal_time: f64) -> [f64; 3] {
+        // e² = f (2 - f)
+        let eccentricity_squared = ELLIPSOID_FLATTENING * (2.0 - ELLIPSOID_FLATTENING);
+
+        // N = a / (1 - e² sin²φ)¹ᐟ²
+        let n = ELLIPSOID_EQUATORIAL_RADIUS
+            / (1.0 - eccentricity_squared * self.latitude.sin().powi(2)).sqrt();
+
+        // θₗ = θ + λ
+        let local_sidereal_time = sidereal_time + self.longitude;
+        [
+            // x = (N + h) cos φ cos θₗ
+            (n + self.altitude) * self.latitude.cos() * local_sidereal_time.cos(),
+            // y = (N + h) cos φ sin θₗ
+            (n + self.altitude) * self.latitude.cos() * local_sidereal_time.sin(),
+            // z = (N (1 - e²) + h) sin φ
+            (n * (1.0 - eccentricity_squared) + self.altitude) * self.latitude.sin(),
+        ]
+    }
+
+    /// Returns the observer's velocity in the True Equator, Mean Equinox (TEME) reference frame in km.s⁻¹
+    ///
+    /// The velocity is caused by the Earth's rotation.
+    ///
+    /// # Arguments
+    ///
+    /// * `sidereal_time` - Greenwich sidereal time in rad, for example calculated with [sgp4::iau_epoch_to_sidereal_time](fn.iau_epoch_to_sidereal_time.html)
+    pub fn velocity(&self, sidereal_time: f64) -> [f64; 3] {
+        let position = self.position(sidereal_time);
+
+        // vₒ = ωₑ × rₒ
+        [
+            -EARTH_ROTATION_RATE * position[1],
+            EARTH_ROTATION_RATE * position[0],
+            0.0,
+        ]
+    }
+
+    /// Returns the rate of change of the distance between the observer and a predicted satellite in km.s⁻¹
+    ///
+    /// The range rate is positive when the satellite moves away from the observer.
+    ///
+    /// # Arguments
+    ///
+    /// * `sidereal_time` - Greenwich sidereal time in rad at the prediction time
+    /// * `prediction` - The satellite position and velocity in the TEME reference frame
+    pub fn range_rate(&self, sidereal_time: f64, prediction: &propagator::Prediction) -> f64 {
+        // ρ = r - rₒ
+        let rho = vector::sub(&prediction.position, &self.position(sidereal_time));
+
+        // ρ̇ = ρ · (v - vₒ) / |ρ|
+        vector::dot(
+            &rho,
+            &vector::sub(&prediction.velocity, &self.velocity(sidereal_time)),
+        ) / vector::norm(&rho)
+    }
+
+    /// Returns the azimuth, elevation, range and range rate of a predicted satellite position
+    ///
+    /// # Arguments
+    ///
+    /// * `sidereal_time` - Greenwich sidereal time in rad at the prediction time
+    /// * `prediction` - The satellite position and velocity in the TEME reference frame
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::from_elements(&elements)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: -33.8688_f64.to_radians(),
+    ///     longitude: 151.2093_f64.to_radians(),
+    ///     altitude: 0.05,
+    /// };
+    /// let t = sgp4::MinutesSinceEpoch(60.0);
+    /// let prediction = constants.propagate(t)?;
+    /// let look_angles = observer.look_angles(
+    ///     sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+    ///     &prediction,
+    /// );
+    /// println!(
+    ///     "elevation = {} rad, range = {} km, range rate = {} km/s",
+    ///     look_angles.elevation, look_angles.range, look_angles.range_rate
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn look_angles(
+        &self,
+        sidereal_time: f64,
+        prediction: &propagator::Prediction,
+    ) -> LookAngles {
+        let observer_position = self.position(sidereal_time);
+
+        // ρ = r - rₒ
+        let rho = vector::sub(&prediction.position, &observer_position);
+
+        // |ρ|
+        let range = vector::norm(&rho);
+
+        // θₗ = θ + λ
+        let local_sidereal_time = sidereal_time + self.longitude;
+
+        // ρₑ = - sin θₗ ρ₀ + cos θₗ ρ₁
+        let east = -local_sidereal_time.sin() * rho[0] + local_sidereal_time.cos() * rho[1];
+
+        // ρₙ = - sin φ cos θₗ ρ₀ - sin φ sin θₗ ρ₁ + cos φ ρ₂
+        let north = -self.latitude.sin() * local_sidereal_time.cos() * rho[0]
+            - self.latitude.sin() * local_sidereal_time.sin() * rho[1]
+            + self.latitude.cos() * rho[2];
+
+        // ρᵤ = cos φ cos θₗ ρ₀ + cos φ sin θₗ ρ₁ + sin φ ρ₂
+        let up = self.latitude.cos() * local_sidereal_time.cos() * rho[0]
+            + self.latitude.cos() * local_sidereal_time.sin() * rho[1]
+            + self.latitude.sin() * rho[2];
+        LookAngles {
+            // A = tan⁻¹(ρₑ / ρₙ) mod 2π
+            azimuth: model::positive_angle(east.atan2(north)),
+
+            // E = tan⁻¹(ρᵤ / (ρₑ² + ρₙ²)¹ᐟ²)
+            elevation: up.atan2((east.powi(2) + north.powi(2)).sqrt()),
+
+            range,
+
+            // ρ̇ = ρ · (v - vₒ) / |ρ|
+            range_rate: vector::dot(
+                &rho,
+                &vector::sub(&prediction.velocity, &self.velocity(sidereal_time)),
+            ) / range,
+        }
+    }
+}
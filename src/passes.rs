@@ -0,0 +1,335 @@
+use crate::events;
+use crate::gp;
+use crate::observer;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A satellite pass over a ground observer
+///
+/// A pass is a time interval during which the satellite's elevation is above a minimum elevation.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pass {
+    /// Time at which the elevation rises above the minimum elevation in minutes since epoch
+    ///
+    /// `None` if the satellite is already above the minimum elevation at the beginning of the window.
+    pub rise: Option<MinutesSinceEpoch>,
+
+    /// Time of the maximum elevation within the window in minutes since epoch
+    pub culmination: MinutesSinceEpoch,
+
+    /// Time at which the elevation falls below the minimum elevation in minutes since epoch
+    ///
+    /// `None` if the satellite is still above the minimum elevation at the end of the window.
+    pub set: Option<MinutesSinceEpoch>,
+
+    /// Maximum elevation within the window in rad
+    pub maximum_elevation: f64,
+
+    /// Azimuth at acquisition of signal (rise, or the beginning of the window) in rad
+    pub rise_azimuth: f64,
+
+    /// Azimuth at the maximum elevation in rad
+    pub culmination_azimuth: f64,
+
+    /// Azimuth at loss of signal (set, or the end of the window) in rad
+    pub set_azimuth: f64,
+}
+
+/// One of the eight principal compass directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompassPoint {
+    /// Azimuth 0°
+    North,
+
+    /// Azimuth 45°
+    NorthEast,
+
+    /// Azimuth 90°
+    East,
+
+    /// Azimuth 135°
+    SouthEast,
+
+    /// Azimuth 180°
+    South,
+
+    /// Azimuth 225°
+    SouthWest,
+
+    /// Azimuth 270°
+    West,
+
+    /// Azimuth 315°
+    NorthWest,
+}
+
+impl CompassPoint {
+    /// Returns the compass point nearest to an azimuth
+    ///
+    /// # Arguments
+    ///
+    /// * `azimuth` - The azimuth in rad, clockwise from the north (any multiple of 2π may be added)
+    pub fn from_azimuth(azimuth: f64) -> Self {
+        // k = ⌊A / (π / 4) + 1 / 2⌋ mod 8
+        match ((azimuth / core::f64::consts::FRAC_PI_4 + 0.5).floor() as i64).rem_euclid(8) {
+            0 => CompassPoint::North,
+            1 => CompassPoint::NorthEast,
+            2 => CompassPoint::East,
+            3 => CompassPoint::SouthEast,
+            4 => CompassPoint::South,
+            5 => CompassPoint::SouthWest,
+            6 => CompassPoint::West,
+            _ => CompassPoint::NorthWest,
+        }
+    }
+}
+
+impl core::fmt::Display for CompassPoint {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(match self {
+            CompassPoint::North => "N",
+            CompassPoint::NorthEast => "NE",
+            CompassPoint::East => "E",
+            CompassPoint::SouthEast => "SE",
+            CompassPoint::South => "S",
+            CompassPoint::SouthWest => "SW",
+            CompassPoint::West => "W",
+            CompassPoint::NorthWest => "NW",
+        })
+    }
+}
+
+impl Pass {
+    /// Returns the compass points at acquisition and loss of signal
+    ///
+    /// For example, `(CompassPoint::SouthWest, CompassPoint::NorthEast)` describes a SW → NE pass.
+    pub fn direction(&self) -> (CompassPoint, CompassPoint) {
+        (
+            CompassPoint::from_azimuth(self.rise_azimuth),
+            CompassPoint::from_azimuth(self.set_azimuth),
+        )
+    }
+}
+
+/// A time-tagged antenna pointing command
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackPoint {
+    /// Time of the command in minutes since epoch
+    pub t: MinutesSinceEpoch,
+
+    /// Unwrapped azimuth in rad (clockwise from the north)
+    ///
+    /// Successive azimuths differ by less than π, hence the value may be outside [0, 2π[.
+    pub azimuth: f64,
+
+    /// Elevation in rad
+    pub elevation: f64,
+}
+
+impl propagator::Constants {
+    /// Finds the passes of the satellite over a ground observer in a time window
+    ///
+    /// The elevation is sampled with a fixed step, and each rise and set is refined by bisection.
+    /// The step must be shorter than the shortest pass of interest, otherwise short passes are missed.
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - The ground observer
+    /// * `minimum_elevation` - The elevation above which the satellite is considered visible in rad
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `step` - The sampling step in minutes (strictly positive)
+    /// * `epoch_to_sidereal_time` - The function to use to convert the J2000 epoch to sidereal time,
+    ///   usually the one used to initialize the propagator
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: 48.8566_f64.to_radians(),
+    ///     longitude: 2.3522_f64.to_radians(),
+    ///     altitude: 0.035,
+    /// };
+    /// for pass in constants.passes(
+    ///     &observer,
+    ///     10.0_f64.to_radians(),
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    ///     0.5,
+    ///     sgp4::iau_epoch_to_sidereal_time,
+    /// )? {
+    ///     let (from, to) = pass.direction();
+    ///     println!(
+    ///         "{:?} -> {:?}, {} → {}, maximum elevation {}°",
+    ///         pass.rise,
+    ///         pass.set,
+    ///         from,
+    ///         to,
+    ///         pass.maximum_elevation.to_degrees()
+    ///     );
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn passes(
+        &self,
+        observer: &observer::Observer,
+        minimum_elevation: f64,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        step: f64,
+        epoch_to_sidereal_time: impl Fn(f64) -> f64,
+    ) -> core::result::Result<alloc::vec::Vec<Pass>, gp::Error> {
+        let look_angles = |t: MinutesSinceEpoch| {
+            self.propagate(t).map(|prediction| {
+                observer.look_angles(
+                    epoch_to_sidereal_time(self.julian_years_since_j2000(t)),
+                    &prediction,
+                )
+            })
+        };
+        let elevation =
+            |t: MinutesSinceEpoch| look_angles(t).map(|look_angles| look_angles.elevation);
+        events::positive_intervals(start, end, step, 1e-6, |t| {
+            elevation(t).map(|elevation| elevation - minimum_elevation)
+        })?
+        .into_iter()
+        .map(|(rise, set)| {
+            let (culmination, maximum_elevation) = events::maximize(
+                rise.map_or(start.0, |rise| rise.0),
+                set.map_or(end.0, |set| set.0),
+                1e-6,
+                elevation,
+            )?;
+            Ok(Pass {
+                rise,
+                culmination,
+                set,
+                maximum_elevation,
+                rise_azimuth: look_angles(rise.unwrap_or(start))?.azimuth,
+                culmination_azimuth: look_angles(culmination)?.azimuth,
+                set_azimuth: look_angles(set.unwrap_or(end))?.azimuth,
+            })
+        })
+        .collect()
+    }
+
+    /// Samples the satellite's azimuth and elevation at a fixed cadence for an antenna rotator
+    ///
+    /// The azimuth is unwrapped to avoid the jump between 2π and 0 when the satellite crosses the north,
+    /// and shifted by a multiple of 2π so that its smallest value is in the range [0, 2π[.
+    /// Rotators with an overlap range (for example GS-232 controllers with a 450° range)
+    /// can follow the resulting track without reversing mid-pass if the largest azimuth is within their range.
+    ///
+    /// The last point is at `end`, even if `end - start` is not a multiple of the cadence.
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - The ground observer
+    /// * `start` - The time of the first point in minutes since epoch, for example a pass rise
+    /// * `end` - The time of the last point in minutes since epoch, for example a pass set
+    /// * `cadence` - The time between two points in minutes (strictly positive)
+    /// * `epoch_to_sidereal_time` - The function to use to convert the J2000 epoch to sidereal time,
+    ///   usually the one used to initialize the propagator
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: 48.8566_f64.to_radians(),
+    ///     longitude: 2.3522_f64.to_radians(),
+    ///     altitude: 0.035,
+    /// };
+    /// let start = sgp4::MinutesSinceEpoch(0.0);
+    /// let end = sgp4::MinutesSinceEpoch(24.0 * 60.0);
+    /// for pass in constants.passes(&observer, 0.0, start, end, 0.5, sgp4::iau_epoch_to_sidereal_time)? {
+    ///     // one command per second
+    ///     for point in constants.track(
+    ///         &observer,
+    ///         pass.rise.unwrap_or(start),
+    ///         pass.set.unwrap_or(end),
+    ///         1.0 / 60.0,
+    ///         sgp4::iau_epoch_to_sidereal_time,
+    ///     )? {
+    ///         println!(
+    ///             "W{:03.0} {:03.0}",
+    ///             point.azimuth.to_degrees(),
+    ///             point.elevation.to_degrees().max(0.0)
+    ///         );
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn track(
+        &self,
+        observer: &observer::Observer,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        cadence: f64,
+        epoch_to_sidereal_time: impl Fn(f64) -> f64,
+    ) -> core::result::Result<alloc::vec::Vec<TrackPoint>, gp::Error> {
+        assert!(cadence > 0.0, "cadence must be strictly positive");
+        let mut track = alloc::vec::Vec::new();
+        if end.0 < start.0 {
+            return Ok(track);
+        }
+        let mut index = 0;
+        loop {
+            let t = MinutesSinceEpoch((start.0 + index as f64 * cadence).min(end.0));
+            let look_angles = observer.look_angles(
+                epoch_to_sidereal_time(self.julian_years_since_j2000(t)),
+                &self.propagate(t)?,
+            );
+            let azimuth = match track.last() {
+                Some(TrackPoint {
+                    azimuth: previous, ..
+                }) => {
+                    // A' = A + 2π k, with k such that |A' - Aₚ| ≤ π
+                    look_angles.azimuth
+                        + 2.0
+                            * core::f64::consts::PI
+                            * ((previous - look_angles.azimuth) / (2.0 * core::f64::consts::PI))
+                                .round()
+                }
+                None => look_angles.azimuth,
+            };
+            track.push(TrackPoint {
+                t,
+                azimuth,
+                elevation: look_angles.elevation,
+            });
+            if t.0 >= end.0 {
+                break;
+            }
+            index += 1;
+        }
+        let minimum = track
+            .iter()
+            .map(|point| point.azimuth)
+            .fold(f64::INFINITY, f64::min);
+        let shift = 2.0 * core::f64::consts::PI * (minimum / (2.0 * core::f64::consts::PI)).floor();
+        for point in track.iter_mut() {
+            point.azimuth -= shift;
+        }
+        Ok(track)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Returns the solar phase angle in rad
+///
+/// The phase angle is the angle at the satellite between the direction of the Sun
+/// and the direction of the observer. It is 0 when the satellite is fully lit as seen
+/// by the observer, and π when the observer sees the satellite's night side.
+///
+/// All positions must be expressed in the same reference frame (for instance TEME).
+///
+/// # Arguments
+///
+/// * `observer_position` - The observer's position in km
+/// * `satellite_position` - The satellite's position in km
+/// * `sun_position` - The Sun's position in km
+pub fn phase_angle(
+    observer_position: &[f64; 3],
+    satellite_position: &[f64; 3],
+    sun_position: &[f64; 3],
+) -> f64 {
+    vector::angle(
+        &vector::sub(sun_position, satellite_position),
+        &vector::sub(observer_position, satellite_position),
+    )
+}
+
+/// Returns an estimate of the satellite's apparent visual magnitude
+///
+/// The satellite is modelled as a diffusely reflecting (Lambertian) sphere.
+/// The standard magnitude follows the convention used by visual observers:
+/// it is the magnitude at a range of 1000 km and a phase angle of 90° (half illuminated).
+///
+/// The estimate does not account for atmospheric extinction or Earth shadow.
+///
+/// # Arguments
+///
+/// * `standard_magnitude` - The satellite's magnitude at 1000 km and 90° phase angle
+/// * `range` - The distance between the observer and the satellite in km
+/// * `phase_angle` - The solar phase angle in rad, see [sgp4::phase_angle](fn.phase_angle.html)
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let constants = sgp4::Constants::from_elements(&elements)?;
+/// let observer = sgp4::Observer {
+///     latitude: 48.8566_f64.to_radians(),
+///     longitude: 2.3522_f64.to_radians(),
+///     altitude: 0.035,
+/// };
+/// let t = sgp4::MinutesSinceEpoch(90.0);
+/// let prediction = constants.propagate(t)?;
+/// let epoch = constants.julian_years_since_j2000(t);
+/// let sidereal_time = sgp4::iau_epoch_to_sidereal_time(epoch);
+/// let phase_angle = sgp4::phase_angle(
+///     &observer.position(sidereal_time),
+///     &prediction.position,
+///     &sgp4::sun_position(epoch),
+/// );
+/// let range = observer.look_angles(sidereal_time, &prediction).range;
+/// let magnitude = sgp4::visual_magnitude(-1.8, range, phase_angle);
+/// println!("m = {}", magnitude);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn visual_magnitude(standard_magnitude: f64, range: f64, phase_angle: f64) -> f64 {
+    // F(φ) = sin φ + (π - φ) cos φ
+    let phase_function =
+        phase_angle.sin() + (core::f64::consts::PI - phase_angle) * phase_angle.cos();
+
+    // m = m₀ + 5 log₁₀(ρ / 1000) - 2.5 log₁₀ F(φ)
+    standard_magnitude + 5.0 * (range / 1000.0).log10() - 2.5 * phase_function.log10()
+}
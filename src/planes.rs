@@ -0,0 +1,166 @@
+use crate::gp;
+use crate::model;
+use crate::propagator;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A group of satellites that share an orbital plane
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrbitalPlane {
+    /// Indices of the plane's satellites in the catalog, sorted by argument of latitude
+    pub members: alloc::vec::Vec<usize>,
+
+    /// Angle between the equator and the mean orbit plane in rad
+    pub inclination: f64,
+
+    /// Angle between the vernal equinox and the ascending node of the mean orbit plane in rad, in the range [0, 2π[
+    pub right_ascension: f64,
+
+    /// Angle between the ascending node and each satellite, measured in the mean orbit plane in rad,
+    /// in the range [0, 2π[ (same order as `members`)
+    pub arguments_of_latitude: alloc::vec::Vec<f64>,
+
+    /// Smallest angular spacing between consecutive satellites in rad
+    pub minimum_spacing: f64,
+
+    /// Largest angular spacing between consecutive satellites in rad
+    pub maximum_spacing: f64,
+
+    /// Mean angular spacing between consecutive satellites in rad (2π divided by the number of members)
+    pub mean_spacing: f64,
+}
+
+// satellites assigned to a plane during the clustering
+struct Group {
+    // sum of the members' orbit normals
+    normals: [f64; 3],
+
+    // catalog indices and positions
+    members: alloc::vec::Vec<(usize, [f64; 3])>,
+}
+
+/// Groups the satellites of a catalog by orbital plane and calculates the in-plane phasing
+///
+/// All the satellites are propagated to the same time. Each satellite joins the first plane
+/// whose normal is within the tolerance of its own orbit normal, or creates a new plane.
+/// Since orbit normals are compared, the clustering accounts for both the right ascension
+/// of the ascending node and the inclination.
+///
+/// # Arguments
+///
+/// * `catalog` - The satellites' propagator constants
+/// * `epoch` - The time at which the satellites are compared in years since UTC 1 January 2000 12h00 (J2000)
+/// * `tolerance` - The maximum angle between the orbit normals of a plane's members in rad
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let tle = (
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// );
+/// let epoch = sgp4::Elements::from_tle(None, tle.0, tle.1)?.epoch();
+/// // a virtual constellation with 3 planes of 4 satellites
+/// let mut catalog = Vec::new();
+/// for plane in 0..3 {
+///     for slot in 0..4 {
+///         let mut elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+///         elements.right_ascension += 120.0 * plane as f64;
+///         elements.mean_anomaly += 90.0 * slot as f64;
+///         catalog.push(sgp4::Constants::from_elements(&elements)?);
+///     }
+/// }
+/// for plane in sgp4::orbital_planes(&catalog, epoch, 1.0_f64.to_radians())? {
+///     println!(
+///         "Ω = {}°: {:?}, spacing in [{}°, {}°]",
+///         plane.right_ascension.to_degrees(),
+///         plane.members,
+///         plane.minimum_spacing.to_degrees(),
+///         plane.maximum_spacing.to_degrees(),
+///     );
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+pub fn orbital_planes(
+    catalog: &[propagator::Constants],
+    epoch: f64,
+    tolerance: f64,
+) -> core::result::Result<alloc::vec::Vec<OrbitalPlane>, gp::Error> {
+    let mut groups: alloc::vec::Vec<Group> = alloc::vec::Vec::new();
+    let cos_tolerance = tolerance.cos();
+    for (index, constants) in catalog.iter().enumerate() {
+        let prediction = constants.propagate(constants.minutes_since_epoch(epoch))?;
+        let angular_momentum = vector::cross(&prediction.position, &prediction.velocity);
+        let norm = vector::norm(&angular_momentum);
+        let normal = [
+            angular_momentum[0] / norm,
+            angular_momentum[1] / norm,
+            angular_momentum[2] / norm,
+        ];
+        match groups.iter_mut().find(|group| {
+            vector::dot(&group.normals, &normal) >= cos_tolerance * vector::norm(&group.normals)
+        }) {
+            Some(group) => {
+                group.normals[0] += normal[0];
+                group.normals[1] += normal[1];
+                group.normals[2] += normal[2];
+                group.members.push((index, prediction.position));
+            }
+            None => groups.push(Group {
+                normals: normal,
+                members: alloc::vec![(index, prediction.position)],
+            }),
+        }
+    }
+    Ok(groups
+        .into_iter()
+        .map(|Group { normals, members }| {
+            let norm = vector::norm(&normals);
+            let normal = [normals[0] / norm, normals[1] / norm, normals[2] / norm];
+
+            // Ω = tan⁻¹(ĥₓ / -ĥᵧ)
+            let right_ascension = normal[0].atan2(-normal[1]);
+            let node = [right_ascension.cos(), right_ascension.sin(), 0.0];
+            let in_plane = vector::cross(&normal, &node);
+
+            // u = tan⁻¹((r · q̂) / (r · p̂))
+            let mut phases: alloc::vec::Vec<(usize, f64)> = members
+                .iter()
+                .map(|(index, position)| {
+                    (
+                        *index,
+                        model::positive_angle(
+                            vector::dot(position, &in_plane).atan2(vector::dot(position, &node)),
+                        ),
+                    )
+                })
+                .collect();
+            phases.sort_by(|a, b| a.1.total_cmp(&b.1));
+            let mut minimum_spacing = core::f64::consts::TAU;
+            let mut maximum_spacing: f64 = 0.0;
+            for (index, (_, argument_of_latitude)) in phases.iter().enumerate() {
+                let spacing = if index + 1 < phases.len() {
+                    phases[index + 1].1 - argument_of_latitude
+                } else {
+                    phases[0].1 + core::f64::consts::TAU - argument_of_latitude
+                };
+                minimum_spacing = minimum_spacing.min(spacing);
+                maximum_spacing = maximum_spacing.max(spacing);
+            }
+            OrbitalPlane {
+                members: phases.iter().map(|(index, _)| *index).collect(),
+                inclination: normal[2].clamp(-1.0, 1.0).acos(),
+                right_ascension: model::positive_angle(right_ascension),
+                arguments_of_latitude: phases.iter().map(|(_, phase)| *phase).collect(),
+                minimum_spacing,
+                maximum_spacing,
+                mean_spacing: core::f64::consts::TAU / phases.len() as f64,
+            }
+        })
+        .collect())
+}
@@ -1,123 +1,179 @@
+use crate::gp;
 use crate::model;
+use crate::real::Real;
 use crate::third_body;
+use crate::vector;
 
 /// Predicted satellite position and velocity after SGP4 propagation
 ///
 /// The position and velocity are given in the True Equator, Mean Equinox (TEME) of epoch reference frame.
+/// The components are `f64` unless the propagator is generic over another [Real](trait.Real.html) type.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Prediction {
+pub struct Prediction<T: Real = f64> {
     /// The three position components (x, y, z) in km
-    pub position: [f64; 3],
+    pub position: [T; 3],
 
     /// The three velocity components (x, y, z) in km.s⁻¹
-    pub velocity: [f64; 3],
+    pub velocity: [T; 3],
+}
+
+impl Prediction {
+    /// Returns the norm of the velocity in km.s⁻¹
+    pub fn speed(&self) -> f64 {
+        vector::norm(&self.velocity)
+    }
+
+    /// Returns the distance between the satellite and the Earth's center in km
+    pub fn geocentric_distance(&self) -> f64 {
+        vector::norm(&self.position)
+    }
+
+    /// Returns the height above a spherical Earth in km
+    ///
+    /// The sphere's radius is the equatorial radius of the model. Since the Earth is flattened,
+    /// this underestimates the height above the ellipsoid by up to about 21 km near the poles.
+    /// [sgp4::Geodetic::from_teme](struct.Geodetic.html#method.from_teme) calculates the height above the WGS84 ellipsoid.
+    ///
+    /// # Arguments
+    ///
+    /// * `geopotential` - The model of Earth gravity that provides the equatorial radius
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let prediction = constants.propagate(sgp4::MinutesSinceEpoch(60.0))?;
+    /// println!(
+    ///     "speed = {} km.s⁻¹, distance = {} km, altitude = {} km",
+    ///     prediction.speed(),
+    ///     prediction.geocentric_distance(),
+    ///     prediction.altitude(&sgp4::WGS84),
+    /// );
+    /// assert!(prediction.altitude(&sgp4::WGS84) > 350.0 && prediction.altitude(&sgp4::WGS84) < 450.0);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn altitude(&self, geopotential: &model::Geopotential) -> f64 {
+        self.geocentric_distance() - geopotential.ae
+    }
 }
 
 /// The Brouwer orbital elements
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Orbit {
+pub struct Orbit<T: Real = f64> {
     /// Angle between the equator and the orbit plane in rad
-    pub inclination: f64,
+    pub inclination: T,
 
     /// Angle between vernal equinox and the point where the orbit crosses the equatorial plane in rad
-    pub right_ascension: f64,
+    pub right_ascension: T,
 
     /// Shape of the orbit
-    pub eccentricity: f64,
+    pub eccentricity: T,
 
     /// Angle between the ascending node and the orbit's point of closest approach to the earth in rad
-    pub argument_of_perigee: f64,
+    pub argument_of_perigee: T,
 
     /// Angle of the satellite location measured from perigee in rad
-    pub mean_anomaly: f64,
+    pub mean_anomaly: T,
 
     /// Mean number of orbits per day in rad.min⁻¹
-    pub mean_motion: f64,
+    pub mean_motion: T,
 }
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub(crate) enum Elliptic {
+pub(crate) enum Elliptic<T: Real> {
     No {},
-    Yes { k11: f64, k12: f64, k13: f64 },
+    Yes { k11: T, k12: T, k13: T },
 }
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub(crate) enum HighAltitude {
+pub(crate) enum HighAltitude<T: Real> {
     No {},
     Yes {
-        c5: f64,
-        d2: f64,
-        d3: f64,
-        d4: f64,
-        eta: f64,
-        k7: f64,
-        k8: f64,
-        k9: f64,
-        k10: f64,
-        elliptic: Elliptic,
+        c5: T,
+        d2: T,
+        d3: T,
+        d4: T,
+        eta: T,
+        k7: T,
+        k8: T,
+        k9: T,
+        k10: T,
+        elliptic: Elliptic<T>,
     },
 }
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub(crate) enum Resonance {
+pub(crate) enum Resonance<T: Real> {
     OneDay {
-        dr1: f64,
-        dr2: f64,
-        dr3: f64,
+        dr1: T,
+        dr2: T,
+        dr3: T,
     },
     HalfDay {
-        d2201: f64,
-        d2211: f64,
-        d3210: f64,
-        d3222: f64,
-        d4410: f64,
-        d4422: f64,
-        d5220: f64,
-        d5232: f64,
-        d5421: f64,
-        d5433: f64,
-        k14: f64,
+        d2201: T,
+        d2211: T,
+        d3210: T,
+        d3222: T,
+        d4410: T,
+        d4422: T,
+        d5220: T,
+        d5232: T,
+        d5421: T,
+        d5433: T,
+        k14: T,
     },
 }
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub(crate) enum Resonant {
+pub(crate) enum Resonant<T: Real> {
     No {
-        a0: f64,
+        a0: T,
     },
     Yes {
-        lambda_0: f64,
-        lambda_dot_0: f64,
+        lambda_0: T,
+        lambda_dot_0: T,
         sidereal_time_0: f64,
-        resonance: Resonance,
+        resonance: Resonance<T>,
     },
 }
 
+// the deep space variant is not boxed since initialization must not allocate
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub(crate) enum Method {
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Method<T: Real> {
     NearEarth {
-        a0: f64,
-        k2: f64,
-        k3: f64,
-        k4: f64,
-        k5: f64,
-        k6: f64,
-        high_altitude: HighAltitude,
-    },
-    DeepSpace {
-        eccentricity_dot: f64,
-        inclination_dot: f64,
-        solar_perturbations: third_body::Perturbations,
-        lunar_perturbations: third_body::Perturbations,
-        resonant: Resonant,
+        a0: T,
+        k2: T,
+        k3: T,
+        k4: T,
+        k5: T,
+        k6: T,
+        high_altitude: HighAltitude<T>,
     },
+    DeepSpace(DeepSpace<T>),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct DeepSpace<T: Real> {
+    pub(crate) eccentricity_dot: T,
+    pub(crate) inclination_dot: T,
+    pub(crate) solar_perturbations: third_body::Perturbations<T>,
+    pub(crate) lunar_perturbations: third_body::Perturbations<T>,
+    pub(crate) resonant: Resonant<T>,
 }
 
 /// Propagator variables calculated from epoch quantities and used during propagation
@@ -126,17 +182,25 @@ pub(crate) enum Method {
 /// They are not mutated during propagation, which means they can
 /// be used by different threads in parallel
 /// (for example to generate predictions at different times).
+/// The coefficients are `f64` unless the propagator is generic over another [Real](trait.Real.html) type.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Constants {
+pub struct Constants<T: Real = f64> {
+    pub(crate) orbit_0: Orbit<T>,
+    pub(crate) right_ascension_dot: T,
+    pub(crate) argument_of_perigee_dot: T,
+    pub(crate) mean_anomaly_dot: T,
+    pub(crate) c1: T,
+    pub(crate) c4: T,
+    pub(crate) k0: T,
+    pub(crate) k1: T,
     pub(crate) geopotential: model::Geopotential,
-    pub(crate) right_ascension_dot: f64,
-    pub(crate) argument_of_perigee_dot: f64,
-    pub(crate) mean_anomaly_dot: f64,
-    pub(crate) c1: f64,
-    pub(crate) c4: f64,
-    pub(crate) k0: f64,
-    pub(crate) k1: f64,
-    pub(crate) method: Method,
-    pub(crate) orbit_0: Orbit,
+    pub(crate) method: Method<T>,
+    pub(crate) epoch: f64,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) eccentricity_limits: gp::EccentricityLimits,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) horizon: Option<gp::Horizon>,
 }
@@ -0,0 +1,192 @@
+use crate::propagator;
+use crate::tle;
+use numpy::IntoPyArray;
+use pyo3::prelude::*;
+
+// converts a crate error into a Python ValueError
+fn value_error<E: core::fmt::Display>(error: E) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(error.to_string())
+}
+
+/// General perturbations orbital data exported to Python
+///
+/// ```python
+/// import sgp4
+///
+/// elements = sgp4.Elements.from_tle(
+///     "ISS (ZARYA)",
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992",
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008",
+/// )
+/// constants = sgp4.Constants.from_elements(elements)
+/// states = constants.propagate_array(numpy.arange(0.0, 1440.0))  # shape (1440, 6)
+/// ```
+#[pyclass(name = "Elements", module = "sgp4", frozen)]
+pub struct PyElements {
+    elements: tle::Elements,
+}
+
+#[pymethods]
+impl PyElements {
+    /// Parses a Two-Line Element Set (TLE) with an optional title
+    #[staticmethod]
+    #[pyo3(signature = (object_name, line1, line2))]
+    pub fn from_tle(object_name: Option<String>, line1: &str, line2: &str) -> PyResult<Self> {
+        Ok(PyElements {
+            elements: tle::Elements::from_tle(
+                object_name,
+                line1.trim().as_bytes(),
+                line2.trim().as_bytes(),
+            )
+            .map_err(value_error)?,
+        })
+    }
+
+    /// Parses a JSON-encoded Orbit Mean-Elements Message (OMM) object
+    #[staticmethod]
+    pub fn from_json(omm: &str) -> PyResult<Self> {
+        Ok(PyElements {
+            elements: serde_json::from_str(omm).map_err(value_error)?,
+        })
+    }
+
+    /// The name of the satellite
+    #[getter]
+    pub fn object_name(&self) -> Option<String> {
+        self.elements.object_name.clone()
+    }
+
+    /// The international designator
+    #[getter]
+    pub fn international_designator(&self) -> Option<String> {
+        self.elements.international_designator.clone()
+    }
+
+    /// The satellite catalog number
+    #[getter]
+    pub fn norad_id(&self) -> u64 {
+        self.elements.norad_id.get()
+    }
+
+    /// The epoch in years since UTC 1 January 2000 12h00 (J2000)
+    #[getter]
+    pub fn epoch(&self) -> f64 {
+        self.elements.epoch()
+    }
+
+    /// The epoch (UTC) formatted as an ISO 8601 string
+    #[getter]
+    pub fn datetime(&self) -> String {
+        self.elements
+            .datetime
+            .format("%Y-%m-%dT%H:%M:%S%.6f")
+            .to_string()
+    }
+
+    /// Angle between the equator and the orbit plane in deg
+    #[getter]
+    pub fn inclination(&self) -> f64 {
+        self.elements.inclination
+    }
+
+    /// Angle between vernal equinox and the point where the orbit crosses the equatorial plane in deg
+    #[getter]
+    pub fn right_ascension(&self) -> f64 {
+        self.elements.right_ascension
+    }
+
+    /// The shape of the orbit
+    #[getter]
+    pub fn eccentricity(&self) -> f64 {
+        self.elements.eccentricity
+    }
+
+    /// Angle between the ascending node and the orbit's point of closest approach to the earth in deg
+    #[getter]
+    pub fn argument_of_perigee(&self) -> f64 {
+        self.elements.argument_of_perigee
+    }
+
+    /// Angle of the satellite location measured from perigee in deg
+    #[getter]
+    pub fn mean_anomaly(&self) -> f64 {
+        self.elements.mean_anomaly
+    }
+
+    /// Mean number of orbits per day (Kozai convention)
+    #[getter]
+    pub fn mean_motion(&self) -> f64 {
+        self.elements.mean_motion
+    }
+
+    /// Radiation pressure coefficient in earth radii⁻¹ (B*)
+    #[getter]
+    pub fn drag_term(&self) -> f64 {
+        self.elements.drag_term
+    }
+
+    /// Returns the number of minutes between the epoch and an ISO 8601 UTC datetime (without time zone)
+    pub fn minutes_since_epoch(&self, datetime: &str) -> PyResult<f64> {
+        let datetime = datetime
+            .parse::<chrono::NaiveDateTime>()
+            .map_err(value_error)?;
+        Ok(self
+            .elements
+            .datetime_to_minutes_since_epoch(&datetime)
+            .map_err(value_error)?
+            .0)
+    }
+}
+
+/// SGP4 propagator exported to Python
+#[pyclass(name = "Constants", module = "sgp4", frozen)]
+pub struct PyConstants {
+    constants: propagator::Constants,
+}
+
+#[pymethods]
+impl PyConstants {
+    /// Initializes a propagator from elements (WGS84, IAU sidereal time)
+    #[staticmethod]
+    pub fn from_elements(elements: &PyElements) -> PyResult<Self> {
+        Ok(PyConstants {
+            constants: propagator::Constants::from_elements(&elements.elements)
+                .map_err(value_error)?,
+        })
+    }
+
+    /// Returns the position (km) and velocity (km.s⁻¹) in the TEME frame at the given minutes since epoch
+    pub fn propagate(&self, minutes: f64) -> PyResult<([f64; 3], [f64; 3])> {
+        let prediction = self
+            .constants
+            .propagate(tle::MinutesSinceEpoch(minutes))
+            .map_err(value_error)?;
+        Ok((prediction.position, prediction.velocity))
+    }
+
+    /// Returns a NumPy array with one row (x, y, z, ẋ, ẏ, ż) per time in minutes since epoch
+    pub fn propagate_array<'py>(
+        &self,
+        py: Python<'py>,
+        minutes: numpy::PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, numpy::PyArray2<f64>>> {
+        let times: Vec<tle::MinutesSinceEpoch> = minutes
+            .as_array()
+            .iter()
+            .map(|minutes| tle::MinutesSinceEpoch(*minutes))
+            .collect();
+        Ok(self
+            .constants
+            .propagate_to_array(&times)
+            .map_err(value_error)?
+            .into_pyarray(py))
+    }
+}
+
+/// Python module definition, compiled with the `python` feature
+#[pymodule]
+pub fn sgp4(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyElements>()?;
+    module.add_class::<PyConstants>()?;
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use crate::staleness;
+use crate::tle;
+use crate::validation;
+
+// score factors applied to each defect
+const BAD_CHECKSUM_FACTOR: f64 = 0.5;
+const WARNING_AGE_FACTOR: f64 = 0.75;
+const REJECTED_AGE_FACTOR: f64 = 0.25;
+const SUSPICIOUS_DRAG_TERM_FACTOR: f64 = 0.5;
+const UNEXPECTED_EPHEMERIS_TYPE_FACTOR: f64 = 0.9;
+
+/// Quality indicators of an element set
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementsQuality {
+    /// Whether the TLE lines' checksums match their content (true for elements that do not come from a TLE)
+    pub checksums_valid: bool,
+
+    /// The age of the elements in days
+    pub age: f64,
+
+    /// The classification of the age
+    pub age_status: staleness::AgeStatus,
+
+    /// The problems found by [Elements::validate](struct.Elements.html#method.validate)
+    pub issues: alloc::vec::Vec<validation::ElementsIssue>,
+
+    /// Whether the ephemeris type is 0, the only value found in distributed data
+    pub ephemeris_type_valid: bool,
+
+    /// Composite score in the range [0, 1], higher is better
+    ///
+    /// The score is 0 if any issue other than a suspicious drag term was found.
+    /// Otherwise, it starts at 1 and is multiplied by 0.5 for bad checksums,
+    /// 0.75 for a warning age, 0.25 for a rejected age, 0.5 for a suspicious drag term,
+    /// and 0.9 for an unexpected ephemeris type.
+    pub score: f64,
+}
+
+impl tle::Elements {
+    /// Returns quality indicators and a composite score that can be used to prioritize or reject element sets
+    ///
+    /// Parsed elements always have valid checksums, use [tle_quality](fn.tle_quality.html)
+    /// to score TLE lines whose checksums may be wrong.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The time of use in years since UTC 1 January 2000 12h00 (J2000)
+    /// * `thresholds` - The age thresholds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let quality = elements.quality(
+    ///     elements.epoch() + 5.0 / 365.25,
+    ///     &sgp4::AgeThresholds::default(),
+    /// );
+    /// assert_eq!(quality.age_status, sgp4::AgeStatus::Warning);
+    /// assert_eq!(quality.score, 0.75);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn quality(&self, epoch: f64, thresholds: &staleness::AgeThresholds) -> ElementsQuality {
+        let age = self.age(epoch);
+        let age_status = thresholds.classify(age);
+        let issues = self.validate();
+        let ephemeris_type_valid = self.ephemeris_type == 0;
+        let mut score = 1.0;
+        for issue in issues.iter() {
+            score *= match issue {
+                validation::ElementsIssue::DragTermOutOfRange(_) => SUSPICIOUS_DRAG_TERM_FACTOR,
+                _ => 0.0,
+            };
+        }
+        score *= match age_status {
+            staleness::AgeStatus::Fresh => 1.0,
+            staleness::AgeStatus::Warning => WARNING_AGE_FACTOR,
+            staleness::AgeStatus::Rejected => REJECTED_AGE_FACTOR,
+        };
+        if !ephemeris_type_valid {
+            score *= UNEXPECTED_EPHEMERIS_TYPE_FACTOR;
+        }
+        ElementsQuality {
+            checksums_valid: true,
+            age,
+            age_status,
+            issues,
+            ephemeris_type_valid,
+            score,
+        }
+    }
+}
+
+/// Parses a TLE and returns its elements and quality indicators
+///
+/// Unlike [Elements::from_tle](struct.Elements.html#method.from_tle), bad checksums are not errors.
+/// They are reported in [ElementsQuality::checksums_valid](struct.ElementsQuality.html#structfield.checksums_valid)
+/// and lower the score. Other format errors are returned.
+///
+/// # Arguments
+///
+/// * `object_name` - The name of the satellite, usually given by a third line placed before the TLE
+/// * `line1` - The first line of the TLE composed of ASCII characters
+/// * `line2` - The second line of the TLE composed of ASCII characters
+/// * `epoch` - The time of use in years since UTC 1 January 2000 12h00 (J2000)
+/// * `thresholds` - The age thresholds
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// // the last digit of the first line is wrong
+/// let (elements, quality) = sgp4::tle_quality(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9993".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+///     sgp4::julian_years_since_j2000(
+///         &sgp4::chrono::NaiveDate::from_ymd_opt(2020, 7, 13)
+///             .unwrap()
+///             .and_hms_opt(0, 0, 0)
+///             .unwrap(),
+///     ),
+///     &sgp4::AgeThresholds::default(),
+/// )?;
+/// assert_eq!(elements.norad_id, 25544);
+/// assert!(!quality.checksums_valid);
+/// assert_eq!(quality.score, 0.5);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn tle_quality(
+    object_name: Option<alloc::string::String>,
+    line1: &[u8],
+    line2: &[u8],
+    epoch: f64,
+    thresholds: &staleness::AgeThresholds,
+) -> core::result::Result<(tle::Elements, ElementsQuality), tle::Error> {
+    let mut lines = [alloc::vec::Vec::from(line1), alloc::vec::Vec::from(line2)];
+    let mut checksums_valid = true;
+    for line in lines.iter_mut() {
+        if line.len() == 69 {
+            let expected = b'0' + tle::checksum(&line[..68]);
+            if line[68] != expected {
+                checksums_valid = false;
+                line[68] = expected;
+            }
+        }
+    }
+    let elements = tle::Elements::from_tle(object_name, &lines[0], &lines[1])?;
+    let mut quality = elements.quality(epoch, thresholds);
+    if !checksums_valid {
+        quality.checksums_valid = false;
+        quality.score *= BAD_CHECKSUM_FACTOR;
+    }
+    Ok((elements, quality))
+}
@@ -0,0 +1,161 @@
+// c = 299792.458 km.s⁻¹
+const SPEED_OF_LIGHT: f64 = 299792.458;
+
+/// Returns the Doppler shift of a signal transmitted by a moving source in Hz
+///
+/// The received frequency is the sum of the transmitted frequency and the Doppler shift.
+/// The first-order (non-relativistic) expression is used,
+/// whose error is well below a millionth of the shift at orbital velocities.
+///
+/// The same expression applies to uplinks, with the shift applied to the frequency received by the satellite.
+///
+/// # Arguments
+///
+/// * `frequency` - The transmitted frequency in Hz
+/// * `range_rate` - The rate of change of the distance between the transmitter and the receiver in km.s⁻¹,
+///   for example calculated with [sgp4::Observer::range_rate](struct.Observer.html#method.range_rate)
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?)?;
+/// let observer = sgp4::Observer {
+///     latitude: 52.2053_f64.to_radians(),
+///     longitude: 0.1218_f64.to_radians(),
+///     altitude: 0.02,
+/// };
+/// let frequency = 145.8e6;
+/// for minutes in 0..90 {
+///     let t = sgp4::MinutesSinceEpoch(minutes as f64);
+///     let range_rate = observer.range_rate(
+///         sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+///         &constants.propagate(t)?,
+///     );
+///     let shift = sgp4::doppler_shift(frequency, range_rate);
+///     println!("t = {} min, f = {} Hz ({} Hz)", t.0, frequency + shift, shift);
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+pub fn doppler_shift(frequency: f64, range_rate: f64) -> f64 {
+    // Δf = -f ρ̇ / c
+    -frequency * range_rate / SPEED_OF_LIGHT
+}
+
+/// Frequency conversion of a linear transponder or a cross-band FM repeater
+///
+/// Frequencies are expressed in the satellite's reference frame (without Doppler shift).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Transponder {
+    /// The downlink frequency is the uplink frequency plus the offset in Hz
+    NonInverting {
+        /// Downlink minus uplink frequency in Hz (negative for downlinks below the uplink)
+        offset: f64,
+    },
+
+    /// The downlink frequency is the offset minus the uplink frequency in Hz
+    ///
+    /// The offset is the sum of the uplink and downlink frequencies,
+    /// for example the sum of the lower uplink passband edge and the upper downlink passband edge.
+    Inverting {
+        /// Uplink plus downlink frequency in Hz
+        offset: f64,
+    },
+}
+
+/// Uplink and downlink frequencies corrected for Doppler shift, as set on a ground station's radios
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DopplerPair {
+    /// Frequency to transmit on the ground in Hz
+    pub uplink: f64,
+
+    /// Frequency received on the ground in Hz
+    pub downlink: f64,
+}
+
+impl Transponder {
+    /// Returns the downlink frequency in the satellite's reference frame in Hz
+    ///
+    /// # Arguments
+    ///
+    /// * `uplink` - The uplink frequency received by the satellite in Hz
+    pub fn downlink(&self, uplink: f64) -> f64 {
+        match self {
+            Transponder::NonInverting { offset } => uplink + offset,
+            Transponder::Inverting { offset } => offset - uplink,
+        }
+    }
+
+    /// Returns the uplink frequency in the satellite's reference frame in Hz
+    ///
+    /// # Arguments
+    ///
+    /// * `downlink` - The downlink frequency transmitted by the satellite in Hz
+    pub fn uplink(&self, downlink: f64) -> f64 {
+        match self {
+            Transponder::NonInverting { offset } => downlink - offset,
+            Transponder::Inverting { offset } => offset - downlink,
+        }
+    }
+
+    /// Returns the ground uplink and downlink frequencies that correspond to a point of the satellite's passband
+    ///
+    /// The uplink frequency is pre-compensated so that the satellite receives `self.uplink(downlink)`,
+    /// and the downlink frequency is the one received on the ground.
+    /// Tracking a pass with a fixed `downlink` keeps the contact at the same point of the passband.
+    ///
+    /// # Arguments
+    ///
+    /// * `downlink` - The downlink frequency transmitted by the satellite in Hz
+    /// * `range_rate` - The rate of change of the distance between the observer and the satellite in km.s⁻¹,
+    ///   for example calculated with [sgp4::Observer::range_rate](struct.Observer.html#method.range_rate)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: 52.2053_f64.to_radians(),
+    ///     longitude: 0.1218_f64.to_radians(),
+    ///     altitude: 0.02,
+    /// };
+    ///
+    /// // 145.990 MHz uplink, 437.800 MHz downlink FM repeater
+    /// let transponder = sgp4::Transponder::NonInverting { offset: 437.8e6 - 145.99e6 };
+    /// for minutes in 0..90 {
+    ///     let t = sgp4::MinutesSinceEpoch(minutes as f64);
+    ///     let pair = transponder.doppler_pair(
+    ///         437.8e6,
+    ///         observer.range_rate(
+    ///             sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+    ///             &constants.propagate(t)?,
+    ///         ),
+    ///     );
+    ///     println!("t = {} min, uplink {} Hz, downlink {} Hz", t.0, pair.uplink, pair.downlink);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn doppler_pair(&self, downlink: f64, range_rate: f64) -> DopplerPair {
+        let uplink = self.uplink(downlink);
+        DopplerPair {
+            // the satellite receives f = fₒ + Δf(fₒ) ≈ f' with fₒ = f' - Δf(f')
+            uplink: uplink - doppler_shift(uplink, range_rate),
+
+            // the ground receives f = f' + Δf(f')
+            downlink: downlink + doppler_shift(downlink, range_rate),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::gp;
+use crate::metadata;
+use crate::model;
+use crate::propagator;
+use crate::tle;
+use crate::ElementsError;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Represents an error raised while moving elements to a new epoch
+#[derive(Debug, Clone)]
+pub enum ReEpochError {
+    /// The original elements cannot be used to initialize a propagator
+    Elements(ElementsError),
+
+    /// The new epoch is too far from the original epoch
+    Datetime(tle::DatetimeToMinutesSinceEpochError),
+
+    /// The mean elements cannot be propagated to the new epoch
+    Propagation(gp::Error),
+}
+
+impl core::fmt::Display for ReEpochError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReEpochError::Elements(error) => error.fmt(formatter),
+            ReEpochError::Datetime(error) => error.fmt(formatter),
+            ReEpochError::Propagation(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl From<ElementsError> for ReEpochError {
+    fn from(value: ElementsError) -> Self {
+        Self::Elements(value)
+    }
+}
+
+impl From<tle::DatetimeToMinutesSinceEpochError> for ReEpochError {
+    fn from(value: tle::DatetimeToMinutesSinceEpochError) -> Self {
+        Self::Datetime(value)
+    }
+}
+
+impl From<gp::Error> for ReEpochError {
+    fn from(value: gp::Error) -> Self {
+        Self::Propagation(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReEpochError {}
+
+// number of corrections applied to deep-space mean elements
+const RE_EPOCH_ITERATIONS: usize = 5;
+
+// non-singular elements (I sin Ω, I cos Ω, e sin ϖ, e cos ϖ, λ) with ϖ = Ω + ω and λ = ϖ + M
+fn equinoctial(orbit: &propagator::Orbit) -> [f64; 5] {
+    let longitude_of_perigee = orbit.right_ascension + orbit.argument_of_perigee;
+    [
+        orbit.inclination * orbit.right_ascension.sin(),
+        orbit.inclination * orbit.right_ascension.cos(),
+        orbit.eccentricity * longitude_of_perigee.sin(),
+        orbit.eccentricity * longitude_of_perigee.cos(),
+        longitude_of_perigee + orbit.mean_anomaly,
+    ]
+}
+
+impl tle::Elements {
+    // copy of the elements with a new epoch and new Brouwer mean elements
+    fn with_orbit(
+        &self,
+        orbit: &propagator::Orbit,
+        datetime: chrono::NaiveDateTime,
+        revolution_number: u64,
+    ) -> core::result::Result<tle::Elements, ElementsError> {
+        Ok(tle::Elements::from_orbit(
+            &model::WGS84,
+            orbit,
+            datetime,
+            self.drag_term,
+            metadata::ElementsMetadata {
+                #[cfg(any(feature = "alloc", feature = "heapless"))]
+                object_name: self.object_name.clone(),
+                #[cfg(any(feature = "alloc", feature = "heapless"))]
+                international_designator: self.international_designator.clone(),
+                norad_id: self.norad_id,
+                classification: self.classification,
+                mean_motion_dot: self.mean_motion_dot,
+                mean_motion_ddot: self.mean_motion_ddot,
+                element_set_number: self.element_set_number,
+                revolution_number,
+                ephemeris_type: self.ephemeris_type,
+            },
+        )?)
+    }
+
+    /// Returns new elements whose epoch is the given datetime
+    ///
+    /// The SGP4 secular and long-period perturbations (gravity, drag and, for deep-space orbits, lunisolar effects)
+    /// are applied to the mean elements, which are then converted back to the Kozai convention.
+    /// Short-period terms are not involved, hence the new elements describe the same mean orbit.
+    /// Predictions obtained from the new elements match the original ones closely near the new epoch
+    /// and drift apart as the drag modelling differs. Identification fields, the mean motion derivatives
+    /// and the drag term are copied, and the revolution number counts the ascending nodes crossed in between.
+    ///
+    /// Deep-space propagators add lunisolar terms at epoch, which are compensated iteratively.
+    /// The compensation is less accurate for near-equatorial deep-space orbits (tens of km for geostationary satellites).
+    ///
+    /// The new elements can be formatted with [Elements::to_tle](struct.Elements.html#method.to_tle).
+    ///
+    /// # Arguments
+    ///
+    /// * `datetime` - The new epoch (UTC)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let new_elements = elements.re_epoch(elements.datetime + sgp4::chrono::Duration::days(1))?;
+    /// let (line1, line2) = new_elements.to_tle()?;
+    /// println!("{line1}\n{line2}");
+    ///
+    /// // the ISS completes 15 revolutions in a day
+    /// assert_eq!(new_elements.revolution_number, elements.revolution_number + 15);
+    ///
+    /// // the new elements predict the same position at their epoch
+    /// let prediction = sgp4::Constants::from_elements(&elements)?
+    ///     .propagate(sgp4::MinutesSinceEpoch(24.0 * 60.0))?;
+    /// let new_prediction = sgp4::Constants::from_elements(&new_elements)?
+    ///     .propagate(sgp4::MinutesSinceEpoch(0.0))?;
+    /// for (x, new_x) in prediction.position.iter().zip(new_prediction.position.iter()) {
+    ///     assert!((x - new_x).abs() < 1e-3);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn re_epoch(
+        &self,
+        datetime: chrono::NaiveDateTime,
+    ) -> core::result::Result<tle::Elements, ReEpochError> {
+        let constants = propagator::Constants::from_elements(self)?;
+        let t = self.datetime_to_minutes_since_epoch(&datetime)?;
+        let (target, ..) = constants.orbital_elements(
+            t.0,
+            constants.initial_state().as_mut(),
+            false,
+            Some(&gp::EccentricityLimits::SGP4),
+        )?;
+
+        // the revolution number increases at each ascending node (u = ω + M rem 2π)
+        let argument_of_latitude_0 = (self.argument_of_perigee + self.mean_anomaly).to_radians();
+        let phase = argument_of_latitude_0
+            - (2.0 * core::f64::consts::PI)
+                * (argument_of_latitude_0 / (2.0 * core::f64::consts::PI)).floor()
+            + (target.argument_of_perigee + target.mean_anomaly - argument_of_latitude_0);
+        let revolution_number = (self.revolution_number as i64
+            + (phase / (2.0 * core::f64::consts::PI)).floor() as i64)
+            .max(0) as u64;
+
+        // deep-space propagators add lunisolar terms at epoch,
+        // the mean elements are corrected until the new propagator's epoch elements match the target
+        // (the corrections may not converge for near-equatorial orbits, hence the best estimate is kept)
+        let target_values = equinoctial(&target);
+        let mut orbit = target.clone();
+        let mut best: Option<(f64, propagator::Orbit)> = None;
+        for _ in 0..RE_EPOCH_ITERATIONS {
+            let new_constants = propagator::Constants::from_elements(&self.with_orbit(
+                &orbit,
+                datetime,
+                revolution_number,
+            )?)?;
+            if let propagator::Method::NearEarth { .. } = new_constants.method {
+                break;
+            }
+            let (epoch_orbit, ..) = new_constants.orbital_elements(
+                0.0,
+                new_constants.initial_state().as_mut(),
+                false,
+                Some(&gp::EccentricityLimits::SGP4),
+            )?;
+            let epoch_values = equinoctial(&epoch_orbit);
+            let mut residuals = [0.0; 5];
+            for index in 0..4 {
+                residuals[index] = target_values[index] - epoch_values[index];
+            }
+            residuals[4] = model::signed_angle(target_values[4] - epoch_values[4]);
+            let residual = residuals
+                .iter()
+                .fold(0.0_f64, |maximum, residual| maximum.max(residual.abs()));
+            let improved = match &best {
+                Some((best_residual, _)) => residual < *best_residual,
+                None => true,
+            };
+            if improved {
+                best = Some((residual, orbit.clone()));
+            }
+            let mut values = equinoctial(&orbit);
+            for (value, residual) in values.iter_mut().zip(residuals.iter()) {
+                *value += residual;
+            }
+
+            // ϖ = tan⁻¹(e sin ϖ / e cos ϖ), Ω = tan⁻¹(I sin Ω / I cos Ω)
+            let longitude_of_perigee = values[2].atan2(values[3]);
+            let right_ascension = values[0].atan2(values[1]);
+            orbit = propagator::Orbit {
+                inclination: values[0].hypot(values[1]),
+                right_ascension,
+                eccentricity: values[2].hypot(values[3]).min(1.0 - f64::EPSILON),
+                argument_of_perigee: longitude_of_perigee - right_ascension,
+                mean_anomaly: values[4] - longitude_of_perigee,
+                mean_motion: orbit.mean_motion + target.mean_motion - epoch_orbit.mean_motion,
+            };
+        }
+        Ok(self.with_orbit(
+            &best.map_or(orbit, |(_, orbit)| orbit),
+            datetime,
+            revolution_number,
+        )?)
+    }
+}
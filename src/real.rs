@@ -0,0 +1,155 @@
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Euclid;
+
+pub(crate) mod sealed {
+    // operations used by the propagator that are not part of the public interface
+    pub trait Sealed: Sized {
+        // whether the number carries derivative parts that must converge in iterative solvers
+        const HAS_DERIVATIVES: bool;
+
+        // sine and cosine of the real part given by f, with the derivative parts of sin and cos
+        fn map_sine_cosine(self, f: fn(f64) -> (f64, f64)) -> (Self, Self);
+
+        // four-quadrant arctangent of the real parts given by f, with the derivative parts of atan2
+        fn map_arctangent2(self, x: Self, f: fn(f64, f64) -> f64) -> Self;
+
+        // Euclidean remainder of the real part, the derivative parts are not modified
+        fn euclidean_remainder(self, modulus: f64) -> Self;
+
+        // the real part restricted to [minimum, maximum], the derivative parts are zero if it is clamped
+        fn clamped(self, minimum: f64, maximum: f64) -> Self;
+
+        // the largest of the real part and other, the derivative parts are zero if other is larger
+        fn maximum(self, other: f64) -> Self;
+    }
+}
+
+/// Number type of the SGP4 initialization and propagation equations
+///
+/// The trait is implemented for `f64` and, with the `hyperdual` feature, for
+/// [Hyperdual](struct.Hyperdual.html). It cannot be implemented outside this crate.
+/// Comparisons (branches of the SGP4 equations) use the real part only, hence derivatives
+/// are those of the active branch. The remainder `x % m` reduces the real part
+/// and does not modify the derivative parts.
+pub trait Real:
+    sealed::Sealed
+    + Copy
+    + core::fmt::Debug
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Div<Output = Self>
+    + core::ops::Neg<Output = Self>
+    + core::ops::AddAssign
+    + core::ops::Add<f64, Output = Self>
+    + core::ops::Sub<f64, Output = Self>
+    + core::ops::Mul<f64, Output = Self>
+    + core::ops::Div<f64, Output = Self>
+    + core::ops::Rem<f64, Output = Self>
+{
+    /// Returns a number without derivative parts
+    fn from_f64(value: f64) -> Self;
+
+    /// Returns the real part
+    fn value(self) -> f64;
+
+    /// Returns the sine
+    fn sine(self) -> Self;
+
+    /// Returns the cosine
+    fn cosine(self) -> Self;
+
+    /// Returns the sine and the cosine
+    fn sine_cosine(self) -> (Self, Self);
+
+    /// Returns the square root
+    fn square_root(self) -> Self;
+
+    /// Returns the absolute value
+    fn absolute(self) -> Self;
+
+    /// Returns the integer power
+    fn integer_power(self, exponent: i32) -> Self;
+
+    /// Returns the real power
+    fn real_power(self, exponent: f64) -> Self;
+
+    /// Returns the four-quadrant arctangent of self (y) and x
+    fn arctangent2(self, x: Self) -> Self;
+}
+
+impl sealed::Sealed for f64 {
+    const HAS_DERIVATIVES: bool = false;
+
+    fn map_sine_cosine(self, f: fn(f64) -> (f64, f64)) -> (Self, Self) {
+        f(self)
+    }
+
+    fn map_arctangent2(self, x: Self, f: fn(f64, f64) -> f64) -> Self {
+        f(self, x)
+    }
+
+    fn euclidean_remainder(self, modulus: f64) -> Self {
+        #[cfg(feature = "std")]
+        {
+            self.rem_euclid(modulus)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Euclid::rem_euclid(&self, &modulus)
+        }
+    }
+
+    fn clamped(self, minimum: f64, maximum: f64) -> Self {
+        self.clamp(minimum, maximum)
+    }
+
+    fn maximum(self, other: f64) -> Self {
+        self.max(other)
+    }
+}
+
+impl Real for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn value(self) -> f64 {
+        self
+    }
+
+    fn sine(self) -> Self {
+        self.sin()
+    }
+
+    fn cosine(self) -> Self {
+        self.cos()
+    }
+
+    fn sine_cosine(self) -> (Self, Self) {
+        self.sin_cos()
+    }
+
+    fn square_root(self) -> Self {
+        self.sqrt()
+    }
+
+    fn absolute(self) -> Self {
+        self.abs()
+    }
+
+    fn integer_power(self, exponent: i32) -> Self {
+        self.powi(exponent)
+    }
+
+    fn real_power(self, exponent: f64) -> Self {
+        self.powf(exponent)
+    }
+
+    fn arctangent2(self, x: Self) -> Self {
+        self.atan2(x)
+    }
+}
@@ -0,0 +1,298 @@
+use crate::gp;
+use crate::history;
+use crate::norad_id;
+use crate::propagator;
+use crate::staleness;
+use crate::tle;
+use crate::ElementsError;
+
+// default minimum propagation time between two queries for the same satellite (1 hour in days)
+const DEFAULT_RETRY_INTERVAL: f64 = 1.0 / 24.0;
+
+/// Provider of up-to-date element sets for [RefreshingTracker](struct.RefreshingTracker.html)
+///
+/// The trait is implemented for functions and closures with the signature
+/// `FnMut(NoradId) -> Result<Option<Elements>, E>`, which cover sources without hooks.
+/// Sources are usually backed by a local catalog updated by another task,
+/// a [CachingFetcher](struct.CachingFetcher.html) cache directory or a database.
+pub trait CatalogSource {
+    /// The source error type
+    type Error;
+
+    /// Returns the most recent element set of a satellite, or `None` if the source has none
+    ///
+    /// Returning the element set that the tracker already uses is not an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `norad_id` - The satellite's catalog number
+    fn latest(
+        &mut self,
+        norad_id: norad_id::NoradId,
+    ) -> core::result::Result<Option<tle::Elements>, Self::Error>;
+
+    /// Called when a newer element set has been added to a satellite's history
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The newest element set before the refresh
+    /// * `current` - The element set returned by the source
+    fn on_refresh(&mut self, previous: &tle::Elements, current: &tle::Elements) {
+        let _ = (previous, current);
+    }
+
+    /// Called when the source has no newer element set and the age exceeds the warning threshold
+    ///
+    /// # Arguments
+    ///
+    /// * `norad_id` - The satellite's catalog number
+    /// * `age` - The age of the element set used for propagation in days
+    /// * `status` - The age classification, `AgeStatus::Warning` or `AgeStatus::Rejected`
+    fn on_stale(&mut self, norad_id: norad_id::NoradId, age: f64, status: staleness::AgeStatus) {
+        let _ = (norad_id, age, status);
+    }
+}
+
+impl<F, E> CatalogSource for F
+where
+    F: FnMut(norad_id::NoradId) -> core::result::Result<Option<tle::Elements>, E>,
+{
+    type Error = E;
+
+    fn latest(
+        &mut self,
+        norad_id: norad_id::NoradId,
+    ) -> core::result::Result<Option<tle::Elements>, E> {
+        self(norad_id)
+    }
+}
+
+/// Represents an error raised by a refreshing tracker
+#[derive(Debug)]
+pub enum RefreshError<E> {
+    /// The source failed
+    Source(E),
+
+    /// The source returned elements that cannot be used to initialize a propagator
+    Elements(ElementsError),
+
+    /// The propagation failed
+    Propagation(gp::Error),
+
+    /// Neither the tracker nor the source has elements for the satellite
+    Unavailable(norad_id::NoradId),
+
+    /// The newest available elements are older than the rejection threshold
+    Rejected {
+        /// The satellite's catalog number
+        norad_id: norad_id::NoradId,
+
+        /// The age of the element set used for propagation in days
+        age: f64,
+    },
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for RefreshError<E> {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RefreshError::Source(error) => formatter.write_fmt(core::format_args!("{}", error)),
+            RefreshError::Elements(error) => formatter.write_fmt(core::format_args!("{}", error)),
+            RefreshError::Propagation(error) => {
+                formatter.write_fmt(core::format_args!("{}", error))
+            }
+            RefreshError::Unavailable(norad_id) => formatter.write_fmt(core::format_args!(
+                "no elements are available for the satellite {}",
+                norad_id
+            )),
+            RefreshError::Rejected { norad_id, age } => formatter.write_fmt(core::format_args!(
+                "the newest elements of the satellite {} are {} days old",
+                norad_id,
+                age
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for RefreshError<E> {}
+
+// elements history of a satellite and propagation time of the last query
+struct Track {
+    history: history::ElementsHistory,
+    last_query: f64,
+}
+
+/// A multi-satellite propagator that swaps in newer element sets when the current ones become stale
+///
+/// Each satellite has an [ElementsHistory](struct.ElementsHistory.html), which switches element sets
+/// at the midpoint between their epochs and blends the predictions around handovers,
+/// hence refreshes do not introduce discontinuities in the predicted trajectory.
+///
+/// The source is queried the first time a satellite is propagated, and then whenever the age of the
+/// element set used for propagation exceeds the warning threshold. Queries for the same satellite
+/// are separated by at least the retry interval (in propagation time) to avoid flooding the source
+/// when it has nothing newer.
+pub struct RefreshingTracker<S: CatalogSource> {
+    source: S,
+    thresholds: staleness::AgeThresholds,
+    blend: f64,
+    retry_interval: f64,
+    tracks: alloc::collections::BTreeMap<norad_id::NoradId, Track>,
+}
+
+impl<S: CatalogSource> RefreshingTracker<S> {
+    /// Creates a tracker without element sets
+    ///
+    /// The handover blending window is 0 (no blending) and the retry interval is one hour.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The element sets provider
+    /// * `thresholds` - The age thresholds that trigger refreshes and rejections
+    pub fn new(source: S, thresholds: staleness::AgeThresholds) -> Self {
+        RefreshingTracker {
+            source,
+            thresholds,
+            blend: 0.0,
+            retry_interval: DEFAULT_RETRY_INTERVAL,
+            tracks: alloc::collections::BTreeMap::new(),
+        }
+    }
+
+    /// Changes the duration of the window centred on handovers over which predictions are blended
+    ///
+    /// # Arguments
+    ///
+    /// * `blend` - The window duration in minutes, see [ElementsHistory::propagate](struct.ElementsHistory.html#method.propagate)
+    pub fn with_blend(mut self, blend: f64) -> Self {
+        self.blend = blend;
+        self
+    }
+
+    /// Changes the minimum propagation time between two queries for the same satellite
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_interval` - The minimum interval in days
+    pub fn with_retry_interval(mut self, retry_interval: f64) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+
+    /// Returns the element sets provider
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Returns the element sets history of a satellite, if it has been propagated
+    ///
+    /// # Arguments
+    ///
+    /// * `norad_id` - The satellite's catalog number
+    pub fn history(&self, norad_id: norad_id::NoradId) -> Option<&history::ElementsHistory> {
+        self.tracks.get(&norad_id).map(|track| &track.history)
+    }
+
+    /// Calculates the SGP4 prediction of a satellite, refreshing its element sets if needed
+    ///
+    /// # Arguments
+    ///
+    /// * `norad_id` - The satellite's catalog number
+    /// * `epoch` - The propagation time in years since UTC 1 January 2000 12h00 (J2000)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let tle = (
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// );
+    /// let epoch = sgp4::Elements::from_tle(None, tle.0, tle.1)?.epoch();
+    /// let mut tracker = sgp4::RefreshingTracker::new(
+    ///     |_norad_id| sgp4::Elements::from_tle(None, tle.0, tle.1).map(Some),
+    ///     sgp4::AgeThresholds::default(),
+    /// )
+    /// .with_blend(30.0);
+    /// let iss = sgp4::NoradId::new(25544)?;
+    /// for hours in 0..48 {
+    ///     let prediction = tracker.propagate(iss, epoch + hours as f64 / (365.25 * 24.0))?;
+    ///     println!("r = {:?} km", prediction.position);
+    /// }
+    /// assert!(matches!(
+    ///     tracker.propagate(iss, epoch + 10.0 / 365.25),
+    ///     Err(sgp4::RefreshError::Rejected { norad_id, .. }) if norad_id == iss,
+    /// ));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn propagate(
+        &mut self,
+        norad_id: norad_id::NoradId,
+        epoch: f64,
+    ) -> core::result::Result<propagator::Prediction, RefreshError<S::Error>> {
+        let query = match self.tracks.get(&norad_id) {
+            Some(track) => {
+                self.thresholds.classify(nearest_age(&track.history, epoch))
+                    != staleness::AgeStatus::Fresh
+                    && (epoch - track.last_query).abs() * 365.25 >= self.retry_interval
+            }
+            None => true,
+        };
+        if query {
+            let latest = self.source.latest(norad_id).map_err(RefreshError::Source)?;
+            let track = self.tracks.entry(norad_id).or_insert_with(|| Track {
+                history: history::ElementsHistory::new(),
+                last_query: epoch,
+            });
+            track.last_query = epoch;
+            if let Some(latest) = latest {
+                let newer = match track.history.iter().last() {
+                    Some(newest) => latest.datetime > newest.datetime,
+                    None => true,
+                };
+                if newer {
+                    track
+                        .history
+                        .insert(latest)
+                        .map_err(RefreshError::Elements)?;
+
+                    // the new element set is the last one since it is more recent than the others
+                    let mut elements = track
+                        .history
+                        .iter()
+                        .skip(track.history.len().saturating_sub(2));
+                    if let (Some(previous), Some(current)) = (elements.next(), elements.next()) {
+                        self.source.on_refresh(previous, current);
+                    }
+                }
+            }
+        }
+        let track = self
+            .tracks
+            .get(&norad_id)
+            .filter(|track| !track.history.is_empty())
+            .ok_or(RefreshError::Unavailable(norad_id))?;
+        let age = nearest_age(&track.history, epoch);
+        let status = self.thresholds.classify(age);
+        if status != staleness::AgeStatus::Fresh && query {
+            self.source.on_stale(norad_id, age, status);
+        }
+        if status == staleness::AgeStatus::Rejected {
+            return Err(RefreshError::Rejected { norad_id, age });
+        }
+        match track.history.propagate(epoch, self.blend) {
+            Some(prediction) => prediction.map_err(RefreshError::Propagation),
+            None => Err(RefreshError::Unavailable(norad_id)),
+        }
+    }
+}
+
+// age in days of the element set whose epoch is the nearest to the given time
+fn nearest_age(history: &history::ElementsHistory, epoch: f64) -> f64 {
+    history
+        .iter()
+        .map(|elements| elements.age(epoch))
+        .min_by(|a, b| a.abs().total_cmp(&b.abs()))
+        .unwrap_or(f64::INFINITY)
+}
@@ -0,0 +1,525 @@
+use crate::events;
+use crate::geodetic;
+use crate::gp;
+use crate::model;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A closed polygon on the Earth's surface
+///
+/// Consecutive vertices (and the last and first vertices) are joined by great circle arcs.
+/// Edges are therefore not parallels of latitude, even if both vertices have the same latitude.
+/// The polygon may cross the antimeridian or contain a pole, but it must fit in a hemisphere.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Region {
+    /// Vertices as [latitude, longitude] pairs in rad
+    ///
+    /// Vertices may be listed clockwise or counter-clockwise.
+    pub vertices: alloc::vec::Vec<[f64; 2]>,
+}
+
+/// A stay of the sub-satellite point inside a region
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegionVisit {
+    /// Time at which the sub-satellite point enters the region in minutes since epoch
+    ///
+    /// `None` if the sub-satellite point is already inside the region at the beginning of the window.
+    pub entry: Option<MinutesSinceEpoch>,
+
+    /// Time at which the sub-satellite point leaves the region in minutes since epoch
+    ///
+    /// `None` if the sub-satellite point is still inside the region at the end of the window.
+    pub exit: Option<MinutesSinceEpoch>,
+
+    /// Time spent inside the region within the window in minutes
+    pub dwell: f64,
+}
+
+/// User-provided geodata (countries, areas of interest...) attached to ground points
+///
+/// The trait is implemented for functions and closures with the signature
+/// `Fn(f64, f64) -> Option<Id>` (latitude and longitude in rad), which suit non-overlapping regions
+/// such as countries, and for slices of `(Id, Region)` pairs, which may overlap.
+pub trait RegionLookup {
+    /// The region identifier type, for example an ISO 3166 country code
+    type Id: Clone + PartialEq;
+
+    /// Appends the identifiers of the regions that contain a point to `ids`
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Geodetic latitude of the point in rad
+    /// * `longitude` - Longitude of the point in rad
+    /// * `ids` - The identifiers list to extend
+    fn lookup(&self, latitude: f64, longitude: f64, ids: &mut alloc::vec::Vec<Self::Id>);
+}
+
+impl<F, I> RegionLookup for F
+where
+    F: Fn(f64, f64) -> Option<I>,
+    I: Clone + PartialEq,
+{
+    type Id = I;
+
+    fn lookup(&self, latitude: f64, longitude: f64, ids: &mut alloc::vec::Vec<I>) {
+        ids.extend(self(latitude, longitude));
+    }
+}
+
+impl<I: Clone + PartialEq> RegionLookup for [(I, Region)] {
+    type Id = I;
+
+    fn lookup(&self, latitude: f64, longitude: f64, ids: &mut alloc::vec::Vec<I>) {
+        ids.extend(
+            self.iter()
+                .filter(|(_, region)| region.contains(latitude, longitude))
+                .map(|(id, _)| id.clone()),
+        );
+    }
+}
+
+/// A stay of the sub-satellite point inside a user-defined region
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegionSegment<I> {
+    /// Region identifier returned by the lookup
+    pub id: I,
+
+    /// Time at which the sub-satellite point enters the region in minutes since epoch
+    ///
+    /// `None` if the sub-satellite point is already inside the region at the beginning of the window.
+    pub entry: Option<MinutesSinceEpoch>,
+
+    /// Time at which the sub-satellite point leaves the region in minutes since epoch
+    ///
+    /// `None` if the sub-satellite point is still inside the region at the end of the window.
+    pub exit: Option<MinutesSinceEpoch>,
+}
+
+// unit vector pointing towards a point on the sphere
+fn unit_vector(latitude: f64, longitude: f64) -> [f64; 3] {
+    [
+        latitude.cos() * longitude.cos(),
+        latitude.cos() * longitude.sin(),
+        latitude.sin(),
+    ]
+}
+
+impl Region {
+    /// Returns the coverage footprint of a satellite for a minimum elevation
+    ///
+    /// The footprint is the set of ground points from which the satellite is seen above the minimum elevation.
+    /// Its boundary is calculated on a spherical Earth with the WGS84 equatorial radius,
+    /// and the vertices are then converted to geodetic coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `sidereal_time` - Greenwich sidereal time in rad, for example calculated with [sgp4::iau_epoch_to_sidereal_time](fn.iau_epoch_to_sidereal_time.html)
+    /// * `position` - The satellite position in the True Equator, Mean Equinox (TEME) reference frame in km
+    /// * `minimum_elevation` - The minimum elevation above the local horizontal plane in rad
+    /// * `vertices` - The number of polygon vertices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let t = sgp4::MinutesSinceEpoch(30.0);
+    /// let footprint = sgp4::Region::footprint(
+    ///     sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+    ///     &constants.propagate(t)?.position,
+    ///     10.0_f64.to_radians(),
+    ///     72,
+    /// );
+    /// for [latitude, longitude] in footprint.vertices {
+    ///     println!("{}° {}°", latitude.to_degrees(), longitude.to_degrees());
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn footprint(
+        sidereal_time: f64,
+        position: &[f64; 3],
+        minimum_elevation: f64,
+        vertices: usize,
+    ) -> Self {
+        let radius = vector::norm(position);
+        let up = [
+            position[0] / radius,
+            position[1] / radius,
+            position[2] / radius,
+        ];
+
+        // λ = cos⁻¹(a cos εₘ / r) - εₘ
+        let central_angle = (geodetic::ELLIPSOID_EQUATORIAL_RADIUS * minimum_elevation.cos()
+            / radius)
+            .clamp(-1.0, 1.0)
+            .acos()
+            - minimum_elevation;
+
+        // local east and north directions below the satellite
+        let east = {
+            let east = vector::cross(&[0.0, 0.0, 1.0], &up);
+            let norm = vector::norm(&east);
+            if norm > 1.0e-12 {
+                [east[0] / norm, east[1] / norm, east[2] / norm]
+            } else {
+                [0.0, 1.0, 0.0]
+            }
+        };
+        let north = vector::cross(&up, &east);
+        Region {
+            vertices: (0..vertices)
+                .map(|index| {
+                    // β = 2π k / n
+                    let azimuth = 2.0 * core::f64::consts::PI * (index as f64) / (vertices as f64);
+
+                    // r = a (cos λ û + sin λ (cos β n̂ + sin β ê))
+                    let mut vertex = [0.0; 3];
+                    for (component, ((up, north), east)) in vertex
+                        .iter_mut()
+                        .zip(up.iter().zip(north.iter()).zip(east.iter()))
+                    {
+                        *component = geodetic::ELLIPSOID_EQUATORIAL_RADIUS
+                            * (central_angle.cos() * up
+                                + central_angle.sin()
+                                    * (azimuth.cos() * north + azimuth.sin() * east));
+                    }
+                    let vertex = geodetic::Geodetic::from_teme(sidereal_time, &vertex);
+                    [vertex.latitude, vertex.longitude]
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the identifiers of the user-defined regions that contain sample points of this region
+    ///
+    /// The sample points are the vertices, `rings` inner rings interpolated between the vertices
+    /// and their centroid along great circles, and the centroid. Applied to a footprint
+    /// (see [Region::footprint](struct.Region.html#method.footprint)), this lists the regions
+    /// (for example countries) that the satellite covers. Regions smaller than the distance
+    /// between sample points may be missed.
+    ///
+    /// Identifiers are listed once, in order of first appearance.
+    ///
+    /// # Arguments
+    ///
+    /// * `lookup` - The user-provided geodata
+    /// * `rings` - The number of inner rings
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let t = sgp4::MinutesSinceEpoch(30.0);
+    /// let footprint = sgp4::Region::footprint(
+    ///     sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+    ///     &constants.propagate(t)?.position,
+    ///     10.0_f64.to_radians(),
+    ///     72,
+    /// );
+    ///
+    /// // replace this with a country lookup
+    /// let hemisphere = |latitude: f64, _longitude: f64| {
+    ///     Some(if latitude >= 0.0 { "north" } else { "south" })
+    /// };
+    /// println!("{:?}", footprint.lookup(&hemisphere, 4));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn lookup<L: RegionLookup + ?Sized>(
+        &self,
+        lookup: &L,
+        rings: usize,
+    ) -> alloc::vec::Vec<L::Id> {
+        let mut ids = alloc::vec::Vec::new();
+        if self.vertices.is_empty() {
+            return ids;
+        }
+        let centroid = self.vertices.iter().fold([0.0; 3], |centroid, vertex| {
+            let vertex = unit_vector(vertex[0], vertex[1]);
+            [
+                centroid[0] + vertex[0],
+                centroid[1] + vertex[1],
+                centroid[2] + vertex[2],
+            ]
+        });
+        let mut point_ids = alloc::vec::Vec::new();
+        let mut add = |point: [f64; 3]| {
+            point_ids.clear();
+            lookup.lookup(
+                point[2].atan2((point[0].powi(2) + point[1].powi(2)).sqrt()),
+                point[1].atan2(point[0]),
+                &mut point_ids,
+            );
+            for id in point_ids.drain(..) {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        };
+        for ring in 0..=rings {
+            // w = k / (n + 1), from the vertices (w = 0) towards the centroid
+            let weight = ring as f64 / (rings + 1) as f64;
+            let centroid_norm = vector::norm(&centroid);
+            for vertex in self.vertices.iter() {
+                let vertex = unit_vector(vertex[0], vertex[1]);
+                add([0, 1, 2].map(|index| {
+                    (1.0 - weight) * vertex[index] + weight * centroid[index] / centroid_norm
+                }));
+            }
+        }
+        add(centroid);
+        ids
+    }
+
+    /// Returns true if the given point lies inside the region
+    ///
+    /// # Arguments
+    ///
+    /// * `latitude` - Latitude of the point in rad
+    /// * `longitude` - Longitude of the point in rad
+    pub fn contains(&self, latitude: f64, longitude: f64) -> bool {
+        let point = unit_vector(latitude, longitude);
+
+        // the winding angle does not distinguish a point from its antipode,
+        // hence points in the hemisphere opposite to the vertices' centroid are discarded
+        let centroid = self.vertices.iter().fold([0.0; 3], |centroid, vertex| {
+            let vertex = unit_vector(vertex[0], vertex[1]);
+            [
+                centroid[0] + vertex[0],
+                centroid[1] + vertex[1],
+                centroid[2] + vertex[2],
+            ]
+        });
+        if vector::dot(&point, &centroid) <= 0.0 {
+            return false;
+        }
+
+        // projection of a vertex on the plane tangent to the sphere at the point
+        let tangent = |vertex: &[f64; 2]| {
+            let vertex = unit_vector(vertex[0], vertex[1]);
+            let projection = vector::dot(&vertex, &point);
+            [
+                vertex[0] - projection * point[0],
+                vertex[1] - projection * point[1],
+                vertex[2] - projection * point[2],
+            ]
+        };
+
+        // the winding angle is ±2π inside the region and 0 outside
+        let mut winding_angle = 0.0;
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            let a = tangent(vertex);
+            let b = tangent(&self.vertices[(index + 1) % self.vertices.len()]);
+            winding_angle += vector::dot(&point, &vector::cross(&a, &b)).atan2(vector::dot(&a, &b));
+        }
+        winding_angle.abs() > core::f64::consts::PI
+    }
+}
+
+impl propagator::Constants {
+    /// Finds the time intervals during which the sub-satellite point lies inside a region
+    ///
+    /// The sub-satellite point is sampled with a fixed step, and each boundary crossing is refined by bisection.
+    /// The step must be shorter than the time needed to fly over the region, otherwise short visits are missed.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region on the Earth's surface
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `step` - The sampling step in minutes (strictly positive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let region = sgp4::Region {
+    ///     vertices: [[35.0_f64, -10.0_f64], [35.0, 30.0], [60.0, 30.0], [60.0, -10.0]]
+    ///         .iter()
+    ///         .map(|[latitude, longitude]| [latitude.to_radians(), longitude.to_radians()])
+    ///         .collect(),
+    /// };
+    /// for visit in constants.region_visits(
+    ///     &region,
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    ///     0.5,
+    /// )? {
+    ///     println!("{:?} -> {:?} ({} min)", visit.entry, visit.exit, visit.dwell);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn region_visits(
+        &self,
+        region: &Region,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        step: f64,
+    ) -> core::result::Result<alloc::vec::Vec<RegionVisit>, gp::Error> {
+        Ok(events::positive_intervals(start, end, step, 1e-6, |t| {
+            self.propagate(t).map(|prediction| {
+                let sub_satellite_point = geodetic::Geodetic::from_teme(
+                    model::iau_epoch_to_sidereal_time(self.julian_years_since_j2000(t)),
+                    &prediction.position,
+                );
+                if region.contains(sub_satellite_point.latitude, sub_satellite_point.longitude) {
+                    1.0
+                } else {
+                    -1.0
+                }
+            })
+        })?
+        .into_iter()
+        .map(|(entry, exit)| RegionVisit {
+            entry,
+            exit,
+            dwell: exit.map_or(end.0, |exit| exit.0) - entry.map_or(start.0, |entry| entry.0),
+        })
+        .collect())
+    }
+
+    /// Finds the time intervals during which the sub-satellite point lies inside user-defined regions
+    ///
+    /// The lookup is applied to the sub-satellite point with a fixed step, and each change of region
+    /// is refined by bisection. The step must be shorter than the time needed to fly over the smallest region
+    /// of interest, otherwise short stays are missed. Segments are sorted by entry time.
+    ///
+    /// # Arguments
+    ///
+    /// * `lookup` - The user-provided geodata
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `step` - The sampling step in minutes (strictly positive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let europe = sgp4::Region {
+    ///     vertices: [[35.0_f64, -10.0_f64], [35.0, 30.0], [60.0, 30.0], [60.0, -10.0]]
+    ///         .iter()
+    ///         .map(|[latitude, longitude]| [latitude.to_radians(), longitude.to_radians()])
+    ///         .collect(),
+    /// };
+    /// let areas_of_interest = [("europe", europe)];
+    /// for segment in constants.region_segments(
+    ///     &areas_of_interest[..],
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    ///     0.5,
+    /// )? {
+    ///     println!("{}: {:?} -> {:?}", segment.id, segment.entry, segment.exit);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn region_segments<L: RegionLookup + ?Sized>(
+        &self,
+        lookup: &L,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        step: f64,
+    ) -> core::result::Result<alloc::vec::Vec<RegionSegment<L::Id>>, gp::Error> {
+        assert!(step > 0.0, "step must be strictly positive");
+        let ids = |t: MinutesSinceEpoch| {
+            self.propagate(t).map(|prediction| {
+                let sub_satellite_point = geodetic::Geodetic::from_teme(
+                    model::iau_epoch_to_sidereal_time(self.julian_years_since_j2000(t)),
+                    &prediction.position,
+                );
+                let mut ids = alloc::vec::Vec::new();
+                lookup.lookup(
+                    sub_satellite_point.latitude,
+                    sub_satellite_point.longitude,
+                    &mut ids,
+                );
+                ids
+            })
+        };
+        let mut segments = alloc::vec::Vec::new();
+        if end.0 < start.0 {
+            return Ok(segments);
+        }
+        let mut open: alloc::vec::Vec<(L::Id, Option<MinutesSinceEpoch>)> =
+            ids(start)?.into_iter().map(|id| (id, None)).collect();
+        let mut previous_t = start.0;
+        let mut sample = 0.0;
+        while previous_t < end.0 {
+            // samples are computed from the start, see events::find_crossings
+            sample += 1.0;
+            let t = (start.0 + sample * step).min(end.0);
+            if t <= previous_t {
+                continue;
+            }
+            let current = ids(MinutesSinceEpoch(t))?;
+
+            // refines the transition of a region between the previous and the current sample
+            let transition = |id: &L::Id, entering: bool| {
+                events::bisect(previous_t, t, entering, 1e-6, &mut |t| {
+                    ids(t).map(|ids| if ids.contains(id) { 1.0 } else { -1.0 })
+                })
+                .map(MinutesSinceEpoch)
+            };
+            let mut index = 0;
+            while index < open.len() {
+                if current.contains(&open[index].0) {
+                    index += 1;
+                } else {
+                    let (id, entry) = open.remove(index);
+                    let exit = transition(&id, false)?;
+                    segments.push(RegionSegment {
+                        id,
+                        entry,
+                        exit: Some(exit),
+                    });
+                }
+            }
+            for id in current {
+                if !open.iter().any(|(open_id, _)| *open_id == id) {
+                    let entry = transition(&id, true)?;
+                    open.push((id, Some(entry)));
+                }
+            }
+            previous_t = t;
+        }
+        segments.extend(open.into_iter().map(|(id, entry)| RegionSegment {
+            id,
+            entry,
+            exit: None,
+        }));
+        segments.sort_by(|a, b| {
+            a.entry
+                .map_or(f64::NEG_INFINITY, |entry| entry.0)
+                .total_cmp(&b.entry.map_or(f64::NEG_INFINITY, |entry| entry.0))
+        });
+        Ok(segments)
+    }
+}
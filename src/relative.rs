@@ -0,0 +1,227 @@
+use crate::geodetic::{ELLIPSOID_EQUATORIAL_RADIUS, ELLIPSOID_FLATTENING};
+use crate::propagator;
+use crate::vector;
+
+/// Relative motion of a satellite with respect to another satellite
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeState {
+    /// Distance between the satellites in km
+    pub range: f64,
+
+    /// Rate of change of the distance between the satellites in km.s⁻¹
+    ///
+    /// The range rate is positive when the satellites move away from each other.
+    pub range_rate: f64,
+
+    /// The three relative velocity components (x, y, z) in the TEME reference frame in km.s⁻¹
+    pub velocity: [f64; 3],
+}
+
+/// Relative position and velocity in the radial, in-track, cross-track (RIC) frame of a reference satellite
+///
+/// The radial axis points along the reference position, the cross-track axis along the reference angular momentum,
+/// and the in-track axis completes the right-handed frame (it is aligned with the velocity for circular orbits).
+/// The frame rotates with the reference satellite.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RicState {
+    /// The three position components (radial, in-track, cross-track) in km
+    pub position: [f64; 3],
+
+    /// The three velocity components (radial, in-track, cross-track) in km.s⁻¹
+    pub velocity: [f64; 3],
+}
+
+/// Returns true if the straight line between two satellites does not intersect the Earth
+///
+/// The Earth is modelled as the WGS84 ellipsoid inflated by the grazing height,
+/// which accounts for the atmospheric layer that a crosslink should not cross.
+///
+/// # Arguments
+///
+/// * `a` - The first satellite's position and velocity in the TEME reference frame
+/// * `b` - The second satellite's position and velocity in the TEME reference frame
+/// * `grazing_height` - The minimum altitude of the line of sight above the ellipsoid in km
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?)?;
+/// // a virtual satellite that follows the ISS on the same orbit, 10 minutes behind
+/// let leader = constants.propagate(sgp4::MinutesSinceEpoch(10.0))?;
+/// let follower = constants.propagate(sgp4::MinutesSinceEpoch(0.0))?;
+/// println!("visible: {}", sgp4::line_of_sight(&leader, &follower, 100.0));
+/// #     Ok(())
+/// # }
+/// ```
+pub fn line_of_sight(
+    a: &propagator::Prediction,
+    b: &propagator::Prediction,
+    grazing_height: f64,
+) -> bool {
+    // aₕ = a + h
+    let equatorial_radius = ELLIPSOID_EQUATORIAL_RADIUS + grazing_height;
+
+    // bₕ = a (1 - f) + h
+    let polar_radius = ELLIPSOID_EQUATORIAL_RADIUS * (1.0 - ELLIPSOID_FLATTENING) + grazing_height;
+
+    // the z axis is stretched so that the inflated ellipsoid becomes a sphere of radius aₕ
+    let stretch = |position: &[f64; 3]| {
+        [
+            position[0],
+            position[1],
+            position[2] * equatorial_radius / polar_radius,
+        ]
+    };
+    let a = stretch(&a.position);
+    let b = stretch(&b.position);
+
+    // d = b - a
+    let direction = vector::sub(&b, &a);
+
+    // s = clamp(-a · d / |d|², 0, 1)
+    let squared_length = vector::dot(&direction, &direction);
+    let s = if squared_length > 0.0 {
+        (-vector::dot(&a, &direction) / squared_length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    // p = a + s d
+    let closest = [
+        a[0] + s * direction[0],
+        a[1] + s * direction[1],
+        a[2] + s * direction[2],
+    ];
+    vector::norm(&closest) > equatorial_radius
+}
+
+/// Returns the range, range rate and relative velocity of a satellite with respect to another
+///
+/// # Arguments
+///
+/// * `a` - The first satellite's position and velocity in the TEME reference frame
+/// * `b` - The second satellite's position and velocity in the TEME reference frame
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?)?;
+/// let relative_state = sgp4::relative_state(
+///     &constants.propagate(sgp4::MinutesSinceEpoch(0.0))?,
+///     &constants.propagate(sgp4::MinutesSinceEpoch(1.0))?,
+/// );
+/// println!("range = {} km", relative_state.range);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn relative_state(a: &propagator::Prediction, b: &propagator::Prediction) -> RelativeState {
+    // ρ = r₂ - r₁
+    let position = vector::sub(&b.position, &a.position);
+
+    // ρ̇ = v₂ - v₁
+    let velocity = vector::sub(&b.velocity, &a.velocity);
+    let range = vector::norm(&position);
+    RelativeState {
+        range,
+
+        // ṙ = ρ · ρ̇ / |ρ|
+        range_rate: if range > 0.0 {
+            vector::dot(&position, &velocity) / range
+        } else {
+            0.0
+        },
+        velocity,
+    }
+}
+
+/// Returns the position and velocity of a satellite in the RIC frame of a reference satellite
+///
+/// # Arguments
+///
+/// * `reference` - The reference satellite's position and velocity in the TEME reference frame
+/// * `target` - The target satellite's position and velocity in the TEME reference frame
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?)?;
+/// // a virtual satellite that precedes the ISS on the same orbit by 1 minute
+/// let ric_state = sgp4::ric_state(
+///     &constants.propagate(sgp4::MinutesSinceEpoch(0.0))?,
+///     &constants.propagate(sgp4::MinutesSinceEpoch(1.0))?,
+/// );
+/// println!("RIC position = {:?} km", ric_state.position);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn ric_state(reference: &propagator::Prediction, target: &propagator::Prediction) -> RicState {
+    let [radial, in_track, cross_track] = ric_axes(reference);
+
+    // ρ = r₂ - r₁
+    let position = vector::sub(&target.position, &reference.position);
+
+    // ρ̇ = v₂ - v₁
+    let velocity = vector::sub(&target.velocity, &reference.velocity);
+    let position = [
+        vector::dot(&position, &radial),
+        vector::dot(&position, &in_track),
+        vector::dot(&position, &cross_track),
+    ];
+
+    // ω = |r × v| / |r|²
+    let angular_velocity = vector::norm(&vector::cross(&reference.position, &reference.velocity))
+        / vector::dot(&reference.position, &reference.position);
+    RicState {
+        position,
+
+        // ρ̇' = M ρ̇ - ω Ĉ × ρ' (M is the TEME to RIC rotation)
+        velocity: [
+            vector::dot(&velocity, &radial) + angular_velocity * position[1],
+            vector::dot(&velocity, &in_track) - angular_velocity * position[0],
+            vector::dot(&velocity, &cross_track),
+        ],
+    }
+}
+
+// radial, in-track and cross-track unit vectors of a reference satellite in the TEME reference frame
+pub(crate) fn ric_axes(reference: &propagator::Prediction) -> [[f64; 3]; 3] {
+    let radius = vector::norm(&reference.position);
+
+    // h = r × v
+    let angular_momentum = vector::cross(&reference.position, &reference.velocity);
+    let angular_momentum_norm = vector::norm(&angular_momentum);
+
+    // R̂ = r / |r|
+    let radial = [
+        reference.position[0] / radius,
+        reference.position[1] / radius,
+        reference.position[2] / radius,
+    ];
+
+    // Ĉ = h / |h|
+    let cross_track = [
+        angular_momentum[0] / angular_momentum_norm,
+        angular_momentum[1] / angular_momentum_norm,
+        angular_momentum[2] / angular_momentum_norm,
+    ];
+
+    // Î = Ĉ × R̂
+    [radial, vector::cross(&cross_track, &radial), cross_track]
+}
@@ -0,0 +1,175 @@
+use crate::gp;
+use crate::model;
+use crate::observer;
+use crate::propagator;
+
+// number of minutes in a Julian year
+const MINUTES_PER_YEAR: f64 = 365.25 * 24.0 * 60.0;
+
+/// A ground target or station with an elevation constraint
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Target {
+    /// The target's location
+    pub observer: observer::Observer,
+
+    /// The elevation above which a satellite has access to the target in rad
+    pub minimum_elevation: f64,
+}
+
+/// A time interval during which a satellite has access to a target
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Access {
+    /// Index of the satellite in the constellation
+    pub satellite: usize,
+
+    /// Beginning of the access in years since UTC 1 January 2000 12h00 (J2000)
+    ///
+    /// Accesses in progress at the beginning of the window start with the window.
+    pub start: f64,
+
+    /// End of the access in years since UTC 1 January 2000 12h00 (J2000)
+    ///
+    /// Accesses in progress at the end of the window end with the window.
+    pub end: f64,
+}
+
+/// Access intervals and revisit statistics of a constellation over a target
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Revisit {
+    /// Access intervals of all the satellites, sorted by start time
+    pub accesses: alloc::vec::Vec<Access>,
+
+    /// Fraction of the window during which at least one satellite has access to the target, in the range [0, 1]
+    pub coverage: f64,
+
+    /// Mean duration of the gaps between accesses in minutes
+    ///
+    /// The gaps at the beginning and at the end of the window are included. The mean is 0 if there are no gaps.
+    pub mean_gap: f64,
+
+    /// Duration of the longest gap between accesses in minutes
+    ///
+    /// The gaps at the beginning and at the end of the window are included.
+    pub maximum_gap: f64,
+}
+
+/// Computes the access intervals and revisit statistics of a constellation over ground targets
+///
+/// Accesses are calculated with [Constants::passes](struct.Constants.html#method.passes)
+/// and the IAU sidereal time expression, and overlapping accesses of different satellites are merged to calculate the gaps.
+/// The window is expressed in years since J2000 since each satellite has its own epoch.
+///
+/// # Arguments
+///
+/// * `constellation` - The satellites' propagator constants
+/// * `targets` - The ground targets and their elevation constraints
+/// * `start` - The beginning of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `end` - The end of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `step` - The sampling step in minutes (strictly positive), see [Constants::passes](struct.Constants.html#method.passes)
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let constants = sgp4::Constants::from_elements(&elements)?;
+/// let targets = [sgp4::Target {
+///     observer: sgp4::Observer {
+///         latitude: 48.8566_f64.to_radians(),
+///         longitude: 2.3522_f64.to_radians(),
+///         altitude: 0.035,
+///     },
+///     minimum_elevation: 10.0_f64.to_radians(),
+/// }];
+/// let revisits = sgp4::revisit(
+///     &[constants],
+///     &targets,
+///     elements.epoch(),
+///     elements.epoch() + 7.0 / 365.25,
+///     0.5,
+/// )?;
+/// println!("maximum gap: {} h", revisits[0].maximum_gap / 60.0);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn revisit(
+    constellation: &[propagator::Constants],
+    targets: &[Target],
+    start: f64,
+    end: f64,
+    step: f64,
+) -> core::result::Result<alloc::vec::Vec<Revisit>, gp::Error> {
+    targets
+        .iter()
+        .map(|target| {
+            let mut accesses = alloc::vec::Vec::new();
+            for (satellite, constants) in constellation.iter().enumerate() {
+                for pass in constants.passes(
+                    &target.observer,
+                    target.minimum_elevation,
+                    constants.minutes_since_epoch(start),
+                    constants.minutes_since_epoch(end),
+                    step,
+                    model::iau_epoch_to_sidereal_time,
+                )? {
+                    accesses.push(Access {
+                        satellite,
+                        start: pass
+                            .rise
+                            .map_or(start, |rise| constants.julian_years_since_j2000(rise)),
+                        end: pass
+                            .set
+                            .map_or(end, |set| constants.julian_years_since_j2000(set)),
+                    });
+                }
+            }
+            accesses.sort_by(|a, b| a.start.total_cmp(&b.start));
+            let mut covered = 0.0;
+            let mut gaps = 0;
+            let mut total_gap = 0.0;
+            let mut maximum_gap: f64 = 0.0;
+            let mut add_gap = |gap: f64| {
+                if gap > 0.0 {
+                    gaps += 1;
+                    total_gap += gap;
+                    maximum_gap = maximum_gap.max(gap);
+                }
+            };
+            let mut covered_until = start;
+            for access in &accesses {
+                if access.start > covered_until {
+                    add_gap((access.start - covered_until) * MINUTES_PER_YEAR);
+                    covered_until = access.start;
+                }
+                if access.end > covered_until {
+                    covered += access.end - covered_until;
+                    covered_until = access.end;
+                }
+            }
+            if end > covered_until {
+                add_gap((end - covered_until) * MINUTES_PER_YEAR);
+            }
+            Ok(Revisit {
+                accesses,
+                coverage: if end > start {
+                    covered / (end - start)
+                } else {
+                    0.0
+                },
+                mean_gap: if gaps > 0 {
+                    total_gap / gaps as f64
+                } else {
+                    0.0
+                },
+                maximum_gap,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,93 @@
+use crate::model;
+use crate::propagator;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Quasi-nonsingular relative orbital elements of a deputy satellite with respect to a chief satellite
+///
+/// The elements follow D'Amico's definition. They are well defined for near-circular orbits
+/// and are singular only for equatorial chief orbits. Dimensionless elements can be multiplied
+/// by the chief's semi-major axis to obtain distances.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeOrbitalElements {
+    /// Relative semi-major axis δa = (a_d - a_c) / a_c
+    pub semi_major_axis: f64,
+
+    /// Relative mean longitude δλ = (u_d - u_c) + (Ω_d - Ω_c) cos I_c in rad
+    pub mean_longitude: f64,
+
+    /// Relative eccentricity vector x component δeₓ = e_d cos ω_d - e_c cos ω_c
+    pub eccentricity_x: f64,
+
+    /// Relative eccentricity vector y component δeᵧ = e_d sin ω_d - e_c sin ω_c
+    pub eccentricity_y: f64,
+
+    /// Relative inclination vector x component δiₓ = I_d - I_c in rad
+    pub inclination_x: f64,
+
+    /// Relative inclination vector y component δiᵧ = (Ω_d - Ω_c) sin I_c in rad
+    pub inclination_y: f64,
+}
+
+impl RelativeOrbitalElements {
+    /// Calculates the relative orbital elements from the chief's and deputy's orbital elements
+    ///
+    /// Both orbits must use the same kind of elements, either mean elements
+    /// (for instance [Orbit::from_kozai_elements](struct.Orbit.html#method.from_kozai_elements))
+    /// for formation design, or osculating elements
+    /// (for instance [Orbit::from_prediction](struct.Orbit.html#method.from_prediction))
+    /// for monitoring propagated states.
+    ///
+    /// # Arguments
+    ///
+    /// * `chief` - The chief satellite's orbital elements
+    /// * `deputy` - The deputy satellite's orbital elements
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// // a virtual deputy that follows the ISS on the same orbit, 10 seconds behind
+    /// let chief = constants.propagate(sgp4::MinutesSinceEpoch(10.0 / 60.0))?;
+    /// let deputy = constants.propagate(sgp4::MinutesSinceEpoch(0.0))?;
+    /// let relative_orbital_elements = sgp4::RelativeOrbitalElements::from_orbits(
+    ///     &sgp4::Orbit::from_prediction(&sgp4::WGS84, &chief),
+    ///     &sgp4::Orbit::from_prediction(&sgp4::WGS84, &deputy),
+    /// );
+    /// println!("δλ = {} rad", relative_orbital_elements.mean_longitude);
+    ///
+    /// // n × 10 s ≈ 0.0113 rad
+    /// assert!((relative_orbital_elements.mean_longitude + 0.0113).abs() < 1e-4);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_orbits(chief: &propagator::Orbit, deputy: &propagator::Orbit) -> Self {
+        // ΔΩ = Ω_d - Ω_c in the range [-π, π[
+        let right_ascension_difference =
+            model::signed_angle(deputy.right_ascension - chief.right_ascension);
+        RelativeOrbitalElements {
+            // δa = (n_c / n_d)²ᐟ³ - 1
+            semi_major_axis: (chief.mean_motion / deputy.mean_motion).powf(2.0 / 3.0) - 1.0,
+
+            // δλ = (M_d + ω_d) - (M_c + ω_c) + ΔΩ cos I_c
+            mean_longitude: model::signed_angle(
+                (deputy.mean_anomaly + deputy.argument_of_perigee)
+                    - (chief.mean_anomaly + chief.argument_of_perigee)
+                    + right_ascension_difference * chief.inclination.cos(),
+            ),
+            eccentricity_x: deputy.eccentricity * deputy.argument_of_perigee.cos()
+                - chief.eccentricity * chief.argument_of_perigee.cos(),
+            eccentricity_y: deputy.eccentricity * deputy.argument_of_perigee.sin()
+                - chief.eccentricity * chief.argument_of_perigee.sin(),
+            inclination_x: deputy.inclination - chief.inclination,
+            inclination_y: right_ascension_difference * chief.inclination.sin(),
+        }
+    }
+}
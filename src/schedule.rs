@@ -0,0 +1,175 @@
+use crate::gp;
+use crate::model;
+use crate::propagator;
+use crate::revisit;
+
+/// A pass of a satellite over a ground station
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contact {
+    /// Index of the satellite in the constellation
+    pub satellite: usize,
+
+    /// Index of the ground station
+    pub station: usize,
+
+    /// Beginning of the contact in years since UTC 1 January 2000 12h00 (J2000)
+    ///
+    /// Contacts in progress at the beginning of the window start with the window.
+    pub start: f64,
+
+    /// End of the contact in years since UTC 1 January 2000 12h00 (J2000)
+    ///
+    /// Contacts in progress at the end of the window end with the window.
+    pub end: f64,
+
+    /// Maximum elevation during the contact in rad
+    pub maximum_elevation: f64,
+}
+
+impl Contact {
+    /// Returns the contact duration in minutes
+    pub fn duration(&self) -> f64 {
+        (self.end - self.start) * (365.25 * 24.0 * 60.0)
+    }
+
+    /// Returns true if the contacts overlap in time and share a satellite or a station
+    ///
+    /// A station tracks one satellite at a time, and a satellite communicates with one station at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The contact to compare with
+    pub fn conflicts_with(&self, other: &Contact) -> bool {
+        (self.satellite == other.satellite || self.station == other.station)
+            && self.start < other.end
+            && other.start < self.end
+    }
+}
+
+/// Computes the contacts between satellites and ground stations in a time window
+///
+/// Contacts are calculated with [Constants::passes](struct.Constants.html#method.passes)
+/// and the IAU sidereal time expression, and sorted by start time.
+///
+/// # Arguments
+///
+/// * `constellation` - The satellites' propagator constants
+/// * `stations` - The ground stations and their elevation constraints
+/// * `start` - The beginning of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `end` - The end of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `step` - The sampling step in minutes (strictly positive), see [Constants::passes](struct.Constants.html#method.passes)
+pub fn contacts(
+    constellation: &[propagator::Constants],
+    stations: &[revisit::Target],
+    start: f64,
+    end: f64,
+    step: f64,
+) -> core::result::Result<alloc::vec::Vec<Contact>, gp::Error> {
+    let mut contacts = alloc::vec::Vec::new();
+    for (satellite, constants) in constellation.iter().enumerate() {
+        for (station, target) in stations.iter().enumerate() {
+            for pass in constants.passes(
+                &target.observer,
+                target.minimum_elevation,
+                constants.minutes_since_epoch(start),
+                constants.minutes_since_epoch(end),
+                step,
+                model::iau_epoch_to_sidereal_time,
+            )? {
+                contacts.push(Contact {
+                    satellite,
+                    station,
+                    start: pass
+                        .rise
+                        .map_or(start, |rise| constants.julian_years_since_j2000(rise)),
+                    end: pass
+                        .set
+                        .map_or(end, |set| constants.julian_years_since_j2000(set)),
+                    maximum_elevation: pass.maximum_elevation,
+                });
+            }
+        }
+    }
+    contacts.sort_by(|a, b| a.start.total_cmp(&b.start));
+    Ok(contacts)
+}
+
+/// Selects a conflict-free subset of contacts
+///
+/// Contacts are considered by decreasing priority and selected if they do not conflict
+/// with a previously selected contact (see [Contact::conflicts_with](struct.Contact.html#method.conflicts_with)).
+/// The priority function encodes the operator's cost model, for instance the contact duration,
+/// the maximum elevation, or per-satellite weights.
+///
+/// # Arguments
+///
+/// * `contacts` - The candidate contacts, for example calculated with [sgp4::contacts](fn.contacts.html)
+/// * `priority` - A function that returns the priority of a contact (higher values are selected first)
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let elements = sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?;
+/// let stations = [
+///     sgp4::Target {
+///         observer: sgp4::Observer {
+///             latitude: 48.8566_f64.to_radians(),
+///             longitude: 2.3522_f64.to_radians(),
+///             altitude: 0.035,
+///         },
+///         minimum_elevation: 5.0_f64.to_radians(),
+///     },
+///     sgp4::Target {
+///         observer: sgp4::Observer {
+///             latitude: 51.5072_f64.to_radians(),
+///             longitude: -0.1276_f64.to_radians(),
+///             altitude: 0.011,
+///         },
+///         minimum_elevation: 5.0_f64.to_radians(),
+///     },
+/// ];
+/// let contacts = sgp4::contacts(
+///     &[sgp4::Constants::from_elements(&elements)?],
+///     &stations,
+///     elements.epoch(),
+///     elements.epoch() + 1.0 / 365.25,
+///     0.5,
+/// )?;
+/// // favour high passes
+/// for contact in sgp4::schedule(&contacts, |contact| contact.maximum_elevation) {
+///     println!(
+///         "station {}: {} min",
+///         contact.station,
+///         contact.duration()
+///     );
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+pub fn schedule(
+    contacts: &[Contact],
+    mut priority: impl FnMut(&Contact) -> f64,
+) -> alloc::vec::Vec<Contact> {
+    let mut candidates: alloc::vec::Vec<(f64, &Contact)> = contacts
+        .iter()
+        .map(|contact| (priority(contact), contact))
+        .collect();
+    candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let mut selected: alloc::vec::Vec<Contact> = alloc::vec::Vec::new();
+    for (_, candidate) in candidates {
+        if !selected
+            .iter()
+            .any(|contact| contact.conflicts_with(candidate))
+        {
+            selected.push(candidate.clone());
+        }
+    }
+    selected.sort_by(|a, b| a.start.total_cmp(&b.start));
+    selected
+}
@@ -0,0 +1,330 @@
+use crate::conjunction;
+use crate::gp;
+use crate::propagator;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// margin added to the prefilter thresholds in km, accounts for short-period perturbations
+const PREFILTER_MARGIN: f64 = 25.0;
+
+/// A close approach between two catalog objects
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatalogConjunction {
+    /// Index of the first object in the catalog
+    pub primary: usize,
+
+    /// Index of the second object in the catalog (always larger than `primary`)
+    pub secondary: usize,
+
+    /// Close approach geometry
+    pub conjunction: conjunction::Conjunction,
+}
+
+// mean orbit quantities used by the prefilters
+struct Shape {
+    semi_major_axis: f64,
+    eccentricity: f64,
+    perigee: f64,
+    apogee: f64,
+    angular_momentum_direction: [f64; 3],
+    eccentricity_direction: [f64; 3],
+    angular_rate: f64,
+}
+
+impl propagator::Constants {
+    // mean orbit shape at the given time in years since UTC 1 January 2000 12h00 (secular terms only)
+    fn shape(&self, epoch: f64) -> Shape {
+        let t = self.minutes_since_epoch(epoch).0;
+
+        // a = aₑ (kₑ / n₀)²ᐟ³
+        let semi_major_axis = self.geopotential.ae
+            * (self.geopotential.ke / self.orbit_0.mean_motion).powf(2.0 / 3.0);
+        let eccentricity = self.orbit_0.eccentricity;
+
+        // Ω = Ω₀ + Ω̇ t, ω = ω₀ + ω̇ t
+        let right_ascension = self.orbit_0.right_ascension + self.right_ascension_dot * t;
+        let argument_of_perigee =
+            self.orbit_0.argument_of_perigee + self.argument_of_perigee_dot * t;
+        let (sin_i, cos_i) = self.orbit_0.inclination.sin_cos();
+        let (sin_o, cos_o) = right_ascension.sin_cos();
+        let (sin_w, cos_w) = argument_of_perigee.sin_cos();
+        Shape {
+            semi_major_axis,
+            eccentricity,
+
+            // r_p = a (1 - e), r_a = a (1 + e)
+            perigee: semi_major_axis * (1.0 - eccentricity),
+            apogee: semi_major_axis * (1.0 + eccentricity),
+            angular_momentum_direction: [sin_i * sin_o, -sin_i * cos_o, cos_i],
+            eccentricity_direction: [
+                cos_o * cos_w - sin_o * sin_w * cos_i,
+                sin_o * cos_w + cos_o * sin_w * cos_i,
+                sin_w * sin_i,
+            ],
+            angular_rate: self.right_ascension_dot.abs() + self.argument_of_perigee_dot.abs(),
+        }
+    }
+}
+
+// radius of a Keplerian orbit in the direction of a unit vector lying in the orbit plane
+fn radius_towards(shape: &Shape, direction: &[f64; 3]) -> f64 {
+    // r = a (1 - e²) / (1 + e cos ν)
+    let cos_true_anomaly = vector::dot(&shape.eccentricity_direction, direction);
+    shape.semi_major_axis * (1.0 - shape.eccentricity.powi(2))
+        / (1.0 + shape.eccentricity * cos_true_anomaly)
+}
+
+// half the window duration in minutes
+fn half_window(start: f64, end: f64) -> f64 {
+    0.5 * (end - start).abs() * (365.25 * 24.0 * 60.0)
+}
+
+// prefilters applied to the shapes at the middle of the window, see conjunction_possible
+fn shapes_possible(a: &Shape, b: &Shape, half_window: f64, threshold: f64) -> bool {
+    let distance = threshold + PREFILTER_MARGIN;
+
+    // apogee / perigee filter
+    if a.perigee.max(b.perigee) - a.apogee.min(b.apogee) > distance {
+        return false;
+    }
+
+    // orbit path filter
+    let node = vector::cross(&a.angular_momentum_direction, &b.angular_momentum_direction);
+    let sin_relative_inclination = vector::norm(&node);
+    if sin_relative_inclination < 1.0e-3 {
+        return true;
+    }
+    let node = [
+        node[0] / sin_relative_inclination,
+        node[1] / sin_relative_inclination,
+        node[2] / sin_relative_inclination,
+    ];
+
+    // the planes are closer than the distance within δ of the nodes, sin δ = d / (r sin I)
+    let node_window = (distance / (a.apogee.min(b.apogee) * sin_relative_inclination))
+        .min(1.0)
+        .asin()
+        + half_window * (a.angular_rate + b.angular_rate) / sin_relative_inclination;
+
+    // |dr/dν| ≤ a e for small eccentricities
+    let radial_slack = (a.semi_major_axis * a.eccentricity + b.semi_major_axis * b.eccentricity)
+        * node_window.min(core::f64::consts::PI);
+    [node, [-node[0], -node[1], -node[2]]]
+        .iter()
+        .any(|direction| {
+            (radius_towards(a, direction) - radius_towards(b, direction)).abs()
+                <= distance + radial_slack
+        })
+}
+
+/// Returns false if two objects cannot come closer than a threshold in a time window
+///
+/// Two geometric filters are applied to the mean orbits:
+/// - the apogee / perigee filter discards pairs whose radial ranges do not overlap,
+/// - the orbit path filter discards pairs whose radii differ at both mutual nodes,
+///   accounting for the angular region around the nodes in which the planes are closer than the threshold,
+///   and for the secular motion of the node and the perigee during the window.
+///
+/// A margin accounts for short-period perturbations. The filters neglect drag, hence the window
+/// should be short (a few days) for decaying objects.
+///
+/// # Arguments
+///
+/// * `a` - The first satellite's propagator constants
+/// * `b` - The second satellite's propagator constants
+/// * `start` - The beginning of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `end` - The end of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `threshold` - The maximum miss distance in km
+pub fn conjunction_possible(
+    a: &propagator::Constants,
+    b: &propagator::Constants,
+    start: f64,
+    end: f64,
+    threshold: f64,
+) -> bool {
+    let middle = 0.5 * (start + end);
+    shapes_possible(
+        &a.shape(middle),
+        &b.shape(middle),
+        half_window(start, end),
+        threshold,
+    )
+}
+
+// prefilters and screens the pairs (primary, secondary) with secondary > primary
+fn screen_primary(
+    catalog: &[propagator::Constants],
+    shapes: &[Shape],
+    primary: usize,
+    start: f64,
+    end: f64,
+    threshold: f64,
+    conjunctions: &mut alloc::vec::Vec<CatalogConjunction>,
+) -> core::result::Result<(), gp::Error> {
+    let half_window = half_window(start, end);
+    for secondary in (primary + 1)..catalog.len() {
+        if shapes_possible(&shapes[primary], &shapes[secondary], half_window, threshold) {
+            for conjunction in conjunction::conjunctions(
+                &catalog[primary],
+                &catalog[secondary],
+                start,
+                end,
+                threshold,
+            )? {
+                conjunctions.push(CatalogConjunction {
+                    primary,
+                    secondary,
+                    conjunction,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Finds the close approaches between all the pairs of objects in a catalog
+///
+/// Pairs are first discarded with [sgp4::conjunction_possible](fn.conjunction_possible.html),
+/// and the remaining pairs are screened with [sgp4::conjunctions](fn.conjunctions.html).
+/// The mean orbit shape of each object is calculated once for the whole catalog.
+/// If the feature `std` is enabled, the prefilter and the screening run in parallel on all the available cores,
+/// and threads take objects from a shared queue so that uneven pair costs do not leave cores idle.
+/// The conjunctions are sorted by time of closest approach, then by primary and secondary index.
+///
+/// # Arguments
+///
+/// * `catalog` - The objects' propagator constants
+/// * `start` - The beginning of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `end` - The end of the window in years since UTC 1 January 2000 12h00 (J2000)
+/// * `threshold` - The maximum miss distance in km
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let tle = (
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// );
+/// let epoch = sgp4::Elements::from_tle(None, tle.0, tle.1)?.epoch();
+/// // virtual satellites in planes separated by 10°
+/// let catalog = (0..8)
+///     .map(|plane| {
+///         let mut elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+///         elements.right_ascension += 10.0 * plane as f64;
+///         Ok(sgp4::Constants::from_elements(&elements)?)
+///     })
+///     .collect::<anyhow::Result<Vec<_>>>()?;
+/// for conjunction in sgp4::screen_catalog(&catalog, epoch, epoch + 1.0 / 365.25, 100.0)? {
+///     println!(
+///         "{} / {}: {} km",
+///         conjunction.primary, conjunction.secondary, conjunction.conjunction.miss_distance
+///     );
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+pub fn screen_catalog(
+    catalog: &[propagator::Constants],
+    start: f64,
+    end: f64,
+    threshold: f64,
+) -> core::result::Result<alloc::vec::Vec<CatalogConjunction>, gp::Error> {
+    let middle = 0.5 * (start + end);
+    let shapes: alloc::vec::Vec<Shape> = catalog
+        .iter()
+        .map(|constants| constants.shape(middle))
+        .collect();
+    #[cfg(feature = "std")]
+    let mut conjunctions = {
+        // each thread takes the next primary object from a shared counter, prefilters its pairs
+        // and screens them immediately, the primaries with the most pairs are handed out first
+        // the primaries below the first failure are always screened, hence the returned error
+        // is that of the lowest failing primary, as without threads
+        let next_primary = core::sync::atomic::AtomicUsize::new(0);
+        let first_failure = core::sync::atomic::AtomicUsize::new(usize::MAX);
+        let threads = std::thread::available_parallelism()
+            .map_or(1, |threads| threads.get())
+            .min(catalog.len())
+            .max(1);
+        std::thread::scope(|scope| {
+            let handles = (0..threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut conjunctions = alloc::vec::Vec::new();
+                        loop {
+                            let primary =
+                                next_primary.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+                            if primary >= catalog.len()
+                                || primary
+                                    > first_failure.load(core::sync::atomic::Ordering::Relaxed)
+                            {
+                                return Ok(conjunctions);
+                            }
+                            if let Err(error) = screen_primary(
+                                catalog,
+                                &shapes,
+                                primary,
+                                start,
+                                end,
+                                threshold,
+                                &mut conjunctions,
+                            ) {
+                                first_failure
+                                    .fetch_min(primary, core::sync::atomic::Ordering::Relaxed);
+                                return Err((primary, error));
+                            }
+                        }
+                    })
+                })
+                .collect::<alloc::vec::Vec<_>>();
+            let mut conjunctions = alloc::vec::Vec::new();
+            let mut first_error: Option<(usize, gp::Error)> = None;
+            for handle in handles {
+                match handle.join() {
+                    Ok(Ok(thread_conjunctions)) => conjunctions.extend(thread_conjunctions),
+                    Ok(Err((primary, error))) => {
+                        if !matches!(first_error, Some((first_primary, _)) if first_primary < primary)
+                        {
+                            first_error = Some((primary, error));
+                        }
+                    }
+                    Err(payload) => std::panic::resume_unwind(payload),
+                }
+            }
+            match first_error {
+                Some((_, error)) => Err(error),
+                None => Ok(conjunctions),
+            }
+        })?
+    };
+    #[cfg(not(feature = "std"))]
+    let mut conjunctions = {
+        let mut conjunctions = alloc::vec::Vec::new();
+        for primary in 0..catalog.len() {
+            screen_primary(
+                catalog,
+                &shapes,
+                primary,
+                start,
+                end,
+                threshold,
+                &mut conjunctions,
+            )?;
+        }
+        conjunctions
+    };
+
+    // the pair indices make the order independent of the thread scheduling
+    conjunctions.sort_by(|a, b| {
+        a.conjunction
+            .tca
+            .total_cmp(&b.conjunction.tca)
+            .then(a.primary.cmp(&b.primary))
+            .then(a.secondary.cmp(&b.secondary))
+    });
+    Ok(conjunctions)
+}
@@ -0,0 +1,75 @@
+use crate::tle;
+
+/// Rule used to choose between several element sets of a satellite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectionPolicy {
+    /// The element set whose epoch is closest to the target time, before or after it
+    Nearest,
+
+    /// The most recent element set whose epoch is before or at the target time
+    ///
+    /// This policy reproduces what an operator could have known at the target time.
+    NearestNotAfter,
+
+    /// The oldest element set whose epoch is after or at the target time
+    NearestNotBefore,
+}
+
+/// Returns the element set that best matches a target time
+///
+/// Returns `None` if no element set satisfies the policy. Ties are resolved in favour of the first candidate.
+///
+/// # Arguments
+///
+/// * `candidates` - The element sets of a single satellite, in any order
+/// * `epoch` - The target time in years since UTC 1 January 2000 12h00 (J2000)
+/// * `policy` - The selection rule
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let history = sgp4::parse_2les(
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+/// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+/// ",
+/// )?;
+/// let target = history[0].epoch() + 0.5 / 365.25;
+/// if let Some(elements) = sgp4::select_elements(
+///     &history,
+///     target,
+///     sgp4::SelectionPolicy::NearestNotAfter,
+/// ) {
+///     let constants = sgp4::Constants::from_elements(elements)?;
+///     let prediction = constants.propagate(constants.minutes_since_epoch(target))?;
+///     println!("r = {:?} km", prediction.position);
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+pub fn select_elements<'a, I: IntoIterator<Item = &'a tle::Elements>>(
+    candidates: I,
+    epoch: f64,
+    policy: SelectionPolicy,
+) -> Option<&'a tle::Elements> {
+    candidates
+        .into_iter()
+        .filter_map(|elements| {
+            // Δy = y₀ - y
+            let offset = elements.epoch() - epoch;
+            match policy {
+                SelectionPolicy::Nearest => Some((elements, offset.abs())),
+                SelectionPolicy::NearestNotAfter => (offset <= 0.0).then_some((elements, -offset)),
+                SelectionPolicy::NearestNotBefore => (offset >= 0.0).then_some((elements, offset)),
+            }
+        })
+        .fold(
+            None,
+            |best: Option<(&'a tle::Elements, f64)>, (elements, distance)| match best {
+                Some((_, best_distance)) if best_distance <= distance => best,
+                _ => Some((elements, distance)),
+            },
+        )
+        .map(|(elements, _)| elements)
+}
@@ -0,0 +1,60 @@
+use crate::ephemeris;
+use crate::model;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+impl propagator::Constants {
+    /// Returns the mean local time of the ascending node in hours, in the range [0, 24[
+    ///
+    /// The local time is the mean solar time at the ascending node, calculated from the
+    /// secular right ascension of the ascending node and the right ascension of the Sun.
+    /// It is nearly constant for Sun-synchronous orbits.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// println!(
+    ///     "LTDN = {} h",
+    ///     constants.local_time_of_descending_node(sgp4::MinutesSinceEpoch(0.0))
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn local_time_of_ascending_node(&self, t: MinutesSinceEpoch) -> f64 {
+        // Ω = Ω₀ + Ω̇ t + k₀ t²
+        let right_ascension =
+            self.orbit_0.right_ascension + self.right_ascension_dot * t.0 + self.k0 * t.0.powi(2);
+        let sun_position = ephemeris::sun_position(self.julian_years_since_j2000(t));
+
+        // LTAN = 12 + 12 (Ω - α☉) / π rem 24
+        12.0 / core::f64::consts::PI
+            * model::positive_angle(
+                right_ascension - sun_position[1].atan2(sun_position[0]) + core::f64::consts::PI,
+            )
+    }
+
+    /// Returns the mean local time of the descending node in hours, in the range [0, 24[
+    ///
+    /// See [Constants::local_time_of_ascending_node](struct.Constants.html#method.local_time_of_ascending_node).
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+    pub fn local_time_of_descending_node(&self, t: MinutesSinceEpoch) -> f64 {
+        // LTDN = LTAN + 12 rem 24
+        (self.local_time_of_ascending_node(t) + 12.0) % 24.0
+    }
+}
@@ -0,0 +1,501 @@
+use chrono::{Datelike, Timelike};
+
+// Space-Track base URL prepended to query paths
+pub(crate) const SPACE_TRACK_URL: &str = "https://www.space-track.org";
+
+// percent-encodes a URL component (RFC 3986 unreserved characters are kept)
+pub(crate) fn percent_encode(value: &str) -> alloc::string::String {
+    let mut encoded = alloc::string::String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&alloc::format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// A Space-Track request class (the table that is queried)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceTrackClass {
+    /// Latest General Perturbations (GP) element set of each object
+    Gp,
+
+    /// All the historical GP element sets
+    GpHistory,
+
+    /// Satellite catalog (launch, decay and physical information)
+    Satcat,
+
+    /// Reentry messages
+    Decay,
+
+    /// Tracking and Impact Prediction (TIP) messages
+    Tip,
+
+    /// Public Conjunction Data Messages
+    CdmPublic,
+}
+
+impl SpaceTrackClass {
+    // name used in query paths
+    fn as_str(&self) -> &'static str {
+        match self {
+            SpaceTrackClass::Gp => "gp",
+            SpaceTrackClass::GpHistory => "gp_history",
+            SpaceTrackClass::Satcat => "satcat",
+            SpaceTrackClass::Decay => "decay",
+            SpaceTrackClass::Tip => "tip",
+            SpaceTrackClass::CdmPublic => "cdm_public",
+        }
+    }
+}
+
+/// Response format of a Space-Track query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpaceTrackFormat {
+    /// JSON array of objects, parsed by `serde_json` into `Vec<Elements>` for GP classes
+    #[default]
+    Json,
+
+    /// Two-Line Element Sets
+    Tle,
+
+    /// Two-Line Element Sets preceded by a title line
+    ThreeLe,
+
+    /// CCSDS XML
+    Xml,
+
+    /// CCSDS Keyword-Value Notation
+    Kvn,
+
+    /// Comma-separated values
+    Csv,
+}
+
+impl SpaceTrackFormat {
+    // name used in query paths
+    fn as_str(&self) -> &'static str {
+        match self {
+            SpaceTrackFormat::Json => "json",
+            SpaceTrackFormat::Tle => "tle",
+            SpaceTrackFormat::ThreeLe => "3le",
+            SpaceTrackFormat::Xml => "xml",
+            SpaceTrackFormat::Kvn => "kvn",
+            SpaceTrackFormat::Csv => "csv",
+        }
+    }
+}
+
+/// A time used in Space-Track predicates, either absolute or relative to the query time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpaceTrackTime {
+    /// An absolute UTC datetime
+    Datetime(chrono::NaiveDateTime),
+
+    /// The query time plus the given number of days (negative values are in the past)
+    Now(f64),
+}
+
+impl From<chrono::NaiveDateTime> for SpaceTrackTime {
+    fn from(value: chrono::NaiveDateTime) -> Self {
+        SpaceTrackTime::Datetime(value)
+    }
+}
+
+/// A value type that can be compared with a Space-Track field
+pub trait SpaceTrackValue {
+    /// Returns the value formatted and percent-encoded for a query path
+    fn to_query_string(&self) -> alloc::string::String;
+}
+
+impl SpaceTrackValue for u64 {
+    fn to_query_string(&self) -> alloc::string::String {
+        alloc::format!("{}", self)
+    }
+}
+
+impl SpaceTrackValue for f64 {
+    fn to_query_string(&self) -> alloc::string::String {
+        alloc::format!("{}", self)
+    }
+}
+
+impl SpaceTrackValue for alloc::string::String {
+    fn to_query_string(&self) -> alloc::string::String {
+        percent_encode(self)
+    }
+}
+
+impl SpaceTrackValue for SpaceTrackTime {
+    fn to_query_string(&self) -> alloc::string::String {
+        match self {
+            SpaceTrackTime::Datetime(datetime) => {
+                let mut result = alloc::format!(
+                    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                    datetime.year(),
+                    datetime.month(),
+                    datetime.day(),
+                    datetime.hour(),
+                    datetime.minute(),
+                    datetime.second()
+                );
+                if datetime.nanosecond() > 0 {
+                    result.push_str(
+                        alloc::format!(".{:09}", datetime.nanosecond()).trim_end_matches('0'),
+                    );
+                }
+                result
+            }
+            SpaceTrackTime::Now(days) if *days == 0.0 => "now".into(),
+            SpaceTrackTime::Now(days) if *days > 0.0 => alloc::format!("now+{}", days),
+            SpaceTrackTime::Now(days) => alloc::format!("now{}", days),
+        }
+    }
+}
+
+/// A Space-Track field whose values have the type `T`
+///
+/// The associated constants list common fields of the `gp`, `gp_history` and `satcat` classes.
+/// Fields are not checked against the class, Space-Track rejects queries with unknown fields.
+#[derive(Debug)]
+pub struct SpaceTrackField<T> {
+    name: &'static str,
+    value_type: core::marker::PhantomData<T>,
+}
+
+impl<T> Clone for SpaceTrackField<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SpaceTrackField<T> {}
+
+impl<T: SpaceTrackValue> SpaceTrackField<T> {
+    /// Creates a field that is not listed in the associated constants
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The field name as listed in Space-Track's model definition, for example `"CREATION_DATE"`
+    pub const fn new(name: &'static str) -> Self {
+        SpaceTrackField {
+            name,
+            value_type: core::marker::PhantomData,
+        }
+    }
+}
+
+impl SpaceTrackField<u64> {
+    /// The satellite catalog number
+    pub const NORAD_CAT_ID: Self = Self::new("NORAD_CAT_ID");
+
+    /// The element set number
+    pub const ELEMENT_SET_NO: Self = Self::new("ELEMENT_SET_NO");
+
+    /// The revolution number at epoch
+    pub const REV_AT_EPOCH: Self = Self::new("REV_AT_EPOCH");
+
+    /// The unique identifier of a GP element set
+    pub const GP_ID: Self = Self::new("GP_ID");
+}
+
+impl SpaceTrackField<f64> {
+    /// Mean number of orbits per day
+    pub const MEAN_MOTION: Self = Self::new("MEAN_MOTION");
+
+    /// The shape of the orbit
+    pub const ECCENTRICITY: Self = Self::new("ECCENTRICITY");
+
+    /// Angle between the equator and the orbit plane in deg
+    pub const INCLINATION: Self = Self::new("INCLINATION");
+
+    /// Radiation pressure coefficient in earth radii⁻¹
+    pub const BSTAR: Self = Self::new("BSTAR");
+
+    /// Orbital period in min
+    pub const PERIOD: Self = Self::new("PERIOD");
+
+    /// Altitude of the apogee in km
+    pub const APOAPSIS: Self = Self::new("APOAPSIS");
+
+    /// Altitude of the perigee in km
+    pub const PERIAPSIS: Self = Self::new("PERIAPSIS");
+
+    /// Semi-major axis in km
+    pub const SEMIMAJOR_AXIS: Self = Self::new("SEMIMAJOR_AXIS");
+}
+
+impl SpaceTrackField<alloc::string::String> {
+    /// The name of the object
+    pub const OBJECT_NAME: Self = Self::new("OBJECT_NAME");
+
+    /// The international designator, for example `1998-067A`
+    pub const OBJECT_ID: Self = Self::new("OBJECT_ID");
+
+    /// The object type (`PAYLOAD`, `ROCKET BODY`, `DEBRIS`, `UNKNOWN` or `TBA`)
+    pub const OBJECT_TYPE: Self = Self::new("OBJECT_TYPE");
+
+    /// The owner's country code
+    pub const COUNTRY_CODE: Self = Self::new("COUNTRY_CODE");
+
+    /// The radar cross-section size (`SMALL`, `MEDIUM` or `LARGE`)
+    pub const RCS_SIZE: Self = Self::new("RCS_SIZE");
+}
+
+impl SpaceTrackField<SpaceTrackTime> {
+    /// The elements' epoch
+    pub const EPOCH: Self = Self::new("EPOCH");
+
+    /// The time at which the element set was published
+    pub const CREATION_DATE: Self = Self::new("CREATION_DATE");
+
+    /// The launch date
+    pub const LAUNCH_DATE: Self = Self::new("LAUNCH_DATE");
+
+    /// The decay date, null if the object is still in orbit
+    pub const DECAY_DATE: Self = Self::new("DECAY_DATE");
+}
+
+/// A condition on the value of a Space-Track field
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpaceTrackPredicate<T> {
+    /// The field is equal to one of the values
+    OneOf(alloc::vec::Vec<T>),
+
+    /// The field is not equal to the value
+    NotEqual(T),
+
+    /// The field is strictly larger than the value
+    GreaterThan(T),
+
+    /// The field is strictly smaller than the value
+    LessThan(T),
+
+    /// The field is in the inclusive range [first, second]
+    Between(T, T),
+
+    /// The field contains the text
+    Like(alloc::string::String),
+
+    /// The field starts with the text
+    StartsWith(alloc::string::String),
+
+    /// The field is null
+    IsNull,
+
+    /// The field is not null
+    IsNotNull,
+}
+
+impl<T: SpaceTrackValue> SpaceTrackPredicate<T> {
+    // predicate formatted for a query path
+    fn to_query_string(&self) -> alloc::string::String {
+        match self {
+            SpaceTrackPredicate::OneOf(values) => values
+                .iter()
+                .map(SpaceTrackValue::to_query_string)
+                .collect::<alloc::vec::Vec<_>>()
+                .join(","),
+            SpaceTrackPredicate::NotEqual(value) => {
+                alloc::format!("<>{}", value.to_query_string())
+            }
+            SpaceTrackPredicate::GreaterThan(value) => {
+                alloc::format!(">{}", value.to_query_string())
+            }
+            SpaceTrackPredicate::LessThan(value) => {
+                alloc::format!("<{}", value.to_query_string())
+            }
+            SpaceTrackPredicate::Between(first, second) => {
+                alloc::format!("{}--{}", first.to_query_string(), second.to_query_string())
+            }
+            SpaceTrackPredicate::Like(text) => alloc::format!("~~{}", percent_encode(text)),
+            SpaceTrackPredicate::StartsWith(text) => alloc::format!("^{}", percent_encode(text)),
+            SpaceTrackPredicate::IsNull => "null-val".into(),
+            SpaceTrackPredicate::IsNotNull => "<>null-val".into(),
+        }
+    }
+}
+
+/// Sort direction of a Space-Track query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest values first
+    Ascending,
+
+    /// Largest values first
+    Descending,
+}
+
+/// Builds Space-Track REST query paths
+///
+/// The builder does not send requests. The resulting path can be passed to
+/// [GpClient::space_track](struct.GpClient.html#method.space_track) or appended to `https://www.space-track.org`.
+///
+/// # Example
+///
+/// ```
+/// let query = sgp4::SpaceTrackQuery::new(sgp4::SpaceTrackClass::Gp)
+///     .filter(
+///         sgp4::SpaceTrackField::EPOCH,
+///         sgp4::SpaceTrackPredicate::GreaterThan(sgp4::SpaceTrackTime::Now(-30.0)),
+///     )
+///     .filter(
+///         sgp4::SpaceTrackField::DECAY_DATE,
+///         sgp4::SpaceTrackPredicate::IsNull,
+///     )
+///     .filter(
+///         sgp4::SpaceTrackField::MEAN_MOTION,
+///         sgp4::SpaceTrackPredicate::Between(11.25, 20.0),
+///     )
+///     .order_by(sgp4::SpaceTrackField::NORAD_CAT_ID, sgp4::SortOrder::Ascending)
+///     .limit(20);
+/// assert_eq!(
+///     query.path(),
+///     "/basicspacedata/query/class/gp/EPOCH/%3Enow-30/DECAY_DATE/null-val/\
+///      MEAN_MOTION/11.25--20/orderby/NORAD_CAT_ID%20asc/limit/20/format/json",
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpaceTrackQuery {
+    class: SpaceTrackClass,
+    predicates: alloc::vec::Vec<(&'static str, alloc::string::String)>,
+    order_by: alloc::vec::Vec<(&'static str, SortOrder)>,
+    limit: Option<(u64, u64)>,
+    distinct: bool,
+    format: SpaceTrackFormat,
+}
+
+impl SpaceTrackQuery {
+    /// Creates a query that returns all the rows of a class in JSON format
+    ///
+    /// # Arguments
+    ///
+    /// * `class` - The queried class
+    pub fn new(class: SpaceTrackClass) -> Self {
+        SpaceTrackQuery {
+            class,
+            predicates: alloc::vec::Vec::new(),
+            order_by: alloc::vec::Vec::new(),
+            limit: None,
+            distinct: false,
+            format: SpaceTrackFormat::Json,
+        }
+    }
+
+    /// Adds a condition, rows must satisfy all the conditions
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The constrained field
+    /// * `predicate` - The condition, whose values have the field's type
+    pub fn filter<T: SpaceTrackValue>(
+        mut self,
+        field: SpaceTrackField<T>,
+        predicate: SpaceTrackPredicate<T>,
+    ) -> Self {
+        self.predicates
+            .push((field.name, predicate.to_query_string()));
+        self
+    }
+
+    /// Adds a sort key, rows are sorted by the first key, then by the second key...
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The sort field
+    /// * `order` - The sort direction
+    pub fn order_by<T>(mut self, field: SpaceTrackField<T>, order: SortOrder) -> Self {
+        self.order_by.push((field.name, order));
+        self
+    }
+
+    /// Returns at most `count` rows
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The maximum number of rows
+    pub fn limit(mut self, count: u64) -> Self {
+        self.limit = Some((count, 0));
+        self
+    }
+
+    /// Returns at most `count` rows, skipping the first `offset` rows
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The maximum number of rows
+    /// * `offset` - The number of rows to skip
+    pub fn limit_with_offset(mut self, count: u64, offset: u64) -> Self {
+        self.limit = Some((count, offset));
+        self
+    }
+
+    /// Removes duplicate rows
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Sets the response format (JSON by default)
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The response format
+    pub fn format(mut self, format: SpaceTrackFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Returns the query path, starting with `/basicspacedata/query/class/`
+    pub fn path(&self) -> alloc::string::String {
+        let mut path = alloc::format!("/basicspacedata/query/class/{}", self.class.as_str());
+        for (name, predicate) in &self.predicates {
+            path.push('/');
+            path.push_str(name);
+            path.push('/');
+            path.push_str(&percent_encode_operators(predicate));
+        }
+        if !self.order_by.is_empty() {
+            path.push_str("/orderby/");
+            for (index, (name, order)) in self.order_by.iter().enumerate() {
+                if index > 0 {
+                    path.push(',');
+                }
+                path.push_str(name);
+                path.push_str(match order {
+                    SortOrder::Ascending => "%20asc",
+                    SortOrder::Descending => "%20desc",
+                });
+            }
+        }
+        if let Some((count, offset)) = self.limit {
+            if offset > 0 {
+                path.push_str(&alloc::format!("/limit/{},{}", count, offset));
+            } else {
+                path.push_str(&alloc::format!("/limit/{}", count));
+            }
+        }
+        if self.distinct {
+            path.push_str("/distinct/true");
+        }
+        path.push_str("/format/");
+        path.push_str(self.format.as_str());
+        path
+    }
+
+    /// Returns the full query URL
+    pub fn url(&self) -> alloc::string::String {
+        alloc::format!("{}{}", SPACE_TRACK_URL, self.path())
+    }
+}
+
+// encodes the comparison operators, which are not valid in URL paths (values are already encoded)
+fn percent_encode_operators(predicate: &str) -> alloc::string::String {
+    predicate
+        .replace('<', "%3C")
+        .replace('>', "%3E")
+        .replace('^', "%5E")
+}
@@ -0,0 +1,86 @@
+use crate::tle;
+
+/// Classification of an element set's age at a propagation time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AgeStatus {
+    /// The age is below the warning threshold
+    Fresh,
+
+    /// The age is between the warning and rejection thresholds
+    Warning,
+
+    /// The age is above the rejection threshold, predictions should not be used
+    Rejected,
+}
+
+/// Element set age thresholds
+///
+/// Ages are absolute values, hence the thresholds also apply to propagation before the epoch.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgeThresholds {
+    /// Age above which predictions should be flagged in days
+    pub warning: f64,
+
+    /// Age above which predictions should be discarded in days
+    pub rejection: f64,
+}
+
+impl Default for AgeThresholds {
+    /// Returns a warning threshold of 3 days and a rejection threshold of 7 days
+    fn default() -> Self {
+        AgeThresholds {
+            warning: 3.0,
+            rejection: 7.0,
+        }
+    }
+}
+
+impl AgeThresholds {
+    /// Classifies an element set age
+    ///
+    /// # Arguments
+    ///
+    /// * `age` - The element set age in days, for example calculated with [Elements::age](struct.Elements.html#method.age)
+    pub fn classify(&self, age: f64) -> AgeStatus {
+        if age.abs() > self.rejection {
+            AgeStatus::Rejected
+        } else if age.abs() > self.warning {
+            AgeStatus::Warning
+        } else {
+            AgeStatus::Fresh
+        }
+    }
+}
+
+impl tle::Elements {
+    /// Returns the age of the elements at a propagation time in days
+    ///
+    /// The age is negative if the propagation time is before the elements' epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The propagation time in years since UTC 1 January 2000 12h00 (J2000)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let age = elements.age(elements.epoch() + 10.0 / 365.25);
+    /// assert_eq!(
+    ///     sgp4::AgeThresholds::default().classify(age),
+    ///     sgp4::AgeStatus::Rejected,
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn age(&self, epoch: f64) -> f64 {
+        (epoch - self.epoch()) * 365.25
+    }
+}
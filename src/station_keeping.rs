@@ -0,0 +1,110 @@
+use crate::events;
+use crate::gp;
+use crate::model;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A geostationary slot: the box in which a satellite must be kept
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StationKeepingBox {
+    /// Longitude of the slot's centre in rad (positive east)
+    pub longitude: f64,
+
+    /// Largest allowed angle between the sub-satellite longitude and the slot's centre in rad
+    pub longitude_tolerance: f64,
+
+    /// Largest allowed inclination in rad
+    pub maximum_inclination: f64,
+}
+
+/// A time interval during which a satellite is outside its station-keeping box
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxViolation {
+    /// Time at which the satellite leaves the box in minutes since epoch
+    ///
+    /// `None` if the satellite is already outside the box at the beginning of the window.
+    pub start: Option<MinutesSinceEpoch>,
+
+    /// Time at which the satellite returns to the box in minutes since epoch
+    ///
+    /// `None` if the satellite is still outside the box at the end of the window.
+    pub end: Option<MinutesSinceEpoch>,
+
+    /// Time spent outside the box within the window in minutes
+    pub duration: f64,
+}
+
+impl propagator::Constants {
+    /// Finds the time intervals during which a geosynchronous satellite is outside a station-keeping box
+    ///
+    /// The box is violated if the sub-satellite longitude or the osculating inclination exceeds its tolerance.
+    /// Since the longitude oscillates daily with the eccentricity, the step should be a fraction of a day.
+    ///
+    /// # Arguments
+    ///
+    /// * `station_keeping_box` - The slot definition
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `step` - The sampling step in minutes (strictly positive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     None,
+    ///     "1 25954U 99060A   04039.68057285 -.00000108  00000-0  00000-0 0  6847".as_bytes(),
+    ///     "2 25954   0.0004 243.8136 0001765  15.5294  22.7134  1.00271289 15615".as_bytes(),
+    /// )?)?;
+    /// let station_keeping_box = sgp4::StationKeepingBox {
+    ///     longitude: -101.0_f64.to_radians(),
+    ///     longitude_tolerance: 0.1_f64.to_radians(),
+    ///     maximum_inclination: 0.1_f64.to_radians(),
+    /// };
+    /// for violation in constants.box_violations(
+    ///     &station_keeping_box,
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(30.0 * 24.0 * 60.0),
+    ///     60.0,
+    /// )? {
+    ///     println!("{:?} -> {:?} ({} min)", violation.start, violation.end, violation.duration);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn box_violations(
+        &self,
+        station_keeping_box: &StationKeepingBox,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        step: f64,
+    ) -> core::result::Result<alloc::vec::Vec<BoxViolation>, gp::Error> {
+        Ok(events::positive_intervals(start, end, step, 1e-6, |t| {
+            let prediction = self.propagate(t)?;
+
+            // i = cos⁻¹(hz / |h|)
+            let angular_momentum = vector::cross(&prediction.position, &prediction.velocity);
+            let inclination = (angular_momentum[2] / vector::norm(&angular_momentum))
+                .clamp(-1.0, 1.0)
+                .acos();
+            let longitude_offset =
+                model::signed_angle(self.longitude(t)? - station_keeping_box.longitude).abs();
+            Ok((longitude_offset - station_keeping_box.longitude_tolerance)
+                .max(inclination - station_keeping_box.maximum_inclination))
+        })?
+        .into_iter()
+        .map(|(violation_start, violation_end)| BoxViolation {
+            start: violation_start,
+            end: violation_end,
+            duration: violation_end.map_or(end.0, |t| t.0)
+                - violation_start.map_or(start.0, |t| t.0),
+        })
+        .collect())
+    }
+}
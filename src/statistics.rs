@@ -0,0 +1,259 @@
+use crate::catalog;
+use crate::model;
+use crate::tle;
+use crate::uncertainty;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Scalar property of an element set used to sort, group and summarize catalogs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CatalogKey {
+    /// Epoch in years since UTC 1 January 2000 12h00 (J2000)
+    Epoch,
+
+    /// Inclination in degrees
+    Inclination,
+
+    /// Eccentricity (unitless)
+    Eccentricity,
+
+    /// Kozai mean motion in revolutions per day
+    MeanMotion,
+
+    /// Orbital period in minutes (WGS84 model)
+    Period,
+
+    /// Mean perigee altitude above the equatorial radius in km (WGS84 model)
+    PerigeeAltitude,
+}
+
+impl CatalogKey {
+    /// Returns the property of an element set
+    ///
+    /// The period and the perigee altitude are NaN if the mean motion is not strictly positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The element set
+    pub fn value(&self, elements: &tle::Elements) -> f64 {
+        match self {
+            CatalogKey::Epoch => elements.epoch(),
+            CatalogKey::Inclination => elements.inclination,
+            CatalogKey::Eccentricity => elements.eccentricity,
+            CatalogKey::MeanMotion => elements.mean_motion,
+            CatalogKey::Period => elements.period(&model::WGS84),
+            CatalogKey::PerigeeAltitude => elements.perigee_altitude(&model::WGS84),
+        }
+    }
+}
+
+/// Summary statistics of a catalog property
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatalogStatistics {
+    /// Number of element sets with a defined value
+    pub count: usize,
+
+    /// Smallest value
+    pub minimum: f64,
+
+    /// Largest value
+    pub maximum: f64,
+
+    /// Arithmetic mean
+    pub mean: f64,
+
+    /// Median (mean of the two central values if the count is even)
+    pub median: f64,
+
+    /// Population standard deviation
+    pub standard_deviation: f64,
+}
+
+impl catalog::Catalog {
+    /// Returns the element sets sorted by increasing value of a property
+    ///
+    /// Element sets with the same value keep their NORAD ID order,
+    /// and element sets whose value is NaN are placed last.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The property used to sort the catalog
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let catalog: sgp4::Catalog = sgp4::parse_3les(
+    ///     "ISS (ZARYA)
+    /// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+    /// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+    /// MOLNIYA 1-36
+    /// 1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+    /// 2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656
+    /// ",
+    /// )?
+    /// .into_iter()
+    /// .collect();
+    /// let by_period = catalog.sorted_by(sgp4::CatalogKey::Period);
+    /// assert_eq!(by_period[0].norad_id, 25544);
+    /// let newest = catalog.sorted_by(sgp4::CatalogKey::Epoch).pop().unwrap();
+    /// assert_eq!(newest.norad_id, 25544);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sorted_by(&self, key: CatalogKey) -> alloc::vec::Vec<&tle::Elements> {
+        let mut elements: alloc::vec::Vec<(f64, &tle::Elements)> = self
+            .iter()
+            .map(|elements| (key.value(elements), elements))
+            .collect();
+        elements.sort_by(|(a, _), (b, _)| match (a.is_nan(), b.is_nan()) {
+            (false, false) => a.total_cmp(b),
+            (a_is_nan, b_is_nan) => a_is_nan.cmp(&b_is_nan),
+        });
+        elements.into_iter().map(|(_, elements)| elements).collect()
+    }
+
+    /// Groups the element sets by bands of a property
+    ///
+    /// The band index of a value v is ⌊v / width⌋, hence the band with index k covers [k width, (k + 1) width[.
+    /// Element sets whose value is NaN are omitted. Each band lists the element sets in NORAD ID order.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The property used to group the catalog
+    /// * `width` - The width of the bands in the property's unit (strictly positive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let catalog: sgp4::Catalog = sgp4::parse_3les(
+    ///     "ISS (ZARYA)
+    /// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+    /// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+    /// MOLNIYA 1-36
+    /// 1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+    /// 2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656
+    /// ",
+    /// )?
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// // 10° inclination bands
+    /// for (band, elements) in catalog.group_by_band(sgp4::CatalogKey::Inclination, 10.0) {
+    ///     println!(
+    ///         "[{}°, {}°[: {} satellites",
+    ///         band * 10,
+    ///         (band + 1) * 10,
+    ///         elements.len()
+    ///     );
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn group_by_band(
+        &self,
+        key: CatalogKey,
+        width: f64,
+    ) -> alloc::collections::BTreeMap<i64, alloc::vec::Vec<&tle::Elements>> {
+        assert!(width > 0.0, "width must be strictly positive");
+        let mut bands = alloc::collections::BTreeMap::new();
+        for elements in self.iter() {
+            let value = key.value(elements);
+            if !value.is_nan() {
+                bands
+                    .entry((value / width).floor() as i64)
+                    .or_insert_with(alloc::vec::Vec::new)
+                    .push(elements);
+            }
+        }
+        bands
+    }
+
+    /// Groups the element sets by orbit regime
+    ///
+    /// The regime is calculated by the propagator constants, see
+    /// [Constants::orbit_regime](struct.Constants.html#method.orbit_regime).
+    /// Element sets that cannot be used to initialize a propagator are omitted.
+    /// Each regime lists the element sets in NORAD ID order.
+    pub fn group_by_regime(
+        &self,
+    ) -> alloc::collections::BTreeMap<uncertainty::OrbitRegime, alloc::vec::Vec<&tle::Elements>>
+    {
+        let mut regimes = alloc::collections::BTreeMap::new();
+        for elements in self.iter() {
+            if let Some(Ok(constants)) = self.constants(elements.norad_id) {
+                regimes
+                    .entry(constants.orbit_regime())
+                    .or_insert_with(alloc::vec::Vec::new)
+                    .push(elements);
+            }
+        }
+        regimes
+    }
+
+    /// Calculates summary statistics of a property
+    ///
+    /// Element sets whose value is NaN are ignored. Returns `None` if no element set has a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The property to summarize
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let catalog: sgp4::Catalog = sgp4::parse_3les(
+    ///     "ISS (ZARYA)
+    /// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+    /// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+    /// MOLNIYA 1-36
+    /// 1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+    /// 2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656
+    /// ",
+    /// )?
+    /// .into_iter()
+    /// .collect();
+    /// let statistics = catalog.statistics(sgp4::CatalogKey::Inclination).unwrap();
+    /// assert_eq!(statistics.count, 2);
+    /// assert!((statistics.median - (51.6461 + 64.1586) / 2.0).abs() < 1e-9);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn statistics(&self, key: CatalogKey) -> Option<CatalogStatistics> {
+        let mut values: alloc::vec::Vec<f64> = self
+            .iter()
+            .map(|elements| key.value(elements))
+            .filter(|value| !value.is_nan())
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+        let count = values.len();
+
+        // μ = Σ vᵢ / n
+        let mean = values.iter().sum::<f64>() / count as f64;
+        Some(CatalogStatistics {
+            count,
+            minimum: values[0],
+            maximum: values[count - 1],
+            mean,
+
+            // the two middle indices are equal if the count is odd
+            median: 0.5 * (values[(count - 1) / 2] + values[count / 2]),
+
+            // σ = (Σ (vᵢ - μ)² / n)¹ᐟ²
+            standard_deviation: (values
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / count as f64)
+                .sqrt(),
+        })
+    }
+}
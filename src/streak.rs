@@ -0,0 +1,158 @@
+use crate::astrometry;
+use crate::gp;
+use crate::model;
+use crate::observer;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// number of intervals used to sample the path during the exposure
+const STREAK_INTERVALS: usize = 16;
+
+/// Apparent trail of a satellite in an image
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Streak {
+    /// Topocentric direction at the beginning of the exposure
+    pub start: astrometry::Equatorial,
+
+    /// Topocentric direction at the end of the exposure
+    pub end: astrometry::Equatorial,
+
+    /// Angular length of the path on the sky in rad
+    pub angular_length: f64,
+
+    /// Length of the streak in pixels
+    pub length: f64,
+
+    /// Direction of motion from the start to the end in rad
+    ///
+    /// The position angle is measured from the north towards the east and lies in the range [0, 2π[.
+    pub position_angle: f64,
+
+    /// Mean angular rate in rad.s⁻¹
+    pub angular_rate: f64,
+}
+
+// angular distance between two directions in rad
+fn separation(a: &astrometry::Equatorial, b: &astrometry::Equatorial) -> f64 {
+    // θ = 2 sin⁻¹((sin²(Δδ / 2) + cos δₐ cos δᵦ sin²(Δα / 2))¹ᐟ²)
+    2.0 * ((0.5 * (b.declination - a.declination)).sin().powi(2)
+        + a.declination.cos()
+            * b.declination.cos()
+            * (0.5 * (b.right_ascension - a.right_ascension))
+                .sin()
+                .powi(2))
+    .sqrt()
+    .min(1.0)
+    .asin()
+}
+
+impl propagator::Constants {
+    /// Predicts the streak left by the satellite in an image taken by a ground observer
+    ///
+    /// The topocentric direction (see [Observer::topocentric_equatorial](struct.Observer.html#method.topocentric_equatorial))
+    /// is sampled 17 times during the exposure, and the angular length is the sum of the arcs between samples.
+    /// The plate scale is the angle covered by a pixel, for instance the field of view divided by the
+    /// number of pixels along the same axis. Streaks longer than the field of view leave the image.
+    ///
+    /// Visibility (elevation, illumination and brightness) is not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - The ground observer
+    /// * `start` - The beginning of the exposure in minutes since epoch
+    /// * `exposure` - The exposure duration in s
+    /// * `plate_scale` - The angle covered by a pixel in rad.pixel⁻¹
+    /// * `conditions` - The atmospheric conditions used to correct refraction, or `None` for geometric directions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: 48.8566_f64.to_radians(),
+    ///     longitude: 2.3522_f64.to_radians(),
+    ///     altitude: 0.035,
+    /// };
+    ///
+    /// // 2 s exposure with a 2° field of view over 4096 pixels, near the culmination of a 70° pass
+    /// let field_of_view = 2.0_f64.to_radians();
+    /// let streak = constants.streak(
+    ///     &observer,
+    ///     sgp4::MinutesSinceEpoch(108.47),
+    ///     2.0,
+    ///     field_of_view / 4096.0,
+    ///     Some(&sgp4::AtmosphericConditions::STANDARD),
+    /// )?;
+    /// assert!((streak.angular_rate.to_degrees() - 0.94).abs() < 0.01);
+    /// println!(
+    ///     "{} pixels towards {}°, {}",
+    ///     streak.length,
+    ///     streak.position_angle.to_degrees(),
+    ///     if streak.angular_length > field_of_view {
+    ///         "leaves the field"
+    ///     } else {
+    ///         "within the field"
+    ///     }
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn streak(
+        &self,
+        observer: &observer::Observer,
+        start: MinutesSinceEpoch,
+        exposure: f64,
+        plate_scale: f64,
+        conditions: Option<&astrometry::AtmosphericConditions>,
+    ) -> core::result::Result<Streak, gp::Error> {
+        let direction = |t: MinutesSinceEpoch| {
+            self.propagate(t).map(|prediction| {
+                observer.topocentric_equatorial(
+                    model::iau_epoch_to_sidereal_time(self.julian_years_since_j2000(t)),
+                    &prediction,
+                    conditions,
+                )
+            })
+        };
+        let first = direction(start)?;
+        let mut previous = first;
+        let mut angular_length = 0.0;
+        for index in 1..=STREAK_INTERVALS {
+            let next = direction(MinutesSinceEpoch(
+                start.0 + exposure / 60.0 * index as f64 / STREAK_INTERVALS as f64,
+            ))?;
+            angular_length += separation(&previous, &next);
+            previous = next;
+        }
+        let last = previous;
+        let delta_right_ascension = last.right_ascension - first.right_ascension;
+        Ok(Streak {
+            start: first,
+            end: last,
+            angular_length,
+            length: angular_length / plate_scale,
+
+            //            sin Δα cos δ₁
+            // P = tan⁻¹ ------------------------------------- mod 2π
+            //            cos δ₀ sin δ₁ - sin δ₀ cos δ₁ cos Δα
+            position_angle: model::positive_angle(
+                (delta_right_ascension.sin() * last.declination.cos()).atan2(
+                    first.declination.cos() * last.declination.sin()
+                        - first.declination.sin()
+                            * last.declination.cos()
+                            * delta_right_ascension.cos(),
+                ),
+            ),
+            angular_rate: angular_length / exposure,
+        })
+    }
+}
@@ -0,0 +1,107 @@
+use crate::ephemeris;
+use crate::events;
+use crate::geodetic;
+use crate::gp;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Day/night terminator crossing type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Terminator {
+    /// The sub-satellite point enters the day side
+    Dawn,
+
+    /// The sub-satellite point enters the night side
+    Dusk,
+}
+
+/// A crossing of the day/night terminator by the sub-satellite point
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TerminatorCrossing {
+    /// Time of the crossing in minutes since epoch
+    pub t: MinutesSinceEpoch,
+
+    /// Crossing type
+    pub terminator: Terminator,
+}
+
+impl propagator::Constants {
+    /// Finds the times at which the sub-satellite point crosses the day/night terminator in a time window
+    ///
+    /// The terminator is the locus of the ground points where the Sun's elevation (measured with respect
+    /// to the WGS84 ellipsoid normal, without refraction) equals `sun_elevation`.
+    /// Negative values define twilight boundaries (for example -6° for civil twilight).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `sun_elevation` - The Sun's elevation at the terminator in rad (0 for the geometric terminator)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// for crossing in constants.terminator_crossings(
+    ///     sgp4::MinutesSinceEpoch(0.0),
+    ///     sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    ///     0.0,
+    /// )? {
+    ///     println!("{:?} at t = {} min", crossing.terminator, crossing.t.0);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn terminator_crossings(
+        &self,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        sun_elevation: f64,
+    ) -> core::result::Result<alloc::vec::Vec<TerminatorCrossing>, gp::Error> {
+        let sin_sun_elevation = sun_elevation.sin();
+        Ok(
+            events::find_crossings(start, end, self.period() / 32.0, 1e-6, |t| {
+                self.propagate(t).map(|prediction| {
+                    let epoch = self.julian_years_since_j2000(t);
+                    let sun_position = ephemeris::sun_position(epoch);
+
+                    // the right ascension of the sub-satellite point is λ + θ
+                    let right_ascension = prediction.position[1].atan2(prediction.position[0]);
+                    let latitude =
+                        geodetic::Geodetic::from_teme(0.0, &prediction.position).latitude;
+
+                    // sin ε☉ = n̂ · r☉ / |r☉| (the Sun's parallax is neglected)
+                    vector::dot(
+                        &[
+                            latitude.cos() * right_ascension.cos(),
+                            latitude.cos() * right_ascension.sin(),
+                            latitude.sin(),
+                        ],
+                        &sun_position,
+                    ) / vector::norm(&sun_position)
+                        - sin_sun_elevation
+                })
+            })?
+            .into_iter()
+            .map(|crossing| TerminatorCrossing {
+                t: crossing.t,
+                terminator: match crossing.direction {
+                    events::Direction::Increasing => Terminator::Dawn,
+                    events::Direction::Decreasing => Terminator::Dusk,
+                },
+            })
+            .collect(),
+        )
+    }
+}
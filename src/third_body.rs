@@ -1,32 +1,342 @@
+use crate::deep_space;
+use crate::real::Real;
+use crate::trig;
+
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
+/// Mean orbit of the Sun or the Moon around the Earth, used to initialize deep space propagators
+///
+/// The angles are measured in the equatorial frame of the propagator (True Equator, Mean Equinox).
+/// The eccentricity, mean motion and perturbation coefficient of each body are not configurable,
+/// since they are also used during propagation.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThirdBodyElements {
+    /// Angle between the equator and the orbit plane in rad
+    pub inclination: f64,
+
+    /// Angle between vernal equinox and the point where the orbit crosses the equatorial plane in rad
+    pub right_ascension: f64,
+
+    /// Angle between the ascending node and the orbit's point of closest approach to the earth in rad
+    pub argument_of_perigee: f64,
+
+    /// Angle of the body relative to the perigee at epoch in rad
+    pub mean_anomaly: f64,
+}
+
+/// Solar and lunar orbits at a propagator's epoch
+///
+/// See [ConstantsBuilder::with_third_bodies](struct.ConstantsBuilder.html#method.with_third_bodies).
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThirdBodies {
+    /// Orbit of the Sun around the Earth
+    pub sun: ThirdBodyElements,
+
+    /// Orbit of the Moon around the Earth
+    pub moon: ThirdBodyElements,
+}
+
+impl ThirdBodies {
+    /// Returns the solar and lunar orbits calculated with the analytic series of SGP4
+    ///
+    /// These are the orbits used by [Constants::new](struct.Constants.html#method.new).
+    /// The series give the sines and cosines of the angles with 8 significant digits,
+    /// hence the angles returned by this function reproduce `Constants::new` predictions
+    /// to within a few millimetres rather than exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The number of years since UTC 1 January 2000 12h00 (J2000)
+    pub fn analytic(epoch: f64) -> ThirdBodies {
+        let (sun, moon) = deep_space::analytic_third_bodies(epoch);
+        ThirdBodies {
+            sun: (&sun).into(),
+            moon: (&moon).into(),
+        }
+    }
+}
+
+// sines and cosines of a third body's orbit angles, in the form used by the deep space initialization
+pub(crate) struct Orientation {
+    pub(crate) inclination_sine: f64,
+    pub(crate) inclination_cosine: f64,
+    pub(crate) right_ascension_sine: f64,
+    pub(crate) right_ascension_cosine: f64,
+    pub(crate) argument_of_perigee_sine: f64,
+    pub(crate) argument_of_perigee_cosine: f64,
+    pub(crate) mean_anomaly_0: f64,
+}
+
+impl From<&ThirdBodyElements> for Orientation {
+    fn from(elements: &ThirdBodyElements) -> Self {
+        let (inclination_sine, inclination_cosine) = elements.inclination.sin_cos();
+        let (right_ascension_sine, right_ascension_cosine) = elements.right_ascension.sin_cos();
+        let (argument_of_perigee_sine, argument_of_perigee_cosine) =
+            elements.argument_of_perigee.sin_cos();
+        Orientation {
+            inclination_sine,
+            inclination_cosine,
+            right_ascension_sine,
+            right_ascension_cosine,
+            argument_of_perigee_sine,
+            argument_of_perigee_cosine,
+            mean_anomaly_0: elements.mean_anomaly,
+        }
+    }
+}
+
+impl From<&Orientation> for ThirdBodyElements {
+    fn from(orientation: &Orientation) -> Self {
+        ThirdBodyElements {
+            inclination: orientation
+                .inclination_sine
+                .atan2(orientation.inclination_cosine),
+            right_ascension: orientation
+                .right_ascension_sine
+                .atan2(orientation.right_ascension_cosine),
+            argument_of_perigee: orientation
+                .argument_of_perigee_sine
+                .atan2(orientation.argument_of_perigee_cosine),
+            mean_anomaly: orientation.mean_anomaly_0,
+        }
+    }
+}
+
+impl ThirdBodyElements {
+    /// Calculates the osculating elements of a body from its geocentric position and velocity
+    ///
+    /// This function converts the output of a high-fidelity ephemeris (for example DE440)
+    /// to the form expected by [ConstantsBuilder::with_third_bodies](struct.ConstantsBuilder.html#method.with_third_bodies).
+    /// The state must be expressed in the equatorial frame of the propagator.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The geocentric position of the body in km
+    /// * `velocity` - The geocentric velocity of the body in km.s⁻¹
+    /// * `gravitational_parameter` - The sum of the gravitational parameters of the Earth and the body in km³.s⁻²
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // the Moon on a circular orbit in the equatorial plane
+    /// let moon = sgp4::ThirdBodyElements::from_state(
+    ///     [0.0, 384400.0, 0.0],
+    ///     [-(403503.0f64 / 384400.0).sqrt(), 0.0, 0.0],
+    ///     403503.0,
+    /// );
+    /// assert!(moon.inclination.abs() < 1e-12);
+    /// ```
+    pub fn from_state(
+        position: [f64; 3],
+        velocity: [f64; 3],
+        gravitational_parameter: f64,
+    ) -> ThirdBodyElements {
+        // h = r × v
+        let h = [
+            position[1] * velocity[2] - position[2] * velocity[1],
+            position[2] * velocity[0] - position[0] * velocity[2],
+            position[0] * velocity[1] - position[1] * velocity[0],
+        ];
+        let h_norm = (h[0].powi(2) + h[1].powi(2) + h[2].powi(2)).sqrt();
+        let r = (position[0].powi(2) + position[1].powi(2) + position[2].powi(2)).sqrt();
+        let rv = position[0] * velocity[0] + position[1] * velocity[1] + position[2] * velocity[2];
+
+        // e = v × h / μ - r / |r|
+        let e = [
+            (velocity[1] * h[2] - velocity[2] * h[1]) / gravitational_parameter - position[0] / r,
+            (velocity[2] * h[0] - velocity[0] * h[2]) / gravitational_parameter - position[1] / r,
+            (velocity[0] * h[1] - velocity[1] * h[0]) / gravitational_parameter - position[2] / r,
+        ];
+        let eccentricity = (e[0].powi(2) + e[1].powi(2) + e[2].powi(2)).sqrt();
+
+        // I = cos⁻¹(h₂ / |h|)
+        let inclination = (h[2] / h_norm).clamp(-1.0, 1.0).acos();
+
+        // Ω = tan⁻¹(h₀ / -h₁)
+        let right_ascension = if h[0] == 0.0 && h[1] == 0.0 {
+            0.0
+        } else {
+            h[0].atan2(-h[1])
+        };
+        let (right_ascension_sine, right_ascension_cosine) = right_ascension.sin_cos();
+
+        // u = │ tan⁻¹(y / x)                               if sin I = 0
+        //     │ tan⁻¹((z / sin I) / (x cos Ω + y sin Ω))  otherwise
+        let argument_of_latitude = if inclination.sin() == 0.0 {
+            position[1].atan2(position[0])
+        } else {
+            (position[2] / inclination.sin())
+                .atan2(position[0] * right_ascension_cosine + position[1] * right_ascension_sine)
+        };
+
+        // ν = tan⁻¹((r · v) |h| / (|h|² - μ |r|))
+        let true_anomaly = if eccentricity == 0.0 {
+            0.0
+        } else {
+            (rv * h_norm).atan2(h_norm.powi(2) - gravitational_parameter * r)
+        };
+
+        // E = 2 tan⁻¹(((1 - e) / (1 + e))¹ᐟ² tan(ν / 2))
+        let eccentric_anomaly = 2.0
+            * (((1.0 - eccentricity) / (1.0 + eccentricity)).sqrt() * (true_anomaly / 2.0).tan())
+                .atan();
+        ThirdBodyElements {
+            inclination,
+            right_ascension,
+            // ω = u - ν
+            argument_of_perigee: argument_of_latitude - true_anomaly,
+            // M = E - e sin E
+            mean_anomaly: eccentric_anomaly - eccentricity * eccentric_anomaly.sin(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub(crate) struct Perturbations {
-    kx0: f64,
-    kx1: f64,
-    kx2: f64,
-    kx3: f64,
-    kx4: f64,
-    kx5: f64,
-    kx6: f64,
-    kx7: f64,
-    kx8: f64,
-    kx9: f64,
-    kx10: f64,
-    kx11: f64,
+pub(crate) struct Perturbations<T: Real = f64> {
+    kx0: T,
+    kx1: T,
+    kx2: T,
+    kx3: T,
+    kx4: T,
+    kx5: T,
+    kx6: T,
+    kx7: T,
+    kx8: T,
+    kx9: T,
+    kx10: T,
+    kx11: T,
     third_body_mean_anomaly_0: f64,
 }
 
+/// Secular rates caused by a third body (the sun or the moon)
+///
+/// Use [Constants::deep_space_coefficients](struct.Constants.html#method.deep_space_coefficients)
+/// to read the rates of a deep space propagator.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dots<T: Real = f64> {
+    /// Inclination rate in rad.min⁻¹
+    pub inclination: T,
+
+    /// Right ascension of the ascending node rate in rad.min⁻¹
+    pub right_ascension: T,
+
+    /// Eccentricity rate in min⁻¹
+    pub eccentricity: T,
+
+    /// Argument of perigee rate in rad.min⁻¹
+    pub argument_of_perigee: T,
+
+    /// Mean anomaly rate in rad.min⁻¹
+    pub mean_anomaly: T,
+}
+
+/// Long-period periodic coefficients of a third body (the sun or the moon)
+///
+/// This is a read-only view of the terms calculated during initialization,
+/// use [Constants::deep_space_coefficients](struct.Constants.html#method.deep_space_coefficients)
+/// to read the coefficients of a deep space propagator.
+/// Together with [ThirdBodyDots](struct.ThirdBodyDots.html), it can be used to attribute
+/// the evolution of the elements to the sun or the moon.
 #[derive(Debug, Clone)]
+pub struct Coefficients {
+    perturbations: Perturbations,
+    eccentricity: f64,
+    mean_motion: f64,
+}
+
+/// Long-period periodic effects of a third body at a given time
+///
+/// The propagator adds the solar and lunar effects to the secular elements.
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub(crate) struct Dots {
-    pub(crate) inclination: f64,
-    pub(crate) right_ascension: f64,
-    pub(crate) eccentricity: f64,
-    pub(crate) argument_of_perigee: f64,
-    pub(crate) mean_anomaly: f64,
+pub struct Effects {
+    /// Eccentricity variation δeₓ (unitless)
+    pub eccentricity: f64,
+
+    /// Inclination variation δIₓ in rad
+    pub inclination: f64,
+
+    /// Mean anomaly variation δMₓ in rad
+    pub mean_anomaly: f64,
+
+    /// Argument of perigee term pₓ₄ in rad
+    ///
+    /// The coupling with the right ascension term (- cos I pₓ₅ / sin I) is not included.
+    pub argument_of_perigee: f64,
+
+    /// Right ascension term pₓ₅ in rad
+    ///
+    /// The term is scaled by sin I, the right ascension variation is pₓ₅ / sin I if I ≥ 0.2 rad.
+    pub right_ascension: f64,
+}
+
+impl Coefficients {
+    pub(crate) fn new(perturbations: &Perturbations, eccentricity: f64, mean_motion: f64) -> Self {
+        Coefficients {
+            perturbations: perturbations.clone(),
+            eccentricity,
+            mean_motion,
+        }
+    }
+
+    /// Returns the coefficients kₓ₀ to kₓ₁₁
+    ///
+    /// kₓ₀ and kₓ₁ (eccentricity) are unitless, the others are in rad.
+    pub fn k(&self) -> [f64; 12] {
+        let perturbations = &self.perturbations;
+        [
+            perturbations.kx0,
+            perturbations.kx1,
+            perturbations.kx2,
+            perturbations.kx3,
+            perturbations.kx4,
+            perturbations.kx5,
+            perturbations.kx6,
+            perturbations.kx7,
+            perturbations.kx8,
+            perturbations.kx9,
+            perturbations.kx10,
+            perturbations.kx11,
+        ]
+    }
+
+    /// Returns the third body's mean anomaly at epoch Mₓ₀ in rad
+    pub fn mean_anomaly_0(&self) -> f64 {
+        self.perturbations.third_body_mean_anomaly_0
+    }
+
+    /// Returns the third body's eccentricity eₓ (unitless)
+    pub fn eccentricity(&self) -> f64 {
+        self.eccentricity
+    }
+
+    /// Returns the third body's mean motion nₓ in rad.min⁻¹
+    pub fn mean_motion(&self) -> f64 {
+        self.mean_motion
+    }
+
+    /// Calculates the long-period periodic effects of the third body
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+    pub fn effects(&self, t: f64) -> Effects {
+        let (eccentricity, inclination, mean_anomaly, argument_of_perigee, right_ascension) = self
+            .perturbations
+            .long_period_periodic_effects(self.eccentricity, self.mean_motion, t);
+        Effects {
+            eccentricity,
+            inclination,
+            mean_anomaly,
+            argument_of_perigee,
+            right_ascension,
+        }
+    }
 }
 
 // inclination_0: the angle between the equator and the orbit plane i₀, in rad
@@ -50,188 +360,201 @@ pub(crate) struct Dots {
 // p1: the constant p₁ = 1 − e₀²
 // b0: the constant β₀ = p₁¹ᐟ²
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn perturbations_and_dots(
-    inclination_0: f64,
-    eccentricity_0: f64,
-    argument_of_perigee_0: f64,
-    n0: f64,
+pub(crate) fn perturbations_and_dots<T: Real>(
+    inclination_0: T,
+    eccentricity_0: T,
+    argument_of_perigee_0: T,
+    n0: T,
     third_body_inclination_sine: f64,
     third_body_inclination_cosine: f64,
-    delta_right_ascension_sine: f64,
-    delta_right_ascension_cosine: f64,
+    delta_right_ascension_sine: T,
+    delta_right_ascension_cosine: T,
     third_body_eccentricity: f64,
     third_body_argument_of_perigee_sine: f64,
     third_body_argument_of_perigee_cosine: f64,
     third_body_perturbation_coefficient: f64,
     third_body_mean_motion: f64,
     third_body_mean_anomaly_0: f64,
-    p1: f64,
-    b0: f64,
-) -> (Perturbations, Dots) {
+    p1: T,
+    b0: T,
+) -> (Perturbations<T>, Dots<T>) {
+    let c = T::from_f64;
+
     // aₓ₁ = cos ωₓ cos(Ω₀ - Ωₓ) + sin ωₓ cos Iₓ sin(Ω₀ - Ωₓ)
-    let ax1 = third_body_argument_of_perigee_cosine * delta_right_ascension_cosine
-        + third_body_argument_of_perigee_sine
-            * third_body_inclination_cosine
+    let ax1 = c(third_body_argument_of_perigee_cosine) * delta_right_ascension_cosine
+        + c(third_body_argument_of_perigee_sine * third_body_inclination_cosine)
             * delta_right_ascension_sine;
 
     // aₓ₃ = - sin ωₓ cos(Ω₀ - Ωₓ) + cos ωₓ cos Iₓ sin(Ω₀ - Ωₓ)
-    let ax3 = -third_body_argument_of_perigee_sine * delta_right_ascension_cosine
-        + third_body_argument_of_perigee_cosine
-            * third_body_inclination_cosine
+    let ax3 = c(-third_body_argument_of_perigee_sine) * delta_right_ascension_cosine
+        + c(third_body_argument_of_perigee_cosine * third_body_inclination_cosine)
             * delta_right_ascension_sine;
 
     // aₓ₇ = - cos ωₓ sin(Ω₀ - Ωₓ) + sin ωₓ cos Iₓ cos(Ω₀ - Ωₓ)
-    let ax7 = -third_body_argument_of_perigee_cosine * delta_right_ascension_sine
-        + third_body_argument_of_perigee_sine
-            * third_body_inclination_cosine
+    let ax7 = c(-third_body_argument_of_perigee_cosine) * delta_right_ascension_sine
+        + c(third_body_argument_of_perigee_sine * third_body_inclination_cosine)
             * delta_right_ascension_cosine;
 
     // aₓ₈ = sin ωₓ sin Iₓ
     let ax8 = third_body_argument_of_perigee_sine * third_body_inclination_sine;
 
     // aₓ₉ = sin ωₓ sin(Ω₀ - Ωₓ) + cos ωₓ cos Iₓ cos(Ω₀ - Ωₓ)
-    let ax9 = third_body_argument_of_perigee_sine * delta_right_ascension_sine
-        + third_body_argument_of_perigee_cosine
-            * third_body_inclination_cosine
+    let ax9 = c(third_body_argument_of_perigee_sine) * delta_right_ascension_sine
+        + c(third_body_argument_of_perigee_cosine * third_body_inclination_cosine)
             * delta_right_ascension_cosine;
 
     // aₓ₁₀ = cos ωₓ sin Iₓ
     let ax10 = third_body_argument_of_perigee_cosine * third_body_inclination_sine;
 
     // aₓ₂ = aₓ₇ cos I₀ + aₓ₈ sin I₀
-    let ax2 = inclination_0.cos() * ax7 + inclination_0.sin() * ax8;
+    let ax2 = inclination_0.cosine() * ax7 + inclination_0.sine() * ax8;
 
     // aₓ₄ = aₓ₉ cos I₀ + aₓ₁₀ sin I₀
-    let ax4 = inclination_0.cos() * ax9 + inclination_0.sin() * ax10;
+    let ax4 = inclination_0.cosine() * ax9 + inclination_0.sine() * ax10;
 
     // aₓ₅ = - aₓ₇ sin I₀ + aₓ₈ cos I₀
-    let ax5 = -inclination_0.sin() * ax7 + inclination_0.cos() * ax8;
+    let ax5 = -inclination_0.sine() * ax7 + inclination_0.cosine() * ax8;
 
     // aₓ₆ = - aₓ₉ sin I₀ + aₓ₁₀ cos I₀
-    let ax6 = -inclination_0.sin() * ax9 + inclination_0.cos() * ax10;
+    let ax6 = -inclination_0.sine() * ax9 + inclination_0.cosine() * ax10;
 
     // Xₓ₁ = aₓ₁ cos ω₀ + aₓ₂ sin ω₀
-    let xx1 = ax1 * argument_of_perigee_0.cos() + ax2 * argument_of_perigee_0.sin();
+    let xx1 = ax1 * argument_of_perigee_0.cosine() + ax2 * argument_of_perigee_0.sine();
 
     // Xₓ₂ = aₓ₃ cos ω₀ + aₓ₄ sin ω₀
-    let xx2 = ax3 * argument_of_perigee_0.cos() + ax4 * argument_of_perigee_0.sin();
+    let xx2 = ax3 * argument_of_perigee_0.cosine() + ax4 * argument_of_perigee_0.sine();
 
     // Xₓ₃ = - aₓ₁ sin ω₀ + aₓ₂ cos ω₀
-    let xx3 = -ax1 * argument_of_perigee_0.sin() + ax2 * argument_of_perigee_0.cos();
+    let xx3 = -ax1 * argument_of_perigee_0.sine() + ax2 * argument_of_perigee_0.cosine();
 
     // Xₓ₄ = - aₓ₃ sin ω₀ + aₓ₄ cos ω₀
-    let xx4 = -ax3 * argument_of_perigee_0.sin() + ax4 * argument_of_perigee_0.cos();
+    let xx4 = -ax3 * argument_of_perigee_0.sine() + ax4 * argument_of_perigee_0.cosine();
 
     // Xₓ₅ = aₓ₅ sin ω₀
-    let xx5 = ax5 * argument_of_perigee_0.sin();
+    let xx5 = ax5 * argument_of_perigee_0.sine();
 
     // Xₓ₆ = aₓ₆ sin ω₀
-    let xx6 = ax6 * argument_of_perigee_0.sin();
+    let xx6 = ax6 * argument_of_perigee_0.sine();
 
     // Xₓ₇ = aₓ₅ cos ω₀
-    let xx7 = ax5 * argument_of_perigee_0.cos();
+    let xx7 = ax5 * argument_of_perigee_0.cosine();
 
     // Xₓ₈ = aₓ₆ cos ω₀
-    let xx8 = ax6 * argument_of_perigee_0.cos();
+    let xx8 = ax6 * argument_of_perigee_0.cosine();
 
     // Zₓ₃₁ = 12 Xₓ₁² - 3 Xₓ₃²
-    let zx31 = 12.0 * xx1.powi(2) - 3.0 * xx3.powi(2);
+    let zx31 = c(12.0) * xx1.integer_power(2) - c(3.0) * xx3.integer_power(2);
 
     // Zₓ₃₂ = 24 Xₓ₁ Xₓ₂ - 6 Xₓ₃ Xₓ₄
-    let zx32 = 24.0 * xx1 * xx2 - 6.0 * xx3 * xx4;
+    let zx32 = c(24.0) * xx1 * xx2 - c(6.0) * xx3 * xx4;
 
     // Zₓ₃₃ = 12 Xₓ₂² - 3 Xₓ₄²
-    let zx33 = 12.0 * xx2.powi(2) - 3.0 * xx4.powi(2);
+    let zx33 = c(12.0) * xx2.integer_power(2) - c(3.0) * xx4.integer_power(2);
 
     // Zₓ₁₁ = - 6 aₓ₁ aₓ₅ + e₀² (- 24 Xₓ₁ Xₓ₇ - 6 Xₓ₃ Xₓ₅)
-    let zx11 = -6.0 * ax1 * ax5 + eccentricity_0.powi(2) * (-24.0 * xx1 * xx7 - 6.0 * xx3 * xx5);
+    let zx11 = c(-6.0) * ax1 * ax5
+        + eccentricity_0.integer_power(2) * (c(-24.0) * xx1 * xx7 - c(6.0) * xx3 * xx5);
 
     // Zₓ₁₃ = - 6 aₓ₃ aₓ₆ + e₀² (-24 Xₓ₂ Xₓ₈ - 6 Xₓ₄ Xₓ₆)
-    let zx13 = -6.0 * ax3 * ax6 + eccentricity_0.powi(2) * (-24.0 * xx2 * xx8 - 6.0 * xx4 * xx6);
+    let zx13 = c(-6.0) * ax3 * ax6
+        + eccentricity_0.integer_power(2) * (c(-24.0) * xx2 * xx8 - c(6.0) * xx4 * xx6);
 
     // Zₓ₂₁ = 6 aₓ₂ aₓ₅ + e₀² (24.0 Xₓ₁ Xₓ₅ - 6 Xₓ₃ Xₓ₇)
-    let zx21 = 6.0 * ax2 * ax5 + eccentricity_0.powi(2) * (24.0 * xx1 * xx5 - 6.0 * xx3 * xx7);
+    let zx21 = c(6.0) * ax2 * ax5
+        + eccentricity_0.integer_power(2) * (c(24.0) * xx1 * xx5 - c(6.0) * xx3 * xx7);
 
     // Zₓ₂₃ = 6 aₓ₄ aₓ₆ + e₀² (24 Xₓ₂ Xₓ₆ - 6 Xₓ₄ Xₓ₈)
-    let zx23 = 6.0 * ax4 * ax6 + eccentricity_0.powi(2) * (24.0 * xx2 * xx6 - 6.0 * xx4 * xx8);
+    let zx23 = c(6.0) * ax4 * ax6
+        + eccentricity_0.integer_power(2) * (c(24.0) * xx2 * xx6 - c(6.0) * xx4 * xx8);
 
     // Zₓ₁ = 2 (3 (aₓ₁² + aₓ₂²) + Zₓ₃₁ e₀²) + p₁ Zₓ₃₁
-    let zx1 = (3.0 * (ax1.powi(2) + ax2.powi(2)) + zx31 * eccentricity_0.powi(2)) * 2.0 + p1 * zx31;
+    let zx1 = (c(3.0) * (ax1.integer_power(2) + ax2.integer_power(2))
+        + zx31 * eccentricity_0.integer_power(2))
+        * 2.0
+        + p1 * zx31;
 
     // Zₓ₃ = 2 (3 (aₓ₃² + aₓ₄²) + Zₓ₃₃ e₀²) + p₁ Zₓ₃₃
-    let zx3 = (3.0 * (ax3.powi(2) + ax4.powi(2)) + zx33 * eccentricity_0.powi(2)) * 2.0 + p1 * zx33;
+    let zx3 = (c(3.0) * (ax3.integer_power(2) + ax4.integer_power(2))
+        + zx33 * eccentricity_0.integer_power(2))
+        * 2.0
+        + p1 * zx33;
 
     // pₓ₀ = Cₓ / n₀"
-    let px0 = third_body_perturbation_coefficient / n0;
+    let px0 = c(third_body_perturbation_coefficient) / n0;
 
     //         1 pₓ₀
     // pₓ₁ = - - ---
     //         2 β₀
-    let px1 = -0.5 * px0 / b0;
+    let px1 = c(-0.5) * px0 / b0;
 
     // pₓ₂ = pₓ₀ β₀
     let px2 = px0 * b0;
 
     // pₓ₃ = - 15 e₀ pₓ₂
-    let px3 = -15.0 * eccentricity_0 * px2;
+    let px3 = c(-15.0) * eccentricity_0 * px2;
 
     // Ω̇ₓ = │ 0                               if I₀ < 5.2359877 × 10⁻²
     //      │                                 or I₀ > π - 5.2359877 × 10⁻²
     //      │ - nₓ pₓ₁ (Zₓ₂₁ + Zₓ₂₃) / sin I₀ otherwise
-    let third_body_right_ascension_dot =
-        if !(5.2359877e-2..=core::f64::consts::PI - 5.2359877e-2).contains(&inclination_0) {
-            0.0
-        } else {
-            -third_body_mean_motion * px1 * (zx21 + zx23) / inclination_0.sin()
-        };
+    let third_body_right_ascension_dot = if !(5.2359877e-2..=core::f64::consts::PI - 5.2359877e-2)
+        .contains(&inclination_0.value())
+    {
+        c(0.0)
+    } else {
+        c(-third_body_mean_motion) * px1 * (zx21 + zx23) / inclination_0.sine()
+    };
     (
         Perturbations {
             // kₓ₀ = 2 pₓ₃ (Xₓ₂ Xₓ₃ + Xₓ₁ Xₓ₄)
-            kx0: 2.0 * px3 * (xx2 * xx3 + xx1 * xx4),
+            kx0: c(2.0) * px3 * (xx2 * xx3 + xx1 * xx4),
 
             // kₓ₁ = 2 pₓ₃ (Xₓ₂ Xₓ₄ - Xₓ₁ Xₓ₃)
-            kx1: 2.0 * px3 * (xx2 * xx4 - xx1 * xx3),
+            kx1: c(2.0) * px3 * (xx2 * xx4 - xx1 * xx3),
 
             // kₓ₂ = 2 pₓ₁ (- 6 (aₓ₁ aₓ₆ + aₓ₃ aₓ₅) + e₀² (- 24 (Xₓ₂ Xₓ₇ + Xₓ₁ Xₓ₈) - 6 (Xₓ₃ Xₓ₆ + Xₓ₄ Xₓ₅)))
-            kx2: 2.0
+            kx2: c(2.0)
                 * px1
-                * (-6.0 * (ax1 * ax6 + ax3 * ax5)
-                    + eccentricity_0.powi(2)
-                        * (-24.0 * (xx2 * xx7 + xx1 * xx8) - 6.0 * (xx3 * xx6 + xx4 * xx5))),
+                * (c(-6.0) * (ax1 * ax6 + ax3 * ax5)
+                    + eccentricity_0.integer_power(2)
+                        * (c(-24.0) * (xx2 * xx7 + xx1 * xx8) - c(6.0) * (xx3 * xx6 + xx4 * xx5))),
 
             // kₓ₃ = 2 pₓ₁ (Zₓ₁₃ - Zₓ₁₁)
-            kx3: 2.0 * px1 * (zx13 - zx11),
+            kx3: c(2.0) * px1 * (zx13 - zx11),
 
             // kₓ₄ = - 2 pₓ₀ (2 (6 (aₓ₁ aₓ₃ + aₓ₂ aₓ₄) + Zₓ₃₂ e₀²) + p₁ Zₓ₃₂)
-            kx4: -2.0
+            kx4: c(-2.0)
                 * px0
-                * ((6.0 * (ax1 * ax3 + ax2 * ax4) + zx32 * eccentricity_0.powi(2)) * 2.0
+                * ((c(6.0) * (ax1 * ax3 + ax2 * ax4) + zx32 * eccentricity_0.integer_power(2))
+                    * 2.0
                     + p1 * zx32),
 
             // kₓ₅ = - 2 pₓ₀ (Zₓ₃ - Zₓ₁)
-            kx5: -2.0 * px0 * (zx3 - zx1),
+            kx5: c(-2.0) * px0 * (zx3 - zx1),
 
             // kₓ₆ = - 2 pₓ₀ (- 21 - 9 e₀²) eₓ
-            kx6: -2.0 * px0 * (-21.0 - 9.0 * eccentricity_0.powi(2)) * third_body_eccentricity,
+            kx6: c(-2.0)
+                * px0
+                * (c(-21.0) - c(9.0) * eccentricity_0.integer_power(2))
+                * third_body_eccentricity,
 
             // kₓ₇ = 2 pₓ₂ Zₓ₃₂
-            kx7: 2.0 * px2 * zx32,
+            kx7: c(2.0) * px2 * zx32,
 
             // kₓ₈ = 2 pₓ₂ (Zₓ₃₃ - Zₓ₃₁)
-            kx8: 2.0 * px2 * (zx33 - zx31),
+            kx8: c(2.0) * px2 * (zx33 - zx31),
 
             // kₓ₉ = - 18 pₓ₂ eₓ
-            kx9: -18.0 * px2 * third_body_eccentricity,
+            kx9: c(-18.0) * px2 * third_body_eccentricity,
 
             // kₓ₁₀ = - 2 pₓ₁ (6 (aₓ₄ aₓ₅ + aₓ₂ aₓ₆) + e₀² (24 (Xₓ₂ Xₓ₅ + Xₓ₁ Xₓ₆) - 6 (Xₓ₄ Xₓ₇ + Xₓ₃ Xₓ₈)))
-            kx10: -2.0
+            kx10: c(-2.0)
                 * px1
-                * (6.0 * (ax4 * ax5 + ax2 * ax6)
-                    + eccentricity_0.powi(2)
-                        * (24.0 * (xx2 * xx5 + xx1 * xx6) - 6.0 * (xx4 * xx7 + xx3 * xx8))),
+                * (c(6.0) * (ax4 * ax5 + ax2 * ax6)
+                    + eccentricity_0.integer_power(2)
+                        * (c(24.0) * (xx2 * xx5 + xx1 * xx6) - c(6.0) * (xx4 * xx7 + xx3 * xx8))),
 
             // kₓ₁₁ = - 2 pₓ₁ (Zₓ₂₃ - Zₓ₂₁)
-            kx11: -2.0 * px1 * (zx23 - zx21),
+            kx11: c(-2.0) * px1 * (zx23 - zx21),
             third_body_mean_anomaly_0,
         },
         Dots {
@@ -244,44 +567,49 @@ pub(crate) fn perturbations_and_dots(
 
             // ω̇ₓ = pₓ₂ nₓ (Zₓ₃₁ + Zₓ₃₃ - 6) - cos I₀ Ω̇ₓ
             argument_of_perigee: px2 * third_body_mean_motion * (zx31 + zx33 - 6.0)
-                - inclination_0.cos() * third_body_right_ascension_dot,
+                - inclination_0.cosine() * third_body_right_ascension_dot,
 
             // Ṁₓ = - nₓ pₓ₀ (Zₓ₁ + Zₓ₃ - 14 - 6 e₀²)
-            mean_anomaly: -third_body_mean_motion
+            mean_anomaly: c(-third_body_mean_motion)
                 * px0
-                * (zx1 + zx3 - 14.0 - 6.0 * eccentricity_0.powi(2)),
+                * (zx1 + zx3 - 14.0 - c(6.0) * eccentricity_0.integer_power(2)),
         },
     )
 }
 
-impl Perturbations {
+impl<T: Real> Perturbations<T> {
     pub(crate) fn long_period_periodic_effects(
         &self,
         third_body_eccentricity: f64,
         third_body_mean_motion: f64,
-        t: f64,
-    ) -> (f64, f64, f64, f64, f64) {
+        t: T,
+    ) -> (T, T, T, T, T) {
+        let c = T::from_f64;
+
         // Mₓ = Mₓ₀ + nₓ t
-        let third_body_mean_anomaly = self.third_body_mean_anomaly_0 + third_body_mean_motion * t;
+        let third_body_mean_anomaly =
+            c(self.third_body_mean_anomaly_0) + c(third_body_mean_motion) * t;
 
         // fₓ = Mₓ + 2 eₓ sin Mₓ
-        let fx =
-            third_body_mean_anomaly + 2.0 * third_body_eccentricity * third_body_mean_anomaly.sin();
+        let fx = third_body_mean_anomaly
+            + c(2.0 * third_body_eccentricity) * trig::sin(third_body_mean_anomaly);
+
+        let (fx_sine, fx_cosine) = trig::sin_cos(fx);
 
         // Fₓ₂ = ¹/₂ sin²fₓ - ¹/₄
-        let fx2 = 0.5 * fx.sin().powi(2) - 0.25;
+        let fx2 = c(0.5) * fx_sine.integer_power(2) - 0.25;
 
         // Fₓ₃ = - ¹/₂ sin fₓ cos fₓ
-        let fx3 = -0.5 * fx.sin() * fx.cos();
+        let fx3 = c(-0.5) * fx_sine * fx_cosine;
         (
             // δeₓ = kₓ₀ Fₓ₂ + kₓ₁ Fₓ₃
             self.kx0 * fx2 + self.kx1 * fx3,
             // δIₓ = kₓ₂ Fₓ₂ + kₓ₃ Fₓ₃
             self.kx2 * fx2 + self.kx3 * fx3,
             // δMₓ = kₓ₄ Fₓ₂ + kₓ₅ Fₓ₃ + kₓ₆ sin fₓ
-            self.kx4 * fx2 + self.kx5 * fx3 + self.kx6 * fx.sin(),
+            self.kx4 * fx2 + self.kx5 * fx3 + self.kx6 * fx_sine,
             // pₓ₄ = kₓ₇ Fₓ₂ + kₓ₈ Fₓ₃ + kₓ₉ sin fₓ
-            self.kx7 * fx2 + self.kx8 * fx3 + self.kx9 * fx.sin(),
+            self.kx7 * fx2 + self.kx8 * fx3 + self.kx9 * fx_sine,
             // pₓ₅ = kₓ₁₀ Fₓ₂ + kₓ₁₁ Fₓ₃
             self.kx10 * fx2 + self.kx11 * fx3,
         )
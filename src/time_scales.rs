@@ -0,0 +1,90 @@
+use crate::tle;
+use chrono::{Datelike, Timelike};
+
+/// Converts a UTC datetime to a hifitime epoch
+///
+/// [hifitime](https://docs.rs/hifitime/3) 3 epochs are used by the nyx-space astrodynamics crates.
+/// Returns `None` if the datetime is outside hifitime's range.
+///
+/// # Arguments
+///
+/// * `datetime` - The UTC datetime
+pub fn datetime_to_hifitime(datetime: &chrono::NaiveDateTime) -> Option<hifitime::Epoch> {
+    hifitime::Epoch::maybe_from_gregorian_utc(
+        datetime.year(),
+        datetime.month() as u8,
+        datetime.day() as u8,
+        datetime.hour() as u8,
+        datetime.minute() as u8,
+        datetime.second() as u8,
+        datetime.nanosecond(),
+    )
+    .ok()
+}
+
+/// Converts a hifitime epoch to a UTC datetime
+///
+/// Returns `None` if the epoch is outside chrono's range.
+///
+/// # Arguments
+///
+/// * `epoch` - The hifitime epoch, in any time scale
+pub fn hifitime_to_datetime(epoch: &hifitime::Epoch) -> Option<chrono::NaiveDateTime> {
+    let (year, month, day, hour, minute, second, nanosecond) = epoch.to_gregorian_utc();
+    chrono::NaiveDate::from_ymd_opt(year, month as u32, day as u32)?.and_hms_nano_opt(
+        hour as u32,
+        minute as u32,
+        second as u32,
+        nanosecond,
+    )
+}
+
+impl tle::Elements {
+    /// Returns the elements' epoch as a hifitime epoch in the UTC time scale
+    ///
+    /// Returns `None` if the epoch is outside hifitime's range.
+    pub fn hifitime_epoch(&self) -> Option<hifitime::Epoch> {
+        datetime_to_hifitime(&self.datetime)
+    }
+
+    /// Returns the time difference in minutes between a hifitime epoch and the elements' epoch
+    ///
+    /// The epoch is converted to UTC, then the difference is calculated without leap seconds
+    /// as in [Elements::datetime_to_minutes_since_epoch](struct.Elements.html#method.datetime_to_minutes_since_epoch).
+    /// The resulting predictions are expressed in the True Equator, Mean Equinox (TEME) reference frame,
+    /// which must be rotated before being used with frames such as EME2000.
+    ///
+    /// Returns `None` if the epoch is outside chrono's range or too far from the elements' epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The propagation time, in any time scale
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let constants = sgp4::Constants::from_elements(&elements)?;
+    /// let epoch = sgp4::hifitime::Epoch::from_gregorian_utc_at_midnight(2020, 7, 13);
+    /// let prediction = constants.propagate(
+    ///     elements
+    ///         .hifitime_to_minutes_since_epoch(&epoch)
+    ///         .ok_or(anyhow::anyhow!("out of range"))?,
+    /// )?;
+    /// println!("r = {:?} km", prediction.position);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn hifitime_to_minutes_since_epoch(
+        &self,
+        epoch: &hifitime::Epoch,
+    ) -> Option<tle::MinutesSinceEpoch> {
+        self.datetime_to_minutes_since_epoch(&hifitime_to_datetime(epoch)?)
+            .ok()
+    }
+}
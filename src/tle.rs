@@ -1,3 +1,4 @@
+use crate::norad_id;
 use chrono::{Datelike, Timelike};
 
 #[cfg(feature = "alloc")]
@@ -86,11 +87,12 @@ impl core::fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
-trait TrimStart {
+pub(crate) trait TrimAscii {
     fn trim_ascii_start_polyfill(&self) -> &[u8];
+    fn trim_ascii_polyfill(&self) -> &[u8];
 }
 
-impl TrimStart for [u8] {
+impl TrimAscii for [u8] {
     fn trim_ascii_start_polyfill(&self) -> &[u8] {
         let mut bytes = self;
         while let [first, rest @ ..] = bytes {
@@ -102,6 +104,18 @@ impl TrimStart for [u8] {
         }
         bytes
     }
+
+    fn trim_ascii_polyfill(&self) -> &[u8] {
+        let mut bytes = self.trim_ascii_start_polyfill();
+        while let [rest @ .., last] = bytes {
+            if last.is_ascii_whitespace() {
+                bytes = rest;
+            } else {
+                break;
+            }
+        }
+        bytes
+    }
 }
 
 trait FromU8: Sized {
@@ -224,7 +238,62 @@ impl DecimalPointAssumedRepresentation for [u8] {
     }
 }
 
+// parses the international designator (columns 10 to 17 of the first line) as "YYYY-NNNPPP"
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+fn parse_international_designator<S: core::fmt::Write + Default>(
+    line1: &[u8],
+) -> core::result::Result<Option<S>, Error> {
+    if line1[9..17]
+        .iter()
+        .all(|character| character.is_ascii_whitespace())
+    {
+        return Ok(None);
+    }
+    let mut international_designator = S::default();
+    write!(
+        international_designator,
+        "{}-{}",
+        match line1[9..11].parse::<u8>().map_err(|_| Error {
+            what: ErrorWhat::ExpectedInteger,
+            line: ErrorLine::Line1,
+            start: 9,
+            end: 11,
+        })? {
+            launch_year if launch_year < 57 => 2000 + launch_year as u16,
+            launch_year => 1900 + launch_year as u16,
+        },
+        core::str::from_utf8(&line1[11..17])
+            .map_err(|_| Error {
+                what: ErrorWhat::ExpectedString,
+                line: ErrorLine::Line1,
+                start: 11,
+                end: 17,
+            })?
+            .trim()
+    )
+    .map_err(|_| Error {
+        what: ErrorWhat::ExpectedString,
+        line: ErrorLine::Line1,
+        start: 9,
+        end: 17,
+    })?;
+    Ok(Some(international_designator))
+}
+
+/// Fixed-capacity object name used without the `alloc` feature
+///
+/// Longer names are truncated at a character boundary.
+#[cfg(all(feature = "heapless", not(feature = "alloc")))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "heapless", not(feature = "alloc")))))]
+pub type ObjectName = heapless::String<32>;
+
+/// Fixed-capacity international designator used without the `alloc` feature
+#[cfg(all(feature = "heapless", not(feature = "alloc")))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "heapless", not(feature = "alloc")))))]
+pub type InternationalDesignator = heapless::String<16>;
+
 /// A satellite's elements classification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Classification {
     /// Declassfied objects or objects without a classification
@@ -279,6 +348,7 @@ pub enum Classification {
 /// )?;
 /// #     Ok(())
 /// # }
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Elements {
     /// The name associated with the satellite
@@ -290,6 +360,11 @@ pub struct Elements {
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     pub object_name: Option<alloc::string::String>,
 
+    /// The name associated with the satellite
+    #[cfg(all(feature = "heapless", not(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "heapless", not(feature = "alloc")))))]
+    pub object_name: Option<ObjectName>,
+
     /// The satellite's international designator
     ///
     /// It consists of the launch year, the launch number of that year and
@@ -299,12 +374,14 @@ pub struct Elements {
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     pub international_designator: Option<alloc::string::String>,
 
+    /// The satellite's international designator
+    #[cfg(all(feature = "heapless", not(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "heapless", not(feature = "alloc")))))]
+    pub international_designator: Option<InternationalDesignator>,
+
     /// The catalog number USSPACECOM has designated for this object
-    #[cfg_attr(
-        feature = "serde",
-        serde(rename = "NORAD_CAT_ID", deserialize_with = "u64_or_string")
-    )]
-    pub norad_id: u64,
+    #[cfg_attr(feature = "serde", serde(rename = "NORAD_CAT_ID"))]
+    pub norad_id: norad_id::NoradId,
 
     /// The elements' classification
     #[cfg_attr(feature = "serde", serde(rename = "CLASSIFICATION_TYPE"))]
@@ -488,12 +565,210 @@ pub fn julian_years_since_j2000_afspc_compatibility_mode(datetime: &chrono::Naiv
         / 365.25
 }
 
+/// Returns the time difference in minutes between two datetimes
+///
+/// This function uses the same conversion as
+/// [Elements::datetime_to_minutes_since_epoch](struct.Elements.html#method.datetime_to_minutes_since_epoch),
+/// for epochs that do not come from an `Elements` object. It does not take leap seconds into account.
+/// The difference is calculated in nanoseconds, hence it fails if the datetimes are more than about 292 years apart.
+///
+/// # Arguments
+///
+/// * `epoch` - The reference datetime
+/// * `datetime` - The datetime to convert, which may be before the epoch
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let epoch = sgp4::chrono::NaiveDate::from_ymd_opt(2020, 7, 12)
+///     .unwrap()
+///     .and_hms_opt(21, 0, 0)
+///     .unwrap();
+/// let datetime = sgp4::chrono::NaiveDate::from_ymd_opt(2020, 7, 13)
+///     .unwrap()
+///     .and_hms_milli_opt(3, 30, 15, 500)
+///     .unwrap();
+/// let t = sgp4::minutes_since_epoch(&epoch, &datetime)?;
+/// assert!((t.0 - (6.0 * 60.0 + 30.0 + 15.5 / 60.0)).abs() < 1e-9);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn minutes_since_epoch(
+    epoch: &chrono::NaiveDateTime,
+    datetime: &chrono::NaiveDateTime,
+) -> core::result::Result<MinutesSinceEpoch, DatetimeToMinutesSinceEpochError> {
+    (*datetime - *epoch)
+        .num_nanoseconds()
+        .ok_or(DatetimeToMinutesSinceEpochError {
+            from: *epoch,
+            to: *datetime,
+        })
+        .map(|nanoseconds| MinutesSinceEpoch(nanoseconds as f64 / 60e9))
+}
+
 /// Minutes ellapsed since the elements' epoch
 ///
 /// This number can be negative since SGP4 can propagate back in time.
-#[derive(Debug, Clone, Copy)]
+///
+/// Adding or subtracting a number (in minutes), a `chrono::Duration` or a `core::time::Duration`
+/// shifts the time, and the difference of two times is a number of minutes.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+///     Some("ISS (ZARYA)".to_owned()),
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+/// )?)?;
+/// let end = sgp4::MinutesSinceEpoch(0.0) + sgp4::chrono::Duration::hours(2);
+/// let mut t = sgp4::MinutesSinceEpoch(0.0);
+/// while t < end {
+///     let prediction = constants.propagate(t)?;
+///     println!("r = {:?} km", prediction.position);
+///     t += core::time::Duration::from_secs(30);
+/// }
+/// assert_eq!(end - sgp4::MinutesSinceEpoch(60.0), 60.0);
+/// assert_eq!(sgp4::chrono::Duration::try_from(end)?, sgp4::chrono::Duration::hours(2));
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MinutesSinceEpoch(pub f64);
 
+impl core::ops::Add<f64> for MinutesSinceEpoch {
+    type Output = MinutesSinceEpoch;
+
+    fn add(self, minutes: f64) -> Self::Output {
+        MinutesSinceEpoch(self.0 + minutes)
+    }
+}
+
+impl core::ops::Sub<f64> for MinutesSinceEpoch {
+    type Output = MinutesSinceEpoch;
+
+    fn sub(self, minutes: f64) -> Self::Output {
+        MinutesSinceEpoch(self.0 - minutes)
+    }
+}
+
+impl core::ops::AddAssign<f64> for MinutesSinceEpoch {
+    fn add_assign(&mut self, minutes: f64) {
+        self.0 += minutes;
+    }
+}
+
+impl core::ops::SubAssign<f64> for MinutesSinceEpoch {
+    fn sub_assign(&mut self, minutes: f64) {
+        self.0 -= minutes;
+    }
+}
+
+impl core::ops::Sub for MinutesSinceEpoch {
+    type Output = f64;
+
+    fn sub(self, other: MinutesSinceEpoch) -> Self::Output {
+        self.0 - other.0
+    }
+}
+
+impl From<chrono::Duration> for MinutesSinceEpoch {
+    fn from(duration: chrono::Duration) -> Self {
+        MinutesSinceEpoch(
+            duration.num_seconds() as f64 / 60.0 + duration.subsec_nanos() as f64 / 60e9,
+        )
+    }
+}
+
+impl From<core::time::Duration> for MinutesSinceEpoch {
+    fn from(duration: core::time::Duration) -> Self {
+        MinutesSinceEpoch(duration.as_secs_f64() / 60.0)
+    }
+}
+
+impl TryFrom<MinutesSinceEpoch> for chrono::Duration {
+    type Error = MinutesSinceEpochToDatetimeError;
+
+    fn try_from(minutes_since_epoch: MinutesSinceEpoch) -> Result<Self, Self::Error> {
+        let nanoseconds = minutes_since_epoch.0 * 60e9;
+        if nanoseconds > i64::MAX as f64 || nanoseconds < i64::MIN as f64 || nanoseconds.is_nan() {
+            Err(MinutesSinceEpochToDatetimeError::MinutesToNanoseconds(
+                minutes_since_epoch.0,
+            ))
+        } else {
+            Ok(chrono::Duration::nanoseconds(nanoseconds.round() as i64))
+        }
+    }
+}
+
+impl TryFrom<MinutesSinceEpoch> for core::time::Duration {
+    type Error = core::time::TryFromFloatSecsError;
+
+    /// Fails if the number of minutes is negative, not finite or too large
+    fn try_from(minutes_since_epoch: MinutesSinceEpoch) -> Result<Self, Self::Error> {
+        core::time::Duration::try_from_secs_f64(minutes_since_epoch.0 * 60.0)
+    }
+}
+
+impl core::ops::Add<chrono::Duration> for MinutesSinceEpoch {
+    type Output = MinutesSinceEpoch;
+
+    fn add(self, duration: chrono::Duration) -> Self::Output {
+        self + MinutesSinceEpoch::from(duration).0
+    }
+}
+
+impl core::ops::Sub<chrono::Duration> for MinutesSinceEpoch {
+    type Output = MinutesSinceEpoch;
+
+    fn sub(self, duration: chrono::Duration) -> Self::Output {
+        self - MinutesSinceEpoch::from(duration).0
+    }
+}
+
+impl core::ops::AddAssign<chrono::Duration> for MinutesSinceEpoch {
+    fn add_assign(&mut self, duration: chrono::Duration) {
+        *self += MinutesSinceEpoch::from(duration).0;
+    }
+}
+
+impl core::ops::SubAssign<chrono::Duration> for MinutesSinceEpoch {
+    fn sub_assign(&mut self, duration: chrono::Duration) {
+        *self -= MinutesSinceEpoch::from(duration).0;
+    }
+}
+
+impl core::ops::Add<core::time::Duration> for MinutesSinceEpoch {
+    type Output = MinutesSinceEpoch;
+
+    fn add(self, duration: core::time::Duration) -> Self::Output {
+        self + MinutesSinceEpoch::from(duration).0
+    }
+}
+
+impl core::ops::Sub<core::time::Duration> for MinutesSinceEpoch {
+    type Output = MinutesSinceEpoch;
+
+    fn sub(self, duration: core::time::Duration) -> Self::Output {
+        self - MinutesSinceEpoch::from(duration).0
+    }
+}
+
+impl core::ops::AddAssign<core::time::Duration> for MinutesSinceEpoch {
+    fn add_assign(&mut self, duration: core::time::Duration) {
+        *self += MinutesSinceEpoch::from(duration).0;
+    }
+}
+
+impl core::ops::SubAssign<core::time::Duration> for MinutesSinceEpoch {
+    fn sub_assign(&mut self, duration: core::time::Duration) {
+        *self -= MinutesSinceEpoch::from(duration).0;
+    }
+}
+
 /// Nanoseconds overflow while converting from datetime to minutes since epoch
 ///
 /// 2⁶⁴ nanoseconds correspond to about 585 years.
@@ -546,8 +821,111 @@ impl core::fmt::Display for MinutesSinceEpochToDatetimeError {
 #[cfg(feature = "std")]
 impl std::error::Error for MinutesSinceEpochToDatetimeError {}
 
+/// Represents an error raised while formatting elements as a TLE
+#[derive(Debug, Clone)]
+pub enum FormatError {
+    /// The NORAD ID is larger than the largest Alpha-5 number (339999)
+    NoradIdOutOfRange(norad_id::NoradId),
+
+    /// The epoch year is outside the range covered by two-digit years (1957 to 2056)
+    EpochOutOfRange(chrono::NaiveDateTime),
+
+    /// The international designator does not follow the YYYY-NNNP pattern
+    InvalidInternationalDesignator,
+
+    /// A numerical field cannot be represented in the TLE format
+    OutOfRange { field: &'static str, value: f64 },
+}
+
+impl core::fmt::Display for FormatError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FormatError::NoradIdOutOfRange(norad_id) => formatter.write_fmt(format_args!(
+                "The NORAD ID {norad_id} does not fit in a TLE"
+            )),
+            FormatError::EpochOutOfRange(datetime) => formatter.write_fmt(format_args!(
+                "The epoch {datetime} is outside the TLE years range (1957 to 2056)"
+            )),
+            FormatError::InvalidInternationalDesignator => {
+                formatter.write_str("The international designator does not match YYYY-NNNP")
+            }
+            FormatError::OutOfRange { field, value } => formatter.write_fmt(format_args!(
+                "The {field} {value} cannot be represented in a TLE"
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FormatError {}
+
+// TLE checksum (sum of the digits plus the number of minus signs, modulo 10)
+pub(crate) fn checksum(line: &[u8]) -> u8 {
+    (line
+        .iter()
+        .fold(0, |accumulator, character| match character {
+            b'-' => accumulator + 1,
+            character if character.is_ascii_digit() => accumulator + (character - b'0') as u16,
+            _ => accumulator,
+        })
+        % 10) as u8
+}
+
+// angle in deg formatted with 4 decimals in the range [0, 360[
+#[cfg(feature = "alloc")]
+fn format_angle(
+    field: &'static str,
+    angle: f64,
+) -> core::result::Result<alloc::string::String, FormatError> {
+    if !angle.is_finite() {
+        return Err(FormatError::OutOfRange {
+            field,
+            value: angle,
+        });
+    }
+    let mut angle = ((angle % 360.0 + 360.0) % 360.0 * 1e4).round() / 1e4;
+    if angle >= 360.0 {
+        angle -= 360.0;
+    }
+    Ok(format!("{angle:8.4}"))
+}
+
+// number with an assumed decimal point and a power of ten exponent (for example -11606-4 for -0.11606 × 10⁻⁴)
+// values too small for the exponent -9 are written with leading zeros, or as zero if the mantissa rounds to zero
+#[cfg(feature = "alloc")]
+fn format_exponential(
+    field: &'static str,
+    value: f64,
+) -> core::result::Result<alloc::string::String, FormatError> {
+    if !value.is_finite() {
+        return Err(FormatError::OutOfRange { field, value });
+    }
+    if value == 0.0 {
+        return Ok(" 00000-0".to_owned());
+    }
+    let mut exponent = (value.abs().log10().floor() as i32 + 1).max(-9);
+    let mut mantissa = (value.abs() / 10.0_f64.powi(exponent) * 1e5).round() as u32;
+    if mantissa == 0 {
+        return Ok(" 00000-0".to_owned());
+    }
+    if mantissa >= 100000 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    if exponent > 9 {
+        return Err(FormatError::OutOfRange { field, value });
+    }
+    Ok(format!(
+        "{}{:05}{}{}",
+        if value < 0.0 { '-' } else { ' ' },
+        mantissa,
+        if exponent <= 0 { '-' } else { '+' },
+        exponent.abs()
+    ))
+}
+
 impl Elements {
-    fn from_lines(line1: &[u8], line2: &[u8]) -> core::result::Result<Elements, Error> {
+    pub(crate) fn from_lines(line1: &[u8], line2: &[u8]) -> core::result::Result<Elements, Error> {
         if line1.len() != 69 {
             return Err(Error {
                 what: ErrorWhat::BadLength,
@@ -600,25 +978,19 @@ impl Elements {
                 });
             }
         }
-        let norad_id = line1[2..7]
-            .trim_ascii_start_polyfill()
-            .parse::<u64>()
-            .map_err(|_| Error {
+        let norad_id = norad_id::NoradId::from_alpha5(&line1[2..7]).map_err(|_| Error {
+            what: ErrorWhat::ExpectedInteger,
+            line: ErrorLine::Line1,
+            start: 2,
+            end: 7,
+        })?;
+        if norad_id
+            != norad_id::NoradId::from_alpha5(&line2[2..7]).map_err(|_| Error {
                 what: ErrorWhat::ExpectedInteger,
-                line: ErrorLine::Line1,
+                line: ErrorLine::Line2,
                 start: 2,
                 end: 7,
-            })?;
-        if norad_id
-            != line2[2..7]
-                .trim_ascii_start_polyfill()
-                .parse::<u64>()
-                .map_err(|_| Error {
-                    what: ErrorWhat::ExpectedInteger,
-                    line: ErrorLine::Line2,
-                    start: 2,
-                    end: 7,
-                })?
+            })?
         {
             return Err(Error {
                 what: ErrorWhat::NoradIdMismatch,
@@ -628,18 +1000,7 @@ impl Elements {
             });
         }
         for (line, content) in [(ErrorLine::Line1, &line1), (ErrorLine::Line2, &line2)] {
-            if (content[..68]
-                .iter()
-                .fold(0, |accumulator, character| match character {
-                    b'-' => accumulator + 1,
-                    character if (&b'0'..=&b'9').contains(&character) => {
-                        accumulator + (character - b'0') as u16
-                    }
-                    _ => accumulator,
-                })
-                % 10) as u8
-                != content[68] - b'0'
-            {
+            if checksum(&content[..68]) != content[68] - b'0' {
                 return Err(Error {
                     what: ErrorWhat::BadChecksum,
                     line,
@@ -649,7 +1010,7 @@ impl Elements {
             }
         }
         Ok(Elements {
-            #[cfg(feature = "alloc")]
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
             object_name: None,
             norad_id,
             classification: match line1[7] {
@@ -665,34 +1026,8 @@ impl Elements {
                     })
                 }
             },
-            #[cfg(feature = "alloc")]
-            international_designator: if line1[9..17]
-                .iter()
-                .all(|character| character.is_ascii_whitespace())
-            {
-                None
-            } else {
-                Some(format!(
-                    "{}-{}",
-                    match line1[9..11].parse::<u8>().map_err(|_| Error {
-                        what: ErrorWhat::ExpectedInteger,
-                        line: ErrorLine::Line1,
-                        start: 9,
-                        end: 11,
-                    })? {
-                        launch_year if launch_year < 57 => 2000 + launch_year as u16,
-                        launch_year => 1900 + launch_year as u16,
-                    },
-                    core::str::from_utf8(&line1[11..17])
-                        .map_err(|_| Error {
-                            what: ErrorWhat::ExpectedString,
-                            line: ErrorLine::Line1,
-                            start: 11,
-                            end: 17,
-                        })?
-                        .trim()
-                ))
-            },
+            #[cfg(any(feature = "alloc", feature = "heapless"))]
+            international_designator: parse_international_designator(line1)?,
             datetime: {
                 let day = line1[20..32]
                     .trim_ascii_start_polyfill()
@@ -875,6 +1210,56 @@ impl Elements {
         Self::from_lines(line1, line2)
     }
 
+    /// Parses a Three-Line Element Set (3LE), that is a TLE preceded by a title line
+    ///
+    /// Without the `alloc` feature, the `heapless` feature stores the object name in an [ObjectName](type.ObjectName.html),
+    /// truncated at a character boundary if it is longer than 32 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `line0` - The title line, used as object name
+    /// * `line1` - The first line of the TLE composed of 69 ASCII characters
+    /// * `line2` - The second line of the TLE composed of 69 ASCII characters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_3le(
+    ///     "ISS (ZARYA)",
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// assert_eq!(elements.object_name.as_deref(), Some("ISS (ZARYA)"));
+    /// assert_eq!(elements.international_designator.as_deref(), Some("1998-067A"));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "heapless"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "heapless"))))]
+    pub fn from_3le(
+        line0: &str,
+        line1: &[u8],
+        line2: &[u8],
+    ) -> core::result::Result<Elements, Error> {
+        let mut result = Self::from_lines(line1, line2)?;
+        #[cfg(feature = "alloc")]
+        {
+            result.object_name = Some(line0.to_owned());
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            let mut length = line0.len().min(32);
+            while !line0.is_char_boundary(length) {
+                length -= 1;
+            }
+            let mut object_name = ObjectName::new();
+            let _ = object_name.push_str(&line0[..length]);
+            result.object_name = Some(object_name);
+        }
+        Ok(result)
+    }
+
     /// Returns the number of years since UTC 1 January 2000 12h00 (J2000)
     ///
     /// This is the recommended method to calculate the epoch
@@ -896,13 +1281,7 @@ impl Elements {
         &self,
         datetime: &chrono::NaiveDateTime,
     ) -> core::result::Result<MinutesSinceEpoch, DatetimeToMinutesSinceEpochError> {
-        (*datetime - self.datetime)
-            .num_nanoseconds()
-            .ok_or(DatetimeToMinutesSinceEpochError {
-                from: self.datetime,
-                to: *datetime,
-            })
-            .map(|nanoseconds| MinutesSinceEpoch(nanoseconds as f64 / 60e9))
+        minutes_since_epoch(&self.datetime, datetime)
     }
 
     /// Builds a datetime from a number of minutes since epoch
@@ -912,20 +1291,142 @@ impl Elements {
         &self,
         minutes_since_epoch: &MinutesSinceEpoch,
     ) -> core::result::Result<chrono::NaiveDateTime, MinutesSinceEpochToDatetimeError> {
-        let nanoseconds = minutes_since_epoch.0 * 60e9;
-        if nanoseconds > i64::MAX as f64 || nanoseconds < i64::MIN as f64 {
-            Err(MinutesSinceEpochToDatetimeError::MinutesToNanoseconds(
-                minutes_since_epoch.0,
-            ))
-        } else {
-            let duration = chrono::Duration::nanoseconds(nanoseconds.round() as i64);
-            self.datetime.checked_add_signed(duration).ok_or(
-                MinutesSinceEpochToDatetimeError::Add {
-                    datetime: self.datetime,
-                    duration,
-                },
-            )
+        let duration = chrono::Duration::try_from(*minutes_since_epoch)?;
+        self.datetime
+            .checked_add_signed(duration)
+            .ok_or(MinutesSinceEpochToDatetimeError::Add {
+                datetime: self.datetime,
+                duration,
+            })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Elements {
+    /// Formats the elements as a Two-Line Element Set (TLE)
+    ///
+    /// Values are rounded to the TLE's fixed precision, the element set number
+    /// and the revolution number are written modulo 10⁴ and 10⁵, and the checksums are recalculated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// let (line1, line2) = elements.to_tle()?;
+    /// assert_eq!(line1, "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992");
+    /// assert_eq!(line2, "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_tle(
+        &self,
+    ) -> core::result::Result<(alloc::string::String, alloc::string::String), FormatError> {
+        let norad_id: alloc::string::String = self
+            .norad_id
+            .to_alpha5()
+            .map_err(|_| FormatError::NoradIdOutOfRange(self.norad_id))?
+            .iter()
+            .map(|byte| *byte as char)
+            .collect();
+        // the epoch is rounded to 8 decimals of a day (864 µs) before it is split into a year and a day,
+        // so that instants close to midnight on 31 December carry over to the next year
+        let nanoseconds = self.datetime.num_seconds_from_midnight() as u64 * 1_000_000_000
+            + self.datetime.nanosecond() as u64;
+        let mut date = self.datetime.date();
+        let mut day_fraction = (nanoseconds + 432_000) / 864_000;
+        if day_fraction >= 100_000_000 {
+            date = date
+                .succ_opt()
+                .ok_or(FormatError::EpochOutOfRange(self.datetime))?;
+            day_fraction -= 100_000_000;
+        }
+        if !(1957..2057).contains(&date.year()) {
+            return Err(FormatError::EpochOutOfRange(self.datetime));
+        }
+        let international_designator = match &self.international_designator {
+            Some(international_designator) => match international_designator.split_once('-') {
+                Some((year, piece))
+                    if year.len() == 4
+                        && year.bytes().all(|character| character.is_ascii_digit())
+                        && piece.len() <= 6 =>
+                {
+                    format!("{}{:<6}", &year[2..], piece)
+                }
+                _ => return Err(FormatError::InvalidInternationalDesignator),
+            },
+            None => "        ".to_owned(),
+        };
+
+        // numerical fields are rounded to the TLE precision before their range is checked,
+        // NaN and infinite values fail the checks
+        let mean_motion_dot = (self.mean_motion_dot.abs() * 1e8).round();
+        if !(0.0..1e8).contains(&mean_motion_dot) {
+            return Err(FormatError::OutOfRange {
+                field: "mean motion derivative",
+                value: self.mean_motion_dot,
+            });
         }
+        let eccentricity = (self.eccentricity * 1e7).round();
+        if !(0.0..1e7).contains(&eccentricity) {
+            return Err(FormatError::OutOfRange {
+                field: "eccentricity",
+                value: self.eccentricity,
+            });
+        }
+        let mean_motion = (self.mean_motion * 1e8).round();
+        if !(0.0..1e10).contains(&mean_motion) {
+            return Err(FormatError::OutOfRange {
+                field: "mean motion",
+                value: self.mean_motion,
+            });
+        }
+        if self.ephemeris_type > 9 {
+            return Err(FormatError::OutOfRange {
+                field: "ephemeris type",
+                value: self.ephemeris_type as f64,
+            });
+        }
+        let mut line1 = format!(
+            "1 {}{} {} {:02}{:03}.{:08} {}.{:08} {} {} {} {:4}",
+            norad_id,
+            match self.classification {
+                Classification::Unclassified => 'U',
+                Classification::Classified => 'C',
+                Classification::Secret => 'S',
+            },
+            international_designator,
+            date.year() % 100,
+            date.ordinal(),
+            day_fraction,
+            if self.mean_motion_dot < 0.0 { '-' } else { ' ' },
+            mean_motion_dot as u32,
+            format_exponential("mean motion second derivative", self.mean_motion_ddot)?,
+            format_exponential("drag term", self.drag_term)?,
+            self.ephemeris_type,
+            self.element_set_number % 10000,
+        );
+        line1.push((b'0' + checksum(line1.as_bytes())) as char);
+        let mean_motion = mean_motion as u64;
+        let mut line2 = format!(
+            "2 {} {} {} {:07} {} {} {:2}.{:08}{:5}",
+            norad_id,
+            format_angle("inclination", self.inclination)?,
+            format_angle("right ascension", self.right_ascension)?,
+            eccentricity as u32,
+            format_angle("argument of perigee", self.argument_of_perigee)?,
+            format_angle("mean anomaly", self.mean_anomaly)?,
+            mean_motion / 100_000_000,
+            mean_motion % 100_000_000,
+            self.revolution_number % 100000,
+        );
+        line2.push((b'0' + checksum(line2.as_bytes())) as char);
+        Ok((line1, line2))
     }
 }
 
@@ -979,8 +1480,8 @@ pub fn parse_3les(tles: &str) -> core::result::Result<alloc::vec::Vec<Elements>,
                 index += 1;
             }
             _ => {
-                elements_vec.push(Elements::from_tle(
-                    Some(lines_buffer[0].to_owned()),
+                elements_vec.push(Elements::from_3le(
+                    lines_buffer[0],
                     lines_buffer[1].as_bytes(),
                     line.as_bytes(),
                 )?);
@@ -991,6 +1492,99 @@ pub fn parse_3les(tles: &str) -> core::result::Result<alloc::vec::Vec<Elements>,
     Ok(elements_vec)
 }
 
+/// Iterator over the TLEs of a byte slice, returned by [iter_tles](fn.iter_tles.html)
+#[derive(Debug, Clone)]
+pub struct Tles<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Tles<'a> {
+    // returns the next line without its terminator (LF or CRLF), or None at the end of the input
+    fn next_line(&mut self) -> Option<&'a [u8]> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        let line = match self.bytes.iter().position(|byte| *byte == b'\n') {
+            Some(index) => {
+                let line = &self.bytes[..index];
+                self.bytes = &self.bytes[index + 1..];
+                line
+            }
+            None => core::mem::take(&mut self.bytes),
+        };
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+}
+
+impl Iterator for Tles<'_> {
+    type Item = core::result::Result<Elements, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = self.next_line()?;
+        while line.trim_ascii_start_polyfill().is_empty() {
+            line = self.next_line()?;
+        }
+        if line.len() == 69 && line.starts_with(b"1 ") {
+            return Some(Elements::from_lines(
+                line,
+                self.next_line().unwrap_or_default(),
+            ));
+        }
+        let line1 = self.next_line().unwrap_or_default();
+        let line2 = self.next_line().unwrap_or_default();
+        #[cfg(any(feature = "alloc", feature = "heapless"))]
+        {
+            // invalid UTF-8 sequences truncate the object name
+            let line0 = match core::str::from_utf8(line) {
+                Ok(line0) => line0,
+                Err(error) => core::str::from_utf8(&line[..error.valid_up_to()]).unwrap_or(""),
+            };
+            Some(Elements::from_3le(line0, line1, line2))
+        }
+        #[cfg(not(any(feature = "alloc", feature = "heapless")))]
+        {
+            Some(Elements::from_lines(line1, line2))
+        }
+    }
+}
+
+/// Parses the TLEs of a byte slice lazily, without copying the input
+///
+/// The input may contain 2LEs, 3LEs (a title line followed by a TLE), or a mix of both.
+/// Lines may end with LF or CRLF, and blank lines are skipped.
+/// Each item is parsed when the iterator advances, hence a TLE that fails to parse
+/// does not prevent the following ones from being read.
+///
+/// Since the input is a byte slice, this function can read a memory-mapped file directly
+/// (for example with the memmap2 crate), without reading large archives into a `String`.
+/// The object name is the only copied field. It requires the `alloc` or `heapless` feature,
+/// and is dropped otherwise.
+///
+/// # Arguments
+///
+/// * `bytes` - The content of a TLE catalog
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// let bytes = b"ISS (ZARYA)
+/// 1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992
+/// 2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008
+/// 1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+/// 2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656
+/// ";
+/// let elements_vec = sgp4::iter_tles(bytes).collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(elements_vec.len(), 2);
+/// assert_eq!(elements_vec[0].object_name.as_deref(), Some("ISS (ZARYA)"));
+/// assert_eq!(elements_vec[1].norad_id, 8195);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn iter_tles(bytes: &[u8]) -> Tles<'_> {
+    Tles { bytes }
+}
+
 #[cfg(feature = "serde")]
 #[cfg(test)]
 mod tests {
@@ -1320,4 +1914,153 @@ mod tests {
         assert_eq!(elements_vec.len(), 2);
         Ok(())
     }
+
+    #[test]
+    fn test_alpha5_norad_id() -> core::result::Result<(), Error> {
+        let mut elements = Elements::from_tle(
+            None,
+            "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+            "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+        )?;
+        elements.norad_id = norad_id::NoradId::new(148493).unwrap();
+        let (line1, line2) = elements.to_tle().unwrap();
+        assert_eq!(&line1[2..7], "E8493");
+        assert_eq!(&line2[2..7], "E8493");
+        assert_eq!(
+            Elements::from_tle(None, line1.as_bytes(), line2.as_bytes())?.norad_id,
+            148493
+        );
+        elements.norad_id = norad_id::NoradId::new(340000).unwrap();
+        assert!(matches!(
+            elements.to_tle(),
+            Err(FormatError::NoradIdOutOfRange(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_tle_epoch_rollover() -> core::result::Result<(), Error> {
+        let mut elements = Elements::from_tle(
+            None,
+            "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+            "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+        )?;
+        let last_day = chrono::NaiveDate::from_ymd_opt(2020, 12, 31).unwrap();
+        elements.datetime = last_day.and_hms_opt(12, 0, 0).unwrap();
+        assert_eq!(&elements.to_tle().unwrap().0[18..32], "20366.50000000");
+        elements.datetime = last_day.and_hms_micro_opt(23, 59, 59, 999900).unwrap();
+        assert_eq!(&elements.to_tle().unwrap().0[18..32], "21001.00000000");
+        elements.datetime = chrono::NaiveDate::from_ymd_opt(2056, 12, 31)
+            .and_then(|date| date.and_hms_micro_opt(23, 59, 59, 999900))
+            .unwrap();
+        assert!(matches!(
+            elements.to_tle(),
+            Err(FormatError::EpochOutOfRange(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_exponential() {
+        assert_eq!(
+            format_exponential("drag term", -0.31515e-4).unwrap(),
+            "-31515-4"
+        );
+        assert_eq!(format_exponential("drag term", 0.0).unwrap(), " 00000-0");
+        assert_eq!(
+            format_exponential("drag term", 0.99999e-9).unwrap(),
+            " 99999-9"
+        );
+        assert_eq!(
+            format_exponential("drag term", 3.0e-11).unwrap(),
+            " 03000-9"
+        );
+        assert_eq!(
+            format_exponential("drag term", 1.0e-15).unwrap(),
+            " 00000-0"
+        );
+        assert_eq!(
+            format_exponential("drag term", -1.0e-300).unwrap(),
+            " 00000-0"
+        );
+        assert!(format_exponential("drag term", 1.0e9).is_err());
+        assert!(format_exponential("drag term", f64::NAN).is_err());
+        assert!(format_exponential("drag term", f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_to_tle_rounding_and_non_finite_values() -> core::result::Result<(), Error> {
+        let mut elements = Elements::from_tle(
+            None,
+            "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+            "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+        )?;
+        elements.drag_term = 4.0e-15;
+        elements.mean_motion_ddot = -2.0e-15;
+        let (line1, line2) = elements.to_tle().unwrap();
+        assert_eq!(&line1[44..61], " 00000-0  00000-0");
+        assert_eq!(
+            Elements::from_tle(None, line1.as_bytes(), line2.as_bytes())?.drag_term,
+            0.0
+        );
+        elements.mean_motion = 99.999999994;
+        let line2 = elements.to_tle().unwrap().1;
+        assert_eq!(line2.len(), 69);
+        assert_eq!(&line2[52..63], "99.99999999");
+        elements.mean_motion = 99.999999999;
+        assert!(matches!(
+            elements.to_tle(),
+            Err(FormatError::OutOfRange {
+                field: "mean motion",
+                ..
+            })
+        ));
+        type Setter = fn(&mut Elements, f64);
+        let setters: [(&str, Setter); 9] = [
+            ("mean motion derivative", |elements, value| {
+                elements.mean_motion_dot = value
+            }),
+            ("mean motion second derivative", |elements, value| {
+                elements.mean_motion_ddot = value
+            }),
+            ("drag term", |elements, value| elements.drag_term = value),
+            ("inclination", |elements, value| {
+                elements.inclination = value
+            }),
+            ("right ascension", |elements, value| {
+                elements.right_ascension = value
+            }),
+            ("eccentricity", |elements, value| {
+                elements.eccentricity = value
+            }),
+            ("argument of perigee", |elements, value| {
+                elements.argument_of_perigee = value
+            }),
+            ("mean anomaly", |elements, value| {
+                elements.mean_anomaly = value
+            }),
+            ("mean motion", |elements, value| {
+                elements.mean_motion = value
+            }),
+        ];
+        for (field, set) in setters {
+            for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                let mut elements = Elements::from_tle(
+                    None,
+                    "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992"
+                        .as_bytes(),
+                    "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008"
+                        .as_bytes(),
+                )?;
+                set(&mut elements, value);
+                match elements.to_tle() {
+                    Err(FormatError::OutOfRange {
+                        field: error_field, ..
+                    }) => assert_eq!(error_field, field),
+                    result => panic!("{field} {value}: {result:?}"),
+                }
+            }
+        }
+        Ok(())
+    }
 }
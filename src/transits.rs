@@ -0,0 +1,251 @@
+use crate::ephemeris;
+use crate::events;
+use crate::gp;
+use crate::model;
+use crate::observer;
+use crate::propagator;
+use crate::tle::MinutesSinceEpoch;
+use crate::vector;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// R☉ = 695700 km
+const SUN_RADIUS: f64 = 695700.0;
+
+// Rₗ = 1737.4 km
+const MOON_RADIUS: f64 = 1737.4;
+
+/// Celestial body whose disk may be crossed by a satellite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransitBody {
+    /// The Sun, see [sgp4::sun_position](fn.sun_position.html)
+    Sun,
+
+    /// The Moon, see [sgp4::moon_position](fn.moon_position.html)
+    Moon,
+}
+
+impl TransitBody {
+    /// Returns the approximate position of the body in the TEME reference frame in km
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - Years since UTC 1 January 2000 12h00
+    pub fn position(&self, epoch: f64) -> [f64; 3] {
+        match self {
+            TransitBody::Sun => ephemeris::sun_position(epoch),
+            TransitBody::Moon => ephemeris::moon_position(epoch),
+        }
+    }
+
+    /// Returns the mean radius of the body in km
+    pub fn radius(&self) -> f64 {
+        match self {
+            TransitBody::Sun => SUN_RADIUS,
+            TransitBody::Moon => MOON_RADIUS,
+        }
+    }
+}
+
+/// A crossing of the Sun or Moon disk by a satellite, as seen by a ground observer
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transit {
+    /// Crossed body
+    pub body: TransitBody,
+
+    /// Time at which the satellite enters the disk in minutes since epoch
+    pub ingress: MinutesSinceEpoch,
+
+    /// Time of the smallest angular separation between the satellite and the body's center in minutes since epoch
+    pub center: MinutesSinceEpoch,
+
+    /// Time at which the satellite leaves the disk in minutes since epoch
+    pub egress: MinutesSinceEpoch,
+
+    /// Smallest angular separation between the satellite and the body's center in rad
+    pub minimum_separation: f64,
+
+    /// Apparent angular radius of the body at the center time in rad
+    pub angular_radius: f64,
+
+    /// Distance between the observer and the satellite at the center time in km
+    pub range: f64,
+}
+
+impl propagator::Constants {
+    /// Finds the transits of the satellite across the Sun or Moon disk as seen by a ground observer
+    ///
+    /// The angular separation between the satellite and the body's center is sampled with a fixed step.
+    /// Each local minimum is refined with a golden-section search, and reported if it is smaller than
+    /// the apparent radius of the body while the body is above the horizon.
+    /// Ingress and egress are then refined by bisection.
+    ///
+    /// Transits of low earth orbit satellites last about a second, but the approach lasts several minutes,
+    /// hence the step only needs to resolve the approach (0.1 min is adequate for low earth orbits).
+    /// Transits whose closest approach is within one step of the window boundaries are not reported.
+    ///
+    /// The Sun and Moon positions are low-precision series, and the satellite position error
+    /// (typically a few kilometers after a day) shifts the ground track of the transit. The predictions are
+    /// meant for planning, and should be refreshed with recent elements before an observation.
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - The ground observer
+    /// * `body` - The body whose disk is crossed
+    /// * `start` - The beginning of the search window in minutes since epoch
+    /// * `end` - The end of the search window in minutes since epoch
+    /// * `step` - The sampling step in minutes (strictly positive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// let observer = sgp4::Observer {
+    ///     latitude: 48.8566_f64.to_radians(),
+    ///     longitude: 2.3522_f64.to_radians(),
+    ///     altitude: 0.035,
+    /// };
+    /// for body in [sgp4::TransitBody::Sun, sgp4::TransitBody::Moon] {
+    ///     for transit in constants.transits(
+    ///         &observer,
+    ///         body,
+    ///         sgp4::MinutesSinceEpoch(0.0),
+    ///         sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    ///         0.1,
+    ///     )? {
+    ///         println!(
+    ///             "{:?} transit at t = {} min, duration {} s",
+    ///             transit.body,
+    ///             transit.center.0,
+    ///             (transit.egress.0 - transit.ingress.0) * 60.0
+    ///         );
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn transits(
+        &self,
+        observer: &observer::Observer,
+        body: TransitBody,
+        start: MinutesSinceEpoch,
+        end: MinutesSinceEpoch,
+        step: f64,
+    ) -> core::result::Result<alloc::vec::Vec<Transit>, gp::Error> {
+        assert!(step > 0.0, "step must be strictly positive");
+
+        // returns the separation and the body's angular radius in rad, and the satellite look angles
+        let geometry = |t: MinutesSinceEpoch| {
+            self.propagate(t).map(|prediction| {
+                let epoch = self.julian_years_since_j2000(t);
+                let sidereal_time = model::iau_epoch_to_sidereal_time(epoch);
+                let observer_position = observer.position(sidereal_time);
+                let body_direction = vector::sub(&body.position(epoch), &observer_position);
+                let body_distance = vector::norm(&body_direction);
+                let look_angles = observer.look_angles(sidereal_time, &prediction);
+                (
+                    vector::angle(
+                        &vector::sub(&prediction.position, &observer_position),
+                        &body_direction,
+                    ),
+                    // ρ = sin⁻¹(R / d)
+                    (body.radius() / body_distance).asin(),
+                    look_angles,
+                )
+            })
+        };
+        let separation = |t: MinutesSinceEpoch| geometry(t).map(|(separation, _, _)| separation);
+        let mut transits = alloc::vec::Vec::new();
+        if end.0 - start.0 < 2.0 * step {
+            return Ok(transits);
+        }
+        let mut samples = [
+            (start.0, separation(start)?),
+            (
+                start.0 + step,
+                separation(MinutesSinceEpoch(start.0 + step))?,
+            ),
+        ];
+        let mut index = 1.0;
+        while samples[1].0 < end.0 {
+            // samples are computed from the start rather than accumulated,
+            // so that steps smaller than the resolution of t still advance
+            index += 1.0;
+            let t = (start.0 + index * step).min(end.0);
+            if t <= samples[1].0 {
+                continue;
+            }
+            let next = (t, separation(MinutesSinceEpoch(t))?);
+            if samples[1].1 < samples[0].1 && samples[1].1 <= next.1 {
+                let (center, _) = events::maximize(samples[0].0, next.0, 1e-8, |t| {
+                    separation(t).map(|separation| -separation)
+                })?;
+                let (minimum_separation, angular_radius, look_angles) = geometry(center)?;
+                if minimum_separation < angular_radius && look_angles.elevation > 0.0 {
+                    let boundary = |t: MinutesSinceEpoch| {
+                        geometry(t)
+                            .map(|(separation, angular_radius, _)| separation - angular_radius)
+                    };
+                    transits.push(Transit {
+                        body,
+                        ingress: outside_disk(start.0, center.0, -step, &boundary)?,
+                        center,
+                        egress: outside_disk(end.0, center.0, step, &boundary)?,
+                        minimum_separation,
+                        angular_radius,
+                        range: look_angles.range,
+                    });
+                }
+            }
+            samples = [samples[1], next];
+        }
+        Ok(transits)
+    }
+}
+
+// walks away from the center (inside the disk) until the satellite is outside the disk,
+// then refines the disk boundary by bisection (the window limit is returned if the boundary is beyond it)
+fn outside_disk(
+    limit: f64,
+    center: f64,
+    step: f64,
+    boundary: &impl Fn(MinutesSinceEpoch) -> core::result::Result<f64, gp::Error>,
+) -> core::result::Result<MinutesSinceEpoch, gp::Error> {
+    let mut inside = center;
+    let mut index = 0.0;
+    loop {
+        index += 1.0;
+        let outside = if step > 0.0 {
+            (center + index * step).min(limit)
+        } else {
+            (center + index * step).max(limit)
+        };
+        if outside == inside && outside != limit {
+            continue;
+        }
+        if boundary(MinutesSinceEpoch(outside))? >= 0.0 {
+            let (mut inside, mut outside) = (inside, outside);
+            while (outside - inside).abs() > 1e-8 {
+                let middle = 0.5 * (inside + outside);
+                if boundary(MinutesSinceEpoch(middle))? < 0.0 {
+                    inside = middle;
+                } else {
+                    outside = middle;
+                }
+            }
+            return Ok(MinutesSinceEpoch(0.5 * (inside + outside)));
+        }
+        if outside == limit {
+            return Ok(MinutesSinceEpoch(limit));
+        }
+        inside = outside;
+    }
+}
@@ -0,0 +1,179 @@
+// trigonometric functions called by propagation
+//
+// With the lut-trig feature, they are implemented with small tables and short polynomials,
+// which is much faster than libm on targets without a floating point unit.
+// Initialization is not affected and always uses the standard library (or libm) functions.
+// The tables only compute the real part, the derivative parts of hyperdual numbers are exact.
+
+use crate::real::Real;
+
+// sin(k π / 32) for k ∈ [0, 16]
+#[cfg(feature = "lut-trig")]
+const SINE_TABLE: [f64; 17] = [
+    0.0,
+    0.0980171403295606,
+    0.19509032201612828,
+    0.2902846772544624,
+    0.3826834323650898,
+    0.47139673682599764,
+    0.5555702330196022,
+    0.6343932841636455,
+    core::f64::consts::FRAC_1_SQRT_2,
+    0.773010453362737,
+    0.8314696123025452,
+    0.881921264348355,
+    0.9238795325112867,
+    0.9569403357322088,
+    0.9807852804032304,
+    0.9951847266721969,
+    1.0,
+];
+
+// tan⁻¹(k / 16) for k ∈ [0, 16]
+#[cfg(feature = "lut-trig")]
+const ARCTANGENT_TABLE: [f64; 17] = [
+    0.0,
+    0.06241880999595735,
+    0.12435499454676144,
+    0.18534794999569476,
+    0.24497866312686414,
+    0.3028848683749714,
+    0.35877067027057225,
+    0.4124104415973873,
+    0.4636476090008061,
+    0.5123894603107377,
+    0.5585993153435624,
+    0.6022873461349642,
+    0.6435011087932844,
+    0.6823165548747481,
+    0.7188299996216245,
+    0.7531512809621944,
+    core::f64::consts::FRAC_PI_4,
+];
+
+// π / 32 = h + m + l, h and m have 27 significant bits so that k h and k m are exact for |k| < 2²⁶
+#[cfg(feature = "lut-trig")]
+const PI_32_HIGH: f64 = 0.09817477036267519;
+
+#[cfg(feature = "lut-trig")]
+const PI_32_MIDDLE: f64 = 6.200584837245948e-11;
+
+#[cfg(feature = "lut-trig")]
+const PI_32_LOW: f64 = 3.575742953818645e-19;
+
+// sin(k π / 32) for any integer k, from the first quadrant table
+#[cfg(feature = "lut-trig")]
+fn table_sine(k: i64) -> f64 {
+    let k = (k & 63) as usize;
+    match k / 16 {
+        0 => SINE_TABLE[k],
+        1 => SINE_TABLE[32 - k],
+        2 => -SINE_TABLE[k - 32],
+        _ => -SINE_TABLE[64 - k],
+    }
+}
+
+#[cfg(not(feature = "lut-trig"))]
+pub(crate) fn sin_cos<T: Real>(angle: T) -> (T, T) {
+    angle.sine_cosine()
+}
+
+#[cfg(feature = "lut-trig")]
+pub(crate) fn sin_cos<T: Real>(angle: T) -> (T, T) {
+    angle.map_sine_cosine(table_sin_cos)
+}
+
+// sin x = sin(k π / 32) cos r + cos(k π / 32) sin r
+// cos x = cos(k π / 32) cos r - sin(k π / 32) sin r
+// with k = ⌊32 x / π⌉ and r = x - k π / 32 ∈ [-π / 64, π / 64]
+// the absolute error is below 10⁻¹⁵ for |x| < 6 × 10⁶
+#[cfg(feature = "lut-trig")]
+fn table_sin_cos(angle: f64) -> (f64, f64) {
+    let scaled = angle * (32.0 / core::f64::consts::PI);
+    let k = if scaled >= 0.0 {
+        (scaled + 0.5) as i64
+    } else {
+        (scaled - 0.5) as i64
+    };
+    let r = ((angle - k as f64 * PI_32_HIGH) - k as f64 * PI_32_MIDDLE) - k as f64 * PI_32_LOW;
+    let r2 = r * r;
+
+    // sin r = r - r³ / 3! + r⁵ / 5! - r⁷ / 7!
+    let r_sine = r * (1.0 - r2 / 6.0 * (1.0 - r2 / 20.0 * (1.0 - r2 / 42.0)));
+
+    // cos r = 1 - r² / 2! + r⁴ / 4! - r⁶ / 6! + r⁸ / 8!
+    let r_cosine = 1.0 - r2 / 2.0 * (1.0 - r2 / 12.0 * (1.0 - r2 / 30.0 * (1.0 - r2 / 56.0)));
+    let k_sine = table_sine(k);
+    let k_cosine = table_sine(k + 16);
+    (
+        k_sine * r_cosine + k_cosine * r_sine,
+        k_cosine * r_cosine - k_sine * r_sine,
+    )
+}
+
+#[cfg(not(feature = "lut-trig"))]
+pub(crate) fn sin<T: Real>(angle: T) -> T {
+    angle.sine()
+}
+
+#[cfg(feature = "lut-trig")]
+pub(crate) fn sin<T: Real>(angle: T) -> T {
+    sin_cos(angle).0
+}
+
+#[cfg(not(feature = "lut-trig"))]
+pub(crate) fn cos<T: Real>(angle: T) -> T {
+    angle.cosine()
+}
+
+#[cfg(feature = "lut-trig")]
+pub(crate) fn cos<T: Real>(angle: T) -> T {
+    sin_cos(angle).1
+}
+
+#[cfg(not(feature = "lut-trig"))]
+pub(crate) fn atan2<T: Real>(y: T, x: T) -> T {
+    y.arctangent2(x)
+}
+
+#[cfg(feature = "lut-trig")]
+pub(crate) fn atan2<T: Real>(y: T, x: T) -> T {
+    y.map_arctangent2(x, table_atan2)
+}
+
+// tan⁻¹ z = tan⁻¹(k / 16) + tan⁻¹ d
+// with z = min(|x|, |y|) / max(|x|, |y|), k = ⌊16 z⌉ and d = (z - k / 16) / (1 + z k / 16) ∈ [-1 / 32, 1 / 32]
+// the result is mapped to the quadrant of (x, y), the absolute error is below 10⁻¹⁵
+#[cfg(feature = "lut-trig")]
+fn table_atan2(y: f64, x: f64) -> f64 {
+    let (x_abs, y_abs) = (x.abs(), y.abs());
+    let (numerator, denominator) = if y_abs > x_abs {
+        (x_abs, y_abs)
+    } else {
+        (y_abs, x_abs)
+    };
+    let z = if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    };
+    let k = ((z * 16.0 + 0.5) as usize).min(16);
+    let c = k as f64 / 16.0;
+    let d = (z - c) / (1.0 + z * c);
+    let d2 = d * d;
+
+    // tan⁻¹ d = d - d³ / 3 + d⁵ / 5 - d⁷ / 7 + d⁹ / 9
+    let mut angle = ARCTANGENT_TABLE[k]
+        + d * (1.0 - d2 * (1.0 / 3.0 - d2 * (1.0 / 5.0 - d2 * (1.0 / 7.0 - d2 / 9.0))));
+    if y_abs > x_abs {
+        angle = core::f64::consts::FRAC_PI_2 - angle;
+    }
+    if x.is_sign_negative() {
+        angle = core::f64::consts::PI - angle;
+    }
+    if y.is_sign_negative() {
+        -angle
+    } else {
+        angle
+    }
+}
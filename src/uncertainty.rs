@@ -0,0 +1,188 @@
+use crate::gp;
+use crate::propagator;
+use crate::tle;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Orbit regime used to select an error growth rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrbitRegime {
+    /// Perigee altitude below 2000 km and eccentricity smaller than 0.25
+    LowEarth,
+
+    /// Orbits that do not belong to the other regimes (for example navigation constellations)
+    MediumEarth,
+
+    /// Mean motion between 0.9 and 1.1 revolutions per day and eccentricity smaller than 0.25
+    Geosynchronous,
+
+    /// Eccentricity greater than or equal to 0.25 (for example Molniya or geostationary transfer orbits)
+    HighlyElliptical,
+}
+
+/// Growth of the 1-sigma position error with the time since epoch
+///
+/// The error is σ = σ₀ + σ̇ |Δt| + σ̈ Δt², where Δt is the time since epoch in days.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorGrowth {
+    /// Position error at epoch σ₀ in km
+    pub epoch_error: f64,
+
+    /// Linear growth rate σ̇ in km.day⁻¹
+    pub rate: f64,
+
+    /// Quadratic growth rate σ̈ in km.day⁻²
+    pub acceleration: f64,
+}
+
+impl ErrorGrowth {
+    /// Returns the 1-sigma position error in km
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - The time since epoch in days (can be positive, negative or zero)
+    pub fn sigma(&self, days: f64) -> f64 {
+        // σ = σ₀ + σ̇ |Δt| + σ̈ Δt²
+        self.epoch_error + self.rate * days.abs() + self.acceleration * days.powi(2)
+    }
+}
+
+/// Empirical position error model, with one growth law per orbit regime
+///
+/// The default values are order-of-magnitude estimates of the errors of public TLEs
+/// (dominated by the in-track component). They are meant to display honest uncertainties
+/// when no covariance is available, and should be calibrated against precise ephemerides
+/// (for example by comparing successive element sets) for quantitative work.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorGrowthModel {
+    /// Error growth for low earth orbits
+    pub low_earth: ErrorGrowth,
+
+    /// Error growth for medium earth orbits
+    pub medium_earth: ErrorGrowth,
+
+    /// Error growth for geosynchronous orbits
+    pub geosynchronous: ErrorGrowth,
+
+    /// Error growth for highly elliptical orbits
+    pub highly_elliptical: ErrorGrowth,
+}
+
+impl Default for ErrorGrowthModel {
+    /// Returns 1 km + 2 km.day⁻¹ + 0.2 km.day⁻² for low earth orbits, 2 km + 1 km.day⁻¹ for medium earth orbits,
+    /// 10 km + 1 km.day⁻¹ for geosynchronous orbits and 5 km + 5 km.day⁻¹ + 0.5 km.day⁻² for highly elliptical orbits
+    fn default() -> Self {
+        ErrorGrowthModel {
+            low_earth: ErrorGrowth {
+                epoch_error: 1.0,
+                rate: 2.0,
+                acceleration: 0.2,
+            },
+            medium_earth: ErrorGrowth {
+                epoch_error: 2.0,
+                rate: 1.0,
+                acceleration: 0.0,
+            },
+            geosynchronous: ErrorGrowth {
+                epoch_error: 10.0,
+                rate: 1.0,
+                acceleration: 0.0,
+            },
+            highly_elliptical: ErrorGrowth {
+                epoch_error: 5.0,
+                rate: 5.0,
+                acceleration: 0.5,
+            },
+        }
+    }
+}
+
+impl ErrorGrowthModel {
+    /// Returns the error growth law of the given regime
+    ///
+    /// # Arguments
+    ///
+    /// * `regime` - The orbit regime, for example calculated with `Constants::orbit_regime`
+    pub fn growth(&self, regime: OrbitRegime) -> &ErrorGrowth {
+        match regime {
+            OrbitRegime::LowEarth => &self.low_earth,
+            OrbitRegime::MediumEarth => &self.medium_earth,
+            OrbitRegime::Geosynchronous => &self.geosynchronous,
+            OrbitRegime::HighlyElliptical => &self.highly_elliptical,
+        }
+    }
+}
+
+/// Predicted position and velocity with an estimated position error
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UncertainPrediction {
+    /// The SGP4 prediction
+    pub prediction: propagator::Prediction,
+
+    /// Estimated 1-sigma position error in km
+    pub position_sigma: f64,
+}
+
+impl propagator::Constants {
+    /// Returns the orbit regime of the epoch elements
+    ///
+    /// The regime is calculated from the Brouwer mean motion and the eccentricity at epoch.
+    pub fn orbit_regime(&self) -> OrbitRegime {
+        // a₀" = (kₑ / n₀")²ᐟ³
+        let semi_major_axis = (self.geopotential.ke / self.orbit_0.mean_motion).powf(2.0 / 3.0)
+            * self.geopotential.ae;
+        let revolutions_per_day = self.orbit_0.mean_motion * (720.0 / core::f64::consts::PI);
+        if self.orbit_0.eccentricity >= 0.25 {
+            OrbitRegime::HighlyElliptical
+        } else if semi_major_axis * (1.0 - self.orbit_0.eccentricity) - self.geopotential.ae
+            < 2000.0
+        {
+            OrbitRegime::LowEarth
+        } else if (0.9..=1.1).contains(&revolutions_per_day) {
+            OrbitRegime::Geosynchronous
+        } else {
+            OrbitRegime::MediumEarth
+        }
+    }
+
+    /// Calculates the SGP4 position and velocity predictions and an estimated position error
+    ///
+    /// The error is calculated with the model's growth law for `Constants::orbit_regime`.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The number of minutes since epoch (can be positive, negative or zero)
+    /// * `model` - The empirical error growth model
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?)?;
+    /// assert_eq!(constants.orbit_regime(), sgp4::OrbitRegime::LowEarth);
+    /// let model = sgp4::ErrorGrowthModel::default();
+    /// let prediction = constants.propagate_with_uncertainty(sgp4::MinutesSinceEpoch(2.0 * 1440.0), &model)?;
+    /// assert!((prediction.position_sigma - 5.8).abs() < 1.0e-9);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn propagate_with_uncertainty(
+        &self,
+        t: tle::MinutesSinceEpoch,
+        model: &ErrorGrowthModel,
+    ) -> core::result::Result<UncertainPrediction, gp::Error> {
+        Ok(UncertainPrediction {
+            prediction: self.propagate(t)?,
+            position_sigma: model.growth(self.orbit_regime()).sigma(t.0 / 1440.0),
+        })
+    }
+}
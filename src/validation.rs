@@ -0,0 +1,146 @@
+use crate::catalog;
+use crate::model;
+use crate::tle;
+
+// largest plausible mean motion in rev.day⁻¹ (orbits below the Earth's surface have about 17 rev.day⁻¹)
+const MAXIMUM_MEAN_MOTION: f64 = 20.0;
+
+// largest plausible absolute drag term (B*) in earth radii⁻¹
+const MAXIMUM_DRAG_TERM: f64 = 0.5;
+
+/// A problem detected by [Elements::validate](struct.Elements.html#method.validate)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ElementsIssue {
+    /// An angle is not finite or outside its range ([0, 180] deg for the inclination, [0, 360[ deg otherwise)
+    AngleOutOfRange {
+        field: catalog::ElementsField,
+        value: f64,
+    },
+
+    /// The eccentricity is not in the range [0, 1[
+    EccentricityOutOfRange(f64),
+
+    /// The mean motion in rev.day⁻¹ is not strictly positive or exceeds 20 rev.day⁻¹
+    MeanMotionOutOfRange(f64),
+
+    /// The semi-major axis cannot be derived from the mean motion (the Brouwer conversion fails)
+    InvalidSemiMajorAxis,
+
+    /// The mean perigee in km is below the Earth's surface
+    PerigeeBelowSurface(f64),
+
+    /// The absolute drag term (B*) in earth radii⁻¹ exceeds 0.5
+    DragTermOutOfRange(f64),
+}
+
+impl core::fmt::Display for ElementsIssue {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ElementsIssue::AngleOutOfRange { field, value } => {
+                formatter.write_fmt(format_args!("{:?} out of range ({} deg)", field, value))
+            }
+            ElementsIssue::EccentricityOutOfRange(eccentricity) => {
+                formatter.write_fmt(format_args!("eccentricity out of range ({})", eccentricity))
+            }
+            ElementsIssue::MeanMotionOutOfRange(mean_motion) => formatter.write_fmt(format_args!(
+                "mean motion out of range ({} rev/day)",
+                mean_motion
+            )),
+            ElementsIssue::InvalidSemiMajorAxis => {
+                formatter.write_str("the semi-major axis cannot be derived from the mean motion")
+            }
+            ElementsIssue::PerigeeBelowSurface(perigee_altitude) => formatter.write_fmt(
+                format_args!("perigee below the surface ({} km)", perigee_altitude),
+            ),
+            ElementsIssue::DragTermOutOfRange(drag_term) => formatter.write_fmt(format_args!(
+                "drag term out of range ({} earth radii⁻¹)",
+                drag_term
+            )),
+        }
+    }
+}
+
+impl tle::Elements {
+    /// Returns the problems detected in the elements
+    ///
+    /// The checks are meant to quarantine corrupt records before they reach a propagator.
+    /// Elements without issues may still fail to initialize a propagator,
+    /// for instance if the eccentricity decays out of range during propagation.
+    ///
+    /// The semi-major axis and perigee are derived with the WGS84 model of Earth gravity,
+    /// and are only checked if the eccentricity and the mean motion are in range.
+    /// Drag terms above 0.5 earth radii⁻¹ correspond to area-to-mass ratios of several m².kg⁻¹
+    /// and are flagged as suspicious.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut elements = sgp4::Elements::from_tle(
+    ///     Some("ISS (ZARYA)".to_owned()),
+    ///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+    ///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    /// )?;
+    /// assert!(elements.validate().is_empty());
+    ///
+    /// // a virtual satellite whose perigee is below the surface
+    /// elements.eccentricity = 0.1;
+    /// assert!(matches!(
+    ///     elements.validate().as_slice(),
+    ///     [sgp4::ElementsIssue::PerigeeBelowSurface(_)],
+    /// ));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self) -> alloc::vec::Vec<ElementsIssue> {
+        let mut issues = alloc::vec::Vec::new();
+        for (field, value, maximum) in [
+            (catalog::ElementsField::Inclination, self.inclination, None),
+            (
+                catalog::ElementsField::RightAscension,
+                self.right_ascension,
+                Some(360.0),
+            ),
+            (
+                catalog::ElementsField::ArgumentOfPerigee,
+                self.argument_of_perigee,
+                Some(360.0),
+            ),
+            (
+                catalog::ElementsField::MeanAnomaly,
+                self.mean_anomaly,
+                Some(360.0),
+            ),
+        ] {
+            let in_range = match maximum {
+                Some(maximum) => (0.0..maximum).contains(&value),
+                None => (0.0..=180.0).contains(&value),
+            };
+            if !in_range {
+                issues.push(ElementsIssue::AngleOutOfRange { field, value });
+            }
+        }
+        let eccentricity_in_range = (0.0..1.0).contains(&self.eccentricity);
+        if !eccentricity_in_range {
+            issues.push(ElementsIssue::EccentricityOutOfRange(self.eccentricity));
+        }
+        let mean_motion_in_range =
+            self.mean_motion > 0.0 && self.mean_motion <= MAXIMUM_MEAN_MOTION;
+        if !mean_motion_in_range {
+            issues.push(ElementsIssue::MeanMotionOutOfRange(self.mean_motion));
+        }
+        if eccentricity_in_range && mean_motion_in_range {
+            let perigee_altitude = self.perigee_altitude(&model::WGS84);
+            if !perigee_altitude.is_finite() {
+                issues.push(ElementsIssue::InvalidSemiMajorAxis);
+            } else if perigee_altitude < 0.0 {
+                issues.push(ElementsIssue::PerigeeBelowSurface(perigee_altitude));
+            }
+        }
+        if !(-MAXIMUM_DRAG_TERM..=MAXIMUM_DRAG_TERM).contains(&self.drag_term) {
+            issues.push(ElementsIssue::DragTermOutOfRange(self.drag_term));
+        }
+        issues
+    }
+}
@@ -0,0 +1,3791 @@
+use crate::gp;
+use crate::propagator;
+use crate::tle;
+
+/// Expected outcome of a reference propagation
+#[derive(Debug, Clone, Copy)]
+pub enum ReferenceState {
+    /// The propagation succeeds
+    Prediction {
+        /// Minutes since epoch
+        time: f64,
+
+        /// The three position components (x, y, z) in km (TEME)
+        position: [f64; 3],
+
+        /// The three velocity components (x, y, z) in km.s⁻¹ (TEME)
+        velocity: [f64; 3],
+    },
+
+    /// The propagation returns `Error::NegativeSemiLatusRectum`
+    NegativeSemiLatusRectum {
+        /// Minutes since epoch
+        time: f64,
+    },
+
+    /// The propagation returns `Error::OutOfRangePerturbedEccentricity`
+    OutOfRangePerturbedEccentricity {
+        /// Minutes since epoch
+        time: f64,
+    },
+}
+
+/// A reference test case
+///
+/// The cases and their expected outputs are those of Vallado et al. (2006), "Revisiting Spacetrack Report #3",
+/// calculated with the AFSPC implementation (WGS72 and AFSPC compatibility mode).
+#[derive(Debug, Clone, Copy)]
+pub struct ReferenceCase {
+    /// The TLE's first line
+    pub line1: &'static str,
+
+    /// The TLE's second line
+    pub line2: &'static str,
+
+    /// The expected outputs at different propagation times
+    pub states: &'static [ReferenceState],
+}
+
+/// Differences between this crate's predictions and the reference outputs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Residuals {
+    /// Number of compared states
+    pub states: usize,
+
+    /// Largest absolute difference between position components in km
+    pub maximum_position: f64,
+
+    /// Largest absolute difference between velocity components in km.s⁻¹
+    pub maximum_velocity: f64,
+
+    /// Number of states whose outcome does not match the reference
+    ///
+    /// This counts unexpected errors, missing errors and non-finite predictions.
+    /// If the TLE cannot be parsed or the propagator cannot be initialized, all the case's states are counted.
+    pub mismatches: usize,
+}
+
+impl Residuals {
+    /// Returns true if all the outcomes match and the residuals are within the given tolerances
+    ///
+    /// The reference build (x86_64 or aarch64 with the `std` feature) stays within 10⁻⁶ km and 10⁻⁹ km.s⁻¹.
+    ///
+    /// # Arguments
+    ///
+    /// * `position_tolerance` - The largest acceptable position difference in km
+    /// * `velocity_tolerance` - The largest acceptable velocity difference in km.s⁻¹
+    pub fn is_within(&self, position_tolerance: f64, velocity_tolerance: f64) -> bool {
+        self.mismatches == 0
+            && self.maximum_position <= position_tolerance
+            && self.maximum_velocity <= velocity_tolerance
+    }
+
+    // accumulates the residuals of another set of states
+    fn combine(&mut self, other: &Residuals) {
+        self.states += other.states;
+        self.maximum_position = self.maximum_position.max(other.maximum_position);
+        self.maximum_velocity = self.maximum_velocity.max(other.maximum_velocity);
+        self.mismatches += other.mismatches;
+    }
+}
+
+impl ReferenceCase {
+    /// Propagates the case's TLE and compares the predictions with the expected outputs
+    ///
+    /// The propagator is initialized with `Constants::from_elements_afspc_compatibility_mode`
+    /// and propagated with `Constants::propagate_afspc_compatibility_mode`.
+    pub fn residuals(&self) -> Residuals {
+        let mut residuals = Residuals {
+            states: self.states.len(),
+            ..Residuals::default()
+        };
+        let constants =
+            match tle::Elements::from_lines(self.line1.as_bytes(), self.line2.as_bytes()) {
+                Ok(elements) => {
+                    match propagator::Constants::from_elements_afspc_compatibility_mode(&elements) {
+                        Ok(constants) => constants,
+                        Err(_) => {
+                            residuals.mismatches = self.states.len();
+                            return residuals;
+                        }
+                    }
+                }
+                Err(_) => {
+                    residuals.mismatches = self.states.len();
+                    return residuals;
+                }
+            };
+        for state in self.states {
+            match *state {
+                ReferenceState::Prediction {
+                    time,
+                    position,
+                    velocity,
+                } => match constants
+                    .propagate_afspc_compatibility_mode(tle::MinutesSinceEpoch(time))
+                {
+                    Ok(prediction) => {
+                        if prediction
+                            .position
+                            .iter()
+                            .chain(prediction.velocity.iter())
+                            .all(|component| component.is_finite())
+                        {
+                            for index in 0..3 {
+                                residuals.maximum_position = residuals
+                                    .maximum_position
+                                    .max((prediction.position[index] - position[index]).abs());
+                                residuals.maximum_velocity = residuals
+                                    .maximum_velocity
+                                    .max((prediction.velocity[index] - velocity[index]).abs());
+                            }
+                        } else {
+                            residuals.mismatches += 1;
+                        }
+                    }
+                    Err(_) => residuals.mismatches += 1,
+                },
+                ReferenceState::NegativeSemiLatusRectum { time } => {
+                    if !matches!(
+                        constants.propagate_afspc_compatibility_mode(tle::MinutesSinceEpoch(time)),
+                        Err(gp::Error::NegativeSemiLatusRectum { .. })
+                    ) {
+                        residuals.mismatches += 1;
+                    }
+                }
+                ReferenceState::OutOfRangePerturbedEccentricity { time } => {
+                    if !matches!(
+                        constants.propagate_afspc_compatibility_mode(tle::MinutesSinceEpoch(time)),
+                        Err(gp::Error::OutOfRangePerturbedEccentricity { .. })
+                    ) {
+                        residuals.mismatches += 1;
+                    }
+                }
+            }
+        }
+        residuals
+    }
+}
+
+/// Returns the reference test cases embedded in the library
+///
+/// The list covers near earth and deep space orbits, resonant orbits, low perigees
+/// and the expected propagation errors. See [verify](fn.verify.html) to run all the cases.
+pub fn reference_cases() -> &'static [ReferenceCase] {
+    &REFERENCE_CASES
+}
+
+/// Runs all the reference test cases and returns the combined residuals
+///
+/// This function checks the propagator on the target hardware and toolchain
+/// (for example soft-float targets or non-default trigonometry features).
+/// It does not allocate.
+///
+/// # Example
+///
+/// ```
+/// let residuals = sgp4::verify();
+/// println!(
+///     "{} states, {} mismatches, {} km, {} km.s⁻¹",
+///     residuals.states,
+///     residuals.mismatches,
+///     residuals.maximum_position,
+///     residuals.maximum_velocity,
+/// );
+/// assert!(residuals.is_within(1.0e-6, 1.0e-9));
+/// ```
+pub fn verify() -> Residuals {
+    let mut residuals = Residuals::default();
+    for reference_case in REFERENCE_CASES.iter() {
+        residuals.combine(&reference_case.residuals());
+    }
+    residuals
+}
+
+static REFERENCE_CASES: [ReferenceCase; 33] = [
+    // TEME example satellite.
+    ReferenceCase {
+        line1: "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+        line2: "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [7022.46529266, -1400.08296755, 0.03995155],
+                velocity: [1.893841015, 6.405893759, 4.534807250],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-7154.03120202, -3783.17682504, -3536.19412294],
+                velocity: [4.741887409, -4.151817765, -2.093935425],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-7134.59340119, 6531.68641334, 3260.27186483],
+                velocity: [-4.113793027, -2.911922039, -2.557327851],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [5568.53901181, 4492.06992591, 3863.87641983],
+                velocity: [-4.209106476, 5.159719888, 2.744852980],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-938.55923943, -6268.18748831, -4294.02924751],
+                velocity: [7.536105209, -0.427127707, 0.989878080],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [-9680.56121728, 2802.47771354, 124.10688038],
+                velocity: [-0.905874102, -4.659467970, -3.227347517],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [190.19796988, 7746.96653614, 5110.00675412],
+                velocity: [-6.112325142, 1.527008184, -0.139152358],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [5579.55640116, -3995.61396789, -1518.82108966],
+                velocity: [4.767927483, 5.123185301, 4.276837355],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [-8650.73082219, -1914.93811525, -3007.03603443],
+                velocity: [3.067165127, -4.828384068, -2.515322836],
+            },
+            ReferenceState::Prediction {
+                time: 3240.0,
+                position: [-5429.79204164, 7574.36493792, 3747.39305236],
+                velocity: [-4.999442110, -1.800561422, -2.229392830],
+            },
+            ReferenceState::Prediction {
+                time: 3600.0,
+                position: [6759.04583722, 2001.58198220, 2783.55192533],
+                velocity: [-2.180993947, 6.402085603, 3.644723952],
+            },
+            ReferenceState::Prediction {
+                time: 3960.0,
+                position: [-3791.44531559, -5712.95617894, -4533.48630714],
+                velocity: [6.668817493, -2.516382327, -0.082384354],
+            },
+            ReferenceState::Prediction {
+                time: 4320.0,
+                position: [-9060.47373569, 4658.70952502, 813.68673153],
+                velocity: [-2.232832783, -4.110453490, -3.157345433],
+            },
+        ],
+    },
+    // Geostationary orbit close to 0.2 radian inclination.
+    // Shows Lyddane choice problem at about –5000 minutes from epoch.
+    ReferenceCase {
+        line1: "1 04632U 70093B   04031.91070959 -.00000084  00000-0  10000-3 0  9955",
+        line2: "2 04632  11.4628 273.1101 1450506 207.6000 143.9350  1.20231981 44145",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [2334.11450085, -41920.44035349, -0.03867437],
+                velocity: [2.826321032, -0.065091664, 0.570936053],
+            },
+            ReferenceState::Prediction {
+                time: -5184.0,
+                position: [-29020.02587128, 13819.84419063, -5713.33679183],
+                velocity: [-1.768068390, -3.235371192, -0.395206135],
+            },
+            ReferenceState::Prediction {
+                time: -5064.0,
+                position: [-32982.56870101, -11125.54996609, -6803.28472771],
+                velocity: [0.617446996, -3.379240041, 0.085954707],
+            },
+            ReferenceState::Prediction {
+                time: -4944.0,
+                position: [-22097.68730513, -31583.13829284, -4836.34329328],
+                velocity: [2.230597499, -2.166594667, 0.426443070],
+            },
+            ReferenceState::Prediction {
+                time: -4896.0,
+                position: [-15129.94694545, -36907.74526221, -3487.56256701],
+                velocity: [2.581167187, -1.524204737, 0.504805763],
+            },
+        ],
+    },
+    // Near Earth normal drag case.
+    // The perigee of 377.26 km is low, but above the threshold of 220 km
+    // for simplified equations, so moderate drag case.
+    ReferenceCase {
+        line1: "1 06251U 62025E   06176.82412014  .00008885  00000-0  12808-3 0  3985",
+        line2: "2 06251  58.0579  54.0425 0030035 139.1568 221.1854 15.56387291  6774",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [3988.31022699, 5498.96657235, 0.90055879],
+                velocity: [-3.290032738, 2.357652820, 6.496623475],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [-3935.69800083, 409.10980837, 5471.33577327],
+                velocity: [-3.374784183, -6.635211043, -1.942056221],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-1675.12766915, -5683.30432352, -3286.21510937],
+                velocity: [5.282496925, 1.508674259, -5.354872978],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [4993.62642836, 2890.54969900, -3600.40145627],
+                velocity: [0.347333429, 5.707031557, 5.070699638],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-1115.07959514, 4015.11691491, 5326.99727718],
+                velocity: [-5.524279443, -4.765738774, 2.402255961],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-4329.10008198, -5176.70287935, 409.65313857],
+                velocity: [2.858408303, -2.933091792, -6.509690397],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [3692.60030028, -976.24265255, -5623.36447493],
+                velocity: [3.897257243, 6.415554948, 1.429112190],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [2301.83510037, 5723.92394553, 2814.61514580],
+                velocity: [-5.110924966, -0.764510559, 5.662120145],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-4990.91637950, -2303.42547880, 3920.86335598],
+                velocity: [-0.993439372, -5.967458360, -4.759110856],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [642.27769977, -4332.89821901, -5183.31523910],
+                velocity: [5.720542579, 4.216573838, -2.846576139],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [4719.78335752, 4798.06938996, -943.58851062],
+                velocity: [-2.294860662, 3.492499389, 6.408334723],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-3299.16993602, 1576.83168320, 5678.67840638],
+                velocity: [-4.460347074, -6.202025196, -0.885874586],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-2777.14682335, -5663.16031708, -2462.54889123],
+                velocity: [4.915493146, 0.123328992, -5.896495091],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [4992.31573893, 1716.62356770, -4287.86065581],
+                velocity: [1.640717189, 6.071570434, 4.338797931],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [-8.22384755, 4662.21521668, 4905.66411857],
+                velocity: [-5.891011274, -3.593173872, 3.365100460],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [-4966.20137963, -4379.59155037, 1349.33347502],
+                velocity: [1.763172581, -3.981456387, -6.343279443],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [2954.49390331, -2080.65984650, -5754.75038057],
+                velocity: [4.895893306, 5.858184322, 0.375474825],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [3363.28794321, 5559.55841180, 1956.05542266],
+                velocity: [-4.587378863, 0.591943403, 6.107838605],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [-4856.66780070, -1107.03450192, 4557.21258241],
+                velocity: [-2.304158557, -6.186437070, -3.956549542],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [-497.84480071, -4863.46005312, -4700.81211217],
+                velocity: [5.960065407, 2.996683369, -3.767123329],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [5241.61936096, 3910.75960683, -1857.93473952],
+                velocity: [-1.124834806, 4.406213160, 6.148161299],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [-2451.38045953, 2610.60463261, 5729.79022069],
+                velocity: [-5.366560525, -5.500855666, 0.187958716],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [-3791.87520638, -5378.82851382, -1575.82737930],
+                velocity: [4.266273592, -1.199162551, -6.276154080],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [4730.53958356, 524.05006433, -4857.29369725],
+                velocity: [2.918056288, 6.135412849, 3.495115636],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [1159.27802897, 5056.60175495, 4353.49418579],
+                velocity: [-5.968060341, -2.314790406, 4.230722669],
+            },
+        ],
+    },
+    // Molniya launch.
+    // Exercises the 0.65 to 0.7 eccentricity branches of the deep-space code.
+    ReferenceCase {
+        line1: "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813",
+        line2: "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [2349.89483350, -14785.93811562, 0.02119378],
+                velocity: [2.721488096, -3.256811655, 4.498416672],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [15223.91713658, -17852.95881713, 25280.39558224],
+                velocity: [1.079041732, 0.875187372, 2.485682813],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [19752.78050009, -8600.07130962, 37522.72921090],
+                velocity: [0.238105279, 1.546110924, 0.986410447],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [19089.29762968, 3107.89495018, 39958.14661370],
+                velocity: [-0.410308034, 1.640332277, -0.306873818],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [13829.66070574, 13977.39999817, 32736.32082508],
+                velocity: [-1.065096849, 1.279983299, -1.760166075],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [3333.05838525, 18395.31728674, 12738.25031238],
+                velocity: [-1.882432221, -0.611623333, -4.039586549],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [2622.13222207, -15125.15464924, 474.51048398],
+                velocity: [2.688287199, -3.078426664, 4.494979530],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [15320.56770017, -17777.32564586, 25539.53198382],
+                velocity: [1.064346229, 0.892184771, 2.459822414],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [19769.70267785, -8458.65104454, 37624.20130236],
+                velocity: [0.229304396, 1.550363884, 0.966993056],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [19048.56201523, 3260.43223119, 39923.39143967],
+                velocity: [-0.418015536, 1.639346953, -0.326094840],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [13729.19205837, 14097.70014810, 32547.52799890],
+                velocity: [-1.074511043, 1.270505211, -1.785099927],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [3148.86165643, 18323.19841703, 12305.75195578],
+                velocity: [-1.895271701, -0.678343847, -4.086577951],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [2890.80638268, -15446.43952300, 948.77010176],
+                velocity: [2.654407490, -2.909344895, 4.486437362],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [15415.98410712, -17699.90714437, 25796.19644689],
+                velocity: [1.049818334, 0.908822332, 2.434107329],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [19786.00618538, -8316.74570581, 37723.74539119],
+                velocity: [0.220539813, 1.554518900, 0.947601047],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [19007.28688729, 3412.85948715, 39886.66579255],
+                velocity: [-0.425733568, 1.638276809, -0.345353807],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [13627.93015254, 14216.95401307, 32356.13706868],
+                velocity: [-1.083991976, 1.260802347, -1.810193903],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [2963.26486560, 18243.85063641, 11868.25797486],
+                velocity: [-1.908015447, -0.747870342, -4.134004492],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [3155.85126036, -15750.70393364, 1422.32496953],
+                velocity: [2.620085624, -2.748990396, 4.473527039],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [15510.15191770, -17620.71002219, 26050.43525345],
+                velocity: [1.035454678, 0.925111006, 2.408534465],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [19801.67198812, -8174.33337167, 37821.38577439],
+                velocity: [0.211812700, 1.558576937, 0.928231880],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [18965.46529379, 3565.19666242, 39847.97510998],
+                velocity: [-0.433459945, 1.637120585, -0.364653213],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [13525.88227400, 14335.15978787, 32162.13236536],
+                velocity: [-1.093537945, 1.250868256, -1.835451681],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [2776.30574260, 18156.98538451, 11425.73046481],
+                velocity: [-1.920632199, -0.820370733, -4.181839232],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [3417.20931587, -16038.79510665, 1894.74934058],
+                velocity: [2.585515864, -2.596818146, 4.456882556],
+            },
+        ],
+    },
+    // Molniya launch.
+    // Exercises the 0.7 to 0.715 eccentricity branches of the deep-space code.
+    ReferenceCase {
+        line1: "1 09880U 77021A   06176.56157475  .00000421  00000-0  10000-3 0  9814",
+        line2: "2 09880  64.5968 349.3786 7069051 270.0229  16.3320  2.00813614112380",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [13020.06750784, -2449.07193500, 1.15896030],
+                velocity: [4.247363935, 1.597178501, 4.956708611],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [19190.32482476, 9249.01266902, 26596.71345328],
+                velocity: [-0.624960193, 1.324550562, 2.495697637],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [11332.67806218, 16517.99124008, 38569.78482991],
+                velocity: [-1.400974747, 0.710947006, 0.923935636],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [328.74217398, 19554.92047380, 40558.26246145],
+                velocity: [-1.593281066, 0.126772913, -0.359627307],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-10684.90590680, 18057.15728839, 33158.75253886],
+                velocity: [-1.383205997, -0.582328999, -1.744412556],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-17069.78000550, 9944.86797897, 13885.91649059],
+                velocity: [0.044133354, -1.853448464, -3.815303117],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [13725.09398980, -2180.70877090, 863.29684523],
+                velocity: [3.878478111, 1.656846496, 4.944867241],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [19089.63879226, 9456.29670247, 27026.79562883],
+                velocity: [-0.656614299, 1.309112636, 2.449371941],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [11106.41248373, 16627.60874079, 38727.35140296],
+                velocity: [-1.409722680, 0.698582526, 0.891383535],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [72.40958621, 19575.08054144, 40492.12544001],
+                velocity: [-1.593394604, 0.113655142, -0.390556063],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [-10905.89252576, 17965.41205111, 32850.07298244],
+                velocity: [-1.371396120, -0.601706604, -1.782817058],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-17044.61207568, 9635.48491849, 13212.59462953],
+                velocity: [0.129244030, -1.903551430, -3.884569098],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [14369.90303735, -1903.85601062, 1722.15319853],
+                velocity: [3.543393116, 1.701687176, 4.913881358],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [18983.96210441, 9661.12233804, 27448.99557732],
+                velocity: [-0.687189304, 1.293808870, 2.403630759],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [10878.79336704, 16735.31433954, 38879.23434264],
+                velocity: [-1.418239666, 0.686235750, 0.858951848],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [-184.03743100, 19593.09371709, 40420.40606889],
+                velocity: [-1.593348925, 0.100448697, -0.421571993],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [-11125.12138631, 17870.19488928, 32534.21521208],
+                velocity: [-1.359116236, -0.621413776, -1.821629856],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [-17004.43272827, 9316.53926351, 12526.11883812],
+                velocity: [0.220330736, -1.955594322, -3.955058575],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [14960.06492693, -1620.68430805, 2574.96359381],
+                velocity: [3.238634028, 1.734723385, 4.868880331],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [18873.46347257, 9863.57004586, 27863.46574735],
+                velocity: [-0.716736981, 1.278632817, 2.358448535],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [10649.86857581, 16841.14172669, 39025.48035006],
+                velocity: [-1.426527152, 0.673901057, 0.826632332],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [-440.53459323, 19608.95524423, 40343.10675451],
+                velocity: [-1.593138597, 0.087147884, -0.452680559],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [-11342.45028909, 17771.44223942, 32211.12535721],
+                velocity: [-1.346344015, -0.641464291, -1.860864234],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [-16948.06005711, 8987.64254880, 11826.28284367],
+                velocity: [0.318007297, -2.009693492, -4.026726648],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [15500.53445068, -1332.90981042, 3419.72315308],
+                velocity: [2.960917974, 1.758331634, 4.813698638],
+            },
+        ],
+    },
+    // Relatively high eccentricity for GEO (e = 0.027)
+    // shows secular integrator problem clearly.
+    ReferenceCase {
+        line1: "1 09998U 74033F   05148.79417928 -.00000112  00000-0  00000+0 0  4480",
+        line2: "2 09998   9.4958 313.1750 0270971 327.5225  30.8097  1.16186785 45878",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [25532.98947267, -27244.26327953, -1.11572421],
+                velocity: [2.410283885, 2.194175683, 0.545888526],
+            },
+            ReferenceState::Prediction {
+                time: -1440.0,
+                position: [-11362.18265118, -35117.55867813, -5413.62537994],
+                velocity: [3.137861261, -1.011678260, 0.267510059],
+            },
+            ReferenceState::Prediction {
+                time: -1380.0,
+                position: [309.25349929, -36960.43090143, -4198.48007670],
+                velocity: [3.292429375, -0.002166046, 0.402111628],
+            },
+            ReferenceState::Prediction {
+                time: -1320.0,
+                position: [11949.04009077, -35127.37816804, -2565.89806468],
+                velocity: [3.119942784, 1.012096444, 0.497284100],
+            },
+            ReferenceState::Prediction {
+                time: -1260.0,
+                position: [22400.45329336, -29798.63236321, -677.91515122],
+                velocity: [2.638533344, 1.922477736, 0.542792913],
+            },
+            ReferenceState::Prediction {
+                time: -1200.0,
+                position: [30640.84752458, -21525.02340201, 1277.34808722],
+                velocity: [1.903464941, 2.634294312, 0.534540934],
+            },
+            ReferenceState::Prediction {
+                time: -1140.0,
+                position: [35899.56788035, -11152.71158138, 3108.72535238],
+                velocity: [0.997393045, 3.079858548, 0.474873291],
+            },
+            ReferenceState::Prediction {
+                time: -1080.0,
+                position: [37732.45438600, 288.18821054, 4643.87587495],
+                velocity: [0.016652226, 3.225184410, 0.371669746],
+            },
+            ReferenceState::Prediction {
+                time: -1020.0,
+                position: [36045.92961699, 11706.61816230, 5746.32646574],
+                velocity: [-0.942409065, 3.069888941, 0.236662980],
+            },
+            ReferenceState::Prediction {
+                time: -960.0,
+                position: [31076.77273609, 22063.44379776, 6325.93403705],
+                velocity: [-1.794027976, 2.642072476, 0.083556127],
+            },
+            ReferenceState::Prediction {
+                time: -900.0,
+                position: [23341.26015320, 30460.88002531, 6342.91707895],
+                velocity: [-2.469409743, 1.990861658, -0.073612096],
+            },
+            ReferenceState::Prediction {
+                time: -840.0,
+                position: [13568.39733054, 36204.45930900, 5806.79548733],
+                velocity: [-2.919354203, 1.178920217, -0.221646814],
+            },
+            ReferenceState::Prediction {
+                time: -780.0,
+                position: [2628.58762420, 38840.10855897, 4771.91979854],
+                velocity: [-3.114400514, 0.276239109, -0.348926401],
+            },
+            ReferenceState::Prediction {
+                time: -720.0,
+                position: [-8535.81598158, 38171.79073851, 3331.00311285],
+                velocity: [-3.043839958, -0.644462527, -0.445808894],
+            },
+        ],
+    },
+    // Original STR#3 report test case.
+    ReferenceCase {
+        line1: "1 11801U          80230.29629788  .01431103  00000-0  14311-1 0    13",
+        line2: "2 11801  46.7916 230.4354 7318036  47.4722  10.4117  2.28537848    13",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [7473.37102491, 428.94748312, 5828.74846783],
+                velocity: [5.107155391, 6.444680305, -0.186133297],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-3305.22148694, 32410.84323331, -24697.16974954],
+                velocity: [-1.301137319, -1.151315600, -0.283335823],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [14271.29083858, 24110.44309009, -4725.76320143],
+                velocity: [-0.320504528, 2.679841539, -2.084054355],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-9990.05800009, 22717.34212448, -23616.88515553],
+                velocity: [-1.016674392, -2.290267981, 0.728923337],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [9787.87836256, 33753.32249667, -15030.79874625],
+                velocity: [-1.094251553, 0.923589906, -1.522311008],
+            },
+        ],
+    },
+    // Geostationary orbit close to 0.2 radian inclination.
+    // Shows Lyddane choice problem at about 2080 minutes from epoch.
+    ReferenceCase {
+        line1: "1 14128U 83058A   06176.02844893 -.00000158  00000-0  10000-3 0  9627",
+        line2: "2 14128  11.4384  35.2134 0011562  26.4582 333.5652  0.98870114 46093",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [34747.57932696, 24502.37114079, -1.32832986],
+                velocity: [-1.731642662, 2.452772615, 0.608510081],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [18263.33439094, 38159.96004751, 4186.18304085],
+                velocity: [-2.744396611, 1.255583260, 0.528558932],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-3023.38840703, 41783.13186459, 7273.03412906],
+                velocity: [-3.035574793, -0.271656544, 0.309645251],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-23516.34391907, 34424.42065671, 8448.49867693],
+                velocity: [-2.529120477, -1.726186020, 0.009582303],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-37837.46699511, 18028.39727170, 7406.25540271],
+                velocity: [-1.360069525, -2.725794686, -0.292555349],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-42243.58460661, -3093.72887774, 4422.91711801],
+                velocity: [0.163110919, -3.009980598, -0.517584362],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-35597.57919549, -23407.91145393, 282.09554383],
+                velocity: [1.641405246, -2.506773678, -0.606963478],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-19649.19834455, -37606.11623860, -3932.71525948],
+                velocity: [2.689647056, -1.349150016, -0.537710698],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [1431.30912160, -41982.04949668, -7120.45467057],
+                velocity: [3.035263353, 0.160882945, -0.327993994],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [22136.97605384, -35388.19823762, -8447.62393401],
+                velocity: [2.587624889, 1.630097136, -0.032349004],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [37050.15790219, -19537.23321425, -7564.83463543],
+                velocity: [1.461844494, 2.674654256, 0.272202191],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [42253.81760945, 1431.81867593, -4699.87621174],
+                velocity: [-0.049247334, 3.019518960, 0.505890058],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [36366.59147396, 22023.54245720, -601.47121821],
+                velocity: [-1.549681546, 2.571788981, 0.607057418],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [20922.12287985, 36826.33975981, 3654.91125886],
+                velocity: [-2.644070068, 1.447521216, 0.548722983],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [-23.77224182, 41945.51688402, 6950.29891751],
+                velocity: [-3.043358385, -0.057417440, 0.346112094],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [-20964.17821076, 36039.06206172, 8418.91984963],
+                velocity: [-2.642795221, -1.546099886, 0.052725852],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [-36401.63863057, 20669.75286162, 7677.19769359],
+                velocity: [-1.549488154, -2.627052310, -0.254079652],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [-42298.30327543, -119.03351118, 4922.96388841],
+                velocity: [-0.052232768, -3.018152669, -0.493827331],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [-37125.62383511, -20879.63058368, 879.86971348],
+                velocity: [1.456499841, -2.619358421, -0.604081694],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [-22250.12320553, -36182.74736487, -3393.15365183],
+                velocity: [2.583161226, -1.536647628, -0.556404555],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [-1563.06258654, -42035.43179159, -6780.02161760],
+                velocity: [3.034917506, -0.052702046, -0.363395654],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [19531.64069587, -36905.65470956, -8395.46892032],
+                velocity: [2.693682199, 1.446079999, -0.075256054],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [35516.53506142, -22123.71916638, -7815.04516935],
+                velocity: [1.646882125, 2.568416058, 0.232985912],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [42196.03535976, -1547.32646751, -5187.39401981],
+                velocity: [0.166491841, 3.019211549, 0.480665780],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [37802.25393045, 19433.57330019, -1198.66634226],
+                velocity: [-1.359930580, 2.677830903, 0.602507466],
+            },
+        ],
+    },
+    // Deep-space object with very low perigee (82.48 km) that uses
+    // the second branch (perigee < 98 km) for limiting the ‘s4’ drag coefficient to 20.
+    ReferenceCase {
+        line1: "1 16925U 86065D   06151.67415771  .02550794 -30915-6  18784-3 0  4486",
+        line2: "2 16925  62.0906 295.0239 5596327 245.1593  47.9690  4.88511875148616",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [5559.11686836, -11941.04090781, -19.41235206],
+                velocity: [3.392116762, -1.946985124, 4.250755852],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [12339.83273749, -2771.14447871, 18904.57603433],
+                velocity: [-0.871247614, 2.600917693, 0.581560002],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-3385.00215658, 7538.13955729, 200.59008616],
+                velocity: [-2.023512865, -4.261808344, -6.856385787],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [12805.22442200, -10258.94667177, 13780.16486738],
+                velocity: [0.619279224, 1.821510542, 2.507365975],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [5682.46556318, 7199.30270473, 15437.67134070],
+                velocity: [-2.474365406, 2.087897336, -2.583767460],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [7628.94243982, -12852.72097492, 2902.87208981],
+                velocity: [2.748131081, -0.740084579, 4.125307943],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [11531.64866625, -858.27542736, 19086.85993771],
+                velocity: [-1.170071901, 2.660311986, 0.096005705],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-3866.98069515, 2603.73442786, -4577.36484577],
+                velocity: [1.157257298, -8.453281164, -4.683959407],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [13054.77732721, -8707.92757730, 15537.63259903],
+                velocity: [0.229846748, 2.119467054, 2.063396852],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [3496.91064652, 8712.83919778, 12845.81838327],
+                velocity: [-2.782184997, 1.552950644, -3.554436131],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [9593.07424729, -13023.75963608, 6250.46484931],
+                velocity: [2.072666376, 0.278735334, 3.778111073],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [10284.79205084, 1487.89914169, 18824.37381327],
+                velocity: [-1.530335053, 2.663107730, -0.542205966],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-984.62035146, -5187.03480813, -5745.59594144],
+                velocity: [4.340271916, -7.266811354, 1.777668888],
+            },
+        ],
+    },
+    // Long period orbit (~4 days) shows Lyddane choice at 1860 min from epoch.
+    // It also demonstrates processing through a leap second,
+    // although the SGP4 code is independent of any leap second processing.
+    // Leap seconds are handled in any external program using the SGP4-derived ephemerides.
+    ReferenceCase {
+        line1: "1 20413U 83020D   05363.79166667  .00000000  00000-0  00000+0 0  7041",
+        line2: "2 20413  12.3514 187.4253 7864447 196.3027 356.5478  0.24690082  7978",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [25123.29290741, -13225.49966286, 3249.40351869],
+                velocity: [0.488683419, 4.797897593, -0.961119693],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-151669.05280515, -5645.20454550, -2198.51592118],
+                velocity: [-0.869182889, -0.870759872, 0.156508219],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [-157497.71657495, -11884.99595074, -1061.44439402],
+                velocity: [-0.749657961, -0.864016715, 0.157766101],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [-162498.32255577, -18062.99733167, 81.00915253],
+                velocity: [-0.638980378, -0.853687105, 0.158098992],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [-166728.76010920, -24155.99648299, 1222.84128677],
+                velocity: [-0.535600687, -0.840455444, 0.157680857],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [-169935.81924592, -31767.29787964, 2749.01540345],
+                velocity: [-0.430050431, -0.828904183, 0.157812340],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [-172703.07831815, -37662.95639336, 3883.60052579],
+                velocity: [-0.338004891, -0.810277487, 0.156020035],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [-174823.19337404, -43417.55605219, 5003.26312809],
+                velocity: [-0.250258622, -0.789828672, 0.153764903],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [-176324.63925775, -49018.51958648, 6104.85025002],
+                velocity: [-0.166136613, -0.767706262, 0.151092242],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [-177231.42142458, -54454.12699497, 7185.48661607],
+                velocity: [-0.085067854, -0.744001567, 0.148033403],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [-177563.73583232, -59713.14859144, 8242.48472591],
+                velocity: [-0.006561730, -0.718760309, 0.144608676],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [-177338.48026483, -64784.54644698, 9273.27220003],
+                velocity: [0.069809946, -0.691990238, 0.140829236],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [-176569.65151461, -69657.21976255, 10275.33063459],
+                velocity: [0.144426878, -0.663665876, 0.136698419],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [-175268.65299073, -74319.77625463, 11246.14177160],
+                velocity: [0.217631370, -0.633731091, 0.132212491],
+            },
+            ReferenceState::Prediction {
+                time: 3000.0,
+                position: [-173444.53039609, -78760.31560396, 12183.13775212],
+                velocity: [0.289737325, -0.602099929, 0.127361017],
+            },
+            ReferenceState::Prediction {
+                time: 3120.0,
+                position: [-171104.14813653, -82966.21323591, 13083.65278381],
+                velocity: [0.361037779, -0.568655903, 0.122126889],
+            },
+            ReferenceState::Prediction {
+                time: 3240.0,
+                position: [-168252.31543803, -86923.89363433, 13944.87382716],
+                velocity: [0.431811396, -0.533249797, 0.116486022],
+            },
+            ReferenceState::Prediction {
+                time: 3360.0,
+                position: [-164891.86832887, -90618.58225954, 14763.78794247],
+                velocity: [0.502328269, -0.495695896, 0.110406725],
+            },
+            ReferenceState::Prediction {
+                time: 3480.0,
+                position: [-161023.71139825, -94034.02398835, 15537.12375729],
+                velocity: [0.572855321, -0.455766412, 0.103848688],
+            },
+            ReferenceState::Prediction {
+                time: 3600.0,
+                position: [-156646.82136726, -97152.15370791, 16261.28409305],
+                velocity: [0.643661538, -0.413183688, 0.096761524],
+            },
+            ReferenceState::Prediction {
+                time: 3720.0,
+                position: [-151758.21285737, -99952.70098346, 16932.26607548],
+                velocity: [0.715023254, -0.367609561, 0.089082727],
+            },
+            ReferenceState::Prediction {
+                time: 3840.0,
+                position: [-146352.86521283, -102412.70506284, 17545.56394158],
+                velocity: [0.787229695, -0.318630913, 0.080734873],
+            },
+            ReferenceState::Prediction {
+                time: 3960.0,
+                position: [-140423.60777444, -104505.90799734, 18096.04807097],
+                velocity: [0.860588979, -0.265739987, 0.071621768],
+            },
+            ReferenceState::Prediction {
+                time: 4080.0,
+                position: [-133960.95961851, -106201.98091318, 18577.81121953],
+                velocity: [0.935434758, -0.208307307, 0.061623110],
+            },
+            ReferenceState::Prediction {
+                time: 4200.0,
+                position: [-126952.91860010, -107465.51906186, 18983.96903112],
+                velocity: [1.012133628, -0.145543878, 0.050587007],
+            },
+            ReferenceState::Prediction {
+                time: 4320.0,
+                position: [-119384.69396454, -108254.71115372, 19306.39581892],
+                velocity: [1.091093313, -0.076447479, 0.038319282],
+            },
+        ],
+    },
+    // Molniya launch.
+    // Exercises the eccentricity branches above 0.715, with a negative B* value.
+    ReferenceCase {
+        line1: "1 21897U 92011A   06176.02341244 -.00001273  00000-0 -13525-3 0  3044",
+        line2: "2 21897  62.1749 198.0096 7421690 253.0462  20.1561  2.01269994104880",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-14464.72135182, -4699.19517587, 0.06681686],
+                velocity: [-3.249312013, -3.281032707, 4.007046940],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [-19410.46286123, -19143.03318969, 23114.05522619],
+                velocity: [0.508602237, -1.156882269, 2.379923455],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-12686.06129708, -23853.75335645, 35529.81733588],
+                velocity: [1.231633829, -0.221718202, 1.118440291],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-2775.46649359, -22839.64574119, 39494.64689967],
+                velocity: [1.468963405, 0.489481769, -0.023972788],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [7679.87883570, -16780.50760106, 34686.21815555],
+                velocity: [1.364171080, 1.211183897, -1.385151371],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [14552.40023028, -4819.50121461, 17154.70672449],
+                velocity: [0.109201591, 2.176124494, -3.854856805],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-15302.38845375, -5556.43440300, 1095.95088753],
+                velocity: [-2.838224312, -3.134231137, 3.992596326],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-19289.20066748, -19427.04851118, 23759.45685636],
+                velocity: [0.552495087, -1.112499437, 2.325112654],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-12376.21976437, -23893.38020018, 35831.33691892],
+                velocity: [1.246701529, -0.194294048, 1.074867282],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-2400.55677665, -22698.62264640, 39482.75964390],
+                velocity: [1.472582922, 0.513555654, -0.069306561],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [8031.66819252, -16455.77592085, 34298.94391742],
+                velocity: [1.351357426, 1.239633234, -1.448195324],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [14559.48780372, -4238.43773813, 16079.23154704],
+                velocity: [-0.026409655, 2.218938770, -4.012628896],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-16036.04980660, -6372.51406468, 2183.44834232],
+                velocity: [-2.485113443, -2.994994355, 3.955891272],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [-19156.71583814, -19698.89059957, 24389.29473934],
+                velocity: [0.594278133, -1.069418599, 2.271152044],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [-12062.72925552, -23925.82362911, 36120.66680667],
+                velocity: [1.261238798, -0.167201856, 1.031478939],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [-2024.96136966, -22551.56626703, 39458.50085787],
+                velocity: [1.475816889, 0.537615764, -0.114887472],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [8379.80916204, -16123.95878459, 33894.75123231],
+                velocity: [1.337468254, 1.268432783, -1.512473301],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [14527.86748873, -3646.33817120, 14960.74306518],
+                velocity: [-0.180035839, 2.261273515, -4.179355590],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [-16680.12147335, -7149.80800425, 3257.64227208],
+                velocity: [-2.178897351, -2.863927095, 3.904876943],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [-19013.58793448, -19958.93766022, 25003.81778666],
+                velocity: [0.634100431, -1.027559823, 2.218002685],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [-11745.76155818, -23951.19438627, 36397.87676581],
+                velocity: [1.275261813, -0.140425132, 0.988259441],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [-1648.81945070, -22398.50594576, 39421.83273890],
+                velocity: [1.478660174, 0.561671519, -0.160733093],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [8723.97652795, -15784.99406275, 33473.35215527],
+                velocity: [1.322433593, 1.297602497, -1.578055493],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [14452.25571587, -3043.42332645, 13796.84870805],
+                velocity: [-0.355190169, 2.302485443, -4.355767077],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [-17246.31075678, -7890.72601508, 4315.39410307],
+                velocity: [-1.910968458, -2.740945672, 3.844722726],
+            },
+        ],
+    },
+    // Near Earth with very low perigee (86.98 km) that uses the second branch (perigee < 98 km)
+    // for limiting the ‘s4’ drag coefficient to 20.
+    // Propagation beyond approximately 2840 min should result in
+    // error trap (modified eccentricity too low).
+    ReferenceCase {
+        line1: "1 22312U 93002D   06094.46235912  .99999999  81888-5  49949-3 0  3953",
+        line2: "2 22312  62.1486  77.4698 0308723 267.9229  88.7392 15.95744531 98783",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [1442.10132912, 6510.23625449, 8.83145885],
+                velocity: [-3.475714837, 0.997262768, 6.835860345],
+            },
+            ReferenceState::Prediction {
+                time: 54.2028672,
+                position: [306.10478453, -5816.45655525, -2979.55846068],
+                velocity: [3.950663855, 3.415332543, -5.879974329],
+            },
+            ReferenceState::Prediction {
+                time: 74.2028672,
+                position: [3282.82085464, 2077.46972905, -5189.17988770],
+                velocity: [0.097342701, 7.375135692, 2.900196702],
+            },
+            ReferenceState::Prediction {
+                time: 94.2028672,
+                position: [530.82729176, 6426.20790003, 1712.37076793],
+                velocity: [-3.837120395, -1.252430637, 6.561602577],
+            },
+            ReferenceState::Prediction {
+                time: 114.2028672,
+                position: [-3191.69170212, 170.27219912, 5956.29807775],
+                velocity: [-1.394956872, -7.438073471, -0.557553115],
+            },
+            ReferenceState::Prediction {
+                time: 134.2028672,
+                position: [-1818.99222465, -6322.45146616, 681.95247154],
+                velocity: [3.349795173, -1.530140265, -6.831522765],
+            },
+            ReferenceState::Prediction {
+                time: 154.2028672,
+                position: [2515.66448634, -2158.83091224, -5552.13320544],
+                velocity: [2.571979660, 7.311930509, -1.639865620],
+            },
+            ReferenceState::Prediction {
+                time: 174.2028672,
+                position: [2414.52833210, 5749.10150922, -1998.59693165],
+                velocity: [-2.681032960, 3.527589301, 6.452951429],
+            },
+            ReferenceState::Prediction {
+                time: 194.2028672,
+                position: [-1877.98944331, 3862.27848302, 5112.48435863],
+                velocity: [-3.261489804, -6.026859137, 3.433254768],
+            },
+            ReferenceState::Prediction {
+                time: 214.2028672,
+                position: [-3117.36584395, -4419.74773864, 3840.85960912],
+                velocity: [1.545479182, -5.475416581, -5.207913748],
+            },
+            ReferenceState::Prediction {
+                time: 234.2028672,
+                position: [815.32034678, -5231.67692249, -3760.04690354],
+                velocity: [3.870864200, 4.455588552, -5.211082191],
+            },
+            ReferenceState::Prediction {
+                time: 254.2028672,
+                position: [3269.54341810, 3029.00081083, -4704.67969713],
+                velocity: [-0.526711345, 6.812157950, 3.929825087],
+            },
+            ReferenceState::Prediction {
+                time: 274.2028672,
+                position: [-10.18099756, 6026.23341453, 2643.50518407],
+                velocity: [-3.953623254, -2.616070012, 6.145637500],
+            },
+            ReferenceState::Prediction {
+                time: 294.2028672,
+                position: [-3320.58819584, -1248.42679945, 5563.06017927],
+                velocity: [-0.637046974, -7.417786044, -2.076120187],
+            },
+            ReferenceState::Prediction {
+                time: 314.2028672,
+                position: [-1025.48974616, -6366.98945782, -911.23559153],
+                velocity: [3.811771909, 0.438071490, -6.829260617],
+            },
+            ReferenceState::Prediction {
+                time: 334.2028672,
+                position: [3003.75996128, -413.85708003, -5706.15591435],
+                velocity: [1.674350083, 7.694169068, 0.316915204],
+            },
+            ReferenceState::Prediction {
+                time: 354.2028672,
+                position: [1731.42816980, 6258.27676925, -409.32527982],
+                velocity: [-3.400497806, 1.447945424, 6.904010052],
+            },
+            ReferenceState::Prediction {
+                time: 374.2028672,
+                position: [-2582.52111460, 2024.19020680, 5647.55650268],
+                velocity: [-2.530348121, -7.221719393, 1.438141553],
+            },
+            ReferenceState::Prediction {
+                time: 394.2028672,
+                position: [-2440.56848578, -5702.77311877, 1934.81094689],
+                velocity: [2.731792947, -3.350576075, -6.527773339],
+            },
+            ReferenceState::Prediction {
+                time: 414.2028672,
+                position: [1951.22934391, -3423.59443045, -5121.67808201],
+                velocity: [3.249039133, 6.465974362, -3.069806659],
+            },
+            ReferenceState::Prediction {
+                time: 434.2028672,
+                position: [2886.50939356, 4888.68626216, -3096.29885989],
+                velocity: [-1.973162139, 4.877039020, 5.832414910],
+            },
+            ReferenceState::Prediction {
+                time: 454.2028672,
+                position: [-1276.55532182, 4553.26898463, 4406.19787375],
+                velocity: [-3.715146421, -5.320176914, 4.418210777],
+            },
+            ReferenceState::Prediction {
+                time: 474.2028672,
+                position: [-3181.54698042, -3831.29976506, 4096.80242787],
+                velocity: [1.114159970, -6.104773578, -4.829967400],
+            },
+        ],
+    },
+    // Rocket body, similar to 21897 (e > 0.715) but positive B*.
+    ReferenceCase {
+        line1: "1 22674U 93035D   06176.55909107  .00002121  00000-0  29868-3 0  6569",
+        line2: "2 22674  63.5035 354.4452 7541712 253.3264  18.7754  1.96679808 93877",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [14712.22023280, -1443.81061850, 0.83497888],
+                velocity: [4.418965470, 1.629592098, 4.115531802],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [25418.88807860, 9342.60307989, 23611.46690798],
+                velocity: [0.051284086, 1.213127306, 2.429004159],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [21619.59550749, 16125.24978864, 36396.79365831],
+                velocity: [-0.963604380, 0.685454965, 1.177181937],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [12721.50543331, 19258.96193362, 40898.47648359],
+                velocity: [-1.457448565, 0.179955469, 0.071502601],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [1272.80760054, 18458.41971897, 37044.74742696],
+                velocity: [-1.674863386, -0.436454983, -1.201040990],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-10058.43188619, 11906.60764454, 21739.62097733],
+                velocity: [-1.245829683, -1.543789125, -3.324449221],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [10924.40116466, -2571.92414170, -2956.34856294],
+                velocity: [6.071727751, 1.349579102, 3.898430260],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [25332.14851525, 8398.91099924, 21783.90654357],
+                velocity: [0.222320754, 1.272214306, 2.580527192],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [22317.71926039, 15574.82086129, 35495.77144092],
+                velocity: [-0.892750056, 0.737383381, 1.291738834],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [13795.68675885, 19088.83051008, 40803.69584385],
+                velocity: [-1.420277669, 0.235599456, 0.185517056],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [2515.17145049, 18746.63776282, 37864.58088636],
+                velocity: [-1.668016053, -0.360431458, -1.052854596],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-9084.48602106, 12982.62608646, 24045.63900249],
+                velocity: [-1.378032363, -1.373184736, -3.013963835],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [5647.00909495, -3293.90518693, -5425.85235063],
+                velocity: [8.507977176, 0.414560797, 2.543322806],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [25111.63372210, 7412.55109488, 19844.25781729],
+                velocity: [0.416496290, 1.332106006, 2.739301737],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [22961.47461641, 14985.74459578, 34511.09257381],
+                velocity: [-0.816711048, 0.789391108, 1.407901804],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [14841.15301459, 18876.91439870, 40626.25901619],
+                velocity: [-1.380403341, 0.290228810, 0.298258120],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [3750.70174081, 18978.57939698, 38578.11783220],
+                velocity: [-1.656939412, -0.287930881, -0.910825599],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [-8027.30219489, 13939.54436955, 26136.49045637],
+                velocity: [-1.474476061, -1.222693624, -2.737178731],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [-1296.95657092, -2813.69369768, -5871.09587258],
+                velocity: [9.881929371, -1.978467207, -1.922261005],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [24738.60364819, 6383.41644019, 17787.27631900],
+                velocity: [0.639556952, 1.392554379, 2.906206324],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [23546.85388669, 14358.15602832, 33441.67679479],
+                velocity: [-0.734895006, 0.841564851, 1.526009909],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [15855.87696303, 18624.05633582, 40367.13420574],
+                velocity: [-1.337753546, 0.343969522, 0.410018472],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [4976.44933591, 19156.75504042, 39189.68603184],
+                velocity: [-1.642084365, -0.218525096, -0.774148204],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [-6909.20746210, 14790.44707042, 28034.46732222],
+                velocity: [-1.545152610, -1.088119523, -2.487447214],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [-7331.65006707, -604.17323419, -2723.51014575],
+                velocity: [6.168997265, -3.634011554, -5.963531682],
+            },
+        ],
+    },
+    // Lyddane bug at less than 70 min, with atan2(), but no quadrant fix.
+    ReferenceCase {
+        line1: "1 23177U 94040C   06175.45752052  .00000386  00000-0  76590-3 0    95",
+        line2: "2 23177   7.0496 179.8238 7258491 296.0482   8.3061  2.25906668 97438",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-8801.60046706, -0.03357557, -0.44522743],
+                velocity: [-3.835279101, -7.662552175, 0.944561323],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [-1684.34352858, -31555.95196340, 3888.99944319],
+                velocity: [2.023055719, -2.151306405, 0.265065778],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [12325.51410155, -38982.15046244, 4802.88832275],
+                velocity: [1.763224157, -0.102514446, 0.012397139],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [22773.66831936, -34348.02176606, 4228.77407391],
+                velocity: [1.067616787, 1.352427865, -0.166956367],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [26194.40441089, -19482.94203672, 2393.84774063],
+                velocity: [-0.313732186, 2.808771328, -0.346204118],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [8893.50573448, 5763.38890561, -713.69884164],
+                velocity: [-7.037399220, 3.022613131, -0.370272416],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-6028.75686537, -25648.99913786, 3164.37107274],
+                velocity: [1.883159288, -3.177051976, 0.390793162],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [8313.57299056, -38146.45710922, 4697.80777535],
+                velocity: [1.905002133, -0.625883074, 0.076098187],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [20181.29108622, -36842.60674073, 4529.12568218],
+                velocity: [1.326244476, 0.921916487, -0.114527455],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [26302.61794569, -25173.39539436, 3084.65309986],
+                velocity: [0.245398835, 2.329974347, -0.287495880],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [19365.07045602, -2700.00490122, 317.42727417],
+                velocity: [-3.009733018, 3.902496058, -0.478928582],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-9667.81878780, -16930.19112642, 2095.87469034],
+                velocity: [1.279288285, -4.736005905, 0.582878255],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [4021.31438583, -36066.09209609, 4442.91587411],
+                velocity: [2.007322354, -1.227461376, 0.149383897],
+            },
+        ],
+    },
+    // Very high eccentricity, shows Kepler solution problems in Report #3 code.
+    ReferenceCase {
+        line1: "1 23333U 94071A   94305.49999999 -.00172956  26967-3  10000-3 0    15",
+        line2: "2 23333  28.7490   2.3720 9728298  30.4360   1.3500  0.07309491    70",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-9301.24542292, 3326.10200382, 2318.36441127],
+                velocity: [-8.729303005, -0.828225037, -0.122314827],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [-44672.91239680, -6213.11996581, -1738.80131727],
+                velocity: [-3.719475070, -1.336673022, -0.621888261],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-67053.08885388, -14994.69685946, -5897.99072793],
+                velocity: [-2.860576613, -1.183771565, -0.568473909],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-85227.84253168, -22897.08484471, -9722.59184564],
+                velocity: [-2.426469823, -1.078592475, -0.525341431],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-100986.00419136, -30171.19698695, -13283.77044765],
+                velocity: [-2.147108978, -1.000530827, -0.491587582],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-115093.00686387, -36962.56316477, -16634.15682929],
+                velocity: [-1.945446188, -0.938947736, -0.464199202],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-127965.80064891, -43363.32967165, -19809.90480432],
+                velocity: [-1.789652016, -0.888278463, -0.441254468],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-139863.28332207, -49436.45704153, -22836.80438139],
+                velocity: [-1.663762568, -0.845315913, -0.421548627],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-150960.22978259, -55227.45413896, -25734.01408879],
+                velocity: [-1.558730986, -0.808061065, -0.404293846],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-161381.71414630, -60770.64040903, -28516.26290017],
+                velocity: [-1.468977174, -0.775190459, -0.388951810],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [-171221.18736947, -66092.76474442, -31195.19847387],
+                velocity: [-1.390837596, -0.745785633, -0.375140398],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-180550.82888746, -71215.23290630, -33780.24938270],
+                velocity: [-1.321788672, -0.719184752, -0.362579495],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-189427.87533074, -76155.54943344, -36279.19882816],
+                velocity: [-1.260024473, -0.694896053, -0.351058133],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [-197898.69401409, -80928.29015181, -38698.57972447],
+                velocity: [-1.204211888, -0.672544709, -0.340413731],
+            },
+            ReferenceState::Prediction {
+                time: 1600.0,
+                position: [-200638.82986236, -82484.14969882, -39488.34331447],
+                velocity: [-1.186748462, -0.665472422, -0.337037582],
+            },
+        ],
+    },
+    // Lyddane bug after 280.5 min respectively, with atan2(), but no quadrant fix.
+    ReferenceCase {
+        line1: "1 23599U 95029B   06171.76535463  .00085586  12891-6  12956-2 0  2905",
+        line2: "2 23599   6.9327   0.2849 5782022 274.4436  25.2425  4.47796565123555",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [9892.63794341, 35.76144969, -1.08228838],
+                velocity: [3.556643237, 6.456009375, 0.783610890],
+            },
+            ReferenceState::Prediction {
+                time: 20.0,
+                position: [11931.95642997, 7340.74973750, 886.46365987],
+                velocity: [0.308329116, 5.532328972, 0.672887281],
+            },
+            ReferenceState::Prediction {
+                time: 40.0,
+                position: [11321.71039205, 13222.84749156, 1602.40119049],
+                velocity: [-1.151973982, 4.285810871, 0.521919425],
+            },
+            ReferenceState::Prediction {
+                time: 60.0,
+                position: [9438.29395675, 17688.05450261, 2146.59293402],
+                velocity: [-1.907904054, 3.179955046, 0.387692479],
+            },
+            ReferenceState::Prediction {
+                time: 80.0,
+                position: [6872.08634639, 20910.11016811, 2539.79945034],
+                velocity: [-2.323995367, 2.207398462, 0.269506121],
+            },
+            ReferenceState::Prediction {
+                time: 100.0,
+                position: [3933.37509798, 23024.07662542, 2798.25966746],
+                velocity: [-2.542860616, 1.327134966, 0.162450076],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [816.64091546, 24118.98675475, 2932.69459428],
+                velocity: [-2.626838010, 0.504502763, 0.062344306],
+            },
+            ReferenceState::Prediction {
+                time: 140.0,
+                position: [-2334.41705804, 24246.86096326, 2949.36448841],
+                velocity: [-2.602259646, -0.288058266, -0.034145135],
+            },
+            ReferenceState::Prediction {
+                time: 160.0,
+                position: [-5394.31798039, 23429.42716149, 2850.86832586],
+                velocity: [-2.474434068, -1.074055982, -0.129868366],
+            },
+            ReferenceState::Prediction {
+                time: 180.0,
+                position: [-8233.35130237, 21661.24480883, 2636.51456118],
+                velocity: [-2.230845533, -1.875742344, -0.227528603],
+            },
+            ReferenceState::Prediction {
+                time: 200.0,
+                position: [-10693.96497348, 18909.88168891, 2302.33707548],
+                velocity: [-1.835912433, -2.716169865, -0.329931880],
+            },
+            ReferenceState::Prediction {
+                time: 220.0,
+                position: [-12553.89669904, 15114.63990716, 1840.93573231],
+                velocity: [-1.212478879, -3.619036996, -0.439970633],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-13450.20591864, 10190.57904289, 1241.95958736],
+                velocity: [-0.189082511, -4.596701971, -0.559173899],
+            },
+            ReferenceState::Prediction {
+                time: 260.0,
+                position: [-12686.60437121, 4079.31106161, 498.27078614],
+                velocity: [1.664498211, -5.559889865, -0.676747779],
+            },
+            ReferenceState::Prediction {
+                time: 280.0,
+                position: [-8672.55867753, -2827.56823315, -342.59644716],
+                velocity: [5.515079852, -5.551222962, -0.676360044],
+            },
+            ReferenceState::Prediction {
+                time: 300.0,
+                position: [1153.31498060, -6411.98692060, -779.87288941],
+                velocity: [9.689818102, 1.388598425, 0.167868798],
+            },
+            ReferenceState::Prediction {
+                time: 320.0,
+                position: [9542.79201056, -533.71253081, -65.73165428],
+                velocity: [3.926947087, 6.459583539, 0.785686755],
+            },
+            ReferenceState::Prediction {
+                time: 340.0,
+                position: [11868.80960100, 6861.59590848, 833.72780602],
+                velocity: [0.452957852, 5.632811328, 0.685262323],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [11376.23941678, 12858.97121366, 1563.40660172],
+                velocity: [-1.087665695, 4.374693347, 0.532207051],
+            },
+            ReferenceState::Prediction {
+                time: 380.0,
+                position: [9547.70300782, 17421.48570758, 2118.56907515],
+                velocity: [-1.876540262, 3.253891728, 0.395810243],
+            },
+            ReferenceState::Prediction {
+                time: 400.0,
+                position: [7008.51470263, 20725.47471227, 2520.56064289],
+                velocity: [-2.308703599, 2.270724438, 0.276138613],
+            },
+            ReferenceState::Prediction {
+                time: 420.0,
+                position: [4083.18551180, 22910.88306802, 2786.35642660],
+                velocity: [-2.536610941, 1.383768875, 0.168165414],
+            },
+            ReferenceState::Prediction {
+                time: 440.0,
+                position: [970.13107533, 24071.19896282, 2927.30875440],
+                velocity: [-2.626673095, 0.557274717, 0.067549303],
+            },
+            ReferenceState::Prediction {
+                time: 460.0,
+                position: [-2183.75499348, 24261.30188126, 2950.09189560],
+                velocity: [-2.607082241, -0.236785937, -0.029112844],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-5252.49066783, 23505.58108388, 2857.68628654],
+                velocity: [-2.484465059, -1.022158411, -0.124702643],
+            },
+            ReferenceState::Prediction {
+                time: 500.0,
+                position: [-8107.41437587, 21801.13395060, 2649.76852683],
+                velocity: [-2.247669530, -1.821071275, -0.221914939],
+            },
+            ReferenceState::Prediction {
+                time: 520.0,
+                position: [-10594.01813094, 19118.22269010, 2322.77197767],
+                velocity: [-1.863224062, -2.656353699, -0.323512642],
+            },
+            ReferenceState::Prediction {
+                time: 540.0,
+                position: [-12496.70758499, 15399.13096351, 1869.75958053],
+                velocity: [-1.258272118, -3.551534022, -0.432332913],
+            },
+            ReferenceState::Prediction {
+                time: 560.0,
+                position: [-13467.50382653, 10561.43040038, 1280.84842178],
+                velocity: [-0.272050695, -4.520503543, -0.550014833],
+            },
+            ReferenceState::Prediction {
+                time: 580.0,
+                position: [-12848.00717497, 4541.72432009, 548.59976478],
+                velocity: [1.493938056, -5.489644146, -0.667479244],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-9152.79920397, -2343.88902799, -287.93741332],
+                velocity: [5.127695273, -5.650584983, -0.686013644],
+            },
+            ReferenceState::Prediction {
+                time: 620.0,
+                position: [280.12478642, -6500.11368508, -790.36236302],
+                velocity: [9.779642904, 0.581430120, 0.074124421],
+            },
+            ReferenceState::Prediction {
+                time: 640.0,
+                position: [9166.21406115, -1093.48756223, -129.53833135],
+                velocity: [4.316926785, 6.438465969, 0.785095966],
+            },
+            ReferenceState::Prediction {
+                time: 660.0,
+                position: [11794.74563870, 6381.74484842, 780.82775971],
+                velocity: [0.604642523, 5.731705440, 0.697571522],
+            },
+            ReferenceState::Prediction {
+                time: 680.0,
+                position: [11424.80363789, 12493.80833338, 1524.27683836],
+                velocity: [-1.021148661, 4.463489406, 0.542537702],
+            },
+            ReferenceState::Prediction {
+                time: 700.0,
+                position: [9652.78920084, 17153.46470428, 2090.43413681],
+                velocity: [-1.844382696, 3.327595388, 0.403924198],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [7141.24742526, 20538.97115158, 2501.18059966],
+                velocity: [-2.293079623, 2.333598993, 0.282727441],
+            },
+        ],
+    },
+    // Geostationary orbit above 3 deg.
+    ReferenceCase {
+        line1: "1 24208U 96044A   06177.04061740 -.00000094  00000-0  10000-3 0  1600",
+        line2: "2 24208   3.8536  80.0121 0026640 311.0977  48.3000  1.00778054 36119",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [7534.10987189, 41266.39266843, -0.10801028],
+                velocity: [-3.027168008, 0.558848996, 0.207982755],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [-14289.19940414, 39469.05530051, 1428.62838591],
+                velocity: [-2.893205245, -1.045447840, 0.179634249],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-32222.92014955, 26916.25425799, 2468.59996594],
+                velocity: [-1.973007929, -2.359335071, 0.102539376],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-41413.95109398, 7055.51656639, 2838.90906671],
+                velocity: [-0.521665080, -3.029172207, -0.002066843],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-39402.72251896, -14716.42475223, 2441.32678358],
+                velocity: [1.066928187, -2.878714619, -0.105865729],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-26751.08889828, -32515.13982431, 1384.38865570],
+                velocity: [2.366228869, -1.951032799, -0.181018498],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-6874.77975542, -41530.38329422, -46.60245459],
+                velocity: [3.027415087, -0.494671177, -0.207337260],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [14859.52039042, -39302.58907247, -1465.02482524],
+                velocity: [2.869609883, 1.100123969, -0.177514425],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [32553.14863770, -26398.88401807, -2485.45866002],
+                velocity: [1.930064459, 2.401574539, -0.099250520],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [41365.67576837, -6298.09965811, -2828.05254033],
+                velocity: [0.459741276, 3.051680214, 0.006431872],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [38858.83295070, 15523.39314924, -2396.86850752],
+                velocity: [-1.140211488, 2.867567143, 0.110637217],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [25701.46068162, 33089.42617648, -1308.68556638],
+                velocity: [-2.428713821, 1.897381431, 0.184605907],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [5501.08137100, 41590.27784405, 138.32522930],
+                velocity: [-3.050691874, 0.409203052, 0.207958133],
+            },
+        ],
+    },
+    // Low-inclination GEO case like 28626,
+    // shows negative inclination problem at around 274 minutes from epoch.
+    ReferenceCase {
+        line1: "1 25954U 99060A   04039.68057285 -.00000108  00000-0  00000-0 0  6847",
+        line2: "2 25954   0.0004 243.8136 0001765  15.5294  22.7134  1.00271289 15615",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [8827.15660472, -41223.00971237, 3.63482963],
+                velocity: [3.007087319, 0.643701323, 0.000941663],
+            },
+            ReferenceState::Prediction {
+                time: -1440.0,
+                position: [8118.18519221, -41368.40537378, 4.11046687],
+                velocity: [3.017696741, 0.591994297, 0.000933016],
+            },
+            ReferenceState::Prediction {
+                time: -1320.0,
+                position: [27766.34015328, -31724.97000557, 9.93297846],
+                velocity: [2.314236153, 2.024903193, 0.000660861],
+            },
+            ReferenceState::Prediction {
+                time: -1200.0,
+                position: [39932.57237973, -13532.60040454, 13.12958252],
+                velocity: [0.987382819, 2.911942843, 0.000213298],
+            },
+            ReferenceState::Prediction {
+                time: -1080.0,
+                position: [41341.01365441, 8305.71681955, 12.84988501],
+                velocity: [-0.605098224, 3.014378268, -0.000291034],
+            },
+            ReferenceState::Prediction {
+                time: -960.0,
+                position: [31614.99210558, 27907.29155353, 9.16618797],
+                velocity: [-2.034243523, 2.305014102, -0.000718418],
+            },
+            ReferenceState::Prediction {
+                time: -840.0,
+                position: [13375.75227587, 39994.27017651, 3.05416854],
+                velocity: [-2.915424366, 0.975119874, -0.000955576],
+            },
+            ReferenceState::Prediction {
+                time: -720.0,
+                position: [-8464.89963309, 41312.93549892, -3.86622919],
+                velocity: [-3.011600615, -0.617275050, -0.000939664],
+            },
+            ReferenceState::Prediction {
+                time: -600.0,
+                position: [-28026.23406158, 31507.89995661, -9.76047869],
+                velocity: [-2.296840160, -2.043607595, -0.000674889],
+            },
+            ReferenceState::Prediction {
+                time: -480.0,
+                position: [-40040.01314363, 13218.00579413, -13.06594832],
+                velocity: [-0.963328772, -2.919827983, -0.000231414],
+            },
+            ReferenceState::Prediction {
+                time: -360.0,
+                position: [-41268.43291976, -8632.06859693, -12.90661266],
+                velocity: [0.630042315, -3.009677376, 0.000273163],
+            },
+            ReferenceState::Prediction {
+                time: -240.0,
+                position: [-31377.85317015, -28156.13970334, -9.32605530],
+                velocity: [2.054021717, -2.288554158, 0.000704959],
+            },
+            ReferenceState::Prediction {
+                time: -120.0,
+                position: [-13031.41552688, -40092.33381029, -3.27636660],
+                velocity: [2.924657466, -0.950541167, 0.000949381],
+            },
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [8827.15660472, -41223.00971237, 3.63482963],
+                velocity: [3.007087319, 0.643701323, 0.000941663],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [28306.85426674, -31243.80147394, 9.57216891],
+                velocity: [2.279137743, 2.064316875, 0.000684127],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [40159.05128805, -12845.39151157, 12.96086316],
+                velocity: [0.937265422, 2.928448287, 0.000245505],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [41192.55903455, 9013.79606759, 12.90495666],
+                velocity: [-0.656727442, 3.003543458, -0.000257479],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [31131.69755798, 28445.55681731, 9.42419238],
+                velocity: [-2.073484842, 2.269770851, -0.000691233],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [12687.81846530, 40217.83324639, 3.44726249],
+                velocity: [-2.931721827, 0.924962230, -0.000940766],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-9172.23500245, 41161.63475527, -3.43575757],
+                velocity: [-3.000571486, -0.668847508, -0.000940101],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-28562.51093192, 31022.45987587, -9.39562161],
+                velocity: [-2.261449202, -2.082713897, -0.000689669],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-40260.77504549, 12529.11484344, -12.84915105],
+                velocity: [-0.913097031, -2.935933528, -0.000256181],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-41114.14376538, -9338.87194483, -12.87952404],
+                velocity: [0.681588815, -2.998432565, 0.000245006],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [-30890.01512240, -28690.40750792, -9.48037212],
+                velocity: [2.092989805, -2.252978152, 0.000680459],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-12341.46194020, -40310.06316386, -3.55833201],
+                velocity: [2.940537098, -0.900219523, 0.000934170],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [9533.27750818, -41065.52390214, 3.30756482],
+                velocity: [2.995596171, 0.695200236, 0.000938525],
+            },
+        ],
+    },
+    // Negative inclination at 9313 min.
+    ReferenceCase {
+        line1: "1 26900U 01039A   06106.74503247  .00000045  00000-0  10000-3 0  8290",
+        line2: "2 26900   0.0164 266.5378 0003319  86.1794 182.2590  1.00273847 16981",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-42014.83795787, 3702.34357772, -26.67500257],
+                velocity: [-0.269775247, -3.061854393, 0.000336726],
+            },
+            ReferenceState::Prediction {
+                time: 9300.0,
+                position: [40968.68133298, -9905.99156086, 11.84946837],
+                velocity: [0.722756848, 2.989645389, -0.000161261],
+            },
+            ReferenceState::Prediction {
+                time: 9360.0,
+                position: [42135.66858481, 1072.99195618, 10.83481752],
+                velocity: [-0.078150602, 3.074772455, -0.000380063],
+            },
+            ReferenceState::Prediction {
+                time: 9400.0,
+                position: [41304.75156132, 8398.27742944, 9.74006214],
+                velocity: [-0.612515135, 3.014117469, -0.000511575],
+            },
+        ],
+    },
+    // Molniya style debris launch.
+    // Exercises the 0.5 to 0.65 eccentricity branches in deep space.
+    ReferenceCase {
+        line1: "1 26975U 78066F   06174.85818871  .00000620  00000-0  10000-3 0  6809",
+        line2: "2 26975  68.4714 236.1303 5602877 123.7484 302.5767  2.05657553 67521",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-14506.92313768, -21613.56043281, 10.05018894],
+                velocity: [2.212943308, 1.159970892, 3.020600202],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [7309.62197950, 6076.00713664, 6800.08705263],
+                velocity: [1.300543383, 5.322579615, -4.788746312],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-3882.62933791, 11960.00543452, -25088.14383845],
+                velocity: [-2.146773699, -1.372461491, -2.579382089],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-16785.45507465, -734.79159704, -34300.57085853],
+                velocity: [-1.386528125, -1.907762641, -0.220949641],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-23524.16689356, -13629.45124622, -30246.27899200],
+                velocity: [-0.462846784, -1.586139830, 1.269293624],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-22890.23597092, -22209.35900155, -16769.91946116],
+                velocity: [0.704351342, -0.671112594, 2.432433851],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-11646.39698980, -19855.44222106, 3574.00109607],
+                velocity: [2.626712727, 1.815887329, 2.960883901],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [7665.76124241, 11159.78946577, 345.93813117],
+                velocity: [-0.584818007, 3.193514161, -5.750338922],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-6369.35388112, 10204.80073022, -27844.52150384],
+                velocity: [-2.050573276, -1.582940542, -2.076075232],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-18345.64763145, -2977.76684430, -34394.90760612],
+                velocity: [-1.243589864, -1.892050757, 0.060372061],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [-23979.74839255, -15436.44139571, -28616.50540218],
+                velocity: [-0.294973425, -1.482987916, 1.478255628],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-21921.97167880, -22852.45147658, -13784.85308485],
+                velocity: [0.945455629, -0.428940995, 2.596964378],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-8266.43821031, -17210.74590112, 6967.95546070],
+                velocity: [3.082244069, 2.665881872, 2.712555075],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [6286.85464535, 13809.56328971, -6321.60663781],
+                velocity: [-1.615964016, 1.383135377, -5.358719132],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [-8730.87526788, 8244.63344365, -30039.92372791],
+                velocity: [-1.935622871, -1.724162072, -1.631224738],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [-19735.81883249, -5191.76593007, -34166.14974143],
+                velocity: [-1.097835530, -1.860148418, 0.324401050],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [-24232.73847703, -17112.08243255, -26742.88893252],
+                velocity: [-0.119786184, -1.364365317, 1.680220468],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [-20654.45640708, -23184.54386047, -10611.55144716],
+                velocity: [1.209238113, -0.144169639, 2.748054938],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [-4337.15988957, -13410.46817244, 9870.45949215],
+                velocity: [3.532753095, 3.772236461, 2.088424247],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [4074.62263523, 14698.07548285, -12248.65327973],
+                velocity: [-2.053824693, 0.203325817, -4.607867718],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [-10950.23438984, 6148.66879447, -31736.65532865],
+                velocity: [-1.809875605, -1.816179062, -1.233364913],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [-20952.40702045, -7358.71507895, -33633.06643074],
+                velocity: [-0.948973031, -1.813594137, 0.573893078],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [-24273.48944134, -18637.15546906, -24633.27702390],
+                velocity: [0.064161440, -1.228537560, 1.875728935],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [-19057.55468077, -23148.29322082, -7269.38614178],
+                velocity: [1.500802809, 0.195383037, 2.879031237],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [43.69305308, -8145.90299207, 11634.57079913],
+                velocity: [3.780661682, 5.105315423, 0.714401345],
+            },
+        ],
+    },
+    // Near Earth normal drag case but with low eccentricity (0.0000884) so certain drag terms
+    // are set to zero to avoid math errors / loss of precision.
+    ReferenceCase {
+        line1: "1 28057U 03049A   06177.78615833  .00000060  00000-0  35940-4 0  1836",
+        line2: "2 28057  98.4283 247.6961 0000884  88.1964 271.9322 14.35478080140550",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-2715.28237486, -6619.26436889, -0.01341443],
+                velocity: [-1.008587273, 0.422782003, 7.385272942],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [-1816.87920942, -1835.78762132, 6661.07926465],
+                velocity: [2.325140071, 6.655669329, 2.463394512],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [1483.17364291, 5395.21248786, 4448.65907172],
+                velocity: [2.560540387, 4.039025766, -5.736648561],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [2801.25607157, 5455.03931333, -3692.12865695],
+                velocity: [-0.595095864, -3.951923117, -6.298799125],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [411.09332812, -1728.99769152, -6935.45548810],
+                velocity: [-2.935970964, -6.684085058, 1.492800886],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-2506.52558454, -6628.98655094, -988.07784497],
+                velocity: [-1.390577189, -0.556164143, 7.312736468],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-2090.79884266, -2723.22832193, 6266.13356576],
+                velocity: [1.992640665, 6.337529519, 3.411803080],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [1091.80560222, 4809.88229503, 5172.42897894],
+                velocity: [2.717483546, 4.805518977, -5.030019896],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [2811.14062300, 5950.65707171, -2813.23705389],
+                velocity: [-0.159662742, -3.121215491, -6.775341949],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [805.72698304, -812.16627907, -7067.58483968],
+                velocity: [-2.798936020, -6.889265977, 0.472770873],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [-2249.59837532, -6505.84890714, -1956.72365062],
+                velocity: [-1.731234729, -1.528750230, 7.096660885],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-2311.57375797, -3560.99112891, 5748.16749600],
+                velocity: [1.626569751, 5.890482233, 4.293545048],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [688.16056594, 4124.87618964, 5794.55994449],
+                velocity: [2.810973665, 5.479585563, -4.224866316],
+            },
+            ReferenceState::Prediction {
+                time: 1560.0,
+                position: [2759.94088230, 6329.87271798, -1879.19518331],
+                velocity: [0.266930672, -2.222670878, -7.119390567],
+            },
+            ReferenceState::Prediction {
+                time: 1680.0,
+                position: [1171.50677137, 125.82053748, -7061.96626202],
+                velocity: [-2.605687852, -6.958489749, -0.556333225],
+            },
+            ReferenceState::Prediction {
+                time: 1800.0,
+                position: [-1951.43708472, -6251.71945820, -2886.95472355],
+                velocity: [-2.024131483, -2.475214272, 6.741537478],
+            },
+            ReferenceState::Prediction {
+                time: 1920.0,
+                position: [-2475.70722288, -4331.90569958, 5117.31234924],
+                velocity: [1.235823539, 5.322743371, 5.091281211],
+            },
+            ReferenceState::Prediction {
+                time: 2040.0,
+                position: [281.46097847, 3353.51057102, 6302.87900650],
+                velocity: [2.840647273, 6.047222485, -3.337085992],
+            },
+            ReferenceState::Prediction {
+                time: 2160.0,
+                position: [2650.33118860, 6584.33434851, -908.29027134],
+                velocity: [0.675457235, -1.274044972, -7.323921567],
+            },
+            ReferenceState::Prediction {
+                time: 2280.0,
+                position: [1501.17226597, 1066.31132756, -6918.71472952],
+                velocity: [-2.361891904, -6.889669974, -1.574718619],
+            },
+            ReferenceState::Prediction {
+                time: 2400.0,
+                position: [-1619.73468334, -5871.14051991, -3760.56587071],
+                velocity: [-2.264093975, -3.376316601, 6.254622256],
+            },
+            ReferenceState::Prediction {
+                time: 2520.0,
+                position: [-2581.04202505, -5020.05572531, 4385.92329047],
+                velocity: [0.829668458, 4.645048038, 5.789262667],
+            },
+            ReferenceState::Prediction {
+                time: 2640.0,
+                position: [-119.22080628, 2510.90620488, 6687.45615459],
+                velocity: [2.807575712, 6.496549689, -2.384136661],
+            },
+            ReferenceState::Prediction {
+                time: 2760.0,
+                position: [2486.23806726, 6708.18210028, 80.43349581],
+                velocity: [1.057274905, -0.294294027, -7.384689123],
+            },
+            ReferenceState::Prediction {
+                time: 2880.0,
+                position: [1788.42334580, 1990.50530957, -6640.59337725],
+                velocity: [-2.074169091, -6.683381288, -2.562777776],
+            },
+        ],
+    },
+    // A GPS navigation satellite in a near circular 12h orbit.
+    ReferenceCase {
+        line1: "1 28129U 03058A   06175.57071136 -.00000104  00000-0  10000-3 0   459",
+        line2: "2 28129  54.7298 324.8098 0048506 266.2640  93.1663  2.00562768 18443",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [21707.46412351, -15318.61752390, 0.13551152],
+                velocity: [1.304029214, 1.816904974, 3.161919976],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [18616.75971861, 3166.15177043, 18833.41523210],
+                velocity: [-2.076122016, 2.838457575, 1.586210535],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-3006.50596328, 18522.20742011, 18941.84078154],
+                velocity: [-3.375452789, 1.032680773, -1.559324534],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-21607.02086957, 15432.59962630, 206.62470309],
+                velocity: [-1.306049851, -1.817011568, -3.163725018],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-18453.06134549, -3150.83256134, -18685.83030936],
+                velocity: [2.106017925, -2.860236337, -1.586151870],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [3425.11742384, -18514.73232706, -18588.67200557],
+                velocity: [3.394666340, -1.003072030, 1.610061295],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [21858.23838148, -15101.51661554, 387.34517048],
+                velocity: [1.247973967, 1.856017403, 3.161439948],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [18360.69935796, 3506.55256762, 19024.81678979],
+                velocity: [-2.122684184, 2.830618605, 1.537510677],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-3412.84765409, 18646.85269710, 18748.00359987],
+                velocity: [-3.366815728, 0.986039922, -1.607874972],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-21758.08331586, 15215.44829478, -180.82181406],
+                velocity: [-1.250144680, -1.856490448, -3.163774870],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [-18193.41290284, -3493.85876912, -18877.14757717],
+                velocity: [2.153326942, -2.852221264, -1.536617760],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [3833.57386848, -18635.77026711, -18388.68722885],
+                velocity: [3.384748179, -0.955363841, 1.658785020],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [22002.20074562, -14879.72595593, 774.32827099],
+                velocity: [1.191573619, 1.894561165, 3.159953047],
+            },
+        ],
+    },
+    // Near Earth low perigee (127.20 km) that uses the branch (perigee < 156 km)
+    // for modifying the ‘s4’ drag coefficient.
+    // Propagation beyond approximately 1460 minutes should result in
+    // error trap (modified eccentricity too low).
+    ReferenceCase {
+        line1: "1 28350U 04020A   06167.21788666  .16154492  76267-5  18678-3 0  8894",
+        line2: "2 28350  64.9977 345.6130 0024870 260.7578  99.9590 16.47856722116490",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [6333.08123128, -1580.82852326, 90.69355720],
+                velocity: [0.714634423, 3.224246550, 7.083128132],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [-3990.93845855, 3052.98341907, 4155.32700629],
+                velocity: [-5.909006188, -0.876307966, -5.039131404],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-603.55232010, -2685.13474569, -5891.70274282],
+                velocity: [7.572519907, -1.975656726, 0.121722605],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [4788.22345627, 782.56169214, 4335.14284621],
+                velocity: [-4.954509026, 3.683346464, 4.804645839],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-6291.84601644, 1547.82790772, -453.67116498],
+                velocity: [-0.308625588, -3.341538574, -7.082659115],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [4480.74573428, -3028.55200374, -3586.94343641],
+                velocity: [5.320920857, 1.199736275, 5.626350481],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-446.42460916, 2932.28872588, 5759.19389757],
+                velocity: [-7.561000245, 1.550975493, -1.374970885],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-3713.79581831, -1382.66125130, -5122.45131136],
+                velocity: [6.090931626, -3.512629733, -3.467571746],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [6058.32017522, -827.47406722, 2104.04678651],
+                velocity: [-1.798403024, 3.787067272, 6.641439744],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-5631.73659006, 2623.70953644, 1766.49125084],
+                velocity: [-3.216401578, -2.309140959, -6.788609120],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [2776.84991560, -3255.36941953, -4837.19667790],
+                velocity: [6.748135564, -0.193044825, 4.005718698],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [1148.04430837, 2486.07343386, 5826.34075913],
+                velocity: [-7.420162295, 2.589456382, 0.356350006],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-4527.90871828, -723.29199041, -4527.44608319],
+                velocity: [5.121674217, -3.909895427, -4.500218556],
+            },
+        ],
+    },
+    // Deep-space object with low perigee (135.75 km) that uses
+    // the branch (perigee < 156 km) for modifying the ‘s4’ drag coefficient.
+    ReferenceCase {
+        line1: "1 28623U 05006B   06177.81079184  .00637644  69054-6  96390-3 0  6000",
+        line2: "2 28623  28.5200 114.9834 6249053 170.2550 212.8965  3.79477162 12753",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-11665.70902324, 24943.61433357, 25.80543633],
+                velocity: [-1.596228621, -1.476127961, 1.126059754],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [-11645.35454950, 979.37668356, 5517.89500058],
+                velocity: [3.407743502, -5.183094988, -0.492983277],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [5619.19252274, 19651.44862280, -7261.38496765],
+                velocity: [-2.013634213, 3.106842861, 0.284235517],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-9708.68629714, 26306.14553149, -1204.29478856],
+                velocity: [-1.824164290, -0.931909596, 1.113419052],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-14394.03162892, 6659.30765074, 5593.38345858],
+                velocity: [1.556522911, -4.681657614, 0.296912248],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [7712.09476270, 15565.72627434, -7342.40465571],
+                velocity: [-1.646800364, 4.070313571, -0.109483081],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-7558.36739603, 27035.11367962, -2385.12054184],
+                velocity: [-1.999583791, -0.393409283, 1.078093515],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-15495.61862220, 11550.15897828, 5053.83178121],
+                velocity: [0.469277336, -4.029761073, 0.679054742],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [9167.02568222, 10363.65204210, -6871.52576042],
+                velocity: [-0.881621027, 5.223361510, -0.740696297],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-5275.80272094, 27151.78486008, -3494.50687216],
+                velocity: [-2.129609388, 0.150196480, 1.021038089],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [-15601.37656145, 15641.29379850, 4217.03266850],
+                velocity: [-0.249183123, -3.405238557, 0.888214503],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [9301.05872300, 3883.15265574, -5477.86477017],
+                velocity: [0.871447821, 6.493677331, -1.885545282],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-2914.31065828, 26665.20392758, -4511.09814335],
+                velocity: [-2.216261909, 0.710067769, 0.940691824],
+            },
+        ],
+    },
+    // Low-inclination (< 3 deg) geostationary orbit that shows the problems
+    // in premature correction of negative inclination at around 1130 minutes from epoch.
+    ReferenceCase {
+        line1: "1 28626U 05008A   06176.46683397 -.00000205  00000-0  10000-3 0  2190",
+        line2: "2 28626   0.0019 286.9433 0000335  13.7918  55.6504  1.00270176  4891",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [42080.71852213, -2646.86387436, 0.81851294],
+                velocity: [0.193105177, 3.068688251, 0.000438449],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [37740.00085593, 18802.76872802, 3.45512584],
+                velocity: [-1.371035206, 2.752105932, 0.000336883],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [23232.82515008, 35187.33981802, 4.98927428],
+                velocity: [-2.565776620, 1.694193132, 0.000163365],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [2467.44290178, 42093.60909959, 5.15062987],
+                velocity: [-3.069341800, 0.179976276, -0.000031739],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-18962.59052991, 37661.66243819, 4.04433258],
+                velocity: [-2.746151982, -1.382675777, -0.000197633],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-35285.00095313, 23085.44402778, 2.08711880],
+                velocity: [-1.683277908, -2.572893625, -0.000296282],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-42103.20138132, 2291.06228893, -0.13274964],
+                velocity: [-0.166974816, -3.070104560, -0.000311007],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-37580.31858370, -19120.40485693, -2.02755702],
+                velocity: [1.394367848, -2.740341612, -0.000248591],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-22934.20761876, -35381.23870806, -3.16495932],
+                velocity: [2.580167539, -1.672360951, -0.000134907],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-2109.90332389, -42110.71508198, -3.36507889],
+                velocity: [3.070935369, -0.153808390, -0.000005855],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [19282.77774728, -37495.59250598, -2.71861462],
+                velocity: [2.734400524, 1.406220933, 0.000103486],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [35480.60990600, -22779.03375285, -1.52841859],
+                velocity: [1.661210676, 2.587414593, 0.000168300],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [42119.96263499, -1925.77567263, -0.19827433],
+                velocity: [0.140521206, 3.071541613, 0.000179561],
+            },
+        ],
+    },
+    // Sub-orbital case (perigee –51km, lost about 50 minutes from epoch) used to test error handling.
+    ReferenceCase {
+        line1: "1 28872U 05037B   05333.02012661  .25992681  00000-0  24476-3 0  1534",
+        line2: "2 28872  96.4736 157.9986 0303955 244.0492 110.6523 16.46015938 10708",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-6131.82730456, 2446.52815528, -253.64211033],
+                velocity: [-0.144920228, 0.995100963, 7.658645067],
+            },
+            ReferenceState::Prediction {
+                time: 5.0,
+                position: [-5799.24256134, 2589.14811119, 2011.54515100],
+                velocity: [2.325207364, -0.047125672, 7.296234071],
+            },
+            ReferenceState::Prediction {
+                time: 10.0,
+                position: [-4769.05061967, 2420.46580562, 4035.30855837],
+                velocity: [4.464585796, -1.060923209, 6.070907874],
+            },
+            ReferenceState::Prediction {
+                time: 15.0,
+                position: [-3175.45157340, 1965.98738086, 5582.12569607],
+                velocity: [6.049639376, -1.935777558, 4.148607019],
+            },
+            ReferenceState::Prediction {
+                time: 20.0,
+                position: [-1210.19024802, 1281.54541294, 6474.68172772],
+                velocity: [6.920746273, -2.580517337, 1.748783868],
+            },
+            ReferenceState::Prediction {
+                time: 25.0,
+                position: [896.73799533, 447.12357305, 6607.22400507],
+                velocity: [6.983396282, -2.925846168, -0.872655207],
+            },
+            ReferenceState::Prediction {
+                time: 30.0,
+                position: [2896.99663534, -440.04738594, 5954.92675486],
+                velocity: [6.211488246, -2.926949815, -3.433959806],
+            },
+            ReferenceState::Prediction {
+                time: 35.0,
+                position: [4545.78970167, -1273.55952872, 4580.16512984],
+                velocity: [4.656984233, -2.568711513, -5.638510954],
+            },
+            ReferenceState::Prediction {
+                time: 40.0,
+                position: [5627.43299371, -1947.94282469, 2634.16714930],
+                velocity: [2.464141047, -1.873985161, -7.195743032],
+            },
+            ReferenceState::Prediction {
+                time: 45.0,
+                position: [5984.72318534, -2371.37691609, 349.87996209],
+                velocity: [-0.121276950, -0.911981546, -7.859613894],
+            },
+            ReferenceState::Prediction {
+                time: 50.0,
+                position: [5548.43325922, -2480.16469245, -1979.24314527],
+                velocity: [-2.763269534, 0.199691915, -7.482796996],
+            },
+        ],
+    },
+    // Last stages of decay.
+    // Crashes before 440 min.
+    ReferenceCase {
+        line1: "1 29141U 85108AA  06170.26783845  .99999999  00000-0  13519-0 0   718",
+        line2: "2 29141  82.4288 273.4882 0015848 277.2124  83.9133 15.93343074  6828",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [423.99295524, -6658.12256149, 136.13040356],
+                velocity: [1.006373613, 0.217309983, 7.662587892],
+            },
+            ReferenceState::Prediction {
+                time: 20.0,
+                position: [931.80883587, -1017.17852239, 6529.19244527],
+                velocity: [-0.298847918, 7.613891977, 1.226399480],
+            },
+            ReferenceState::Prediction {
+                time: 40.0,
+                position: [-83.44906141, 6286.20208453, 2223.49837161],
+                velocity: [-1.113515974, 2.530970283, -7.219445568],
+            },
+            ReferenceState::Prediction {
+                time: 60.0,
+                position: [-958.57681221, 3259.26005348, -5722.63732467],
+                velocity: [-0.101225813, -6.735338321, -3.804851872],
+            },
+            ReferenceState::Prediction {
+                time: 80.0,
+                position: [-255.25619985, -5132.59762974, -4221.27233118],
+                velocity: [1.077709303, -4.905938824, 5.892521264],
+            },
+            ReferenceState::Prediction {
+                time: 100.0,
+                position: [867.44295097, -5038.40402933, 4256.73810533],
+                velocity: [0.479447535, 5.032326446, 5.857126248],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [559.16882013, 3376.30587937, 5699.22017391],
+                velocity: [-0.906749328, 6.646149867, -3.852331832],
+            },
+            ReferenceState::Prediction {
+                time: 140.0,
+                position: [-669.85184205, 6196.00229484, -2281.95741770],
+                velocity: [-0.795804092, -2.752114827, -7.202478520],
+            },
+            ReferenceState::Prediction {
+                time: 160.0,
+                position: [-784.20708019, -1278.53125553, -6449.19892596],
+                velocity: [0.636702380, -7.595425203, 1.431090802],
+            },
+            ReferenceState::Prediction {
+                time: 180.0,
+                position: [406.15811659, -6607.03115799, 148.33021477],
+                velocity: [1.009818575, 0.231843765, 7.692047844],
+            },
+            ReferenceState::Prediction {
+                time: 200.0,
+                position: [916.34911813, -884.08649248, 6491.09810362],
+                velocity: [-0.302163049, 7.669887109, 1.084336909],
+            },
+            ReferenceState::Prediction {
+                time: 220.0,
+                position: [-104.02490970, 6304.31821405, 1960.08739882],
+                velocity: [-1.108873823, 2.259522809, -7.351147710],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-944.61642849, 2872.17248379, -5846.94103362],
+                velocity: [-0.051117686, -6.989747076, -3.413102600],
+            },
+            ReferenceState::Prediction {
+                time: 260.0,
+                position: [-187.16569888, -5404.86163467, -3731.97057618],
+                velocity: [1.094696706, -4.412110995, 6.326060952],
+            },
+            ReferenceState::Prediction {
+                time: 280.0,
+                position: [884.59720467, -4465.74516163, 4725.83632696],
+                velocity: [0.380656028, 5.691554046, 5.303910983],
+            },
+            ReferenceState::Prediction {
+                time: 300.0,
+                position: [446.40767236, 4086.66839620, 5093.05596650],
+                velocity: [-0.982424447, 6.072965199, -4.791630682],
+            },
+            ReferenceState::Prediction {
+                time: 320.0,
+                position: [-752.24467495, 5588.35473301, -3275.04092573],
+                velocity: [-0.661161370, -4.016290740, -6.676898026],
+            },
+            ReferenceState::Prediction {
+                time: 340.0,
+                position: [-643.72872525, -2585.02528560, -5923.01306608],
+                velocity: [0.807922142, -7.171597814, 3.041115058],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [584.40295819, -6202.35605817, 1781.00536019],
+                velocity: [0.869250450, 2.226927514, 7.471676765],
+            },
+            ReferenceState::Prediction {
+                time: 380.0,
+                position: [779.59211765, 1100.73728301, 6311.59529480],
+                velocity: [-0.599552305, 7.721032522, -1.275153027],
+            },
+            ReferenceState::Prediction {
+                time: 400.0,
+                position: [-403.03155588, 6399.18000837, -364.12735875],
+                velocity: [-1.008861924, -0.516636615, -7.799812287],
+            },
+            ReferenceState::Prediction {
+                time: 420.0,
+                position: [-852.93910071, 192.65232023, -6322.47054784],
+                velocity: [0.396006194, -7.882964919, -0.289331517],
+            },
+        ],
+    },
+    // Near Earth with perigee 212.24 km, thus uses
+    // simplified drag branch (perigee < 220 km) test.
+    ReferenceCase {
+        line1: "1 29238U 06022G   06177.28732010  .00766286  10823-4  13334-2 0   101",
+        line2: "2 29238  51.5595 213.7903 0202579  95.2503 267.9010 15.73823839  1061",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-5566.59512819, -3789.75991159, 67.60382245],
+                velocity: [2.873759367, -3.825340523, 6.023253926],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [4474.27915495, -1447.72286142, 4619.83927235],
+                velocity: [4.712595822, 5.668306153, -2.701606741],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [1922.17712474, 5113.01138342, -4087.08470203],
+                velocity: [-6.490769651, -0.522350158, -3.896001154],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [-6157.93546882, -2094.70798790, -1941.63730960],
+                velocity: [0.149900661, -5.175192523, 5.604262034],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [2482.64052411, -3268.45944555, 5146.38006190],
+                velocity: [6.501814698, 4.402848754, -0.350943511],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [4036.26455287, 4827.43347201, -2507.99063955],
+                velocity: [-5.184409515, 1.772280695, -5.331390168],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-5776.81371622, -118.64155319, -3641.22052418],
+                velocity: [-2.539917207, -5.622701582, 4.403125405],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [67.98699487, -4456.49213473, 4863.71794283],
+                velocity: [7.183809420, 2.418917791, 2.015642495],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [5520.62207038, 3782.38203554, -596.73193161],
+                velocity: [-3.027966069, 3.754152525, -6.013506363],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-4528.05104455, 1808.46273329, -4816.99727762],
+                velocity: [-4.808419763, -5.185789345, 2.642104494],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [-2356.61468078, -4852.51202272, 3856.53816184],
+                velocity: [6.688446735, 0.118520958, 4.021854210],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [6149.65800134, 2173.59423261, 1369.29488732],
+                velocity: [-0.345832777, 5.109857861, -5.842951828],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [-2629.55011449, 3400.98040158, -5344.38217129],
+                velocity: [-6.368548448, -3.998963509, 0.577253064],
+            },
+        ],
+    },
+    // Original STR#3 report test case.
+    ReferenceCase {
+        line1: "1 88888U          80275.98708465  .00073094  13844-3  66816-4 0    87",
+        line2: "2 88888  72.8435 115.9689 0086731  52.6988 110.5714 16.05824518  1058",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [2328.96975262, -5995.22051338, 1719.97297192],
+                velocity: [2.912073281, -0.983417956, -7.090816210],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [1020.69234558, 2286.56260634, -6191.55565927],
+                velocity: [-3.746543902, 6.467532721, 1.827985678],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [-3226.54349155, 3503.70977525, 4532.80979343],
+                velocity: [1.000992116, -5.788042888, 5.162585826],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [2456.10706533, -6071.93855503, 1222.89768554],
+                velocity: [2.679390040, -0.448290811, -7.228792155],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [787.16457349, 2719.91800946, -6043.86662024],
+                velocity: [-3.759883839, 6.277439314, 2.397897864],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-3110.97648029, 3121.73026235, 4878.15217035],
+                velocity: [1.244916056, -6.124880425, 4.700576353],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [2567.56229695, -6112.50383922, 713.96374435],
+                velocity: [2.440245751, 0.098109002, -7.319959258],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [556.05661780, 3144.52288201, -5855.34636178],
+                velocity: [-3.754660143, 6.044752775, 2.957941672],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-2982.47940539, 2712.61663711, 5192.32330472],
+                velocity: [1.475566773, -6.427737014, 4.202420227],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [2663.08964352, -6115.48290885, 196.40072866],
+                velocity: [2.196121564, 0.652415093, -7.362824152],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [328.54999674, 3557.09490552, -5626.21427211],
+                velocity: [-3.731193288, 5.769341172, 3.504058731],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [-2842.06876757, 2278.42343492, 5472.33437150],
+                velocity: [1.691852635, -6.693216335, 3.671022712],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [2742.55398832, -6079.67009123, -326.39012649],
+                velocity: [1.948497651, 1.211072678, -7.356193131],
+            },
+        ],
+    },
+    ReferenceCase {
+        line1: "1 20413U 83020D   05363.79166667  .00000000  00000-0  00000+0 0  7041",
+        line2: "2 20413  12.3514 187.4253 7864447 196.3027 356.5478  0.24690082  7978",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [25123.29290741, -13225.49966286, 3249.40351869],
+                velocity: [0.488683419, 4.797897593, -0.961119693],
+            },
+            ReferenceState::Prediction {
+                time: 1844000.0,
+                position: [-35697.35025451, -70749.92495964, 14190.12461545],
+                velocity: [1.649636113, 1.769993942, -0.576290053],
+            },
+            ReferenceState::Prediction {
+                time: 1844005.0,
+                position: [-35200.64824316, -70216.17362227, 14016.77915662],
+                velocity: [1.657878275, 1.786366861, -0.579577470],
+            },
+            ReferenceState::Prediction {
+                time: 1844010.0,
+                position: [-34701.45381520, -69677.45988703, 13842.43709323],
+                velocity: [1.666228231, 1.803062654, -0.582913655],
+            },
+            ReferenceState::Prediction {
+                time: 1844015.0,
+                position: [-34199.73419511, -69133.68506099, 13667.08357914],
+                velocity: [1.674688808, 1.820093010, -0.586300058],
+            },
+            ReferenceState::Prediction {
+                time: 1844020.0,
+                position: [-33695.45574304, -68584.74685089, 13490.70332382],
+                velocity: [1.683262939, 1.837470228, -0.589738194],
+            },
+            ReferenceState::Prediction {
+                time: 1844025.0,
+                position: [-33188.58392291, -68030.53917382, 13313.28057302],
+                velocity: [1.691953664, 1.855207259, -0.593229642],
+            },
+            ReferenceState::Prediction {
+                time: 1844030.0,
+                position: [-32679.08326923, -67470.95195529, 13134.79908827],
+                velocity: [1.700764139, 1.873317750, -0.596776055],
+            },
+            ReferenceState::Prediction {
+                time: 1844035.0,
+                position: [-32166.91735222, -66905.87091261, 12955.24212519],
+                velocity: [1.709697636, 1.891816094, -0.600379156],
+            },
+            ReferenceState::Prediction {
+                time: 1844040.0,
+                position: [-31652.04874147, -66335.17732309, 12774.59241050],
+                velocity: [1.718757551, 1.910717482, -0.604040751],
+            },
+            ReferenceState::Prediction {
+                time: 1844045.0,
+                position: [-31134.43896798, -65758.74777552, 12592.83211778],
+                velocity: [1.727947411, 1.930037964, -0.607762726],
+            },
+            ReferenceState::Prediction {
+                time: 1844050.0,
+                position: [-30614.04848454, -65176.45390327, 12409.94284157],
+                velocity: [1.737270876, 1.949794512, -0.611547059],
+            },
+            ReferenceState::Prediction {
+                time: 1844055.0,
+                position: [-30090.83662441, -64588.16209752, 12225.90557016],
+                velocity: [1.746731748, 1.970005085, -0.615395818],
+            },
+            ReferenceState::Prediction {
+                time: 1844060.0,
+                position: [-29564.76155792, -63993.73319837, 12040.70065637],
+                velocity: [1.756333977, 1.990688712, -0.619311176],
+            },
+            ReferenceState::Prediction {
+                time: 1844065.0,
+                position: [-29035.78024784, -63393.02216277, 11854.30778690],
+                velocity: [1.766081663, 2.011865571, -0.623295408],
+            },
+            ReferenceState::Prediction {
+                time: 1844070.0,
+                position: [-28503.84840188, -62785.87770518, 11666.70594931],
+                velocity: [1.775979069, 2.033557081, -0.627350903],
+            },
+            ReferenceState::Prediction {
+                time: 1844075.0,
+                position: [-27968.92042406, -62172.14191043, 11477.87339723],
+                velocity: [1.786030623, 2.055786004, -0.631480172],
+            },
+            ReferenceState::Prediction {
+                time: 1844080.0,
+                position: [-27430.94936367, -61551.64981438, 11287.78761309],
+                velocity: [1.796240927, 2.078576552, -0.635685852],
+            },
+            ReferenceState::Prediction {
+                time: 1844085.0,
+                position: [-26889.88686232, -60924.22895005, 11096.42526856],
+                velocity: [1.806614763, 2.101954513, -0.639970719],
+            },
+            ReferenceState::Prediction {
+                time: 1844090.0,
+                position: [-26345.68309880, -60289.69885481, 10903.76218217],
+                velocity: [1.817157098, 2.125947384, -0.644337692],
+            },
+            ReferenceState::Prediction {
+                time: 1844095.0,
+                position: [-25798.28673217, -59647.87053555, 10709.77327429],
+                velocity: [1.827873095, 2.150584520, -0.648789848],
+            },
+            ReferenceState::Prediction {
+                time: 1844100.0,
+                position: [-25247.64484245, -58998.54588611, 10514.43251873],
+                velocity: [1.838768116, 2.175897299, -0.653330430],
+            },
+            ReferenceState::Prediction {
+                time: 1844105.0,
+                position: [-24693.70286998, -58341.51705293, 10317.71289135],
+                velocity: [1.849847729, 2.201919308, -0.657962859],
+            },
+            ReferenceState::Prediction {
+                time: 1844110.0,
+                position: [-24136.40455244, -57676.56574206, 10119.58631477],
+                velocity: [1.861117715, 2.228686544, -0.662690746],
+            },
+            ReferenceState::Prediction {
+                time: 1844115.0,
+                position: [-23575.69186056, -57003.46246135, 9920.02359927],
+                velocity: [1.872584071, 2.256237646, -0.667517908],
+            },
+            ReferenceState::Prediction {
+                time: 1844120.0,
+                position: [-23011.50493242, -56321.96569019, 9718.99437947],
+                velocity: [1.884253014, 2.284614147, -0.672448379],
+            },
+            ReferenceState::Prediction {
+                time: 1844125.0,
+                position: [-22443.78200684, -55631.82096815, 9516.46704636],
+                velocity: [1.896130986, 2.313860763, -0.677486431],
+            },
+            ReferenceState::Prediction {
+                time: 1844130.0,
+                position: [-21872.45935608, -54932.75989218, 9312.40867434],
+                velocity: [1.908224653, 2.344025713, -0.682636585],
+            },
+            ReferenceState::Prediction {
+                time: 1844135.0,
+                position: [-21297.47121883, -54224.49901162, 9106.78494287],
+                velocity: [1.920540902, 2.375161082, -0.687903637],
+            },
+            ReferenceState::Prediction {
+                time: 1844140.0,
+                position: [-20718.74973403, -53506.73860734, 8899.56005222],
+                velocity: [1.933086841, 2.407323230, -0.693292673],
+            },
+            ReferenceState::Prediction {
+                time: 1844145.0,
+                position: [-20136.22487674, -52779.16134023, 8690.69663286],
+                velocity: [1.945869783, 2.440573257, -0.698809093],
+            },
+            ReferenceState::Prediction {
+                time: 1844150.0,
+                position: [-19549.82439734, -52041.43075119, 8480.15564799],
+                velocity: [1.958897241, 2.474977526, -0.704458636],
+            },
+            ReferenceState::Prediction {
+                time: 1844155.0,
+                position: [-18959.47376563, -51293.18959215, 8267.89628842],
+                velocity: [1.972176899, 2.510608267, -0.710247405],
+            },
+            ReferenceState::Prediction {
+                time: 1844160.0,
+                position: [-18365.09612269, -50534.05796487, 8053.87585961],
+                velocity: [1.985716590, 2.547544259, -0.716181895],
+            },
+            ReferenceState::Prediction {
+                time: 1844165.0,
+                position: [-17766.61224263, -49763.63123890, 7838.04965980],
+                velocity: [1.999524252, 2.585871620, -0.722269025],
+            },
+            ReferenceState::Prediction {
+                time: 1844170.0,
+                position: [-17163.94050833, -48981.47771614, 7620.37084880],
+                velocity: [2.013607877, 2.625684710, -0.728516169],
+            },
+            ReferenceState::Prediction {
+                time: 1844175.0,
+                position: [-16556.99690580, -48187.13600348, 7400.79030672],
+                velocity: [2.027975437, 2.667087175, -0.734931191],
+            },
+            ReferenceState::Prediction {
+                time: 1844180.0,
+                position: [-15945.69504290, -47380.11204687, 7179.25648175],
+                velocity: [2.042634794, 2.710193161, -0.741522483],
+            },
+            ReferenceState::Prediction {
+                time: 1844185.0,
+                position: [-15329.94620103, -46559.87577354, 6955.71522690],
+                velocity: [2.057593576, 2.755128724, -0.748299005],
+            },
+            ReferenceState::Prediction {
+                time: 1844190.0,
+                position: [-14709.65942851, -45725.85727497, 6730.10962444],
+                velocity: [2.072859013, 2.802033480, -0.755270324],
+            },
+            ReferenceState::Prediction {
+                time: 1844195.0,
+                position: [-14084.74168946, -44877.44245330, 6502.37979807],
+                velocity: [2.088437732, 2.851062542, -0.762446659],
+            },
+            ReferenceState::Prediction {
+                time: 1844200.0,
+                position: [-13455.09808433, -44013.96803490, 6272.46271243],
+                velocity: [2.104335484, 2.902388807, -0.769838921],
+            },
+            ReferenceState::Prediction {
+                time: 1844205.0,
+                position: [-12820.63216428, -43134.71583613, 6040.29196035],
+                velocity: [2.120556786, 2.956205671, -0.777458756],
+            },
+            ReferenceState::Prediction {
+                time: 1844210.0,
+                position: [-12181.24636732, -42238.90613844, 5805.79753835],
+                velocity: [2.137104464, 3.012730262, -0.785318582],
+            },
+            ReferenceState::Prediction {
+                time: 1844215.0,
+                position: [-11536.84261368, -41325.68999865, 5568.90561221],
+                velocity: [2.153979041, 3.072207323, -0.793431617],
+            },
+            ReferenceState::Prediction {
+                time: 1844220.0,
+                position: [-10887.32310987, -40394.14027737, 5329.53827536],
+                velocity: [2.171177951, 3.134913901, -0.801811902],
+            },
+            ReferenceState::Prediction {
+                time: 1844225.0,
+                position: [-10232.59142737, -39443.24111540, 5087.61330523],
+                velocity: [2.188694483, 3.201165036, -0.810474295],
+            },
+            ReferenceState::Prediction {
+                time: 1844230.0,
+                position: [-9572.55394393, -38471.87551671, 4843.04392506],
+                velocity: [2.206516401, 3.271320733, -0.819434438],
+            },
+            ReferenceState::Prediction {
+                time: 1844235.0,
+                position: [-8907.12176784, -37478.81060673, 4595.73858344],
+                velocity: [2.224624089, 3.345794555, -0.828708674],
+            },
+            ReferenceState::Prediction {
+                time: 1844240.0,
+                position: [-8236.21330715, -36462.68000983, 4345.60076943],
+                velocity: [2.242988067, 3.425064297, -0.838313893],
+            },
+            ReferenceState::Prediction {
+                time: 1844245.0,
+                position: [-7559.75771067, -35421.96263240, 4092.52889178],
+                velocity: [2.261565621, 3.509685388, -0.848267260],
+            },
+            ReferenceState::Prediction {
+                time: 1844250.0,
+                position: [-6877.69949423, -34354.95691398, 3836.41626403],
+                velocity: [2.280296205, 3.600307848, -0.858585776],
+            },
+            ReferenceState::Prediction {
+                time: 1844255.0,
+                position: [-6190.00479593, -33259.74931042, 3577.15125966],
+                velocity: [2.299095084, 3.697697981, -0.869285578],
+            },
+            ReferenceState::Prediction {
+                time: 1844260.0,
+                position: [-5496.66989517, -32134.17535485, 3314.61773512],
+                velocity: [2.317844446, 3.802766452, -0.880380840],
+            },
+            ReferenceState::Prediction {
+                time: 1844265.0,
+                position: [-4797.73291806, -30975.77105656, 3048.69587160],
+                velocity: [2.336380825, 3.916605048, -0.891882055],
+            },
+            ReferenceState::Prediction {
+                time: 1844270.0,
+                position: [-4093.29008842, -29781.71155368, 2779.26366928],
+                velocity: [2.354477016, 4.040535491, -0.903793358],
+            },
+            ReferenceState::Prediction {
+                time: 1844275.0,
+                position: [-3383.51857112, -28548.73271713, 2506.19946418],
+                velocity: [2.371815647, 4.176175206, -0.916108304],
+            },
+            ReferenceState::Prediction {
+                time: 1844280.0,
+                position: [-2668.70904438, -27273.02958541, 2229.38606006],
+                velocity: [2.387949818, 4.325527393, -0.928803170],
+            },
+            ReferenceState::Prediction {
+                time: 1844285.0,
+                position: [-1949.31292724, -25950.12276772, 1948.71744545],
+                velocity: [2.402243201, 4.491106668, -0.941826145],
+            },
+            ReferenceState::Prediction {
+                time: 1844290.0,
+                position: [-1226.01219539, -24574.67969914, 1664.10971973],
+                velocity: [2.413776635, 4.676117853, -0.955079559],
+            },
+            ReferenceState::Prediction {
+                time: 1844295.0,
+                position: [-499.82494656, -23140.27088532, 1375.51902292],
+                velocity: [2.421198325, 4.884716234, -0.968389967],
+            },
+            ReferenceState::Prediction {
+                time: 1844300.0,
+                position: [227.73070622, -21639.03026438, 1082.97143381],
+                velocity: [2.422475651, 5.122396027, -0.981456298],
+            },
+            ReferenceState::Prediction {
+                time: 1844305.0,
+                position: [954.37414156, -20061.17032355, 786.61399449],
+                velocity: [2.414468102, 5.396586724, -0.993756776],
+            },
+            ReferenceState::Prediction {
+                time: 1844310.0,
+                position: [1676.62598578, -18394.27054334, 486.80451896],
+                velocity: [2.392158864, 5.717597475, -1.004374559],
+            },
+            ReferenceState::Prediction {
+                time: 1844315.0,
+                position: [2389.05019358, -16622.20037093, 184.27608369],
+                velocity: [2.347196726, 6.100163738, -1.011653737],
+            },
+            ReferenceState::Prediction {
+                time: 1844320.0,
+                position: [3082.86422007, -14723.43238400, -119.54602920],
+                velocity: [2.264946104, 6.566068142, -1.012476101],
+            },
+            ReferenceState::Prediction {
+                time: 1844325.0,
+                position: [3743.21501841, -12668.30537300, -421.89289454],
+                velocity: [2.118033246, 7.148704873, -1.000616134],
+            },
+            ReferenceState::Prediction {
+                time: 1844330.0,
+                position: [4343.37719213, -10414.45480896, -717.19751285],
+                velocity: [1.850804312, 7.900986048, -0.962613657],
+            },
+            ReferenceState::Prediction {
+                time: 1844335.0,
+                position: [4831.03777566, -7899.26116450, -993.48392108],
+                velocity: [1.337297849, 8.906916366, -0.866084974],
+            },
+            ReferenceState::Prediction {
+                time: 1844340.0,
+                position: [5091.55546380, -5030.01134361, -1222.14210549],
+                velocity: [0.252792005, 10.276493768, -0.621814132],
+            },
+        ],
+    },
+    // Near-zero eccentricity
+    ReferenceCase {
+        line1: "1 33335U 05008A   06176.46683397 -.00000205  00000-0  10000-3 0  2193",
+        line2: "2 33335   0.0019 286.9433 0000004  13.7918  55.6504  1.00270176  4897",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [42081.34386081, -2649.18487875, 0.81820315],
+                velocity: [0.193184518, 3.068627007, 0.000438443],
+            },
+            ReferenceState::Prediction {
+                time: 20.0,
+                position: [42151.86113568, 1038.56497521, 1.31183638],
+                velocity: [-0.075730915, 3.073768897, 0.000428300],
+            },
+            ReferenceState::Prediction {
+                time: 40.0,
+                position: [41899.82539501, 4718.36752490, 1.78786028],
+                velocity: [-0.344066764, 3.055389800, 0.000415234],
+            },
+            ReferenceState::Prediction {
+                time: 60.0,
+                position: [41327.16536793, 8362.06432379, 2.24306194],
+                velocity: [-0.609769675, 3.013630372, 0.000399389],
+            },
+            ReferenceState::Prediction {
+                time: 80.0,
+                position: [40438.26325401, 11941.77323201, 2.67443598],
+                velocity: [-0.870806448, 2.948810178, 0.000380927],
+            },
+            ReferenceState::Prediction {
+                time: 100.0,
+                position: [39239.92118669, 15430.10177531, 3.07920420],
+                velocity: [-1.125179589, 2.861425248, 0.000360030],
+            },
+            ReferenceState::Prediction {
+                time: 120.0,
+                position: [37741.30917901, 18800.35675644, 3.45483317],
+                velocity: [-1.370942600, 2.752144282, 0.000336890],
+            },
+            ReferenceState::Prediction {
+                time: 140.0,
+                position: [35953.89494997, 22026.74851504, 3.79904972],
+                velocity: [-1.606214870, 2.621803528, 0.000311718],
+            },
+            ReferenceState::Prediction {
+                time: 160.0,
+                position: [33891.35616861, 25084.58827300, 4.10985412],
+                velocity: [-1.829196068, 2.471400388, 0.000284732],
+            },
+            ReferenceState::Prediction {
+                time: 180.0,
+                position: [31569.47578743, 27950.47705519, 4.38553102],
+                velocity: [-2.038179917, 2.302085784, 0.000256165],
+            },
+            ReferenceState::Prediction {
+                time: 200.0,
+                position: [29006.02126614, 30602.48473998, 4.62465800],
+                velocity: [-2.231567255, 2.115155347, 0.000226253],
+            },
+            ReferenceState::Prediction {
+                time: 220.0,
+                position: [26220.60860999, 33020.31786944, 4.82611168],
+                velocity: [-2.407878265, 1.912039506, 0.000195241],
+            },
+            ReferenceState::Prediction {
+                time: 240.0,
+                position: [23234.55226321, 35185.47493519, 4.98907152],
+                velocity: [-2.565763805, 1.694292541, 0.000163378],
+            },
+            ReferenceState::Prediction {
+                time: 260.0,
+                position: [20070.70200615, 37081.38795177, 5.11302111],
+                velocity: [-2.704015726, 1.463580689, 0.000130915],
+            },
+            ReferenceState::Prediction {
+                time: 280.0,
+                position: [16753.26810433, 38693.54923410, 5.19774715],
+                velocity: [-2.821576121, 1.221669394, 0.000098102],
+            },
+            ReferenceState::Prediction {
+                time: 300.0,
+                position: [13307.63604740, 40009.62240917, 5.24333607],
+                velocity: [-2.917545416, 0.970409795, 0.000065188],
+            },
+            ReferenceState::Prediction {
+                time: 320.0,
+                position: [9760.17229554, 41019.53681249, 5.25016837],
+                velocity: [-2.991189257, 0.711724566, 0.000032419],
+            },
+            ReferenceState::Prediction {
+                time: 340.0,
+                position: [6138.02252001, 41715.56454704, 5.21891075],
+                velocity: [-3.041944126, 0.447593198, 0.000000034],
+            },
+            ReferenceState::Prediction {
+                time: 360.0,
+                position: [2468.90388143, 42092.37961516, 5.15050615],
+                velocity: [-3.069421652, 0.180036856, -0.000031733],
+            },
+            ReferenceState::Prediction {
+                time: 380.0,
+                position: [-1219.10706450, 42147.09867100, 5.04616179],
+                velocity: [-3.073411586, -0.088897090, -0.000062658],
+            },
+            ReferenceState::Prediction {
+                time: 400.0,
+                position: [-4897.78921104, 41879.30308168, 4.90733536],
+                velocity: [-3.053883409, -0.357150730, -0.000092529],
+            },
+            ReferenceState::Prediction {
+                time: 420.0,
+                position: [-8538.99285015, 41291.04212849, 4.73571938],
+                velocity: [-3.010986561, -0.622671362, -0.000121143],
+            },
+            ReferenceState::Prediction {
+                time: 440.0,
+                position: [-12114.85507563, 40386.81732367, 4.53322412],
+                velocity: [-2.945049303, -0.883427197, -0.000148313],
+            },
+            ReferenceState::Prediction {
+                time: 460.0,
+                position: [-15598.01299133, 39173.54796277, 4.30195895],
+                velocity: [-2.856576200, -1.137422908, -0.000173864],
+            },
+            ReferenceState::Prediction {
+                time: 480.0,
+                position: [-18961.81309308, 37660.51817630, 4.04421255],
+                velocity: [-2.746244262, -1.382714901, -0.000197639],
+            },
+            ReferenceState::Prediction {
+                time: 500.0,
+                position: [-22180.51522214, 35859.30588575, 3.76243190],
+                velocity: [-2.614897767, -1.617426179, -0.000219495],
+            },
+            ReferenceState::Prediction {
+                time: 520.0,
+                position: [-25229.48952966, 33783.69420777, 3.45920052],
+                velocity: [-2.463541792, -1.839760715, -0.000239309],
+            },
+            ReferenceState::Prediction {
+                time: 540.0,
+                position: [-28085.40494491, 31449.56598423, 3.13721591],
+                velocity: [-2.293334531, -2.048017187, -0.000256974],
+            },
+            ReferenceState::Prediction {
+                time: 560.0,
+                position: [-30726.40770527, 28874.78224541, 2.79926643],
+                velocity: [-2.105578426, -2.240601999, -0.000272403],
+            },
+            ReferenceState::Prediction {
+                time: 580.0,
+                position: [-33132.28858188, 26079.04553622, 2.44820796],
+                velocity: [-1.901710205, -2.416041476, -0.000285526],
+            },
+            ReferenceState::Prediction {
+                time: 600.0,
+                position: [-35284.63752139, 23083.74915120, 2.08694032],
+                velocity: [-1.683289887, -2.572993142, -0.000296293],
+            },
+            ReferenceState::Prediction {
+                time: 620.0,
+                position: [-37166.98452041, 19911.81343196, 1.71838379],
+                velocity: [-1.451988843, -2.710255987, -0.000304674],
+            },
+            ReferenceState::Prediction {
+                time: 640.0,
+                position: [-38764.92565492, 16587.51037972, 1.34545586],
+                velocity: [-1.209577008, -2.826779663, -0.000310657],
+            },
+            ReferenceState::Prediction {
+                time: 660.0,
+                position: [-40066.23330012, 13136.27792487, 0.97104837],
+                velocity: [-0.957909340, -2.921672518, -0.000314248],
+            },
+            ReferenceState::Prediction {
+                time: 680.0,
+                position: [-41060.94969727, 9584.52527475, 0.59800531],
+                velocity: [-0.698911620, -2.994208421, -0.000315473],
+            },
+            ReferenceState::Prediction {
+                time: 700.0,
+                position: [-41741.46315175, 5959.43082914, 0.22910131],
+                velocity: [-0.434565724, -3.043832317, -0.000314374],
+            },
+            ReferenceState::Prediction {
+                time: 720.0,
+                position: [-42102.56627900, 2288.73420969, -0.13297887],
+                velocity: [-0.166894449, -3.070164473, -0.000311012],
+            },
+            ReferenceState::Prediction {
+                time: 740.0,
+                position: [-42141.49585277, -1399.47600560, -0.48565983],
+                velocity: [0.102053956, -3.073003389, -0.000305463],
+            },
+            ReferenceState::Prediction {
+                time: 760.0,
+                position: [-41857.95395065, -5076.97721736, -0.82649380],
+                velocity: [0.370221472, -3.052327336, -0.000297818],
+            },
+            ReferenceState::Prediction {
+                time: 780.0,
+                position: [-41254.11023517, -8715.62876702, -1.15317748],
+                velocity: [0.635556054, -3.008294522, -0.000288183],
+            },
+            ReferenceState::Prediction {
+                time: 800.0,
+                position: [-40334.58535264, -12287.58727144, -1.46356754],
+                velocity: [0.896027331, -2.941241884, -0.000276676],
+            },
+            ReferenceState::Prediction {
+                time: 820.0,
+                position: [-39106.41557705, -15765.51968317, -1.75569446],
+                velocity: [1.149642148, -2.851682510, -0.000263431],
+            },
+            ReferenceState::Prediction {
+                time: 840.0,
+                position: [-37578.99896933, -19122.81244597, -2.02777480],
+                velocity: [1.394459813, -2.740301711, -0.000248586],
+            },
+            ReferenceState::Prediction {
+                time: 860.0,
+                position: [-35764.02346385, -22333.77514503, -2.27822161],
+                velocity: [1.628606950, -2.607951779, -0.000232295],
+            },
+            ReferenceState::Prediction {
+                time: 880.0,
+                position: [-33675.37743265, -25373.83709355, -2.50565327],
+                velocity: [1.850291834, -2.455645465, -0.000214716],
+            },
+            ReferenceState::Prediction {
+                time: 900.0,
+                position: [-31329.04341132, -28219.73535130, -2.70890026],
+                velocity: [2.057818102, -2.284548228, -0.000196015],
+            },
+            ReferenceState::Prediction {
+                time: 920.0,
+                position: [-28742.97580008, -30849.69273646, -2.88701028],
+                velocity: [2.249597731, -2.095969320, -0.000176361],
+            },
+            ReferenceState::Prediction {
+                time: 940.0,
+                position: [-25936.96347556, -33243.58446856, -3.03925139],
+                velocity: [2.424163195, -1.891351766, -0.000155931],
+            },
+            ReferenceState::Prediction {
+                time: 960.0,
+                position: [-22932.47836472, -35383.09216713, -3.16511333],
+                velocity: [2.580178691, -1.672261322, -0.000134899],
+            },
+            ReferenceState::Prediction {
+                time: 980.0,
+                position: [-19752.51113952, -37251.84402776, -3.26430697],
+                velocity: [2.716450362, -1.440374493, -0.000113442],
+            },
+            ReferenceState::Prediction {
+                time: 1000.0,
+                position: [-16421.39528965, -38835.54010283, -3.33676204],
+                velocity: [2.831935435, -1.197465704, -0.000091737],
+            },
+            ReferenceState::Prediction {
+                time: 1020.0,
+                position: [-12964.62091949, -40122.06172807, -3.38262296],
+                velocity: [2.925750195, -0.945393726, -0.000069958],
+            },
+            ReferenceState::Prediction {
+                time: 1040.0,
+                position: [-9408.63969423, -41101.56425770, -3.40224311],
+                velocity: [2.997176756, -0.686087446, -0.000048275],
+            },
+            ReferenceState::Prediction {
+                time: 1060.0,
+                position: [-5780.66242756, -41766.55239827, -3.39617741],
+                velocity: [3.045668546, -0.421531112, -0.000026852],
+            },
+            ReferenceState::Prediction {
+                time: 1080.0,
+                position: [-2108.45086007, -42111.93756495, -3.36517338],
+                velocity: [3.070854497, -0.153749147, -0.000005850],
+            },
+            ReferenceState::Prediction {
+                time: 1100.0,
+                position: [1579.89477842, -42135.07682093, -3.31016077],
+                velocity: [3.072541880, 0.115209344, 0.000014580],
+            },
+            ReferenceState::Prediction {
+                time: 1120.0,
+                position: [5256.15079690, -41835.79310235, -3.23223988],
+                velocity: [3.050717780, 0.383286250, 0.000034295],
+            },
+            ReferenceState::Prediction {
+                time: 1140.0,
+                position: [8892.18601615, -41216.37657357, -3.13266867],
+                velocity: [3.005549198, 0.648430207, 0.000053160],
+            },
+            ReferenceState::Prediction {
+                time: 1160.0,
+                position: [12460.17703381, -40281.56710259, -3.01284877],
+                velocity: [2.937381771, 0.908612294, 0.000071053],
+            },
+            ReferenceState::Prediction {
+                time: 1180.0,
+                position: [15932.82113445, -39038.51799074, -2.87431064],
+                velocity: [2.846737129, 1.161841559, 0.000087861],
+            },
+            ReferenceState::Prediction {
+                time: 1200.0,
+                position: [19283.54521514, -37496.74123395, -2.71869780],
+                velocity: [2.734308898, 1.406180256, 0.000103483],
+            },
+            ReferenceState::Prediction {
+                time: 1220.0,
+                position: [22486.70912785, -35668.03473474, -2.54775052],
+                velocity: [2.600957401, 1.639758669, 0.000117831],
+            },
+            ReferenceState::Prediction {
+                time: 1240.0,
+                position: [25517.80188259, -33566.39202175, -2.36328894],
+                velocity: [2.447703066, 1.860789425, 0.000130830],
+            },
+            ReferenceState::Prediction {
+                time: 1260.0,
+                position: [28353.62920984, -31207.89516780, -2.16719590],
+                velocity: [2.275718622, 2.067581166, 0.000142418],
+            },
+            ReferenceState::Prediction {
+                time: 1280.0,
+                position: [30972.49104691, -28610.59172582, -1.96139952],
+                velocity: [2.086320125, 2.258551497, 0.000152546],
+            },
+            ReferenceState::Prediction {
+                time: 1300.0,
+                position: [33354.34759018, -25794.35662449, -1.74785572],
+                velocity: [1.880956885, 2.432239090, 0.000161178],
+            },
+            ReferenceState::Prediction {
+                time: 1320.0,
+                position: [35480.97264231, -22780.74008043, -1.52853093],
+                velocity: [1.661200376, 2.587314870, 0.000168293],
+            },
+            ReferenceState::Prediction {
+                time: 1340.0,
+                position: [37336.09308113, -19592.80269080, -1.30538495],
+                velocity: [1.428732211, 2.722592184, 0.000173883],
+            },
+            ReferenceState::Prediction {
+                time: 1360.0,
+                position: [38905.51338297, -16254.93896825, -1.08035428],
+                velocity: [1.185331274, 2.837035880, 0.000177953],
+            },
+            ReferenceState::Prediction {
+                time: 1380.0,
+                position: [40177.22424735, -12792.69066866, -0.85533591],
+                velocity: [0.932860106, 2.929770230, 0.000180519],
+            },
+            ReferenceState::Prediction {
+                time: 1400.0,
+                position: [41141.49449212, -9232.55134025, -0.63217187],
+                velocity: [0.673250654, 3.000085631, 0.000181613],
+            },
+            ReferenceState::Prediction {
+                time: 1420.0,
+                position: [41790.94551564, -5601.76358958, -0.41263443],
+                velocity: [0.408489486, 3.047444033, 0.000181274],
+            },
+            ReferenceState::Prediction {
+                time: 1440.0,
+                position: [42120.60775638, -1928.11061608, -0.19841236],
+                velocity: [0.140602589, 3.071483058, 0.000179558],
+            },
+        ],
+    },
+    // Propagation should fail 25 minutes after epoch.
+    ReferenceCase {
+        line1: "1 33333U 05037B   05333.02012661  .25992681  00000-0  24476-3 0  1532",
+        line2: "2 33333  96.4736 157.9986 9950000 244.0492 110.6523  4.00004038 10700",
+        states: &[
+            ReferenceState::Prediction {
+                time: 0.0,
+                position: [-12908.67135870, 8084.56464378, 22887.74960008],
+                velocity: [-0.076981979, 0.252652062, 1.837356358],
+            },
+            ReferenceState::Prediction {
+                time: 5.0,
+                position: [836.36198558, 3131.21861830, 27739.12500595],
+                velocity: [0.806969092, -0.303613357, 1.495581060],
+            },
+            ReferenceState::Prediction {
+                time: 10.0,
+                position: [12529.16240012, -7305.76672566, 24606.25882463],
+                velocity: [1.077046921, -0.832176467, 0.734844393],
+            },
+            ReferenceState::Prediction {
+                time: 15.0,
+                position: [17680.27781737, -19040.50274272, 13889.53302171],
+                velocity: [0.838850492, -1.010897050, 0.019845764],
+            },
+            ReferenceState::Prediction {
+                time: 20.0,
+                position: [23876.96955477, -37275.65263893, -8113.95104473],
+                velocity: [0.589108130, -0.767768418, -0.260379679],
+            },
+            ReferenceState::NegativeSemiLatusRectum { time: 25.0 },
+        ],
+    },
+    // Propagation should fail at epoch.
+    ReferenceCase {
+        line1: "1 33334U 78066F   06174.85818871  .00000620  00000-0  10000-3 0  6806",
+        line2: "2 33334  68.4714 236.1303 5602877 123.7484 302.5767  0.00001000 67521",
+        states: &[ReferenceState::OutOfRangePerturbedEccentricity { time: 0.0 }],
+    },
+];
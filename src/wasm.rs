@@ -0,0 +1,131 @@
+use crate::propagator;
+use crate::tle;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsError;
+
+/// A satellite propagator exported to JavaScript
+///
+/// The propagator keeps the parsed elements and the SGP4 epoch constants (WGS84, IAU sidereal time).
+/// Positions and velocities are returned as arrays of three numbers,
+/// in km and km.s⁻¹ in the True Equator, Mean Equinox (TEME) reference frame.
+///
+/// ```js
+/// import { Propagator } from "sgp4";
+///
+/// const propagator = Propagator.fromTle(
+///     "ISS (ZARYA)",
+///     "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992",
+///     "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008",
+/// );
+/// const [x, y, z] = propagator.position(new Date());
+/// ```
+#[wasm_bindgen(js_name = Propagator)]
+pub struct WasmPropagator {
+    elements: tle::Elements,
+    constants: propagator::Constants,
+}
+
+impl WasmPropagator {
+    fn new(elements: tle::Elements) -> Result<WasmPropagator, JsError> {
+        let constants = propagator::Constants::from_elements(&elements)?;
+        Ok(WasmPropagator {
+            elements,
+            constants,
+        })
+    }
+
+    // SGP4 prediction at a JavaScript date
+    fn prediction(&self, date: &js_sys::Date) -> Result<propagator::Prediction, JsError> {
+        let datetime = chrono::DateTime::from_timestamp_millis(date.get_time() as i64)
+            .ok_or_else(|| JsError::new("the date is out of range"))?
+            .naive_utc();
+        Ok(self
+            .constants
+            .propagate(self.elements.datetime_to_minutes_since_epoch(&datetime)?)?)
+    }
+}
+
+#[wasm_bindgen(js_class = Propagator)]
+impl WasmPropagator {
+    /// Parses a TLE and initializes a propagator
+    ///
+    /// # Arguments
+    ///
+    /// * `object_name` - The name of the satellite, usually given by a third line placed before the TLE
+    /// * `line1` - The first line of the TLE
+    /// * `line2` - The second line of the TLE
+    #[wasm_bindgen(js_name = fromTle)]
+    pub fn from_tle(
+        object_name: Option<String>,
+        line1: &str,
+        line2: &str,
+    ) -> Result<WasmPropagator, JsError> {
+        WasmPropagator::new(tle::Elements::from_tle(
+            object_name,
+            line1.trim().as_bytes(),
+            line2.trim().as_bytes(),
+        )?)
+    }
+
+    /// Parses a JSON-encoded OMM object and initializes a propagator
+    ///
+    /// # Arguments
+    ///
+    /// * `omm` - The JSON object as a string, for example an element of Celestrak's JSON arrays
+    #[wasm_bindgen(js_name = fromOmm)]
+    pub fn from_omm(omm: &str) -> Result<WasmPropagator, JsError> {
+        WasmPropagator::new(serde_json::from_str(omm)?)
+    }
+
+    /// Returns the name of the satellite, if any
+    #[wasm_bindgen(getter, js_name = objectName)]
+    pub fn object_name(&self) -> Option<String> {
+        self.elements.object_name.clone()
+    }
+
+    /// Returns the satellite catalog number
+    #[wasm_bindgen(getter, js_name = noradId)]
+    pub fn norad_id(&self) -> f64 {
+        self.elements.norad_id.get() as f64
+    }
+
+    /// Returns the elements' epoch as a date
+    #[wasm_bindgen(getter)]
+    pub fn epoch(&self) -> js_sys::Date {
+        js_sys::Date::new(&(self.elements.datetime.and_utc().timestamp_millis() as f64).into())
+    }
+
+    /// Returns the position [x, y, z] in km at the given date
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The propagation time
+    pub fn position(&self, date: &js_sys::Date) -> Result<Vec<f64>, JsError> {
+        Ok(self.prediction(date)?.position.to_vec())
+    }
+
+    /// Returns the velocity [ẋ, ẏ, ż] in km.s⁻¹ at the given date
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The propagation time
+    pub fn velocity(&self, date: &js_sys::Date) -> Result<Vec<f64>, JsError> {
+        Ok(self.prediction(date)?.velocity.to_vec())
+    }
+
+    /// Returns the position and velocity [x, y, z, ẋ, ẏ, ż] at the given number of minutes since epoch
+    ///
+    /// # Arguments
+    ///
+    /// * `minutes` - The propagation time in minutes since epoch (negative values propagate back in time)
+    #[wasm_bindgen(js_name = propagateMinutes)]
+    pub fn propagate_minutes(&self, minutes: f64) -> Result<Vec<f64>, JsError> {
+        let prediction = self.constants.propagate(tle::MinutesSinceEpoch(minutes))?;
+        Ok(prediction
+            .position
+            .iter()
+            .chain(prediction.velocity.iter())
+            .copied()
+            .collect())
+    }
+}
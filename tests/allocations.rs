@@ -0,0 +1,103 @@
+#[path = "test_cases.rs"]
+mod test_cases;
+use test_cases::*;
+
+// counts the allocations of the threads that enabled counting
+struct CountingAllocator;
+
+static ALLOCATIONS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+thread_local! {
+    static COUNTING: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        if COUNTING.with(std::cell::Cell::get) {
+            ALLOCATIONS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+        std::alloc::System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: std::alloc::Layout) {
+        std::alloc::System.dealloc(pointer, layout)
+    }
+
+    unsafe fn realloc(
+        &self,
+        pointer: *mut u8,
+        layout: std::alloc::Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        if COUNTING.with(std::cell::Cell::get) {
+            ALLOCATIONS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+        std::alloc::System.realloc(pointer, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// returns the number of allocations made by the closure on the current thread
+fn allocations<T>(closure: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.load(std::sync::atomic::Ordering::SeqCst);
+    COUNTING.with(|counting| counting.set(true));
+    let result = std::hint::black_box(closure());
+    COUNTING.with(|counting| counting.set(false));
+    (
+        result,
+        ALLOCATIONS.load(std::sync::atomic::Ordering::SeqCst) - before,
+    )
+}
+
+#[test]
+fn allocations_free() -> anyhow::Result<()> {
+    let test_cases: TestCases = toml::from_str(include_str!("test_cases.toml")).unwrap();
+    for test_case in test_cases.list.iter() {
+        #[cfg(feature = "alloc")]
+        let element =
+            sgp4::Elements::from_tle(None, test_case.line1.as_bytes(), test_case.line2.as_bytes())
+                .map_err(|error| anyhow::anyhow!("{error}"))?;
+
+        #[cfg(not(feature = "alloc"))]
+        let element =
+            sgp4::Elements::from_tle(test_case.line1.as_bytes(), test_case.line2.as_bytes())
+                .map_err(|error| anyhow::anyhow!("{error}"))?;
+
+        for afspc_compatibility_mode in [false, true] {
+            let (constants, count) = allocations(|| {
+                if afspc_compatibility_mode {
+                    sgp4::Constants::from_elements_afspc_compatibility_mode(&element)
+                } else {
+                    sgp4::Constants::from_elements(&element)
+                }
+            });
+            assert_eq!(count, 0, "{} initialization allocated", test_case.line1);
+            let constants = constants.map_err(|error| anyhow::anyhow!("{error}"))?;
+            let (_, count) = allocations(|| {
+                let mut state = constants.initial_state();
+                for state_case in &test_case.states {
+                    let t = sgp4::MinutesSinceEpoch(match state_case {
+                        State::Ok { time, .. } => *time,
+                        State::Err { time, .. } => *time,
+                    });
+                    let _ = std::hint::black_box(if afspc_compatibility_mode {
+                        constants.propagate_afspc_compatibility_mode(t)
+                    } else {
+                        constants.propagate(t)
+                    });
+                    if t.0 >= 0.0 {
+                        let _ = std::hint::black_box(constants.propagate_from_state(
+                            t,
+                            state.as_mut(),
+                            afspc_compatibility_mode,
+                        ));
+                    }
+                }
+            });
+            assert_eq!(count, 0, "{} propagation allocated", test_case.line1);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+fn radius(constants: &sgp4::Constants, t: f64) -> anyhow::Result<f64> {
+    let position = constants.propagate(sgp4::MinutesSinceEpoch(t))?.position;
+    Ok((position[0].powi(2) + position[1].powi(2) + position[2].powi(2)).sqrt())
+}
+
+#[test]
+fn apsis_passages_molniya() -> anyhow::Result<()> {
+    let elements = sgp4::Elements::from_tle(
+        Some("MOLNIYA 1-36".to_owned()),
+        "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+        "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    )?;
+    let constants = sgp4::Constants::from_elements(&elements)?;
+    let passages = constants.apsis_passages(
+        sgp4::MinutesSinceEpoch(0.0),
+        sgp4::MinutesSinceEpoch(7.0 * 24.0 * 60.0),
+    )?;
+
+    // two revolutions per day, starting with an apogee at 318.89 min
+    assert_eq!(passages.len(), 28);
+    assert_eq!(passages[0].apsis, sgp4::Apsis::Apogee);
+    assert!((passages[0].t.0 - 318.89).abs() < 0.01);
+
+    // perigees and apogees alternate, half a period apart
+    for pair in passages.windows(2) {
+        assert_ne!(pair[0].apsis, pair[1].apsis);
+        assert!((pair[1].t.0 - pair[0].t.0 - constants.period() / 2.0).abs() < 0.1);
+    }
+
+    let semi_major_axis = elements.semi_major_axis(&sgp4::WGS84);
+    for passage in passages.iter() {
+        // the radius at the passage is the propagated radius
+        assert!((passage.radius - radius(&constants, passage.t.0)?).abs() < 1e-9);
+
+        // the passage is a local extremum of the radius
+        for offset in [-1.0, -1e-2, 1e-2, 1.0] {
+            let neighbor = radius(&constants, passage.t.0 + offset)?;
+            match passage.apsis {
+                sgp4::Apsis::Perigee => assert!(neighbor > passage.radius),
+                sgp4::Apsis::Apogee => assert!(neighbor < passage.radius),
+            }
+        }
+
+        // the radius is a (1 ∓ e) up to short-period perturbations
+        let expected = match passage.apsis {
+            sgp4::Apsis::Perigee => semi_major_axis * (1.0 - elements.eccentricity),
+            sgp4::Apsis::Apogee => semi_major_axis * (1.0 + elements.eccentricity),
+        };
+        assert!((passage.radius - expected).abs() < 50.0);
+    }
+    Ok(())
+}
+
+#[test]
+fn apsis_passages_near_circular() -> anyhow::Result<()> {
+    // short-period perturbations dominate the radius of the near-circular ISS orbit,
+    // the passages still alternate and are local extrema
+    let constants = sgp4::Constants::from_elements(&iss()?)?;
+    let passages = constants.apsis_passages(
+        sgp4::MinutesSinceEpoch(0.0),
+        sgp4::MinutesSinceEpoch(24.0 * 60.0),
+    )?;
+    assert!(!passages.is_empty());
+    for pair in passages.windows(2) {
+        assert_ne!(pair[0].apsis, pair[1].apsis);
+        match pair[0].apsis {
+            sgp4::Apsis::Perigee => assert!(pair[0].radius < pair[1].radius),
+            sgp4::Apsis::Apogee => assert!(pair[0].radius > pair[1].radius),
+        }
+    }
+    Ok(())
+}
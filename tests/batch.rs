@@ -0,0 +1,65 @@
+#[path = "test_cases.rs"]
+mod test_cases;
+use test_cases::*;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn batch() -> anyhow::Result<()> {
+    let test_cases: TestCases = toml::from_str(include_str!("test_cases.toml")).unwrap();
+
+    // repeats the test cases so that the batch is split across threads
+    let mut elements = Vec::new();
+    for _ in 0..32 {
+        for test_case in test_cases.list.iter() {
+            elements.push(
+                sgp4::Elements::from_tle(
+                    None,
+                    test_case.line1.as_bytes(),
+                    test_case.line2.as_bytes(),
+                )
+                .map_err(|error| anyhow::anyhow!("{error}"))?,
+            );
+        }
+    }
+    for afspc_compatibility_mode in [false, true] {
+        let batch = if afspc_compatibility_mode {
+            sgp4::Constants::from_elements_afspc_compatibility_mode_batch(&elements)
+        } else {
+            sgp4::Constants::from_elements_batch(&elements)
+        };
+        assert_eq!(batch.len(), elements.len());
+        for (index, (element, constants)) in elements.iter().zip(batch.iter()).enumerate() {
+            let expected = if afspc_compatibility_mode {
+                sgp4::Constants::from_elements_afspc_compatibility_mode(element)
+            } else {
+                sgp4::Constants::from_elements(element)
+            };
+            match (constants, expected) {
+                (Ok(constants), Ok(expected)) => {
+                    let test_case = &test_cases.list[index % test_cases.list.len()];
+                    for state in &test_case.states {
+                        if let State::Ok { time, .. } = state {
+                            let t = sgp4::MinutesSinceEpoch(*time);
+                            assert_eq!(
+                                constants
+                                    .propagate(t)
+                                    .ok()
+                                    .map(|prediction| prediction.position),
+                                expected
+                                    .propagate(t)
+                                    .ok()
+                                    .map(|prediction| prediction.position),
+                            );
+                        }
+                    }
+                }
+                (Err(_), Err(_)) => {}
+                _ => panic!(
+                    "{} batch and single initializations differ",
+                    element.norad_id
+                ),
+            }
+        }
+    }
+    Ok(())
+}
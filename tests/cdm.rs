@@ -0,0 +1,270 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+fn assert_close(first: f64, second: f64, tolerance: f64) {
+    assert!(
+        (first - second).abs() <= tolerance,
+        "{first} and {second} differ by more than {tolerance}"
+    );
+}
+
+#[test]
+fn cdm_round_trip() -> anyhow::Result<()> {
+    let tle = (
+        "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+        "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    );
+    let elements = sgp4::Elements::from_tle(Some("ISS (ZARYA)".to_owned()), tle.0, tle.1)?;
+    let iss = sgp4::Constants::from_elements(&elements)?;
+    let mut other_elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+    other_elements.right_ascension += 10.0;
+    let other = sgp4::Constants::from_elements(&other_elements)?;
+    let conjunction = sgp4::closest_approach(
+        &iss,
+        &other,
+        elements.epoch(),
+        elements.epoch() + 60.0 / (365.25 * 24.0 * 60.0),
+        1e-6,
+    )?;
+    let mut other_object = sgp4::CdmObject::new(&other_elements, &other, conjunction.tca)?;
+    other_object.covariance = Some(core::array::from_fn(|index| (index + 1) as f64 * 1e-3));
+    let cdm = sgp4::Cdm::new(
+        elements.datetime,
+        "EXAMPLE",
+        "EXAMPLE-1",
+        conjunction.tca,
+        [
+            sgp4::CdmObject::new(&elements, &iss, conjunction.tca)?,
+            other_object,
+        ],
+    );
+    let kvn = cdm.to_kvn();
+    assert!(!kvn.contains("TEME"));
+    assert_eq!(kvn.matches("EME2000").count(), 2);
+    assert_eq!(kvn.matches("= DEFAULT").count(), 1);
+    assert_eq!(kvn.matches("= CALCULATED").count(), 1);
+
+    let parsed = sgp4::Cdm::from_kvn(&kvn)?;
+
+    // datetimes are written with a microsecond resolution
+    assert!(
+        (parsed.creation_date - cdm.creation_date)
+            .num_nanoseconds()
+            .unwrap()
+            .abs()
+            < 1000
+    );
+    assert!((parsed.tca - cdm.tca).num_nanoseconds().unwrap().abs() < 1000);
+    assert_eq!(parsed.originator, cdm.originator);
+    assert_eq!(parsed.message_id, cdm.message_id);
+    assert_close(parsed.miss_distance, cdm.miss_distance, 1e-12);
+    assert_close(parsed.relative_speed, cdm.relative_speed, 1e-12);
+    for index in 0..3 {
+        assert_close(
+            parsed.relative_position[index],
+            cdm.relative_position[index],
+            1e-12,
+        );
+        assert_close(
+            parsed.relative_velocity[index],
+            cdm.relative_velocity[index],
+            1e-12,
+        );
+    }
+    for (parsed_object, object) in parsed.objects.iter().zip(cdm.objects.iter()) {
+        assert_eq!(parsed_object.norad_id, object.norad_id);
+        assert_eq!(parsed_object.object_name, object.object_name);
+        assert_eq!(
+            parsed_object.international_designator,
+            object.international_designator
+        );
+        assert_eq!(parsed_object.reference_frame, object.reference_frame);
+        assert_eq!(parsed_object.position, object.position);
+        assert_eq!(parsed_object.velocity, object.velocity);
+        match (parsed_object.covariance, object.covariance) {
+            (Some(parsed_covariance), Some(covariance)) => {
+                for (parsed_term, term) in parsed_covariance.iter().zip(covariance.iter()) {
+                    assert_close(*parsed_term, *term, 1e-15);
+                }
+            }
+            (parsed_covariance, covariance) => assert_eq!(parsed_covariance, covariance),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn cdm_teme_object() -> anyhow::Result<()> {
+    let elements = iss()?;
+    let constants = sgp4::Constants::from_elements(&elements)?;
+    let tca = elements.epoch() + 10.0 / (365.25 * 24.0 * 60.0);
+    let object = sgp4::CdmObject::new(&elements, &constants, tca)?;
+    let prediction = constants.propagate(sgp4::MinutesSinceEpoch(10.0))?;
+
+    // objects set to TEME are rotated to EME2000 when the message is written
+    let mut teme_object = object.clone();
+    teme_object.reference_frame = "TEME".to_owned();
+    teme_object.position = prediction.position;
+    teme_object.velocity = prediction.velocity;
+    let parsed = sgp4::Cdm::from_kvn(
+        &sgp4::Cdm::new(
+            elements.datetime,
+            "EXAMPLE",
+            "EXAMPLE-1",
+            tca,
+            [object, teme_object],
+        )
+        .to_kvn(),
+    )?;
+    assert_eq!(parsed.objects[1].reference_frame, "EME2000");
+    for index in 0..3 {
+        assert_close(
+            parsed.objects[1].position[index],
+            parsed.objects[0].position[index],
+            1e-6,
+        );
+        assert_close(
+            parsed.objects[1].velocity[index],
+            parsed.objects[0].velocity[index],
+            1e-9,
+        );
+    }
+
+    // precession since J2000 rotates the TEME position by about 0.3°
+    let shift = (0..3)
+        .map(|index| (parsed.objects[0].position[index] - prediction.position[index]).powi(2))
+        .sum::<f64>()
+        .sqrt();
+    assert!(shift > 10.0);
+    Ok(())
+}
+
+const KVN: &str = "CCSDS_CDM_VERS                       = 1.0
+COMMENT a hand-written message
+CREATION_DATE                        = 2020-07-12T21:16:01.000000
+ORIGINATOR                           = EXAMPLE
+MESSAGE_ID                           = EXAMPLE-2
+TCA                                  = 2020-195T06:30:00.500000
+MISS_DISTANCE                        = 1250.5                   [m]
+RELATIVE_POSITION_R                  = 30                       [m]
+RELATIVE_POSITION_T                  = -400                     [m]
+RELATIVE_POSITION_N                  = 1184.5                   [m]
+
+OBJECT                               = OBJECT1
+OBJECT_DESIGNATOR                    = 25544
+OBJECT_NAME                          = ISS (ZARYA)
+INTERNATIONAL_DESIGNATOR             = 1998-067A
+COVARIANCE_METHOD                    = CALCULATED
+REF_FRAME                            = EME2000
+X                                    = 6000                     [km]
+Y                                    = 1000                     [km]
+Z                                    = 2000                     [km]
+X_DOT                                = 1                        [km/s]
+Y_DOT                                = 7                        [km/s]
+Z_DOT                                = 2                        [km/s]
+CR_R                                 = 250000                   [m**2]
+CT_T                                 = 4000000                  [m**2]
+CNDOT_NDOT                           = 0.25                     [m**2/s**2]
+OBJECT                               = OBJECT2
+OBJECT_DESIGNATOR                    = E8493
+OBJECT_NAME                          = UNKNOWN
+REF_FRAME                            = GCRF
+X                                    = 6000.1                   [km]
+Y                                    = 999.6                    [km]
+Z                                    = 2001.2                   [km]
+X_DOT                                = -1                       [km/s]
+Y_DOT                                = 7                        [km/s]
+Z_DOT                                = -2                       [km/s]
+";
+
+#[test]
+fn cdm_from_kvn() -> anyhow::Result<()> {
+    let cdm = sgp4::Cdm::from_kvn(KVN)?;
+    assert_eq!(cdm.originator, "EXAMPLE");
+    assert_eq!(cdm.message_id, "EXAMPLE-2");
+
+    // day 195 of 2020 is 13 July
+    assert_eq!(
+        cdm.tca,
+        sgp4::chrono::NaiveDate::from_ymd_opt(2020, 7, 13)
+            .and_then(|date| date.and_hms_micro_opt(6, 30, 0, 500000))
+            .unwrap()
+    );
+
+    // m → km
+    assert_close(cdm.miss_distance, 1.2505, 1e-12);
+    assert_close(cdm.relative_position[0], 0.03, 1e-12);
+    assert_close(cdm.relative_position[1], -0.4, 1e-12);
+    assert_close(cdm.relative_position[2], 1.1845, 1e-12);
+
+    // the relative speed is missing and calculated from the states
+    assert_close(cdm.relative_speed, 20.0f64.sqrt(), 1e-12);
+
+    // the keywords after OBJECT = OBJECT2 belong to the second object
+    let [first, second] = &cdm.objects;
+    assert_eq!(first.norad_id, 25544);
+    assert_eq!(first.object_name.as_deref(), Some("ISS (ZARYA)"));
+    assert_eq!(first.international_designator.as_deref(), Some("1998-067A"));
+    assert_eq!(first.reference_frame, "EME2000");
+    assert_eq!(first.position, [6000.0, 1000.0, 2000.0]);
+    assert_eq!(first.velocity, [1.0, 7.0, 2.0]);
+    assert_eq!(second.norad_id, 148493);
+    assert_eq!(second.object_name, None);
+    assert_eq!(second.international_designator, None);
+    assert_eq!(second.reference_frame, "GCRF");
+    assert_eq!(second.position, [6000.1, 999.6, 2001.2]);
+    assert_eq!(second.velocity, [-1.0, 7.0, -2.0]);
+
+    // m² → km², m².s⁻² → km².s⁻²
+    let covariance = first.covariance.unwrap();
+    assert_close(covariance[0], 0.25, 1e-15);
+    assert_close(covariance[1], 0.0, 1e-15);
+    assert_close(covariance[2], 4.0, 1e-15);
+    assert_close(covariance[20], 2.5e-7, 1e-21);
+    assert!(second.covariance.is_none());
+
+    // a written and re-read message matches the original
+    let parsed = sgp4::Cdm::from_kvn(&cdm.to_kvn())?;
+    assert_eq!(parsed.tca, cdm.tca);
+    assert_close(parsed.miss_distance, cdm.miss_distance, 1e-12);
+    assert_close(parsed.relative_speed, cdm.relative_speed, 1e-12);
+    assert_eq!(parsed.objects[1].norad_id, cdm.objects[1].norad_id);
+    assert_eq!(parsed.objects[0].covariance, cdm.objects[0].covariance);
+    Ok(())
+}
+
+#[test]
+fn cdm_from_kvn_errors() {
+    match sgp4::Cdm::from_kvn(&KVN.replace("MISS_DISTANCE", "COMMENT MISS_DISTANCE")) {
+        Err(sgp4::CdmError::MissingKeyword { section, keyword }) => {
+            assert_eq!(section, "HEADER");
+            assert_eq!(keyword, "MISS_DISTANCE");
+        }
+        result => panic!("unexpected result {result:?}"),
+    }
+    match sgp4::Cdm::from_kvn(&KVN.replace(
+        "Z_DOT                                = -2                       [km/s]",
+        "",
+    )) {
+        Err(sgp4::CdmError::MissingKeyword { section, keyword }) => {
+            assert_eq!(section, "OBJECT2");
+            assert_eq!(keyword, "Z_DOT");
+        }
+        result => panic!("unexpected result {result:?}"),
+    }
+    match sgp4::Cdm::from_kvn(&KVN.replace("MESSAGE_ID                           =", "MESSAGE_ID"))
+    {
+        Err(sgp4::CdmError::MalformedLine { line }) => assert_eq!(line, 5),
+        result => panic!("unexpected result {result:?}"),
+    }
+    match sgp4::Cdm::from_kvn(&KVN.replace("= 6000.1", "= 6000,1")) {
+        Err(sgp4::CdmError::BadValue { line, keyword }) => {
+            assert_eq!(line, 31);
+            assert_eq!(keyword, "X");
+        }
+        result => panic!("unexpected result {result:?}"),
+    }
+}
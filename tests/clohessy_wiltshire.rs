@@ -0,0 +1,83 @@
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+#[test]
+fn clohessy_wiltshire_sgp4() -> anyhow::Result<()> {
+    let reference_constants = sgp4::Constants::from_elements(&iss()?)?;
+    let mut elements = iss()?;
+    elements.right_ascension += 0.001;
+    elements.mean_anomaly -= 0.005;
+    let target_constants = sgp4::Constants::from_elements(&elements)?;
+    let propagator = sgp4::ClohessyWiltshire::new(
+        &reference_constants.propagate(sgp4::MinutesSinceEpoch(0.0))?,
+        &target_constants.propagate(sgp4::MinutesSinceEpoch(0.0))?,
+    );
+
+    // the linearized motion follows SGP4 within a few meters for half a revolution
+    for minutes in 0..=30 {
+        let t = minutes as f64;
+        let expected = sgp4::ric_state(
+            &reference_constants.propagate(sgp4::MinutesSinceEpoch(t))?,
+            &target_constants.propagate(sgp4::MinutesSinceEpoch(t))?,
+        );
+        let actual = propagator.propagate(t);
+        let error = (0..3)
+            .map(|index| (actual.position[index] - expected.position[index]).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        assert!(error < if minutes <= 10 { 1e-3 } else { 5e-3 });
+    }
+    Ok(())
+}
+
+#[test]
+fn clohessy_wiltshire_closed_forms() {
+    // ISS mean motion in rad.s⁻¹
+    let n = 0.0011274946866106652;
+    let period = 2.0 * core::f64::consts::PI / n / 60.0;
+    let assert_state = |state: &sgp4::RicState, position: [f64; 3], velocity: [f64; 3]| {
+        for index in 0..3 {
+            assert!((state.position[index] - position[index]).abs() < 1e-9);
+            assert!((state.velocity[index] - velocity[index]).abs() < 1e-12);
+        }
+    };
+
+    // a target 100 m above the reference with the same velocity drifts backwards by 12 π x₀ per revolution
+    let propagator = sgp4::ClohessyWiltshire {
+        mean_motion: n,
+        initial_state: sgp4::RicState {
+            position: [0.1, 0.0, 0.0],
+            velocity: [0.0, 0.0, 0.0],
+        },
+    };
+    assert_state(
+        &propagator.propagate(period),
+        [0.1, -1.2 * core::f64::consts::PI, 0.0],
+        [0.0, 0.0, 0.0],
+    );
+
+    // ẏ₀ = -2 n x₀ cancels the drift, the target follows a closed 2 × 1 ellipse
+    let propagator = sgp4::ClohessyWiltshire {
+        mean_motion: n,
+        initial_state: sgp4::RicState {
+            position: [0.1, 0.0, 0.05],
+            velocity: [0.0, -0.2 * n, 0.0],
+        },
+    };
+    assert_state(
+        &propagator.propagate(period / 2.0),
+        [-0.1, 0.0, -0.05],
+        [0.0, 0.2 * n, 0.0],
+    );
+    assert_state(
+        &propagator.propagate(period),
+        [0.1, 0.0, 0.05],
+        [0.0, -0.2 * n, 0.0],
+    );
+    assert_state(
+        &propagator.propagate(period / 4.0),
+        [0.0, -0.2, 0.0],
+        [-0.1 * n, 0.0, -0.05 * n],
+    );
+}
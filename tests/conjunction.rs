@@ -0,0 +1,165 @@
+#![cfg(feature = "alloc")]
+
+// the ISS and a virtual satellite whose orbital plane is tilted by 10° about the line of nodes,
+// hence the satellites cross near the nodes twice per orbit
+fn crossing_pair() -> anyhow::Result<(f64, sgp4::Constants, sgp4::Constants)> {
+    let tle = (
+        "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+        "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    );
+    let elements = sgp4::Elements::from_tle(Some("ISS (ZARYA)".to_owned()), tle.0, tle.1)?;
+    let mut other_elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+    other_elements.inclination += 10.0;
+    other_elements.mean_anomaly -= 0.01;
+    Ok((
+        elements.epoch(),
+        sgp4::Constants::from_elements(&elements)?,
+        sgp4::Constants::from_elements(&other_elements)?,
+    ))
+}
+
+fn distance(a: &sgp4::Constants, b: &sgp4::Constants, t: f64) -> anyhow::Result<f64> {
+    let a_prediction = a.propagate(sgp4::MinutesSinceEpoch(t))?;
+    let b_prediction = b.propagate(sgp4::MinutesSinceEpoch(t))?;
+    Ok((0..3)
+        .map(|index| (a_prediction.position[index] - b_prediction.position[index]).powi(2))
+        .sum::<f64>()
+        .sqrt())
+}
+
+// local minima of the distance found by scanning with a 0.01 min step,
+// then with a 10⁻⁵ min step around each coarse minimum
+fn brute_force_minima(
+    a: &sgp4::Constants,
+    b: &sgp4::Constants,
+    duration: f64,
+) -> anyhow::Result<Vec<(f64, f64)>> {
+    let coarse_step = 0.01;
+    let distances = (0..=(duration / coarse_step).round() as usize)
+        .map(|index| distance(a, b, index as f64 * coarse_step))
+        .collect::<anyhow::Result<Vec<f64>>>()?;
+    let mut minima = Vec::new();
+    for index in 1..distances.len() - 1 {
+        if distances[index] < distances[index - 1] && distances[index] <= distances[index + 1] {
+            let mut minimum = (index as f64 * coarse_step, distances[index]);
+            for fine_index in 0..=2000 {
+                let t = (index as f64 - 1.0) * coarse_step + fine_index as f64 * 1e-5;
+                let distance = distance(a, b, t)?;
+                if distance < minimum.1 {
+                    minimum = (t, distance);
+                }
+            }
+            minima.push(minimum);
+        }
+    }
+    Ok(minima)
+}
+
+#[test]
+fn conjunctions_match_brute_force() -> anyhow::Result<()> {
+    let (epoch, a, b) = crossing_pair()?;
+    let duration = 6.0 * 60.0;
+    let threshold = 50.0;
+    let conjunctions = sgp4::conjunctions(
+        &a,
+        &b,
+        epoch,
+        epoch + duration / (365.25 * 24.0 * 60.0),
+        threshold,
+    )?;
+    let minima: Vec<(f64, f64)> = brute_force_minima(&a, &b, duration)?
+        .into_iter()
+        .filter(|(_, distance)| *distance <= threshold)
+        .collect();
+
+    // the satellites meet every half orbit, and the differential nodal precession
+    // moves the crossings apart by about 7 km per approach, beyond the threshold after 5 approaches
+    assert_eq!(minima.len(), 5);
+
+    // the sampling at a 32nd of the period misses none of the approaches
+    assert_eq!(conjunctions.len(), minima.len());
+    for (conjunction, (t, miss_distance)) in conjunctions.iter().zip(minima.iter()) {
+        // the distance is flat near TCA (a 6 ms error changes a 40 km miss distance by less than 1 mm)
+        let tca = a.minutes_since_epoch(conjunction.tca).0;
+        assert!((tca - t).abs() < 1e-4, "TCA {tca} != {t}");
+        assert!((conjunction.miss_distance - miss_distance).abs() < 1e-6);
+        let ric_distance = conjunction
+            .ric_position
+            .iter()
+            .map(|x| x.powi(2))
+            .sum::<f64>()
+            .sqrt();
+        assert!((ric_distance - conjunction.miss_distance).abs() < 1e-9);
+
+        // the planes are 10° apart, v ≈ 2 × 7.66 km.s⁻¹ × sin(5°)
+        assert!((conjunction.relative_speed - 1.335).abs() < 0.01);
+    }
+    Ok(())
+}
+
+#[test]
+fn closest_approach_matches_brute_force() -> anyhow::Result<()> {
+    let (epoch, a, b) = crossing_pair()?;
+    let (t, miss_distance) = brute_force_minima(&a, &b, 60.0)?[0];
+    let conjunction = sgp4::closest_approach(
+        &a,
+        &b,
+        epoch + (t - 5.0) / (365.25 * 24.0 * 60.0),
+        epoch + (t + 5.0) / (365.25 * 24.0 * 60.0),
+        1e-6,
+    )?;
+    assert!((a.minutes_since_epoch(conjunction.tca).0 - t).abs() < 1e-4);
+    assert!((conjunction.miss_distance - miss_distance).abs() < 1e-6);
+
+    // first crossing, 44 min after epoch at 8.98 km
+    assert!((a.minutes_since_epoch(conjunction.tca).0 - 43.977).abs() < 1e-3);
+    assert!((conjunction.miss_distance - 8.978).abs() < 1e-3);
+    Ok(())
+}
+
+#[test]
+fn screening_volumes() {
+    let sphere = sgp4::ScreeningVolume::Sphere { radius: 2.0 };
+    let ellipsoid = sgp4::ScreeningVolume::Ellipsoid {
+        radial: 1.0,
+        in_track: 4.0,
+        cross_track: 2.0,
+    };
+    let box_volume = sgp4::ScreeningVolume::Box {
+        radial: 1.0,
+        in_track: 4.0,
+        cross_track: 2.0,
+    };
+    assert_eq!(sphere.bounding_radius(), 2.0);
+    assert_eq!(ellipsoid.bounding_radius(), 4.0);
+
+    // (1² + 4² + 2²)¹ᐟ² = 21¹ᐟ²
+    assert_eq!(box_volume.bounding_radius(), 21.0f64.sqrt());
+
+    // points on the boundary are inside
+    assert!(sphere.contains(&[0.0, -2.0, 0.0]));
+    for point in [
+        [1.0, 0.0, 0.0],
+        [0.0, -4.0, 0.0],
+        [0.0, 0.0, 2.0],
+        [-0.6, 3.2, 0.0],
+    ] {
+        assert!(ellipsoid.contains(&point));
+        assert!(box_volume.contains(&point));
+    }
+    for point in [[1.0, 4.0, 2.0], [-1.0, 4.0, -2.0], [1.0, -1.0, 0.0]] {
+        assert!(box_volume.contains(&point));
+    }
+
+    // points just outside
+    assert!(!sphere.contains(&[0.0, 2.001, 0.0]));
+    for point in [[1.001, 0.0, 0.0], [0.0, 4.001, 0.0], [0.0, 0.0, -2.001]] {
+        assert!(!ellipsoid.contains(&point));
+        assert!(!box_volume.contains(&point));
+    }
+
+    // corners of the box are outside the ellipsoid
+    assert!(!ellipsoid.contains(&[1.0, 4.0, 2.0]));
+    assert!(!ellipsoid.contains(&[0.9, 2.0, 0.0]));
+    assert!(box_volume.contains(&[0.9, 2.0, 0.0]));
+}
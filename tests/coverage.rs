@@ -0,0 +1,91 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+#[test]
+fn coverage_map_look_angles() -> anyhow::Result<()> {
+    let elements = iss()?;
+    let constants = sgp4::Constants::from_elements(&elements)?;
+    let minimum_elevation = 10.0_f64.to_radians();
+    let start = elements.epoch();
+    let end = start + 0.25 / 365.25;
+    let coverage_map = sgp4::coverage_map(
+        &[constants.clone(), constants.clone()],
+        minimum_elevation,
+        5.0_f64.to_radians(),
+        start,
+        end,
+        1.0,
+    )?;
+    assert_eq!(coverage_map.latitudes.len(), 37);
+    assert_eq!(coverage_map.longitudes.len(), 72);
+    assert!((coverage_map.latitudes[36] - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert!((coverage_map.longitudes[71] - 175.0_f64.to_radians()).abs() < 1e-12);
+    assert_eq!(coverage_map.samples, 6 * 60 + 1);
+
+    // the satellite is in view if its elevation is above the minimum elevation
+    let predictions = (0..coverage_map.samples)
+        .map(|sample| {
+            let epoch = start + sample as f64 / (365.25 * 24.0 * 60.0);
+            Ok((
+                sgp4::iau_epoch_to_sidereal_time(epoch),
+                constants.propagate(constants.minutes_since_epoch(epoch))?,
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    for (latitude_index, latitude) in coverage_map.latitudes.iter().enumerate() {
+        for (longitude_index, longitude) in coverage_map.longitudes.iter().enumerate() {
+            let observer = sgp4::Observer {
+                latitude: *latitude,
+                longitude: *longitude,
+                altitude: 0.0,
+            };
+            let covered = predictions
+                .iter()
+                .filter(|(sidereal_time, prediction)| {
+                    observer.look_angles(*sidereal_time, prediction).elevation >= minimum_elevation
+                })
+                .count();
+            let index = latitude_index * coverage_map.longitudes.len() + longitude_index;
+
+            // the constellation contains the same satellite twice
+            assert_eq!(coverage_map.covered[index], covered);
+            assert_eq!(coverage_map.in_view[index], 2 * covered);
+            assert_eq!(
+                coverage_map.mean_in_view(latitude_index, longitude_index),
+                2.0 * coverage_map.coverage(latitude_index, longitude_index)
+            );
+
+            // the footprint at 10° elevation extends about 14° beyond the 51.6° inclination
+            if latitude.abs() > 70.0_f64.to_radians() {
+                assert_eq!(covered, 0);
+            }
+        }
+    }
+
+    // over a day, the time fraction at the equator approaches the footprint's area fraction (1 - cos λ) / 2
+    // times the ground track density 2 / (π sin i), with λ = cos⁻¹(R cos εₘ / (R + h)) - εₘ
+    let coverage_map = sgp4::coverage_map(
+        &[constants],
+        minimum_elevation,
+        5.0_f64.to_radians(),
+        start,
+        start + 1.0 / 365.25,
+        1.0,
+    )?;
+    let radius = sgp4::WGS84.ae;
+    let footprint = (radius * minimum_elevation.cos() / elements.semi_major_axis(&sgp4::WGS84))
+        .acos()
+        - minimum_elevation;
+    let expected = (1.0 - footprint.cos()) / 2.0 * 2.0
+        / (core::f64::consts::PI * elements.inclination.to_radians().sin());
+    let coverages: Vec<f64> = (0..coverage_map.longitudes.len())
+        .map(|longitude_index| coverage_map.coverage(18, longitude_index))
+        .collect();
+    assert!(coverages.iter().all(|coverage| *coverage > 0.0));
+    let mean = coverages.iter().sum::<f64>() / coverages.len() as f64;
+    assert!((mean / expected - 1.0).abs() < 0.05);
+    Ok(())
+}
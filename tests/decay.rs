@@ -0,0 +1,65 @@
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+// constant density in kg.m⁻³, for which the decay integrals have closed forms
+const DENSITY: f64 = 1e-12;
+
+#[test]
+fn ballistic_coefficient() -> anyhow::Result<()> {
+    let elements = sgp4::Elements::from_tle(
+        None,
+        "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+        "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    )?;
+
+    // B = 2 × 1.1873 × 10⁻⁴ / 0.15696615
+    assert!((elements.ballistic_coefficient() - 1.51281e-3).abs() < 1e-8);
+    assert!(iss()?.ballistic_coefficient() < 0.0);
+    Ok(())
+}
+
+#[test]
+fn decay_estimate_closed_forms() -> anyhow::Result<()> {
+    let constant_density = |_altitude: f64, _solar_activity: &sgp4::SolarActivity| DENSITY;
+    let mut elements = iss()?;
+    let semi_major_axis_0 = elements.semi_major_axis(&sgp4::WGS84);
+    let semi_major_axis_1 = sgp4::WGS84.ae + 120.0;
+
+    // the ISS elements have a negative mean motion derivative and drag term
+    assert!(elements.decay_estimate().is_none());
+    assert!(elements
+        .decay_estimate_with_density(&constant_density)
+        .is_none());
+
+    // ȧ = -K a¹ᐟ², with K = B ρ μ¹ᐟ² (m.s⁻¹ converted to km.day⁻¹), hence Δt = 2 (a₀¹ᐟ² - a₁¹ᐟ²) / K
+    elements.drag_term = 1e-4;
+    let k = elements.ballistic_coefficient()
+        * DENSITY
+        * (sgp4::WGS84.ke.powi(2) * (sgp4::WGS84.ae * 1e3).powi(3) / 3600.0 * 1e3).sqrt()
+        * 86.4;
+    let lifetime = 2.0 * (semi_major_axis_0.sqrt() - semi_major_axis_1.sqrt()) / k;
+    let estimate = elements
+        .decay_estimate_with_density(&constant_density)
+        .unwrap();
+    assert!((elements.age(estimate.epoch) / lifetime - 1.0).abs() < 1e-4);
+    assert!((elements.age(estimate.earliest) - 0.75 * lifetime).abs() < 1e-3 * lifetime);
+    assert!((elements.age(estimate.latest) - 1.25 * lifetime).abs() < 1e-3 * lifetime);
+
+    // ȧ = ȧ₀ (a / a₀)¹ᐟ², with ȧ₀ = -4 a₀ ṅ / (3 n₀), hence Δt = 2 a₀¹ᐟ² (a₀¹ᐟ² - a₁¹ᐟ²) / |ȧ₀|
+    elements.mean_motion_dot = 1e-4;
+    let decay_rate_0 =
+        4.0 * semi_major_axis_0 * elements.mean_motion_dot / (3.0 * elements.mean_motion);
+    let lifetime =
+        2.0 * semi_major_axis_0.sqrt() * (semi_major_axis_0.sqrt() - semi_major_axis_1.sqrt())
+            / decay_rate_0;
+    let estimate = elements
+        .decay_estimate_with_density(&constant_density)
+        .unwrap();
+    assert!((elements.age(estimate.epoch) / lifetime - 1.0).abs() < 1e-6);
+
+    // the exponential atmosphere is thinner at the ISS altitude than near 120 km, the decay accelerates
+    let estimate = elements.decay_estimate().unwrap();
+    assert!(elements.age(estimate.epoch) < lifetime);
+    Ok(())
+}
@@ -0,0 +1,90 @@
+fn molniya() -> anyhow::Result<sgp4::Elements> {
+    Ok(sgp4::Elements::from_tle(
+        Some("MOLNIYA 1-36".to_owned()),
+        "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+        "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    )?)
+}
+
+// the analytic series with the lunar node shifted by 0.1 rad
+fn shifted_third_bodies(epoch: f64) -> sgp4::ThirdBodies {
+    let mut third_bodies = sgp4::ThirdBodies::analytic(epoch);
+    third_bodies.moon.right_ascension += 0.1;
+    third_bodies
+}
+
+fn assert_dots_close(a: &sgp4::ThirdBodyDots, b: &sgp4::ThirdBodyDots, tolerance: f64) {
+    for (a, b) in [
+        (a.inclination, b.inclination),
+        (a.right_ascension, b.right_ascension),
+        (a.eccentricity, b.eccentricity),
+        (a.argument_of_perigee, b.argument_of_perigee),
+        (a.mean_anomaly, b.mean_anomaly),
+    ] {
+        assert!(
+            (a - b).abs() <= tolerance * b.abs().max(1e-12),
+            "{a} != {b}"
+        );
+    }
+}
+
+#[test]
+fn coefficients_with_analytic_third_bodies() -> anyhow::Result<()> {
+    let elements = molniya()?;
+    let constants = sgp4::Constants::from_elements(&elements)?;
+    let coefficients = constants.deep_space_coefficients().unwrap();
+    let analytic = constants
+        .deep_space_coefficients_with_third_bodies(&sgp4::ThirdBodies::analytic(constants.epoch()))
+        .unwrap();
+
+    // the analytic orbits reproduce the series to 8 significant digits
+    assert_dots_close(&analytic.solar_dots, &coefficients.solar_dots, 1e-6);
+    assert_dots_close(&analytic.lunar_dots, &coefficients.lunar_dots, 1e-6);
+
+    let iss = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+        None,
+        "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+        "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    )?)?;
+    assert!(iss.deep_space_coefficients().is_none());
+    assert!(iss
+        .deep_space_coefficients_with_third_bodies(&sgp4::ThirdBodies::analytic(iss.epoch()))
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn coefficients_with_external_third_bodies() -> anyhow::Result<()> {
+    let elements = molniya()?;
+    let orbit_0 = sgp4::Orbit::from_kozai_elements(
+        &sgp4::WGS84,
+        sgp4::Degrees(elements.inclination),
+        sgp4::Degrees(elements.right_ascension),
+        elements.eccentricity,
+        sgp4::Degrees(elements.argument_of_perigee),
+        sgp4::Degrees(elements.mean_anomaly),
+        elements.mean_motion * (core::f64::consts::PI / 720.0),
+    )?;
+    let constants =
+        sgp4::Constants::builder_from_orbit(elements.epoch(), elements.drag_term, orbit_0)
+            .with_third_bodies(&shifted_third_bodies)
+            .build()?;
+    let analytic = constants.deep_space_coefficients().unwrap();
+    let shifted = constants
+        .deep_space_coefficients_with_third_bodies(&shifted_third_bodies(constants.epoch()))
+        .unwrap();
+
+    // only the lunar rates depend on the lunar node
+    assert_dots_close(&shifted.solar_dots, &analytic.solar_dots, 1e-6);
+    assert!(
+        (shifted.lunar_dots.right_ascension - analytic.lunar_dots.right_ascension).abs()
+            > 1e-3 * analytic.lunar_dots.right_ascension.abs()
+    );
+
+    // the periodic coefficients are the ones stored in the propagator
+    assert_eq!(
+        shifted.lunar_coefficients.k(),
+        analytic.lunar_coefficients.k()
+    );
+    Ok(())
+}
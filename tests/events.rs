@@ -0,0 +1,16 @@
+#[cfg(feature = "alloc")]
+#[test]
+fn find_crossings_terminates_below_resolution() {
+    // the step and the tolerance are smaller than the spacing of f64 values around 10⁹
+    let crossings = sgp4::find_crossings(
+        sgp4::MinutesSinceEpoch(1e9),
+        sgp4::MinutesSinceEpoch(1e9 + 1e-6),
+        1e-9,
+        1e-12,
+        |t| Ok::<f64, core::convert::Infallible>(t.0 - (1e9 + 5e-7)),
+    )
+    .unwrap();
+    assert_eq!(crossings.len(), 1);
+    assert_eq!(crossings[0].direction, sgp4::Direction::Increasing);
+    assert!((crossings[0].t.0 - (1e9 + 5e-7)).abs() < 1e-6);
+}
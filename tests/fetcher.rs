@@ -0,0 +1,211 @@
+#![cfg(all(feature = "std", feature = "serde"))]
+
+use std::future::Future;
+
+const ISS: &[u8] = br#"[{
+    "OBJECT_NAME": "ISS (ZARYA)",
+    "OBJECT_ID": "1998-067A",
+    "EPOCH": "2020-07-12T01:19:07.402656",
+    "MEAN_MOTION": 15.49560532,
+    "ECCENTRICITY": 0.0001771,
+    "INCLINATION": 51.6435,
+    "RA_OF_ASC_NODE": 225.4004,
+    "ARG_OF_PERICENTER": 44.9625,
+    "MEAN_ANOMALY": 5.1087,
+    "EPHEMERIS_TYPE": 0,
+    "CLASSIFICATION_TYPE": "U",
+    "NORAD_CAT_ID": 25544,
+    "ELEMENT_SET_NO": 999,
+    "REV_AT_EPOCH": 23587,
+    "BSTAR": 0.0049645,
+    "MEAN_MOTION_DOT": 0.00289036,
+    "MEAN_MOTION_DDOT": 0
+}]"#;
+
+// URL and headers of a request
+type Request = (String, Vec<(String, String)>);
+
+// transport that replays queued responses and records the requests
+#[derive(Default)]
+struct Transport {
+    responses: std::sync::Mutex<std::collections::VecDeque<sgp4::HttpResponse>>,
+    requests: std::sync::Mutex<Vec<Request>>,
+}
+
+impl Transport {
+    fn respond(&self, status: u16, headers: &[(&str, &str)], body: &[u8]) {
+        self.responses
+            .lock()
+            .unwrap()
+            .push_back(sgp4::HttpResponse {
+                status,
+                headers: headers
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+                body: body.to_vec(),
+            });
+    }
+
+    // removes and returns the recorded requests
+    fn requests(&self) -> Vec<Request> {
+        std::mem::take(&mut *self.requests.lock().unwrap())
+    }
+}
+
+impl sgp4::HttpClient for Transport {
+    type Error = std::convert::Infallible;
+
+    async fn get(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<sgp4::HttpResponse, Self::Error> {
+        self.requests.lock().unwrap().push((
+            url.to_owned(),
+            headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        ));
+        Ok(self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("unexpected request"))
+    }
+
+    async fn post_form(
+        &self,
+        _url: &str,
+        _form: &[(&str, &str)],
+    ) -> Result<sgp4::HttpResponse, Self::Error> {
+        unreachable!()
+    }
+}
+
+// the mock transport never suspends, hence a single poll completes the future
+fn block_on<F: Future>(future: F) -> F::Output {
+    match std::pin::pin!(future)
+        .as_mut()
+        .poll(&mut std::task::Context::from_waker(std::task::Waker::noop()))
+    {
+        std::task::Poll::Ready(output) => output,
+        std::task::Poll::Pending => panic!("the future is pending"),
+    }
+}
+
+// empty cache directory specific to a test
+fn cache_directory(name: &str) -> std::path::PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("sgp4-fetcher-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&directory);
+    directory
+}
+
+fn celestrak(
+    fetcher: &sgp4::CachingFetcher<Transport>,
+) -> Result<(Vec<sgp4::Elements>, sgp4::CacheStatus), sgp4::FetcherError<std::convert::Infallible>>
+{
+    block_on(fetcher.celestrak(sgp4::CelestrakQuery::Group("stations")))
+}
+
+#[test]
+fn fetcher_fresh_cache() -> anyhow::Result<()> {
+    let directory = cache_directory("fresh");
+    let fetcher = sgp4::CachingFetcher::new(Transport::default(), &directory);
+    fetcher
+        .http_client()
+        .respond(200, &[("ETag", "\"1\"")], ISS);
+    let (elements_vec, status) = celestrak(&fetcher)?;
+    assert_eq!(status, sgp4::CacheStatus::Downloaded);
+    assert_eq!(elements_vec.len(), 1);
+    assert_eq!(elements_vec[0].norad_id, 25544);
+    assert_eq!(
+        fetcher.http_client().requests(),
+        [(
+            "https://celestrak.org/NORAD/elements/gp.php?GROUP=stations&FORMAT=json".to_owned(),
+            Vec::new()
+        )]
+    );
+    assert_eq!(std::fs::read(directory.join("GROUP-stations.json"))?, ISS);
+
+    // a second fetcher with the same directory reuses the cache without sending a request
+    let fetcher = sgp4::CachingFetcher::new(Transport::default(), &directory);
+    let (elements_vec, status) = celestrak(&fetcher)?;
+    assert_eq!(status, sgp4::CacheStatus::Fresh);
+    assert_eq!(elements_vec[0].norad_id, 25544);
+    assert!(fetcher.http_client().requests().is_empty());
+    std::fs::remove_dir_all(&directory)?;
+    Ok(())
+}
+
+#[test]
+fn fetcher_revalidation() -> anyhow::Result<()> {
+    let directory = cache_directory("revalidation");
+    let fetcher = sgp4::CachingFetcher::new(Transport::default(), &directory)
+        .with_minimum_interval(std::time::Duration::ZERO);
+    let last_modified = "Sun, 12 Jul 2020 02:00:00 GMT";
+    fetcher.http_client().respond(
+        200,
+        &[("etag", "\"1\""), ("Last-Modified", last_modified)],
+        ISS,
+    );
+    assert_eq!(celestrak(&fetcher)?.1, sgp4::CacheStatus::Downloaded);
+    assert!(fetcher.http_client().requests()[0].1.is_empty());
+
+    // the validators are sent back, and a 304 response reuses the cached body
+    fetcher.http_client().respond(304, &[], b"");
+    let (elements_vec, status) = celestrak(&fetcher)?;
+    assert_eq!(status, sgp4::CacheStatus::NotModified);
+    assert_eq!(elements_vec[0].norad_id, 25544);
+    assert!((elements_vec[0].mean_motion - 15.49560532).abs() < 1e-12);
+    let expected_headers = vec![
+        ("If-None-Match".to_owned(), "\"1\"".to_owned()),
+        ("If-Modified-Since".to_owned(), last_modified.to_owned()),
+    ];
+    assert_eq!(fetcher.http_client().requests()[0].1, expected_headers);
+
+    // errors leave the cache untouched
+    fetcher.http_client().respond(503, &[], b"");
+    assert!(matches!(
+        celestrak(&fetcher),
+        Err(sgp4::FetcherError::Client(sgp4::ClientError::Status(503)))
+    ));
+    assert_eq!(fetcher.http_client().requests()[0].1, expected_headers);
+
+    // a new response replaces the cached body and validators
+    fetcher
+        .http_client()
+        .respond(200, &[("ETag", "\"2\"")], b"No GP data found");
+    let (elements_vec, status) = celestrak(&fetcher)?;
+    assert_eq!(status, sgp4::CacheStatus::Downloaded);
+    assert!(elements_vec.is_empty());
+    assert_eq!(fetcher.http_client().requests()[0].1, expected_headers);
+    fetcher.http_client().respond(304, &[], b"");
+    let (elements_vec, status) = celestrak(&fetcher)?;
+    assert_eq!(status, sgp4::CacheStatus::NotModified);
+    assert!(elements_vec.is_empty());
+    assert_eq!(
+        fetcher.http_client().requests()[0].1,
+        [("If-None-Match".to_owned(), "\"2\"".to_owned())]
+    );
+    std::fs::remove_dir_all(&directory)?;
+    Ok(())
+}
+
+#[test]
+fn fetcher_not_modified_without_cache() -> anyhow::Result<()> {
+    let directory = cache_directory("not-modified");
+    let fetcher = sgp4::CachingFetcher::new(Transport::default(), &directory);
+
+    // a 304 response without a cached body is an error, and nothing is cached
+    fetcher.http_client().respond(304, &[], b"");
+    assert!(matches!(
+        celestrak(&fetcher),
+        Err(sgp4::FetcherError::Client(sgp4::ClientError::Status(304)))
+    ));
+    assert!(!directory.exists());
+    Ok(())
+}
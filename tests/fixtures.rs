@@ -0,0 +1,7 @@
+pub fn iss() -> anyhow::Result<sgp4::Elements> {
+    Ok(sgp4::Elements::from_tle(
+        Some("ISS (ZARYA)".to_owned()),
+        "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+        "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    )?)
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "alloc")]
+
+// GEO satellite 25954, kept at 101.04° W, optionally with a modified mean motion
+fn geo(mean_motion_offset: f64) -> anyhow::Result<sgp4::Constants> {
+    let mut elements = sgp4::Elements::from_tle(
+        None,
+        "1 25954U 99060A   04039.68057285 -.00000108  00000-0  00000-0 0  6847".as_bytes(),
+        "2 25954   0.0004 243.8136 0001765  15.5294  22.7134  1.00271289 15615".as_bytes(),
+    )?;
+    elements.mean_motion += mean_motion_offset;
+    Ok(sgp4::Constants::from_elements(&elements)?)
+}
+
+fn longitude(constants: &sgp4::Constants, t: f64) -> anyhow::Result<f64> {
+    let t = sgp4::MinutesSinceEpoch(t);
+    Ok(sgp4::Geodetic::from_teme(
+        sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+        &constants.propagate(t)?.position,
+    )
+    .longitude)
+}
+
+#[test]
+fn geo_drift_rate() -> anyhow::Result<()> {
+    for (mean_motion_offset, drift_rate) in [(0.01, 3.6), (-0.01, -3.6)] {
+        // a satellite faster than the Earth's rotation drifts east by 360° per extra revolution
+        let constants = geo(mean_motion_offset)?;
+        let geo_state = constants.geo_state(sgp4::MinutesSinceEpoch(0.0))?;
+        assert!((geo_state.drift_rate.to_degrees() - drift_rate).abs() < 0.01);
+        assert!(geo_state.libration.is_none());
+
+        // the drift rate matches the longitude change over a day
+        let change =
+            (longitude(&constants, 24.0 * 60.0)? - longitude(&constants, 0.0)?).to_degrees();
+        assert!((change - drift_rate).abs() < 0.01);
+    }
+
+    // a slow drift librates around the stable point with a larger amplitude
+    let geo_state = geo(0.001)?.geo_state(sgp4::MinutesSinceEpoch(0.0))?;
+    assert!((geo_state.drift_rate.to_degrees() - 0.357).abs() < 0.001);
+    let libration = geo_state.libration.unwrap();
+    assert!((libration.center.to_degrees() + 104.93).abs() < 1e-9);
+    assert!(libration.amplitude.to_degrees() > 3.9);
+    Ok(())
+}
+
+#[test]
+fn station_keeping_box_violations() -> anyhow::Result<()> {
+    // the satellite drifts east by 0.036°/day and leaves a ±0.1° box centred on its initial longitude
+    let constants = geo(0.0001)?;
+    let initial_longitude = longitude(&constants, 0.0)?;
+    let station_keeping_box = sgp4::StationKeepingBox {
+        longitude: initial_longitude,
+        longitude_tolerance: 0.1_f64.to_radians(),
+        maximum_inclination: 0.1_f64.to_radians(),
+    };
+    let end = 10.0 * 24.0 * 60.0;
+    let violations = constants.box_violations(
+        &station_keeping_box,
+        sgp4::MinutesSinceEpoch(0.0),
+        sgp4::MinutesSinceEpoch(end),
+        60.0,
+    )?;
+    assert!(!violations.is_empty());
+    let first = violations[0].start.unwrap().0;
+
+    // 0.1° / 0.036°.day⁻¹ ≈ 2.8 days, the daily oscillation advances or delays the exit by a fraction of a day
+    assert!((first / (24.0 * 60.0) - 2.8).abs() < 0.5);
+    assert!(
+        (longitude(&constants, first)?
+            - initial_longitude
+            - station_keeping_box.longitude_tolerance)
+            .abs()
+            < 1e-9
+    );
+    assert!(violations.last().unwrap().end.is_none());
+    for violation in violations.iter() {
+        assert!(
+            (violation.duration
+                - (violation.end.map_or(end, |t| t.0) - violation.start.unwrap().0))
+                .abs()
+                < 1e-9
+        );
+    }
+
+    // the 0.0004° inclination exceeds a 0.0001° limit during the whole window
+    let violations = geo(0.0)?.box_violations(
+        &sgp4::StationKeepingBox {
+            longitude: initial_longitude,
+            longitude_tolerance: 1.0_f64.to_radians(),
+            maximum_inclination: 0.0001_f64.to_radians(),
+        },
+        sgp4::MinutesSinceEpoch(0.0),
+        sgp4::MinutesSinceEpoch(end),
+        60.0,
+    )?;
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].start.is_none() && violations[0].end.is_none());
+    assert_eq!(violations[0].duration, end);
+
+    // a 1° box with a 0.1° inclination limit is never violated
+    assert!(geo(0.0)?
+        .box_violations(
+            &sgp4::StationKeepingBox {
+                longitude: initial_longitude,
+                longitude_tolerance: 1.0_f64.to_radians(),
+                maximum_inclination: 0.1_f64.to_radians(),
+            },
+            sgp4::MinutesSinceEpoch(0.0),
+            sgp4::MinutesSinceEpoch(end),
+            60.0,
+        )?
+        .is_empty());
+    Ok(())
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+fn norm(vector: &[f64; 3]) -> f64 {
+    vector
+        .iter()
+        .map(|component| component.powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn unit(vector: [f64; 3]) -> [f64; 3] {
+    let norm = norm(&vector);
+    vector.map(|component| component / norm)
+}
+
+fn sub(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+// point at the given distance from the satellite in the (x, y) plane, at the given angle from the x axis in degrees
+fn towards(satellite: &[f64; 3], distance: f64, angle: f64) -> [f64; 3] {
+    [
+        satellite[0] + distance * angle.to_radians().cos(),
+        satellite[1] + distance * angle.to_radians().sin(),
+        satellite[2],
+    ]
+}
+
+#[test]
+fn glint_angle_geometry() {
+    let satellite = [7000.0, 0.0, 0.0];
+    let normal = [1.0, 0.0, 0.0];
+    let sun = towards(&satellite, 1.5e8, 30.0);
+
+    // the Sun's mirror image is at -30°
+    let angle = |observer_angle: f64| {
+        sgp4::glint_angle(
+            &towards(&satellite, 1000.0, observer_angle),
+            &satellite,
+            &sun,
+            &normal,
+        )
+    };
+    assert!(angle(-30.0).unwrap() < 1e-7);
+    assert!((angle(-40.0).unwrap() - 10.0_f64.to_radians()).abs() < 1e-9);
+    assert!((angle(0.0).unwrap() - 30.0_f64.to_radians()).abs() < 1e-9);
+    assert!((angle(60.0).unwrap() - 90.0_f64.to_radians()).abs() < 1e-9);
+
+    // the observer or the Sun is behind the panel
+    assert!(angle(120.0).is_none());
+    assert!(sgp4::glint_angle(
+        &towards(&satellite, 1000.0, -30.0),
+        &satellite,
+        &towards(&satellite, 1.5e8, 150.0),
+        &normal,
+    )
+    .is_none());
+}
+
+#[test]
+fn glints_inertial_mirror() -> anyhow::Result<()> {
+    let constants = sgp4::Constants::from_elements(&iss()?)?;
+    let observer = sgp4::Observer {
+        latitude: 48.8566_f64.to_radians(),
+        longitude: 2.3522_f64.to_radians(),
+        altitude: 0.035,
+    };
+
+    // first sample with the satellite high above the observer and sunlit
+    let geometry = |t: f64| -> anyhow::Result<_> {
+        let epoch = constants.julian_years_since_j2000(sgp4::MinutesSinceEpoch(t));
+        let sidereal_time = sgp4::iau_epoch_to_sidereal_time(epoch);
+        let prediction = constants.propagate(sgp4::MinutesSinceEpoch(t))?;
+        let sun_position = sgp4::sun_position(epoch);
+        let observer_position = observer.position(sidereal_time);
+        let look_angles = observer.look_angles(sidereal_time, &prediction);
+        Ok((prediction, sun_position, observer_position, look_angles))
+    };
+    let mut t = 0.0;
+    loop {
+        let (prediction, sun_position, _, look_angles) = geometry(t)?;
+        // p = r · ŝ, the satellite is sunlit if p > 0 or |r|² - p² > R²
+        let projection = (0..3)
+            .map(|index| prediction.position[index] * sun_position[index])
+            .sum::<f64>()
+            / norm(&sun_position);
+        if look_angles.elevation > 30.0_f64.to_radians()
+            && (projection > 0.0
+                || norm(&prediction.position).powi(2) - projection.powi(2) > 6378.137_f64.powi(2))
+        {
+            break;
+        }
+        t += 0.1;
+        assert!(t < 24.0 * 60.0);
+    }
+
+    // the mirror's normal bisects the Sun and observer directions at t
+    let (prediction, sun_position, observer_position, look_angles) = geometry(t)?;
+    let sun_direction = unit(sub(&sun_position, &prediction.position));
+    let observer_direction = unit(sub(&observer_position, &prediction.position));
+    let normal = unit([0, 1, 2].map(|index| sun_direction[index] + observer_direction[index]));
+    let glints = constants.glints(
+        &observer,
+        &sgp4::PanelOrientation::Inertial(normal),
+        1.0_f64.to_radians(),
+        sgp4::MinutesSinceEpoch(t - 10.0),
+        sgp4::MinutesSinceEpoch(t + 10.0),
+        0.01,
+    )?;
+    assert_eq!(glints.len(), 1);
+    let glint = &glints[0];
+    assert!((glint.peak.0 - t).abs() < 1e-6);
+    assert!(glint.minimum_angle < 1e-6);
+    assert!((glint.range - look_angles.range).abs() < 1e-4);
+
+    // the reflection is almost fixed, the glint lasts 2 θₘₐₓ / ω, with ω the apparent angular rate of the observer
+    let observer_velocity = observer.velocity(sgp4::iau_epoch_to_sidereal_time(
+        constants.julian_years_since_j2000(sgp4::MinutesSinceEpoch(t)),
+    ));
+    let velocity = sub(&prediction.velocity, &observer_velocity);
+    let radial_velocity = (0..3)
+        .map(|index| velocity[index] * observer_direction[index])
+        .sum::<f64>();
+    let angular_rate =
+        (norm(&velocity).powi(2) - radial_velocity.powi(2)).sqrt() / look_angles.range;
+    let (start, end) = (glint.start.unwrap().0, glint.end.unwrap().0);
+    assert!(
+        ((end - start) * 60.0 / (2.0 * 1.0_f64.to_radians() / angular_rate) - 1.0).abs() < 1e-2
+    );
+    assert!((0.5 * (start + end) - glint.peak.0).abs() < 1e-2 * (end - start));
+
+    // a nadir panel does not reflect the Sun towards the observer at this time
+    assert!(constants
+        .glints(
+            &observer,
+            &sgp4::PanelOrientation::Ric([-1.0, 0.0, 0.0]),
+            1.0_f64.to_radians(),
+            sgp4::MinutesSinceEpoch(t - 0.1),
+            sgp4::MinutesSinceEpoch(t + 0.1),
+            0.01,
+        )?
+        .is_empty());
+    Ok(())
+}
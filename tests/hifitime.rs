@@ -0,0 +1,62 @@
+#![cfg(feature = "hifitime")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+use sgp4::hifitime;
+
+#[test]
+fn datetime_round_trip() -> anyhow::Result<()> {
+    let datetime = chrono::NaiveDate::from_ymd_opt(2020, 7, 12)
+        .and_then(|date| date.and_hms_nano_opt(21, 16, 1, 500_000_000))
+        .ok_or(anyhow::anyhow!("invalid datetime"))?;
+    let epoch = sgp4::datetime_to_hifitime(&datetime).ok_or(anyhow::anyhow!("out of range"))?;
+    assert_eq!(
+        epoch,
+        hifitime::Epoch::from_gregorian_utc(2020, 7, 12, 21, 16, 1, 500_000_000)
+    );
+    assert_eq!(sgp4::hifitime_to_datetime(&epoch), Some(datetime));
+    Ok(())
+}
+
+#[test]
+fn time_scales() -> anyhow::Result<()> {
+    // TAI was 37 s ahead of UTC in 2020
+    let elements = iss()?;
+    let utc = hifitime::Epoch::from_gregorian_utc_at_midnight(2020, 7, 13);
+    let tai = hifitime::Epoch::from_gregorian_tai_hms(2020, 7, 13, 0, 0, 37);
+    let gps = utc.in_time_scale(hifitime::TimeScale::GPST);
+    let expected = elements.datetime_to_minutes_since_epoch(
+        &chrono::NaiveDate::from_ymd_opt(2020, 7, 13)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .ok_or(anyhow::anyhow!("invalid datetime"))?,
+    )?;
+    for epoch in [utc, tai, gps] {
+        let minutes_since_epoch = elements
+            .hifitime_to_minutes_since_epoch(&epoch)
+            .ok_or(anyhow::anyhow!("out of range"))?;
+        assert!((minutes_since_epoch.0 - expected.0).abs() < 1.0e-9);
+    }
+    Ok(())
+}
+
+#[test]
+fn propagation() -> anyhow::Result<()> {
+    let elements = iss()?;
+    let constants = sgp4::Constants::from_elements(&elements)?;
+    let epoch = elements
+        .hifitime_epoch()
+        .ok_or(anyhow::anyhow!("out of range"))?;
+    assert_eq!(sgp4::hifitime_to_datetime(&epoch), Some(elements.datetime));
+    let minutes_since_epoch = elements
+        .hifitime_to_minutes_since_epoch(&(epoch + hifitime::Unit::Minute * 90))
+        .ok_or(anyhow::anyhow!("out of range"))?;
+    assert!((minutes_since_epoch.0 - 90.0).abs() < 1.0e-9);
+    let prediction = constants.propagate(minutes_since_epoch)?;
+    let expected = constants.propagate(sgp4::MinutesSinceEpoch(90.0))?;
+    for index in 0..3 {
+        assert!((prediction.position[index] - expected.position[index]).abs() < 1.0e-6);
+        assert!((prediction.velocity[index] - expected.velocity[index]).abs() < 1.0e-9);
+    }
+    Ok(())
+}
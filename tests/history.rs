@@ -0,0 +1,186 @@
+#![cfg(feature = "alloc")]
+
+const TLE: (&str, &str) = (
+    "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992",
+    "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008",
+);
+
+const MINUTES_PER_YEAR: f64 = 365.25 * 24.0 * 60.0;
+
+// the ISS element set moved to a later epoch with SGP4, optionally shifted along the orbit
+fn update(hours: i64, mean_anomaly_offset: f64) -> anyhow::Result<sgp4::Elements> {
+    let elements = sgp4::Elements::from_tle(None, TLE.0.as_bytes(), TLE.1.as_bytes())?;
+    let mut update = elements.re_epoch(elements.datetime + sgp4::chrono::Duration::hours(hours))?;
+    update.mean_anomaly += mean_anomaly_offset;
+    Ok(update)
+}
+
+fn distance(first: &sgp4::Prediction, second: &sgp4::Prediction) -> f64 {
+    (0..3)
+        .map(|index| (first.position[index] - second.position[index]).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+#[test]
+fn history_handover_and_blending() -> anyhow::Result<()> {
+    let first = update(0, 0.0)?;
+    let second = update(12, 0.01)?;
+    let handover = (first.epoch() + second.epoch()) / 2.0;
+    let first_constants = sgp4::Constants::from_elements(&first)?;
+    let second_constants = sgp4::Constants::from_elements(&second)?;
+    let mut history = sgp4::ElementsHistory::new();
+    history.insert(second)?;
+    history.insert(first)?;
+
+    // an element set with the same epoch replaces the previous one
+    history.insert(update(12, 0.01)?)?;
+    assert_eq!(history.len(), 2);
+    let epochs: Vec<f64> = history.iter().map(|elements| elements.epoch()).collect();
+    assert!(epochs[0] < epochs[1]);
+
+    let propagate = |constants: &sgp4::Constants, epoch: f64| {
+        constants.propagate(constants.minutes_since_epoch(epoch))
+    };
+    for (epoch, expected) in [
+        (handover - 1.0 / MINUTES_PER_YEAR, &first_constants),
+        (handover + 1.0 / MINUTES_PER_YEAR, &second_constants),
+    ] {
+        // the element set nearest in time is used without blending
+        let prediction = history.propagate(epoch, 0.0).unwrap()?;
+        assert_eq!(prediction.position, propagate(expected, epoch)?.position);
+        assert_eq!(
+            propagate(history.constants(epoch).unwrap(), epoch)?.position,
+            prediction.position
+        );
+
+        // 1 minute from the handover is outside a 1 minute blending window
+        let blended = history.propagate(epoch, 1.0).unwrap()?;
+        assert_eq!(blended.position, prediction.position);
+    }
+
+    // the 0.01° shift along the orbit is a 1.19 km jump at the handover
+    let before = propagate(&first_constants, handover)?;
+    let after = propagate(&second_constants, handover)?;
+    assert!((distance(&before, &after) - 1.19).abs() < 0.01);
+
+    // the blended prediction is the mean of the two predictions at the handover
+    let blended = history.propagate(handover, 30.0).unwrap()?;
+    for index in 0..3 {
+        assert!(
+            (blended.position[index] - (before.position[index] + after.position[index]) / 2.0)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    // the blending window ends 15 minutes after the handover with the second element set's prediction
+    let end = handover + 15.0 / MINUTES_PER_YEAR;
+    let blended = history.propagate(end, 30.0).unwrap()?;
+    assert!(distance(&blended, &propagate(&second_constants, end)?) < 1e-9);
+    for index in 0..3 {
+        assert!(
+            (blended.velocity[index] - propagate(&second_constants, end)?.velocity[index]).abs()
+                < 1e-12
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn history_handover_discontinuities() -> anyhow::Result<()> {
+    let mut history = sgp4::ElementsHistory::new();
+    history.insert(update(0, 0.0)?)?;
+
+    // a consistent update has no discontinuity
+    history.insert(update(12, 0.0)?)?;
+
+    // an update shifted by 0.01° along the orbit jumps by 0.01° × 6795 km in-track
+    history.insert(update(24, 0.01)?)?;
+    let handovers = history.handovers()?;
+    assert_eq!(handovers.len(), 2);
+    let epochs: Vec<f64> = history.iter().map(|elements| elements.epoch()).collect();
+    assert!((handovers[0].epoch - (epochs[0] + epochs[1]) / 2.0).abs() < 1e-12);
+    assert!(handovers[0].position_jump < 1e-3);
+    assert!(handovers[0].velocity_jump < 1e-6);
+    let in_track = 0.01_f64.to_radians() * 6795.0;
+    assert!((handovers[1].position_jump - in_track).abs() < 0.02);
+    assert!((handovers[1].ric_state.position[1] - in_track).abs() < 0.02);
+    assert!(handovers[1].ric_state.position[0].abs() < 0.02);
+    assert!(handovers[1].ric_state.position[2].abs() < 1e-3);
+    Ok(())
+}
+
+#[test]
+fn history_smoothed_drag_terms() -> anyhow::Result<()> {
+    let mut history = sgp4::ElementsHistory::new();
+    assert!(history.smoothed_drag_term(0.0, 1.0).is_none());
+    for (hours, drag_term) in [(0, -3.0e-5), (12, 2.0e-4), (24, -2.0e-5), (36, 1.0e-5)] {
+        let mut elements = update(hours, 0.0)?;
+        elements.drag_term = drag_term;
+        history.insert(elements)?;
+    }
+    let epochs: Vec<f64> = history.iter().map(|elements| elements.epoch()).collect();
+
+    // the 1.5 day window centred on the second element set contains the first three (odd count)
+    assert_eq!(history.smoothed_drag_term(epochs[1], 1.5), Some(-2.0e-5));
+
+    // the 3 day window contains all four (even count), the median is the mean of the two middle values
+    assert_eq!(
+        history.smoothed_drag_term(epochs[1], 3.0),
+        Some((-2.0e-5 + 1.0e-5) / 2.0)
+    );
+
+    // the window centred on the last element set only contains it and the previous one
+    assert_eq!(
+        history.smoothed_drag_term(epochs[3], 1.2),
+        Some((-2.0e-5 + 1.0e-5) / 2.0)
+    );
+    assert!(history
+        .smoothed_drag_term(epochs[3] + 2.0 / 365.25, 1.0)
+        .is_none());
+
+    // the published drag terms are kept, the outlier no longer drives the propagation
+    history.smooth_drag_terms(1.5)?;
+    assert_eq!(history.iter().nth(1).unwrap().drag_term, 2.0e-4);
+    let mut smoothed = update(12, 0.0)?;
+    smoothed.drag_term = -2.0e-5;
+    let smoothed = sgp4::Constants::from_elements(&smoothed)?;
+    let t = sgp4::MinutesSinceEpoch(60.0);
+    assert_eq!(
+        history.constants(epochs[1]).unwrap().propagate(t)?.position,
+        smoothed.propagate(t)?.position
+    );
+    Ok(())
+}
+
+#[test]
+fn history_accuracy() -> anyhow::Result<()> {
+    let mut history = sgp4::ElementsHistory::new();
+    assert!(history.accuracy(0.0, 1.0)?.is_none());
+
+    // every update is shifted by 0.01° along the orbit relative to the previous one
+    for (index, hours) in [0, 12, 24, 36].into_iter().enumerate() {
+        history.insert(update(hours, -0.01 * index as f64)?)?;
+    }
+
+    // pairs separated by 0.5 day (3) and 1 day (2), the 1.5 day pair is beyond the maximum horizon
+    let errors = history.propagation_errors(1.1)?;
+    assert_eq!(errors.len(), 5);
+    for error in errors.iter() {
+        // the older element set is ahead by 0.01° per 0.5 day of horizon
+        let in_track = 0.01_f64.to_radians() * 6795.0 * (error.horizon / 0.5).round();
+        assert!((error.ric_position[1] - in_track).abs() < 0.02);
+    }
+
+    let statistics = history.accuracy(0.75, 1.1)?.unwrap();
+    assert_eq!(statistics.samples, 2);
+    let in_track = 0.02_f64.to_radians() * 6795.0;
+    assert!((statistics.mean[1] - in_track).abs() < 0.02);
+    assert!((statistics.rms[1] - in_track).abs() < 0.02);
+    assert!((statistics.maximum - in_track).abs() < 0.04);
+    assert!(statistics.rms[0] < 0.01 && statistics.rms[2] < 0.01);
+    assert!(history.accuracy(1.2, 2.0)?.is_some());
+    assert!(history.accuracy(1.6, 2.0)?.is_none());
+    Ok(())
+}
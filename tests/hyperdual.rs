@@ -0,0 +1,187 @@
+#![cfg(feature = "hyperdual")]
+
+#[path = "test_cases.rs"]
+mod test_cases;
+use test_cases::*;
+
+// central finite difference of the positions predicted from perturbed elements
+fn position_difference(
+    test_case: &TestCase,
+    perturb: impl Fn(&mut sgp4::Elements, f64),
+    step: f64,
+    time: f64,
+) -> Option<[f64; 3]> {
+    let propagate = |delta| {
+        let mut perturbed =
+            sgp4::Elements::from_tle(None, test_case.line1.as_bytes(), test_case.line2.as_bytes())
+                .ok()?;
+        perturb(&mut perturbed, delta);
+        sgp4::Constants::from_elements(&perturbed)
+            .ok()?
+            .propagate(sgp4::MinutesSinceEpoch(time))
+            .ok()
+    };
+    let before = propagate(-step)?;
+    let after = propagate(step)?;
+    Some(core::array::from_fn(|index| {
+        (after.position[index] - before.position[index]) / (2.0 * step)
+    }))
+}
+
+#[test]
+fn hyperdual() -> anyhow::Result<()> {
+    let test_cases: TestCases = toml::from_str(include_str!("test_cases.toml")).unwrap();
+    for test_case in test_cases.list.iter() {
+        let element =
+            sgp4::Elements::from_tle(None, test_case.line1.as_bytes(), test_case.line2.as_bytes())
+                .map_err(|error| anyhow::anyhow!("{error}"))?;
+        let constants = sgp4::Constants::from_elements(&element)?;
+
+        // ε₁ is the time and ε₂ is the drag term (first run) or the mean anomaly (second run)
+        let mut drag_term_seeded =
+            sgp4::DifferentiableElements::<sgp4::Hyperdual>::from_elements(&element);
+        drag_term_seeded.drag_term.e2 = 1.0;
+        let mut mean_anomaly_seeded =
+            sgp4::DifferentiableElements::<sgp4::Hyperdual>::from_elements(&element);
+        mean_anomaly_seeded.mean_anomaly.e2 = 1.0;
+        for state in &test_case.states {
+            let time = match state {
+                State::Ok { time, .. } => *time,
+                State::Err { time, .. } => *time,
+            };
+            let prediction = constants.propagate(sgp4::MinutesSinceEpoch(time));
+            let f64_prediction = sgp4::propagate_differentiable(
+                &sgp4::WGS84,
+                &sgp4::DifferentiableElements::<f64>::from_elements(&element),
+                time,
+            );
+            let differentiable_predictions =
+                [&drag_term_seeded, &mean_anomaly_seeded].map(|differentiable| {
+                    sgp4::propagate_differentiable(
+                        &sgp4::WGS84,
+                        differentiable,
+                        sgp4::Hyperdual::new(time, 1.0, 0.0, 0.0),
+                    )
+                });
+            let prediction = match prediction {
+                Ok(prediction) => prediction,
+                Err(_) => {
+                    assert!(f64_prediction.is_err());
+                    assert!(differentiable_predictions
+                        .iter()
+                        .all(|prediction| prediction.is_err()));
+                    continue;
+                }
+            };
+
+            // the real parts are those of the f64 propagator
+            let f64_prediction = f64_prediction.map_err(|error| anyhow::anyhow!("{error}"))?;
+            assert_eq!(f64_prediction.position, prediction.position);
+            assert_eq!(f64_prediction.velocity, prediction.velocity);
+            let [drag_term_prediction, mean_anomaly_prediction] = differentiable_predictions;
+            let drag_term_prediction =
+                drag_term_prediction.map_err(|error| anyhow::anyhow!("{error}"))?;
+            let mean_anomaly_prediction =
+                mean_anomaly_prediction.map_err(|error| anyhow::anyhow!("{error}"))?;
+            for index in 0..3 {
+                assert_eq!(
+                    drag_term_prediction.position[index].real,
+                    prediction.position[index]
+                );
+                assert_eq!(
+                    drag_term_prediction.velocity[index].real,
+                    prediction.velocity[index]
+                );
+                assert_eq!(
+                    mean_anomaly_prediction.position[index].e1,
+                    drag_term_prediction.position[index].e1
+                );
+            }
+
+            // the tolerances account for the rounding error of the propagated positions,
+            // which grows with the apoapsis radius and with the angle swept since epoch
+            // (a = (μ / n²)¹ᐟ³ with μ in km³.s⁻² and n in rad.s⁻¹)
+            let radius = prediction
+                .position
+                .iter()
+                .map(|component| component.powi(2))
+                .sum::<f64>()
+                .sqrt();
+            let apoapsis_radius = (398600.5
+                / (element.mean_motion * (core::f64::consts::PI / 43200.0)).powi(2))
+            .cbrt()
+                * (1.0 + element.eccentricity);
+            let swept_angle = (element.mean_motion * (core::f64::consts::PI / 720.0) * time).abs();
+            let rounding_error = f64::EPSILON * apoapsis_radius * (1.0 + swept_angle);
+
+            // the time derivative is compared with a second-order finite difference,
+            // one-sided away from the epoch so that the samples share the resonance integrator step
+            // (the relative term covers the truncation error near the perigee of eccentric orbits)
+            let step = if time < 0.0 { -1.0e-4 } else { 1.0e-4 };
+            let next = constants.propagate(sgp4::MinutesSinceEpoch(time + step));
+            let next_next = constants.propagate(sgp4::MinutesSinceEpoch(time + 2.0 * step));
+            if let (Ok(next), Ok(next_next)) = (&next, &next_next) {
+                for index in 0..3 {
+                    let difference = (-3.0 * prediction.position[index]
+                        + 4.0 * next.position[index]
+                        - next_next.position[index])
+                        / (2.0 * step);
+                    assert!(
+                        (drag_term_prediction.position[index].e1 - difference).abs()
+                            < 1.0e-5
+                                + 1.0e-7 * difference.abs()
+                                + 4.0 * rounding_error / step.abs(),
+                        "∂r/∂t {} {} {} {}",
+                        test_case.line1,
+                        time,
+                        drag_term_prediction.position[index].e1,
+                        difference,
+                    );
+                }
+            }
+
+            // the element derivatives are compared with central finite differences
+            // of propagators initialized with perturbed elements
+            for (differentiable_prediction, difference, step) in [
+                (
+                    &drag_term_prediction,
+                    position_difference(
+                        test_case,
+                        |elements, delta| elements.drag_term += delta,
+                        1.0e-7,
+                        time,
+                    ),
+                    1.0e-7,
+                ),
+                (
+                    &mean_anomaly_prediction,
+                    position_difference(
+                        test_case,
+                        |elements, delta| elements.mean_anomaly += delta.to_degrees(),
+                        1.0e-6,
+                        time,
+                    ),
+                    1.0e-6,
+                ),
+            ] {
+                if let Some(difference) = difference {
+                    for (position, difference) in
+                        differentiable_prediction.position.iter().zip(difference)
+                    {
+                        assert!(
+                            (position.e2 - difference).abs()
+                                < 1.0e-4 * difference.abs()
+                                    + (1.0e-12 * radius + 4.0 * rounding_error) / step,
+                            "∂r/∂x {} {} {} {}",
+                            test_case.line1,
+                            time,
+                            position.e2,
+                            difference,
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
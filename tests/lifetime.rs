@@ -0,0 +1,79 @@
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+// constant density in kg.m⁻³, for which the circular decay has a closed form
+const DENSITY: f64 = 1e-11;
+
+// ballistic coefficient in m².kg⁻¹
+const BALLISTIC_COEFFICIENT: f64 = 0.005;
+
+fn circular_iss() -> anyhow::Result<sgp4::Elements> {
+    let mut elements = iss()?;
+    elements.eccentricity = 0.0;
+    Ok(elements)
+}
+
+#[test]
+fn lifetime_closed_form() -> anyhow::Result<()> {
+    let elements = circular_iss()?;
+    let constant_density = |_altitude: f64, _solar_activity: &sgp4::SolarActivity| DENSITY;
+    let average_activity = |_epoch: f64| sgp4::SolarActivity::default();
+
+    // ȧ = -K a¹ᐟ², with K = B ρ μ¹ᐟ² (km.s⁻¹ converted to km.day⁻¹), hence Δt = 2 (a₀¹ᐟ² - a₁¹ᐟ²) / K
+    let k = BALLISTIC_COEFFICIENT
+        * DENSITY
+        * 1e3
+        * (sgp4::WGS84.ke.powi(2) * sgp4::WGS84.ae.powi(3) / 3600.0).sqrt()
+        * 86400.0;
+    let lifetime =
+        2.0 * (elements.semi_major_axis(&sgp4::WGS84).sqrt() - (sgp4::WGS84.ae + 120.0).sqrt()) / k;
+    let epoch = elements
+        .lifetime(
+            BALLISTIC_COEFFICIENT,
+            average_activity,
+            constant_density,
+            25.0,
+        )
+        .unwrap();
+    assert!((elements.age(epoch) / lifetime - 1.0).abs() < 1e-3);
+
+    // the object does not decay within the maximum duration
+    assert!(elements
+        .lifetime(
+            BALLISTIC_COEFFICIENT,
+            average_activity,
+            constant_density,
+            0.5 * lifetime / 365.25,
+        )
+        .is_none());
+    assert!(elements
+        .lifetime(0.0, average_activity, constant_density, 25.0)
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn lifetime_solar_activity() -> anyhow::Result<()> {
+    let elements = circular_iss()?;
+    let lifetime = |f107: f64| {
+        elements
+            .lifetime(
+                BALLISTIC_COEFFICIENT,
+                |_| sgp4::SolarActivity {
+                    f107,
+                    f107_average: f107,
+                    ap: 15.0,
+                },
+                sgp4::ExponentialDensity,
+                25.0,
+            )
+            .map(|epoch| elements.age(epoch))
+    };
+
+    // a denser thermosphere during a solar maximum shortens the lifetime
+    let minimum = lifetime(70.0).unwrap();
+    let maximum = lifetime(250.0).unwrap();
+    assert!(maximum < minimum);
+    Ok(())
+}
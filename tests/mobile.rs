@@ -0,0 +1,92 @@
+#![cfg(feature = "uniffi")]
+
+const LINE1: &str = "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992";
+const LINE2: &str = "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008";
+
+#[test]
+fn uniffi_propagator() -> anyhow::Result<()> {
+    let propagator = sgp4::UniffiPropagator::from_tle(
+        Some("ISS (ZARYA)".to_owned()),
+        format!("  {}\n", LINE1),
+        LINE2.to_owned(),
+    )?;
+    assert_eq!(propagator.object_name().as_deref(), Some("ISS (ZARYA)"));
+    assert_eq!(propagator.norad_id(), 25544);
+
+    // 2020-07-12T21:16:01.000416Z
+    assert_eq!(propagator.epoch_unix_milliseconds(), 1594588561000);
+
+    // the predictions match the Rust propagator
+    let constants = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+        None,
+        LINE1.as_bytes(),
+        LINE2.as_bytes(),
+    )?)?;
+    let expected = constants.propagate(sgp4::MinutesSinceEpoch(90.0))?;
+    let prediction = propagator.propagate(90.0)?;
+    assert_eq!(prediction.position, expected.position);
+    assert_eq!(prediction.velocity, expected.velocity);
+    let prediction = propagator.propagate_unix_milliseconds(1594588561000 + 90 * 60000)?;
+    for index in 0..3 {
+        // the epoch is truncated to the millisecond, the satellite moves about 8 m per millisecond
+        assert!((prediction.position[index] - expected.position[index]).abs() < 1e-5);
+    }
+    Ok(())
+}
+
+#[test]
+fn uniffi_errors() -> anyhow::Result<()> {
+    assert!(matches!(
+        sgp4::UniffiPropagator::from_tle(None, LINE1.to_owned(), LINE1.to_owned()),
+        Err(sgp4::UniffiError::Parse { .. })
+    ));
+    assert!(matches!(
+        sgp4::UniffiPropagator::from_json("{}".to_owned()),
+        Err(sgp4::UniffiError::Parse { .. })
+    ));
+
+    let omm = |mean_motion: f64, eccentricity: f64, drag_term: f64| {
+        format!(
+            r#"{{
+                "OBJECT_NAME": "ISS (ZARYA)",
+                "OBJECT_ID": "1998-067A",
+                "EPOCH": "2020-07-12T01:19:07.402656",
+                "MEAN_MOTION": {},
+                "ECCENTRICITY": {},
+                "INCLINATION": 51.6435,
+                "RA_OF_ASC_NODE": 225.4004,
+                "ARG_OF_PERICENTER": 44.9625,
+                "MEAN_ANOMALY": 5.1087,
+                "EPHEMERIS_TYPE": 0,
+                "CLASSIFICATION_TYPE": "U",
+                "NORAD_CAT_ID": 25544,
+                "ELEMENT_SET_NO": 999,
+                "REV_AT_EPOCH": 23587,
+                "BSTAR": {},
+                "MEAN_MOTION_DOT": 0.00289036,
+                "MEAN_MOTION_DDOT": 0
+            }}"#,
+            mean_motion, eccentricity, drag_term
+        )
+    };
+    assert_eq!(
+        sgp4::UniffiPropagator::from_json(omm(15.49560532, 0.0001771, 0.0049645))?.norad_id(),
+        25544
+    );
+    match sgp4::UniffiPropagator::from_json(omm(15.49560532, 1.5, 0.0049645)) {
+        Err(error @ sgp4::UniffiError::Elements { .. }) => assert_eq!(
+            error.to_string(),
+            "The epoch eccentricity (1.5) is outside the range [0, 1["
+        ),
+        _ => panic!("expected an elements error"),
+    }
+
+    // a large drag term makes the orbit decay within a week
+    let propagator = sgp4::UniffiPropagator::from_json(omm(15.49560532, 0.0001771, 0.5))?;
+    assert!(propagator.propagate(0.0).is_ok());
+    assert!(matches!(
+        propagator.propagate(10000.0),
+        Err(sgp4::UniffiError::Propagation { .. })
+    ));
+    Ok(())
+}
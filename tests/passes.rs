@@ -0,0 +1,232 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+fn paris() -> sgp4::Observer {
+    sgp4::Observer {
+        latitude: 48.8566_f64.to_radians(),
+        longitude: 2.3522_f64.to_radians(),
+        altitude: 0.035,
+    }
+}
+
+fn elevation(constants: &sgp4::Constants, t: sgp4::MinutesSinceEpoch) -> anyhow::Result<f64> {
+    Ok(paris()
+        .look_angles(
+            sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+            &constants.propagate(t)?,
+        )
+        .elevation)
+}
+
+#[test]
+fn passes_bracket_culminations() -> anyhow::Result<()> {
+    let constants = sgp4::Constants::from_elements(&iss()?)?;
+    let minimum_elevation = 10.0_f64.to_radians();
+    let passes = constants.passes(
+        &paris(),
+        minimum_elevation,
+        sgp4::MinutesSinceEpoch(0.0),
+        sgp4::MinutesSinceEpoch(24.0 * 60.0),
+        0.5,
+        sgp4::iau_epoch_to_sidereal_time,
+    )?;
+    assert_eq!(passes.len(), 5);
+    for (pass, (culmination, maximum_elevation)) in passes.iter().zip([
+        (12.17, 15.06),
+        (108.47, 69.53),
+        (205.37, 51.83),
+        (302.41, 67.49),
+        (399.07, 34.22),
+    ]) {
+        let rise = pass.rise.unwrap();
+        let set = pass.set.unwrap();
+        assert!(rise.0 < pass.culmination.0 && pass.culmination.0 < set.0);
+        assert!((pass.culmination.0 - culmination).abs() < 0.01);
+        assert!((pass.maximum_elevation.to_degrees() - maximum_elevation).abs() < 0.01);
+
+        // the rise and set are refined to 10⁻⁶ min, during which the elevation changes by less than 10⁻⁶ rad
+        assert!((elevation(&constants, rise)? - minimum_elevation).abs() < 1e-6);
+        assert!((elevation(&constants, set)? - minimum_elevation).abs() < 1e-6);
+
+        // the culmination is the maximum of the elevation
+        for offset in [-0.1, -1e-3, 1e-3, 0.1] {
+            assert!(
+                elevation(
+                    &constants,
+                    sgp4::MinutesSinceEpoch(pass.culmination.0 + offset)
+                )? < pass.maximum_elevation
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn passes_open_at_window_bounds() -> anyhow::Result<()> {
+    let constants = sgp4::Constants::from_elements(&iss()?)?;
+    let minimum_elevation = 10.0_f64.to_radians();
+
+    // the window starts and ends in the middle of the 69.5° pass (rise 105.13, culmination 108.47, set 111.82)
+    let passes = constants.passes(
+        &paris(),
+        minimum_elevation,
+        sgp4::MinutesSinceEpoch(107.0),
+        sgp4::MinutesSinceEpoch(110.0),
+        0.5,
+        sgp4::iau_epoch_to_sidereal_time,
+    )?;
+    assert_eq!(passes.len(), 1);
+    assert!(passes[0].rise.is_none());
+    assert!(passes[0].set.is_none());
+    assert!((passes[0].culmination.0 - 108.47).abs() < 0.01);
+
+    // the window starts after the culmination, the maximum is at the beginning of the window
+    let passes = constants.passes(
+        &paris(),
+        minimum_elevation,
+        sgp4::MinutesSinceEpoch(110.0),
+        sgp4::MinutesSinceEpoch(200.0),
+        0.5,
+        sgp4::iau_epoch_to_sidereal_time,
+    )?;
+    assert_eq!(passes.len(), 1);
+    assert!(passes[0].rise.is_none());
+    assert!((passes[0].set.unwrap().0 - 111.82).abs() < 0.01);
+    assert!((passes[0].culmination.0 - 110.0).abs() < 1e-5);
+    assert!(
+        (passes[0].maximum_elevation - elevation(&constants, sgp4::MinutesSinceEpoch(110.0))?)
+            .abs()
+            < 1e-5
+    );
+    assert!(
+        (passes[0].rise_azimuth
+            - paris()
+                .look_angles(
+                    sgp4::iau_epoch_to_sidereal_time(
+                        constants.julian_years_since_j2000(sgp4::MinutesSinceEpoch(110.0))
+                    ),
+                    &constants.propagate(sgp4::MinutesSinceEpoch(110.0))?,
+                )
+                .azimuth)
+            .abs()
+            < 1e-12
+    );
+
+    // the window ends before the culmination, the maximum is at the end of the window
+    let passes = constants.passes(
+        &paris(),
+        minimum_elevation,
+        sgp4::MinutesSinceEpoch(100.0),
+        sgp4::MinutesSinceEpoch(107.0),
+        0.5,
+        sgp4::iau_epoch_to_sidereal_time,
+    )?;
+    assert_eq!(passes.len(), 1);
+    assert!((passes[0].rise.unwrap().0 - 105.13).abs() < 0.01);
+    assert!(passes[0].set.is_none());
+    assert!((passes[0].culmination.0 - 107.0).abs() < 1e-5);
+    Ok(())
+}
+
+#[test]
+fn passes_and_track_use_the_given_sidereal_time() -> anyhow::Result<()> {
+    let constants = sgp4::Constants::from_elements_afspc_compatibility_mode(&iss()?)?;
+    let look_angles = |t: sgp4::MinutesSinceEpoch| -> anyhow::Result<sgp4::LookAngles> {
+        Ok(paris().look_angles(
+            sgp4::afspc_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+            &constants.propagate(t)?,
+        ))
+    };
+    let minimum_elevation = 10.0_f64.to_radians();
+    let passes = constants.passes(
+        &paris(),
+        minimum_elevation,
+        sgp4::MinutesSinceEpoch(0.0),
+        sgp4::MinutesSinceEpoch(24.0 * 60.0),
+        0.5,
+        sgp4::afspc_epoch_to_sidereal_time,
+    )?;
+    assert_eq!(passes.len(), 5);
+    for pass in passes.iter() {
+        let rise = pass.rise.unwrap();
+        let set = pass.set.unwrap();
+        assert!((look_angles(rise)?.elevation - minimum_elevation).abs() < 1e-6);
+        assert!((look_angles(set)?.elevation - minimum_elevation).abs() < 1e-6);
+        assert_eq!(look_angles(rise)?.azimuth, pass.rise_azimuth);
+        for point in constants.track(
+            &paris(),
+            rise,
+            set,
+            1.0 / 60.0,
+            sgp4::afspc_epoch_to_sidereal_time,
+        )? {
+            let expected = look_angles(point.t)?;
+            assert_eq!(point.elevation, expected.elevation);
+            let turns = (point.azimuth - expected.azimuth) / (2.0 * core::f64::consts::PI);
+            assert!((turns - turns.round()).abs() < 1e-12);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn revisit_gaps_and_coverage() -> anyhow::Result<()> {
+    let elements = iss()?;
+    let epoch = elements.epoch();
+    let constants = sgp4::Constants::from_elements(&elements)?;
+    let minutes_per_year = 365.25 * 24.0 * 60.0;
+    let target = sgp4::Target {
+        observer: paris(),
+        minimum_elevation: 10.0_f64.to_radians(),
+    };
+    let passes = constants.passes(
+        &target.observer,
+        target.minimum_elevation,
+        sgp4::MinutesSinceEpoch(0.0),
+        sgp4::MinutesSinceEpoch(24.0 * 60.0),
+        0.5,
+        sgp4::iau_epoch_to_sidereal_time,
+    )?;
+
+    // gaps between the window bounds and the passes
+    let mut bounds = vec![0.0];
+    for pass in passes.iter() {
+        bounds.push(pass.rise.unwrap().0);
+        bounds.push(pass.set.unwrap().0);
+    }
+    bounds.push(24.0 * 60.0);
+    let gaps: Vec<f64> = bounds
+        .chunks(2)
+        .map(|bounds| bounds[1] - bounds[0])
+        .collect();
+    let covered: f64 = passes
+        .iter()
+        .map(|pass| pass.set.unwrap().0 - pass.rise.unwrap().0)
+        .sum();
+
+    // a second identical satellite overlaps the first, the merged accesses are unchanged
+    for constellation in [vec![constants.clone()], vec![constants.clone(), constants]] {
+        let revisit = &sgp4::revisit(
+            &constellation,
+            core::slice::from_ref(&target),
+            epoch,
+            epoch + 1.0 / 365.25,
+            0.5,
+        )?[0];
+        assert_eq!(revisit.accesses.len(), passes.len() * constellation.len());
+        for access in revisit.accesses.iter() {
+            assert!(access.start < access.end);
+        }
+        assert!((revisit.coverage - covered / (24.0 * 60.0)).abs() < 1e-9);
+        assert!((revisit.mean_gap - gaps.iter().sum::<f64>() / gaps.len() as f64).abs() < 1e-6);
+        assert!((revisit.maximum_gap - gaps.iter().cloned().fold(0.0, f64::max)).abs() < 1e-6);
+
+        // the last pass sets at 402.15, hence the longest gap lasts until the end of the day
+        assert!((revisit.maximum_gap - (24.0 * 60.0 - 402.15)).abs() < 0.01);
+        assert!(((revisit.accesses[0].start - epoch) * minutes_per_year - 10.16).abs() < 0.01);
+    }
+    Ok(())
+}
@@ -0,0 +1,56 @@
+#[test]
+fn phase_angle_geometry() {
+    let satellite = [7000.0, 0.0, 0.0];
+    let sun = [7000.0 + 1.5e8, 0.0, 0.0];
+    assert!(sgp4::phase_angle(&[7500.0, 0.0, 0.0], &satellite, &sun).abs() < 1e-12);
+    assert!(
+        (sgp4::phase_angle(&[7000.0, 500.0, 0.0], &satellite, &sun) - core::f64::consts::FRAC_PI_2)
+            .abs()
+            < 1e-12
+    );
+    assert!(
+        (sgp4::phase_angle(&[6500.0, 0.0, 0.0], &satellite, &sun) - core::f64::consts::PI).abs()
+            < 1e-12
+    );
+    assert!(
+        (sgp4::phase_angle(&[7500.0, 500.0, 0.0], &satellite, &sun) - core::f64::consts::FRAC_PI_4)
+            .abs()
+            < 1e-12
+    );
+}
+
+#[test]
+fn visual_magnitude_lambertian_sphere() {
+    // the standard magnitude is defined at 1000 km and 90°
+    assert!(
+        (sgp4::visual_magnitude(-1.8, 1000.0, core::f64::consts::FRAC_PI_2) + 1.8).abs() < 1e-12
+    );
+
+    // twice the range is 5 log₁₀ 2 fainter
+    assert!(
+        (sgp4::visual_magnitude(-1.8, 2000.0, core::f64::consts::FRAC_PI_2)
+            - (-1.8 + 1.505149978319906))
+            .abs()
+            < 1e-12
+    );
+
+    // F(0) = π, F(3π/4) = √2 / 2 - π √2 / 8
+    assert!((sgp4::visual_magnitude(0.0, 1000.0, 0.0) + 1.2428746817353344).abs() < 1e-12);
+    assert!(
+        (sgp4::visual_magnitude(0.0, 1000.0, 3.0 * core::f64::consts::FRAC_PI_4)
+            - 2.047203908532399)
+            .abs()
+            < 1e-12
+    );
+    assert!(
+        (sgp4::visual_magnitude(-1.8, 500.0, core::f64::consts::FRAC_PI_3) + 4.009563935635778)
+            .abs()
+            < 1e-12
+    );
+
+    // the magnitude increases (the satellite fades) with the phase angle
+    let magnitudes: Vec<f64> = (0..18)
+        .map(|index| sgp4::visual_magnitude(-1.8, 1000.0, (index as f64 * 10.0).to_radians()))
+        .collect();
+    assert!(magnitudes.windows(2).all(|pair| pair[0] < pair[1]));
+}
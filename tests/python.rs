@@ -0,0 +1,80 @@
+#![cfg(feature = "python")]
+
+const LINE1: &str = "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992";
+const LINE2: &str = "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008";
+
+#[test]
+fn python_elements() -> anyhow::Result<()> {
+    let elements = sgp4::PyElements::from_tle(Some("ISS (ZARYA)".to_owned()), LINE1, LINE2)?;
+    assert_eq!(elements.object_name().as_deref(), Some("ISS (ZARYA)"));
+    assert_eq!(
+        elements.international_designator().as_deref(),
+        Some("1998-067A")
+    );
+    assert_eq!(elements.norad_id(), 25544);
+    assert_eq!(elements.datetime(), "2020-07-12T21:16:01.000416");
+    assert_eq!(elements.inclination(), 51.6461);
+    assert_eq!(elements.right_ascension(), 221.2784);
+    assert_eq!(elements.eccentricity(), 0.0001413);
+    assert_eq!(elements.argument_of_perigee(), 89.1723);
+    assert_eq!(elements.mean_anomaly(), 280.4612);
+    assert_eq!(elements.mean_motion(), 15.49507896);
+    assert_eq!(elements.drag_term(), -0.31515e-4);
+    assert!((elements.minutes_since_epoch("2020-07-12T22:16:01.000416")? - 60.0).abs() < 1e-9);
+    assert_eq!(
+        sgp4::PyElements::from_json(
+            r#"{
+                "OBJECT_NAME": "ISS (ZARYA)",
+                "OBJECT_ID": "1998-067A",
+                "EPOCH": "2020-07-12T01:19:07.402656",
+                "MEAN_MOTION": 15.49560532,
+                "ECCENTRICITY": 0.0001771,
+                "INCLINATION": 51.6435,
+                "RA_OF_ASC_NODE": 225.4004,
+                "ARG_OF_PERICENTER": 44.9625,
+                "MEAN_ANOMALY": 5.1087,
+                "EPHEMERIS_TYPE": 0,
+                "CLASSIFICATION_TYPE": "U",
+                "NORAD_CAT_ID": 25544,
+                "ELEMENT_SET_NO": 999,
+                "REV_AT_EPOCH": 23587,
+                "BSTAR": 0.0049645,
+                "MEAN_MOTION_DOT": 0.00289036,
+                "MEAN_MOTION_DDOT": 0
+            }"#
+        )?
+        .datetime(),
+        "2020-07-12T01:19:07.402656"
+    );
+
+    let constants = sgp4::PyConstants::from_elements(&elements)?;
+    let expected = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+        None,
+        LINE1.as_bytes(),
+        LINE2.as_bytes(),
+    )?)?
+    .propagate(sgp4::MinutesSinceEpoch(90.0))?;
+    let (position, velocity) = constants.propagate(90.0)?;
+    assert_eq!(position, expected.position);
+    assert_eq!(velocity, expected.velocity);
+    Ok(())
+}
+
+#[test]
+fn python_value_errors() {
+    pyo3::Python::initialize();
+    pyo3::Python::attach(|py| {
+        for error in [
+            sgp4::PyElements::from_tle(None, LINE2, LINE1)
+                .err()
+                .unwrap(),
+            sgp4::PyElements::from_json("{}").err().unwrap(),
+            sgp4::PyElements::from_tle(None, LINE1, LINE2)
+                .unwrap()
+                .minutes_since_epoch("2020-07-12")
+                .unwrap_err(),
+        ] {
+            assert!(error.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        }
+    });
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+#[test]
+fn doppler_shift_first_order() {
+    // Δf = -f ρ̇ / c, approaching satellites shift the frequency up
+    assert!((sgp4::doppler_shift(437.8e6, -7.0) - 10222.4053).abs() < 1e-3);
+    assert!((sgp4::doppler_shift(437.8e6, 7.0) + 10222.4053).abs() < 1e-3);
+    assert_eq!(sgp4::doppler_shift(437.8e6, 0.0), 0.0);
+}
+
+#[test]
+fn transponder_doppler_pair() {
+    let non_inverting = sgp4::Transponder::NonInverting {
+        offset: 437.8e6 - 145.99e6,
+    };
+    assert!((non_inverting.downlink(145.99e6) - 437.8e6).abs() < 1e-6);
+    assert!((non_inverting.uplink(437.8e6) - 145.99e6).abs() < 1e-6);
+
+    // 435.030 MHz to 435.060 MHz uplink, 145.920 MHz to 145.950 MHz downlink, inverted
+    let inverting = sgp4::Transponder::Inverting {
+        offset: 435.03e6 + 145.95e6,
+    };
+    assert!((inverting.downlink(435.03e6) - 145.95e6).abs() < 1e-6);
+    assert!((inverting.downlink(435.06e6) - 145.92e6).abs() < 1e-6);
+    assert!((inverting.uplink(145.935e6) - 435.045e6).abs() < 1e-6);
+
+    for transponder in [non_inverting, inverting] {
+        for range_rate in [-7.0, -1.0, 0.0, 3.0, 7.0] {
+            let downlink = transponder.downlink(145.99e6);
+            let pair = transponder.doppler_pair(downlink, range_rate);
+
+            // the satellite receives the nominal uplink up to a second-order term f (ρ̇ / c)²
+            let received = pair.uplink + sgp4::doppler_shift(pair.uplink, range_rate);
+            assert!((received - 145.99e6).abs() < 0.5);
+            assert!((pair.downlink - downlink * (1.0 - range_rate / 299792.458)).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn doppler_shift_pass() -> anyhow::Result<()> {
+    let constants = sgp4::Constants::from_elements(&iss()?)?;
+    let observer = sgp4::Observer {
+        latitude: 48.8566_f64.to_radians(),
+        longitude: 2.3522_f64.to_radians(),
+        altitude: 0.035,
+    };
+    let shift = |t: sgp4::MinutesSinceEpoch| -> anyhow::Result<f64> {
+        Ok(sgp4::doppler_shift(
+            437.8e6,
+            observer.range_rate(
+                sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+                &constants.propagate(t)?,
+            ),
+        ))
+    };
+
+    // the second pass over Paris culminates at 69.5°, the shift sweeps from about +10 kHz to -10 kHz
+    let pass = &constants.passes(
+        &observer,
+        0.0,
+        sgp4::MinutesSinceEpoch(100.0),
+        sgp4::MinutesSinceEpoch(120.0),
+        0.5,
+        sgp4::iau_epoch_to_sidereal_time,
+    )?[0];
+    assert!((pass.maximum_elevation.to_degrees() - 69.53).abs() < 1e-2);
+    assert!((shift(pass.rise.unwrap())? - 10069.0).abs() < 1.0);
+    assert!(shift(pass.culmination)?.abs() < 20.0);
+    assert!((shift(pass.set.unwrap())? + 10064.8).abs() < 1.0);
+    Ok(())
+}
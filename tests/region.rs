@@ -0,0 +1,190 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+fn region(vertices: &[[f64; 2]]) -> sgp4::Region {
+    sgp4::Region {
+        vertices: vertices
+            .iter()
+            .map(|[latitude, longitude]| [latitude.to_radians(), longitude.to_radians()])
+            .collect(),
+    }
+}
+
+fn sub_satellite_point(
+    constants: &sgp4::Constants,
+    t: sgp4::MinutesSinceEpoch,
+) -> anyhow::Result<sgp4::Geodetic> {
+    Ok(sgp4::Geodetic::from_teme(
+        sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+        &constants.propagate(t)?.position,
+    ))
+}
+
+#[test]
+fn region_contains() {
+    let contains = |region: &sgp4::Region, latitude: f64, longitude: f64| {
+        region.contains(latitude.to_radians(), longitude.to_radians())
+    };
+
+    // vertices listed counter-clockwise and clockwise
+    for vertices in [
+        [[35.0, -10.0], [35.0, 30.0], [60.0, 30.0], [60.0, -10.0]],
+        [[35.0, -10.0], [60.0, -10.0], [60.0, 30.0], [35.0, 30.0]],
+    ] {
+        let europe = region(&vertices);
+        assert!(contains(&europe, 48.8566, 2.3522));
+        assert!(!contains(&europe, 30.0, 2.3522));
+        assert!(!contains(&europe, 48.8566, 40.0));
+
+        // the antipode of Paris is not inside
+        assert!(!contains(&europe, -48.8566, -177.6478));
+
+        // the edges are great circle arcs, which reach tan⁻¹(tan(φ) / cos(20°)) at 10°E,
+        // that is 36.69° for the southern edge and 61.52° for the northern edge
+        assert!(!contains(&europe, 36.6, 10.0));
+        assert!(contains(&europe, 36.8, 10.0));
+        assert!(contains(&europe, 61.4, 10.0));
+        assert!(!contains(&europe, 61.6, 10.0));
+    }
+
+    // a region crossing the antimeridian
+    let pacific = region(&[
+        [-10.0, 170.0],
+        [-10.0, -170.0],
+        [10.0, -170.0],
+        [10.0, 170.0],
+    ]);
+    assert!(contains(&pacific, 0.0, 180.0));
+    assert!(contains(&pacific, 5.0, -175.0));
+    assert!(!contains(&pacific, 0.0, 0.0));
+    assert!(!contains(&pacific, 0.0, 160.0));
+
+    // a region containing the north pole
+    let arctic = region(&[[70.0, 0.0], [70.0, 90.0], [70.0, 180.0], [70.0, -90.0]]);
+    assert!(contains(&arctic, 90.0, 0.0));
+    assert!(contains(&arctic, 80.0, 45.0));
+    assert!(!contains(&arctic, 60.0, 45.0));
+    assert!(!contains(&arctic, -80.0, 45.0));
+}
+
+#[test]
+fn region_visits_boundaries() -> anyhow::Result<()> {
+    let constants = sgp4::Constants::from_elements(&iss()?)?;
+    let europe = region(&[[35.0, -10.0], [35.0, 30.0], [60.0, 30.0], [60.0, -10.0]]);
+    let visits = constants.region_visits(
+        &europe,
+        sgp4::MinutesSinceEpoch(0.0),
+        sgp4::MinutesSinceEpoch(24.0 * 60.0),
+        0.5,
+    )?;
+    assert!(!visits.is_empty());
+    for visit in visits.iter() {
+        let entry = visit.entry.unwrap();
+        let exit = visit.exit.unwrap();
+        assert!((visit.dwell - (exit.0 - entry.0)).abs() < 1e-12);
+
+        // the sub-satellite point is outside 10⁻⁴ min before the entry and after the exit
+        for (t, inside) in [
+            (entry.0 - 1e-4, false),
+            (entry.0 + 1e-4, true),
+            ((entry.0 + exit.0) / 2.0, true),
+            (exit.0 - 1e-4, true),
+            (exit.0 + 1e-4, false),
+        ] {
+            let point = sub_satellite_point(&constants, sgp4::MinutesSinceEpoch(t))?;
+            assert_eq!(europe.contains(point.latitude, point.longitude), inside);
+        }
+    }
+
+    // the ISS flies over Paris at the culmination of its 69.5° pass
+    assert!(visits
+        .iter()
+        .any(|visit| visit.entry.unwrap().0 < 108.47 && 108.47 < visit.exit.unwrap().0));
+
+    // a window starting and ending inside the region
+    let visit = &visits[0];
+    let start = visit.entry.unwrap().0 + 0.1;
+    let end = visit.exit.unwrap().0 - 0.1;
+    let inner = constants.region_visits(
+        &europe,
+        sgp4::MinutesSinceEpoch(start),
+        sgp4::MinutesSinceEpoch(end),
+        0.5,
+    )?;
+    assert_eq!(inner.len(), 1);
+    assert!(inner[0].entry.is_none() && inner[0].exit.is_none());
+    assert!((inner[0].dwell - (end - start)).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn region_segments_and_lookup() -> anyhow::Result<()> {
+    let constants = sgp4::Constants::from_elements(&iss()?)?;
+    let hemisphere =
+        |latitude: f64, _longitude: f64| Some(if latitude >= 0.0 { "north" } else { "south" });
+
+    // the hemisphere changes at the node crossings
+    let start = sgp4::MinutesSinceEpoch(0.0);
+    let end = sgp4::MinutesSinceEpoch(6.0 * 60.0);
+    let segments = constants.region_segments(&hemisphere, start, end, 0.5)?;
+    let crossings = constants.node_crossings(start, end, sgp4::iau_epoch_to_sidereal_time)?;
+    assert_eq!(segments.len(), crossings.len() + 1);
+    assert_eq!(segments[0].id, "north");
+    assert!(segments[0].entry.is_none());
+    assert!(segments.last().unwrap().exit.is_none());
+    for (index, crossing) in crossings.iter().enumerate() {
+        // the geodetic and geocentric latitudes are both zero on the equator
+        assert!((segments[index].exit.unwrap().0 - crossing.t.0).abs() < 1e-5);
+        assert!((segments[index + 1].entry.unwrap().0 - crossing.t.0).abs() < 1e-5);
+        assert_eq!(
+            segments[index + 1].id,
+            if crossing.node == sgp4::Node::Ascending {
+                "north"
+            } else {
+                "south"
+            }
+        );
+    }
+
+    // overlapping regions are reported separately
+    let europe = region(&[[35.0, -10.0], [35.0, 30.0], [60.0, 30.0], [60.0, -10.0]]);
+    let france = region(&[[42.0, -5.0], [42.0, 8.0], [51.0, 8.0], [51.0, -5.0]]);
+    let regions = [("europe", europe.clone()), ("france", france)];
+    let segments = constants.region_segments(&regions[..], start, end, 0.1)?;
+    let visits = constants.region_visits(&europe, start, end, 0.1)?;
+    let europe_segments: Vec<&sgp4::RegionSegment<&str>> = segments
+        .iter()
+        .filter(|segment| segment.id == "europe")
+        .collect();
+    assert_eq!(europe_segments.len(), visits.len());
+    for (segment, visit) in europe_segments.iter().zip(visits.iter()) {
+        assert!((segment.entry.unwrap().0 - visit.entry.unwrap().0).abs() < 1e-5);
+        assert!((segment.exit.unwrap().0 - visit.exit.unwrap().0).abs() < 1e-5);
+    }
+
+    // the 69.5° pass over Paris crosses France, within the European segment
+    let france_segment = segments
+        .iter()
+        .find(|segment| segment.id == "france")
+        .unwrap();
+    assert!(france_segment.entry.unwrap().0 < 108.47 && 108.47 < france_segment.exit.unwrap().0);
+    assert!(europe_segments.iter().any(|segment| {
+        segment.entry.unwrap().0 < france_segment.entry.unwrap().0
+            && france_segment.exit.unwrap().0 < segment.exit.unwrap().0
+    }));
+
+    // the 10° footprint at the culmination covers both regions and lies in the northern hemisphere
+    let t = sgp4::MinutesSinceEpoch(108.47);
+    let footprint = sgp4::Region::footprint(
+        sgp4::iau_epoch_to_sidereal_time(constants.julian_years_since_j2000(t)),
+        &constants.propagate(t)?.position,
+        10.0_f64.to_radians(),
+        72,
+    );
+    assert_eq!(footprint.lookup(&regions[..], 4), vec!["europe", "france"]);
+    assert_eq!(footprint.lookup(&hemisphere, 4), vec!["north"]);
+    Ok(())
+}
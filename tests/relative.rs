@@ -0,0 +1,107 @@
+fn prediction(position: [f64; 3], velocity: [f64; 3]) -> sgp4::Prediction {
+    sgp4::Prediction { position, velocity }
+}
+
+// satellite at rest in the (x, y) plane, at the given angle from the x axis in degrees
+fn equatorial(radius: f64, angle: f64) -> sgp4::Prediction {
+    prediction(
+        [
+            radius * angle.to_radians().cos(),
+            radius * angle.to_radians().sin(),
+            0.0,
+        ],
+        [0.0, 0.0, 0.0],
+    )
+}
+
+#[test]
+fn line_of_sight_geometry() {
+    // the closest point of the chord is at r cos(Δθ / 2) from the Earth's center
+    assert!(!sgp4::line_of_sight(
+        &equatorial(7000.0, 0.0),
+        &equatorial(7000.0, 180.0),
+        0.0
+    ));
+    assert!(!sgp4::line_of_sight(
+        &equatorial(7000.0, 0.0),
+        &equatorial(7000.0, 60.0),
+        100.0
+    ));
+    assert!(sgp4::line_of_sight(
+        &equatorial(7000.0, 0.0),
+        &equatorial(7000.0, 40.0),
+        100.0
+    ));
+    assert!(!sgp4::line_of_sight(
+        &equatorial(7000.0, 0.0),
+        &equatorial(7000.0, 40.0),
+        250.0
+    ));
+
+    // the segment does not extend beyond the satellites
+    assert!(sgp4::line_of_sight(
+        &equatorial(7000.0, 0.0),
+        &equatorial(8000.0, 0.0),
+        500.0
+    ));
+
+    // a chord 6370 km from the center passes above the poles (b = 6356.75 km) but below the equator
+    assert!(sgp4::line_of_sight(
+        &prediction([1000.0, 0.0, 6370.0], [0.0, 0.0, 0.0]),
+        &prediction([-1000.0, 0.0, 6370.0], [0.0, 0.0, 0.0]),
+        0.0
+    ));
+    assert!(!sgp4::line_of_sight(
+        &prediction([6370.0, 1000.0, 0.0], [0.0, 0.0, 0.0]),
+        &prediction([6370.0, -1000.0, 0.0], [0.0, 0.0, 0.0]),
+        0.0
+    ));
+}
+
+#[test]
+fn relative_state_range_rate() {
+    let a = prediction([7000.0, 0.0, 0.0], [0.0, 7.5, 0.0]);
+    let state = sgp4::relative_state(&a, &prediction([7000.0, 100.0, 0.0], [0.0, 7.5, 0.1]));
+    assert!((state.range - 100.0).abs() < 1e-12);
+    assert_eq!(state.range_rate, 0.0);
+    assert_eq!(state.velocity, [0.0, 0.0, 0.1]);
+
+    // ṙ = ρ · ρ̇ / |ρ|
+    let state = sgp4::relative_state(&a, &prediction([7060.0, 80.0, 0.0], [0.1, 7.5, 0.0]));
+    assert!((state.range - 100.0).abs() < 1e-12);
+    assert!((state.range_rate - 0.06).abs() < 1e-12);
+    assert_eq!(sgp4::relative_state(&a, &a).range_rate, 0.0);
+}
+
+#[test]
+fn ric_state_rotating_frame() {
+    // reference on a circular equatorial orbit, ω = v / r
+    let angular_velocity = 7.5 / 7000.0;
+    let reference = prediction([7000.0, 0.0, 0.0], [0.0, 7.5, 0.0]);
+
+    // a target that rotates rigidly with the frame (v = ω ẑ × r) is at rest in the RIC frame
+    let state = sgp4::ric_state(
+        &reference,
+        &prediction(
+            [7010.0, 20.0, 5.0],
+            [-angular_velocity * 20.0, angular_velocity * 7010.0, 0.0],
+        ),
+    );
+    for index in 0..3 {
+        assert!((state.position[index] - [10.0, 20.0, 5.0][index]).abs() < 1e-9);
+        assert!(state.velocity[index].abs() < 1e-12);
+    }
+
+    // cross-track offsets are not affected by the frame rotation
+    let state = sgp4::ric_state(
+        &reference,
+        &prediction([7000.0, 0.0, 1.0], [0.0, 7.5, 0.002]),
+    );
+    assert!(state.position[0].abs() < 1e-12 && state.position[1].abs() < 1e-12);
+    assert!((state.position[2] - 1.0).abs() < 1e-12);
+    assert!(state.velocity[0].abs() < 1e-12 && state.velocity[1].abs() < 1e-12);
+    assert!((state.velocity[2] - 0.002).abs() < 1e-12);
+    let state = sgp4::ric_state(&reference, &prediction([7100.0, 0.0, 0.0], [0.0, 7.5, 0.0]));
+    assert!((state.position[0] - 100.0).abs() < 1e-9);
+    assert!((state.velocity[1] + angular_velocity * 100.0).abs() < 1e-12);
+}
@@ -0,0 +1,76 @@
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+// mean orbit with offsets applied to the ISS elements (degrees and revolutions per day)
+fn orbit(
+    inclination: f64,
+    right_ascension: f64,
+    eccentricity: f64,
+    mean_anomaly: f64,
+    mean_motion: f64,
+) -> anyhow::Result<sgp4::Orbit> {
+    let elements = iss()?;
+    Ok(sgp4::Orbit::from_kozai_elements(
+        &sgp4::WGS84,
+        sgp4::Degrees(elements.inclination + inclination),
+        sgp4::Degrees(elements.right_ascension + right_ascension),
+        elements.eccentricity + eccentricity,
+        sgp4::Degrees(elements.argument_of_perigee),
+        sgp4::Degrees(elements.mean_anomaly + mean_anomaly),
+        (elements.mean_motion + mean_motion) * (core::f64::consts::PI / 720.0),
+    )?)
+}
+
+#[test]
+fn relative_orbital_elements_offsets() -> anyhow::Result<()> {
+    let elements = iss()?;
+    let inclination = elements.inclination.to_radians();
+    let argument_of_perigee = elements.argument_of_perigee.to_radians();
+    let chief = orbit(0.0, 0.0, 0.0, 0.0, 0.0)?;
+
+    // δλ = ΔM + ΔΩ cos I, δiᵧ = ΔΩ sin I
+    let roe =
+        sgp4::RelativeOrbitalElements::from_orbits(&chief, &orbit(0.0, 0.01, 0.0, 0.02, 0.0)?);
+    assert_eq!(roe.semi_major_axis, 0.0);
+    assert!((roe.mean_longitude - (0.02 + 0.01 * inclination.cos()).to_radians()).abs() < 1e-12);
+    assert!(roe.eccentricity_x.abs() < 1e-15 && roe.eccentricity_y.abs() < 1e-15);
+    assert_eq!(roe.inclination_x, 0.0);
+    assert!((roe.inclination_y - (0.01 * inclination.sin()).to_radians()).abs() < 1e-12);
+
+    // δeₓ = Δe cos ω, δeᵧ = Δe sin ω
+    let roe =
+        sgp4::RelativeOrbitalElements::from_orbits(&chief, &orbit(0.0, 0.0, 0.001, 0.0, 0.0)?);
+    assert!((roe.eccentricity_x - 0.001 * argument_of_perigee.cos()).abs() < 1e-12);
+    assert!((roe.eccentricity_y - 0.001 * argument_of_perigee.sin()).abs() < 1e-12);
+
+    // δiₓ = ΔI
+    let roe = sgp4::RelativeOrbitalElements::from_orbits(&chief, &orbit(0.01, 0.0, 0.0, 0.0, 0.0)?);
+    assert!((roe.inclination_x - 0.01_f64.to_radians()).abs() < 1e-12);
+    assert_eq!(roe.inclination_y, 0.0);
+
+    // δa ≈ -2 Δn / (3 n) to first order
+    let roe =
+        sgp4::RelativeOrbitalElements::from_orbits(&chief, &orbit(0.0, 0.0, 0.0, 0.0, -0.001)?);
+    assert!((roe.semi_major_axis - 2.0 * 0.001 / (3.0 * elements.mean_motion)).abs() < 1e-8);
+    assert_eq!(roe.mean_longitude, 0.0);
+    Ok(())
+}
+
+#[test]
+fn relative_orbital_elements_along_track() -> anyhow::Result<()> {
+    let constants = sgp4::Constants::from_elements(&iss()?)?;
+    let chief = constants.propagate(sgp4::MinutesSinceEpoch(10.0 / 60.0))?;
+    let deputy = constants.propagate(sgp4::MinutesSinceEpoch(0.0))?;
+    let roe = sgp4::RelativeOrbitalElements::from_orbits(
+        &sgp4::Orbit::from_prediction(&sgp4::WGS84, &chief),
+        &sgp4::Orbit::from_prediction(&sgp4::WGS84, &deputy),
+    );
+
+    // the deputy trails by n × 10 s, up to the short-period variations of the osculating elements
+    let mean_motion = 15.49507896236008 * (2.0 * core::f64::consts::PI / 86400.0);
+    assert!((roe.mean_longitude + mean_motion * 10.0).abs() < 5e-5);
+    assert!(roe.semi_major_axis.abs() < 1e-5);
+    assert!(roe.inclination_x.abs() < 1e-5 && roe.inclination_y.abs() < 1e-5);
+    Ok(())
+}
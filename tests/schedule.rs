@@ -0,0 +1,142 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+// contact with start and end times in minutes
+fn contact(
+    satellite: usize,
+    station: usize,
+    start: f64,
+    end: f64,
+    elevation: f64,
+) -> sgp4::Contact {
+    sgp4::Contact {
+        satellite,
+        station,
+        start: start / (365.25 * 24.0 * 60.0),
+        end: end / (365.25 * 24.0 * 60.0),
+        maximum_elevation: elevation.to_radians(),
+    }
+}
+
+fn selection(contacts: &[sgp4::Contact]) -> Vec<(usize, usize, f64)> {
+    contacts
+        .iter()
+        .map(|contact| {
+            (
+                contact.satellite,
+                contact.station,
+                (contact.start * (365.25 * 24.0 * 60.0)).round(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn schedule_priorities() {
+    let contacts = [
+        contact(0, 0, 0.0, 10.0, 20.0),
+        contact(1, 0, 5.0, 8.0, 80.0),
+        contact(0, 1, 8.0, 12.0, 30.0),
+        contact(1, 1, 12.0, 20.0, 10.0),
+        contact(2, 2, 0.0, 20.0, 5.0),
+    ];
+    assert!((contacts[0].duration() - 10.0).abs() < 1e-9);
+
+    // conflicts require a shared satellite or station, and a strict overlap
+    assert!(contacts[0].conflicts_with(&contacts[1]));
+    assert!(contacts[0].conflicts_with(&contacts[2]));
+    assert!(!contacts[0].conflicts_with(&contacts[3]));
+    assert!(!contacts[1].conflicts_with(&contacts[2]));
+    assert!(!contacts[2].conflicts_with(&contacts[3]));
+    assert!(!contacts[3].conflicts_with(&contacts[4]));
+
+    // the highest contact blocks the first one, which frees its satellite for the third one
+    // (contacts that end when another starts do not conflict)
+    assert_eq!(
+        selection(&sgp4::schedule(&contacts, |contact| contact.maximum_elevation)),
+        [(2, 2, 0.0), (1, 0, 5.0), (0, 1, 8.0), (1, 1, 12.0)]
+    );
+
+    // the longest contacts block the short ones, and selected contacts with the same start keep their priority order
+    assert_eq!(
+        selection(&sgp4::schedule(&contacts, sgp4::Contact::duration)),
+        [(2, 2, 0.0), (0, 0, 0.0), (1, 1, 12.0)]
+    );
+    assert!(sgp4::schedule(&[], sgp4::Contact::duration).is_empty());
+}
+
+#[test]
+fn contacts_passes() -> anyhow::Result<()> {
+    let elements = iss()?;
+    let constants = sgp4::Constants::from_elements(&elements)?;
+    let stations = [
+        sgp4::Target {
+            observer: sgp4::Observer {
+                latitude: 48.8566_f64.to_radians(),
+                longitude: 2.3522_f64.to_radians(),
+                altitude: 0.035,
+            },
+            minimum_elevation: 5.0_f64.to_radians(),
+        },
+        sgp4::Target {
+            observer: sgp4::Observer {
+                latitude: 51.5072_f64.to_radians(),
+                longitude: -0.1276_f64.to_radians(),
+                altitude: 0.011,
+            },
+            minimum_elevation: 5.0_f64.to_radians(),
+        },
+    ];
+    let start = elements.epoch();
+    let end = start + 1.0 / 365.25;
+    let contacts = sgp4::contacts(std::slice::from_ref(&constants), &stations, start, end, 0.5)?;
+
+    // the contacts are the passes above each station, sorted by start time
+    assert_eq!(contacts.len(), 14);
+    assert!(contacts
+        .windows(2)
+        .all(|pair| pair[0].start <= pair[1].start));
+    for (station, target) in stations.iter().enumerate() {
+        let passes = constants.passes(
+            &target.observer,
+            target.minimum_elevation,
+            constants.minutes_since_epoch(start),
+            constants.minutes_since_epoch(end),
+            0.5,
+            sgp4::iau_epoch_to_sidereal_time,
+        )?;
+        let station_contacts: Vec<&sgp4::Contact> = contacts
+            .iter()
+            .filter(|contact| contact.station == station)
+            .collect();
+        assert_eq!(station_contacts.len(), passes.len());
+        for (contact, pass) in station_contacts.iter().zip(passes.iter()) {
+            assert_eq!(contact.satellite, 0);
+            assert_eq!(
+                contact.start,
+                constants.julian_years_since_j2000(pass.rise.unwrap())
+            );
+            assert_eq!(
+                contact.end,
+                constants.julian_years_since_j2000(pass.set.unwrap())
+            );
+            assert_eq!(contact.maximum_elevation, pass.maximum_elevation);
+        }
+    }
+
+    // Paris and London see the same passes, the satellite can only serve the higher one
+    let selected = sgp4::schedule(&contacts, |contact| contact.maximum_elevation);
+    assert_eq!(
+        selected
+            .iter()
+            .map(|contact| contact.station)
+            .collect::<Vec<_>>(),
+        [0, 0, 1, 1, 0, 0, 0]
+    );
+    assert!((elements.age(selected[2].start) * 24.0 * 60.0 - 200.747).abs() < 1e-3);
+    assert!((selected[2].maximum_elevation.to_degrees() - 89.093).abs() < 1e-3);
+    Ok(())
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "alloc")]
+
+// ISS copies in planes tilted about the line of nodes, a Molniya orbit and a geostationary satellite
+fn catalog() -> anyhow::Result<(f64, Vec<sgp4::Constants>)> {
+    let tle = (
+        "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992".as_bytes(),
+        "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008".as_bytes(),
+    );
+    let epoch = sgp4::Elements::from_tle(None, tle.0, tle.1)?.epoch();
+    let mut catalog = (0..6)
+        .map(|plane| {
+            let mut elements = sgp4::Elements::from_tle(None, tle.0, tle.1)?;
+            elements.inclination += 5.0 * plane as f64;
+            elements.mean_anomaly -= 0.01 * plane as f64;
+            Ok(sgp4::Constants::from_elements(&elements)?)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    catalog.push(sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+        None,
+        "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813".as_bytes(),
+        "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656".as_bytes(),
+    )?)?);
+    catalog.push(sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+        None,
+        "1 25954U 99060A   04039.68057285 -.00000108  00000-0  00000-0 0  6847".as_bytes(),
+        "2 25954   0.0004 243.8136 0001765  15.5294  22.7134  1.00271289 15615".as_bytes(),
+    )?)?);
+    Ok((epoch, catalog))
+}
+
+#[test]
+fn conjunction_possible() -> anyhow::Result<()> {
+    let (epoch, catalog) = catalog()?;
+    let end = epoch + 1.0 / 365.25;
+    assert!(sgp4::conjunction_possible(
+        &catalog[0],
+        &catalog[1],
+        epoch,
+        end,
+        10.0
+    ));
+
+    // the geostationary orbit is far above the ISS copies
+    for constants in &catalog[..6] {
+        assert!(!sgp4::conjunction_possible(
+            constants,
+            &catalog[7],
+            epoch,
+            end,
+            10.0
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn screen_catalog() -> anyhow::Result<()> {
+    let (epoch, catalog) = catalog()?;
+    let end = epoch + 0.2 / 365.25;
+    let threshold = 10.0;
+    let conjunctions = sgp4::screen_catalog(&catalog, epoch, end, threshold)?;
+    assert!(!conjunctions.is_empty());
+
+    // every pair screened without prefilter
+    let mut expected = Vec::new();
+    for primary in 0..catalog.len() {
+        for secondary in (primary + 1)..catalog.len() {
+            for conjunction in sgp4::conjunctions(
+                &catalog[primary],
+                &catalog[secondary],
+                epoch,
+                end,
+                threshold,
+            )? {
+                expected.push((primary, secondary, conjunction));
+            }
+        }
+    }
+    expected.sort_by(|a, b| {
+        a.2.tca
+            .total_cmp(&b.2.tca)
+            .then(a.0.cmp(&b.0))
+            .then(a.1.cmp(&b.1))
+    });
+    assert_eq!(conjunctions.len(), expected.len());
+    for (conjunction, (primary, secondary, expected)) in conjunctions.iter().zip(expected.iter()) {
+        assert_eq!(conjunction.primary, *primary);
+        assert_eq!(conjunction.secondary, *secondary);
+        assert_eq!(conjunction.conjunction.tca, expected.tca);
+        assert_eq!(
+            conjunction.conjunction.miss_distance,
+            expected.miss_distance
+        );
+    }
+
+    // the order does not depend on the thread scheduling
+    let repeated = sgp4::screen_catalog(&catalog, epoch, end, threshold)?;
+    assert!(conjunctions.iter().zip(repeated.iter()).all(|(a, b)| (
+        a.primary,
+        a.secondary,
+        a.conjunction.tca
+    ) == (
+        b.primary,
+        b.secondary,
+        b.conjunction.tca
+    )));
+    Ok(())
+}
+
+#[test]
+fn screen_catalog_error() -> anyhow::Result<()> {
+    // decaying copies with epochs 10 minutes apart fail at different propagation times
+    let catalog = (0..8)
+        .map(|index| {
+            let mut elements = sgp4::Elements::from_tle(
+                None,
+                "1 33333U 05037B   05333.02012661  .25992681  00000-0  24476-3 0  1532".as_bytes(),
+                "2 33333  96.4736 157.9986 9950000 244.0492 110.6523  4.00004038 10700".as_bytes(),
+            )?;
+            elements.datetime += chrono::Duration::minutes(10 * index);
+            Ok(sgp4::Constants::from_elements(&elements)?)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let start = catalog[0].epoch();
+    let end = start + 1.0 / 365.25;
+
+    // the first error of the pairs screened in order
+    let expected = 'pairs: {
+        for primary in 0..catalog.len() {
+            for secondary in (primary + 1)..catalog.len() {
+                if sgp4::conjunction_possible(
+                    &catalog[primary],
+                    &catalog[secondary],
+                    start,
+                    end,
+                    10.0,
+                ) {
+                    if let Err(error) =
+                        sgp4::conjunctions(&catalog[primary], &catalog[secondary], start, end, 10.0)
+                    {
+                        break 'pairs error;
+                    }
+                }
+            }
+        }
+        panic!("the screening of the decaying copies should fail");
+    };
+
+    // the error does not depend on the thread scheduling
+    for _ in 0..16 {
+        match sgp4::screen_catalog(&catalog, start, end, 10.0) {
+            Ok(_) => panic!("the screening of the decaying copies should fail"),
+            Err(error) => assert_eq!(format!("{error:?}"), format!("{expected:?}")),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "alloc")]
+
+#[path = "fixtures.rs"]
+mod fixtures;
+use fixtures::iss;
+
+fn constants() -> anyhow::Result<sgp4::Constants> {
+    Ok(sgp4::Constants::from_elements(&iss()?)?)
+}
+
+fn norm(vector: &[f64; 3]) -> f64 {
+    vector
+        .iter()
+        .map(|component| component.powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+// whole minute at which the satellite is closest to the line between the Earth's and the body's centers
+fn overhead_time(constants: &sgp4::Constants, body: sgp4::TransitBody) -> anyhow::Result<f64> {
+    let mut best = (0.0, -1.0);
+    for minutes in 0..24 * 60 {
+        let t = minutes as f64;
+        let position = constants.propagate(sgp4::MinutesSinceEpoch(t))?.position;
+        let body_position =
+            body.position(constants.julian_years_since_j2000(sgp4::MinutesSinceEpoch(t)));
+        let cosine = (0..3)
+            .map(|index| position[index] * body_position[index])
+            .sum::<f64>()
+            / (norm(&position) * norm(&body_position));
+        if cosine > best.1 {
+            best = (t, cosine);
+        }
+    }
+    Ok(best.0)
+}
+
+// ground point on the line from the body's center through the satellite, and its distance to the satellite
+fn aligned_observer(
+    constants: &sgp4::Constants,
+    body: sgp4::TransitBody,
+    t: f64,
+) -> anyhow::Result<(sgp4::Observer, f64)> {
+    let epoch = constants.julian_years_since_j2000(sgp4::MinutesSinceEpoch(t));
+    let sidereal_time = sgp4::iau_epoch_to_sidereal_time(epoch);
+    let position = constants.propagate(sgp4::MinutesSinceEpoch(t))?.position;
+    let body_position = body.position(epoch);
+    let direction = [
+        position[0] - body_position[0],
+        position[1] - body_position[1],
+        position[2] - body_position[2],
+    ];
+    let distance = norm(&direction);
+    let point = |s: f64| {
+        sgp4::Geodetic::from_teme(
+            sidereal_time,
+            &[
+                position[0] + s * direction[0] / distance,
+                position[1] + s * direction[1] / distance,
+                position[2] + s * direction[2] / distance,
+            ],
+        )
+    };
+
+    // bisection on the distance between the satellite and the ground
+    let (mut above, mut below) = (0.0, 3000.0);
+    assert!(point(below).altitude < 0.0);
+    while below - above > 1e-9 {
+        let middle = 0.5 * (above + below);
+        if point(middle).altitude > 0.0 {
+            above = middle;
+        } else {
+            below = middle;
+        }
+    }
+    let ground = point(above);
+    Ok((
+        sgp4::Observer {
+            latitude: ground.latitude,
+            longitude: ground.longitude,
+            altitude: 0.0,
+        },
+        above,
+    ))
+}
+
+#[test]
+fn transits_aligned_observer() -> anyhow::Result<()> {
+    let constants = constants()?;
+    for body in [sgp4::TransitBody::Sun, sgp4::TransitBody::Moon] {
+        let t = overhead_time(&constants, body)?;
+        let (observer, range) = aligned_observer(&constants, body, t)?;
+        let transits = constants.transits(
+            &observer,
+            body,
+            sgp4::MinutesSinceEpoch(t - 30.0),
+            sgp4::MinutesSinceEpoch(t + 30.0),
+            0.1,
+        )?;
+        assert_eq!(transits.len(), 1);
+        let transit = &transits[0];
+        assert_eq!(transit.body, body);
+        assert!((transit.center.0 - t).abs() < 1e-6);
+        assert!(transit.minimum_separation < 1e-6);
+        assert!((transit.range - range).abs() < 1e-5);
+
+        // ρ = sin⁻¹(R / d), with d the distance between the observer and the body's center
+        let epoch = constants.julian_years_since_j2000(sgp4::MinutesSinceEpoch(t));
+        let observer_position = observer.position(sgp4::iau_epoch_to_sidereal_time(epoch));
+        let body_position = body.position(epoch);
+        let body_distance = norm(&[
+            body_position[0] - observer_position[0],
+            body_position[1] - observer_position[1],
+            body_position[2] - observer_position[2],
+        ]);
+        assert!((transit.angular_radius - (body.radius() / body_distance).asin()).abs() < 1e-12);
+
+        // a central transit lasts 2 ρ / ω, with ω the apparent angular rate of the satellite
+        let prediction = constants.propagate(transit.center)?;
+        let line_of_sight = [
+            prediction.position[0] - observer_position[0],
+            prediction.position[1] - observer_position[1],
+            prediction.position[2] - observer_position[2],
+        ];
+        let observer_velocity = observer.velocity(sgp4::iau_epoch_to_sidereal_time(epoch));
+        let velocity = [
+            prediction.velocity[0] - observer_velocity[0],
+            prediction.velocity[1] - observer_velocity[1],
+            prediction.velocity[2] - observer_velocity[2],
+        ];
+        let radial_velocity = (0..3)
+            .map(|index| velocity[index] * line_of_sight[index])
+            .sum::<f64>()
+            / range;
+        let angular_rate = (norm(&velocity).powi(2) - radial_velocity.powi(2)).sqrt() / range;
+        let duration = (transit.egress.0 - transit.ingress.0) * 60.0;
+        assert!((duration / (2.0 * transit.angular_radius / angular_rate) - 1.0).abs() < 1e-3);
+        assert!(
+            (0.5 * (transit.ingress.0 + transit.egress.0) - transit.center.0).abs()
+                < 1e-2 * (transit.egress.0 - transit.ingress.0)
+        );
+
+        // the transit is only visible near the central line
+        let paris = sgp4::Observer {
+            latitude: 48.8566_f64.to_radians(),
+            longitude: 2.3522_f64.to_radians(),
+            altitude: 0.035,
+        };
+        assert!(constants
+            .transits(
+                &paris,
+                body,
+                sgp4::MinutesSinceEpoch(t - 30.0),
+                sgp4::MinutesSinceEpoch(t + 30.0),
+                0.1,
+            )?
+            .is_empty());
+    }
+    Ok(())
+}
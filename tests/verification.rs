@@ -0,0 +1,75 @@
+#![cfg(feature = "verification")]
+
+#[path = "test_cases.rs"]
+mod test_cases;
+use test_cases::*;
+
+#[test]
+fn verification() {
+    // the cases embedded in the library must match the test cases file
+    let test_cases: TestCases = toml::from_str(include_str!("test_cases.toml")).unwrap();
+    let reference_cases = sgp4::reference_cases();
+    assert_eq!(reference_cases.len(), test_cases.list.len());
+    for (reference_case, test_case) in reference_cases.iter().zip(test_cases.list.iter()) {
+        assert_eq!(reference_case.line1, test_case.line1);
+        assert_eq!(reference_case.line2, test_case.line2);
+        assert_eq!(
+            reference_case.states.len(),
+            test_case.states.len(),
+            "{}",
+            test_case.line1
+        );
+        for (reference_state, state) in reference_case.states.iter().zip(test_case.states.iter()) {
+            match (reference_state, state) {
+                (
+                    sgp4::ReferenceState::Prediction {
+                        time: reference_time,
+                        position: reference_position,
+                        velocity: reference_velocity,
+                    },
+                    State::Ok {
+                        time,
+                        position,
+                        velocity,
+                        ..
+                    },
+                ) => {
+                    assert_eq!(reference_time, time, "{}", test_case.line1);
+                    assert_eq!(reference_position, position, "{}", test_case.line1);
+                    assert_eq!(reference_velocity, velocity, "{}", test_case.line1);
+                }
+                (
+                    sgp4::ReferenceState::NegativeSemiLatusRectum {
+                        time: reference_time,
+                    },
+                    State::Err { time, error },
+                ) if error == "negative semi-latus rectum" => {
+                    assert_eq!(reference_time, time, "{}", test_case.line1);
+                }
+                (
+                    sgp4::ReferenceState::OutOfRangePerturbedEccentricity {
+                        time: reference_time,
+                    },
+                    State::Err { time, error },
+                ) if error == "diverging perturbed eccentricity" => {
+                    assert_eq!(reference_time, time, "{}", test_case.line1);
+                }
+                (reference_state, _) => {
+                    panic!("{} {:?}", test_case.line1, reference_state)
+                }
+            }
+        }
+    }
+
+    // the tolerances are those of tests/propagate.rs
+    let residuals = sgp4::verify();
+    assert_eq!(
+        residuals.states,
+        test_cases
+            .list
+            .iter()
+            .map(|test_case| test_case.states.len())
+            .sum::<usize>()
+    );
+    assert!(residuals.is_within(1.0e-6, 1.0e-9), "{residuals:?}");
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "wasm")]
+
+// JavaScript values (dates and errors) cannot be created outside a WebAssembly runtime,
+// hence these tests only cover the methods that succeed without them
+
+const LINE1: &str = "1 25544U 98067A   20194.88612269 -.00002218  00000-0 -31515-4 0  9992";
+const LINE2: &str = "2 25544  51.6461 221.2784 0001413  89.1723 280.4612 15.49507896236008";
+
+#[test]
+fn wasm_propagator() -> anyhow::Result<()> {
+    let propagator = sgp4::WasmPropagator::from_tle(
+        Some("ISS (ZARYA)".to_owned()),
+        &format!("{}\n", LINE1),
+        LINE2,
+    )
+    .unwrap();
+    assert_eq!(propagator.object_name().as_deref(), Some("ISS (ZARYA)"));
+    assert_eq!(propagator.norad_id(), 25544.0);
+
+    // the state is the position followed by the velocity
+    let expected = sgp4::Constants::from_elements(&sgp4::Elements::from_tle(
+        None,
+        LINE1.as_bytes(),
+        LINE2.as_bytes(),
+    )?)?
+    .propagate(sgp4::MinutesSinceEpoch(90.0))?;
+    let state = propagator.propagate_minutes(90.0).unwrap();
+    assert_eq!(state.len(), 6);
+    assert_eq!(state[..3], expected.position);
+    assert_eq!(state[3..], expected.velocity);
+
+    let propagator = sgp4::WasmPropagator::from_omm(
+        r#"{
+            "OBJECT_NAME": "ISS (ZARYA)",
+            "OBJECT_ID": "1998-067A",
+            "EPOCH": "2020-07-12T01:19:07.402656",
+            "MEAN_MOTION": 15.49560532,
+            "ECCENTRICITY": 0.0001771,
+            "INCLINATION": 51.6435,
+            "RA_OF_ASC_NODE": 225.4004,
+            "ARG_OF_PERICENTER": 44.9625,
+            "MEAN_ANOMALY": 5.1087,
+            "EPHEMERIS_TYPE": 0,
+            "CLASSIFICATION_TYPE": "U",
+            "NORAD_CAT_ID": 25544,
+            "ELEMENT_SET_NO": 999,
+            "REV_AT_EPOCH": 23587,
+            "BSTAR": 0.0049645,
+            "MEAN_MOTION_DOT": 0.00289036,
+            "MEAN_MOTION_DDOT": 0
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(propagator.norad_id(), 25544.0);
+    let position = &propagator.propagate_minutes(0.0).unwrap()[..3];
+    let radius = position
+        .iter()
+        .map(|component| component.powi(2))
+        .sum::<f64>()
+        .sqrt();
+    assert!(radius > 6700.0 && radius < 6850.0);
+    Ok(())
+}